@@ -0,0 +1,82 @@
+// Benchmarks for `wt list` internals, timed in-process by the hidden `wt bench`
+//
+// `collect` and layout live in the binary, so criterion can't call them
+// directly. Each sample runs `wt bench --phase <phase> --iterations <n>`
+// against a prepared repo and uses the total the command reports, which
+// excludes process startup and repo setup.
+//
+// Benchmark groups:
+//   - collect: `wt list` data collection (1, 8, 32 worktrees)
+//   - layout: column layout + row rendering (1, 8, 32, 128 worktrees)
+//
+// Run examples:
+//   cargo bench --bench list_phases                  # All benchmarks
+//   cargo bench --bench list_phases layout           # Layout only (fast)
+//   cargo bench --bench list_phases -- --skip 32     # Skip large repos
+//
+// Without criterion, for a quick look:
+//   cargo run --release -- bench --worktrees 32
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use std::path::Path;
+use std::process::Command;
+use std::time::Duration;
+use wt_perf::{RepoConfig, create_repo};
+
+/// Run `iters` iterations of `phase` in one `wt bench` process; return their total.
+fn run_phase(repo_path: &Path, phase: &str, iters: u64) -> Duration {
+    let output = Command::new(env!("CARGO_BIN_EXE_wt"))
+        .args(["bench", "--phase", phase, "--iterations"])
+        .arg(iters.to_string())
+        .arg("--repo")
+        .arg(repo_path)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "wt bench failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let nanos: u64 = String::from_utf8_lossy(&output.stdout)
+        .trim()
+        .parse()
+        .unwrap();
+    Duration::from_nanos(nanos)
+}
+
+fn bench_phase(c: &mut Criterion, phase: &str, worktree_counts: &[usize]) {
+    let mut group = c.benchmark_group(phase);
+
+    for &worktrees in worktree_counts {
+        let temp = create_repo(&RepoConfig::typical(worktrees));
+        let repo_path = temp.path().join("repo");
+
+        group.bench_with_input(
+            BenchmarkId::from_parameter(worktrees),
+            &repo_path,
+            |b, repo_path| {
+                b.iter_custom(|iters| run_phase(repo_path, phase, iters));
+            },
+        );
+    }
+
+    group.finish();
+}
+
+fn bench_collect(c: &mut Criterion) {
+    bench_phase(c, "collect", &[1, 8, 32]);
+}
+
+fn bench_layout(c: &mut Criterion) {
+    bench_phase(c, "layout", &[1, 8, 32, 128]);
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default()
+        .sample_size(20)
+        .measurement_time(std::time::Duration::from_secs(10))
+        .warm_up_time(std::time::Duration::from_secs(2));
+    targets = bench_collect, bench_layout
+}
+criterion_main!(benches);
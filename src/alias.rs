@@ -0,0 +1,139 @@
+//! Command aliases from the `[alias]` section of the user config.
+//!
+//! An alias expands to a built-in command line before clap sees it:
+//!
+//! ```toml
+//! [alias]
+//! st = "step for-each -- git status --short"
+//! new = "switch --create {{ args }} --base main"
+//! ```
+//!
+//! Arguments after the alias are appended to the expansion, unless the
+//! expansion is a template, in which case they go where `{{ args }}` says
+//! (shell-quoted). Built-in commands take precedence over aliases, and an
+//! alias must expand to a built-in command — aliases don't chain.
+
+use std::collections::BTreeMap;
+use std::ffi::OsString;
+
+use anyhow::{Context, bail};
+use clap::Command;
+
+use crate::GlobalArgs;
+
+/// Aliases from the user config, if any.
+pub fn load() -> BTreeMap<String, String> {
+    worktrunk::config::UserConfig::load_section("alias").unwrap_or_default()
+}
+
+/// `argv` with a leading alias expanded; unchanged when there's none.
+pub fn expand(
+    argv: Vec<OsString>,
+    aliases: &BTreeMap<String, String>,
+    cmd: &Command,
+) -> anyhow::Result<Vec<OsString>> {
+    let Some(index) = GlobalArgs::scan(&argv).subcommand else {
+        return Ok(argv);
+    };
+    let name = argv[index].to_string_lossy().into_owned();
+    if cmd.find_subcommand(&name).is_some() {
+        return Ok(argv);
+    }
+    let Some(expansion) = aliases.get(&name) else {
+        return Ok(argv);
+    };
+
+    let rest: Vec<String> = argv[index + 1..]
+        .iter()
+        .map(|arg| arg.to_string_lossy().into_owned())
+        .collect();
+    let line = if expansion.contains("{{") {
+        let args = shlex::try_join(rest.iter().map(String::as_str))
+            .with_context(|| format!("Failed to quote arguments for alias {name}"))?;
+        let env = minijinja::Environment::new();
+        env.render_str(expansion, minijinja::context! { args })
+            .with_context(|| format!("Failed to expand alias {name}"))?
+    } else {
+        expansion.clone()
+    };
+    let Some(mut words) = shlex::split(&line) else {
+        bail!("Alias {name} has unbalanced quotes: {line}");
+    };
+    match words.first() {
+        Some(first) if cmd.find_subcommand(first).is_some() => {}
+        Some(first) => bail!("Alias {name} expands to {first}, which isn't a wt command"),
+        None => bail!("Alias {name} is empty"),
+    }
+    if !expansion.contains("{{") {
+        words.extend(rest);
+    }
+
+    let mut expanded = argv[..index].to_vec();
+    expanded.extend(words.into_iter().map(OsString::from));
+    Ok(expanded)
+}
+
+/// `cmd` with aliases listed among its subcommands, for `wt --help`.
+pub fn with_aliases(mut cmd: Command, aliases: &BTreeMap<String, String>) -> Command {
+    for (name, expansion) in aliases {
+        if cmd.find_subcommand(name).is_none() {
+            cmd = cmd.subcommand(
+                Command::new(name.clone()).about(format!("Alias for `wt {expansion}`")),
+            );
+        }
+    }
+    cmd
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn argv(args: &[&str]) -> Vec<OsString> {
+        args.iter().map(OsString::from).collect()
+    }
+
+    fn expand_str(args: &[&str]) -> anyhow::Result<Vec<OsString>> {
+        let aliases = BTreeMap::from([
+            (
+                "st".to_string(),
+                "step for-each -- git status --short".to_string(),
+            ),
+            (
+                "new".to_string(),
+                "switch --create {{ args }} --base main".to_string(),
+            ),
+            ("list".to_string(), "remove".to_string()),
+            ("loop".to_string(), "st".to_string()),
+        ]);
+        expand(argv(args), &aliases, &crate::cli::build_command())
+    }
+
+    #[test]
+    fn test_expand() {
+        assert_eq!(
+            expand_str(&["wt", "-C", "repo", "st"]).unwrap(),
+            argv(&[
+                "wt", "-C", "repo", "step", "for-each", "--", "git", "status", "--short"
+            ])
+        );
+        assert_eq!(
+            expand_str(&["wt", "--color", "never", "st"]).unwrap(),
+            argv(&[
+                "wt", "--color", "never", "step", "for-each", "--", "git", "status", "--short"
+            ])
+        );
+        assert_eq!(
+            expand_str(&["wt", "new", "feature x"]).unwrap(),
+            argv(&["wt", "switch", "--create", "feature x", "--base", "main"])
+        );
+        // Built-ins win
+        assert_eq!(expand_str(&["wt", "list"]).unwrap(), argv(&["wt", "list"]));
+        assert_eq!(
+            expand_str(&["wt", "unknown"]).unwrap(),
+            argv(&["wt", "unknown"])
+        );
+        // No chaining
+        assert!(expand_str(&["wt", "loop"]).is_err());
+    }
+}
@@ -0,0 +1,32 @@
+use clap::Subcommand;
+use worktrunk::forge::Forge;
+
+/// Subcommands for `wt auth`
+#[derive(Subcommand)]
+pub enum AuthCommand {
+    /// Show which token each forge uses and its scopes
+    Status,
+
+    /// Store a token for a forge
+    ///
+    /// Reads the token from stdin.
+    #[command(
+        after_long_help = r#"The token goes to git's credential store (`git credential approve`), so it's kept by whatever helper git is configured with — the macOS keychain, libsecret, Git Credential Manager. Worktrunk passes it to `gh` and `glab` as `GH_TOKEN` / `GITLAB_TOKEN`.
+
+```console
+gh auth token | wt auth login github
+wt auth login gitlab < ~/.gitlab-token
+```
+"#
+    )]
+    Login {
+        /// Forge the token is for
+        forge: Forge,
+    },
+
+    /// Remove a stored token
+    Logout {
+        /// Forge whose token to remove
+        forge: Forge,
+    },
+}
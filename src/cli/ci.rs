@@ -0,0 +1,18 @@
+use clap::Subcommand;
+
+/// Subcommands for `wt ci`
+#[derive(Subcommand)]
+pub enum CiCommand {
+    /// Re-run failed CI for a branch
+    #[command(
+        after_long_help = r#"Re-runs what failed for the branch's latest commit: on GitHub, the failed jobs of each failed workflow run (`gh run rerun --failed`); on GitLab, a retry of the failed pipeline. Passing and in-progress runs are left alone.
+"#
+    )]
+    Rerun {
+        /// Branch whose CI to re-run
+        ///
+        /// Defaults to current branch.
+        #[arg(add = crate::completion::branch_value_completer())]
+        branch: Option<String>,
+    },
+}
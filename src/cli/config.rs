@@ -74,10 +74,6 @@ Use --yes to skip confirmation."#
         #[arg(short, long)]
         yes: bool,
 
-        /// Show what would be changed
-        #[arg(long)]
-        dry_run: bool,
-
         /// Command name for shell integration (defaults to binary name)
         ///
         /// Use this to create shell integration for an alternate command name.
@@ -122,10 +118,6 @@ Detects various forms of the integration pattern regardless of:
         /// Skip confirmation prompt
         #[arg(short, long)]
         yes: bool,
-
-        /// Show what would be changed
-        #[arg(long)]
-        dry_run: bool,
     },
 
     /// Show output theme samples
@@ -210,6 +202,26 @@ pub enum ConfigCommand {
         project: bool,
     },
 
+    /// Edit configuration file and validate it
+    #[command(
+        after_long_help = r#"Opens user config (`~/.config/worktrunk/config.toml`) in `$VISUAL`, then `$EDITOR`, falling back to `vi`. With `--project`, opens project config (`.config/wt.toml`) instead.
+
+When the editor exits, the file is checked as `wt` would load it. Syntax and schema errors are shown right away, with an offer to re-open the editor; unknown keys are reported as warnings.
+
+## Examples
+
+```console
+wt config edit
+wt config edit --project
+EDITOR="code --wait" wt config edit
+```"#
+    )]
+    Edit {
+        /// Edit project config (`.config/wt.toml`) instead of user config
+        #[arg(long)]
+        project: bool,
+    },
+
     /// Show configuration files & locations
     #[command(
         after_long_help = r#"Shows location and contents of user config (`~/.config/worktrunk/config.toml`)
@@ -308,12 +320,13 @@ Without a subcommand, runs `get`. Use `set` to override, or `clear` then `get` t
 
 Worktrunk detects the default branch automatically:
 
-1. **Worktrunk cache** — Checks `git config worktrunk.default-branch` (single command)
-2. **Git cache** — Detects primary remote and checks its HEAD (e.g., `origin/HEAD`)
-3. **Remote query** — If not cached, queries `git ls-remote` (100ms–2s)
-4. **Local inference** — If no remote, infers from local branches
+1. **Project config** — `default-branch` in `.config/wt.toml`, shared by the team
+2. **Worktrunk cache** — Checks `git config worktrunk.default-branch` (single command)
+3. **Git cache** — Detects primary remote and checks its HEAD (e.g., `origin/HEAD`)
+4. **Remote query** — If not cached, queries `git ls-remote` (100ms–2s)
+5. **Local inference** — If no remote, infers from local branches
 
-Once detected, the result is cached in `worktrunk.default-branch` for fast access.
+Once detected, the result is cached in `worktrunk.default-branch` for fast access. The project config takes precedence over `set`; `wt doctor` shows which value is in use.
 
 The local inference fallback uses these heuristics in order:
 - If only one local branch exists, uses it
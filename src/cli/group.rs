@@ -0,0 +1,32 @@
+use clap::Subcommand;
+
+/// Subcommands for `wt group`
+#[derive(Subcommand)]
+pub enum GroupCommand {
+    /// Add branches to a group
+    ///
+    /// Creates the group if it doesn't exist.
+    Add {
+        /// Group name
+        name: String,
+
+        /// Branches to add
+        #[arg(required = true, add = crate::completion::worktree_only_completer())]
+        branches: Vec<String>,
+    },
+
+    /// Remove branches from a group
+    ///
+    /// Without branches, removes the whole group.
+    Remove {
+        /// Group name
+        name: String,
+
+        /// Branches to remove
+        #[arg(add = crate::completion::worktree_only_completer())]
+        branches: Vec<String>,
+    },
+
+    /// List groups and their branches
+    List,
+}
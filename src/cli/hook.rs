@@ -7,7 +7,9 @@ use super::config::ApprovalsCommand;
 pub enum HookCommand {
     /// Show configured hooks
     ///
-    /// Lists user and project hooks. Project hooks show approval status (❓ = needs approval).
+    /// Lists user and project hooks with their `when` and `paths` conditions.
+    /// Project hooks show approval status (❓ = needs approval).
+    #[command(alias = "list")]
     Show {
         /// Hook type to show (default: all)
         #[arg(value_parser = ["post-create", "post-start", "post-switch", "pre-commit", "pre-merge", "post-merge", "pre-remove", "post-remove"])]
@@ -18,6 +20,13 @@ pub enum HookCommand {
         expanded: bool,
     },
 
+    /// Check hook commands without running them
+    ///
+    /// Expands each command's template in the current worktree and checks
+    /// that the programs it starts are on PATH. Exits non-zero on any
+    /// problem, so it can run in CI.
+    Check,
+
     /// Run post-create hooks
     ///
     /// Blocking — waits for completion before continuing.
@@ -190,6 +199,32 @@ pub enum HookCommand {
         vars: Vec<(String, String)>,
     },
 
+    /// Show output logs of recent hook runs
+    ///
+    /// Lists per-run logs of foreground hooks, newest first.
+    #[command(
+        after_long_help = r#"Foreground hooks (post-create, pre-commit, pre-merge, post-merge, pre-remove, and any hook run with `--foreground`) stream to the terminal and are also logged to `.git/worktrunk/hook-logs/`, one file per run. When a hook fails, the error shows its log path. The last 100 runs are kept.
+
+Background hooks log to `.git/wt-logs/` instead; see [`wt config state logs`](@/config.md#wt-config-state-logs).
+
+## Examples
+
+List logged runs, newest first:
+```console
+wt hook logs
+```
+
+Show the output of the most recent run:
+```console
+wt hook logs --last
+```"#
+    )]
+    Logs {
+        /// Print the most recent run's output
+        #[arg(long)]
+        last: bool,
+    },
+
     /// Manage command approvals
     #[command(
         after_long_help = r#"Project hooks require approval on first run to prevent untrusted projects from running arbitrary commands.
@@ -0,0 +1,14 @@
+use clap::Subcommand;
+
+/// Subcommands for `wt perf`
+#[derive(Subcommand)]
+pub enum PerfCommand {
+    /// Summarize the slowest commands and operations
+    ///
+    /// Reads the timing log in `.git/wt-logs/perf.jsonl`.
+    Report {
+        /// Operations to show
+        #[arg(long, default_value_t = 15)]
+        limit: usize,
+    },
+}
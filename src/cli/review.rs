@@ -0,0 +1,18 @@
+use clap::Subcommand;
+
+/// Subcommands for `wt review`
+#[derive(Subcommand)]
+pub enum ReviewCommand {
+    /// Remove the current review worktree
+    ///
+    /// Deletes the branch too if `wt review` created it.
+    Done {
+        /// Skip approval prompts
+        #[arg(short, long)]
+        yes: bool,
+
+        /// Skip hooks
+        #[arg(long = "no-verify", action = clap::ArgAction::SetFalse, default_value_t = true)]
+        verify: bool,
+    },
+}
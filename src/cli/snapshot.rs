@@ -0,0 +1,26 @@
+use clap::Subcommand;
+
+/// Subcommands for `wt snapshot`
+#[derive(Subcommand)]
+pub enum SnapshotCommand {
+    /// List the current worktree's snapshots, newest first
+    List,
+
+    /// Put the worktree back the way a snapshot recorded it
+    ///
+    /// Current changes are saved as a new snapshot first.
+    Restore {
+        /// Snapshot number
+        ///
+        /// Defaults to the newest snapshot.
+        id: Option<u32>,
+    },
+
+    /// Show how the worktree differs from a snapshot
+    Diff {
+        /// Snapshot number
+        ///
+        /// Defaults to the newest snapshot.
+        id: Option<u32>,
+    },
+}
@@ -0,0 +1,50 @@
+use clap::Subcommand;
+
+/// Subcommands for `wt stack`
+#[derive(Subcommand)]
+pub enum StackCommand {
+    /// Stack a branch on another branch
+    ///
+    /// Replaces any parent recorded earlier.
+    Add {
+        /// Branch to build on
+        #[arg(add = crate::completion::branch_value_completer())]
+        parent: String,
+
+        /// Branch to stack
+        ///
+        /// Defaults to the current worktree's branch.
+        #[arg(add = crate::completion::branch_value_completer())]
+        branch: Option<String>,
+    },
+
+    /// Push a stack and open a PR/MR per branch
+    ///
+    /// Each PR/MR targets the branch's parent and links the rest of the stack.
+    Submit {
+        /// Any branch in the stack
+        ///
+        /// Defaults to the current worktree's branch.
+        #[arg(add = crate::completion::branch_value_completer())]
+        branch: Option<String>,
+
+        /// Open new PRs/MRs as drafts
+        #[arg(long)]
+        draft: bool,
+
+        /// Skip confirmation prompts
+        #[arg(short, long)]
+        yes: bool,
+    },
+
+    /// Take a branch out of its stack
+    ///
+    /// Branches stacked on it move down to its parent.
+    Remove {
+        /// Branch to unstack
+        ///
+        /// Defaults to the current worktree's branch.
+        #[arg(add = crate::completion::branch_value_completer())]
+        branch: Option<String>,
+    },
+}
@@ -127,6 +127,35 @@ wt step squash --show-prompt | less
         show_prompt: bool,
     },
 
+    /// Split uncommitted changes into a commit series
+    ///
+    /// Groups changed files into commits, then commits each group.
+    #[command(
+        after_long_help = r#"Breaks the worktree's uncommitted changes into several commits, for teams that review commit by commit.
+
+With [commit generation](@/llm-commits.md) configured, the LLM groups the changed files by logical change and writes each commit's subject. Otherwise — or for files the LLM leaves out — files are grouped by top-level directory. The plan is shown for confirmation before anything is committed; `--dry-run` stops after showing it.
+
+With `--patch`, each group is staged hunk by hunk (`git add --patch`), so a file can contribute to several commits; whatever is left over after the last group stays uncommitted. A safety backup of the working tree is saved to `refs/wt-backup/<branch>` first.
+
+## Examples
+
+```console
+wt step split             # Group, confirm, commit
+wt step split --patch     # Pick hunks for each commit
+wt step split --dry-run   # Show the plan only
+```
+"#
+    )]
+    Split {
+        /// Skip the confirmation prompt
+        #[arg(short, long)]
+        yes: bool,
+
+        /// Select hunks for each commit interactively
+        #[arg(short, long)]
+        patch: bool,
+    },
+
     /// Fast-forward target to current branch
     #[command(
         after_long_help = r#"Updates the local target branch (e.g., `main`) to include current commits.
@@ -136,9 +165,16 @@ wt step squash --show-prompt | less
 ```console
 wt step push             # Fast-forward main to current branch
 wt step push develop     # Fast-forward develop instead
+wt step push --remote    # Push the current branch to the remote
 ```
 
 Similar to `git push . HEAD:<target>`, but uses `receive.denyCurrentBranch=updateInstead` internally.
+
+With `--remote`, pushes the current branch to the primary remote instead, setting its upstream as `[push] set-upstream` allows. A branch rewritten since its last push (rebase, squash) is pushed with `--force-with-lease --force-if-includes`; protected branches are never force-pushed.
+
+With `[secret-scan] enabled = true`, the outgoing commits are scanned for secrets first; findings block the push. Skip the scan with `--no-verify`.
+
+Branches listed under `[branches] protected` in the project config are refused unless `--force` is passed.
 "#
     )]
     Push {
@@ -147,11 +183,23 @@ Similar to `git push . HEAD:<target>`, but uses `receive.denyCurrentBranch=updat
         /// Defaults to default branch.
         #[arg(add = crate::completion::branch_value_completer())]
         target: Option<String>,
+
+        /// Skip the secret scan
+        #[arg(long = "no-verify", action = clap::ArgAction::SetFalse, default_value_t = true)]
+        verify: bool,
+
+        /// Push to a protected branch
+        #[arg(short, long)]
+        force: bool,
+
+        /// Push the current branch to the primary remote
+        #[arg(long, conflicts_with_all = ["target", "force"])]
+        remote: bool,
     },
 
     /// Rebase onto target
     #[command(
-        after_long_help = r#"Rebases the current branch onto the target branch. Conflicts abort immediately; use `git rebase --abort` to recover.
+        after_long_help = r#"Rebases the current branch onto the target branch. Conflicts stop the rebase; `wt resolve` works through them, or `git rebase --abort` recovers.
 
 ## Examples
 
@@ -269,15 +317,40 @@ The `.worktreeinclude` pattern is shared with [Claude Code on desktop](https://c
         #[arg(long, add = crate::completion::worktree_only_completer())]
         to: Option<String>,
 
-        /// Show what would be copied
-        #[arg(long)]
-        dry_run: bool,
-
         /// Overwrite existing files in destination
         #[arg(long)]
         force: bool,
     },
 
+    /// Create this worktree's database
+    ///
+    /// Runs `[database] create` from the project config.
+    #[command(
+        after_long_help = r#"Runs the `create` command under `[database]` in the project config in the current worktree, with the hook template variables. `{{ branch_slug }}` gives each branch a database-safe name:
+
+```toml
+# .config/wt.toml
+[database]
+create = "createdb -T app_dev app_{{ branch_slug }}"
+drop = "dropdb --if-exists app_{{ branch_slug }}"
+
+[post-create]
+db = "wt step db-create"
+
+[pre-remove]
+db = "wt step db-drop"
+```
+
+The database is tracked until `wt step db-drop` runs for the branch. `wt gc` reports tracked databases whose worktree is gone.
+"#
+    )]
+    DbCreate,
+
+    /// Drop this worktree's database
+    ///
+    /// Runs `[database] drop` from the project config.
+    DbDrop,
+
     /// \[experimental\] Run command in each worktree
     ///
     /// Executes sequentially with real-time output; continues on failure.
@@ -304,6 +377,12 @@ Run npm install in all worktrees:
 wt step for-each -- npm install
 ```
 
+Run only in the worktrees of a group (see `wt group`):
+
+```console
+wt step for-each --group backend -- make test
+```
+
 Use branch name in command:
 
 ```console
@@ -320,6 +399,10 @@ Note: This command is experimental and may change in future versions.
 "#
     )]
     ForEach {
+        /// Only worktrees whose branch is in this group
+        #[arg(long)]
+        group: Option<String>,
+
         /// Command template (see --help for all variables)
         #[arg(required = true, last = true, num_args = 1..)]
         args: Vec<String>,
@@ -389,10 +472,6 @@ Note: This command is experimental and may change in future versions.
         #[arg(add = crate::completion::worktree_only_completer())]
         branches: Vec<String>,
 
-        /// Show what would be moved
-        #[arg(long)]
-        dry_run: bool,
-
         /// Commit uncommitted changes before relocating
         #[arg(long)]
         commit: bool,
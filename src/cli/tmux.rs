@@ -0,0 +1,14 @@
+use clap::Subcommand;
+
+/// Subcommands for `wt tmux`
+#[derive(Subcommand)]
+pub enum TmuxCommand {
+    /// Create a window for every worktree
+    #[command(
+        after_long_help = r#"Creates a window, started in the worktree directory, for each worktree that doesn't have one. Existing windows are left alone and focus doesn't change.
+
+Inside tmux, windows go in the current session. Outside, they go in the `[tmux] session` session, which is created detached if needed — attach with `wt tmux`.
+"#
+    )]
+    Sync,
+}
@@ -0,0 +1,521 @@
+//! `wt absorb`: fold staged changes into the branch commits they amend.
+//!
+//! Each staged hunk is blamed against `HEAD`. When every line it replaces was
+//! last touched by the same commit on the branch (between the merge base with
+//! the target and `HEAD`), the hunk becomes part of a `fixup!` commit for that
+//! commit. Hunks that touch several commits, commits outside the branch, or
+//! new/deleted/binary files stay staged.
+//!
+//! Fixups are built in a scratch index, so the real index is never rewritten:
+//! once a fixup lands in `HEAD`, its hunks simply stop showing as staged.
+//! With `--rebase`, an autosquash rebase onto the merge base folds the fixups
+//! in, through the same rebase path as `wt step rebase`.
+
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+
+use anyhow::Context;
+use color_print::cformat;
+use worktrunk::git::{GitError, Repository};
+use worktrunk::shell_exec::Cmd;
+use worktrunk::state::Operation;
+use worktrunk::styling::{
+    eprintln, format_with_gutter, hint_message, info_message, progress_message, success_message,
+};
+
+use super::snapshot::auto_snapshot;
+use super::step_commands::run_rebase;
+use super::undo::record;
+
+/// Options for `wt absorb`
+pub struct AbsorbOptions<'a> {
+    /// Branch whose merge base bounds the commits that can be amended
+    pub target: Option<&'a str>,
+    /// Autosquash the fixups into their commits afterwards
+    pub rebase: bool,
+    /// Print the mapping without committing
+    pub dry_run: bool,
+}
+
+/// A zero-context hunk from `git diff -U0`.
+#[derive(Debug, Clone, PartialEq)]
+struct Hunk {
+    old_start: u32,
+    old_count: u32,
+    new_count: u32,
+    /// The `-`, `+` and `\ No newline` lines
+    lines: Vec<String>,
+}
+
+impl Hunk {
+    fn delta(&self) -> i64 {
+        i64::from(self.new_count) - i64::from(self.old_count)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct FileDiff {
+    path: String,
+    hunks: Vec<Hunk>,
+}
+
+/// Parse `<start>[,<count>]` from a hunk header.
+fn parse_range(range: &str) -> Option<(u32, u32)> {
+    match range.split_once(',') {
+        Some((start, count)) => Some((start.parse().ok()?, count.parse().ok()?)),
+        None => Some((range.parse().ok()?, 1)),
+    }
+}
+
+/// Parse `git diff -U0` output into per-file hunks.
+///
+/// Only plain content modifications are kept; files that are added, deleted,
+/// renamed, binary or change mode can't be blamed line by line.
+fn parse_diff(diff: &str) -> Vec<FileDiff> {
+    let mut files = Vec::new();
+    let mut current: Option<FileDiff> = None;
+    let mut skip = false;
+    for line in diff.lines() {
+        if line.starts_with("diff --git ") {
+            files.extend(current.take().filter(|f| !f.hunks.is_empty()));
+            skip = false;
+            continue;
+        }
+        if skip {
+            continue;
+        }
+        if line.starts_with("new file mode")
+            || line.starts_with("deleted file mode")
+            || line.starts_with("old mode")
+            || line.starts_with("rename from")
+            || line.starts_with("Binary files")
+        {
+            skip = true;
+            current = None;
+            continue;
+        }
+        if let Some(path) = line.strip_prefix("+++ b/") {
+            current = Some(FileDiff {
+                path: path.to_string(),
+                hunks: Vec::new(),
+            });
+            continue;
+        }
+        if let Some(header) = line.strip_prefix("@@ -") {
+            let Some((old, rest)) = header.split_once(" +") else {
+                continue;
+            };
+            let new = rest.split(' ').next().unwrap_or_default();
+            if let (Some(file), Some((old_start, old_count)), Some((_, new_count))) =
+                (current.as_mut(), parse_range(old), parse_range(new))
+            {
+                file.hunks.push(Hunk {
+                    old_start,
+                    old_count,
+                    new_count,
+                    lines: Vec::new(),
+                });
+            }
+            continue;
+        }
+        if (line.starts_with('-') || line.starts_with('+') || line.starts_with('\\'))
+            && !line.starts_with("--- ")
+            && let Some(hunk) = current.as_mut().and_then(|f| f.hunks.last_mut())
+        {
+            hunk.lines.push(line.to_string());
+        }
+    }
+    files.extend(current.filter(|f| !f.hunks.is_empty()));
+    files
+}
+
+/// Render the `selected` hunks of `file` as a zero-context patch against an
+/// index where the `applied` hunks are already in place.
+///
+/// Hunk positions in the original diff are relative to `HEAD`; each hunk is
+/// shifted by the line delta of the earlier hunks already applied.
+fn render_patch(file: &FileDiff, applied: &[bool], selected: &[usize]) -> String {
+    let mut patch = format!("diff --git a/{0} b/{0}\n--- a/{0}\n+++ b/{0}\n", file.path);
+    // Delta of earlier hunks in this same patch, for the new-side position
+    let mut in_patch = 0i64;
+    for &index in selected {
+        let hunk = &file.hunks[index];
+        let shift: i64 = file.hunks[..index]
+            .iter()
+            .zip(applied)
+            .filter(|(_, applied)| **applied)
+            .map(|(h, _)| h.delta())
+            .sum();
+        let old_start = i64::from(hunk.old_start) + shift;
+        // A zero count names the line before the change
+        let new_start = old_start
+            + in_patch
+            + match (hunk.old_count, hunk.new_count) {
+                (0, _) => 1,
+                (_, 0) => -1,
+                _ => 0,
+            };
+        patch.push_str(&format!(
+            "@@ -{old_start},{} +{new_start},{} @@\n",
+            hunk.old_count, hunk.new_count
+        ));
+        for line in &hunk.lines {
+            patch.push_str(line);
+            patch.push('\n');
+        }
+        in_patch += hunk.delta();
+    }
+    patch
+}
+
+/// Commits that last touched the lines `hunk` replaces.
+///
+/// A pure addition has no lines of its own; the line above it (or the first
+/// line, at the top of the file) stands in.
+fn blame_hunk(repo: &Repository, path: &str, hunk: &Hunk) -> HashSet<String> {
+    let range = if hunk.old_count == 0 {
+        let line = hunk.old_start.max(1);
+        format!("{line},{line}")
+    } else {
+        format!("{},{}", hunk.old_start, hunk.old_start + hunk.old_count - 1)
+    };
+    let Ok(output) = repo.current_worktree().run_command(&[
+        "blame",
+        "--porcelain",
+        "-L",
+        &range,
+        "HEAD",
+        "--",
+        path,
+    ]) else {
+        return HashSet::new();
+    };
+    output
+        .lines()
+        .filter(|line| !line.starts_with('\t'))
+        .filter_map(|line| line.split(' ').next())
+        .filter(|sha| sha.len() >= 40 && sha.chars().all(|c| c.is_ascii_hexdigit()))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Run git against the scratch index at `index`.
+fn git_in_index(
+    root: &Path,
+    index: &Path,
+    args: &[&str],
+    stdin: Option<&str>,
+) -> anyhow::Result<()> {
+    let mut cmd = Cmd::new("git")
+        .args(args.iter().copied())
+        .current_dir(root)
+        .env("GIT_INDEX_FILE", index.to_string_lossy());
+    if let Some(stdin) = stdin {
+        cmd = cmd.stdin_bytes(stdin);
+    }
+    let output = cmd
+        .run()
+        .with_context(|| format!("Failed to run git {}", args.join(" ")))?;
+    if !output.status.success() {
+        return Err(GitError::Other {
+            message: format!(
+                "git {} failed: {}",
+                args.first().unwrap_or(&""),
+                String::from_utf8_lossy(&output.stderr).trim()
+            ),
+        }
+        .into());
+    }
+    Ok(())
+}
+
+/// Handle `wt absorb`.
+pub fn handle_absorb(opts: AbsorbOptions<'_>) -> anyhow::Result<()> {
+    let AbsorbOptions {
+        target,
+        rebase,
+        dry_run,
+    } = opts;
+    let repo = Repository::current()?;
+    let worktree = repo.current_worktree();
+    let Some(branch) = worktree.branch()? else {
+        return Err(GitError::DetachedHead {
+            action: Some("absorb".into()),
+        }
+        .into());
+    };
+    if !worktree.has_staged_changes()? {
+        eprintln!("{}", info_message("Nothing to absorb; no staged changes"));
+        return Ok(());
+    }
+
+    let target = repo.require_target_ref(target)?;
+    let merge_base = repo
+        .merge_base("HEAD", &target)?
+        .context("Cannot absorb: no common ancestor with target branch")?;
+    // Newest first; fixups are created oldest first so they read in order
+    let candidates: Vec<String> = worktree
+        .run_command(&["rev-list", &format!("{merge_base}..HEAD")])?
+        .lines()
+        .map(str::to_string)
+        .collect();
+    if candidates.is_empty() {
+        eprintln!(
+            "{}",
+            info_message(cformat!(
+                "Nothing to absorb into; <bold>{branch}</> has no commits ahead of <bold>{target}</>"
+            ))
+        );
+        return Ok(());
+    }
+
+    let diff = worktree.run_command(&[
+        "diff",
+        "--cached",
+        "-U0",
+        "--no-color",
+        "--no-ext-diff",
+        "--no-renames",
+    ])?;
+    let files = parse_diff(&diff);
+
+    // Commit → (file index, hunk index) pairs it absorbs
+    let mut plan: BTreeMap<usize, Vec<(usize, usize)>> = BTreeMap::new();
+    for (f, file) in files.iter().enumerate() {
+        for (h, hunk) in file.hunks.iter().enumerate() {
+            let blamed = blame_hunk(&repo, &file.path, hunk);
+            if blamed.len() != 1 {
+                continue;
+            }
+            let sha = blamed.into_iter().next().unwrap();
+            if let Some(position) = candidates.iter().position(|c| *c == sha) {
+                plan.entry(position).or_default().push((f, h));
+            }
+        }
+    }
+    if plan.is_empty() {
+        eprintln!(
+            "{}",
+            info_message(cformat!(
+                "No staged hunk maps to a single commit on <bold>{branch}</>; nothing absorbed"
+            ))
+        );
+        return Ok(());
+    }
+
+    let subject = |sha: &str| {
+        worktree
+            .run_command(&["log", "-1", "--format=%h %s", sha])
+            .map(|s| s.trim().to_string())
+            .unwrap_or_else(|_| sha[..7].to_string())
+    };
+    let summary = plan
+        .iter()
+        .rev()
+        .map(|(&position, hunks)| {
+            let mut paths: Vec<&str> = hunks.iter().map(|&(f, _)| files[f].path.as_str()).collect();
+            paths.dedup();
+            format!(
+                "{}\n{}",
+                subject(&candidates[position]),
+                paths
+                    .iter()
+                    .map(|p| format!("    {p}"))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    let total: usize = plan.values().map(Vec::len).sum();
+    eprintln!(
+        "{}",
+        info_message(cformat!(
+            "Absorbing {total} hunk{} into {} commit{}:",
+            if total == 1 { "" } else { "s" },
+            plan.len(),
+            if plan.len() == 1 { "" } else { "s" }
+        ))
+    );
+    eprintln!("{}", format_with_gutter(&summary, None));
+    if dry_run {
+        return Ok(());
+    }
+
+    let before = worktree
+        .run_command(&["rev-parse", "HEAD"])?
+        .trim()
+        .to_string();
+    let root = worktree.root()?;
+    let scratch = worktree.git_dir()?.join("worktrunk-absorb-index");
+    let mut applied: Vec<Vec<bool>> = files.iter().map(|f| vec![false; f.hunks.len()]).collect();
+    let result = (|| -> anyhow::Result<()> {
+        git_in_index(&root, &scratch, &["read-tree", "HEAD"], None)?;
+        for (&position, hunks) in plan.iter().rev() {
+            let mut by_file: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+            for &(f, h) in hunks {
+                by_file.entry(f).or_default().push(h);
+            }
+            let patch: String = by_file
+                .iter()
+                .map(|(&f, selected)| render_patch(&files[f], &applied[f], selected))
+                .collect();
+            git_in_index(
+                &root,
+                &scratch,
+                &["apply", "--cached", "--unidiff-zero", "-"],
+                Some(&patch),
+            )?;
+            let fixup = format!("--fixup={}", candidates[position]);
+            let mut args = vec!["commit", "--quiet", fixup.as_str()];
+            args.extend(repo.signing_args());
+            git_in_index(&root, &scratch, &args, None)
+                .map_err(|e| repo.signing_error("absorb", e))?;
+            for (f, selected) in by_file {
+                for h in selected {
+                    applied[f][h] = true;
+                }
+            }
+        }
+        Ok(())
+    })();
+    let _ = std::fs::remove_file(&scratch);
+    result?;
+
+    eprintln!(
+        "{}",
+        success_message(cformat!(
+            "Created {} fixup commit{} on <bold>{branch}</>",
+            plan.len(),
+            if plan.len() == 1 { "" } else { "s" }
+        ))
+    );
+    if worktree.has_staged_changes()? {
+        eprintln!(
+            "{}",
+            hint_message("Hunks that didn't map to a single branch commit are still staged")
+        );
+    }
+
+    let after = worktree
+        .run_command(&["rev-parse", "HEAD"])?
+        .trim()
+        .to_string();
+    record(
+        &repo,
+        Operation::RefMoved {
+            branch: branch.clone(),
+            before,
+            after: after.clone(),
+            operation: "absorb".into(),
+            snapshot: None,
+        },
+    );
+
+    if rebase {
+        eprintln!(
+            "{}",
+            progress_message(cformat!("Squashing fixups into <bold>{branch}</>..."))
+        );
+        // Anything left staged or unstaged is stashed around the rebase
+        let snapshot = auto_snapshot(&worktree, "rebase");
+        run_rebase(
+            &repo,
+            &["--interactive", "--autosquash", "--autostash"],
+            &merge_base,
+        )?;
+        if let Ok(squashed) = worktree.run_command(&["rev-parse", "HEAD"]) {
+            record(
+                &repo,
+                Operation::RefMoved {
+                    branch,
+                    before: after,
+                    after: squashed.trim().to_string(),
+                    operation: "rebase".into(),
+                    snapshot,
+                },
+            );
+        }
+        eprintln!("{}", success_message("Squashed fixups into their commits"));
+    } else {
+        eprintln!(
+            "{}",
+            hint_message(cformat!(
+                "To fold them in, run <bright-black>git rebase -i --autosquash {}</>",
+                &merge_base[..7]
+            ))
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DIFF: &str = "\
+diff --git a/src/lib.rs b/src/lib.rs
+index 1111111..2222222 100644
+--- a/src/lib.rs
++++ b/src/lib.rs
+@@ -2 +2 @@ fn a() {
+-    old();
++    new();
+@@ -5,0 +6,2 @@ fn b() {
++    one();
++    two();
+@@ -9,2 +11 @@ fn c() {
+-    x();
+-    y();
++    xy();
+diff --git a/new.txt b/new.txt
+new file mode 100644
+index 0000000..3333333
+--- /dev/null
++++ b/new.txt
+@@ -0,0 +1 @@
++hello
+";
+
+    #[test]
+    fn test_parse_diff() {
+        let files = parse_diff(DIFF);
+        assert_eq!(files.len(), 1);
+        let file = &files[0];
+        assert_eq!(file.path, "src/lib.rs");
+        let ranges: Vec<_> = file
+            .hunks
+            .iter()
+            .map(|h| (h.old_start, h.old_count, h.new_count))
+            .collect();
+        assert_eq!(ranges, vec![(2, 1, 1), (5, 0, 2), (9, 2, 1)]);
+        assert_eq!(
+            file.hunks[2].lines,
+            vec!["-    x();", "-    y();", "+    xy();"]
+        );
+    }
+
+    #[test]
+    fn test_render_patch_shifts_after_applied_hunks() {
+        let file = &parse_diff(DIFF)[0];
+        // Nothing applied yet: positions are HEAD's
+        assert_eq!(
+            render_patch(file, &[false, false, false], &[1, 2]),
+            "\
+diff --git a/src/lib.rs b/src/lib.rs
+--- a/src/lib.rs
++++ b/src/lib.rs
+@@ -5,0 +6,2 @@
++    one();
++    two();
+@@ -9,2 +11,1 @@
+-    x();
+-    y();
++    xy();
+"
+        );
+        // The two-line addition already landed: the last hunk moves down by two
+        let patch = render_patch(file, &[false, true, false], &[2]);
+        assert!(patch.contains("@@ -11,2 +11,1 @@"), "{patch}");
+    }
+}
@@ -0,0 +1,228 @@
+//! `wt activity` — recent commits across worktrees, for standups.
+//!
+//! Each worktree's branch is logged over the period (only the commits it has
+//! beyond the default branch, so shared history isn't counted on every
+//! branch), grouped by author day with line totals. Worktrees with no commits
+//! in the period are listed at the end, which is where abandoned branches
+//! show up.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+
+use color_print::cformat;
+use worktrunk::git::Repository;
+use worktrunk::styling::{eprintln, info_message, paint, theme};
+
+/// A commit with its line counts.
+#[derive(Debug, Clone, PartialEq)]
+struct Commit {
+    hash: String,
+    date: String,
+    subject: String,
+    added: usize,
+    deleted: usize,
+}
+
+/// Expand shorthand like `1w` or `3d` into a date git understands.
+///
+/// Anything else (`yesterday`, `2024-05-01`, `2.weeks`) is passed to git
+/// unchanged.
+fn expand_since(since: &str) -> String {
+    let Some((unit_at, _)) = since.char_indices().last() else {
+        return since.to_string();
+    };
+    let (count, unit) = since.split_at(unit_at);
+    let unit = match unit {
+        "h" => "hour",
+        "d" => "day",
+        "w" => "week",
+        "m" => "month",
+        "y" => "year",
+        _ => return since.to_string(),
+    };
+    match count.parse::<u32>() {
+        Ok(count) => format!("{count} {unit}{} ago", if count == 1 { "" } else { "s" }),
+        Err(_) => since.to_string(),
+    }
+}
+
+/// Parse `git log --numstat` output in the format `handle_activity` asks for.
+fn parse_log(output: &str) -> Vec<Commit> {
+    output
+        .split('\x1e')
+        .filter_map(|record| {
+            let mut lines = record.trim_matches('\n').lines();
+            let mut header = lines.next()?.split('\x1f');
+            let mut commit = Commit {
+                hash: header.next()?.to_string(),
+                date: header.next()?.to_string(),
+                subject: header.next().unwrap_or_default().to_string(),
+                added: 0,
+                deleted: 0,
+            };
+            for line in lines {
+                let mut fields = line.split('\t');
+                // Binary files show `-` for both counts
+                commit.added += fields.next().and_then(|n| n.parse().ok()).unwrap_or(0);
+                commit.deleted += fields.next().and_then(|n| n.parse().ok()).unwrap_or(0);
+            }
+            Some(commit)
+        })
+        .collect()
+}
+
+fn commits_label(count: usize) -> String {
+    format!("{count} commit{}", if count == 1 { "" } else { "s" })
+}
+
+/// `+added -deleted` in the theme's diff colors.
+fn line_counts(added: usize, deleted: usize) -> String {
+    let theme = theme();
+    format!(
+        "{} {}",
+        paint(theme.addition, &format!("+{added}")),
+        paint(theme.deletion, &format!("-{deleted}"))
+    )
+}
+
+/// Handle `wt activity`.
+pub(crate) fn handle_activity(since: &str) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let since = expand_since(since);
+    let default_branch = repo.default_branch();
+    let since_arg = format!("--since={since}");
+
+    // Buffered, so output taller than the terminal can go through the pager
+    let mut output = String::new();
+    let mut quiet = Vec::new();
+    let mut total = (0, 0, 0);
+    for worktree in repo.list_worktrees()? {
+        let Some(branch) = worktree.branch else {
+            continue;
+        };
+        let mut args = vec![
+            "log",
+            "--no-merges",
+            since_arg.as_str(),
+            "--date=short",
+            "--format=%x1e%h%x1f%ad%x1f%s",
+            "--numstat",
+            branch.as_str(),
+        ];
+        let exclude = default_branch
+            .as_deref()
+            .filter(|default| *default != branch)
+            .map(|default| format!("^refs/heads/{default}"));
+        if let Some(exclude) = &exclude {
+            args.push(exclude);
+        }
+        let commits = parse_log(&repo.run_command(&args)?);
+        if commits.is_empty() {
+            quiet.push(branch);
+            continue;
+        }
+
+        let mut by_day: BTreeMap<&str, Vec<&Commit>> = BTreeMap::new();
+        for commit in &commits {
+            by_day.entry(&commit.date).or_default().push(commit);
+        }
+        let added: usize = commits.iter().map(|c| c.added).sum();
+        let deleted: usize = commits.iter().map(|c| c.deleted).sum();
+        writeln!(
+            output,
+            "{}",
+            cformat!(
+                "<bold>{branch}</>  {}  {}",
+                commits_label(commits.len()),
+                line_counts(added, deleted)
+            )
+        )?;
+        // Newest day first, like `git log`
+        for (day, commits) in by_day.iter().rev() {
+            let added: usize = commits.iter().map(|c| c.added).sum();
+            let deleted: usize = commits.iter().map(|c| c.deleted).sum();
+            writeln!(
+                output,
+                "{}",
+                cformat!(
+                    "  <dim>{day}</>  {}  {}",
+                    commits_label(commits.len()),
+                    line_counts(added, deleted)
+                )
+            )?;
+            for commit in commits {
+                writeln!(
+                    output,
+                    "{}",
+                    cformat!("    <dim>{}</> {}", commit.hash, commit.subject)
+                )?;
+            }
+        }
+        writeln!(output)?;
+        total.0 += commits.len();
+        total.1 += added;
+        total.2 += deleted;
+    }
+
+    if total.0 > 0 {
+        writeln!(
+            output,
+            "{}",
+            cformat!(
+                "<bold>Total</>  {}  {}",
+                commits_label(total.0),
+                line_counts(total.1, total.2)
+            )
+        )?;
+    }
+    crate::pager::page_stdout(&output)?;
+    if !quiet.is_empty() {
+        eprintln!(
+            "{}",
+            info_message(cformat!(
+                "No commits since {since}: <bold>{}</>",
+                quiet.join(", ")
+            ))
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_since() {
+        assert_eq!(expand_since("1w"), "1 week ago");
+        assert_eq!(expand_since("3d"), "3 days ago");
+        assert_eq!(expand_since("12h"), "12 hours ago");
+        assert_eq!(expand_since("yesterday"), "yesterday");
+        assert_eq!(expand_since("2024-05-01"), "2024-05-01");
+        assert_eq!(expand_since("xw"), "xw");
+    }
+
+    #[test]
+    fn test_parse_log() {
+        let output = "\x1eabc1234\x1f2026-10-15\x1fAdd parser\n\n10\t2\tsrc/parse.rs\n-\t-\tlogo.png\n\x1edef5678\x1f2026-10-14\x1fEmpty\n";
+        assert_eq!(
+            parse_log(output),
+            vec![
+                Commit {
+                    hash: "abc1234".into(),
+                    date: "2026-10-15".into(),
+                    subject: "Add parser".into(),
+                    added: 10,
+                    deleted: 2,
+                },
+                Commit {
+                    hash: "def5678".into(),
+                    date: "2026-10-14".into(),
+                    subject: "Empty".into(),
+                    added: 0,
+                    deleted: 0,
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,239 @@
+//! `wt agent` — a fresh worktree for one coding-agent task.
+//!
+//! Creates a branch from the default branch (named after the task unless
+//! `--branch` is given), runs the `[agent] command` in its worktree with the
+//! task prompt, and when the agent exits offers to finish the way a person
+//! would: `wt merge`. An agent that changed nothing leaves the worktree in
+//! place with a hint to remove it.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use anyhow::Context;
+use color_print::cformat;
+use worktrunk::config::{UserConfig, expand_template};
+use worktrunk::git::{GitError, Repository, base_path};
+use worktrunk::shell_exec::Cmd;
+use worktrunk::styling::{
+    eprintln, format_bash_with_gutter, format_with_gutter, hint_message, info_message,
+    progress_message,
+};
+
+use super::command_executor::{CommandContext, build_hook_context};
+use super::handle_switch::{SwitchOptions, handle_switch};
+use super::issue::slugify;
+use super::merge::{MergeOptions, handle_merge};
+use crate::output::prompt::{Prompt, assume_yes, can_prompt};
+
+/// Options for the agent command
+pub struct AgentOptions<'a> {
+    /// Task prompt; asked for when omitted
+    pub task: Option<&'a str>,
+    /// Branch name; derived from the task when omitted
+    pub branch: Option<&'a str>,
+    pub yes: bool,
+    pub verify: bool,
+}
+
+/// Branch for a task without `--branch`: `agent/<slug of the task>`.
+fn task_branch(task: &str) -> String {
+    let slug = slugify(task);
+    if slug.is_empty() {
+        "agent/task".to_string()
+    } else {
+        format!("agent/{slug}")
+    }
+}
+
+/// Handle the agent command.
+pub fn handle_agent(
+    opts: AgentOptions<'_>,
+    config: &mut UserConfig,
+    binary_name: &str,
+) -> anyhow::Result<()> {
+    let AgentOptions {
+        task,
+        branch,
+        yes,
+        verify,
+    } = opts;
+
+    let task = match task {
+        Some(task) => task.to_string(),
+        None => Prompt::new("Task for the agent:")
+            .hint(cformat!(
+                "Pass the task as an argument: <bright-black>wt agent \"Fix the flaky login test\"</>"
+            ))
+            .text(None)?,
+    };
+    let task = task.as_str();
+
+    let repo = Repository::current().context("Failed to start agent")?;
+    let project_id = repo.project_identifier().ok();
+    let Some(template) = config
+        .agent(project_id.as_deref())
+        .and_then(|agent| agent.command)
+    else {
+        return Err(GitError::Other {
+            message: cformat!(
+                "No agent command configured; set <bold>[agent] command</> in the user config, e.g. <bright-black>command = \"claude {{{{ task }}}}\"</>"
+            ),
+        }
+        .into());
+    };
+    let default_branch = repo.default_branch().ok_or_else(|| GitError::Other {
+        message: "Cannot determine the default branch to start the agent from".into(),
+    })?;
+    let branch = branch.map_or_else(|| task_branch(task), str::to_string);
+    if repo.branch(&branch).exists()? {
+        return Err(GitError::Other {
+            message: cformat!(
+                "Branch <bold>{branch}</> already exists; pass <bright-black>--branch</> to pick another name"
+            ),
+        }
+        .into());
+    }
+
+    // Create: same as `wt switch --create <branch> --base <default branch>`
+    handle_switch(
+        SwitchOptions {
+            branch: &branch,
+            create: true,
+            base: Some(&default_branch),
+            sparse: None,
+            orphan: false,
+            devcontainer: false,
+            detach: false,
+            execute: None,
+            execute_args: &[],
+            open: None,
+            yes,
+            clobber: false,
+            change_dir: true,
+            verify,
+        },
+        config,
+        binary_name,
+    )?;
+    let worktree_path =
+        repo.worktree_for_branch(&branch)?
+            .ok_or_else(|| GitError::WorktreeMissing {
+                branch: branch.clone(),
+            })?;
+
+    let ctx = CommandContext::new(&repo, config, Some(&branch), &worktree_path, yes);
+    let template_vars = build_hook_context(&ctx, &[("task", task)]);
+    let vars: HashMap<&str, &str> = template_vars
+        .iter()
+        .map(|(k, v)| (k.as_str(), v.as_str()))
+        .collect();
+    let command = expand_template(&template, &vars, true, &repo, "agent command")?;
+
+    eprintln!("{}", progress_message("Running agent..."));
+    eprintln!("{}", format_bash_with_gutter(&command));
+    // The agent gets the terminal: it's usually interactive
+    Cmd::shell(&command)
+        .current_dir(&worktree_path)
+        .forward_signals()
+        .stream()
+        .context("Agent command failed")?;
+
+    offer_merge(&repo, &branch, &default_branch, &worktree_path, yes, verify)
+}
+
+/// After the agent exits: merge its work if there is any and the user agrees.
+fn offer_merge(
+    repo: &Repository,
+    branch: &str,
+    default_branch: &str,
+    worktree_path: &Path,
+    yes: bool,
+    verify: bool,
+) -> anyhow::Result<()> {
+    let worktree = repo.worktree_at(worktree_path);
+    let commits = repo.count_commits(default_branch, branch)?;
+    if commits == 0 && !worktree.is_dirty()? {
+        eprintln!(
+            "{}",
+            info_message(cformat!("Agent made no changes on <bold>{branch}</>"))
+        );
+        eprintln!(
+            "{}",
+            hint_message(cformat!(
+                "To clean up, run <bright-black>wt remove {branch}</>"
+            ))
+        );
+        return Ok(());
+    }
+
+    // `wt merge` acts on the current worktree; with -C that can't be moved
+    let merge_hint = || {
+        eprintln!(
+            "{}",
+            hint_message(cformat!(
+                "To merge, run <bright-black>wt merge</> in the <bold>{branch}</> worktree"
+            ))
+        );
+    };
+    if base_path().as_path() != Path::new(".") {
+        merge_hint();
+        return Ok(());
+    }
+    let merge = if yes || assume_yes() {
+        true
+    } else if can_prompt() {
+        let options = [
+            cformat!("Merge into <bold>{default_branch}</>"),
+            "Keep the worktree".to_string(),
+            "Show the changes".to_string(),
+        ];
+        loop {
+            match Prompt::new("Agent finished. Next?").select(&options, 1)? {
+                0 => break true,
+                1 => break false,
+                _ => {
+                    let range = format!("{default_branch}..{branch}");
+                    if let Ok(log) = repo.run_command(&["log", "--oneline", &range]) {
+                        eprintln!("{}", format_with_gutter(log.trim_end(), None));
+                    }
+                    if let Ok(status) = worktree.run_command(&["status", "--short"]) {
+                        eprintln!("{}", format_with_gutter(status.trim_end(), None));
+                    }
+                }
+            }
+        }
+    } else {
+        false
+    };
+    if !merge {
+        merge_hint();
+        return Ok(());
+    }
+
+    std::env::set_current_dir(worktree_path)
+        .with_context(|| format!("Failed to enter {}", worktree_path.display()))?;
+    handle_merge(MergeOptions {
+        target: Some(default_branch),
+        squash: None,
+        commit: None,
+        rebase: None,
+        remove: None,
+        verify: Some(verify),
+        yes,
+        stage: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_task_branch() {
+        assert_eq!(
+            task_branch("Fix the login redirect loop"),
+            "agent/fix-the-login-redirect-loop"
+        );
+        assert_eq!(task_branch("???"), "agent/task");
+    }
+}
@@ -0,0 +1,205 @@
+//! `wt auth` — forge tokens.
+//!
+//! Storage and lookup live in [`worktrunk::forge`]; this module is the
+//! command surface, plus the scope checks behind `wt auth status`.
+
+use std::io::{IsTerminal, Read};
+
+use color_print::cformat;
+use serde::Deserialize;
+use strum::IntoEnumIterator;
+use worktrunk::forge::{Forge, TokenSource, erase_token, forge_cli, store_token, token};
+use worktrunk::git::GitError;
+use worktrunk::styling::{eprintln, hint_message, info_message, success_message, warning_message};
+
+pub(crate) fn handle_auth_login(forge: Forge) -> anyhow::Result<()> {
+    let mut stdin = std::io::stdin();
+    if stdin.is_terminal() {
+        return Err(GitError::Other {
+            message: cformat!(
+                "Pipe the token on stdin, e.g. <bright-black>wt auth login {forge} << token.txt</>"
+            ),
+        }
+        .into());
+    }
+    let mut input = String::new();
+    stdin.read_to_string(&mut input)?;
+    store_token(forge, &input)?;
+    eprintln!(
+        "{}",
+        success_message(cformat!("Stored <bold>{forge}</> token"))
+    );
+    Ok(())
+}
+
+pub(crate) fn handle_auth_logout(forge: Forge) -> anyhow::Result<()> {
+    if erase_token(forge)? {
+        eprintln!(
+            "{}",
+            success_message(cformat!("Removed <bold>{forge}</> token"))
+        );
+    } else {
+        eprintln!(
+            "{}",
+            info_message(cformat!("No stored <bold>{forge}</> token"))
+        );
+    }
+    Ok(())
+}
+
+pub(crate) fn handle_auth_status() -> anyhow::Result<()> {
+    for forge in Forge::iter() {
+        let source = match token(forge) {
+            Some((_, TokenSource::Env(var))) => format!("token from {var}"),
+            Some((_, TokenSource::Stored)) => "stored token".to_string(),
+            None if matches!(forge, Forge::Bitbucket | Forge::Jira) => {
+                eprintln!("{}", info_message(cformat!("<bold>{forge}</>: no token")));
+                continue;
+            }
+            None => format!("{} login", cli_name(forge)),
+        };
+        match scopes(forge) {
+            Some(Ok(scopes)) => match missing_scope(forge, &scopes) {
+                None => eprintln!(
+                    "{}",
+                    success_message(cformat!(
+                        "<bold>{forge}</>: {source}; scopes: {}",
+                        display_scopes(&scopes)
+                    ))
+                ),
+                Some(scope) => {
+                    eprintln!(
+                        "{}",
+                        warning_message(cformat!(
+                            "<bold>{forge}</>: {source}; scopes: {}",
+                            display_scopes(&scopes)
+                        ))
+                    );
+                    eprintln!(
+                        "{}",
+                        hint_message(cformat!(
+                            "PRs and CI need the <bright-black>{scope}</> scope"
+                        ))
+                    );
+                }
+            },
+            Some(Err(err)) => {
+                eprintln!(
+                    "{}",
+                    warning_message(cformat!("<bold>{forge}</>: {source}; can't authenticate"))
+                );
+                eprintln!("{}", hint_message(err));
+            }
+            // No API to ask (Bitbucket, Jira)
+            None => eprintln!("{}", info_message(cformat!("<bold>{forge}</>: {source}"))),
+        }
+    }
+    Ok(())
+}
+
+fn cli_name(forge: Forge) -> &'static str {
+    match forge {
+        Forge::GitHub => "gh",
+        Forge::GitLab => "glab",
+        Forge::Bitbucket => "bitbucket",
+        Forge::Jira => "jira",
+    }
+}
+
+/// Scopes of the token the forge's CLI will use, or why they couldn't be
+/// read. `None` when there's no CLI to ask.
+fn scopes(forge: Forge) -> Option<Result<Vec<String>, String>> {
+    #[derive(Deserialize)]
+    struct GitLabToken {
+        scopes: Vec<String>,
+    }
+
+    let cmd = match forge {
+        Forge::GitHub => forge_cli("gh").args(["api", "--include", "user"]),
+        Forge::GitLab => forge_cli("glab").args(["api", "personal_access_tokens/self"]),
+        Forge::Bitbucket | Forge::Jira => return None,
+    };
+    let output = match cmd.run() {
+        Ok(output) => output,
+        Err(e) => return Some(Err(format!("{} unavailable: {e}", cli_name(forge)))),
+    };
+    if !output.status.success() {
+        return Some(Err(String::from_utf8_lossy(&output.stderr)
+            .trim()
+            .to_string()));
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    Some(match forge {
+        Forge::GitHub => Ok(parse_github_scopes(&stdout)),
+        _ => serde_json::from_str::<GitLabToken>(&stdout)
+            .map(|token| token.scopes)
+            .map_err(|e| format!("Unexpected glab response: {e}")),
+    })
+}
+
+/// Scopes from the `X-OAuth-Scopes` response header. Fine-grained GitHub
+/// tokens don't send one.
+fn parse_github_scopes(response: &str) -> Vec<String> {
+    response
+        .lines()
+        .take_while(|line| !line.trim().is_empty())
+        .find_map(|line| {
+            let (name, value) = line.split_once(':')?;
+            name.eq_ignore_ascii_case("x-oauth-scopes").then_some(value)
+        })
+        .map(|value| {
+            value
+                .split(',')
+                .map(|scope| scope.trim().to_string())
+                .filter(|scope| !scope.is_empty())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// The scope PRs and CI need, if the token lacks it. Tokens without
+/// reported scopes (fine-grained) get the benefit of the doubt.
+fn missing_scope(forge: Forge, scopes: &[String]) -> Option<&'static str> {
+    let needed = match forge {
+        Forge::GitHub => "repo",
+        Forge::GitLab => "api",
+        Forge::Bitbucket | Forge::Jira => return None,
+    };
+    (!scopes.is_empty() && !scopes.iter().any(|scope| scope == needed)).then_some(needed)
+}
+
+fn display_scopes(scopes: &[String]) -> String {
+    if scopes.is_empty() {
+        "not reported (fine-grained)".to_string()
+    } else {
+        scopes.join(", ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_github_scopes() {
+        let response =
+            "HTTP/2.0 200 OK\r\nX-Oauth-Scopes: repo, workflow\r\n\r\n{\"x-oauth-scopes: gist\"}";
+        assert_eq!(parse_github_scopes(response), ["repo", "workflow"]);
+        assert!(parse_github_scopes("HTTP/2.0 200 OK\r\n\r\n{}").is_empty());
+    }
+
+    #[test]
+    fn test_missing_scope() {
+        let scopes = |s: &[&str]| s.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            missing_scope(Forge::GitHub, &scopes(&["gist"])),
+            Some("repo")
+        );
+        assert_eq!(missing_scope(Forge::GitHub, &scopes(&["repo"])), None);
+        assert_eq!(missing_scope(Forge::GitHub, &[]), None);
+        assert_eq!(
+            missing_scope(Forge::GitLab, &scopes(&["read_api"])),
+            Some("api")
+        );
+    }
+}
@@ -0,0 +1,376 @@
+//! Backporting commits to other branches.
+//!
+//! `wt backport` cherry-picks commits onto a target branch in its worktree,
+//! runs the pre-merge hooks there, and optionally opens a PR/MR. A conflict
+//! stops the cherry-pick where it is, for the user to finish.
+//!
+//! `wt merge main,release/1.2` merges into the first target as usual, then
+//! cherry-picks the merged commits onto each of the others. Targets are
+//! independent: a conflict on one is aborted and reported, and the rest still
+//! go ahead.
+
+use std::path::PathBuf;
+
+use anyhow::Context;
+use color_print::cformat;
+use worktrunk::HookType;
+use worktrunk::config::UserConfig;
+use worktrunk::git::{GitCommandError, GitError, Repository, WorkingTree};
+use worktrunk::path::{format_path_for_display, sanitize_for_filename};
+use worktrunk::state::Operation;
+use worktrunk::styling::{eprintln, info_message, progress_message, success_message};
+
+use super::command_approval::approve_hooks;
+use super::command_executor::CommandContext;
+use super::forge::{PrContent, create_pr, detect_platform, push_with_upstream};
+use super::handle_switch::{SwitchOptions, handle_switch};
+use super::hooks::{HookFailureStrategy, execute_hook};
+use super::undo::record;
+
+/// Options for the backport command
+pub struct BackportOptions<'a> {
+    /// Commits, `a..b` ranges or branches, in the order given
+    pub commits: &'a [String],
+    pub to: &'a str,
+    /// Backport on a new branch and open a PR/MR against `to`
+    pub pr: bool,
+    pub yes: bool,
+    pub verify: bool,
+}
+
+/// Outcome of cherry-picking onto one target.
+pub(crate) enum Backport {
+    /// Every commit landed (or was already there).
+    Applied { before: String, after: String },
+    /// `commit` didn't apply cleanly; the target was left as it was.
+    Conflict { commit: String, git_output: String },
+}
+
+/// Run `git cherry-pick -x` for `commits` in `worktree`.
+///
+/// Commits whose changes are already there are skipped. On a conflict the
+/// cherry-pick is left in progress.
+fn cherry_pick(worktree: &WorkingTree<'_>, commits: &[String]) -> anyhow::Result<()> {
+    let mut args = vec!["cherry-pick", "-x"];
+    args.extend(commits.iter().map(String::as_str));
+    let mut result = worktree.run_command(&args);
+    // An already-applied commit stops the cherry-pick with nothing to commit
+    while result.is_err() && cherry_pick_head(worktree).is_some() && nothing_staged(worktree) {
+        result = worktree.run_command(&["cherry-pick", "--skip"]);
+    }
+    result.map(|_| ())
+}
+
+/// Whether the index matches HEAD and has no conflicts.
+fn nothing_staged(worktree: &WorkingTree<'_>) -> bool {
+    worktree
+        .run_command(&["ls-files", "--unmerged"])
+        .is_ok_and(|unmerged| unmerged.trim().is_empty())
+        && worktree
+            .run_command(&["diff", "--cached", "--quiet"])
+            .is_ok()
+}
+
+/// The commit a stopped cherry-pick in `worktree` is on, if one is in progress.
+fn cherry_pick_head(worktree: &WorkingTree<'_>) -> Option<String> {
+    worktree
+        .run_command(&["rev-parse", "--verify", "--quiet", "CHERRY_PICK_HEAD"])
+        .ok()
+        .map(|sha| sha.trim().to_string())
+        .filter(|sha| !sha.is_empty())
+}
+
+fn record_backport(repo: &Repository, branch: &str, before: &str, after: &str) {
+    if before != after {
+        record(
+            repo,
+            Operation::RefMoved {
+                branch: branch.to_string(),
+                before: before.to_string(),
+                after: after.to_string(),
+                operation: "backport".into(),
+                snapshot: None,
+            },
+        );
+    }
+}
+
+/// Cherry-pick `commits` (oldest first) onto `target` with `-x`, so each
+/// records the commit it came from.
+///
+/// Runs in the target's worktree when it has one — callers check it's clean
+/// first — and otherwise in a temporary worktree under the git directory,
+/// removed afterwards. A conflict is aborted, leaving the target unchanged.
+pub(crate) fn cherry_pick_onto(
+    repo: &Repository,
+    target: &str,
+    commits: &[String],
+) -> anyhow::Result<Backport> {
+    let before = repo
+        .run_command(&["rev-parse", &format!("refs/heads/{target}")])?
+        .trim()
+        .to_string();
+    let (path, temporary) = match repo.worktree_for_branch(target)? {
+        Some(path) => (path, false),
+        None => (add_temporary_worktree(repo, target)?, true),
+    };
+    let path_str = path.to_string_lossy().to_string();
+    let worktree = repo.worktree_at(&path);
+
+    let outcome = match cherry_pick(&worktree, commits) {
+        Ok(()) => {
+            let after = worktree
+                .run_command(&["rev-parse", "HEAD"])?
+                .trim()
+                .to_string();
+            record_backport(repo, target, &before, &after);
+            Ok(Backport::Applied { before, after })
+        }
+        Err(e) => match cherry_pick_head(&worktree) {
+            Some(commit) => {
+                let _ = worktree.run_command(&["cherry-pick", "--abort"]);
+                Ok(Backport::Conflict {
+                    commit,
+                    git_output: e.to_string(),
+                })
+            }
+            None => Err(e),
+        },
+    };
+
+    if temporary {
+        let _ = repo.run_command(&["worktree", "remove", "--force", &path_str]);
+    }
+    outcome
+}
+
+/// Check out `target` in a scratch worktree for the length of a backport.
+fn add_temporary_worktree(repo: &Repository, target: &str) -> anyhow::Result<PathBuf> {
+    let path = repo
+        .git_common_dir()
+        .join("wt-backport")
+        .join(sanitize_for_filename(target));
+    // Left over from an interrupted backport
+    if path.exists() {
+        let _ = repo.run_command(&["worktree", "remove", "--force", &path.to_string_lossy()]);
+        if !worktrunk::dry_run::skip(&format!("remove {}", format_path_for_display(&path))) {
+            let _ = std::fs::remove_dir_all(&path);
+        }
+        let _ = repo.run_command(&["worktree", "prune"]);
+    }
+    repo.run_command(&[
+        "worktree",
+        "add",
+        "--quiet",
+        &path.to_string_lossy(),
+        target,
+    ])?;
+    Ok(path)
+}
+
+/// Expand commits, `a..b` ranges and branches to commit SHAs, oldest first
+/// within each argument and without repeats.
+///
+/// A branch stands for its commits that aren't on the default branch.
+fn resolve_commits(repo: &Repository, args: &[String]) -> anyhow::Result<Vec<String>> {
+    let mut commits: Vec<String> = Vec::new();
+    for arg in args {
+        let resolved: Vec<String> = if arg.contains("..") {
+            repo.run_command(&["rev-list", "--reverse", "--no-merges", arg])?
+                .lines()
+                .map(String::from)
+                .collect()
+        } else if repo.branch(arg).exists_locally()? {
+            let default_branch = repo.default_branch().ok_or_else(|| GitError::Other {
+                message: "Cannot determine the default branch to find the branch's commits".into(),
+            })?;
+            let own = repo
+                .run_command(&[
+                    "rev-list",
+                    "--reverse",
+                    "--no-merges",
+                    &format!("{default_branch}..{arg}"),
+                ])?
+                .lines()
+                .map(String::from)
+                .collect::<Vec<_>>();
+            if own.is_empty() {
+                return Err(GitError::Other {
+                    message: cformat!(
+                        "<bold>{arg}</> has no commits that aren't on <bold>{default_branch}</>; name the commits instead"
+                    ),
+                }
+                .into());
+            }
+            own
+        } else {
+            let sha = repo
+                .run_command(&[
+                    "rev-parse",
+                    "--verify",
+                    "--quiet",
+                    &format!("{arg}^{{commit}}"),
+                ])
+                .map_err(|_| GitError::ReferenceNotFound {
+                    reference: arg.clone(),
+                })?;
+            vec![sha.trim().to_string()]
+        };
+        for commit in resolved {
+            if !commits.contains(&commit) {
+                commits.push(commit);
+            }
+        }
+    }
+    Ok(commits)
+}
+
+/// Branch a `--pr` backport is made on: `backport/<to>/<source>`, where the
+/// source is the first argument when it's a branch, else its short SHA.
+fn backport_branch(repo: &Repository, to: &str, args: &[String], commits: &[String]) -> String {
+    let source = args
+        .first()
+        .filter(|arg| !arg.contains("..") && repo.branch(arg).exists_locally().unwrap_or(false))
+        .cloned()
+        .unwrap_or_else(|| commits[0].chars().take(7).collect());
+    format!("backport/{to}/{source}")
+}
+
+/// Handle `wt backport`.
+pub fn handle_backport(
+    opts: BackportOptions<'_>,
+    config: &mut UserConfig,
+    binary_name: &str,
+) -> anyhow::Result<()> {
+    let BackportOptions {
+        commits: args,
+        to,
+        pr,
+        yes,
+        verify,
+    } = opts;
+
+    let repo = Repository::current().context("Failed to backport")?;
+    let to = repo.require_target_branch(Some(to))?;
+    let commits = resolve_commits(&repo, args)?;
+    if commits.is_empty() {
+        eprintln!("{}", info_message("No commits to backport"));
+        return Ok(());
+    }
+    let branch = if pr {
+        backport_branch(&repo, &to, args, &commits)
+    } else {
+        to.clone()
+    };
+
+    // Create or reuse the worktree, as `wt switch` would
+    handle_switch(
+        SwitchOptions {
+            branch: &branch,
+            create: !repo.branch(&branch).exists()?,
+            base: pr.then_some(to.as_str()),
+            sparse: None,
+            orphan: false,
+            devcontainer: false,
+            detach: false,
+            execute: None,
+            execute_args: &[],
+            open: None,
+            yes,
+            clobber: false,
+            change_dir: true,
+            verify,
+        },
+        config,
+        binary_name,
+    )?;
+    let worktree_path =
+        repo.worktree_for_branch(&branch)?
+            .ok_or_else(|| GitError::WorktreeMissing {
+                branch: branch.clone(),
+            })?;
+    let worktree = repo.worktree_at(&worktree_path);
+    worktree.ensure_clean("backport", Some(&branch), false)?;
+
+    // Approve pre-merge hooks before anything changes
+    let ctx = CommandContext::new(&repo, config, Some(&branch), &worktree_path, yes);
+    let run_hooks = verify && approve_hooks(&ctx, &[HookType::PreMerge])?;
+
+    let count = commits.len();
+    let commit_text = if count == 1 { "commit" } else { "commits" };
+    eprintln!(
+        "{}",
+        progress_message(cformat!(
+            "Cherry-picking {count} {commit_text} onto <bold>{branch}</>..."
+        ))
+    );
+    let before = worktree
+        .run_command(&["rev-parse", "HEAD"])?
+        .trim()
+        .to_string();
+    if let Err(e) = cherry_pick(&worktree, &commits) {
+        let Some(commit) = cherry_pick_head(&worktree) else {
+            return Err(e);
+        };
+        return Err(GitError::CherryPickConflict {
+            target_branch: branch,
+            commit,
+            git_output: e.to_string(),
+            source: GitCommandError::find(&e).cloned(),
+        }
+        .into());
+    }
+    let after = worktree
+        .run_command(&["rev-parse", "HEAD"])?
+        .trim()
+        .to_string();
+    record_backport(&repo, &branch, &before, &after);
+    if before == after {
+        eprintln!(
+            "{}",
+            info_message(cformat!("<bold>{branch}</> already has these changes"))
+        );
+        return Ok(());
+    }
+    let applied = repo.count_commits(&before, &after)?;
+    let applied_text = if applied == 1 { "commit" } else { "commits" };
+    eprintln!(
+        "{}",
+        success_message(cformat!(
+            "Backported to <bold>{branch}</> <bright-black>({applied} {applied_text})</>"
+        ))
+    );
+
+    if run_hooks {
+        execute_hook(
+            &ctx,
+            HookType::PreMerge,
+            &[("target", to.as_str())],
+            HookFailureStrategy::FailFast,
+            None,
+            crate::output::post_hook_display_path(&worktree_path),
+        )?;
+    }
+
+    if pr {
+        let platform = detect_platform(&repo)?;
+        let project_id = repo.project_identifier().ok();
+        let push_config = config.push(project_id.as_deref()).unwrap_or_default();
+        push_with_upstream(&repo, &worktree_path, &branch, &push_config, yes)?;
+        let url = create_pr(
+            platform,
+            &worktree_path,
+            &branch,
+            PrContent {
+                base: Some(&to),
+                ..Default::default()
+            },
+        )?;
+        eprintln!(
+            "{}",
+            success_message(cformat!("Opened backport to <bold>{to}</> {url}"))
+        );
+    }
+
+    Ok(())
+}
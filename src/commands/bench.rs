@@ -0,0 +1,241 @@
+//! `wt bench` — time `wt list` internals on a synthetic repository.
+//!
+//! Hidden development aid. Builds a temporary repository with the requested
+//! number of worktrees (or uses `--repo`), then times the two phases that
+//! dominate `wt list`: data collection ([`collect`]) and column layout plus
+//! row rendering. Both run in-process, so criterion can measure functions that
+//! live in the binary: `benches/list_phases.rs` passes `--phase` and
+//! `--iterations` and reads back the total nanoseconds.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use anyhow::Context;
+use worktrunk::config::UserConfig;
+use worktrunk::git::{GitError, Repository};
+use worktrunk::shell_exec::Cmd;
+use worktrunk::styling::format_heading;
+
+use super::list::collect::{TaskKind, collect};
+use super::list::columns::WidthLimits;
+use super::list::layout::calculate_layout_with_width;
+use super::list::model::ListData;
+use crate::cli::BenchPhase;
+
+/// Terminal width for layout, so timings don't depend on the terminal.
+const LAYOUT_WIDTH: usize = 160;
+
+/// Files in the synthetic repository's initial commit.
+const SYNTHETIC_FILES: usize = 50;
+
+/// Handle `wt bench`.
+pub(crate) fn handle_bench(
+    worktrees: usize,
+    iterations: usize,
+    repo: Option<PathBuf>,
+    phase: Option<BenchPhase>,
+) -> anyhow::Result<()> {
+    let iterations = iterations.max(1);
+    let synthetic;
+    let repo_path = match repo {
+        Some(path) => path,
+        None => {
+            synthetic = SyntheticRepo::create(worktrees)?;
+            synthetic.main_path()
+        }
+    };
+
+    // Same tasks as a plain `wt list`; CI and ticket lookups would time the network
+    let skip_tasks: HashSet<TaskKind> = [
+        TaskKind::BranchDiff,
+        TaskKind::CiStatus,
+        TaskKind::WorkingTreeConflicts,
+        TaskKind::DiskUsage,
+        TaskKind::Health,
+        TaskKind::Ticket,
+    ]
+    .into_iter()
+    .collect();
+    let config = UserConfig::default();
+    let run_collect = || -> anyhow::Result<ListData> {
+        // A fresh repository each run, so cached git results don't carry over
+        let repo = Repository::at(&repo_path)?;
+        collect(
+            &repo,
+            false,
+            false,
+            &skip_tasks,
+            None,
+            false,
+            false,
+            None,
+            &config,
+            None,
+            false,
+        )?
+        .ok_or_else(|| {
+            GitError::Other {
+                message: "No worktrees to benchmark".into(),
+            }
+            .into()
+        })
+    };
+    let run_layout = |data: &ListData| {
+        let layout = calculate_layout_with_width(
+            &data.items,
+            &skip_tasks,
+            None,
+            LAYOUT_WIDTH,
+            &WidthLimits::new(),
+            &data.main_worktree_path,
+            None,
+        );
+        for item in &data.items {
+            std::hint::black_box(layout.format_list_item_line(item));
+        }
+    };
+
+    // Untimed first run warms the filesystem cache and supplies the layout input
+    let data = run_collect()?;
+    let mut collect_times = Vec::new();
+    if !matches!(phase, Some(BenchPhase::Layout)) {
+        for _ in 0..iterations {
+            let started = Instant::now();
+            std::hint::black_box(run_collect()?);
+            collect_times.push(started.elapsed());
+        }
+    }
+    let mut layout_times = Vec::new();
+    if !matches!(phase, Some(BenchPhase::Collect)) {
+        for _ in 0..iterations {
+            let started = Instant::now();
+            run_layout(&data);
+            layout_times.push(started.elapsed());
+        }
+    }
+
+    if let Some(phase) = phase {
+        let times = match phase {
+            BenchPhase::Collect => &collect_times,
+            BenchPhase::Layout => &layout_times,
+        };
+        println!("{}", times.iter().sum::<Duration>().as_nanos());
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        format_heading(
+            "WT LIST PHASES",
+            Some(&format!("{} items, {iterations} runs", data.items.len()))
+        )
+    );
+    for (name, times) in [
+        ("collect", &mut collect_times),
+        ("layout", &mut layout_times),
+    ] {
+        times.sort_unstable();
+        println!(
+            "{name:7}  median {:>9}  fastest {:>9}  slowest {:>9}",
+            format_duration(times[times.len() / 2]),
+            format_duration(times[0]),
+            format_duration(times[times.len() - 1]),
+        );
+    }
+    Ok(())
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{:.2}ms", duration.as_secs_f64() * 1000.0)
+}
+
+/// A temporary repository with a main worktree and `worktrees` linked ones,
+/// removed on drop.
+///
+/// Each linked worktree has a branch one commit ahead of `main`; every other
+/// one also has an uncommitted change, so status and diff columns have data.
+struct SyntheticRepo {
+    root: PathBuf,
+}
+
+impl SyntheticRepo {
+    fn create(worktrees: usize) -> anyhow::Result<Self> {
+        let root = std::env::temp_dir().join(format!("wt-bench-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        let repo = Self { root };
+        let main = repo.main_path();
+        std::fs::create_dir_all(&main).context("Failed to create benchmark repository")?;
+
+        git(&main, &["init", "--quiet", "--initial-branch=main"])?;
+        for i in 0..SYNTHETIC_FILES {
+            std::fs::write(
+                main.join(format!("file_{i}.rs")),
+                format!("pub fn function_{i}() -> usize {{ {i} }}\n"),
+            )?;
+        }
+        git(&main, &["add", "."])?;
+        git(&main, &["commit", "--quiet", "-m", "Initial commit"])?;
+
+        for i in 0..worktrees {
+            let branch = format!("feature-{i}");
+            let path = repo.root.join(format!("repo.{branch}"));
+            let path_str = path.to_string_lossy();
+            git(
+                &main,
+                &["worktree", "add", "--quiet", "-b", &branch, &path_str],
+            )?;
+            std::fs::write(path.join(format!("{branch}.rs")), "pub fn feature() {}\n")?;
+            git(&path, &["add", "."])?;
+            git(
+                &path,
+                &["commit", "--quiet", "-m", &format!("Add {branch}")],
+            )?;
+            if i % 2 == 0 {
+                std::fs::write(
+                    path.join("file_0.rs"),
+                    "pub fn function_0() -> usize { 1 }\n",
+                )?;
+            }
+        }
+        Ok(repo)
+    }
+
+    fn main_path(&self) -> PathBuf {
+        self.root.join("repo")
+    }
+}
+
+impl Drop for SyntheticRepo {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.root);
+    }
+}
+
+/// Run git in `dir` with a fixed identity and no signing.
+fn git(dir: &Path, args: &[&str]) -> anyhow::Result<()> {
+    let output = Cmd::new("git")
+        .args([
+            "-c",
+            "user.name=wt bench",
+            "-c",
+            "user.email=bench@example.com",
+            "-c",
+            "commit.gpgsign=false",
+        ])
+        .args(args.iter().copied())
+        .current_dir(dir)
+        .run()
+        .with_context(|| format!("Failed to run git {}", args.join(" ")))?;
+    if !output.status.success() {
+        return Err(GitError::Other {
+            message: format!(
+                "git {} failed: {}",
+                args.join(" "),
+                String::from_utf8_lossy(&output.stderr).trim()
+            ),
+        }
+        .into());
+    }
+    Ok(())
+}
@@ -0,0 +1,289 @@
+//! `wt export` and `wt import` — move a branch between machines without
+//! pushing it.
+//!
+//! An export is a plain git bundle holding the branch, plus two refs under
+//! `refs/wt-export/`: `metadata`, a commit whose message is worktrunk's JSON
+//! metadata for the branch (its note and stack base), and `changes`, a
+//! snapshot commit of uncommitted work when the branch's worktree has any
+//! (see [`super::snapshot`]). The bundle leaves out commits already on the
+//! remote's copy of the base branch, so the importing machine needs to have
+//! fetched it.
+
+use std::path::Path;
+
+use anyhow::Context;
+use color_print::cformat;
+use serde::{Deserialize, Serialize};
+use worktrunk::config::UserConfig;
+use worktrunk::git::{GitError, Repository};
+use worktrunk::path::format_path_for_display;
+use worktrunk::state::StateStore;
+use worktrunk::styling::{eprintln, hint_message, success_message, warning_message};
+
+use super::handle_switch::{SwitchOptions, handle_switch};
+use super::snapshot::{apply_snapshot, snapshot_commit};
+
+const METADATA_REF: &str = "refs/wt-export/metadata";
+const CHANGES_REF: &str = "refs/wt-export/changes";
+
+/// Worktrunk's metadata for an exported branch.
+#[derive(Debug, Serialize, Deserialize)]
+struct ExportMetadata {
+    version: u32,
+    branch: String,
+    /// Note shown in `wt list`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    note: Option<String>,
+    /// Branch this one is stacked on
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    base: Option<String>,
+}
+
+/// Delete the temporary `refs/wt-export/` refs.
+fn clear_export_refs(repo: &Repository) {
+    for name in [METADATA_REF, CHANGES_REF] {
+        // Ref may not exist (no uncommitted changes); that's fine
+        let _ = repo.run_command(&["update-ref", "-d", name]);
+    }
+}
+
+/// Bundle `branch` with the metadata and changes commits, leaving out
+/// commits reachable from `exclude`.
+fn write_bundle(
+    repo: &Repository,
+    file: &Path,
+    branch: &str,
+    metadata_commit: &str,
+    changes: Option<&str>,
+    exclude: Option<&str>,
+) -> anyhow::Result<()> {
+    repo.run_command(&["update-ref", METADATA_REF, metadata_commit])?;
+    let file = file.to_string_lossy();
+    let head_ref = format!("refs/heads/{branch}");
+    let mut args = vec!["bundle", "create", file.as_ref(), &head_ref];
+    args.push(METADATA_REF);
+    if let Some(changes) = changes {
+        repo.run_command(&["update-ref", CHANGES_REF, changes])?;
+        args.push(CHANGES_REF);
+    }
+    let exclude = exclude.map(|upstream| format!("^{upstream}"));
+    args.extend(exclude.as_deref());
+    repo.run_command(&args)?;
+    Ok(())
+}
+
+/// Handle `wt export [branch] --bundle <file>`.
+pub(crate) fn handle_export(branch: Option<&str>, file: &Path) -> anyhow::Result<()> {
+    let repo = Repository::current().context("Failed to export branch")?;
+    let branch = match branch {
+        Some(branch) => repo.resolve_worktree_name(branch)?,
+        None => repo.require_current_branch("export")?,
+    };
+    if !repo.branch(&branch).exists_locally()? {
+        return Err(GitError::BranchNotFound {
+            branch,
+            show_create_hint: false,
+        }
+        .into());
+    }
+    let file = std::path::absolute(file)?;
+
+    let state = StateStore::new(repo.git_common_dir()).load()?;
+    let metadata = ExportMetadata {
+        version: 1,
+        branch: branch.clone(),
+        note: state.notes.get(&branch).cloned(),
+        base: state.stacks.get(&branch).cloned(),
+    };
+    let changes = match repo.worktree_for_branch(&branch)? {
+        Some(path) => snapshot_commit(&repo.worktree_at(&path), &branch, Some("Exported"))?,
+        None => None,
+    };
+
+    // Commits the remote's copy of the base already has stay out of the
+    // bundle, unless it has the branch's tip too: git then drops the branch
+    let base = match &metadata.base {
+        Some(base) => base.clone(),
+        None => repo.default_branch().unwrap_or_default(),
+    };
+    let prerequisite = repo
+        .primary_remote()
+        .ok()
+        .map(|remote| format!("refs/remotes/{remote}/{base}"))
+        .filter(|upstream| {
+            repo.run_command(&["rev-parse", "--verify", "--quiet", upstream])
+                .is_ok()
+        })
+        .filter(|upstream| !repo.is_ancestor(&branch, upstream).unwrap_or(false));
+
+    let json = serde_json::to_string(&metadata)?;
+    let metadata_commit = repo
+        .run_command(&[
+            "commit-tree",
+            &format!("refs/heads/{branch}^{{tree}}"),
+            "-m",
+            &json,
+        ])?
+        .trim()
+        .to_string();
+
+    let created = write_bundle(
+        &repo,
+        &file,
+        &branch,
+        &metadata_commit,
+        changes.as_deref(),
+        prerequisite.as_deref(),
+    );
+    clear_export_refs(&repo);
+    created.context("Failed to create bundle")?;
+
+    let with_changes = if changes.is_some() {
+        " with its uncommitted changes"
+    } else {
+        ""
+    };
+    eprintln!(
+        "{}",
+        success_message(cformat!(
+            "Exported <bold>{branch}</>{with_changes} to <bold>{}</>",
+            format_path_for_display(&file)
+        ))
+    );
+    if let Some(upstream) = &prerequisite {
+        let upstream = upstream.trim_start_matches("refs/remotes/");
+        eprintln!(
+            "{}",
+            hint_message(cformat!(
+                "Leaves out commits on <bright-black>{upstream}</>; fetch it before importing"
+            ))
+        );
+    }
+    Ok(())
+}
+
+/// Handle `wt import <file>`.
+pub(crate) fn handle_import(
+    file: &Path,
+    yes: bool,
+    verify: bool,
+    config: &mut UserConfig,
+    binary_name: &str,
+) -> anyhow::Result<()> {
+    let repo = Repository::current().context("Failed to import bundle")?;
+    let file = std::path::absolute(file)?;
+    let file_str = file.to_string_lossy();
+    let display = format_path_for_display(&file);
+
+    repo.run_command(&["bundle", "verify", &file_str])
+        .with_context(|| format!("Can't import {display}"))?;
+    let heads = repo.run_command(&["bundle", "list-heads", &file_str])?;
+    let refs: Vec<&str> = heads
+        .lines()
+        .filter_map(|line| line.split_once(' ').map(|(_, name)| name))
+        .collect();
+    let branch = match refs
+        .iter()
+        .filter_map(|name| name.strip_prefix("refs/heads/"))
+        .collect::<Vec<_>>()
+        .as_slice()
+    {
+        [branch] if refs.contains(&METADATA_REF) => branch.to_string(),
+        _ => {
+            return Err(GitError::Other {
+                message: cformat!(
+                    "{display} wasn't created by <bright-black>wt export</>; use <bright-black>git fetch</> to read it"
+                ),
+            }
+            .into());
+        }
+    };
+    if repo.branch(&branch).exists_locally()? {
+        return Err(GitError::BranchAlreadyExists { branch }.into());
+    }
+
+    let head_refspec = format!("refs/heads/{branch}:refs/heads/{branch}");
+    let metadata_refspec = format!("{METADATA_REF}:{METADATA_REF}");
+    let changes_refspec = format!("{CHANGES_REF}:{CHANGES_REF}");
+    let has_changes = refs.contains(&CHANGES_REF);
+    let mut args = vec!["fetch", "--quiet", file_str.as_ref(), &head_refspec];
+    args.push(&metadata_refspec);
+    if has_changes {
+        args.push(&changes_refspec);
+    }
+    let fetched = repo.run_command(&args);
+    let metadata = fetched.and_then(|_| {
+        let message = repo.run_command(&["log", "-1", "--format=%B", METADATA_REF])?;
+        serde_json::from_str::<ExportMetadata>(message.trim())
+            .context("Bundle metadata is malformed")
+    });
+    let changes = has_changes
+        .then(|| repo.run_command(&["rev-parse", CHANGES_REF]))
+        .transpose()
+        .map(|sha| sha.map(|sha| sha.trim().to_string()));
+    clear_export_refs(&repo);
+    let (metadata, changes) = (metadata?, changes?);
+
+    if metadata.note.is_some() || metadata.base.is_some() {
+        let base_exists = match &metadata.base {
+            Some(base) => repo.branch(base).exists_locally()?,
+            None => false,
+        };
+        StateStore::new(repo.git_common_dir()).update(|state| {
+            if let Some(note) = &metadata.note {
+                state.notes.insert(branch.clone(), note.clone());
+            }
+            if let Some(base) = &metadata.base
+                && base_exists
+            {
+                state.stacks.insert(branch.clone(), base.clone());
+            }
+        })?;
+        if let Some(base) = &metadata.base
+            && !base_exists
+        {
+            eprintln!(
+                "{}",
+                warning_message(cformat!(
+                    "<bold>{branch}</> was stacked on <bold>{base}</>, which doesn't exist here; not restoring the stack"
+                ))
+            );
+        }
+    }
+
+    handle_switch(
+        SwitchOptions {
+            branch: &branch,
+            create: false,
+            base: None,
+            sparse: None,
+            orphan: false,
+            devcontainer: false,
+            detach: false,
+            execute: None,
+            execute_args: &[],
+            open: None,
+            yes,
+            clobber: false,
+            change_dir: true,
+            verify,
+        },
+        config,
+        binary_name,
+    )?;
+
+    if let Some(changes) = changes {
+        let path = repo
+            .worktree_for_branch(&branch)?
+            .ok_or_else(|| GitError::WorktreeMissing {
+                branch: branch.clone(),
+            })?;
+        apply_snapshot(&repo.worktree_at(&path), &changes)
+            .context("Failed to restore uncommitted changes")?;
+        eprintln!(
+            "{}",
+            success_message(cformat!("Restored <bold>{branch}</>'s uncommitted changes"))
+        );
+    }
+    Ok(())
+}
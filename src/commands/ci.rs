@@ -0,0 +1,412 @@
+//! `wt ci` — individual CI jobs for a branch.
+//!
+//! `wt list` collapses CI into one glyph; this lists the jobs behind it. On
+//! GitHub, jobs come from the workflow runs for the branch's head commit
+//! (`gh run list` + `gh run view`); on GitLab, from the latest pipeline for
+//! the branch (`glab ci get`). `--logs` streams one job's log through the
+//! same CLI, and `wt ci rerun` re-runs what failed.
+
+use std::path::Path;
+
+use anstyle::{Color, Style};
+use anyhow::Context;
+use chrono::DateTime;
+use color_print::cformat;
+use serde::Deserialize;
+use worktrunk::git::{GitError, Repository};
+use worktrunk::styling::{eprintln, info_message, success_message};
+
+use super::forge::{detect_platform, forge_cmd, run_forge};
+use super::list::ci_status::{CiPlatform, CiStatus};
+
+/// One CI job (GitHub Actions job or GitLab pipeline job).
+#[derive(Debug, Clone, PartialEq)]
+struct CiJob {
+    /// Job ID, as accepted by `gh run view --job` / `glab ci trace`
+    id: u64,
+    name: String,
+    status: CiStatus,
+    duration_secs: Option<u64>,
+    url: String,
+}
+
+pub(crate) fn handle_ci(branch: Option<&str>, logs: Option<&str>) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let branch = match branch {
+        Some(branch) => branch.to_string(),
+        None => repo.require_current_branch("show CI for")?,
+    };
+    let platform = detect_platform(&repo)?;
+    let root = repo.current_worktree().root()?;
+    let jobs = fetch_jobs(&repo, platform, &root, &branch)?;
+
+    if let Some(job) = logs {
+        let job = find_job(&jobs, job, &branch)?;
+        let cmd = match platform {
+            CiPlatform::GitHub => {
+                forge_cmd(platform).args(["run", "view", "--log", "--job", &job.id.to_string()])
+            }
+            CiPlatform::GitLab => forge_cmd(platform).args(["ci", "trace", &job.id.to_string()]),
+        };
+        return cmd
+            .current_dir(&root)
+            .stream()
+            .with_context(|| format!("Failed to fetch the log for {}", job.name));
+    }
+
+    if jobs.is_empty() {
+        eprintln!(
+            "{}",
+            info_message(cformat!("No CI jobs for <bold>{branch}</>"))
+        );
+        return Ok(());
+    }
+    let name_width = jobs.iter().map(|job| job.name.chars().count()).max();
+    for job in &jobs {
+        let style = Style::new().fg_color(Some(Color::Ansi(job.status.color())));
+        let status: &'static str = job.status.into();
+        let duration = job.duration_secs.map(format_duration).unwrap_or_default();
+        println!(
+            "{style}●{style:#} {:<name_width$}  {:<8} {:>7}  {}",
+            job.name,
+            status,
+            duration,
+            job.url,
+            name_width = name_width.unwrap_or_default(),
+        );
+    }
+    Ok(())
+}
+
+/// Re-run the failed CI for the branch's latest commit: failed jobs of failed
+/// GitHub workflow runs, or a retry of the failed GitLab pipeline.
+pub(crate) fn handle_ci_rerun(branch: Option<&str>) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let branch = match branch {
+        Some(branch) => branch.to_string(),
+        None => repo.require_current_branch("re-run CI for")?,
+    };
+    let platform = detect_platform(&repo)?;
+    let root = repo.current_worktree().root()?;
+
+    let rerun = match platform {
+        CiPlatform::GitHub => {
+            let failed: Vec<_> = github_runs_at_head(&repo, &root, &branch)?
+                .into_iter()
+                .filter(|run| {
+                    let conclusion = run.conclusion.as_deref().unwrap_or_default();
+                    github_job_status(&run.status, conclusion) == CiStatus::Failed
+                })
+                .collect();
+            for run in &failed {
+                let id = run.database_id.to_string();
+                run_forge(
+                    platform,
+                    forge_cmd(platform)
+                        .args(["run", "rerun", &id, "--failed"])
+                        .current_dir(&root),
+                    &format!("re-run workflow run {id}"),
+                )?;
+            }
+            failed.len()
+        }
+        CiPlatform::GitLab => {
+            let pipeline = gitlab_pipeline(&root, &branch)?;
+            if matches!(pipeline.status.as_str(), "failed" | "canceled") {
+                run_forge(
+                    platform,
+                    forge_cmd(platform)
+                        .args([
+                            "api",
+                            "--method",
+                            "POST",
+                            &format!("projects/:id/pipelines/{}/retry", pipeline.id),
+                        ])
+                        .current_dir(&root),
+                    &format!("retry pipeline {}", pipeline.id),
+                )?;
+                1
+            } else {
+                0
+            }
+        }
+    };
+
+    if rerun == 0 {
+        eprintln!(
+            "{}",
+            info_message(cformat!("No failed CI to re-run for <bold>{branch}</>"))
+        );
+    } else {
+        let what = match platform {
+            CiPlatform::GitHub if rerun == 1 => "1 workflow run".to_string(),
+            CiPlatform::GitHub => format!("{rerun} workflow runs"),
+            CiPlatform::GitLab => "the pipeline".to_string(),
+        };
+        eprintln!(
+            "{}",
+            success_message(cformat!(
+                "Re-running failed jobs in {what} for <bold>{branch}</>; follow with <bright-black>wt ci {branch}</>"
+            ))
+        );
+    }
+    Ok(())
+}
+
+/// Find a job by ID or exact name. Several jobs with the same name (matrix
+/// builds, reruns) prefer the first failed one.
+fn find_job<'a>(jobs: &'a [CiJob], query: &str, branch: &str) -> anyhow::Result<&'a CiJob> {
+    if let Ok(id) = query.parse::<u64>()
+        && let Some(job) = jobs.iter().find(|job| job.id == id)
+    {
+        return Ok(job);
+    }
+    let mut matching = jobs.iter().filter(|job| job.name == query);
+    let first = matching.clone().next();
+    matching
+        .find(|job| job.status == CiStatus::Failed)
+        .or(first)
+        .ok_or_else(|| {
+            GitError::Other {
+                message: cformat!(
+                    "No CI job <bold>{query}</> for <bold>{branch}</>; list jobs with <bright-black>wt ci {branch}</>"
+                ),
+            }
+            .into()
+        })
+}
+
+fn fetch_jobs(
+    repo: &Repository,
+    platform: CiPlatform,
+    root: &Path,
+    branch: &str,
+) -> anyhow::Result<Vec<CiJob>> {
+    match platform {
+        CiPlatform::GitHub => fetch_github_jobs(repo, root, branch),
+        CiPlatform::GitLab => fetch_gitlab_jobs(root, branch),
+    }
+}
+
+/// A GitHub Actions workflow run.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GitHubRun {
+    database_id: u64,
+    head_sha: String,
+    status: String,
+    conclusion: Option<String>,
+}
+
+/// Workflow runs for the branch's head commit.
+fn github_runs_at_head(
+    repo: &Repository,
+    root: &Path,
+    branch: &str,
+) -> anyhow::Result<Vec<GitHubRun>> {
+    let head = repo.run_command(&["rev-parse", branch])?.trim().to_string();
+    let output = run_forge(
+        CiPlatform::GitHub,
+        forge_cmd(CiPlatform::GitHub)
+            .args([
+                "run",
+                "list",
+                "--branch",
+                branch,
+                "--limit",
+                "20",
+                "--json",
+                "databaseId,headSha,status,conclusion",
+            ])
+            .current_dir(root),
+        &format!("list workflow runs for {branch}"),
+    )?;
+    let runs: Vec<GitHubRun> =
+        serde_json::from_slice(&output.stdout).context("Failed to parse gh response")?;
+    Ok(runs
+        .into_iter()
+        .filter(|run| run.head_sha == head)
+        .collect())
+}
+
+fn fetch_github_jobs(repo: &Repository, root: &Path, branch: &str) -> anyhow::Result<Vec<CiJob>> {
+    #[derive(Deserialize)]
+    struct RunJobs {
+        jobs: Vec<Job>,
+    }
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Job {
+        database_id: u64,
+        name: String,
+        status: String,
+        conclusion: Option<String>,
+        started_at: Option<String>,
+        completed_at: Option<String>,
+        url: String,
+    }
+
+    let mut jobs = Vec::new();
+    for run in github_runs_at_head(repo, root, branch)? {
+        let output = run_forge(
+            CiPlatform::GitHub,
+            forge_cmd(CiPlatform::GitHub)
+                .args([
+                    "run",
+                    "view",
+                    &run.database_id.to_string(),
+                    "--json",
+                    "jobs",
+                ])
+                .current_dir(root),
+            &format!("fetch jobs for run {}", run.database_id),
+        )?;
+        let run_jobs: RunJobs =
+            serde_json::from_slice(&output.stdout).context("Failed to parse gh response")?;
+        jobs.extend(run_jobs.jobs.into_iter().map(|job| {
+            // Unfinished jobs report a zero timestamp rather than null
+            let parse = |ts: &Option<String>| {
+                ts.as_deref()
+                    .and_then(|ts| DateTime::parse_from_rfc3339(ts).ok())
+            };
+            let duration_secs = match (parse(&job.started_at), parse(&job.completed_at)) {
+                (Some(start), Some(end)) if end > start => {
+                    u64::try_from((end - start).num_seconds()).ok()
+                }
+                _ => None,
+            };
+            CiJob {
+                id: job.database_id,
+                status: github_job_status(
+                    &job.status,
+                    job.conclusion.as_deref().unwrap_or_default(),
+                ),
+                name: job.name,
+                duration_secs,
+                url: job.url,
+            }
+        }));
+    }
+    Ok(jobs)
+}
+
+/// The latest GitLab pipeline for a branch.
+#[derive(Deserialize)]
+struct GitLabPipeline {
+    id: u64,
+    status: String,
+    #[serde(default)]
+    jobs: Vec<GitLabJob>,
+}
+
+#[derive(Deserialize)]
+struct GitLabJob {
+    id: u64,
+    name: String,
+    status: String,
+    duration: Option<f64>,
+    web_url: String,
+}
+
+fn gitlab_pipeline(root: &Path, branch: &str) -> anyhow::Result<GitLabPipeline> {
+    let output = run_forge(
+        CiPlatform::GitLab,
+        forge_cmd(CiPlatform::GitLab)
+            .args(["ci", "get", "--branch", branch, "--output", "json"])
+            .current_dir(root),
+        &format!("fetch the pipeline for {branch}"),
+    )?;
+    serde_json::from_slice(&output.stdout).context("Failed to parse glab response")
+}
+
+fn fetch_gitlab_jobs(root: &Path, branch: &str) -> anyhow::Result<Vec<CiJob>> {
+    Ok(gitlab_pipeline(root, branch)?
+        .jobs
+        .into_iter()
+        .map(|job| CiJob {
+            id: job.id,
+            status: gitlab_job_status(&job.status),
+            name: job.name,
+            duration_secs: job.duration.map(|secs| secs.round() as u64),
+            url: job.web_url,
+        })
+        .collect())
+}
+
+/// Status of a GitHub job or workflow run.
+fn github_job_status(status: &str, conclusion: &str) -> CiStatus {
+    if status != "completed" {
+        return CiStatus::Running;
+    }
+    match conclusion {
+        "success" => CiStatus::Passed,
+        "skipped" | "neutral" => CiStatus::NoCI,
+        _ => CiStatus::Failed,
+    }
+}
+
+fn gitlab_job_status(status: &str) -> CiStatus {
+    match status {
+        "success" => CiStatus::Passed,
+        "failed" | "canceled" => CiStatus::Failed,
+        "skipped" | "manual" => CiStatus::NoCI,
+        _ => CiStatus::Running,
+    }
+}
+
+/// `42s`, `3m 05s`, `1h 02m`
+fn format_duration(secs: u64) -> String {
+    match secs {
+        0..60 => format!("{secs}s"),
+        60..3600 => format!("{}m {:02}s", secs / 60, secs % 60),
+        _ => format!("{}h {:02}m", secs / 3600, secs % 3600 / 60),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn job(id: u64, name: &str, status: CiStatus) -> CiJob {
+        CiJob {
+            id,
+            name: name.into(),
+            status,
+            duration_secs: None,
+            url: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_job_status_mapping() {
+        assert_eq!(github_job_status("in_progress", ""), CiStatus::Running);
+        assert_eq!(github_job_status("completed", "success"), CiStatus::Passed);
+        assert_eq!(github_job_status("completed", "skipped"), CiStatus::NoCI);
+        assert_eq!(
+            github_job_status("completed", "timed_out"),
+            CiStatus::Failed
+        );
+        assert_eq!(gitlab_job_status("canceled"), CiStatus::Failed);
+        assert_eq!(gitlab_job_status("pending"), CiStatus::Running);
+    }
+
+    #[test]
+    fn test_find_job_prefers_failed_and_accepts_ids() {
+        let jobs = [
+            job(1, "test", CiStatus::Passed),
+            job(2, "test", CiStatus::Failed),
+            job(3, "lint", CiStatus::Passed),
+        ];
+        assert_eq!(find_job(&jobs, "test", "main").unwrap().id, 2);
+        assert_eq!(find_job(&jobs, "1", "main").unwrap().id, 1);
+        assert_eq!(find_job(&jobs, "lint", "main").unwrap().id, 3);
+        assert!(find_job(&jobs, "build", "main").is_err());
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(42), "42s");
+        assert_eq!(format_duration(185), "3m 05s");
+        assert_eq!(format_duration(3720), "1h 02m");
+    }
+}
@@ -17,7 +17,6 @@
 //! This ensures approval happens exactly once at the command entry point,
 //! eliminating the need to thread `auto_trust` through execution layers.
 
-use std::io::{self, IsTerminal, Write};
 use std::path::Path;
 
 use anyhow::Context;
@@ -25,12 +24,12 @@ use color_print::cformat;
 use worktrunk::config::UserConfig;
 use worktrunk::git::{GitError, HookType};
 use worktrunk::styling::{
-    INFO_SYMBOL, WARNING_SYMBOL, eprint, eprintln, format_bash_with_gutter, hint_message,
-    prompt_message, stderr, warning_message,
+    INFO_SYMBOL, eprintln, format_bash_with_gutter, hint_message, warning_message,
 };
 
 use super::hook_filter::{HookSource, ParsedFilter};
 use super::project_config::{HookCommand, collect_commands_for_hooks};
+use crate::output::prompt::{Prompt, assume_yes, can_prompt};
 
 /// Batch approval helper used when multiple commands are queued for execution.
 /// Returns `Ok(true)` when execution may continue, `Ok(false)` when the user
@@ -118,9 +117,9 @@ fn prompt_for_batch_approval(commands: &[&HookCommand], project_id: &str) -> any
 
     eprintln!(
         "{}",
-        cformat!(
-            "{WARNING_SYMBOL} <yellow><bold>{project_name}</> needs approval to execute <bold>{count}</> command{plural}:</>"
-        )
+        warning_message(cformat!(
+            "<bold>{project_name}</> needs approval to execute <bold>{count}</> command{plural}:"
+        ))
     );
 
     for cmd in commands {
@@ -139,24 +138,11 @@ fn prompt_for_batch_approval(commands: &[&HookCommand], project_id: &str) -> any
     // Check if stdin is a TTY before attempting to prompt
     // This happens AFTER showing the commands so they appear in CI/CD logs
     // even when the prompt cannot be displayed (fail-fast principle)
-    if !io::stdin().is_terminal() {
+    if !can_prompt() {
         return Err(GitError::NotInteractive.into());
     }
 
-    // Blank line before prompt for visual separation
-    worktrunk::styling::eprintln!();
-    stderr().flush()?;
-
-    eprint!(
-        "{} ",
-        prompt_message(cformat!("Allow and remember? <bold>[y/N]</>"))
-    );
-    stderr().flush()?;
-
-    let mut response = String::new();
-    io::stdin().read_line(&mut response)?;
-
-    Ok(response.trim().eq_ignore_ascii_case("y"))
+    Prompt::new("Allow and remember?").confirm(false)
 }
 
 /// Collect project commands for hooks and request batch approval.
@@ -231,5 +217,11 @@ pub fn approve_hooks_filtered(
     }
 
     let project_id = ctx.repo.project_identifier()?;
-    approve_command_batch(&commands, &project_id, ctx.config, ctx.yes, false)
+    approve_command_batch(
+        &commands,
+        &project_id,
+        ctx.config,
+        ctx.yes || assume_yes(),
+        false,
+    )
 }
@@ -1,9 +1,13 @@
 use std::collections::HashMap;
 use std::path::Path;
+use std::time::Duration;
 use worktrunk::HookType;
-use worktrunk::config::{Command, CommandConfig, UserConfig, expand_template};
+use worktrunk::config::{
+    Command, CommandConfig, UserConfig, branch_vars, evaluate_condition, expand_template,
+};
 use worktrunk::git::Repository;
 use worktrunk::path::to_posix_path;
+use worktrunk::state::{PORT_BLOCK_SIZE, StateStore};
 
 use super::hook_filter::HookSource;
 
@@ -12,6 +16,8 @@ pub struct PreparedCommand {
     pub name: Option<String>,
     pub expanded: String,
     pub context_json: String,
+    /// The command's own time limit, if set
+    pub timeout: Option<Duration>,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -133,6 +139,25 @@ pub fn build_hook_context(
         }
     }
 
+    if let Some(branch) = ctx.branch {
+        let jira = ctx
+            .config
+            .jira(ctx.project_id().as_deref())
+            .unwrap_or_default();
+        for (key, value) in branch_vars(branch, jira.key_pattern()) {
+            map.insert(key.into(), value);
+        }
+    }
+
+    // Stable per-branch port block, allocated on first use
+    if let Some(branch) = ctx.branch
+        && let Ok(Some(port)) =
+            StateStore::new(ctx.repo.git_common_dir()).update(|state| state.port_block(branch))
+    {
+        map.insert("port".into(), port.to_string());
+        map.insert("port_end".into(), (port + PORT_BLOCK_SIZE - 1).to_string());
+    }
+
     // Add extra vars (e.g., target branch for merge)
     for (k, v) in extra_vars {
         map.insert((*k).into(), (*v).into());
@@ -165,16 +190,51 @@ fn expand_commands(
         .collect();
 
     let mut result = Vec::new();
+    // Merge base for path-filtered commands, computed on first use
+    let mut diff_base: Option<Option<String>> = None;
 
     for cmd in commands {
         let template_name = match &cmd.name {
             Some(name) => format!("{}:{}", source, name),
             None => format!("{} {} hook", source, hook_type),
         };
-        let expanded_str = expand_template(&cmd.template, &vars, true, ctx.repo, &template_name)?;
+
+        if let Some(when) = &cmd.when
+            && !evaluate_condition(when, &vars, ctx.worktree_path, ctx.repo, &template_name)?
+        {
+            log::debug!("Skipping {template_name}: `{when}` is false");
+            continue;
+        }
+
+        // Path-filtered commands run only when matching files changed. When the
+        // diff can't be computed, they run: skipping setup silently is worse.
+        let changed = if cmd.paths.is_empty() {
+            None
+        } else {
+            let base = diff_base.get_or_insert_with(|| path_filter_base(ctx, &base_context));
+            match base
+                .as_deref()
+                .and_then(|base| changed_files(ctx, base, &cmd.paths))
+            {
+                Some(files) if files.is_empty() => {
+                    log::debug!("Skipping {template_name}: no changes match {:?}", cmd.paths);
+                    continue;
+                }
+                files => files.map(|files| files.join(" ")),
+            }
+        };
+        let mut cmd_vars = vars.clone();
+        if let Some(files) = &changed {
+            cmd_vars.insert("changed_files", files.as_str());
+        }
+        let expanded_str =
+            expand_template(&cmd.template, &cmd_vars, true, ctx.repo, &template_name)?;
 
         // Build per-command JSON with hook_type and hook_name
         let mut cmd_context = base_context.clone();
+        if let Some(files) = changed {
+            cmd_context.insert("changed_files".into(), files);
+        }
         cmd_context.insert("hook_type".into(), hook_type.to_string());
         if let Some(ref name) = cmd.name {
             cmd_context.insert("hook_name".into(), name.clone());
@@ -183,7 +243,8 @@ fn expand_commands(
             .expect("HashMap<String, String> serialization should never fail");
 
         result.push((
-            Command::with_expansion(cmd.name.clone(), cmd.template.clone(), expanded_str),
+            Command::with_expansion(cmd.name.clone(), cmd.template.clone(), expanded_str)
+                .with_timeout(cmd.timeout),
             context_json,
         ));
     }
@@ -191,6 +252,35 @@ fn expand_commands(
     Ok(result)
 }
 
+/// Merge base between the worktree's HEAD and the branch its hook compares
+/// against: the merge target, else the `--create` base, else the default branch.
+/// The worktree's own branch is skipped, as a manual `wt hook pre-merge`
+/// targets it.
+fn path_filter_base(ctx: &CommandContext<'_>, context: &HashMap<String, String>) -> Option<String> {
+    let branch = context.get("branch");
+    let base = ["target", "base", "default_branch"]
+        .into_iter()
+        .filter_map(|key| context.get(key))
+        .find(|base| Some(*base) != branch)?;
+    ctx.repo
+        .worktree_at(ctx.worktree_path)
+        .run_command(&["merge-base", base, "HEAD"])
+        .ok()
+        .map(|sha| sha.trim().to_string())
+}
+
+/// Files changed since `base` (committed or not) that match `paths`.
+fn changed_files(ctx: &CommandContext<'_>, base: &str, paths: &[String]) -> Option<Vec<String>> {
+    let mut args = vec!["diff", "--name-only", base, "--"];
+    args.extend(paths.iter().map(String::as_str));
+    let output = ctx
+        .repo
+        .worktree_at(ctx.worktree_path)
+        .run_command(&args)
+        .ok()?;
+    Some(output.lines().map(String::from).collect())
+}
+
 /// Prepare commands for execution.
 ///
 /// Expands command templates with context variables and returns prepared
@@ -219,6 +309,7 @@ pub fn prepare_commands(
             name: cmd.name,
             expanded: cmd.expanded,
             context_json,
+            timeout: cmd.timeout.map(Duration::from_secs),
         })
         .collect())
 }
@@ -135,7 +135,10 @@ impl<'a> CommitGenerator<'a> {
         let formatted_message = self.format_message_for_display(&commit_message);
         eprintln!("{}", format_with_gutter(&formatted_message, None));
 
-        wt.run_command(&["commit", "-m", &commit_message])
+        let mut commit_args = vec!["commit", "-m", commit_message.as_str()];
+        commit_args.extend(wt.repo().signing_args());
+        wt.run_command(&commit_args)
+            .map_err(|e| wt.repo().signing_error("new", e))
             .context("Failed to commit")?;
 
         let commit_hash = wt
@@ -113,6 +113,10 @@ fn create_config_file(
         return Ok(());
     }
 
+    if worktrunk::dry_run::skip(&format!("create {}", format_path_for_display(&path))) {
+        return Ok(());
+    }
+
     // Create parent directory if it doesn't exist
     if let Some(parent) = path.parent() {
         std::fs::create_dir_all(parent).context("Failed to create config directory")?;
@@ -0,0 +1,128 @@
+//! Config file editing.
+//!
+//! Opens the user or project config in `$VISUAL`/`$EDITOR` and validates it
+//! once the editor exits, so a typo shows up now rather than as a confusing
+//! failure in some later command.
+
+use anyhow::Context;
+use color_print::cformat;
+use worktrunk::config::{
+    ProjectConfig, UserConfig, find_unknown_project_keys, find_unknown_user_keys,
+};
+use worktrunk::git::{GitError, Repository};
+use worktrunk::path::format_path_for_display;
+use worktrunk::shell_exec::Cmd;
+use worktrunk::styling::{eprint, eprintln, error_message, format_with_gutter, success_message};
+
+use super::show::warn_unknown_keys;
+use super::state::require_user_config_path;
+use crate::output::prompt::{Prompt, can_prompt};
+
+/// Handle the config edit command
+pub fn handle_config_edit(project: bool) -> anyhow::Result<()> {
+    let (path, config_type) = if project {
+        let repo = Repository::current()?;
+        let path = repo.current_worktree().root()?.join(".config/wt.toml");
+        (path, "Project config")
+    } else {
+        (require_user_config_path()?, "User config")
+    };
+    if !path.exists() {
+        let create = if project {
+            "wt config create --project"
+        } else {
+            "wt config create"
+        };
+        return Err(GitError::Other {
+            message: cformat!(
+                "{config_type} not found: <bold>{}</>; to create it, run <bright-black>{create}</>",
+                format_path_for_display(&path)
+            ),
+        }
+        .into());
+    }
+
+    let editor = std::env::var("VISUAL")
+        .ok()
+        .or_else(|| std::env::var("EDITOR").ok())
+        .filter(|cmd| !cmd.trim().is_empty())
+        .unwrap_or_else(|| "vi".to_string());
+    let quoted = path.to_string_lossy();
+    let quoted = shlex::try_quote(&quoted).unwrap_or(quoted.as_ref().into());
+    let command = format!("{editor} {quoted}");
+
+    loop {
+        Cmd::shell(&command)
+            .forward_signals()
+            .stream()
+            .context("Editor failed")?;
+
+        let contents = std::fs::read_to_string(&path).context("Failed to read config file")?;
+        match validate(&contents, project) {
+            Ok(warnings) => {
+                eprint!("{warnings}");
+                eprintln!(
+                    "{}",
+                    success_message(cformat!(
+                        "{config_type} is valid: <bold>{}</>",
+                        format_path_for_display(&path)
+                    ))
+                );
+                return Ok(());
+            }
+            Err(problem) => {
+                eprintln!("{}", error_message(format!("Invalid {config_type}")));
+                eprintln!("{}", format_with_gutter(&problem, None));
+                if !can_prompt() || !Prompt::new("Re-open the editor?").confirm(true)? {
+                    return Err(GitError::Other {
+                        message: cformat!(
+                            "{config_type} was saved with errors; to fix them, run <bright-black>wt config edit{}</>",
+                            if project { " --project" } else { "" }
+                        ),
+                    }
+                    .into());
+                }
+            }
+        }
+    }
+}
+
+/// Check `contents` against the config schema: `Err` with the problem when
+/// it's invalid, else `Ok` with warnings for unknown keys (possibly empty).
+fn validate(contents: &str, project: bool) -> Result<String, String> {
+    if project {
+        toml::from_str::<ProjectConfig>(contents).map_err(|e| e.to_string())?;
+        Ok(warn_unknown_keys::<ProjectConfig>(
+            &find_unknown_project_keys(contents),
+        ))
+    } else {
+        UserConfig::load_from_str(contents).map_err(|e| e.to_string())?;
+        Ok(warn_unknown_keys::<UserConfig>(&find_unknown_user_keys(
+            contents,
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(validate("", false), Ok(String::new()));
+        assert_eq!(
+            validate("post-create = \"npm ci\"\n", true),
+            Ok(String::new())
+        );
+
+        // Syntax and schema errors
+        assert!(validate("post-create = \n", true).is_err());
+        assert!(validate("worktree-path = 3\n", false).is_err());
+        // Validation beyond the schema
+        assert!(validate("worktree-path = \"\"\n", false).is_err());
+
+        // Unknown keys are warnings, not errors
+        let warnings = validate("post-craete = \"npm ci\"\n", true).unwrap();
+        assert!(warnings.contains("post-craete"), "{warnings}");
+    }
+}
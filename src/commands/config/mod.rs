@@ -3,14 +3,18 @@
 //! Commands for managing user config, project config, state, and hints.
 
 mod create;
+mod edit;
 mod hints;
 mod show;
 mod state;
 
 // Re-export public functions
 pub use create::handle_config_create;
+pub use edit::handle_config_edit;
 pub use hints::{handle_hints_clear, handle_hints_get};
+pub(crate) use show::get_git_version;
 pub use show::handle_config_show;
+pub(crate) use state::require_user_config_path;
 pub use state::{
     handle_logs_get, handle_state_clear, handle_state_clear_all, handle_state_get,
     handle_state_set, handle_state_show,
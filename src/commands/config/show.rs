@@ -124,7 +124,7 @@ fn is_statusline_configured() -> bool {
 }
 
 /// Get the git version string (e.g., "2.47.1")
-fn get_git_version() -> Option<String> {
+pub(crate) fn get_git_version() -> Option<String> {
     let output = Cmd::new("git").arg("--version").run().ok()?;
     if !output.status.success() {
         return None;
@@ -163,6 +163,7 @@ fn check_zsh_compinit_missing() -> bool {
     let Ok(output) = Cmd::new("zsh")
         .args(["--no-globalrcs", "-ic", "(( $+functions[compdef] ))"])
         .env("ZSH_DISABLE_COMPFIX", "true")
+        .read_only()
         .run()
     else {
         return false; // Can't determine, don't warn
@@ -269,6 +270,10 @@ fn render_diagnostics(out: &mut String) -> anyhow::Result<()> {
     let platform = get_platform_for_repo(&repo, platform_override, None);
 
     match platform {
+        // Checking auth would reach the forge
+        _ if worktrunk::offline::is_offline() => {
+            writeln!(out, "{}", hint_message("CI tool check skipped (offline)"))?;
+        }
         Some(CiPlatform::GitHub) => {
             let ci_tools = CiToolsStatus::detect(None);
             render_ci_tool_status(
@@ -310,6 +315,12 @@ fn render_diagnostics(out: &mut String) -> anyhow::Result<()> {
 
     if !commit_config.is_configured() {
         writeln!(out, "{}", hint_message("Commit generation not configured"))?;
+    } else if worktrunk::offline::is_offline() {
+        writeln!(
+            out,
+            "{}",
+            hint_message("Commit generation check skipped (offline)")
+        )?;
     } else {
         let command_display = commit_config.command.as_ref().unwrap().clone();
 
@@ -970,6 +981,10 @@ pub(super) fn render_ci_tool_status(
 
 /// Render version update check (fetches from GitHub)
 fn render_version_check(out: &mut String) -> anyhow::Result<()> {
+    if worktrunk::offline::is_offline() {
+        writeln!(out, "{}", hint_message("Version check skipped (offline)"))?;
+        return Ok(());
+    }
     match fetch_latest_version() {
         Ok(latest) => {
             let current = crate::cli::version_str();
@@ -1014,6 +1029,8 @@ fn fetch_latest_version() -> anyhow::Result<String> {
         env!("CARGO_PKG_VERSION")
     );
     let output = Cmd::new("curl")
+        .network()
+        .read_only()
         .args([
             "--silent",
             "--fail",
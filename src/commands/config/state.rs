@@ -10,6 +10,7 @@ use color_print::cformat;
 use etcetera::base_strategy::{BaseStrategy, choose_base_strategy};
 use worktrunk::git::Repository;
 use worktrunk::path::{format_path_for_display, sanitize_for_filename};
+use worktrunk::state::StateStore;
 use worktrunk::styling::{
     eprintln, format_heading, format_with_gutter, info_message, println, success_message,
     warning_message,
@@ -22,6 +23,7 @@ use worktrunk::utils::get_now;
 use super::super::list::ci_status::{CachedCiStatus, CiBranchName};
 use crate::display::format_relative_time_short;
 use crate::help_pager::show_help_in_pager;
+use crate::output::Progress;
 
 // ==================== Path Helpers ====================
 
@@ -79,6 +81,12 @@ fn clear_logs(repo: &Repository) -> anyhow::Result<usize> {
     if !log_dir.exists() {
         return Ok(0);
     }
+    if worktrunk::dry_run::skip(&format!(
+        "remove logs in {}",
+        worktrunk::path::format_path_for_display(&log_dir)
+    )) {
+        return Ok(0);
+    }
 
     let mut cleared = 0;
     for entry in std::fs::read_dir(&log_dir)? {
@@ -316,10 +324,12 @@ pub fn handle_state_get(key: &str, branch: Option<String>) -> anyhow::Result<()>
             }
 
             let ci_branch = CiBranchName::from_branch_ref(&branch_name, is_remote, &repo);
+            let progress = Progress::spinner(format!("Checking CI for {branch_name}..."));
             let ci_status = PrStatus::detect(&repo, &ci_branch, &head)
                 .map_or(super::super::list::ci_status::CiStatus::NoCI, |s| {
                     s.ci_status
                 });
+            progress.finish();
             let status_str: &'static str = ci_status.into();
             println!("{status_str}");
         }
@@ -361,6 +371,16 @@ pub fn handle_state_set(key: &str, value: String, branch: Option<String>) -> any
                 "{}",
                 success_message(cformat!("Set default branch to <bold>{value}</>"))
             );
+            if let Some(project) = repo.project_default_branch()
+                && project != value
+            {
+                eprintln!(
+                    "{}",
+                    warning_message(cformat!(
+                        "The project config sets <bold>default-branch = \"{project}\"</>, which takes precedence"
+                    ))
+                );
+            }
         }
         "previous-branch" => {
             repo.set_switch_previous(Some(&value))?;
@@ -566,6 +586,21 @@ pub fn handle_state_clear_all() -> anyhow::Result<()> {
         cleared_any = true;
     }
 
+    // Clear cached worktree sizes (`wt list --du`)
+    if super::super::list::disk_usage::clear_cache(repo.git_common_dir()) > 0 {
+        cleared_any = true;
+    }
+
+    // Clear cached working tree statuses (`wt daemon` watcher)
+    if super::super::list::status_cache::clear_cache(repo.git_common_dir()) > 0 {
+        cleared_any = true;
+    }
+
+    // Clear the state store (access history, background hook runs, ...)
+    if StateStore::new(repo.git_common_dir()).clear()? {
+        cleared_any = true;
+    }
+
     // Clear all logs
     let logs_cleared = clear_logs(&repo)?;
     if logs_cleared > 0 {
@@ -695,7 +730,7 @@ fn handle_state_show_json(repo: &Repository) -> anyhow::Result<()> {
         "hints": hints
     });
 
-    println!("{}", serde_json::to_string_pretty(&output)?);
+    worktrunk::styling::print_json(&output)?;
     Ok(())
 }
 
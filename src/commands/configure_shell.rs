@@ -1,17 +1,17 @@
 use std::collections::HashSet;
 use std::fs::{self, OpenOptions};
-use std::io::{self, BufRead, BufReader, Write};
+use std::io::{BufRead, BufReader, Write};
 use std::path::{Path, PathBuf};
 
 use anstyle::Style;
 use worktrunk::path::format_path_for_display;
 use worktrunk::shell::{self, Shell};
 use worktrunk::styling::{
-    INFO_SYMBOL, SUCCESS_SYMBOL, eprint, eprintln, format_bash_with_gutter, format_toml,
+    INFO_SYMBOL, SUCCESS_SYMBOL, Symbol, eprintln, format_bash_with_gutter, format_toml,
     format_with_gutter, hint_message, prompt_message, warning_message,
 };
 
-use crate::output::prompt::{PromptResponse, prompt_yes_no_preview};
+use crate::output::prompt::{Prompt, PromptResponse, assume_yes, prompt_yes_no_preview};
 
 pub struct ConfigureResult {
     pub shell: Shell,
@@ -73,7 +73,7 @@ impl UninstallAction {
         }
     }
 
-    pub fn symbol(&self) -> &'static str {
+    pub fn symbol(&self) -> Symbol {
         match self {
             UninstallAction::Removed => SUCCESS_SYMBOL,
             UninstallAction::WouldRemove => INFO_SYMBOL,
@@ -102,7 +102,7 @@ impl ConfigAction {
     }
 
     /// Returns the appropriate symbol for this action
-    pub fn symbol(&self) -> &'static str {
+    pub fn symbol(&self) -> Symbol {
         match self {
             ConfigAction::Added | ConfigAction::Created => SUCCESS_SYMBOL,
             ConfigAction::AlreadyExists => INFO_SYMBOL,
@@ -235,6 +235,7 @@ pub fn handle_configure_shell(
     }
 
     // Show what will be done and ask for confirmation (unless --yes flag is used)
+    let skip_confirmation = skip_confirmation || assume_yes();
     if !skip_confirmation
         && !prompt_for_install(
             &preview.configured,
@@ -814,21 +815,12 @@ pub fn prompt_for_install(
 
 /// Prompt user for yes/no confirmation (simple [y/N] prompt)
 fn prompt_yes_no() -> Result<bool, String> {
-    // Blank line before prompt for visual separation
-    eprintln!();
-    eprint!(
-        "{} ",
-        prompt_message(color_print::cformat!("Proceed? <bold>[y/N]</>"))
-    );
-    io::stderr().flush().map_err(|e| e.to_string())?;
-
-    let mut input = String::new();
-    io::stdin()
-        .read_line(&mut input)
-        .map_err(|e| e.to_string())?;
-
-    let response = input.trim().to_lowercase();
-    Ok(response == "y" || response == "yes")
+    Prompt::new("Proceed?")
+        .hint(color_print::cformat!(
+            "Add <bright-black>--yes</> to proceed"
+        ))
+        .confirm(false)
+        .map_err(|e| e.to_string())
 }
 
 /// Fish completion content - finds command in PATH, with WORKTRUNK_BIN as optional override
@@ -942,6 +934,7 @@ pub fn handle_unconfigure_shell(
     }
 
     // Show what will be done and ask for confirmation (unless --yes flag is used)
+    let skip_confirmation = skip_confirmation || assume_yes();
     if !skip_confirmation
         && !prompt_for_uninstall_confirmation(&preview.results, &preview.completion_results)?
     {
@@ -1256,7 +1249,8 @@ fn prompt_for_uninstall_confirmation(
 pub fn handle_show_theme() {
     use color_print::cformat;
     use worktrunk::styling::{
-        error_message, hint_message, info_message, progress_message, success_message,
+        error_message, format_heading, hint_message, info_message, paint, progress_message,
+        success_message, theme,
     };
 
     // Progress
@@ -1298,6 +1292,19 @@ pub fn handle_show_theme() {
 
     eprintln!();
 
+    // Heading and diff stats
+    eprintln!(
+        "{}",
+        format_heading("USER CONFIG", Some("~/.config/worktrunk/config.toml"))
+    );
+    eprintln!(
+        "3 files, {}, {}",
+        paint(theme().addition, "+45"),
+        paint(theme().deletion, "-12")
+    );
+
+    eprintln!();
+
     // Gutter - error details (plain text, no syntax highlighting)
     eprintln!("{}", info_message("Gutter formatting (error details):"));
     eprintln!(
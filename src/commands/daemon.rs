@@ -0,0 +1,460 @@
+//! `wt daemon` — keeps `wt list` and `wt prompt` results warm for one repository.
+//!
+//! The daemon listens on a unix socket in the git common dir. A client sends
+//! its arguments, working directory, and terminal shape; the daemon answers
+//! with what `wt` printed for them. Answers are computed by running `wt`
+//! itself, so they're identical to an uncached run, and kept in a cache that
+//! a background thread refreshes whenever refs, worktree HEADs, or indexes
+//! change, and at least every [`MAX_AGE`] otherwise (CI status, and without
+//! the watcher edits to files, don't show up in the fingerprint).
+//!
+//! With its filesystem watcher (on unless `--no-watch`), the daemon also
+//! records which worktrees changed, so both its own refreshes and any `wt
+//! list` skip `git status` for worktrees nobody touched — see
+//! [`status_cache`](super::list::status_cache).
+//!
+//! Each request is checked against the repository fingerprint before a
+//! cached answer is returned, and the daemon only runs requests that parse
+//! as a command it [`serves`] — a client can't make it run anything else.
+//!
+//! Clients fall back to computing results themselves whenever the daemon
+//! isn't running or doesn't answer, so a missing or wedged daemon only
+//! costs speed.
+
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, IsTerminal, Write};
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use clap::FromArgMatches;
+use color_print::cformat;
+use serde::{Deserialize, Serialize};
+use worktrunk::git::{GitError, Repository};
+use worktrunk::shell_exec::Cmd;
+use worktrunk::styling::{eprintln, hint_message, success_message, warning_message};
+
+use super::list::status_cache;
+use super::watch::StatusWatcher;
+use crate::cli::{Cli, Commands};
+
+/// Socket file name, inside the git common dir.
+const SOCKET_NAME: &str = "wt-daemon.sock";
+
+/// Set to bypass the daemon; also set on the daemon's own `wt` runs.
+pub const NO_DAEMON_ENV: &str = "WORKTRUNK_NO_DAEMON";
+
+/// How often the refresher checks the repository fingerprint.
+const POLL: Duration = Duration::from_secs(2);
+
+/// Oldest a cached answer gets, even when the fingerprint is unchanged.
+const MAX_AGE: Duration = Duration::from_secs(10);
+
+/// Entries nobody asked for in this long are dropped.
+const IDLE: Duration = Duration::from_secs(600);
+
+/// How long a client waits for an answer before computing it itself.
+const CLIENT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// One query: everything that can change what `wt` prints.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+struct Request {
+    cwd: PathBuf,
+    args: Vec<String>,
+    color: bool,
+    width: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Response {
+    stdout: String,
+    stderr: String,
+    code: i32,
+}
+
+struct Entry {
+    response: Response,
+    fingerprint: String,
+    refreshed: Instant,
+    used: Instant,
+}
+
+impl Entry {
+    fn is_stale(&self, fingerprint: &str, now: Instant) -> bool {
+        self.fingerprint != fingerprint || now.duration_since(self.refreshed) >= MAX_AGE
+    }
+}
+
+type Cache = Arc<Mutex<HashMap<Request, Entry>>>;
+
+fn socket_path(repo: &Repository) -> PathBuf {
+    repo.git_common_dir().join(SOCKET_NAME)
+}
+
+/// Commands a running `wt daemon` can answer: read-only, output-only ones.
+pub fn serves(command: &Commands) -> bool {
+    match command {
+        Commands::Prompt { .. } => true,
+        Commands::List {
+            subcommand,
+            all_repos,
+            progressive,
+            ..
+        } => subcommand.is_none() && !all_repos && !progressive,
+        _ => false,
+    }
+}
+
+/// Whether a client's arguments are a query the daemon answers. Checked
+/// again here since anything that can reach the socket can send a request.
+fn allowed(args: &[String]) -> bool {
+    let argv = std::iter::once("wt").chain(args.iter().map(String::as_str));
+    let Ok(matches) = crate::cli::build_command().try_get_matches_from(argv) else {
+        return false;
+    };
+    Cli::from_arg_matches(&matches).is_ok_and(|cli| {
+        cli.verbose == 0 && !cli.dry_run && cli.command.as_ref().is_some_and(serves)
+    })
+}
+
+/// Run the daemon for the current repository until interrupted.
+pub fn handle_daemon(watch: bool) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let socket = socket_path(&repo);
+    if UnixStream::connect(&socket).is_ok() {
+        return Err(GitError::Other {
+            message: cformat!(
+                "A daemon is already running for this repository (<bright-black>{}</>)",
+                socket.display()
+            ),
+        }
+        .into());
+    }
+    // Left behind by a daemon that didn't exit cleanly
+    let _ = std::fs::remove_file(&socket);
+    let listener = UnixListener::bind(&socket)?;
+
+    eprintln!(
+        "{}",
+        success_message(cformat!(
+            "Serving <bold>wt list</> and <bold>wt prompt</> from <bright-black>{}</>",
+            socket.display()
+        ))
+    );
+    let watcher = if watch {
+        match StatusWatcher::start(&repo) {
+            Ok(Some(watcher)) => Some(watcher),
+            Ok(None) => {
+                eprintln!(
+                    "{}",
+                    warning_message("Another process is watching this repository's worktrees")
+                );
+                None
+            }
+            Err(e) => {
+                eprintln!(
+                    "{}",
+                    warning_message(format!("Not watching worktrees for changes: {e}"))
+                );
+                None
+            }
+        }
+    } else {
+        None
+    };
+    eprintln!("{}", hint_message("Press Ctrl-C to stop"));
+
+    let cache: Cache = Arc::default();
+    {
+        let cache = Arc::clone(&cache);
+        let repo = repo.clone();
+        std::thread::spawn(move || refresh_loop(&repo, &cache, watcher));
+    }
+
+    for stream in listener.incoming() {
+        let Ok(stream) = stream else { continue };
+        let cache = Arc::clone(&cache);
+        let repo = repo.clone();
+        std::thread::spawn(move || {
+            if let Err(e) = serve(stream, &repo, &cache) {
+                log::debug!("daemon: connection failed: {e}");
+            }
+        });
+    }
+    Ok(())
+}
+
+/// Answer one connection: a JSON request line in, a JSON response line out.
+fn serve(stream: UnixStream, repo: &Repository, cache: &Cache) -> anyhow::Result<()> {
+    let mut line = String::new();
+    BufReader::new(&stream).read_line(&mut line)?;
+    let request: Request = serde_json::from_str(&line)?;
+    if !allowed(&request.args) {
+        anyhow::bail!("refusing to run wt {}", request.args.join(" "));
+    }
+
+    // Fingerprint first: changes made while computing trigger a refresh
+    let fingerprint = fingerprint(repo);
+    let now = Instant::now();
+    let cached = cache
+        .lock()
+        .unwrap()
+        .get_mut(&request)
+        .filter(|entry| !entry.is_stale(&fingerprint, now))
+        .map(|entry| {
+            entry.used = now;
+            entry.response.clone()
+        });
+    let response = match cached {
+        Some(response) => response,
+        None => {
+            let response = compute(&request)?;
+            let now = Instant::now();
+            cache.lock().unwrap().insert(
+                request,
+                Entry {
+                    response: response.clone(),
+                    fingerprint,
+                    refreshed: now,
+                    used: now,
+                },
+            );
+            response
+        }
+    };
+
+    let mut stream = stream;
+    writeln!(stream, "{}", serde_json::to_string(&response)?)?;
+    Ok(())
+}
+
+/// Keep cached answers current, and forget the ones nobody asks for.
+fn refresh_loop(repo: &Repository, cache: &Cache, mut watcher: Option<StatusWatcher>) {
+    loop {
+        std::thread::sleep(POLL);
+        if let Some(watcher) = &mut watcher {
+            watcher.sync(repo);
+        }
+        let fingerprint = fingerprint(repo);
+        let now = Instant::now();
+        let stale: Vec<Request> = {
+            let mut cache = cache.lock().unwrap();
+            cache.retain(|_, entry| now.duration_since(entry.used) < IDLE);
+            cache
+                .iter()
+                .filter(|(_, entry)| entry.is_stale(&fingerprint, now))
+                .map(|(request, _)| request.clone())
+                .collect()
+        };
+        // Computed without the lock so queries keep getting (older) answers
+        for request in stale {
+            let Ok(response) = compute(&request) else {
+                continue;
+            };
+            if let Some(entry) = cache.lock().unwrap().get_mut(&request) {
+                entry.response = response;
+                entry.fingerprint.clone_from(&fingerprint);
+                entry.refreshed = Instant::now();
+            }
+        }
+    }
+}
+
+/// What `wt` prints for a request, computed by running it.
+fn compute(request: &Request) -> anyhow::Result<Response> {
+    let exe = std::env::current_exe()?;
+    let mut cmd = Cmd::new(exe.to_string_lossy())
+        .args(request.args.iter().map(String::as_str))
+        .current_dir(&request.cwd)
+        .env(NO_DAEMON_ENV, "1");
+    cmd = if request.color {
+        cmd.env("CLICOLOR_FORCE", "1").env_remove("NO_COLOR")
+    } else {
+        cmd.env("NO_COLOR", "1").env_remove("CLICOLOR_FORCE")
+    };
+    if let Some(width) = request.width {
+        cmd = cmd.env("COLUMNS", width.to_string());
+    }
+    let output = cmd.run()?;
+    Ok(Response {
+        stdout: String::from_utf8_lossy(&output.stdout).into_owned(),
+        stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+        code: output.status.code().unwrap_or(1),
+    })
+}
+
+/// Changes whenever refs, worktrees, their HEADs, or their indexes change —
+/// and, while the watcher runs, their files.
+///
+/// Only refs need git; worktrees are read straight from their git dirs (as
+/// `wt prompt` does), so a request costs one git process however many
+/// worktrees there are.
+fn fingerprint(repo: &Repository) -> String {
+    let common_dir = repo.git_common_dir();
+    let mut fingerprint = repo
+        .run_command(&["for-each-ref", "--format=%(objectname) %(refname)"])
+        .unwrap_or_default();
+    // The main worktree's git dir is the common dir; each linked worktree has
+    // its own under `worktrees/`
+    let mut git_dirs: Vec<PathBuf> = std::fs::read_dir(common_dir.join("worktrees"))
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.path())
+        .collect();
+    git_dirs.sort();
+    git_dirs.insert(0, common_dir.to_path_buf());
+    for git_dir in git_dirs {
+        let head = std::fs::read_to_string(git_dir.join("HEAD")).unwrap_or_default();
+        let index = std::fs::metadata(git_dir.join("index"))
+            .and_then(|meta| meta.modified())
+            .ok();
+        let generation = worktree_of(common_dir, &git_dir)
+            .and_then(|worktree| status_cache::generation(common_dir, &worktree));
+        fingerprint.push_str(&format!(
+            "{} {} {index:?} {generation:?}\n",
+            git_dir.display(),
+            head.trim()
+        ));
+    }
+    fingerprint
+}
+
+/// The worktree a git dir belongs to: the directory holding its `.git`,
+/// which linked worktrees record in their `gitdir` file.
+fn worktree_of(common_dir: &Path, git_dir: &Path) -> Option<PathBuf> {
+    let dot_git = if git_dir == common_dir {
+        common_dir.to_path_buf()
+    } else {
+        PathBuf::from(std::fs::read_to_string(git_dir.join("gitdir")).ok()?.trim())
+    };
+    dot_git.parent().map(Path::to_path_buf)
+}
+
+/// Ask a running daemon to answer for this invocation and print its answer.
+///
+/// Returns the exit code to exit with, or `None` when there's no daemon (or
+/// it didn't answer) and the command should run normally.
+pub fn query() -> Option<i32> {
+    if std::env::var_os(NO_DAEMON_ENV).is_some() {
+        return None;
+    }
+    let repo = Repository::current().ok()?;
+    let socket = socket_path(&repo);
+    if !socket.exists() {
+        return None;
+    }
+    let stdout = std::io::stdout();
+    let color = worktrunk::styling::colors_enabled(worktrunk::styling::Stream::Stdout);
+    let width = stdout
+        .is_terminal()
+        .then(worktrunk::styling::get_terminal_width);
+    let request = Request {
+        cwd: std::env::current_dir().ok()?,
+        args: std::env::args().skip(1).collect(),
+        color,
+        width,
+    };
+    let response = ask(&socket, &request)
+        .inspect_err(|e| log::debug!("daemon: no answer: {e}"))
+        .ok()?;
+
+    // Long `wt list` output is paged, as it would be without the daemon;
+    // when the pager fails, the output is printed instead
+    let paged = crate::pager::try_page(&response.stdout)
+        .inspect_err(|e| log::debug!("daemon: paging failed: {e}"))
+        .unwrap_or(false);
+    if !paged {
+        let mut stdout = stdout.lock();
+        stdout.write_all(response.stdout.as_bytes()).ok()?;
+        stdout.flush().ok()?;
+    }
+    let mut stderr = std::io::stderr().lock();
+    let _ = stderr.write_all(response.stderr.as_bytes());
+    Some(response.code)
+}
+
+fn ask(socket: &Path, request: &Request) -> anyhow::Result<Response> {
+    let mut stream = UnixStream::connect(socket)?;
+    stream.set_read_timeout(Some(CLIENT_TIMEOUT))?;
+    writeln!(stream, "{}", serde_json::to_string(request)?)?;
+    let mut line = String::new();
+    BufReader::new(&stream).read_line(&mut line)?;
+    Ok(serde_json::from_str(&line)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(fingerprint: &str, refreshed: Instant) -> Entry {
+        Entry {
+            response: Response {
+                stdout: String::new(),
+                stderr: String::new(),
+                code: 0,
+            },
+            fingerprint: fingerprint.to_string(),
+            refreshed,
+            used: refreshed,
+        }
+    }
+
+    #[test]
+    fn test_entry_staleness() {
+        let now = Instant::now();
+        assert!(!entry("a", now).is_stale("a", now));
+        assert!(entry("a", now).is_stale("b", now));
+        assert!(entry("a", now).is_stale("a", now + MAX_AGE));
+    }
+
+    #[test]
+    fn test_worktree_of() {
+        let dir = tempfile::tempdir().unwrap();
+        let common_dir = dir.path().join("repo/.git");
+        let linked = common_dir.join("worktrees/feature");
+        std::fs::create_dir_all(&linked).unwrap();
+        std::fs::write(linked.join("gitdir"), "/code/repo.feature/.git\n").unwrap();
+
+        assert_eq!(
+            worktree_of(&common_dir, &common_dir),
+            Some(dir.path().join("repo"))
+        );
+        assert_eq!(
+            worktree_of(&common_dir, &linked),
+            Some(PathBuf::from("/code/repo.feature"))
+        );
+        assert_eq!(
+            worktree_of(&common_dir, &common_dir.join("worktrees/gone")),
+            None
+        );
+    }
+
+    #[test]
+    fn test_allowed() {
+        let allowed = |args: &str| allowed(&args.split(' ').map(String::from).collect::<Vec<_>>());
+        assert!(allowed("list"));
+        assert!(allowed("list --format=json"));
+        assert!(allowed("--color always list"));
+        assert!(allowed("prompt"));
+        assert!(!allowed("list --progressive"));
+        assert!(!allowed("-v list"));
+        assert!(!allowed("--dry-run list"));
+        assert!(!allowed("remove feature"));
+        assert!(!allowed("step for-each -- rm -rf ."));
+        assert!(!allowed("not-a-command"));
+    }
+
+    #[test]
+    fn test_request_roundtrip() {
+        let request = Request {
+            cwd: PathBuf::from("/repo"),
+            args: vec!["list".into(), "--format=json".into()],
+            color: false,
+            width: Some(120),
+        };
+        let json = serde_json::to_string(&request).unwrap();
+        assert_eq!(serde_json::from_str::<Request>(&json).unwrap(), request);
+    }
+}
@@ -0,0 +1,232 @@
+//! Per-worktree databases (`[database]` in the project config).
+//!
+//! `wt step db-create` runs the configured `create` command for the current
+//! worktree and records the expanded `drop` command in the state store;
+//! `wt step db-drop` runs it and forgets the database. They're meant to be
+//! called from `post-create` and `pre-remove` hooks. A worktree removed
+//! without its hooks leaves its database behind, and `wt gc` finds it by
+//! comparing the recorded branches with the worktrees that still exist.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use color_print::cformat;
+use worktrunk::config::{ProjectDatabaseConfig, UserConfig, expand_template};
+use worktrunk::git::{GitError, Repository};
+use worktrunk::state::StateStore;
+use worktrunk::styling::{
+    eprintln, format_bash_with_gutter, hint_message, info_message, progress_message,
+    success_message, warning_message,
+};
+
+use super::command_executor::{CommandContext, build_hook_context};
+use crate::output::execute_command_in_worktree;
+use crate::output::prompt::{Prompt, can_prompt};
+
+/// Create the current worktree's database.
+pub fn step_db_create() -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let database = database_config(&repo)?;
+    let Some(create) = database.create.as_deref() else {
+        return Err(GitError::Other {
+            message: cformat!(
+                "No <bold>create</> command under <bold>[database]</> in .config/wt.toml"
+            ),
+        }
+        .into());
+    };
+    let branch = repo.require_current_branch("create a database for")?;
+    let config = UserConfig::load()?;
+    let path = repo.current_worktree().root()?;
+    let ctx = CommandContext::new(&repo, &config, Some(&branch), &path, false);
+    let vars = build_hook_context(&ctx, &[]);
+    let vars: HashMap<&str, &str> = vars.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect();
+
+    let command = expand_template(create, &vars, true, &repo, "database create")?;
+    let drop = database
+        .drop
+        .as_deref()
+        .map(|drop| expand_template(drop, &vars, true, &repo, "database drop"))
+        .transpose()?
+        .unwrap_or_default();
+
+    eprintln!(
+        "{}",
+        progress_message(cformat!("Creating database for <bold>{branch}</>..."))
+    );
+    execute_command_in_worktree(&path, &command, None, None, None)?;
+    StateStore::new(repo.git_common_dir()).update(|state| {
+        state.databases.insert(branch.clone(), drop);
+    })?;
+    eprintln!(
+        "{}",
+        success_message(cformat!("Created database for <bold>{branch}</>"))
+    );
+    Ok(())
+}
+
+/// Drop the current worktree's database.
+pub fn step_db_drop() -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let database = database_config(&repo)?;
+    let Some(drop) = database.drop.as_deref() else {
+        return Err(GitError::Other {
+            message: cformat!(
+                "No <bold>drop</> command under <bold>[database]</> in .config/wt.toml"
+            ),
+        }
+        .into());
+    };
+    let branch = repo.require_current_branch("drop the database of")?;
+    let config = UserConfig::load()?;
+    let path = repo.current_worktree().root()?;
+    let ctx = CommandContext::new(&repo, &config, Some(&branch), &path, false);
+    let vars = build_hook_context(&ctx, &[]);
+    let vars: HashMap<&str, &str> = vars.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect();
+    let command = expand_template(drop, &vars, true, &repo, "database drop")?;
+
+    eprintln!(
+        "{}",
+        progress_message(cformat!("Dropping database of <bold>{branch}</>..."))
+    );
+    execute_command_in_worktree(&path, &command, None, None, None)?;
+    StateStore::new(repo.git_common_dir()).update(|state| {
+        state.databases.remove(&branch);
+    })?;
+    eprintln!(
+        "{}",
+        success_message(cformat!("Dropped database of <bold>{branch}</>"))
+    );
+    Ok(())
+}
+
+/// Report databases whose worktree is gone; with `drop`, drop them, and on
+/// a terminal without it, drop the ones picked.
+pub fn handle_gc(drop: bool) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let store = StateStore::new(repo.git_common_dir());
+    let live: HashSet<String> = repo
+        .list_worktrees()?
+        .into_iter()
+        .filter(|wt| !wt.is_prunable())
+        .filter_map(|wt| wt.branch)
+        .collect();
+    let orphans = orphaned(&store.load()?.databases, &live);
+
+    if orphans.is_empty() {
+        eprintln!("{}", info_message("No orphaned databases"));
+        return Ok(());
+    }
+
+    if !drop {
+        for (branch, command) in &orphans {
+            eprintln!(
+                "{}",
+                warning_message(cformat!(
+                    "Database of <bold>{branch}</> outlived its worktree"
+                ))
+            );
+            if !command.is_empty() {
+                eprintln!("{}", format_bash_with_gutter(command));
+            }
+        }
+    }
+
+    // On a terminal, plain `wt gc` asks which of them to drop
+    let selected: Vec<usize> = if drop {
+        (0..orphans.len()).collect()
+    } else if can_prompt() {
+        let branches: Vec<String> = orphans.iter().map(|(branch, _)| branch.clone()).collect();
+        Prompt::new("Drop which databases?").multi_select(&branches, &[])?
+    } else {
+        eprintln!(
+            "{}",
+            hint_message(cformat!("To drop them, run <bright-black>wt gc --drop</>"))
+        );
+        return Ok(());
+    };
+
+    let root = repo.repo_path();
+    let mut failed = false;
+    for (branch, command) in selected.into_iter().map(|i| &orphans[i]) {
+        if command.is_empty() {
+            eprintln!(
+                "{}",
+                warning_message(cformat!(
+                    "No drop command was recorded for the database of <bold>{branch}</>; drop it by hand"
+                ))
+            );
+            failed = true;
+            continue;
+        }
+        eprintln!(
+            "{}",
+            progress_message(cformat!("Dropping database of <bold>{branch}</>..."))
+        );
+        match execute_command_in_worktree(root, command, None, None, None) {
+            Ok(()) => {
+                store.update(|state| {
+                    state.databases.remove(branch);
+                })?;
+                eprintln!(
+                    "{}",
+                    success_message(cformat!("Dropped database of <bold>{branch}</>"))
+                );
+            }
+            Err(e) => {
+                eprintln!(
+                    "{}",
+                    warning_message(cformat!(
+                        "Failed to drop the database of <bold>{branch}</>: {e}"
+                    ))
+                );
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        return Err(GitError::Other {
+            message: "Some databases were not dropped".into(),
+        }
+        .into());
+    }
+    Ok(())
+}
+
+fn database_config(repo: &Repository) -> anyhow::Result<ProjectDatabaseConfig> {
+    repo.load_project_config()?
+        .and_then(|config| config.database().cloned())
+        .ok_or_else(|| {
+            GitError::Other {
+                message: cformat!("No <bold>[database]</> section in .config/wt.toml"),
+            }
+            .into()
+        })
+}
+
+/// Tracked databases, with their drop commands, whose branch has no worktree.
+fn orphaned(databases: &BTreeMap<String, String>, live: &HashSet<String>) -> Vec<(String, String)> {
+    databases
+        .iter()
+        .filter(|(branch, _)| !live.contains(*branch))
+        .map(|(branch, command)| (branch.clone(), command.clone()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_orphaned() {
+        let databases = BTreeMap::from([
+            ("feature".to_string(), "dropdb app_feature".to_string()),
+            ("gone".to_string(), "dropdb app_gone".to_string()),
+        ]);
+        let live = HashSet::from(["main".to_string(), "feature".to_string()]);
+        assert_eq!(
+            orphaned(&databases, &live),
+            vec![("gone".to_string(), "dropdb app_gone".to_string())]
+        );
+    }
+}
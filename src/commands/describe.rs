@@ -0,0 +1,68 @@
+//! Branch names from a description, for `wt switch --create --describe`.
+//!
+//! The commit generation command suggests a name that follows the project's
+//! `[branches] naming` patterns; the user confirms or edits it before the
+//! worktree is created.
+
+use anyhow::bail;
+use color_print::cformat;
+use worktrunk::config::UserConfig;
+use worktrunk::git::Repository;
+use worktrunk::styling::{eprintln, progress_message, warning_message};
+
+use crate::llm::suggest_branch_name;
+use crate::output::prompt::Prompt;
+
+/// Recent branches shown to the LLM as examples of the repository's style.
+const RECENT_BRANCHES: usize = 10;
+
+/// Resolve `wt switch --create --describe TEXT` to a branch name.
+///
+/// With `yes`, the suggestion is taken as is.
+pub(crate) fn branch_from_description(
+    config: &UserConfig,
+    description: &str,
+    yes: bool,
+) -> anyhow::Result<String> {
+    let repo = Repository::current()?;
+    let project_id = repo.project_identifier().ok();
+    let generation = config.commit_generation(project_id.as_deref());
+    let branches_config = repo
+        .load_project_config()?
+        .and_then(|config| config.branches)
+        .unwrap_or_default();
+    let mut recent_branches = repo.all_branches().unwrap_or_default();
+    recent_branches.truncate(RECENT_BRANCHES);
+
+    eprintln!("{}", progress_message("Generating branch name..."));
+    let suggested = suggest_branch_name(
+        &generation,
+        description,
+        &branches_config.naming,
+        &recent_branches,
+    )?;
+    if repo
+        .run_command(&["check-ref-format", "--branch", &suggested])
+        .is_err()
+    {
+        bail!("LLM suggested an invalid branch name: {suggested}");
+    }
+    if !branches_config.follows_naming(&suggested) {
+        eprintln!(
+            "{}",
+            warning_message(cformat!(
+                "<bold>{suggested}</> doesn't match <bright-black>[branches] naming</> ({})",
+                branches_config.naming.join(", ")
+            ))
+        );
+    }
+
+    if yes {
+        return Ok(suggested);
+    }
+    Prompt::new("Branch name")
+        .hint(cformat!(
+            "Add <bright-black>--yes</> to take the suggestion, or pass a name: <bright-black>wt switch --create {suggested}</>"
+        ))
+        .text(Some(&suggested))
+}
@@ -0,0 +1,198 @@
+//! Dev containers for worktrees.
+//!
+//! `wt switch --create --devcontainer` runs `devcontainer up` for the new
+//! worktree. Its containers are labelled `worktrunk.project=<name>`, with the
+//! name derived from the repository and branch; the name is also passed as
+//! `COMPOSE_PROJECT_NAME`, so Compose-based configurations get a project of
+//! their own per worktree. The branch is remembered in the state store, which
+//! is how `wt remove` knows to tear the containers down and `wt list` knows
+//! whose state to show.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::process::Stdio;
+
+use color_print::cformat;
+use worktrunk::git::{GitError, Repository};
+use worktrunk::shell_exec::Cmd;
+use worktrunk::state::StateStore;
+use worktrunk::styling::{eprintln, progress_message, success_message, warning_message};
+
+/// Docker label identifying a worktree's containers.
+const LABEL: &str = "worktrunk.project";
+
+/// Container project name for `branch`: lowercase, with anything Docker
+/// doesn't allow in project names replaced by `-`.
+fn project_name(repo_name: &str, branch: &str) -> String {
+    format!("{repo_name}-{branch}")
+        .to_lowercase()
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect()
+}
+
+/// Start the dev container for the worktree of `branch` at `path`.
+pub(crate) fn up(repo: &Repository, branch: &str, path: &Path) -> anyhow::Result<()> {
+    if which::which("devcontainer").is_err() {
+        return Err(GitError::Other {
+            message: cformat!(
+                "devcontainer CLI not found; install it with <bright-black>npm install -g @devcontainers/cli</>"
+            ),
+        }
+        .into());
+    }
+    let repo_name = repo
+        .repo_path()
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "worktree".into());
+    let name = project_name(&repo_name, branch);
+
+    eprintln!(
+        "{}",
+        progress_message(cformat!("Starting dev container <bold>{name}</>..."))
+    );
+    Cmd::new("devcontainer")
+        .args(["up", "--workspace-folder"])
+        .arg(path.to_string_lossy())
+        .args(["--id-label", &format!("{LABEL}={name}")])
+        .env("COMPOSE_PROJECT_NAME", &name)
+        .stdout(Stdio::from(std::io::stderr()))
+        .stream()?;
+
+    StateStore::new(repo.git_common_dir()).update(|state| {
+        state.devcontainers.insert(branch.to_string(), name.clone());
+    })?;
+    eprintln!(
+        "{}",
+        success_message(cformat!("Started dev container <bold>{name}</>"))
+    );
+    Ok(())
+}
+
+/// Remove the dev container of `branch`, if it has one.
+///
+/// Best-effort: a failure is reported and leaves the branch tracked, so the
+/// container still shows in `wt list`.
+pub(crate) fn down(repo: &Repository, branch: &str) {
+    if worktrunk::dry_run::is_dry_run() {
+        return;
+    }
+    let store = StateStore::new(repo.git_common_dir());
+    let Some(name) = store
+        .load()
+        .ok()
+        .and_then(|state| state.devcontainers.get(branch).cloned())
+    else {
+        return;
+    };
+
+    let filter = format!("label={LABEL}={name}");
+    let removed = docker(&["ps", "-aq", "--filter", &filter]).and_then(|ids| {
+        let mut args = vec!["rm", "-f"];
+        args.extend(ids.split_whitespace());
+        if args.len() > 2 {
+            docker(&args)?;
+        }
+        Ok(())
+    });
+    match removed {
+        Ok(()) => {
+            let _ = store.update(|state| state.devcontainers.remove(branch));
+            eprintln!(
+                "{}",
+                success_message(cformat!("Removed dev container <bold>{name}</>"))
+            );
+        }
+        Err(e) => eprintln!(
+            "{}",
+            warning_message(cformat!(
+                "Failed to remove dev container <bold>{name}</>: {e}"
+            ))
+        ),
+    }
+}
+
+/// Container state (`running`, `exited`, ...) of each branch with a dev
+/// container; `gone` when its containers no longer exist.
+///
+/// Docker is only asked when some branch has a dev container. Empty if it
+/// can't be reached.
+pub(crate) fn container_states(repo: &Repository) -> HashMap<String, String> {
+    let tracked = StateStore::new(repo.git_common_dir())
+        .load()
+        .map(|state| state.devcontainers)
+        .unwrap_or_default();
+    if tracked.is_empty() {
+        return HashMap::new();
+    }
+    let format = format!("{{{{.Label \"{LABEL}\"}}}}\t{{{{.State}}}}");
+    let Ok(output) = docker(&[
+        "ps",
+        "-a",
+        "--filter",
+        &format!("label={LABEL}"),
+        "--format",
+        &format,
+    ]) else {
+        return HashMap::new();
+    };
+    let states = parse_states(&output);
+    tracked
+        .into_iter()
+        .map(|(branch, name)| {
+            let state = states.get(&name).cloned().unwrap_or_else(|| "gone".into());
+            (branch, state)
+        })
+        .collect()
+}
+
+/// Map `docker ps` lines of `<project>\t<state>` to each project's state.
+///
+/// A Compose project has several containers; it counts as running if any is.
+fn parse_states(output: &str) -> HashMap<String, String> {
+    let mut states: HashMap<String, String> = HashMap::new();
+    for (name, state) in output.lines().filter_map(|line| line.split_once('\t')) {
+        let entry = states
+            .entry(name.to_string())
+            .or_insert_with(|| state.into());
+        if state == "running" {
+            *entry = state.into();
+        }
+    }
+    states
+}
+
+fn docker(args: &[&str]) -> anyhow::Result<String> {
+    let output = Cmd::new("docker").args(args.iter().copied()).run()?;
+    if !output.status.success() {
+        anyhow::bail!("{}", String::from_utf8_lossy(&output.stderr).trim());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_project_name() {
+        assert_eq!(project_name("app", "feature/Auth"), "app-feature-auth");
+        assert_eq!(project_name("My.Repo", "fix_1"), "my-repo-fix_1");
+    }
+
+    #[test]
+    fn test_parse_states() {
+        let output = "app-a\texited\napp-b\texited\napp-a\trunning\napp-a\texited\nnoise\n";
+        let states = parse_states(output);
+        assert_eq!(states["app-a"], "running");
+        assert_eq!(states["app-b"], "exited");
+        assert_eq!(states.len(), 2);
+    }
+}
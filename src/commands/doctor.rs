@@ -0,0 +1,718 @@
+//! Doctor command handler.
+//!
+//! `wt doctor` checks the environment worktrunk depends on — git, shell
+//! integration, config files, forge CLIs, the commit generation command, and
+//! worktree metadata — and prints a fix for each problem. It exits non-zero
+//! when any check fails, so it can gate CI or setup scripts.
+//!
+//! `wt doctor --worktrees` instead explains each problem `wt list --health`
+//! flags, worktree by worktree.
+
+use std::collections::HashMap;
+
+use color_print::cformat;
+use worktrunk::config::{
+    ProjectConfig, UserConfig, find_unknown_project_keys, find_unknown_user_keys,
+};
+use worktrunk::git::{Repository, WorktrunkError};
+use worktrunk::path::format_path_for_display;
+use worktrunk::styling::{
+    eprintln, error_message, format_heading, format_with_gutter, hint_message, info_message,
+    success_message, warning_message,
+};
+
+use super::config::{get_git_version, require_user_config_path};
+use super::configure_shell::{ConfigAction, scan_shell_configs};
+use super::list::ci_status::{CiPlatform, CiToolsStatus, get_platform_for_repo};
+use super::list::health::{self, HealthFlag};
+use super::worktree::{broken_build_cache_links, uses_direnv, uses_mise};
+use crate::llm::test_commit_generation;
+use crate::output;
+
+/// Oldest git with `git worktree remove` and `git worktree move`.
+const MIN_GIT_VERSION: (u32, u32) = (2, 17);
+
+/// Oldest git with `for-each-ref --format='%(ahead-behind:...)'`, which `wt list` batches.
+const RECOMMENDED_GIT_VERSION: (u32, u32) = (2, 36);
+
+/// Result of one check.
+#[derive(Debug, PartialEq, Eq)]
+enum Check {
+    Pass(String),
+    /// Works, but something should be fixed
+    Warn {
+        message: String,
+        fix: String,
+    },
+    /// Broken
+    Fail {
+        message: String,
+        fix: String,
+        details: Option<String>,
+    },
+    /// Not applicable here
+    Skip(String),
+}
+
+impl Check {
+    fn warn(message: impl Into<String>, fix: impl Into<String>) -> Self {
+        Self::Warn {
+            message: message.into(),
+            fix: fix.into(),
+        }
+    }
+
+    fn fail(message: impl Into<String>, fix: impl Into<String>) -> Self {
+        Self::Fail {
+            message: message.into(),
+            fix: fix.into(),
+            details: None,
+        }
+    }
+
+    fn print(&self) {
+        match self {
+            Check::Pass(message) => eprintln!("{}", success_message(message)),
+            Check::Warn { message, fix } => {
+                eprintln!("{}", warning_message(message));
+                eprintln!("{}", hint_message(fix));
+            }
+            Check::Fail {
+                message,
+                fix,
+                details,
+            } => {
+                eprintln!("{}", error_message(message));
+                if let Some(details) = details {
+                    eprintln!("{}", format_with_gutter(details, None));
+                }
+                eprintln!("{}", hint_message(fix));
+            }
+            Check::Skip(message) => eprintln!("{}", info_message(message)),
+        }
+    }
+}
+
+/// Handle the doctor command.
+///
+/// With `worktrees`, checks each worktree's health instead of the environment.
+pub fn handle_doctor(worktrees: bool) -> anyhow::Result<()> {
+    let repo = Repository::current().ok();
+
+    let sections: Vec<(&str, Vec<Check>)> = if worktrees {
+        vec![("WORKTREES", check_worktree_health(repo.as_ref()))]
+    } else {
+        vec![
+            ("GIT", {
+                let mut checks = vec![check_git_version(get_git_version().as_deref())];
+                checks.extend(check_worktrees(repo.as_ref()));
+                checks.extend(check_default_branch(repo.as_ref()));
+                checks.extend(check_lfs(repo.as_ref()));
+                checks.extend(check_build_cache(repo.as_ref()));
+                checks.extend(check_dev_env(repo.as_ref()));
+                checks.extend(check_signing(repo.as_ref()));
+                checks
+            }),
+            ("SHELL INTEGRATION", check_shell_integration()),
+            ("CONFIG", check_config(repo.as_ref())),
+            ("FORGE", vec![check_forge(repo.as_ref())]),
+            (
+                "COMMIT GENERATION",
+                vec![check_commit_generation(repo.as_ref())],
+            ),
+        ]
+    };
+
+    let mut failures = 0;
+    for (i, (title, checks)) in sections.iter().enumerate() {
+        if i > 0 {
+            eprintln!();
+        }
+        eprintln!("{}", format_heading(title, None));
+        for check in checks {
+            check.print();
+            failures += usize::from(matches!(check, Check::Fail { .. }));
+        }
+    }
+
+    if failures > 0 {
+        return Err(WorktrunkError::AlreadyDisplayed { exit_code: 1 }.into());
+    }
+    Ok(())
+}
+
+/// Parse `major.minor` from a `git --version` number like `2.39.3 (Apple Git-146)`.
+fn parse_git_version(version: &str) -> Option<(u32, u32)> {
+    let mut parts = version.split(|c: char| !c.is_ascii_digit());
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    Some((major, minor))
+}
+
+fn check_git_version(version: Option<&str>) -> Check {
+    let Some(version) = version else {
+        return Check::fail("git not found", "Install git and make sure it is on PATH");
+    };
+    let (min_major, min_minor) = MIN_GIT_VERSION;
+    let (rec_major, rec_minor) = RECOMMENDED_GIT_VERSION;
+    match parse_git_version(version) {
+        Some(v) if v < MIN_GIT_VERSION => Check::fail(
+            cformat!("git <bold>{version}</> is too old"),
+            format!("Upgrade to git {min_major}.{min_minor} or newer"),
+        ),
+        Some(v) if v < RECOMMENDED_GIT_VERSION => Check::warn(
+            cformat!("git <bold>{version}</> works, but <bold>wt list</> is slower"),
+            format!("Upgrade to git {rec_major}.{rec_minor} or newer"),
+        ),
+        Some(_) => Check::Pass(cformat!("git <bold>{version}</>")),
+        None => Check::warn(
+            cformat!("Could not parse git version <bold>{version}</>"),
+            format!("Worktrunk needs git {min_major}.{min_minor} or newer"),
+        ),
+    }
+}
+
+/// Worktree support and orphaned worktree metadata.
+fn check_worktrees(repo: Option<&Repository>) -> Vec<Check> {
+    let Some(repo) = repo else {
+        return vec![Check::Skip(
+            "Not in a git repository; repository checks skipped".into(),
+        )];
+    };
+    let worktrees = match repo.list_worktrees() {
+        Ok(worktrees) => worktrees,
+        Err(e) => {
+            return vec![Check::Fail {
+                message: "Could not list worktrees".into(),
+                fix: "Check that this git supports git worktree list --porcelain".into(),
+                details: Some(e.to_string()),
+            }];
+        }
+    };
+
+    let mut checks = vec![Check::Pass(format!(
+        "Worktrees supported ({} in this repository)",
+        worktrees.len()
+    ))];
+    let orphaned: Vec<_> = worktrees.iter().filter(|wt| wt.is_prunable()).collect();
+    if !orphaned.is_empty() {
+        let paths = orphaned
+            .iter()
+            .map(|wt| format_path_for_display(&wt.path))
+            .collect::<Vec<_>>()
+            .join(", ");
+        checks.push(Check::warn(
+            format!(
+                "{} worktree(s) with missing directories: {paths}",
+                orphaned.len()
+            ),
+            cformat!("Run <bright-black>git worktree prune</> to remove their metadata"),
+        ));
+    }
+    checks
+}
+
+/// Each worktree's problems, as `wt list --health` flags them, with the fix.
+fn check_worktree_health(repo: Option<&Repository>) -> Vec<Check> {
+    let Some(repo) = repo else {
+        return vec![Check::Skip(
+            "Not in a git repository; worktree checks skipped".into(),
+        )];
+    };
+    let worktrees = match repo.list_worktrees() {
+        Ok(worktrees) => worktrees,
+        Err(e) => {
+            return vec![Check::Fail {
+                message: "Could not list worktrees".into(),
+                fix: "Check that this git supports git worktree list --porcelain".into(),
+                details: Some(e.to_string()),
+            }];
+        }
+    };
+
+    let mut checks = Vec::new();
+    for wt in worktrees.iter().filter(|wt| !wt.bare) {
+        let path = format_path_for_display(&wt.path);
+        let name = wt.branch.clone().unwrap_or_else(|| path.clone());
+        let mut flags = health::state_flags(wt.is_prunable(), wt.locked.is_some(), wt.detached);
+        if !wt.is_prunable() {
+            match health::probe(repo, &wt.path, wt.branch.as_deref(), &wt.head) {
+                Ok(probed) => flags.extend(probed),
+                Err(e) => {
+                    checks.push(Check::Fail {
+                        message: cformat!("Could not check <bold>{name}</>"),
+                        fix: cformat!(
+                            "Run <bright-black>git status</> in {path} to see what's wrong"
+                        ),
+                        details: Some(e.to_string()),
+                    });
+                    continue;
+                }
+            }
+        }
+
+        let flags = health::sorted(flags);
+        if flags.is_empty() {
+            checks.push(Check::Pass(cformat!("<bold>{name}</> healthy")));
+        }
+        for flag in flags {
+            let mut explanation = flag.explanation();
+            if flag == HealthFlag::Locked
+                && let Some(reason) = wt.locked.as_deref().filter(|r| !r.is_empty())
+            {
+                explanation.push_str(&format!(" ({reason})"));
+            }
+            checks.push(Check::warn(
+                cformat!("<bold>{name}</>: {explanation}"),
+                flag.fix(&name, &path),
+            ));
+        }
+    }
+    checks
+}
+
+/// The default branch, and whether the configured one exists.
+fn check_default_branch(repo: Option<&Repository>) -> Vec<Check> {
+    let Some(repo) = repo else {
+        return Vec::new();
+    };
+    let project = repo.project_default_branch();
+    let mut checks = Vec::new();
+    match repo.default_branch() {
+        Some(branch) if project.as_deref() == Some(branch.as_str()) => {
+            checks.push(Check::Pass(cformat!(
+                "Default branch <bold>{branch}</> (from project config)"
+            )));
+        }
+        Some(branch) => {
+            if let Some(project) = project {
+                checks.push(Check::warn(
+                    cformat!(
+                        "Project config sets default branch <bold>{project}</>, which does not exist locally"
+                    ),
+                    cformat!(
+                        "Fetch it, or fix <bright-black>default-branch</> in .config/wt.toml"
+                    ),
+                ));
+            }
+            checks.push(Check::Pass(cformat!("Default branch <bold>{branch}</>")));
+        }
+        None => checks.push(match repo.invalid_default_branch_config() {
+            Some(configured) => Check::warn(
+                cformat!("Configured default branch <bold>{configured}</> does not exist locally"),
+                cformat!("To reset, run <bright-black>wt config state default-branch clear</>"),
+            ),
+            None => Check::warn(
+                "Could not determine the default branch",
+                cformat!(
+                    "Run <bright-black>wt config state default-branch set BRANCH</>, or set <bright-black>default-branch</> in .config/wt.toml"
+                ),
+            ),
+        }),
+    }
+    checks
+}
+
+/// Git LFS setup in worktrees that track LFS files.
+///
+/// A worktree without the LFS filter or hooks checks out pointer files
+/// instead of content, which usually surfaces much later as a build failure.
+fn check_lfs(repo: Option<&Repository>) -> Vec<Check> {
+    let Some(repo) = repo else {
+        return Vec::new();
+    };
+    let Ok(worktrees) = repo.list_worktrees() else {
+        return Vec::new();
+    };
+    let lfs_worktrees: Vec<_> = worktrees
+        .iter()
+        .filter(|wt| !wt.bare && !wt.is_prunable())
+        .map(|wt| repo.worktree_at(&wt.path))
+        .filter(|wt| wt.uses_lfs())
+        .collect();
+    if lfs_worktrees.is_empty() {
+        return Vec::new();
+    }
+
+    if repo.run_command(&["lfs", "version"]).is_err() {
+        return vec![Check::warn(
+            "Repository uses Git LFS, but git-lfs is not installed",
+            "Install git-lfs from https://git-lfs.com",
+        )];
+    }
+    let missing: Vec<_> = lfs_worktrees
+        .iter()
+        .filter(|wt| !wt.lfs_installed().unwrap_or(false))
+        .map(|wt| format_path_for_display(wt.path()))
+        .collect();
+    if missing.is_empty() {
+        return vec![Check::Pass("Git LFS hooks installed".into())];
+    }
+    vec![Check::warn(
+        format!(
+            "Git LFS hooks missing in {} worktree(s): {}",
+            missing.len(),
+            missing.join(", ")
+        ),
+        cformat!(
+            "Run <bright-black>git lfs install --local && git lfs pull</> in each to replace pointer files with content"
+        ),
+    )]
+}
+
+/// direnv and mise in a worktree that has their configuration.
+///
+/// Without the tool, the `.envrc` or mise config is silently ignored; without
+/// `[dev-env]`, each new worktree has to be trusted by hand.
+fn check_dev_env(repo: Option<&Repository>) -> Vec<Check> {
+    let Some(root) = repo.and_then(|repo| repo.current_worktree().root().ok()) else {
+        return Vec::new();
+    };
+    let dev_env = UserConfig::load()
+        .ok()
+        .and_then(|config| config.dev_env(repo?.project_identifier().ok().as_deref()))
+        .unwrap_or_default();
+    let tools = [
+        (
+            "direnv",
+            ".envrc",
+            uses_direnv(&root),
+            dev_env.direnv(),
+            "https://direnv.net",
+        ),
+        (
+            "mise",
+            "mise config",
+            uses_mise(&root),
+            dev_env.mise(),
+            "https://mise.jdx.dev",
+        ),
+    ];
+
+    let mut checks = Vec::new();
+    for (tool, file, used, enabled, homepage) in tools {
+        if !used {
+            continue;
+        }
+        checks.push(if which::which(tool).is_err() {
+            Check::warn(
+                format!("Worktree has a {file}, but {tool} is not installed"),
+                format!("Install {tool} from {homepage}"),
+            )
+        } else if enabled {
+            Check::Pass(cformat!(
+                "{tool} trusted in new worktrees (<bold>[dev-env]</>)"
+            ))
+        } else {
+            Check::Skip(cformat!(
+                "{tool} installed; set <bold>[dev-env] {tool} = true</> to trust new worktrees"
+            ))
+        });
+    }
+    checks
+}
+
+/// Check `[build-cache]` symlinks in existing worktrees still resolve.
+///
+/// Deleting the cache (e.g. `rm -rf .git/wt-cache`) leaves every linked
+/// worktree with a dangling `target/` or `node_modules/`.
+fn check_build_cache(repo: Option<&Repository>) -> Vec<Check> {
+    let Some(repo) = repo else {
+        return Vec::new();
+    };
+    let Ok(Some(project_config)) = repo.load_project_config() else {
+        return Vec::new();
+    };
+    let Some(cache) = project_config.build_cache() else {
+        return Vec::new();
+    };
+    let broken = broken_build_cache_links(repo, cache);
+    if broken.is_empty() {
+        return vec![Check::Pass("Build cache links resolve".into())];
+    }
+    let paths: Vec<_> = broken.iter().map(|p| format_path_for_display(p)).collect();
+    vec![Check::warn(
+        format!(
+            "{} broken build cache link(s): {}",
+            paths.len(),
+            paths.join(", ")
+        ),
+        cformat!(
+            "Remove with <bright-black>rm {}</>, then rebuild; new worktrees re-create the cache",
+            paths.join(" ")
+        ),
+    )]
+}
+
+/// Signing key for `commit.gpgsign`.
+///
+/// Squash and rebase re-sign every commit they write, so a missing or locked
+/// key fails `wt merge` partway through.
+fn check_signing(repo: Option<&Repository>) -> Vec<Check> {
+    let Some(repo) = repo else {
+        return Vec::new();
+    };
+    let Some(signing) = repo.signing_config() else {
+        return Vec::new();
+    };
+    let format = signing.format;
+    match signing.check_key(repo) {
+        Ok(()) => vec![Check::Pass(cformat!(
+            "Commit signing with <bold>{format}</> key available"
+        ))],
+        Err(reason) => vec![Check::fail(
+            cformat!(
+                "Commit signing is on, but the <bold>{format}</> key is unavailable: {reason}"
+            ),
+            cformat!(
+                "Set <bright-black>git config user.signingkey</> to an available key, or turn signing off with <bright-black>git config commit.gpgsign false</>"
+            ),
+        )],
+    }
+}
+
+fn check_shell_integration() -> Vec<Check> {
+    let cmd = crate::binary_name();
+    let installed: Vec<String> = match scan_shell_configs(None, true, &cmd) {
+        Ok(scan) => scan
+            .configured
+            .iter()
+            .filter(|r| matches!(r.action, ConfigAction::AlreadyExists))
+            .map(|r| r.shell.to_string())
+            .collect(),
+        Err(e) => {
+            return vec![Check::warn(
+                format!("Could not determine shell status: {e}"),
+                "Set $HOME so shell config files can be found",
+            )];
+        }
+    };
+
+    if installed.is_empty() {
+        return vec![Check::warn(
+            "Shell integration not installed; wt can't change directories",
+            cformat!("Run <bright-black>{cmd} config shell install</>"),
+        )];
+    }
+    let mut checks = vec![Check::Pass(format!(
+        "Shell integration installed for {}",
+        installed.join(", ")
+    ))];
+    if !output::is_shell_integration_active() {
+        checks.push(Check::warn(
+            "Shell integration not active in this shell",
+            "Restart the shell, or check that the shell config is sourced",
+        ));
+    }
+    checks
+}
+
+fn check_config(repo: Option<&Repository>) -> Vec<Check> {
+    let mut checks = Vec::new();
+
+    match require_user_config_path() {
+        Ok(path) if !path.exists() => {
+            checks.push(Check::Pass("No user config (using defaults)".into()))
+        }
+        Ok(path) => {
+            let path_display = format_path_for_display(&path);
+            checks.push(check_config_file::<UserConfig>(
+                &path_display,
+                std::fs::read_to_string(&path),
+                find_unknown_user_keys,
+            ));
+        }
+        Err(e) => checks.push(Check::fail(e.to_string(), "Set $HOME or $XDG_CONFIG_HOME")),
+    }
+
+    if let Some(repo) = repo
+        && let Ok(root) = repo.current_worktree().root()
+    {
+        let path = root.join(".config").join("wt.toml");
+        if path.exists() {
+            let path_display = format_path_for_display(&path);
+            checks.push(check_config_file::<ProjectConfig>(
+                &path_display,
+                std::fs::read_to_string(&path),
+                find_unknown_project_keys,
+            ));
+        }
+    }
+    checks
+}
+
+/// Validate one config file: syntax and schema, then unknown keys.
+fn check_config_file<C>(
+    path_display: &str,
+    contents: std::io::Result<String>,
+    find_unknown: fn(&str) -> HashMap<String, toml::Value>,
+) -> Check
+where
+    C: serde::de::DeserializeOwned,
+{
+    let contents = match contents {
+        Ok(contents) => contents,
+        Err(e) => {
+            return Check::Fail {
+                message: cformat!("Cannot read <bold>{path_display}</>"),
+                fix: "Check the file's permissions".into(),
+                details: Some(e.to_string()),
+            };
+        }
+    };
+    if let Err(e) = toml::from_str::<C>(&contents) {
+        return Check::Fail {
+            message: cformat!("Invalid config <bold>{path_display}</>"),
+            fix: cformat!("Fix the error above; <bright-black>wt config show</> shows the file"),
+            details: Some(e.to_string()),
+        };
+    }
+    let mut unknown: Vec<_> = find_unknown(&contents).into_keys().collect();
+    if !unknown.is_empty() {
+        unknown.sort();
+        return Check::warn(
+            cformat!(
+                "Unknown keys in <bold>{path_display}</>: {}",
+                unknown.join(", ")
+            ),
+            cformat!(
+                "Remove them; <bright-black>wt config show</> suggests where misplaced keys belong"
+            ),
+        );
+    }
+    Check::Pass(cformat!("Config valid <bold>{path_display}</>"))
+}
+
+fn check_forge(repo: Option<&Repository>) -> Check {
+    let Some(repo) = repo else {
+        return Check::Skip("Not in a git repository; forge check skipped".into());
+    };
+    let project_config = repo.load_project_config().ok().flatten();
+    let platform_override = project_config.as_ref().and_then(|c| c.ci_platform());
+    let (tool, install_url, installed, authenticated) =
+        match get_platform_for_repo(repo, platform_override, None) {
+            Some(CiPlatform::GitHub) => {
+                let tools = CiToolsStatus::detect(None);
+                (
+                    "gh",
+                    "https://cli.github.com",
+                    tools.gh_installed,
+                    tools.gh_authenticated,
+                )
+            }
+            Some(CiPlatform::GitLab) => {
+                let tools = CiToolsStatus::detect(None);
+                (
+                    "glab",
+                    "https://gitlab.com/gitlab-org/cli",
+                    tools.glab_installed,
+                    tools.glab_authenticated,
+                )
+            }
+            None => {
+                return Check::Skip(
+                    "No GitHub or GitLab remote; CI status and PR commands unavailable".into(),
+                );
+            }
+        };
+    forge_check(tool, install_url, installed, authenticated)
+}
+
+fn forge_check(tool: &str, install_url: &str, installed: bool, authenticated: bool) -> Check {
+    match (installed, authenticated) {
+        (true, true) => Check::Pass(cformat!("<bold>{tool}</> installed & authenticated")),
+        (true, false) => Check::warn(
+            cformat!("<bold>{tool}</> not authenticated; CI status and PR commands will fail"),
+            cformat!("Run <bright-black>{tool} auth login</>"),
+        ),
+        (false, _) => Check::warn(
+            cformat!("<bold>{tool}</> not found; CI status and PR commands unavailable"),
+            format!("Install {tool} from {install_url}"),
+        ),
+    }
+}
+
+fn check_commit_generation(repo: Option<&Repository>) -> Check {
+    let config = match UserConfig::load() {
+        Ok(config) => config,
+        Err(_) => return Check::Skip("User config invalid; see above".into()),
+    };
+    let project_id = repo.and_then(|r| r.project_identifier().ok());
+    let commit_config = config.commit_generation(project_id.as_deref());
+    let Some(command) = commit_config.command.clone() else {
+        return Check::Skip("Commit generation not configured".into());
+    };
+    match test_commit_generation(&commit_config) {
+        Ok(_) => Check::Pass(cformat!("Commit generation working (<bold>{command}</>)")),
+        Err(e) => Check::Fail {
+            message: cformat!("Commit generation failed (<bold>{command}</>)"),
+            fix: "Check commit.generation.command in user config, and that the tool is installed and logged in".into(),
+            details: Some(e.to_string()),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_git_version() {
+        assert_eq!(parse_git_version("2.47.1"), Some((2, 47)));
+        assert_eq!(parse_git_version("2.39.3 (Apple Git-146)"), Some((2, 39)));
+        assert_eq!(parse_git_version("2.45.2.windows.1"), Some((2, 45)));
+        assert_eq!(parse_git_version("unknown"), None);
+    }
+
+    #[test]
+    fn test_check_git_version() {
+        assert!(matches!(check_git_version(None), Check::Fail { .. }));
+        assert!(matches!(
+            check_git_version(Some("2.11.0")),
+            Check::Fail { .. }
+        ));
+        assert!(matches!(
+            check_git_version(Some("2.30.1")),
+            Check::Warn { .. }
+        ));
+        assert!(matches!(check_git_version(Some("2.47.1")), Check::Pass(_)));
+    }
+
+    #[test]
+    fn test_forge_check() {
+        assert!(matches!(forge_check("gh", "", true, true), Check::Pass(_)));
+        let Check::Warn { fix, .. } = forge_check("gh", "", true, false) else {
+            panic!("expected warning");
+        };
+        assert!(fix.contains("gh auth login"));
+        let Check::Warn { fix, .. } = forge_check("glab", "https://example.com", false, false)
+        else {
+            panic!("expected warning");
+        };
+        assert!(fix.contains("https://example.com"));
+    }
+
+    #[test]
+    fn test_check_config_file() {
+        let check = check_config_file::<UserConfig>(
+            "config.toml",
+            Ok("worktree-path = \"../{{ branch }}\"\n".into()),
+            find_unknown_user_keys,
+        );
+        assert!(matches!(check, Check::Pass(_)));
+
+        let check = check_config_file::<UserConfig>(
+            "config.toml",
+            Ok("worktree-path = [\n".into()),
+            find_unknown_user_keys,
+        );
+        assert!(matches!(check, Check::Fail { .. }));
+
+        let check = check_config_file::<UserConfig>(
+            "config.toml",
+            Ok("not-a-key = 1\n".into()),
+            find_unknown_user_keys,
+        );
+        assert!(matches!(check, Check::Warn { .. }));
+    }
+}
@@ -0,0 +1,341 @@
+//! Done command handler.
+//!
+//! `wt done` is the counterpart to `wt start`: commit, integrate the branch
+//! (local merge or PR/MR), optionally wait for CI, remove the worktree, and
+//! summarize. Each step keeps the guardrails of the command it reuses —
+//! failed CI keeps the worktree, and unmerged branches are never deleted.
+
+use std::thread;
+use std::time::{Duration, Instant};
+
+use anyhow::Context;
+use color_print::cformat;
+use worktrunk::HookType;
+use worktrunk::config::{DoneConfig, FinishMode, UserConfig};
+use worktrunk::git::{GitError, Repository};
+use worktrunk::styling::{eprintln, info_message, progress_message, success_message};
+
+use super::command_approval::approve_hooks;
+use super::context::CommandEnv;
+use super::forge::{
+    PrContent, create_pr, detect_platform, find_open_pr, mark_ready, push_with_upstream,
+};
+use super::list::ci_status::{CiBranchName, CiStatus, PrStatus};
+use super::merge::{MergeOptions, handle_merge};
+use super::step_commands::step_commit;
+use super::worktree::handle_remove_current;
+use crate::output::{Progress, handle_remove_output};
+
+/// Interval between CI status checks while waiting.
+const CI_POLL_INTERVAL: Duration = Duration::from_secs(15);
+
+/// How long "no CI" counts as "not started yet" after a push. Right after a
+/// push or PR create the check rollup is empty even when workflows exist.
+const CI_NO_CHECKS_GRACE: Duration = Duration::from_secs(120);
+
+/// Options for the done command
+pub struct DoneOptions<'a> {
+    /// Merge target (merge mode). Defaults to the default branch.
+    pub target: Option<&'a str>,
+    /// CLI override for `[done] finish`
+    pub finish: Option<FinishMode>,
+    /// CLI override for `[done] wait-ci`
+    pub wait_ci: Option<bool>,
+    /// CLI override for `[done] remove`
+    pub remove: Option<bool>,
+    pub yes: bool,
+    pub verify: bool,
+}
+
+/// Handle the done command.
+pub fn handle_done(opts: DoneOptions<'_>, config: &UserConfig) -> anyhow::Result<()> {
+    let DoneOptions {
+        target,
+        finish,
+        wait_ci,
+        remove,
+        yes,
+        verify,
+    } = opts;
+
+    let repo = Repository::current().context("Failed to finish work")?;
+    let branch = repo.require_current_branch("finish")?;
+    let project_id = repo.project_identifier().ok();
+    let done_config = config.done(project_id.as_deref()).unwrap_or_default();
+
+    let finish = finish.unwrap_or_else(|| done_config.finish());
+    let wait_ci = wait_ci.unwrap_or_else(|| done_config.wait_ci());
+    let remove = remove.unwrap_or_else(|| done_config.remove());
+
+    match finish {
+        FinishMode::Merge => {
+            if wait_ci {
+                return Err(GitError::Other {
+                    message: "--wait-ci needs the PR flow; use --pr or set finish = \"pr\"".into(),
+                }
+                .into());
+            }
+            let target = target
+                .map(str::to_string)
+                .or_else(|| repo.default_branch())
+                .unwrap_or_default();
+            // `wt merge` commits, squashes, rebases, merges, and removes
+            handle_merge(MergeOptions {
+                target: (!target.is_empty()).then_some(target.as_str()),
+                squash: None,
+                commit: None,
+                rebase: None,
+                remove: Some(remove),
+                verify: Some(verify),
+                yes,
+                stage: None,
+            })?;
+            // Removal is reported by `wt merge` itself (it keeps the main worktree)
+            print_summary(&branch, &[cformat!("merged into <bold>{target}</>")]);
+        }
+        FinishMode::Pr => {
+            if target.is_some() {
+                return Err(GitError::Other {
+                    message: "A merge target can't be used with --pr; the PR/MR targets the \
+                              repository default"
+                        .into(),
+                }
+                .into());
+            }
+            finish_with_pr(
+                &repo,
+                config,
+                PrFinish {
+                    branch: &branch,
+                    wait_ci,
+                    remove,
+                    yes,
+                    verify,
+                    done_config: &done_config,
+                },
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Resolved settings for the PR flow
+struct PrFinish<'a> {
+    branch: &'a str,
+    wait_ci: bool,
+    remove: bool,
+    yes: bool,
+    verify: bool,
+    done_config: &'a DoneConfig,
+}
+
+/// PR flow: commit, push, open or mark ready, wait for CI, clean up.
+fn finish_with_pr(
+    repo: &Repository,
+    config: &UserConfig,
+    opts: PrFinish<'_>,
+) -> anyhow::Result<()> {
+    let PrFinish {
+        branch,
+        wait_ci,
+        remove,
+        yes,
+        verify,
+        done_config,
+    } = opts;
+    if repo.default_branch().as_deref() == Some(branch) {
+        return Err(GitError::Other {
+            message: cformat!("Cannot open a PR from the default branch <bold>{branch}</>"),
+        }
+        .into());
+    }
+    let platform = detect_platform(repo)?;
+    let worktree_path = repo.current_worktree().root()?;
+    let mut summary = Vec::new();
+
+    // Commit: same as `wt step commit` (LLM message when configured)
+    if repo.current_worktree().is_dirty()? {
+        step_commit(yes, !verify, None, false)?;
+        summary.push("committed changes".to_string());
+    }
+
+    let project_id = repo.project_identifier().ok();
+    let push_config = config.push(project_id.as_deref()).unwrap_or_default();
+    push_with_upstream(repo, &worktree_path, branch, &push_config, yes)?;
+
+    // Reuse the draft from `wt start --draft-pr` if there is one
+    let url = match find_open_pr(platform, &worktree_path, branch)? {
+        Some(pr) if pr.draft => {
+            mark_ready(platform, &worktree_path, branch)?;
+            summary.push(format!("marked ready {}", pr.url));
+            pr.url
+        }
+        Some(pr) => {
+            summary.push(format!("updated {}", pr.url));
+            pr.url
+        }
+        None => {
+            let url = create_pr(platform, &worktree_path, branch, PrContent::default())?;
+            summary.push(format!("opened {url}"));
+            url
+        }
+    };
+    eprintln!(
+        "{}",
+        success_message(cformat!("PR/MR for <bold>{branch}</> is ready: {url}"))
+    );
+
+    if wait_ci {
+        let timeout = Duration::from_secs(done_config.ci_timeout());
+        let status = wait_for_ci(repo, branch, timeout)?;
+        summary.push(format!("CI {}", <&'static str>::from(status)));
+    }
+
+    if remove {
+        let result = handle_remove_current(false, false, false, config)
+            .context("Failed to remove worktree")?;
+        let run_hooks = verify && {
+            let env = CommandEnv::for_action_branchless()?;
+            let ctx = env.context(yes);
+            let approved = approve_hooks(
+                &ctx,
+                &[
+                    HookType::PreRemove,
+                    HookType::PostRemove,
+                    HookType::PostSwitch,
+                ],
+            )?;
+            if !approved {
+                eprintln!("{}", info_message("Commands declined, continuing removal"));
+            }
+            approved
+        };
+        handle_remove_output(&result, true, run_hooks)?;
+        summary.push("worktree removed".into());
+    }
+
+    print_summary(branch, &summary);
+    Ok(())
+}
+
+/// Poll CI until it settles. Passing (or no CI configured) returns the status;
+/// failures and timeouts are errors so cleanup doesn't run.
+///
+/// Every poll bypasses the CI status cache, and "no CI" only counts once
+/// [`CI_NO_CHECKS_GRACE`] has passed without any checks reporting.
+fn wait_for_ci(repo: &Repository, branch: &str, timeout: Duration) -> anyhow::Result<CiStatus> {
+    let head = repo
+        .current_worktree()
+        .run_command(&["rev-parse", "HEAD"])?
+        .trim()
+        .to_string();
+    let ci_branch = CiBranchName::from_branch_ref(branch, false, repo);
+
+    eprintln!(
+        "{}",
+        progress_message(cformat!("Waiting for CI on <bold>{branch}</>..."))
+    );
+    let start = Instant::now();
+    // The line above names the wait; the spinner only tracks its status
+    let progress = Progress::spinner("CI pending");
+    loop {
+        let status = PrStatus::detect_fresh(repo, &ci_branch, &head).map(|s| s.ci_status);
+        let label: &'static str = status.map_or("pending", Into::into);
+        progress.set_message(format!("CI {label} ({}s)", start.elapsed().as_secs()));
+        match ci_wait_outcome(status, start.elapsed()) {
+            CiWait::Done(status) => return Ok(status),
+            CiWait::Failed(status) => {
+                let status: &'static str = status.into();
+                return Err(GitError::Other {
+                    message: cformat!("CI {status} for <bold>{branch}</>; keeping the worktree"),
+                }
+                .into());
+            }
+            CiWait::Pending => {}
+        }
+        if start.elapsed() >= timeout {
+            return Err(GitError::Other {
+                message: cformat!(
+                    "Timed out after {}s waiting for CI on <bold>{branch}</>; keeping the worktree",
+                    timeout.as_secs()
+                ),
+            }
+            .into());
+        }
+        thread::sleep(CI_POLL_INTERVAL);
+    }
+}
+
+/// What to do after one CI status check.
+#[derive(Debug, PartialEq, Eq)]
+enum CiWait {
+    Done(CiStatus),
+    Failed(CiStatus),
+    Pending,
+}
+
+fn ci_wait_outcome(status: Option<CiStatus>, elapsed: Duration) -> CiWait {
+    match status {
+        Some(CiStatus::Passed) => CiWait::Done(CiStatus::Passed),
+        // Checks for a fresh push may not have registered yet
+        Some(CiStatus::NoCI) if elapsed < CI_NO_CHECKS_GRACE => CiWait::Pending,
+        Some(CiStatus::NoCI) => CiWait::Done(CiStatus::NoCI),
+        Some(status @ (CiStatus::Failed | CiStatus::Conflicts)) => CiWait::Failed(status),
+        // Not reported yet (checks not started), running, or a transient fetch error
+        None | Some(CiStatus::Running | CiStatus::Error) => CiWait::Pending,
+    }
+}
+
+fn print_summary(branch: &str, steps: &[String]) {
+    let detail = if steps.is_empty() {
+        String::new()
+    } else {
+        format!(": {}", steps.join(", "))
+    };
+    eprintln!(
+        "{}",
+        success_message(cformat!("Done with <bold>{branch}</>{detail}"))
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ci_wait_outcome() {
+        let now = Duration::ZERO;
+        assert_eq!(
+            ci_wait_outcome(Some(CiStatus::Passed), now),
+            CiWait::Done(CiStatus::Passed)
+        );
+        assert_eq!(
+            ci_wait_outcome(Some(CiStatus::Failed), now),
+            CiWait::Failed(CiStatus::Failed)
+        );
+        assert_eq!(
+            ci_wait_outcome(Some(CiStatus::Conflicts), now),
+            CiWait::Failed(CiStatus::Conflicts)
+        );
+        assert_eq!(
+            ci_wait_outcome(Some(CiStatus::Running), now),
+            CiWait::Pending
+        );
+        assert_eq!(ci_wait_outcome(Some(CiStatus::Error), now), CiWait::Pending);
+        assert_eq!(ci_wait_outcome(None, now), CiWait::Pending);
+    }
+
+    #[test]
+    fn test_ci_wait_outcome_no_ci_grace() {
+        // An empty rollup right after the push means checks haven't started
+        assert_eq!(
+            ci_wait_outcome(Some(CiStatus::NoCI), Duration::from_secs(30)),
+            CiWait::Pending
+        );
+        assert_eq!(
+            ci_wait_outcome(Some(CiStatus::NoCI), CI_NO_CHECKS_GRACE),
+            CiWait::Done(CiStatus::NoCI)
+        );
+    }
+}
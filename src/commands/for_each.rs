@@ -35,7 +35,7 @@ use worktrunk::styling::{
 };
 
 use crate::commands::command_executor::{CommandContext, build_hook_context};
-use crate::commands::worktree_display_name;
+use crate::commands::{group_branches, worktree_display_name};
 
 /// Run a command in each worktree sequentially.
 ///
@@ -43,13 +43,22 @@ use crate::commands::worktree_display_name;
 /// in real-time. Continues on errors and reports a summary at the end.
 ///
 /// All template variables from hooks are available, and context JSON is piped to stdin.
-pub fn step_for_each(args: Vec<String>) -> anyhow::Result<()> {
+/// With `group`, only worktrees whose branch is in that group are visited.
+pub fn step_for_each(group: Option<&str>, args: Vec<String>) -> anyhow::Result<()> {
     let repo = Repository::current()?;
+    let members = group.map(|name| group_branches(&repo, name)).transpose()?;
     // Filter out prunable worktrees (directory deleted) - can't run commands there
     let worktrees: Vec<_> = repo
         .list_worktrees()?
         .into_iter()
         .filter(|wt| !wt.is_prunable())
+        .filter(|wt| {
+            members.as_ref().is_none_or(|members| {
+                wt.branch
+                    .as_ref()
+                    .is_some_and(|branch| members.contains(branch))
+            })
+        })
         .collect();
     let config = UserConfig::load()?;
 
@@ -0,0 +1,353 @@
+//! Forge (GitHub/GitLab) operations shared by `wt start`, `wt done` and
+//! `wt stack submit`.
+//!
+//! Pull/merge requests are managed through the platform CLIs (`gh`, `glab`),
+//! the same tools used for CI status, with any token stored by `wt auth login`
+//! in their environment. Platform detection honors the project's
+//! `ci.platform` override.
+
+use std::io::ErrorKind;
+use std::path::Path;
+use std::process::Output;
+
+use anyhow::{Context, bail};
+use color_print::cformat;
+use serde::Deserialize;
+use worktrunk::config::{PushConfig, SetUpstream};
+use worktrunk::forge::forge_cli;
+use worktrunk::git::{GitError, Repository};
+use worktrunk::shell_exec::Cmd;
+use worktrunk::styling::{
+    eprintln, info_message, progress_message, success_message, warning_message,
+};
+
+use super::list::ci_status::{CiPlatform, get_platform_for_repo};
+use crate::output::Progress;
+use crate::output::prompt::{PromptResponse, assume_yes, can_prompt, prompt_yes_no_preview};
+
+/// Detect the forge platform, honoring the project's `ci.platform` override.
+pub(crate) fn detect_platform(repo: &Repository) -> anyhow::Result<CiPlatform> {
+    let project_config = repo.load_project_config()?;
+    let platform_override = project_config.as_ref().and_then(|c| c.ci_platform());
+    get_platform_for_repo(repo, platform_override, None).ok_or_else(|| {
+        GitError::Other {
+            message: "Could not detect GitHub or GitLab from remotes; \
+                      set ci.platform in .config/wt.toml"
+                .into(),
+        }
+        .into()
+    })
+}
+
+/// Base command for a platform CLI, with prompts disabled.
+pub(crate) fn forge_cmd(platform: CiPlatform) -> Cmd {
+    match platform {
+        CiPlatform::GitHub => forge_cli("gh").env("GH_PROMPT_DISABLED", "1"),
+        CiPlatform::GitLab => forge_cli("glab"),
+    }
+}
+
+fn tool_name(platform: CiPlatform) -> &'static str {
+    match platform {
+        CiPlatform::GitHub => "gh",
+        CiPlatform::GitLab => "glab",
+    }
+}
+
+/// Run a platform CLI command, turning a missing tool or non-zero exit into
+/// an error that names `action`.
+pub(crate) fn run_forge(platform: CiPlatform, cmd: Cmd, action: &str) -> anyhow::Result<Output> {
+    let tool = tool_name(platform);
+    let progress = Progress::spinner(format!("Waiting for {tool}..."));
+    let output = cmd.run();
+    progress.finish();
+    let output = match output {
+        Ok(output) => output,
+        Err(e) if e.kind() == ErrorKind::NotFound => {
+            bail!("{tool} not installed; needed to {action}")
+        }
+        Err(e) => return Err(anyhow::Error::from(e).context(format!("Failed to run {tool}"))),
+    };
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(GitError::Other {
+            message: format!("Failed to {action}: {}", stderr.trim()),
+        }
+        .into());
+    }
+    Ok(output)
+}
+
+/// Push `branch` to the primary remote, setting it as upstream when the
+/// branch has none and `[push] set-upstream` allows it.
+pub(crate) fn push_with_upstream(
+    repo: &Repository,
+    worktree_path: &Path,
+    branch: &str,
+    push_config: &PushConfig,
+    yes: bool,
+) -> anyhow::Result<()> {
+    let remote = repo.primary_remote()?;
+    let mut args = vec!["push".to_string()];
+    let set_upstream = repo.branch(branch).upstream()?.is_none()
+        && confirm_set_upstream(push_config.set_upstream(), &remote, branch, yes)?;
+    if set_upstream {
+        args.push("--set-upstream".into());
+    }
+
+    // A rebased or squashed branch no longer contains what was pushed before.
+    // Force with a lease on the remote-tracking ref, and require (via
+    // `--force-if-includes`) that its tip is in the branch's reflog: a
+    // background fetch can move the tracking ref to commits someone else
+    // pushed, which a bare lease would then overwrite.
+    if let Some(lease) = repo.rewritten_remote_branch(&remote, branch)? {
+        if repo.is_protected_branch(branch) {
+            return Err(GitError::ProtectedBranch {
+                branch: branch.to_string(),
+                action: "force-push".into(),
+                force_hint: false,
+            }
+            .into());
+        }
+        eprintln!(
+            "{}",
+            warning_message(cformat!(
+                "<bold>{remote}/{branch}</> has commits no longer on <bold>{branch}</> (rewritten by rebase or squash); force-pushing with lease on <bold>{}</>",
+                &lease[..7.min(lease.len())]
+            ))
+        );
+        args.push(format!("--force-with-lease=refs/heads/{branch}"));
+        args.push("--force-if-includes".into());
+    } else {
+        eprintln!(
+            "{}",
+            progress_message(cformat!(
+                "Pushing <bold>{branch}</> to <bold>{remote}</>..."
+            ))
+        );
+    }
+    args.push(remote.clone());
+    args.push(branch.to_string());
+
+    let progress = Progress::spinner(format!("Waiting for {remote}..."));
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    repo.worktree_at(worktree_path).run_remote_command(&args)?;
+    progress.finish();
+
+    let message = if set_upstream {
+        cformat!(
+            "Pushed <bold>{branch}</> to <bold>{remote}</>, tracking <bold>{remote}/{branch}</>"
+        )
+    } else {
+        cformat!("Pushed <bold>{branch}</> to <bold>{remote}</>")
+    };
+    eprintln!("{}", success_message(message));
+    Ok(())
+}
+
+/// Whether to set `<remote>/<branch>` as upstream of a branch that has none.
+///
+/// `ask` prompts when interactive; `--yes` and non-interactive runs set it,
+/// since the PR/MR flows expect tracking.
+fn confirm_set_upstream(
+    mode: SetUpstream,
+    remote: &str,
+    branch: &str,
+    yes: bool,
+) -> anyhow::Result<bool> {
+    match mode {
+        SetUpstream::Always => Ok(true),
+        SetUpstream::Never => Ok(false),
+        SetUpstream::Ask if yes || assume_yes() || !can_prompt() => Ok(true),
+        SetUpstream::Ask => {
+            let response = prompt_yes_no_preview(
+                &cformat!("<bold>{branch}</> has no upstream. Track <bold>{remote}/{branch}</>?"),
+                || {
+                    eprintln!(
+                        "{}",
+                        info_message(cformat!(
+                            "Runs <bright-black>git push --set-upstream {remote} {branch}</>; set <bright-black>[push] set-upstream</> to always or never to skip this prompt"
+                        ))
+                    );
+                },
+            )?;
+            Ok(response == PromptResponse::Accepted)
+        }
+    }
+}
+
+/// Content of a new PR/MR. Without a title, the CLI fills it from commits.
+#[derive(Debug, Default)]
+pub(crate) struct PrContent<'a> {
+    pub draft: bool,
+    pub title: Option<&'a str>,
+    pub body: Option<&'a str>,
+    /// Branch to merge into; the repository's default branch when unset
+    pub base: Option<&'a str>,
+}
+
+/// Open a PR/MR for `branch`. Returns its URL.
+pub(crate) fn create_pr(
+    platform: CiPlatform,
+    worktree_path: &Path,
+    branch: &str,
+    content: PrContent<'_>,
+) -> anyhow::Result<String> {
+    let mut cmd = match platform {
+        CiPlatform::GitHub => forge_cmd(platform).args(["pr", "create", "--head", branch]),
+        CiPlatform::GitLab => {
+            forge_cmd(platform).args(["mr", "create", "--yes", "--source-branch", branch])
+        }
+    };
+    if content.draft {
+        cmd = cmd.arg("--draft");
+    }
+    if let Some(base) = content.base {
+        let base_flag = match platform {
+            CiPlatform::GitHub => "--base",
+            CiPlatform::GitLab => "--target-branch",
+        };
+        cmd = cmd.args([base_flag, base]);
+    }
+    cmd = match content.title {
+        Some(title) => {
+            let body_flag = match platform {
+                CiPlatform::GitHub => "--body",
+                CiPlatform::GitLab => "--description",
+            };
+            cmd.args(["--title", title, body_flag, content.body.unwrap_or("")])
+        }
+        None => cmd.arg("--fill"),
+    };
+
+    let output = run_forge(
+        platform,
+        cmd.current_dir(worktree_path),
+        &format!("open a PR/MR for {branch}"),
+    )?;
+    // Both CLIs print the new PR/MR URL as the last line of stdout
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    Ok(stdout.lines().last().unwrap_or_default().trim().to_string())
+}
+
+/// An open PR/MR for a branch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct OpenPr {
+    pub url: String,
+    pub draft: bool,
+    pub body: String,
+}
+
+/// Find the open PR/MR for `branch`, if any.
+pub(crate) fn find_open_pr(
+    platform: CiPlatform,
+    worktree_path: &Path,
+    branch: &str,
+) -> anyhow::Result<Option<OpenPr>> {
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct GhPr {
+        url: String,
+        is_draft: bool,
+        state: String,
+        #[serde(default)]
+        body: String,
+    }
+    #[derive(Deserialize)]
+    struct GlabMr {
+        web_url: String,
+        #[serde(default)]
+        draft: bool,
+        state: String,
+        #[serde(default)]
+        description: Option<String>,
+    }
+
+    let cmd = match platform {
+        CiPlatform::GitHub => {
+            forge_cmd(platform).args(["pr", "view", branch, "--json", "url,isDraft,state,body"])
+        }
+        CiPlatform::GitLab => forge_cmd(platform).args(["mr", "view", branch, "--output", "json"]),
+    };
+    let tool = tool_name(platform);
+    let output = match cmd.current_dir(worktree_path).run() {
+        Ok(output) => output,
+        Err(e) if e.kind() == ErrorKind::NotFound => {
+            bail!("{tool} not installed; needed to find the PR/MR for {branch}")
+        }
+        Err(e) => return Err(anyhow::Error::from(e).context(format!("Failed to run {tool}"))),
+    };
+    // Both CLIs exit non-zero when no PR/MR exists for the branch
+    if !output.status.success() {
+        return Ok(None);
+    }
+
+    let context = || format!("Failed to parse {tool} response");
+    let pr = match platform {
+        CiPlatform::GitHub => {
+            let pr: GhPr = serde_json::from_slice(&output.stdout).with_context(context)?;
+            (pr.state == "OPEN").then_some(OpenPr {
+                url: pr.url,
+                draft: pr.is_draft,
+                body: pr.body,
+            })
+        }
+        CiPlatform::GitLab => {
+            let mr: GlabMr = serde_json::from_slice(&output.stdout).with_context(context)?;
+            (mr.state == "opened").then_some(OpenPr {
+                url: mr.web_url,
+                draft: mr.draft,
+                body: mr.description.unwrap_or_default(),
+            })
+        }
+    };
+    Ok(pr)
+}
+
+/// Mark the draft PR/MR for `branch` as ready for review.
+pub(crate) fn mark_ready(
+    platform: CiPlatform,
+    worktree_path: &Path,
+    branch: &str,
+) -> anyhow::Result<()> {
+    let cmd = match platform {
+        CiPlatform::GitHub => forge_cmd(platform).args(["pr", "ready", branch]),
+        CiPlatform::GitLab => forge_cmd(platform).args(["mr", "update", branch, "--ready"]),
+    };
+    run_forge(
+        platform,
+        cmd.current_dir(worktree_path),
+        &format!("mark the PR/MR for {branch} ready"),
+    )?;
+    Ok(())
+}
+
+/// Retarget the open PR/MR for `branch` onto `base` and replace its description.
+pub(crate) fn update_pr(
+    platform: CiPlatform,
+    worktree_path: &Path,
+    branch: &str,
+    base: &str,
+    body: &str,
+) -> anyhow::Result<()> {
+    let cmd = match platform {
+        CiPlatform::GitHub => {
+            forge_cmd(platform).args(["pr", "edit", branch, "--base", base, "--body", body])
+        }
+        CiPlatform::GitLab => forge_cmd(platform).args([
+            "mr",
+            "update",
+            branch,
+            "--target-branch",
+            base,
+            "--description",
+            body,
+        ]),
+    };
+    run_forge(
+        platform,
+        cmd.current_dir(worktree_path),
+        &format!("update the PR/MR for {branch}"),
+    )?;
+    Ok(())
+}
@@ -0,0 +1,148 @@
+//! Frecency (frequency + recency) ranking of worktrees, in the style of zoxide.
+//!
+//! Every switch bumps the target worktree's rank. Scores weight the rank by
+//! how recently it was visited, so a worktree used constantly last month
+//! drops below one used a few times today. The interactive picker and
+//! `wt recent` order worktrees by score, and `wt jump` resolves a partial name
+//! to the best match.
+//!
+//! The history is the `access` map of the repository's state store
+//! (`.git/worktrunk/state.json`), shared by all worktrees. Entries are keyed
+//! by worktree path; paths that no longer exist are dropped on the next write.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use worktrunk::state::{AccessEntry, StateStore};
+use worktrunk::utils::get_now;
+
+/// Total rank above which all entries are aged, keeping the history small
+/// and letting old favourites fade.
+const MAX_TOTAL_RANK: f64 = 1000.0;
+
+const HOUR: u64 = 60 * 60;
+const DAY: u64 = 24 * HOUR;
+const WEEK: u64 = 7 * DAY;
+
+/// Visit history for a repository's worktrees.
+#[derive(Debug, Default)]
+pub struct Frecency {
+    entries: BTreeMap<PathBuf, AccessEntry>,
+}
+
+impl Frecency {
+    /// Load the history, treating an unreadable state store as empty.
+    pub fn load(git_common_dir: &Path) -> Self {
+        let entries = StateStore::new(git_common_dir)
+            .load()
+            .map(|state| state.access)
+            .unwrap_or_default();
+        Self { entries }
+    }
+
+    /// Score for a worktree path; 0.0 if never visited.
+    pub fn score(&self, path: &Path) -> f64 {
+        self.entries
+            .get(path)
+            .map_or(0.0, |entry| entry_score(entry, get_now()))
+    }
+
+    /// Unix timestamp of the latest visit to a worktree path, if any.
+    pub fn last_visit(&self, path: &Path) -> Option<u64> {
+        self.entries.get(path).map(|entry| entry.last_accessed)
+    }
+}
+
+/// Bump `path`'s rank, aging every entry once the total grows too large.
+fn visit(entries: &mut BTreeMap<PathBuf, AccessEntry>, path: &Path, now: u64) {
+    let entry = entries.entry(path.to_path_buf()).or_insert(AccessEntry {
+        rank: 0.0,
+        last_accessed: now,
+    });
+    entry.rank += 1.0;
+    entry.last_accessed = now;
+
+    let total: f64 = entries.values().map(|e| e.rank).sum();
+    if total > MAX_TOTAL_RANK {
+        for entry in entries.values_mut() {
+            entry.rank *= 0.9;
+        }
+        entries.retain(|_, entry| entry.rank >= 1.0);
+    }
+}
+
+/// Record a visit to `path`.
+///
+/// Best-effort: the ranking is a convenience, so failures to read or write
+/// the state store never fail the switch.
+pub fn record_visit(git_common_dir: &Path, path: &Path) {
+    let result = StateStore::new(git_common_dir).update(|state| {
+        visit(&mut state.access, path, get_now());
+        state.access.retain(|path, _| path.exists());
+    });
+    if let Err(e) = result {
+        log::debug!("Failed to record visit: {e:#}");
+    }
+}
+
+/// zoxide's weighting: recent visits count up to 4x, stale ones a quarter.
+fn entry_score(entry: &AccessEntry, now: u64) -> f64 {
+    let age = now.saturating_sub(entry.last_accessed);
+    let weight = if age < HOUR {
+        4.0
+    } else if age < DAY {
+        2.0
+    } else if age < WEEK {
+        0.5
+    } else {
+        0.25
+    };
+    entry.rank * weight
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recent_visits_outrank_old_favourites() {
+        let now = 10 * WEEK;
+        let mut db = Frecency::default();
+        for _ in 0..5 {
+            visit(&mut db.entries, Path::new("/repo.old"), now - 2 * WEEK);
+        }
+        visit(&mut db.entries, Path::new("/repo.new"), now);
+        visit(&mut db.entries, Path::new("/repo.new"), now);
+
+        let old = entry_score(&db.entries[Path::new("/repo.old")], now);
+        let new = entry_score(&db.entries[Path::new("/repo.new")], now);
+        assert_eq!(old, 1.25);
+        assert_eq!(new, 8.0);
+    }
+
+    #[test]
+    fn test_aging_drops_rarely_used_entries() {
+        let mut db = Frecency::default();
+        db.entries.insert(
+            PathBuf::from("/repo.rare"),
+            AccessEntry {
+                rank: 1.0,
+                last_accessed: 0,
+            },
+        );
+        db.entries.insert(
+            PathBuf::from("/repo.busy"),
+            AccessEntry {
+                rank: MAX_TOTAL_RANK,
+                last_accessed: 0,
+            },
+        );
+        visit(&mut db.entries, Path::new("/repo.busy"), 1);
+
+        assert!(!db.entries.contains_key(Path::new("/repo.rare")));
+        assert_eq!(
+            db.entries[Path::new("/repo.busy")].rank,
+            (MAX_TOTAL_RANK + 1.0) * 0.9
+        );
+    }
+}
@@ -0,0 +1,64 @@
+//! `wt graph` — commit graph of the worktree branches.
+//!
+//! `git log --graph` limited to the branches checked out in worktrees plus the
+//! default branch, starting just above the commit they all share. That
+//! commit is drawn as the boundary, so each branch's divergence from the
+//! others is visible without the rest of the repository's history.
+
+use color_print::cformat;
+use worktrunk::git::Repository;
+use worktrunk::styling::{eprintln, info_message, println};
+
+/// Handle `wt graph`.
+pub(crate) fn handle_graph(compact: bool) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let mut branches: Vec<String> = repo.default_branch().into_iter().collect();
+    for worktree in repo.list_worktrees()? {
+        if let Some(branch) = worktree.branch
+            && !branches.contains(&branch)
+        {
+            branches.push(branch);
+        }
+    }
+    if branches.len() < 2 {
+        eprintln!(
+            "{}",
+            info_message(cformat!(
+                "Nothing to graph; create a worktree with <bright-black>wt switch --create</>"
+            ))
+        );
+        return Ok(());
+    }
+
+    let refs: Vec<String> = branches.iter().map(|b| format!("refs/heads/{b}")).collect();
+    let mut base_args = vec!["merge-base", "--octopus"];
+    base_args.extend(refs.iter().map(String::as_str));
+    let base = repo.run_command(&base_args).ok();
+
+    let decorate: Vec<String> = refs
+        .iter()
+        .map(|r| format!("--decorate-refs={r}"))
+        .collect();
+    let mut args = vec![
+        "log",
+        "--graph",
+        "--color=always",
+        "--format=%C(auto)%h%d %s %C(dim)(%cr)%C(reset)",
+    ];
+    args.extend(decorate.iter().map(String::as_str));
+    if compact {
+        args.push("--simplify-by-decoration");
+    }
+    args.extend(refs.iter().map(String::as_str));
+    // Unrelated histories have no shared base; the full graph is shown then
+    let exclude = base.as_deref().map(|base| format!("^{}", base.trim()));
+    if let Some(exclude) = &exclude {
+        args.extend(["--boundary", exclude.as_str()]);
+    }
+    let graph = repo.run_command(&args)?;
+    // anstream drops the colors when stdout isn't a terminal
+    for line in graph.lines() {
+        println!("{line}");
+    }
+    Ok(())
+}
@@ -0,0 +1,91 @@
+//! `wt group` — named sets of branches kept in the state store.
+//!
+//! Groups only record branch names; `wt step for-each --group` resolves them
+//! to worktrees at run time, so a branch can join a group before its worktree
+//! exists.
+
+use std::collections::BTreeSet;
+
+use color_print::cformat;
+use worktrunk::git::{GitError, Repository};
+use worktrunk::state::StateStore;
+use worktrunk::styling::{eprintln, info_message, success_message};
+
+pub(crate) fn handle_group_add(name: &str, branches: &[String]) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let store = StateStore::new(repo.git_common_dir());
+    let size = store.update(|state| {
+        let group = state.groups.entry(name.to_string()).or_default();
+        group.extend(branches.iter().cloned());
+        group.len()
+    })?;
+    eprintln!(
+        "{}",
+        success_message(cformat!(
+            "Group <bold>{name}</> has {size} branch{}",
+            if size == 1 { "" } else { "es" }
+        ))
+    );
+    Ok(())
+}
+
+pub(crate) fn handle_group_remove(name: &str, branches: &[String]) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let store = StateStore::new(repo.git_common_dir());
+    let found = store.update(|state| {
+        let Some(group) = state.groups.get_mut(name) else {
+            return false;
+        };
+        for branch in branches {
+            group.remove(branch);
+        }
+        if branches.is_empty() || group.is_empty() {
+            state.groups.remove(name);
+        }
+        true
+    })?;
+    if !found {
+        return Err(unknown_group(name).into());
+    }
+    let message = if branches.is_empty() {
+        cformat!("Removed group <bold>{name}</>")
+    } else {
+        cformat!("Removed {} from group <bold>{name}</>", branches.join(", "))
+    };
+    eprintln!("{}", success_message(message));
+    Ok(())
+}
+
+pub(crate) fn handle_group_list() -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let state = StateStore::new(repo.git_common_dir()).load()?;
+    if state.groups.is_empty() {
+        eprintln!(
+            "{}",
+            info_message(cformat!(
+                "No groups; create one with <bright-black>wt group add</>"
+            ))
+        );
+        return Ok(());
+    }
+    for (name, branches) in &state.groups {
+        let branches: Vec<_> = branches.iter().map(String::as_str).collect();
+        println!("{name}: {}", branches.join(" "));
+    }
+    Ok(())
+}
+
+/// Branches in the named group; errors if the group doesn't exist.
+pub(crate) fn group_branches(repo: &Repository, name: &str) -> anyhow::Result<BTreeSet<String>> {
+    let mut state = StateStore::new(repo.git_common_dir()).load()?;
+    state
+        .groups
+        .remove(name)
+        .ok_or_else(|| unknown_group(name).into())
+}
+
+fn unknown_group(name: &str) -> GitError {
+    GitError::Other {
+        message: cformat!("No group named <bold>{name}</>; see <bright-black>wt group list</>"),
+    }
+}
@@ -4,18 +4,25 @@ use std::collections::HashMap;
 use std::path::Path;
 
 use anyhow::Context;
+use color_print::cformat;
 use worktrunk::HookType;
 use worktrunk::config::{UserConfig, expand_template};
+use worktrunk::git::GitError;
 use worktrunk::git::Repository;
-use worktrunk::styling::{eprintln, info_message};
+use worktrunk::path::format_path_for_display;
+use worktrunk::styling::{
+    eprintln, format_bash_with_gutter, hint_message, info_message, progress_message,
+    success_message,
+};
 
 use super::command_approval::approve_hooks;
 use super::command_executor::{CommandContext, build_hook_context};
 use super::worktree::{
-    SwitchBranchInfo, SwitchPlan, SwitchResult, execute_switch, get_path_mismatch, plan_switch,
+    SwitchBranchInfo, SwitchPlan, SwitchResult, compute_worktree_path, execute_switch,
+    get_path_mismatch, paths_match, plan_switch,
 };
 use crate::output::{
-    execute_user_command, handle_switch_output, is_shell_integration_active,
+    change_directory, execute_user_command, handle_switch_output, is_shell_integration_active,
     prompt_shell_integration,
 };
 
@@ -24,8 +31,18 @@ pub struct SwitchOptions<'a> {
     pub branch: &'a str,
     pub create: bool,
     pub base: Option<&'a str>,
+    /// Sparse-checkout profile from project config (`--sparse`)
+    pub sparse: Option<&'a str>,
+    /// Create the branch with no history (`--orphan`)
+    pub orphan: bool,
+    /// Start a dev container for a new worktree (`--devcontainer`)
+    pub devcontainer: bool,
+    /// Treat `branch` as a tag or commit and check it out detached (`--detach`)
+    pub detach: bool,
     pub execute: Option<&'a str>,
     pub execute_args: &'a [String],
+    /// Open the worktree in an editor: `Some(None)` uses the configured editor
+    pub open: Option<Option<&'a str>>,
     pub yes: bool,
     pub clobber: bool,
     /// Whether to change directory after switching (default: true)
@@ -127,6 +144,109 @@ pub(crate) fn spawn_switch_background_hooks(
     super::hooks::spawn_background_hooks(&ctx, hooks)
 }
 
+/// Look up a `[sparse-profiles]` entry in the project config.
+fn sparse_profile_patterns(repo: &Repository, profile: &str) -> anyhow::Result<Vec<String>> {
+    let project_config = repo.load_project_config()?.unwrap_or_default();
+    if let Some(patterns) = project_config.sparse_profile(profile) {
+        return Ok(patterns.to_vec());
+    }
+    let available: Vec<&str> = project_config
+        .sparse_profiles
+        .keys()
+        .map(String::as_str)
+        .collect();
+    let message = if available.is_empty() {
+        cformat!(
+            "No sparse-checkout profile <bold>{profile}</>; define profiles under <bright-black>[sparse-profiles]</> in .config/wt.toml"
+        )
+    } else {
+        cformat!(
+            "No sparse-checkout profile <bold>{profile}</>; available: {}",
+            available.join(", ")
+        )
+    };
+    Err(GitError::Other { message }.into())
+}
+
+/// Create or reuse a detached worktree at a tag or commit (`wt switch --detach`).
+///
+/// The path comes from the worktree template with `target` in place of the
+/// branch. No hooks run: they're written for branches.
+fn switch_detached(
+    repo: &Repository,
+    config: &UserConfig,
+    target: &str,
+    change_dir: bool,
+) -> anyhow::Result<()> {
+    let commit = repo
+        .run_command(&[
+            "rev-parse",
+            "--verify",
+            "--quiet",
+            &format!("{target}^{{commit}}"),
+        ])
+        .map_err(|_| GitError::ReferenceNotFound {
+            reference: target.to_string(),
+        })?
+        .trim()
+        .to_string();
+    let path = compute_worktree_path(repo, target, config)?;
+    let path_display = format_path_for_display(&path);
+
+    let existing = repo
+        .list_worktrees()?
+        .into_iter()
+        .find(|wt| paths_match(&wt.path, &path));
+    match existing {
+        Some(wt) if wt.detached && wt.head == commit => {
+            eprintln!(
+                "{}",
+                info_message(cformat!(
+                    "Switched to detached worktree for <bold>{target}</> @ <bold>{path_display}</>"
+                ))
+            );
+        }
+        Some(_) => {
+            return Err(GitError::Other {
+                message: cformat!(
+                    "{path_display} is already a worktree, not at <bold>{target}</>; run <bright-black>wt remove</> inside it first"
+                ),
+            }
+            .into());
+        }
+        None if path.exists() => {
+            return Err(GitError::Other {
+                message: format!("{path_display} already exists"),
+            }
+            .into());
+        }
+        None => {
+            let path_str = path.to_string_lossy();
+            repo.run_command(&[
+                "worktree",
+                "add",
+                "--detach",
+                "--",
+                path_str.as_ref(),
+                &commit,
+            ])
+            .with_context(|| format!("Failed to create worktree for {target}"))?;
+            eprintln!(
+                "{}",
+                success_message(cformat!(
+                    "Created detached worktree for <bold>{target}</> @ <bold>{path_display}</>"
+                ))
+            );
+        }
+    }
+
+    super::frecency::record_visit(repo.git_common_dir(), &path);
+    if change_dir {
+        change_directory(&path)?;
+    }
+    Ok(())
+}
+
 /// Handle the switch command.
 pub fn handle_switch(
     opts: SwitchOptions<'_>,
@@ -137,8 +257,13 @@ pub fn handle_switch(
         branch,
         create,
         base,
+        sparse,
+        orphan,
+        devcontainer,
+        detach,
         execute,
         execute_args,
+        open,
         yes,
         clobber,
         change_dir,
@@ -146,9 +271,18 @@ pub fn handle_switch(
     } = opts;
 
     let repo = Repository::current().context("Failed to switch worktree")?;
+    if detach {
+        return switch_detached(&repo, config, branch, change_dir);
+    }
 
     // Validate FIRST (before approval) - fails fast if branch doesn't exist, etc.
-    let plan = plan_switch(&repo, branch, create, base, clobber, config)?;
+    let mut plan = plan_switch(&repo, branch, create, base, clobber, config)?;
+    if let Some(profile) = sparse {
+        plan.set_sparse(sparse_profile_patterns(&repo, profile)?);
+    }
+    if orphan {
+        plan.set_orphan();
+    }
 
     // "Approve at the Gate": collect and approve hooks upfront
     // This ensures approval happens once at the command entry point
@@ -163,6 +297,8 @@ pub fn handle_switch(
         return Ok(());
     }
 
+    super::frecency::record_visit(repo.git_common_dir(), result.path());
+
     // Compute path mismatch lazily (deferred from plan_switch for existing worktrees)
     let branch_info = match &result {
         SwitchResult::Existing { path } | SwitchResult::AlreadyAt(path) => {
@@ -182,16 +318,33 @@ pub fn handle_switch(
     let source_root = repo.current_worktree().root()?;
     let hooks_display_path =
         handle_switch_output(&result, &branch_info, change_dir, Some(&source_root), &cwd)?;
+    if orphan && matches!(result, SwitchResult::Created { .. }) {
+        eprintln!(
+            "{}",
+            hint_message(cformat!(
+                "<bold>{}</> has no commits yet; the first <bright-black>git commit</> in the worktree starts its history",
+                branch_info.branch
+            ))
+        );
+    }
 
     // Offer shell integration if not already installed/active
     // (only shows prompt/hint when shell integration isn't working)
     // With --execute: show hints only (don't interrupt with prompt)
     // Best-effort: don't fail switch if offer fails
     if !is_shell_integration_active() {
-        let skip_prompt = execute.is_some();
+        let skip_prompt = execute.is_some() || open.is_some();
         let _ = prompt_shell_integration(config, binary_name, skip_prompt);
     }
 
+    // Before hooks, so post-start commands can use the container
+    if devcontainer
+        && !worktrunk::dry_run::is_dry_run()
+        && let SwitchResult::Created { path, .. } = &result
+    {
+        super::devcontainer::up(&repo, &branch_info.branch, path)?;
+    }
+
     // Build extra vars for base branch context (used by both hooks and --execute)
     // "base" is the branch we branched from when creating a new worktree.
     // For existing worktrees, there's no base concept.
@@ -242,8 +395,89 @@ pub fn handle_switch(
                 .collect();
             format!("{} {}", expanded_cmd, escaped_args.join(" "))
         };
-        execute_user_command(&full_cmd, hooks_display_path.as_deref())?;
+        let timeout = config
+            .timeouts(repo.project_identifier().ok().as_deref())
+            .unwrap_or_default()
+            .execute();
+        execute_user_command(&full_cmd, hooks_display_path.as_deref(), timeout)?;
+    }
+
+    // Open the editor last: terminal editors take over the process, like --execute
+    // Note: --open conflicts with --execute via clap
+    if let Some(editor) = open {
+        let project_id = repo.project_identifier().ok();
+        let editor_config = config.editor(project_id.as_deref()).unwrap_or_default();
+        let Some(editor) = editor
+            .map(str::to_string)
+            .or_else(|| editor_config.command())
+        else {
+            return Err(GitError::Other {
+                message: cformat!(
+                    "No editor configured; pass <bold>--open=EDITOR</>, set <bold>[editor] command</>, or set $VISUAL"
+                ),
+            }
+            .into());
+        };
+        let command = editor_command(&editor, editor_config.reuse_window(), result.path());
+        eprintln!(
+            "{}",
+            progress_message(cformat!("Opening in <bold>{}</>:", editor_name(&editor)))
+        );
+        eprintln!("{}", format_bash_with_gutter(&command));
+        crate::output::execute(command, None)?;
     }
 
     Ok(())
 }
+
+/// Program name of an editor command (`code` for `/usr/bin/code --wait`).
+fn editor_name(editor: &str) -> String {
+    let program = shlex::split(editor)
+        .and_then(|words| words.into_iter().next())
+        .unwrap_or_else(|| editor.to_string());
+    Path::new(&program)
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or(program)
+}
+
+/// Build the editor invocation: editor, reuse-window flag, then the quoted path.
+///
+/// Only editors with a known "reuse window" flag get one; others always open
+/// however they normally do.
+fn editor_command(editor: &str, reuse_window: bool, path: &Path) -> String {
+    let reuse_flag = match editor_name(editor).as_str() {
+        "code" | "code-insiders" | "codium" | "cursor" | "windsurf" | "zed" => Some("-r"),
+        "subl" => Some("-a"),
+        _ => None,
+    };
+    let path = path.to_string_lossy();
+    let path = shlex::try_quote(&path).unwrap_or(path.as_ref().into());
+    match reuse_flag.filter(|_| reuse_window) {
+        Some(flag) => format!("{editor} {flag} {path}"),
+        None => format!("{editor} {path}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_editor_command() {
+        let path = Path::new("/repo/feature branch");
+        assert_eq!(
+            editor_command("code", true, path),
+            "code -r '/repo/feature branch'"
+        );
+        assert_eq!(
+            editor_command("code", false, path),
+            "code '/repo/feature branch'"
+        );
+        assert_eq!(
+            editor_command("nvim", true, path),
+            "nvim '/repo/feature branch'"
+        );
+        assert_eq!(editor_name("/usr/local/bin/zed --wait"), "zed");
+    }
+}
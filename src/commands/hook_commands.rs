@@ -5,6 +5,7 @@
 //! - `add_approvals` - Approve all project commands
 //! - `clear_approvals` - Clear approved commands
 //! - `handle_hook_show` - Display configured hooks
+//! - `handle_hook_check` - Validate hook commands without running them
 
 use std::collections::HashMap;
 use std::fmt::Write as _;
@@ -13,12 +14,14 @@ use anyhow::Context;
 use color_print::cformat;
 use strum::IntoEnumIterator;
 use worktrunk::HookType;
-use worktrunk::config::{CommandConfig, ProjectConfig, UserConfig};
-use worktrunk::git::{GitError, Repository};
+use worktrunk::config::{
+    Command, CommandConfig, HooksConfig, ProjectConfig, TemplateExpandError, UserConfig,
+};
+use worktrunk::git::{GitError, Repository, WorktrunkError};
 use worktrunk::path::format_path_for_display;
 use worktrunk::styling::{
-    INFO_SYMBOL, PROMPT_SYMBOL, eprintln, format_bash_with_gutter, format_heading, hint_message,
-    info_message, success_message,
+    INFO_SYMBOL, PROMPT_SYMBOL, eprintln, error_message, format_bash_with_gutter, format_heading,
+    hint_message, info_message, success_message,
 };
 
 use super::command_approval::approve_hooks_filtered;
@@ -671,6 +674,9 @@ fn render_hook_commands(
         };
 
         writeln!(out, "{emoji} {label}{suffix}")?;
+        if let Some(conditions) = format_conditions(cmd) {
+            writeln!(out, "  {conditions}")?;
+        }
 
         // Show template or expanded command
         let command_text = if let Some(command_ctx) = ctx {
@@ -686,8 +692,31 @@ fn render_hook_commands(
     Ok(())
 }
 
+/// The `when` and `paths` conditions of a hook command, if any.
+fn format_conditions(cmd: &Command) -> Option<String> {
+    let mut parts = Vec::new();
+    if let Some(when) = &cmd.when {
+        parts.push(format!("when {when}"));
+    }
+    if !cmd.paths.is_empty() {
+        parts.push(format!("paths {}", cmd.paths.join(", ")));
+    }
+    (!parts.is_empty()).then(|| cformat!("<dim>{}</>", parts.join("; ")))
+}
+
 /// Expand a command template with context variables
 fn expand_command_template(template: &str, ctx: &CommandContext, hook_type: HookType) -> String {
+    // On any error, show both the template and error message
+    try_expand_command_template(template, ctx, hook_type)
+        .unwrap_or_else(|err| format!("# {}\n{}", err.message, template))
+}
+
+/// Expand a command template with context variables, returning template errors
+fn try_expand_command_template(
+    template: &str,
+    ctx: &CommandContext,
+    hook_type: HookType,
+) -> Result<String, TemplateExpandError> {
     // Build extra vars based on hook type (same logic as run_hook approval)
     let default_branch = ctx.repo.default_branch();
     let extra_vars: Vec<(&str, &str)> = match hook_type {
@@ -712,7 +741,196 @@ fn expand_command_template(template: &str, ctx: &CommandContext, hook_type: Hook
         .collect();
 
     // Use the standard template expansion (shell-escaped)
-    // On any error, show both the template and error message
     worktrunk::config::expand_template(template, &vars, true, ctx.repo, "hook preview")
-        .unwrap_or_else(|err| format!("# {}\n{}", err.message, template))
+}
+
+/// Handle `wt hook check` command - validate hook commands without running them
+///
+/// Each command's template must expand in the current worktree, and the
+/// programs it starts must be on PATH. Exits non-zero when any check fails.
+pub fn handle_hook_check() -> anyhow::Result<()> {
+    let env = CommandEnv::for_action_branchless()?;
+    let ctx = env.context(false);
+    let project_config = env.repo.load_project_config()?;
+    let project_id = env.repo.project_identifier().ok();
+    let user_hooks = env.config.hooks(project_id.as_deref());
+
+    let sections = [
+        ("USER HOOKS", Some(&user_hooks)),
+        (
+            "PROJECT HOOKS",
+            project_config.as_ref().map(|config| &config.hooks),
+        ),
+    ];
+    let mut failures = 0;
+    for (i, (title, hooks)) in sections.into_iter().enumerate() {
+        if i > 0 {
+            eprintln!();
+        }
+        eprintln!("{}", format_heading(title, None));
+        let checked = hooks.map_or(0, |hooks| check_hooks(hooks, &ctx, &mut failures));
+        if checked == 0 {
+            eprintln!("{}", hint_message("(none configured)"));
+        }
+    }
+
+    if failures > 0 {
+        return Err(WorktrunkError::AlreadyDisplayed { exit_code: 1 }.into());
+    }
+    Ok(())
+}
+
+/// Check every command in `hooks`, counting failures. Returns how many
+/// commands were checked.
+fn check_hooks(hooks: &HooksConfig, ctx: &CommandContext, failures: &mut usize) -> usize {
+    let mut checked = 0;
+    for hook_type in HookType::iter() {
+        let Some(config) = hooks.get(hook_type) else {
+            continue;
+        };
+        for cmd in config.commands() {
+            checked += 1;
+            let label = match &cmd.name {
+                Some(name) => cformat!("{hook_type} <bold>{name}</>"),
+                None => hook_type.to_string(),
+            };
+            let expanded = match try_expand_command_template(&cmd.template, ctx, hook_type) {
+                Ok(expanded) => expanded,
+                Err(err) => {
+                    *failures += 1;
+                    eprintln!("{}", error_message(format!("{label}: {}", err.message)));
+                    continue;
+                }
+            };
+            let Some(programs) = command_programs(&expanded) else {
+                eprintln!(
+                    "{}",
+                    info_message(format!("{label}: too complex to check; skipped"))
+                );
+                continue;
+            };
+            let missing: Vec<_> = programs
+                .iter()
+                .filter(|program| !program_exists(program, ctx.worktree_path))
+                .collect();
+            if missing.is_empty() {
+                eprintln!("{}", success_message(label));
+            } else {
+                *failures += 1;
+                for program in missing {
+                    eprintln!(
+                        "{}",
+                        error_message(cformat!("{label}: <bold>{program}</> not found on PATH"))
+                    );
+                }
+            }
+        }
+    }
+    checked
+}
+
+/// Whether `program` can be run: a path relative to the worktree, or a name on PATH.
+fn program_exists(program: &str, worktree_path: &std::path::Path) -> bool {
+    if program.contains('/') {
+        worktree_path.join(program).exists()
+    } else {
+        which::which(program).is_ok()
+    }
+}
+
+/// Shell words that start a command but aren't programs looked up on PATH.
+const SHELL_BUILTINS: &[&str] = &[
+    "!", ".", ":", "[", "[[", "alias", "break", "case", "cd", "command", "continue", "do", "done",
+    "echo", "elif", "else", "esac", "eval", "exec", "exit", "export", "false", "fi", "for",
+    "function", "if", "local", "printf", "pwd", "read", "return", "set", "shift", "source", "test",
+    "then", "trap", "true", "type", "ulimit", "umask", "unset", "until", "wait", "while", "{", "}",
+];
+
+/// Programs a shell command starts: the first word of each simple command,
+/// skipping builtins, variable assignments and `$VAR` programs.
+///
+/// Returns `None` for commands too complex to split without a shell parser
+/// (heredocs, command substitution, unbalanced quotes).
+fn command_programs(command: &str) -> Option<Vec<String>> {
+    if command.contains("<<") || command.contains("$(") || command.contains('`') {
+        return None;
+    }
+
+    // Split on `;`, `&`, `|`, newlines and subshell parentheses outside quotes
+    let mut segments = vec![String::new()];
+    let mut quote = None;
+    let mut chars = command.chars();
+    while let Some(c) = chars.next() {
+        let current = segments.last_mut().expect("segments is never empty");
+        match (quote, c) {
+            (_, '\\') => {
+                current.push(c);
+                current.extend(chars.next());
+            }
+            (None, '\'' | '"') => {
+                quote = Some(c);
+                current.push(c);
+            }
+            (Some(q), c) if c == q => {
+                quote = None;
+                current.push(c);
+            }
+            (None, ';' | '&' | '|' | '\n' | '(' | ')') => segments.push(String::new()),
+            _ => current.push(c),
+        }
+    }
+    if quote.is_some() {
+        return None;
+    }
+
+    let mut programs = Vec::new();
+    for segment in segments {
+        let words = shlex::split(&segment)?;
+        let Some(program) = words.into_iter().find(|word| !is_assignment(word)) else {
+            continue;
+        };
+        if SHELL_BUILTINS.contains(&program.as_str()) || program.starts_with('$') {
+            continue;
+        }
+        if !programs.contains(&program) {
+            programs.push(program);
+        }
+    }
+    Some(programs)
+}
+
+/// `NAME=value`, as in `RUST_LOG=debug cargo test`.
+fn is_assignment(word: &str) -> bool {
+    word.split_once('=').is_some_and(|(name, _)| {
+        !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_command_programs() {
+        assert_eq!(command_programs("cargo test").unwrap(), ["cargo"]);
+        assert_eq!(
+            command_programs("cd web && npm ci || yarn install").unwrap(),
+            ["npm", "yarn"]
+        );
+        assert_eq!(
+            command_programs("RUST_LOG=debug cargo test; cargo build").unwrap(),
+            ["cargo"]
+        );
+        assert_eq!(
+            command_programs("echo 'a && b' | grep -q a").unwrap(),
+            ["grep"]
+        );
+        assert_eq!(
+            command_programs("./scripts/setup.sh\nmake").unwrap(),
+            ["./scripts/setup.sh", "make"]
+        );
+        assert!(command_programs("$EDITOR file").unwrap().is_empty());
+        assert!(command_programs("echo $(date)").is_none());
+        assert!(command_programs("echo 'unterminated").is_none());
+    }
 }
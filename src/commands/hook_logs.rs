@@ -0,0 +1,100 @@
+//! Per-run output logs for foreground hooks, and `wt hook logs`.
+//!
+//! Background hooks already log to `.git/wt-logs/` (one file per hook,
+//! overwritten on each run). Foreground hooks stream to the terminal; their
+//! output is also copied to `.git/worktrunk/hook-logs/{timestamp}-{branch}-{source}-{hook-type}-{name}.log`
+//! so a failure can be reviewed after the terminal has scrolled away.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use color_print::cformat;
+use worktrunk::git::Repository;
+use worktrunk::path::format_path_for_display;
+use worktrunk::state::StateStore;
+use worktrunk::styling::{eprintln, info_message};
+use worktrunk::utils::get_now;
+
+use super::process::HookLog;
+use crate::display::format_relative_time_short;
+
+/// Per-run logs kept before the oldest are pruned.
+const MAX_HOOK_LOGS: usize = 100;
+
+/// Path for a new run's log, pruning old runs to make room.
+pub(crate) fn run_log_path(repo: &Repository, branch: &str, hook_log: &HookLog) -> PathBuf {
+    let dir = StateStore::new(repo.git_common_dir()).hook_logs_dir();
+    prune(&dir);
+    dir.join(format!("{}-{}", get_now(), hook_log.filename(branch)))
+}
+
+/// Last lines of a run's log, for errors about a command that was cut off.
+pub(crate) fn log_tail(path: &Path) -> Option<String> {
+    const TAIL_LINES: usize = 20;
+    let contents = fs::read_to_string(path).ok()?;
+    let lines: Vec<&str> = contents.lines().collect();
+    let start = lines.len().saturating_sub(TAIL_LINES);
+    Some(lines[start..].join("\n"))
+}
+
+/// Remove all but the newest `MAX_HOOK_LOGS - 1` logs. Best-effort.
+fn prune(dir: &Path) {
+    let logs = list_logs(dir);
+    for (_, path) in logs.iter().skip(MAX_HOOK_LOGS - 1) {
+        let _ = fs::remove_file(path);
+    }
+}
+
+/// Logs in `dir` with their start timestamps, newest first.
+fn list_logs(dir: &Path) -> Vec<(u64, PathBuf)> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut logs: Vec<_> = entries
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let name = path.file_name()?.to_str()?;
+            let timestamp = name.strip_suffix(".log")?.split_once('-')?.0.parse().ok()?;
+            Some((timestamp, path))
+        })
+        .collect();
+    // Same-second runs sort by name; good enough for "newest first"
+    logs.sort_by(|a, b| b.cmp(a));
+    logs
+}
+
+/// Handle `wt hook logs`.
+pub fn handle_hook_logs(last: bool) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let dir = StateStore::new(repo.git_common_dir()).hook_logs_dir();
+    let logs = list_logs(&dir);
+
+    if last {
+        let Some((_, path)) = logs.first() else {
+            eprintln!("{}", info_message("No hook runs logged yet"));
+            return Ok(());
+        };
+        let contents = fs::read_to_string(path)?;
+        eprintln!(
+            "{}",
+            info_message(cformat!(
+                "Output of <bold>{}</>",
+                format_path_for_display(path)
+            ))
+        );
+        print!("{contents}");
+        return Ok(());
+    }
+
+    if logs.is_empty() {
+        eprintln!("{}", info_message("No hook runs logged yet"));
+    }
+    for (timestamp, path) in &logs {
+        println!(
+            "{}  {}",
+            format_relative_time_short(*timestamp as i64),
+            path.display()
+        );
+    }
+    Ok(())
+}
@@ -1,15 +1,17 @@
 use std::path::{Path, PathBuf};
+use std::time::Instant;
 
 use color_print::cformat;
 use worktrunk::HookType;
-use worktrunk::config::CommandConfig;
-use worktrunk::git::WorktrunkError;
+use worktrunk::config::{Command, CommandConfig};
+use worktrunk::git::{WorktrunkError, exit_codes, is_interrupted};
 use worktrunk::path::format_path_for_display;
 use worktrunk::styling::{
     eprintln, error_message, format_bash_with_gutter, progress_message, verbosity, warning_message,
 };
 
 use super::command_executor::{CommandContext, PreparedCommand, prepare_commands};
+use super::hook_logs::{log_tail, run_log_path};
 use crate::commands::process::{HookLog, spawn_detached};
 use crate::output::execute_command_in_worktree;
 
@@ -77,7 +79,10 @@ pub use super::hook_filter::{HookSource, ParsedFilter};
 
 /// Prepare hook commands from both user and project configs.
 ///
-/// Collects commands from user config first, then project config, applying the name filter.
+/// Collects user commands first, then project commands, then user commands
+/// marked `after-project`, applying the name filter. User commands the
+/// project lists under `[user-hooks] skip` are left out unless asked for by
+/// name.
 /// The filter supports source prefixes: `user:foo` or `project:foo` to run only from one source.
 /// Returns a flat list of commands with source information for execution.
 ///
@@ -98,10 +103,21 @@ pub fn prepare_hook_commands(
 
     let display_path = display_path.map(|p| p.to_path_buf());
 
-    // Process user config first, then project config (execution order)
+    let project = ctx.repo.load_project_config()?;
+    let skipped = |cmd: &Command| {
+        name_filter.is_none()
+            && project
+                .as_ref()
+                .is_some_and(|p| p.skips_user_hook(cmd.name.as_deref()))
+    };
+    let user_before = user_config.map(|c| c.filtered(|cmd| !cmd.after_project && !skipped(cmd)));
+    let user_after = user_config.map(|c| c.filtered(|cmd| cmd.after_project && !skipped(cmd)));
+
+    // Execution order
     let sources = [
-        (HookSource::User, user_config),
+        (HookSource::User, user_before.as_ref()),
         (HookSource::Project, project_config),
+        (HookSource::User, user_after.as_ref()),
     ];
 
     for (source, config) in sources {
@@ -338,15 +354,66 @@ pub fn run_hook_with_filter(
 
     // Track first failure's exit code for Warn strategy (to propagate after all commands run)
     let mut first_failure_exit_code: Option<i32> = None;
+    let default_timeout = ctx
+        .config
+        .timeouts(ctx.project_id().as_deref())
+        .unwrap_or_default()
+        .hook();
 
     for cmd in commands {
         cmd.announce()?;
 
-        if let Err(err) = execute_command_in_worktree(
+        let label = format!("{hook_type} {}", cmd.summary_name());
+        log::debug!("Hook {label} started: {}", cmd.prepared.expanded);
+        let started = Instant::now();
+        let log_path = run_log_path(
+            ctx.repo,
+            ctx.branch.unwrap_or("HEAD"),
+            &HookLog::hook(
+                cmd.source,
+                hook_type,
+                cmd.prepared.name.as_deref().unwrap_or("hook"),
+            ),
+        );
+        let result = execute_command_in_worktree(
             ctx.worktree_path,
             &cmd.prepared.expanded,
             Some(&cmd.prepared.context_json),
-        ) {
+            Some(&log_path),
+            cmd.prepared.timeout.or(default_timeout),
+        );
+        log::debug!(
+            "Hook {label} finished in {}ms (ok={})",
+            started.elapsed().as_millis(),
+            result.is_ok()
+        );
+        worktrunk::perf::record(
+            worktrunk::perf::SpanKind::Phase,
+            &format!("hook {label}"),
+            started.elapsed(),
+        );
+
+        if let Err(err) = result {
+            if let Some(WorktrunkError::ChildProcessTimedOut { timeout }) = err.downcast_ref() {
+                let timed_out = WorktrunkError::HookTimedOut {
+                    hook_type,
+                    command_name: cmd.prepared.name.clone(),
+                    timeout: *timeout,
+                    output: log_tail(&log_path),
+                    log: Some(log_path),
+                };
+                match failure_strategy {
+                    HookFailureStrategy::FailFast => return Err(timed_out.into()),
+                    HookFailureStrategy::Warn => {
+                        eprintln!("{timed_out}");
+                        if first_failure_exit_code.is_none() && hook_type == HookType::PostMerge {
+                            first_failure_exit_code = Some(exit_codes::TIMED_OUT);
+                        }
+                        continue;
+                    }
+                }
+            }
+
             // Extract raw message and exit code from error
             let (err_msg, exit_code) = if let Some(wt_err) = err.downcast_ref::<WorktrunkError>() {
                 match wt_err {
@@ -359,17 +426,20 @@ pub fn run_hook_with_filter(
                 (err.to_string(), None)
             };
 
-            match &failure_strategy {
-                HookFailureStrategy::FailFast => {
+            // An interrupted command (Ctrl-C, closed terminal) stops the whole
+            // operation, even under Warn; the user asked for it to stop.
+            match (&failure_strategy, is_interrupted(&err)) {
+                (HookFailureStrategy::FailFast, _) | (_, true) => {
                     return Err(WorktrunkError::HookCommandFailed {
                         hook_type,
                         command_name: cmd.prepared.name.clone(),
                         error: err_msg,
                         exit_code,
+                        log: Some(log_path),
                     }
                     .into());
                 }
-                HookFailureStrategy::Warn => {
+                (HookFailureStrategy::Warn, false) => {
                     let message = match &cmd.prepared.name {
                         Some(name) => cformat!("Command <bold>{name}</> failed: {err_msg}"),
                         None => format!("Command failed: {err_msg}"),
@@ -378,7 +448,7 @@ pub fn run_hook_with_filter(
 
                     // Track first failure to propagate exit code later (only for PostMerge)
                     if first_failure_exit_code.is_none() && hook_type == HookType::PostMerge {
-                        first_failure_exit_code = Some(exit_code.unwrap_or(1));
+                        first_failure_exit_code = Some(exit_codes::HOOK_FAILED);
                     }
                 }
             }
@@ -0,0 +1,331 @@
+//! `wt init`: convert a regular clone to the bare repository layout.
+//!
+//! ```text
+//! myproject/              myproject/
+//! ├── .git/         →     ├── .git/    (bare)
+//! ├── src/                └── main/    (worktree)
+//! └── README.md               ├── src/
+//!                             └── README.md
+//! ```
+//!
+//! The checkout is moved rather than re-created: every file in the clone —
+//! uncommitted changes, untracked and ignored files included — ends up in
+//! the new worktree, and the index moves with it so staged changes stay
+//! staged. Everything is checked to be writable and on one filesystem before
+//! anything changes, and a failure partway moves everything back. New
+//! worktrees then go next to it via a per-project
+//! `worktree-path = "../{{ branch | sanitize }}"`.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use color_print::cformat;
+use worktrunk::config::{UserConfig, sanitize_branch_name};
+use worktrunk::git::{GitError, Repository};
+use worktrunk::path::format_path_for_display;
+use worktrunk::shell_exec::Cmd;
+use worktrunk::styling::{eprintln, format_with_gutter, info_message, success_message};
+
+use crate::output::prompt::{PromptResponse, assume_yes, prompt_yes_no_preview};
+use crate::output::{change_directory, is_shell_integration_active, prompt_shell_integration};
+
+/// Worktree path template for the bare layout (relative to the `.git` directory).
+const BARE_LAYOUT_TEMPLATE: &str = "../{{ branch | sanitize }}";
+
+/// Handle `wt init`.
+pub fn handle_init_layout(
+    yes: bool,
+    config: &mut UserConfig,
+    binary_name: &str,
+) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    if repo.is_bare() {
+        eprintln!(
+            "{}",
+            info_message("Repository already uses the bare layout; nothing to convert")
+        );
+        return Ok(());
+    }
+
+    let root = repo.current_worktree().root()?;
+    let dot_git = root.join(".git");
+    if !dot_git.is_dir() || dunce::canonicalize(&dot_git)? != repo.git_common_dir() {
+        return Err(GitError::Other {
+            message: "wt init must run from the main worktree of a regular clone".into(),
+        }
+        .into());
+    }
+    if repo
+        .current_worktree()
+        .run_command(&["rev-parse", "--verify", "-q", "HEAD"])
+        .is_err()
+    {
+        return Err(GitError::Other {
+            message: "wt init needs at least one commit".into(),
+        }
+        .into());
+    }
+
+    let branch = repo.current_worktree().branch()?;
+    let dir_name = sanitize_branch_name(branch.as_deref().unwrap_or("detached"));
+    let target = root.join(&dir_name);
+    if target.exists() {
+        return Err(GitError::Other {
+            message: cformat!(
+                "Cannot move the checkout to <bold>{}</>: path already exists",
+                format_path_for_display(&target)
+            ),
+        }
+        .into());
+    }
+
+    if !(yes || assume_yes()) {
+        let target_display = format_path_for_display(&target);
+        let prompt = cformat!(
+            "Convert to the bare layout, moving this checkout to <bold>{target_display}</>?"
+        );
+        let response = prompt_yes_no_preview(&prompt, || {
+            let plan = format!(
+                "git config core.bare true\n\
+                 git worktree add --no-checkout {dir_name} {}\n\
+                 # move all files into {dir_name}/, with the index",
+                branch.as_deref().unwrap_or("--detach HEAD")
+            );
+            eprintln!("{}", format_with_gutter(&plan, None));
+        })?;
+        if response == PromptResponse::Declined {
+            eprintln!("{}", info_message("Conversion cancelled"));
+            return Ok(());
+        }
+    }
+
+    convert_to_bare(&root, &target, branch.as_deref())?;
+
+    eprintln!(
+        "{}",
+        success_message(cformat!(
+            "Converted to bare layout; checkout moved to <bold>{}</>",
+            format_path_for_display(&target)
+        ))
+    );
+
+    // Identify the project from the converted repo: path-based ids change with the layout
+    let converted = Repository::at(&target)?;
+    let project_id = converted.project_identifier()?;
+    config
+        .set_project_worktree_path(&project_id, BARE_LAYOUT_TEMPLATE, None)
+        .context("Failed to save worktree-path")?;
+    eprintln!(
+        "{}",
+        info_message(cformat!(
+            "Set <bold>worktree-path = \"{BARE_LAYOUT_TEMPLATE}\"</> for this project, so new worktrees go next to it"
+        ))
+    );
+
+    if !is_shell_integration_active() {
+        let _ = prompt_shell_integration(config, binary_name, false);
+    }
+    change_directory(&target)?;
+    Ok(())
+}
+
+/// Flip the repository to bare and move the checkout into a linked worktree.
+fn convert_to_bare(root: &Path, target: &Path, branch: Option<&str>) -> anyhow::Result<()> {
+    if worktrunk::dry_run::skip(&format!(
+        "move the checkout in {} to {}",
+        format_path_for_display(root),
+        format_path_for_display(target)
+    )) {
+        return Ok(());
+    }
+    let git = |args: &[&str], dir: &Path| -> anyhow::Result<String> {
+        let output = Cmd::new("git")
+            .args(args.iter().copied())
+            .current_dir(dir)
+            .run()?;
+        if !output.status.success() {
+            return Err(GitError::Other {
+                message: format!(
+                    "git {} failed: {}",
+                    args.join(" "),
+                    String::from_utf8_lossy(&output.stderr).trim()
+                ),
+            }
+            .into());
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+    let dot_git = root.join(".git");
+    let head = git(&["rev-parse", "HEAD"], root)?;
+    let entries = entries_to_move(root, target)?;
+    check_movable(root, &dot_git, &entries)?;
+
+    // Once bare, the old checkout no longer holds the branch, so it can be added as a worktree
+    git(&["config", "core.bare", "true"], &dot_git)?;
+    let target_str = target.to_string_lossy();
+    let mut add_args = vec!["worktree", "add", "--no-checkout", target_str.as_ref()];
+    match branch {
+        Some(branch) => add_args.push(branch),
+        None => add_args.extend(["--detach", head.as_str()]),
+    }
+    if let Err(e) = git(&add_args, &dot_git) {
+        // Nothing has moved yet; restore the regular layout
+        let _ = git(&["config", "core.bare", "false"], &dot_git);
+        return Err(e);
+    }
+
+    // Every completed move, so a failure partway can put them all back
+    let mut moved: Vec<(PathBuf, PathBuf)> = Vec::new();
+    let result = (|| -> anyhow::Result<()> {
+        for entry in &entries {
+            let dest = target.join(entry.file_name().context("Unexpected path")?);
+            fs::rename(entry, &dest).with_context(|| {
+                format!(
+                    "Failed to move {} into the new worktree",
+                    format_path_for_display(entry)
+                )
+            })?;
+            moved.push((entry.clone(), dest));
+        }
+
+        // The old index describes exactly these files; reuse it so staged changes survive
+        let worktree_git_dir = PathBuf::from(git(&["rev-parse", "--absolute-git-dir"], target)?);
+        let index = dot_git.join("index");
+        if index.exists() {
+            let dest = worktree_git_dir.join("index");
+            fs::rename(&index, &dest).context("Failed to move the index into the new worktree")?;
+            moved.push((index, dest));
+        }
+        Ok(())
+    })();
+
+    if let Err(e) = result {
+        let restored = moved
+            .iter()
+            .rev()
+            .all(|(from, to)| fs::rename(to, from).is_ok());
+        if !restored {
+            return Err(e.context(cformat!(
+                "Some files are still in <bold>{}</>; move them back to <bold>{}</> and run <bright-black>git config core.bare false</>",
+                format_path_for_display(target),
+                format_path_for_display(root)
+            )));
+        }
+        // Unregister the new worktree and restore the regular layout
+        let _ = fs::remove_file(target.join(".git"));
+        let _ = fs::remove_dir(target);
+        let _ = git(&["worktree", "prune"], &dot_git);
+        let _ = git(&["config", "core.bare", "false"], &dot_git);
+        return Err(e);
+    }
+    Ok(())
+}
+
+/// Refuse up front what would make a move fail partway: entries on another
+/// filesystem (a rename can't cross it) and directories that can't be
+/// written.
+fn check_movable(root: &Path, dot_git: &Path, entries: &[PathBuf]) -> anyhow::Result<()> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::MetadataExt;
+        let device = fs::metadata(root)?.dev();
+        for path in entries.iter().map(PathBuf::as_path).chain([dot_git]) {
+            if fs::symlink_metadata(path)?.dev() != device {
+                return Err(GitError::Other {
+                    message: cformat!(
+                        "<bold>{}</> is on a different filesystem; it can't be moved into the new worktree",
+                        format_path_for_display(path)
+                    ),
+                }
+                .into());
+            }
+        }
+    }
+    // Moving a directory rewrites its `..` entry, so it must be writable too
+    let dirs = entries
+        .iter()
+        .map(PathBuf::as_path)
+        .filter(|path| fs::symlink_metadata(path).is_ok_and(|meta| meta.is_dir()));
+    for dir in [root, dot_git].into_iter().chain(dirs) {
+        if !is_writable(dir) {
+            return Err(GitError::Other {
+                message: cformat!(
+                    "<bold>{}</> isn't writable; nothing was changed",
+                    format_path_for_display(dir)
+                ),
+            }
+            .into());
+        }
+    }
+    Ok(())
+}
+
+/// Whether a file can be created in `dir`, tested by creating one.
+fn is_writable(dir: &Path) -> bool {
+    let probe = dir.join(format!(".wt-init-{}", std::process::id()));
+    let created = fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&probe)
+        .is_ok();
+    if created {
+        let _ = fs::remove_file(&probe);
+    }
+    created
+}
+
+/// Everything in the clone's root except `.git` and the new worktree itself.
+fn entries_to_move(root: &Path, target: &Path) -> anyhow::Result<Vec<PathBuf>> {
+    let mut entries = Vec::new();
+    for entry in fs::read_dir(root)? {
+        let path = entry?.path();
+        if path.file_name().is_some_and(|name| name == ".git") || path == target {
+            continue;
+        }
+        entries.push(path);
+    }
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_entries_to_move_skips_git_and_target() {
+        let dir = tempfile::tempdir().unwrap();
+        for name in [".git", "main", "src"] {
+            fs::create_dir(dir.path().join(name)).unwrap();
+        }
+        fs::write(dir.path().join(".env"), "").unwrap();
+
+        let mut moved = entries_to_move(dir.path(), &dir.path().join("main")).unwrap();
+        moved.sort();
+        assert_eq!(moved, vec![dir.path().join(".env"), dir.path().join("src")]);
+    }
+
+    #[test]
+    fn test_check_movable() {
+        let dir = tempfile::tempdir().unwrap();
+        for name in [".git", "src"] {
+            fs::create_dir(dir.path().join(name)).unwrap();
+        }
+        let entries = vec![dir.path().join("src")];
+        check_movable(dir.path(), &dir.path().join(".git"), &entries).unwrap();
+        // The probe file doesn't stay behind
+        assert_eq!(fs::read_dir(dir.path().join("src")).unwrap().count(), 0);
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let src = dir.path().join("src");
+            fs::set_permissions(&src, fs::Permissions::from_mode(0o555)).unwrap();
+            // Root ignores permissions
+            if !is_writable(&src) {
+                assert!(check_movable(dir.path(), &dir.path().join(".git"), &entries).is_err());
+            }
+            fs::set_permissions(&src, fs::Permissions::from_mode(0o755)).unwrap();
+        }
+    }
+}
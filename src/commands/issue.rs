@@ -0,0 +1,286 @@
+//! Issue tracker lookups shared by `wt start #N` and `wt switch --from-issue`.
+//!
+//! Issues come from GitHub or GitLab through their CLIs, or from Jira (see
+//! [`super::jira`]) when `[start] tracker = "jira"`.
+//!
+//! An issue resolves to a branch name through the `[start] branch` template.
+//! Its link is stored as the branch's note in the state store, which
+//! `wt list` shows in the Note column.
+
+use std::collections::HashMap;
+
+use anyhow::Context;
+use color_print::cformat;
+use serde::Deserialize;
+use worktrunk::config::{IssueTracker, StartConfig, UserConfig, expand_template};
+use worktrunk::git::{GitError, Repository};
+use worktrunk::state::StateStore;
+use worktrunk::styling::{eprintln, progress_message};
+
+use super::forge::{detect_platform, forge_cmd, run_forge};
+use super::jira::Jira;
+use super::list::ci_status::CiPlatform;
+
+/// An issue fetched from the tracker.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Issue {
+    pub id: String,
+    pub title: String,
+    pub url: String,
+}
+
+impl Issue {
+    /// Template variables for the branch template, `setup`, and `open`.
+    pub(crate) fn vars(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("issue", self.id.clone()),
+            ("issue_key", self.id.clone()),
+            ("title", self.title.clone()),
+            ("slug", slugify(&self.title)),
+        ]
+    }
+
+    /// Short reference used in commit messages and PR bodies (`#123`, or a
+    /// Jira key as is).
+    pub(crate) fn reference(&self) -> String {
+        if self.id.chars().all(|c| c.is_ascii_digit()) {
+            format!("#{}", self.id)
+        } else {
+            self.id.clone()
+        }
+    }
+}
+
+/// Parse an issue reference: `#123` or `issue:123`.
+pub(crate) fn parse_issue_ref(target: &str) -> Option<u32> {
+    target
+        .strip_prefix('#')
+        .or_else(|| target.strip_prefix("issue:"))
+        .and_then(|n| n.parse().ok())
+}
+
+/// Maximum slug length, keeping branch names and worktree paths manageable.
+const MAX_SLUG_LEN: usize = 40;
+
+/// Reduce an issue title to a branch-safe slug.
+///
+/// Lowercases ASCII alphanumerics and collapses every other run of characters
+/// to a single `-`. Truncates at a word boundary when the title is long.
+pub(crate) fn slugify(title: &str) -> String {
+    let mut slug = String::new();
+    for c in title.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    if slug.len() > MAX_SLUG_LEN {
+        slug.truncate(MAX_SLUG_LEN);
+        if let Some(idx) = slug.rfind('-') {
+            slug.truncate(idx);
+        }
+    }
+    slug.trim_end_matches('-').to_string()
+}
+
+/// Fetch an issue from the configured tracker, falling back to the forge of
+/// the remote.
+///
+/// Accepts `1234`, `#1234`, or `issue:1234`; Jira takes a ticket key like
+/// `PROJ-123`.
+pub(crate) fn fetch_issue(
+    repo: &Repository,
+    config: &UserConfig,
+    start_config: &StartConfig,
+    id: &str,
+) -> anyhow::Result<Issue> {
+    if start_config.tracker() == Some(IssueTracker::Jira) {
+        return fetch_jira_issue(repo, config, id);
+    }
+    let Some(number) = parse_issue_ref(id).or_else(|| id.parse().ok()) else {
+        return Err(GitError::Other {
+            message: cformat!("Invalid issue <bold>{id}</>; expected a number like <bold>1234</>"),
+        }
+        .into());
+    };
+    let platform = match start_config.tracker() {
+        Some(IssueTracker::Github) => CiPlatform::GitHub,
+        Some(IssueTracker::Gitlab) => CiPlatform::GitLab,
+        Some(IssueTracker::Jira) => unreachable!("handled above"),
+        None => detect_platform(repo)?,
+    };
+    eprintln!(
+        "{}",
+        progress_message(cformat!("Fetching issue <bold>#{number}</>..."))
+    );
+    fetch_forge_issue(repo, platform, number)
+}
+
+/// Fetch a Jira ticket as an issue.
+fn fetch_jira_issue(repo: &Repository, config: &UserConfig, key: &str) -> anyhow::Result<Issue> {
+    let project_id = repo.project_identifier().ok();
+    let jira_config = config.jira(project_id.as_deref()).unwrap_or_default();
+    let Some(jira) = Jira::from_config(&jira_config)? else {
+        return Err(GitError::Other {
+            message: cformat!(
+                "<bold>[start] tracker = \"jira\"</> needs a site; set <bold>url</> under <bold>[jira]</>"
+            ),
+        }
+        .into());
+    };
+    if !jira.is_key(key) {
+        return Err(GitError::Other {
+            message: cformat!(
+                "Invalid Jira ticket <bold>{key}</>; expected a key like <bold>PROJ-123</>"
+            ),
+        }
+        .into());
+    }
+    eprintln!(
+        "{}",
+        progress_message(cformat!("Fetching ticket <bold>{key}</>..."))
+    );
+    let ticket = jira.fetch(key)?;
+    Ok(Issue {
+        id: ticket.key,
+        title: ticket.summary,
+        url: ticket.url,
+    })
+}
+
+/// Fetch an issue's title and link using the platform's CLI.
+fn fetch_forge_issue(
+    repo: &Repository,
+    platform: CiPlatform,
+    number: u32,
+) -> anyhow::Result<Issue> {
+    #[derive(Deserialize)]
+    struct IssueResponse {
+        title: String,
+        #[serde(alias = "web_url")]
+        url: String,
+    }
+
+    let number_str = number.to_string();
+    let cmd = match platform {
+        CiPlatform::GitHub => {
+            forge_cmd(platform).args(["issue", "view", &number_str, "--json", "title,url"])
+        }
+        CiPlatform::GitLab => {
+            forge_cmd(platform).args(["issue", "view", &number_str, "--output", "json"])
+        }
+    };
+    let output = run_forge(
+        platform,
+        cmd.current_dir(repo.repo_path()),
+        &format!("fetch issue #{number}"),
+    )?;
+
+    let response: IssueResponse = serde_json::from_slice(&output.stdout)
+        .with_context(|| format!("Failed to parse response for issue #{number}"))?;
+    Ok(Issue {
+        id: number_str,
+        title: response.title,
+        url: response.url,
+    })
+}
+
+/// Expand the `[start] branch` template for an issue.
+pub(crate) fn issue_branch(
+    repo: &Repository,
+    start_config: &StartConfig,
+    issue: &Issue,
+) -> anyhow::Result<String> {
+    let issue_vars = issue.vars();
+    let default_branch = repo.default_branch();
+    let mut vars: HashMap<&str, &str> = issue_vars.iter().map(|(k, v)| (*k, v.as_str())).collect();
+    // Same names as in hooks and `worktree-path`, for templates shared between them
+    if let Some(name) = repo.repo_path().file_name().and_then(|n| n.to_str()) {
+        vars.insert("repo", name);
+    }
+    if let Some(default_branch) = &default_branch {
+        vars.insert("default_branch", default_branch);
+    }
+    Ok(expand_template(
+        start_config.branch(),
+        &vars,
+        false,
+        repo,
+        "start branch template",
+    )?)
+}
+
+/// Resolve `wt switch --create --from-issue ID` to a branch name.
+///
+/// The note is recorded up front because `--execute` replaces the process
+/// once the worktree exists.
+pub(crate) fn branch_from_issue(config: &UserConfig, id: &str) -> anyhow::Result<String> {
+    let repo = Repository::current()?;
+    let project_id = repo.project_identifier().ok();
+    let start_config = config.start(project_id.as_deref()).unwrap_or_default();
+    let issue = fetch_issue(&repo, config, &start_config, id)?;
+    let branch = issue_branch(&repo, &start_config, &issue)?;
+    record_issue_note(&repo, &branch, &issue)?;
+    Ok(branch)
+}
+
+/// Record the issue link as the branch's note.
+pub(crate) fn record_issue_note(
+    repo: &Repository,
+    branch: &str,
+    issue: &Issue,
+) -> anyhow::Result<()> {
+    StateStore::new(repo.git_common_dir()).update(|state| {
+        state.notes.insert(branch.to_string(), issue.url.clone());
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_issue_ref() {
+        assert_eq!(parse_issue_ref("#123"), Some(123));
+        assert_eq!(parse_issue_ref("issue:45"), Some(45));
+        assert_eq!(parse_issue_ref("feature"), None);
+        assert_eq!(parse_issue_ref("#abc"), None);
+        assert_eq!(parse_issue_ref("pr:12"), None);
+    }
+
+    #[test]
+    fn test_slugify() {
+        assert_eq!(slugify("Fix login bug"), "fix-login-bug");
+        assert_eq!(
+            slugify("  [API] Rate-limit: 429s!  "),
+            "api-rate-limit-429s"
+        );
+        assert_eq!(slugify("Ünïcode títle"), "n-code-t-tle");
+        assert_eq!(slugify("!!!"), "");
+        let long = slugify("Support configurable timeouts for every long running network call");
+        assert!(long.len() <= MAX_SLUG_LEN);
+        assert!(!long.ends_with('-'));
+        assert_eq!(long, "support-configurable-timeouts-for-every");
+    }
+
+    #[test]
+    fn test_issue_vars() {
+        let issue = Issue {
+            id: "7".into(),
+            title: "Add dark mode".into(),
+            url: "https://github.com/o/r/issues/7".into(),
+        };
+        let vars = issue.vars();
+        assert!(vars.contains(&("issue", "7".into())));
+        assert!(vars.contains(&("issue_key", "7".into())));
+        assert!(vars.contains(&("slug", "add-dark-mode".into())));
+        assert_eq!(issue.reference(), "#7");
+
+        let ticket = Issue {
+            id: "PROJ-12".into(),
+            ..issue
+        };
+        assert_eq!(ticket.reference(), "PROJ-12");
+    }
+}
@@ -0,0 +1,187 @@
+//! Jira tickets for `wt switch --from-issue` and the `wt list` Ticket column.
+//!
+//! Jira has no CLI worktrunk can lean on the way it does `gh` and `glab`, so
+//! tickets come from the REST API through `curl`. The token from `wt auth
+//! login jira` is fed to curl on stdin (`--config -`) rather than the command
+//! line, keeping it out of the process list.
+
+use anyhow::{Context, bail};
+use regex::Regex;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use worktrunk::config::JiraConfig;
+use worktrunk::forge::{Forge, forge_cli, token};
+
+/// A Jira ticket's summary and workflow status.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, JsonSchema)]
+pub(crate) struct Ticket {
+    pub key: String,
+    pub summary: String,
+    pub status: String,
+    pub url: String,
+}
+
+/// A configured Jira site.
+#[derive(Debug)]
+pub(crate) struct Jira {
+    url: String,
+    user: Option<String>,
+    key_pattern: Regex,
+}
+
+impl Jira {
+    /// The site from `[jira]`, or `None` when no URL is configured.
+    pub(crate) fn from_config(config: &JiraConfig) -> anyhow::Result<Option<Self>> {
+        let Some(url) = config.url() else {
+            return Ok(None);
+        };
+        let key_pattern = Regex::new(config.key_pattern())
+            .with_context(|| format!("Invalid [jira] key-pattern: {}", config.key_pattern()))?;
+        Ok(Some(Self {
+            url: url.to_string(),
+            user: config.user().map(str::to_string),
+            key_pattern,
+        }))
+    }
+
+    /// The ticket key named in `branch`, if any.
+    pub(crate) fn ticket_key<'a>(&self, branch: &'a str) -> Option<&'a str> {
+        self.key_pattern.find(branch).map(|m| m.as_str())
+    }
+
+    /// Whether `key` is a whole ticket key.
+    pub(crate) fn is_key(&self, key: &str) -> bool {
+        self.key_pattern
+            .find(key)
+            .is_some_and(|m| m.start() == 0 && m.end() == key.len())
+    }
+
+    pub(crate) fn browse_url(&self, key: &str) -> String {
+        format!("{}/browse/{key}", self.url)
+    }
+
+    /// Fetch a ticket's summary and status.
+    pub(crate) fn fetch(&self, key: &str) -> anyhow::Result<Ticket> {
+        #[derive(Deserialize)]
+        struct Response {
+            key: String,
+            fields: Fields,
+        }
+        #[derive(Deserialize)]
+        struct Fields {
+            summary: String,
+            status: Status,
+        }
+        #[derive(Deserialize)]
+        struct Status {
+            name: String,
+        }
+
+        let api_url = format!(
+            "{}/rest/api/2/issue/{}?fields=summary,status",
+            self.url,
+            urlencoding::encode(key)
+        );
+        let output = forge_cli("curl")
+            .args([
+                "--silent",
+                "--show-error",
+                "--fail",
+                "--max-time",
+                "15",
+                "--header",
+                "Accept: application/json",
+                "--config",
+                "-",
+            ])
+            .stdin_bytes(self.curl_config(&api_url))
+            .read_only()
+            .run()
+            .context("Failed to run curl")?;
+        if !output.status.success() {
+            bail!(
+                "Failed to fetch Jira ticket {key}: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        let response: Response = serde_json::from_slice(&output.stdout)
+            .with_context(|| format!("Failed to parse Jira response for {key}"))?;
+        Ok(Ticket {
+            url: self.browse_url(&response.key),
+            key: response.key,
+            summary: response.fields.summary,
+            status: response.fields.status.name,
+        })
+    }
+
+    /// curl config carrying the URL and credentials.
+    ///
+    /// Jira Cloud takes basic auth with the account email and an API token;
+    /// Data Center takes a personal access token as a bearer token.
+    fn curl_config(&self, api_url: &str) -> String {
+        let mut config = format!("url = \"{}\"\n", escape(api_url));
+        if let Some((token, _)) = token(Forge::Jira) {
+            match &self.user {
+                Some(user) => {
+                    config.push_str(&format!("user = \"{}:{}\"\n", escape(user), escape(&token)))
+                }
+                None => config.push_str(&format!(
+                    "header = \"Authorization: Bearer {}\"\n",
+                    escape(&token)
+                )),
+            }
+        }
+        config
+    }
+}
+
+/// Escape a value for a double-quoted curl config string.
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn jira() -> Jira {
+        Jira::from_config(&JiraConfig {
+            url: Some("https://acme.atlassian.net/".into()),
+            ..Default::default()
+        })
+        .unwrap()
+        .unwrap()
+    }
+
+    #[test]
+    fn test_ticket_key() {
+        let jira = jira();
+        assert_eq!(jira.ticket_key("feature/PROJ-123-login"), Some("PROJ-123"));
+        assert_eq!(jira.ticket_key("AB2-7"), Some("AB2-7"));
+        assert_eq!(jira.ticket_key("fix-login"), None);
+        assert!(jira.is_key("PROJ-123"));
+        assert!(!jira.is_key("PROJ-123-login"));
+        assert!(!jira.is_key("1234"));
+    }
+
+    #[test]
+    fn test_browse_url_and_config() {
+        let jira = jira();
+        assert_eq!(
+            jira.browse_url("PROJ-1"),
+            "https://acme.atlassian.net/browse/PROJ-1"
+        );
+        assert!(Jira::from_config(&JiraConfig::default()).unwrap().is_none());
+        let invalid = JiraConfig {
+            url: Some("https://acme.atlassian.net".into()),
+            key_pattern: Some("[".into()),
+            ..Default::default()
+        };
+        assert!(Jira::from_config(&invalid).is_err());
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape(r#"a"b\c"#), r#"a\"b\\c"#);
+    }
+}
@@ -0,0 +1,96 @@
+//! `wt jump`: switch to the most-used worktree matching a partial name.
+
+use color_print::cformat;
+use worktrunk::config::UserConfig;
+use worktrunk::git::{GitError, Repository, WorktreeInfo};
+
+use super::frecency::Frecency;
+use super::handle_switch::{SwitchOptions, handle_switch};
+
+/// Handle `wt jump <QUERY>...`.
+pub fn handle_jump(
+    query: &[String],
+    config: &mut UserConfig,
+    binary_name: &str,
+) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let frecency = Frecency::load(repo.git_common_dir());
+    let current = repo.current_worktree().root().ok();
+
+    let worktrees = repo.list_worktrees()?;
+    let candidates: Vec<&WorktreeInfo> = worktrees
+        .iter()
+        .filter(|wt| !wt.bare && !wt.is_prunable() && wt.branch.is_some())
+        .filter(|wt| matches_query(wt, query))
+        .collect();
+
+    // Like zoxide, prefer somewhere other than here when there's a choice
+    let best = candidates
+        .iter()
+        .filter(|wt| candidates.len() == 1 || current.as_deref() != Some(wt.path.as_path()))
+        .max_by(|a, b| frecency.score(&a.path).total_cmp(&frecency.score(&b.path)));
+    let Some(branch) = best.and_then(|wt| wt.branch.as_deref()) else {
+        return Err(GitError::Other {
+            message: cformat!("No worktree matches <bold>{}</>", query.join(" ")),
+        }
+        .into());
+    };
+
+    handle_switch(
+        SwitchOptions {
+            branch,
+            create: false,
+            base: None,
+            sparse: None,
+            orphan: false,
+            devcontainer: false,
+            detach: false,
+            execute: None,
+            execute_args: &[],
+            open: None,
+            yes: false,
+            clobber: false,
+            change_dir: true,
+            verify: true,
+        },
+        config,
+        binary_name,
+    )
+}
+
+/// Every query term must appear (case-insensitively) in the branch or directory name.
+fn matches_query(wt: &WorktreeInfo, query: &[String]) -> bool {
+    let branch = wt.branch.as_deref().unwrap_or_default().to_lowercase();
+    let dir = wt
+        .path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    query.iter().all(|term| {
+        let term = term.to_lowercase();
+        branch.contains(&term) || dir.contains(&term)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_matches_query() {
+        let wt = WorktreeInfo {
+            path: PathBuf::from("/code/repo.feature-auth"),
+            head: "abc123".into(),
+            branch: Some("feature/Auth-Login".into()),
+            bare: false,
+            detached: false,
+            locked: None,
+            prunable: None,
+        };
+        let query = |terms: &[&str]| terms.iter().map(|t| t.to_string()).collect::<Vec<_>>();
+        assert!(matches_query(&wt, &query(&["auth"])));
+        assert!(matches_query(&wt, &query(&["feat", "login"])));
+        assert!(!matches_query(&wt, &query(&["auth", "billing"])));
+    }
+}
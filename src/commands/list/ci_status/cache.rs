@@ -67,20 +67,29 @@ impl CachedCiStatus {
     }
 
     /// Get the cache directory path: `.git/wt-cache/ci-status/`
-    fn cache_dir(repo: &Repository) -> PathBuf {
-        repo.git_common_dir().join("wt-cache").join("ci-status")
+    fn cache_dir(git_common_dir: &Path) -> PathBuf {
+        git_common_dir.join("wt-cache").join("ci-status")
     }
 
     /// Get the cache file path for a branch.
     fn cache_file(repo: &Repository, branch: &str) -> PathBuf {
-        let dir = Self::cache_dir(repo);
+        Self::cache_file_in(repo.git_common_dir(), branch)
+    }
+
+    fn cache_file_in(git_common_dir: &Path, branch: &str) -> PathBuf {
+        let dir = Self::cache_dir(git_common_dir);
         let safe_branch = sanitize_for_filename(branch);
         dir.join(format!("{safe_branch}.json"))
     }
 
     /// Read cached CI status from file.
     pub(super) fn read(repo: &Repository, branch: &str) -> Option<Self> {
-        let path = Self::cache_file(repo, branch);
+        Self::read_in(repo.git_common_dir(), branch)
+    }
+
+    /// Read cached CI status given the git common dir, without running git.
+    pub(super) fn read_in(git_common_dir: &Path, branch: &str) -> Option<Self> {
+        let path = Self::cache_file_in(git_common_dir, branch);
         let json = fs::read_to_string(&path).ok()?;
         serde_json::from_str(&json).ok()
     }
@@ -126,7 +135,7 @@ impl CachedCiStatus {
 
     /// List all cached CI statuses as (branch_name, cached_status) pairs.
     pub(crate) fn list_all(repo: &Repository) -> Vec<(String, Self)> {
-        let cache_dir = Self::cache_dir(repo);
+        let cache_dir = Self::cache_dir(repo.git_common_dir());
 
         let entries = match fs::read_dir(&cache_dir) {
             Ok(entries) => entries,
@@ -152,7 +161,7 @@ impl CachedCiStatus {
 
     /// Clear all cached CI statuses, returns count cleared.
     pub(crate) fn clear_all(repo: &Repository) -> usize {
-        let cache_dir = Self::cache_dir(repo);
+        let cache_dir = Self::cache_dir(repo.git_common_dir());
 
         let entries = match fs::read_dir(&cache_dir) {
             Ok(entries) => entries,
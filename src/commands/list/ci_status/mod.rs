@@ -8,9 +8,12 @@ mod github;
 mod gitlab;
 mod platform;
 
+use std::path::Path;
+
 use anstyle::{AnsiColor, Color, Style};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use worktrunk::forge::forge_cli;
 use worktrunk::git::Repository;
 use worktrunk::shell_exec::Cmd;
 use worktrunk::utils::get_now;
@@ -123,7 +126,7 @@ const MAX_PRS_TO_FETCH: u8 = 20;
 /// - Using TTY-specific output formatting
 /// - Opening browsers for authentication
 fn non_interactive_cmd(program: &str) -> Cmd {
-    Cmd::new(program)
+    forge_cli(program)
         .env_remove("CLICOLOR_FORCE")
         .env_remove("GH_FORCE_TTY")
         .env("NO_COLOR", "1")
@@ -136,7 +139,7 @@ fn non_interactive_cmd(program: &str) -> Cmd {
 /// On Windows, CreateProcessW (via Cmd) searches PATH for .exe files.
 /// We provide .exe mocks in tests via mock-stub, so this works consistently.
 fn tool_available(tool: &str, args: &[&str]) -> bool {
-    Cmd::new(tool)
+    forge_cli(tool)
         .args(args.iter().copied())
         .run()
         .map(|o| o.status.success())
@@ -247,7 +250,8 @@ pub struct PrStatus {
     pub ci_status: CiStatus,
     /// Source of the CI status (PR/MR or branch workflow)
     pub source: CiSource,
-    /// True if local HEAD differs from remote HEAD (unpushed changes)
+    /// True if local HEAD differs from remote HEAD (unpushed changes), or if
+    /// the status is an expired cache entry shown in offline mode
     pub is_stale: bool,
     /// URL to the PR/MR (if available)
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -333,7 +337,8 @@ impl PrStatus {
     /// Results (including None) are cached in `.git/wt-cache/ci-status/<branch>.json`
     /// for 30-60 seconds to avoid hitting GitHub API rate limits. TTL uses deterministic jitter
     /// based on repo path to spread cache expirations across concurrent statuslines. Invalidated
-    /// when HEAD changes.
+    /// when HEAD changes. In offline mode an expired entry is returned anyway,
+    /// marked stale, and nothing is fetched.
     ///
     /// # Fork Support
     /// Runs gh commands from the repository directory to enable auto-detection of
@@ -362,6 +367,14 @@ impl PrStatus {
                 );
                 return cached.status;
             }
+            // Offline, an expired entry is the best we have; dim it as stale
+            if worktrunk::offline::is_offline() {
+                log::debug!("Offline; using expired CI status for {}", branch.full_name);
+                return cached.status.map(|status| Self {
+                    is_stale: true,
+                    ..status
+                });
+            }
             log::debug!(
                 "Cache expired for {} (age={}s, ttl={}s, head_match={})",
                 branch.full_name,
@@ -371,7 +384,37 @@ impl PrStatus {
             );
         }
 
+        if worktrunk::offline::is_offline() {
+            return None;
+        }
+
         // Cache miss or expired - fetch fresh status
+        Self::detect_and_cache(repo, branch, local_head, has_upstream, now_secs)
+    }
+
+    /// Detect CI status without reading the cache, then refresh it.
+    ///
+    /// For callers polling for a change (`wt done --wait-ci`): a cached entry
+    /// from right after the push would otherwise hide progress for a full TTL.
+    pub fn detect_fresh(
+        repo: &Repository,
+        branch: &CiBranchName,
+        local_head: &str,
+    ) -> Option<Self> {
+        if worktrunk::offline::is_offline() {
+            return None;
+        }
+        let has_upstream = branch.has_upstream(repo);
+        Self::detect_and_cache(repo, branch, local_head, has_upstream, get_now())
+    }
+
+    fn detect_and_cache(
+        repo: &Repository,
+        branch: &CiBranchName,
+        local_head: &str,
+        has_upstream: bool,
+        now_secs: u64,
+    ) -> Option<Self> {
         let status = Self::detect_uncached(repo, branch, local_head, has_upstream);
 
         // Cache the result (including None - means no CI found for this branch)
@@ -386,6 +429,17 @@ impl PrStatus {
         status
     }
 
+    /// Last known CI status for `branch` at `local_head`, from the cache only.
+    ///
+    /// Never runs `gh`/`glab` or git, so it's cheap enough for shell prompts;
+    /// the TTL is ignored since a slightly stale status beats none. Returns
+    /// `None` when nothing is cached for this commit.
+    pub fn cached(git_common_dir: &Path, branch: &str, local_head: &str) -> Option<Self> {
+        CachedCiStatus::read_in(git_common_dir, branch)
+            .filter(|cached| cached.head == local_head)?
+            .status
+    }
+
     /// Detect CI status without caching (internal implementation)
     ///
     /// Platform is determined by project config override or remote URL detection.
@@ -9,14 +9,15 @@
 use std::sync::Arc;
 
 use crossbeam_channel as chan;
+use worktrunk::config::JiraConfig;
 use worktrunk::git::{BranchRef, Repository, WorktreeInfo};
 
 use super::CollectOptions;
 use super::tasks::{
     AheadBehindTask, BranchDiffTask, CiStatusTask, CommitDetailsTask, CommittedTreesMatchTask,
-    GitOperationTask, HasFileChangesTask, IsAncestorTask, MergeTreeConflictsTask, Task,
-    TaskContext, UpstreamTask, UrlStatusTask, UserMarkerTask, WorkingTreeConflictsTask,
-    WorkingTreeDiffTask, WouldMergeAddTask,
+    DiskUsageTask, GitOperationTask, HasFileChangesTask, HealthTask, IsAncestorTask,
+    MergeTreeConflictsTask, Task, TaskContext, TicketTask, UpstreamTask, UrlStatusTask,
+    UserMarkerTask, WorkingTreeConflictsTask, WorkingTreeDiffTask, WouldMergeAddTask,
 };
 use super::types::{TaskError, TaskKind, TaskResult};
 
@@ -91,6 +92,9 @@ fn dispatch_task(kind: TaskKind, ctx: TaskContext) -> Result<TaskResult, TaskErr
         TaskKind::Upstream => UpstreamTask::compute(ctx),
         TaskKind::CiStatus => CiStatusTask::compute(ctx),
         TaskKind::UrlStatus => UrlStatusTask::compute(ctx),
+        TaskKind::DiskUsage => DiskUsageTask::compute(ctx),
+        TaskKind::Health => HealthTask::compute(ctx),
+        TaskKind::Ticket => TicketTask::compute(ctx),
     }
 }
 
@@ -166,8 +170,13 @@ pub fn work_items_for_worktree(
     let item_url = if include_url {
         options.url_template.as_ref().and_then(|template| {
             wt.branch.as_ref().and_then(|branch| {
-                let mut vars = std::collections::HashMap::new();
-                vars.insert("branch", branch.as_str());
+                let key_pattern = options
+                    .key_pattern
+                    .as_deref()
+                    .unwrap_or(JiraConfig::DEFAULT_KEY_PATTERN);
+                let branch_vars = worktrunk::config::branch_vars(branch, key_pattern);
+                let vars: std::collections::HashMap<&str, &str> =
+                    branch_vars.iter().map(|(k, v)| (*k, v.as_str())).collect();
                 worktrunk::config::expand_template(template, &vars, false, repo, "url-template")
                     .ok()
             })
@@ -192,6 +201,7 @@ pub fn work_items_for_worktree(
         branch_ref: BranchRef::from(wt),
         item_idx,
         item_url,
+        jira: options.jira.clone(),
     };
 
     // Check if this branch is stale and should skip expensive tasks.
@@ -202,7 +212,7 @@ pub fn work_items_for_worktree(
 
     let has_commits = wt.has_commits();
 
-    let mut items = Vec::with_capacity(15);
+    let mut items = Vec::with_capacity(18);
 
     // Helper to add a work item and register the expected result
     let mut add_item = |kind: TaskKind| {
@@ -228,10 +238,16 @@ pub fn work_items_for_worktree(
         TaskKind::MergeTreeConflicts,
         TaskKind::CiStatus,
         TaskKind::WouldMergeAdd,
+        TaskKind::DiskUsage,
+        TaskKind::Health,
+        TaskKind::Ticket,
     ] {
         if skip.contains(&kind) {
             continue;
         }
+        if kind == TaskKind::Ticket && options.jira.is_none() {
+            continue;
+        }
         // Skip expensive tasks for stale branches (far behind default branch)
         if is_stale && EXPENSIVE_TASKS.contains(&kind) {
             continue;
@@ -287,12 +303,13 @@ pub fn work_items_for_branch(
         branch_ref,
         item_idx,
         item_url: None, // Branches without worktrees don't have URLs
+        jira: options.jira.clone(),
     };
 
     // Check if this branch is stale and should skip expensive tasks.
     let is_stale = options.stale_branches.contains(branch_name);
 
-    let mut items = Vec::with_capacity(11);
+    let mut items = Vec::with_capacity(12);
 
     // Helper to add a work item and register the expected result
     let mut add_item = |kind: TaskKind| {
@@ -314,10 +331,14 @@ pub fn work_items_for_branch(
         TaskKind::MergeTreeConflicts,
         TaskKind::CiStatus,
         TaskKind::WouldMergeAdd,
+        TaskKind::Ticket,
     ] {
         if skip.contains(&kind) {
             continue;
         }
+        if kind == TaskKind::Ticket && options.jira.is_none() {
+            continue;
+        }
         // Skip expensive tasks for stale branches (far behind default branch)
         if is_stale && EXPENSIVE_TASKS.contains(&kind) {
             continue;
@@ -359,6 +380,8 @@ mod tests {
             skip_tasks,
             url_template: Some("http://localhost/{{ branch }}".to_string()),
             stale_branches: HashSet::new(),
+            jira: None,
+            key_pattern: None,
         };
 
         let expected_results = Arc::new(ExpectedResults::default());
@@ -105,13 +105,16 @@ use crossbeam_channel as chan;
 use dunce::canonicalize;
 use once_cell::sync::OnceCell;
 use rayon::prelude::*;
-use worktrunk::git::{Repository, WorktreeInfo};
+use worktrunk::git::{GitRemoteUrl, Repository, WorktreeInfo};
+use worktrunk::state::StateStore;
 use worktrunk::styling::{
-    INFO_SYMBOL, eprintln, format_with_gutter, hint_message, warning_message,
+    INFO_SYMBOL, Stream, eprintln, format_with_gutter, hint_message, supports_hyperlinks,
+    warning_message,
 };
 
 use crate::commands::is_worktree_at_expected_path;
 
+use super::ci_status::CiStatus;
 use super::model::{DisplayFields, ItemKind, ListItem, WorktreeData};
 
 // Re-exports for sibling modules (columns.rs, render.rs, layout.rs)
@@ -183,6 +186,14 @@ pub struct CollectOptions {
     /// TODO: Consider adding a visible indicator in Status column when integration
     /// checks are skipped, so users know the `⊂` symbol may be incomplete.
     pub stale_branches: std::collections::HashSet<String>,
+
+    /// Jira site for the Ticket column (`[jira] list`). The Ticket task only
+    /// runs when this is set.
+    pub jira: Option<Arc<crate::commands::jira::Jira>>,
+
+    /// `[jira] key-pattern` for `issue_key` in the URL template (default
+    /// pattern when unset).
+    pub key_pattern: Option<String>,
 }
 
 fn worktree_branch_set(worktrees: &[WorktreeInfo]) -> std::collections::HashSet<&str> {
@@ -199,6 +210,8 @@ fn worktree_branch_set(worktrees: &[WorktreeInfo]) -> std::collections::HashSet<
 /// - If `render_table` is true: renders final table (buffered mode)
 /// - If `render_table` is false: returns data without rendering (JSON mode)
 ///
+/// With `group_by`, the buffered table is split into sections under headings.
+///
 /// The `command_timeout` parameter, if set, limits how long individual git commands can run.
 /// This is useful for `wt switch` interactive picker to show the TUI faster by skipping slow operations.
 ///
@@ -214,14 +227,17 @@ pub fn collect(
     show_branches: bool,
     show_remotes: bool,
     skip_tasks: &std::collections::HashSet<TaskKind>,
+    columns: Option<&[super::columns::ColumnKind]>,
     show_progress: bool,
     render_table: bool,
+    group_by: Option<crate::cli::GroupBy>,
     config: &worktrunk::config::UserConfig,
     command_timeout: Option<std::time::Duration>,
     skip_expensive_for_stale: bool,
 ) -> anyhow::Result<Option<super::model::ListData>> {
     use super::progressive_table::ProgressiveTable;
     worktrunk::shell_exec::trace_instant("List collect started");
+    let _phase = worktrunk::perf::phase("list collect");
 
     // Phase 1: Parallel fetch of ALL independent git data
     //
@@ -391,6 +407,13 @@ pub fn collect(
             let mut worktree_data =
                 WorktreeData::from_worktree(wt, is_main, is_current, is_previous);
             worktree_data.branch_worktree_mismatch = branch_worktree_mismatch;
+            // One git call, only for the rare detached worktree
+            if wt.detached {
+                worktree_data.detached_at = repo
+                    .run_command(&["describe", "--tags", "--exact-match", &wt.head])
+                    .ok()
+                    .map(|tag| tag.trim().to_string());
+            }
 
             // URL expanded post-skeleton to minimize time-to-skeleton
             ListItem {
@@ -408,6 +431,10 @@ pub fn collect(
                 pr_status: None,
                 url: None,
                 url_active: None,
+                note: None,
+                protected: false,
+                ticket: None,
+                container: None,
                 status_symbols: None,
                 display: DisplayFields::default(),
                 kind: ItemKind::Worktree(Box::new(worktree_data)),
@@ -427,30 +454,72 @@ pub fn collect(
     all_items.extend(
         remote_branches
             .iter()
-            .map(|(name, sha)| ListItem::new_branch(sha.clone(), name.clone())),
+            .map(|(name, sha)| ListItem::new_remote_branch(sha.clone(), name.clone())),
     );
 
+    // Notes come from the state store and protection from the project config;
+    // both are needed before layout, which sizes the Branch and Note columns
+    let mut notes = StateStore::new(repo.git_common_dir())
+        .load()
+        .map(|state| state.notes)
+        .unwrap_or_default();
+    let project_config = repo.load_project_config().ok().flatten();
+    // Only runs docker when some branch has a dev container
+    let mut containers = crate::commands::devcontainer::container_states(repo);
+    for item in &mut all_items {
+        if let Some(branch) = &item.branch {
+            item.note = notes.remove(branch);
+            item.container = containers.remove(branch);
+            item.protected = project_config
+                .as_ref()
+                .is_some_and(|config| config.is_protected_branch(branch));
+        }
+    }
+
     // If no URL template configured, add UrlStatus to skip_tasks
     let mut effective_skip_tasks = skip_tasks.clone();
     if url_template.is_none() {
         effective_skip_tasks.insert(TaskKind::UrlStatus);
     }
 
+    // Tickets need a Jira site; without one the column is hidden
+    let project_id = repo.project_identifier().ok();
+    let jira_config = config.jira(project_id.as_deref()).unwrap_or_default();
+    let jira = if effective_skip_tasks.contains(&TaskKind::Ticket) {
+        None
+    } else {
+        crate::commands::jira::Jira::from_config(&jira_config)?.map(Arc::new)
+    };
+    if jira.is_none() {
+        effective_skip_tasks.insert(TaskKind::Ticket);
+    }
+
+    let width_limits = super::columns::configured_width_limits(config, project_id.as_deref())?;
+
     // Calculate layout from items (worktrees, local branches, and remote branches)
-    let layout = super::layout::calculate_layout_from_basics(
+    let mut layout = super::layout::calculate_layout_from_basics(
         &all_items,
         &effective_skip_tasks,
+        columns,
+        &width_limits,
         &main_worktree.path,
         url_template.as_deref(),
     );
+    if supports_hyperlinks(Stream::Stdout) {
+        layout.forge_url = repo
+            .primary_remote_url()
+            .and_then(|url| GitRemoteUrl::parse(&url));
+    }
 
     // Single-line invariant: use safe width to prevent line wrapping
-    let max_width = crate::display::get_terminal_width();
+    let max_width = super::layout::list_width();
 
     // Create collection options from skip set
     let mut options = CollectOptions {
         skip_tasks: effective_skip_tasks,
         url_template: url_template.clone(),
+        jira,
+        key_pattern: jira_config.key_pattern,
         ..Default::default()
     };
 
@@ -832,12 +901,7 @@ pub fn collect(
         } else {
             // Non-TTY: output to stdout (same as buffered mode)
             // Progressive skeleton was suppressed; now output the final table
-            println!("{}", layout.format_header_line());
-            for item in &all_items {
-                println!("{}", layout.format_list_item_line(item));
-            }
-            println!();
-            println!("{}", final_msg);
+            print_table(repo, &layout, &all_items, group_by, &final_msg)?;
         }
     } else if render_table {
         // Buffered mode: render final table
@@ -849,12 +913,7 @@ pub fn collect(
             timed_out_count,
         );
 
-        println!("{}", layout.format_header_line());
-        for item in &all_items {
-            println!("{}", layout.format_list_item_line(item));
-        }
-        println!();
-        println!("{}", final_msg);
+        print_table(repo, &layout, &all_items, group_by, &final_msg)?;
     }
 
     // Status symbols are now computed during data collection (both modes), no fallback needed
@@ -899,6 +958,29 @@ pub fn collect(
         eprintln!("{}", hint_message(crate::diagnostic::issue_hint()));
     }
 
+    // CI lookups that still failed after retries (rate limit, network) show as ⚠
+    let ci_errors = all_items
+        .iter()
+        .filter(|item| {
+            matches!(&item.pr_status, Some(Some(status)) if status.ci_status == CiStatus::Error)
+        })
+        .count();
+    if ci_errors > 0 {
+        eprintln!(
+            "{}",
+            warning_message(format!(
+                "CI status unavailable for {ci_errors} branch{} (rate limit or network); shown as ⚠",
+                if ci_errors == 1 { "" } else { "es" }
+            ))
+        );
+        eprintln!(
+            "{}",
+            hint_message(cformat!(
+                "Check the forge token with <bright-black>wt auth status</>"
+            ))
+        );
+    }
+
     // Populate display fields for all items (used by JSON output and statusline)
     for item in &mut all_items {
         item.finalize_display();
@@ -920,6 +1002,56 @@ pub fn collect(
     }))
 }
 
+/// Print the finished table to stdout, through the pager when it's taller
+/// than the terminal.
+///
+/// With `group_by`, rows go under a heading per group, a blank line between
+/// groups, all below the one column header.
+fn print_table(
+    repo: &Repository,
+    layout: &super::layout::LayoutConfig,
+    items: &[ListItem],
+    group_by: Option<crate::cli::GroupBy>,
+    final_msg: &str,
+) -> anyhow::Result<()> {
+    let mut table = layout.format_header_line() + "\n";
+    match group_by {
+        Some(by) => {
+            let stacks = if by == crate::cli::GroupBy::Stack {
+                StateStore::new(repo.git_common_dir()).load()?.stacks
+            } else {
+                Default::default()
+            };
+            let now = worktrunk::utils::get_now() as i64;
+            for (i, group) in super::group::group_items(items, by, &stacks, now)
+                .iter()
+                .enumerate()
+            {
+                if i > 0 {
+                    table.push('\n');
+                }
+                table.push_str(&group.heading());
+                table.push('\n');
+                for item in &group.items {
+                    table.push_str(&layout.format_list_item_line(item));
+                    table.push('\n');
+                }
+            }
+        }
+        None => {
+            for item in items {
+                table.push_str(&layout.format_list_item_line(item));
+                table.push('\n');
+            }
+        }
+    }
+    table.push('\n');
+    table.push_str(final_msg);
+    table.push('\n');
+    crate::pager::page_stdout(&table)?;
+    Ok(())
+}
+
 // ============================================================================
 // Sorting Helpers
 // ============================================================================
@@ -1002,6 +1134,10 @@ pub fn build_worktree_item(
         pr_status: None,
         url: None,
         url_active: None,
+        note: None,
+        protected: false,
+        ticket: None,
+        container: None,
         status_symbols: None,
         display: DisplayFields::default(),
         kind: ItemKind::Worktree(Box::new(WorktreeData::from_worktree(
@@ -87,6 +87,18 @@ pub(super) fn apply_default(
             // URL is set at item creation, only default url_active
             items[idx].url_active = None;
         }
+        TaskKind::DiskUsage => {
+            // Leave as None — size is omitted for worktrees that couldn't be measured
+        }
+        TaskKind::Health => {
+            // Record no probed problems, so the cell stops showing a placeholder
+            if let ItemKind::Worktree(data) = &mut items[idx].kind {
+                data.health = Some(Vec::new());
+            }
+        }
+        TaskKind::Ticket => {
+            // Leave as None — the Ticket column stays empty when Jira is unreachable
+        }
     }
 }
 
@@ -290,6 +302,23 @@ pub(super) fn drain_results(
                     item.url_active = active;
                 }
             }
+            TaskResult::DiskUsage { bytes, .. } => {
+                if let ItemKind::Worktree(data) = &mut item.kind {
+                    data.disk_usage = Some(bytes);
+                } else {
+                    debug_assert!(false, "DiskUsage result for non-worktree item");
+                }
+            }
+            TaskResult::Health { flags, .. } => {
+                if let ItemKind::Worktree(data) = &mut item.kind {
+                    data.health = Some(flags);
+                } else {
+                    debug_assert!(false, "Health result for non-worktree item");
+                }
+            }
+            TaskResult::Ticket { ticket, .. } => {
+                item.ticket = ticket;
+            }
         }
 
         // Invoke callback (progressive mode re-renders rows, buffered mode does nothing)
@@ -1,19 +1,25 @@
 //! Task trait and implementations.
 //!
-//! Contains the `Task` trait interface and all 15 task implementations that
+//! Contains the `Task` trait interface and all 18 task implementations that
 //! compute various git operations for worktrees and branches.
 
 use std::net::{SocketAddr, TcpStream};
+use std::path::Path;
+use std::sync::Arc;
 use std::time::Duration;
 
 use worktrunk::git::{LineDiff, Repository};
 
 use super::super::ci_status::{CiBranchName, PrStatus};
+use super::super::disk_usage;
+use super::super::health;
 use super::super::model::{
     ActiveGitOperation, AheadBehind, BranchDiffTotals, CommitDetails, UpstreamStatus,
     WorkingTreeStatus,
 };
+use super::super::status_cache::{self, CachedStatus};
 use super::types::{ErrorCause, TaskError, TaskKind, TaskResult};
+use crate::commands::jira::Jira;
 
 // ============================================================================
 // Task Context
@@ -39,6 +45,8 @@ pub struct TaskContext {
     /// Expanded URL for this item (from project config template).
     /// UrlStatusTask uses this to check if the port is listening.
     pub item_url: Option<String>,
+    /// Jira site for TicketTask (`[jira] list` only).
+    pub jira: Option<Arc<Jira>>,
 }
 
 impl TaskContext {
@@ -368,14 +376,36 @@ impl Task for WorkingTreeDiffTask {
             .working_tree(&ctx.repo)
             .expect("WorkingTreeDiffTask requires a worktree");
 
+        // Unchanged since a watcher-validated run: skip `git status` entirely
+        let common_dir = ctx.repo.git_common_dir();
+        if let Some(cached) = CachedStatus::read(common_dir, wt.path()) {
+            let (mut working_tree_status, _, has_conflicts) =
+                parse_working_tree_status(&cached.status_output);
+            if working_tree_status.modified && wt.has_submodules() {
+                working_tree_status.submodule =
+                    has_dirty_submodule(&cached.status_output, wt.path());
+            }
+            return Ok(TaskResult::WorkingTreeDiff {
+                item_idx: ctx.item_idx,
+                working_tree_diff: cached.working_tree_diff,
+                working_tree_status,
+                has_conflicts,
+            });
+        }
+        // Read before computing, so changes made meanwhile invalidate the entry
+        let generation = status_cache::generation(common_dir, wt.path());
+
         // Use --no-optional-locks to avoid index lock contention with WorkingTreeConflictsTask's
         // `git stash create` which needs the index lock.
         let status_output = wt
             .run_command(&["--no-optional-locks", "status", "--porcelain"])
             .map_err(|e| ctx.error(Self::KIND, &e))?;
 
-        let (working_tree_status, is_dirty, has_conflicts) =
+        let (mut working_tree_status, is_dirty, has_conflicts) =
             parse_working_tree_status(&status_output);
+        if working_tree_status.modified && wt.has_submodules() {
+            working_tree_status.submodule = has_dirty_submodule(&status_output, wt.path());
+        }
 
         let working_tree_diff = if is_dirty {
             wt.working_tree_diff_stats()
@@ -383,6 +413,15 @@ impl Task for WorkingTreeDiffTask {
         } else {
             LineDiff::default()
         };
+        if let Some(generation) = generation {
+            CachedStatus::write(
+                common_dir,
+                wt.path(),
+                generation,
+                &status_output,
+                working_tree_diff,
+            );
+        }
 
         Ok(TaskResult::WorkingTreeDiff {
             item_idx: ctx.item_idx,
@@ -655,6 +694,78 @@ impl Task for UrlStatusTask {
     }
 }
 
+/// Task 14 (worktree only, --du only): On-disk size of the worktree directory
+pub struct DiskUsageTask;
+
+impl Task for DiskUsageTask {
+    const KIND: TaskKind = TaskKind::DiskUsage;
+
+    fn compute(ctx: TaskContext) -> Result<TaskResult, TaskError> {
+        // This task is only spawned for worktree items, so worktree path is always present.
+        let wt = ctx
+            .branch_ref
+            .working_tree(&ctx.repo)
+            .expect("DiskUsageTask requires a worktree");
+        let bytes = disk_usage::worktree_size(ctx.repo.git_common_dir(), wt.path())
+            .map_err(|e| ctx.error(Self::KIND, &anyhow::Error::from(e)))?;
+        Ok(TaskResult::DiskUsage {
+            item_idx: ctx.item_idx,
+            bytes,
+        })
+    }
+}
+
+/// Task 15 (`[jira] list` only): Jira ticket named in the branch
+pub struct TicketTask;
+
+impl Task for TicketTask {
+    const KIND: TaskKind = TaskKind::Ticket;
+
+    fn compute(ctx: TaskContext) -> Result<TaskResult, TaskError> {
+        // Only spawned when a Jira site is configured.
+        let jira = ctx.jira.as_ref().expect("TicketTask requires a Jira site");
+        let key = ctx
+            .branch_ref
+            .branch
+            .as_deref()
+            .and_then(|branch| jira.ticket_key(branch));
+        let ticket = match key {
+            Some(key) => Some(jira.fetch(key).map_err(|e| ctx.error(Self::KIND, &e))?),
+            None => None,
+        };
+        Ok(TaskResult::Ticket {
+            item_idx: ctx.item_idx,
+            ticket,
+        })
+    }
+}
+
+/// Task 16 (worktree only, --health only): Problems that need git calls to find
+pub struct HealthTask;
+
+impl Task for HealthTask {
+    const KIND: TaskKind = TaskKind::Health;
+
+    fn compute(ctx: TaskContext) -> Result<TaskResult, TaskError> {
+        // This task is only spawned for worktree items, so worktree path is always present.
+        let wt = ctx
+            .branch_ref
+            .working_tree(&ctx.repo)
+            .expect("HealthTask requires a worktree");
+        let flags = health::probe(
+            &ctx.repo,
+            wt.path(),
+            ctx.branch_ref.branch.as_deref(),
+            &ctx.branch_ref.commit_sha,
+        )
+        .map_err(|e| ctx.error(Self::KIND, &e))?;
+        Ok(TaskResult::Health {
+            item_idx: ctx.item_idx,
+            flags,
+        })
+    }
+}
+
 // ============================================================================
 // Helper Functions
 // ============================================================================
@@ -686,6 +797,17 @@ pub(crate) fn parse_port_from_url(url: &str) -> Option<u16> {
 
 /// Parse git status output to extract working tree status and conflict state.
 /// Returns (WorkingTreeStatus, is_dirty, has_conflicts).
+/// Whether any modified path in `git status --porcelain` output is a submodule.
+///
+/// Porcelain v1 reports a changed submodule like any modified file; a
+/// populated submodule is recognizable by its own `.git` file.
+fn has_dirty_submodule(status_output: &str, worktree_path: &Path) -> bool {
+    status_output
+        .lines()
+        .filter(|line| line.len() > 3 && line.as_bytes()[1] == b'M')
+        .any(|line| worktree_path.join(&line[3..]).join(".git").exists())
+}
+
 pub(super) fn parse_working_tree_status(status_output: &str) -> (WorkingTreeStatus, bool, bool) {
     let mut has_untracked = false;
     let mut has_modified = false;
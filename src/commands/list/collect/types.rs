@@ -9,10 +9,12 @@
 use worktrunk::git::LineDiff;
 
 use super::super::ci_status::PrStatus;
+use super::super::health::HealthFlag;
 use super::super::model::{
     ActiveGitOperation, AheadBehind, BranchDiffTotals, CommitDetails, ListItem, UpstreamStatus,
     WorkingTreeStatus,
 };
+use crate::commands::jira::Ticket;
 
 /// Context for status symbol computation during result processing
 #[derive(Clone, Default)]
@@ -154,6 +156,19 @@ pub(crate) enum TaskResult {
         /// Whether the port is listening (None if no URL or couldn't parse port)
         active: Option<bool>,
     },
+    /// On-disk size of the worktree (`--du` only)
+    DiskUsage { item_idx: usize, bytes: u64 },
+    /// Worktree problems found by git calls (`--health` only)
+    Health {
+        item_idx: usize,
+        flags: Vec<HealthFlag>,
+    },
+    /// Jira ticket named in the branch (`[jira] list` only); None when the
+    /// branch names no ticket
+    Ticket {
+        item_idx: usize,
+        ticket: Option<Ticket>,
+    },
 }
 
 impl TaskResult {
@@ -174,7 +189,10 @@ impl TaskResult {
             | TaskResult::UserMarker { item_idx, .. }
             | TaskResult::Upstream { item_idx, .. }
             | TaskResult::CiStatus { item_idx, .. }
-            | TaskResult::UrlStatus { item_idx, .. } => *item_idx,
+            | TaskResult::UrlStatus { item_idx, .. }
+            | TaskResult::DiskUsage { item_idx, .. }
+            | TaskResult::Health { item_idx, .. }
+            | TaskResult::Ticket { item_idx, .. } => *item_idx,
         }
     }
 }
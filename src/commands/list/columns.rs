@@ -1,5 +1,12 @@
+use std::collections::{BTreeMap, HashMap};
+
+use worktrunk::config::{ColumnWidthLimit, UserConfig};
+
 use super::collect::TaskKind;
 
+/// Per-column width limits from `[list] column-widths`.
+pub type WidthLimits = HashMap<ColumnKind, ColumnWidthLimit>;
+
 /// Logical identifier for each column rendered by `wt list`.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum ColumnKind {
@@ -10,8 +17,13 @@ pub enum ColumnKind {
     AheadBehind,
     BranchDiff,
     Path,
+    Health,    // Worktree problems (--health)
+    DiskUsage, // On-disk size (--du)
     Upstream,
-    Url, // Dev server URL from project config template
+    Url,    // Dev server URL from project config template
+    Note,   // Branch note from the state store (issue link)
+    Ticket, // Jira ticket status ([jira] list)
+    Docker, // Dev container state (wt switch --devcontainer)
     CiStatus,
     Commit,
     Time,
@@ -28,8 +40,13 @@ impl ColumnKind {
             ColumnKind::AheadBehind => "main↕",
             ColumnKind::BranchDiff => "main…±",
             ColumnKind::Path => "Path",
+            ColumnKind::Health => "Health",
+            ColumnKind::DiskUsage => "Size",
             ColumnKind::Upstream => "Remote⇅",
             ColumnKind::Url => "URL",
+            ColumnKind::Note => "Note",
+            ColumnKind::Ticket => "Ticket",
+            ColumnKind::Docker => "Docker",
             ColumnKind::Time => "Age",
             ColumnKind::CiStatus => "CI",
             ColumnKind::Commit => "Commit",
@@ -37,6 +54,32 @@ impl ColumnKind {
         }
     }
 
+    /// Name used to select this column with `--columns` and `[list] columns`.
+    ///
+    /// The gutter has no name; it's always shown.
+    pub const fn name(self) -> Option<&'static str> {
+        match self {
+            ColumnKind::Gutter => None,
+            ColumnKind::Branch => Some("branch"),
+            ColumnKind::Status => Some("status"),
+            ColumnKind::WorkingDiff => Some("head"),
+            ColumnKind::AheadBehind => Some("ahead"),
+            ColumnKind::BranchDiff => Some("main-diff"),
+            ColumnKind::Path => Some("path"),
+            ColumnKind::Health => Some("health"),
+            ColumnKind::DiskUsage => Some("size"),
+            ColumnKind::Upstream => Some("remote"),
+            ColumnKind::Url => Some("url"),
+            ColumnKind::Note => Some("note"),
+            ColumnKind::Ticket => Some("ticket"),
+            ColumnKind::Docker => Some("docker"),
+            ColumnKind::CiStatus => Some("ci"),
+            ColumnKind::Commit => Some("commit"),
+            ColumnKind::Time => Some("age"),
+            ColumnKind::Message => Some("message"),
+        }
+    }
+
     /// Get the base priority for this column (lower = more important).
     ///
     /// Used by both `wt list` layout and statusline truncation to ensure
@@ -91,14 +134,75 @@ pub const COLUMN_SPECS: &[ColumnSpec] = &[
     ColumnSpec::new(ColumnKind::AheadBehind, 4, None),
     ColumnSpec::new(ColumnKind::BranchDiff, 6, Some(TaskKind::BranchDiff)),
     ColumnSpec::new(ColumnKind::Path, 7, None),
+    ColumnSpec::new(ColumnKind::Health, 17, Some(TaskKind::Health)),
+    ColumnSpec::new(ColumnKind::DiskUsage, 13, Some(TaskKind::DiskUsage)),
     ColumnSpec::new(ColumnKind::Upstream, 8, None),
     ColumnSpec::new(ColumnKind::Url, 9, Some(TaskKind::UrlStatus)),
+    ColumnSpec::new(ColumnKind::Note, 14, None),
+    ColumnSpec::new(ColumnKind::Ticket, 15, Some(TaskKind::Ticket)),
+    ColumnSpec::new(ColumnKind::Docker, 16, None),
     ColumnSpec::new(ColumnKind::CiStatus, 5, Some(TaskKind::CiStatus)),
     ColumnSpec::new(ColumnKind::Commit, 10, None),
     ColumnSpec::new(ColumnKind::Time, 11, None),
     ColumnSpec::new(ColumnKind::Message, 12, None),
 ];
 
+/// Parse column names from `--columns` or `[list] columns`, keeping their order.
+pub fn parse_column_names(names: &[String]) -> anyhow::Result<Vec<ColumnKind>> {
+    let mut columns = Vec::new();
+    for name in names.iter().map(|n| n.trim()).filter(|n| !n.is_empty()) {
+        let kind = parse_column_name(name)?;
+        if !columns.contains(&kind) {
+            columns.push(kind);
+        }
+    }
+    if columns.is_empty() {
+        anyhow::bail!("No columns selected");
+    }
+    Ok(columns)
+}
+
+/// Parse `[list] column-widths`, keyed by column name.
+pub fn parse_column_widths(
+    widths: &BTreeMap<String, ColumnWidthLimit>,
+) -> anyhow::Result<WidthLimits> {
+    widths
+        .iter()
+        .map(|(name, limit)| Ok((parse_column_name(name.trim())?, *limit)))
+        .collect()
+}
+
+/// Width limits from `[list] column-widths`, with project overrides.
+pub fn configured_width_limits(
+    config: &UserConfig,
+    project: Option<&str>,
+) -> anyhow::Result<WidthLimits> {
+    Ok(config
+        .list(project)
+        .and_then(|list| list.column_widths().map(parse_column_widths))
+        .transpose()?
+        .unwrap_or_default())
+}
+
+fn parse_column_name(name: &str) -> anyhow::Result<ColumnKind> {
+    match COLUMN_SPECS
+        .iter()
+        .find(|spec| spec.kind.name() == Some(name))
+    {
+        Some(spec) => Ok(spec.kind),
+        None => {
+            let valid: Vec<_> = COLUMN_SPECS
+                .iter()
+                .filter_map(|spec| spec.kind.name())
+                .collect();
+            anyhow::bail!(
+                "Unknown column '{name}'; valid columns: {}",
+                valid.join(", ")
+            );
+        }
+    }
+}
+
 pub fn column_display_index(kind: ColumnKind) -> usize {
     COLUMN_SPECS
         .iter()
@@ -122,8 +226,13 @@ mod tests {
             ColumnKind::AheadBehind,
             ColumnKind::BranchDiff,
             ColumnKind::Path,
+            ColumnKind::Health,
+            ColumnKind::DiskUsage,
             ColumnKind::Upstream,
             ColumnKind::Url,
+            ColumnKind::Note,
+            ColumnKind::Ticket,
+            ColumnKind::Docker,
             ColumnKind::CiStatus,
             ColumnKind::Commit,
             ColumnKind::Time,
@@ -152,11 +261,32 @@ mod tests {
             .unwrap();
         assert_eq!(ci_status.requires_task, Some(TaskKind::CiStatus));
 
+        let disk_usage = COLUMN_SPECS
+            .iter()
+            .find(|c| c.kind == ColumnKind::DiskUsage)
+            .unwrap();
+        assert_eq!(disk_usage.requires_task, Some(TaskKind::DiskUsage));
+
+        let health = COLUMN_SPECS
+            .iter()
+            .find(|c| c.kind == ColumnKind::Health)
+            .unwrap();
+        assert_eq!(health.requires_task, Some(TaskKind::Health));
+
+        let ticket = COLUMN_SPECS
+            .iter()
+            .find(|c| c.kind == ColumnKind::Ticket)
+            .unwrap();
+        assert_eq!(ticket.requires_task, Some(TaskKind::Ticket));
+
         // All other columns should not require a background task to render
         for spec in COLUMN_SPECS {
             if spec.kind != ColumnKind::BranchDiff
                 && spec.kind != ColumnKind::Url
                 && spec.kind != ColumnKind::CiStatus
+                && spec.kind != ColumnKind::DiskUsage
+                && spec.kind != ColumnKind::Health
+                && spec.kind != ColumnKind::Ticket
             {
                 assert!(
                     spec.requires_task.is_none(),
@@ -208,6 +338,43 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_column_names() {
+        let names = |s: &str| s.split(',').map(String::from).collect::<Vec<_>>();
+        assert_eq!(
+            parse_column_names(&names("branch,ahead,ci")).unwrap(),
+            vec![
+                ColumnKind::Branch,
+                ColumnKind::AheadBehind,
+                ColumnKind::CiStatus
+            ]
+        );
+        // Order is kept, duplicates and blanks dropped
+        assert_eq!(
+            parse_column_names(&names("message, branch,,message")).unwrap(),
+            vec![ColumnKind::Message, ColumnKind::Branch]
+        );
+        let err = parse_column_names(&names("branch,bogus")).unwrap_err();
+        assert!(err.to_string().contains("Unknown column 'bogus'"));
+        assert!(parse_column_names(&names("")).is_err());
+    }
+
+    #[test]
+    fn test_parse_column_widths() {
+        let limit = ColumnWidthLimit {
+            min: None,
+            max: Some(30),
+        };
+        let widths = BTreeMap::from([("branch".to_string(), limit)]);
+        assert_eq!(
+            parse_column_widths(&widths).unwrap(),
+            WidthLimits::from([(ColumnKind::Branch, limit)])
+        );
+        let widths = BTreeMap::from([("bogus".to_string(), limit)]);
+        let err = parse_column_widths(&widths).unwrap_err();
+        assert!(err.to_string().contains("Unknown column 'bogus'"));
+    }
+
     #[test]
     fn test_all_column_kinds_have_priority() {
         // Every ColumnKind variant must be in COLUMN_SPECS so priority() works correctly.
@@ -220,8 +387,13 @@ mod tests {
             ColumnKind::AheadBehind,
             ColumnKind::BranchDiff,
             ColumnKind::Path,
+            ColumnKind::Health,
+            ColumnKind::DiskUsage,
             ColumnKind::Upstream,
             ColumnKind::Url,
+            ColumnKind::Note,
+            ColumnKind::Ticket,
+            ColumnKind::Docker,
             ColumnKind::CiStatus,
             ColumnKind::Commit,
             ColumnKind::Time,
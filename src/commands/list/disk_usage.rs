@@ -0,0 +1,183 @@
+//! On-disk size of worktrees for `wt list --du`.
+//!
+//! Walking a worktree with a populated `node_modules` or `target/` takes
+//! seconds, so sizes are cached in `.git/wt-cache/disk-usage/<worktree>.json`
+//! for [`CachedDiskUsage::TTL_SECS`]. Each worktree is walked in its own task,
+//! so worktrees are measured in parallel.
+//!
+//! Sizes count allocated blocks where the platform reports them (like `du`),
+//! don't follow symlinks, and skip the main worktree's `.git` directory, which
+//! belongs to the repository rather than the worktree.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use worktrunk::path::sanitize_for_filename;
+use worktrunk::utils::get_now;
+
+/// Cached size stored in `.git/wt-cache/disk-usage/<worktree>.json`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedDiskUsage {
+    /// The worktree path, to detect filename collisions after sanitizing
+    path: PathBuf,
+    bytes: u64,
+    /// Unix timestamp when the size was measured
+    checked_at: u64,
+}
+
+impl CachedDiskUsage {
+    /// Sizes change slowly relative to how often `wt list` runs.
+    const TTL_SECS: u64 = 10 * 60;
+
+    fn cache_file(git_common_dir: &Path, worktree: &Path) -> PathBuf {
+        let name = sanitize_for_filename(&worktree.to_string_lossy());
+        git_common_dir
+            .join("wt-cache")
+            .join("disk-usage")
+            .join(format!("{name}.json"))
+    }
+
+    fn read(git_common_dir: &Path, worktree: &Path) -> Option<Self> {
+        let json = fs::read_to_string(Self::cache_file(git_common_dir, worktree)).ok()?;
+        let cached: Self = serde_json::from_str(&json).ok()?;
+        (cached.path == worktree).then_some(cached)
+    }
+
+    /// Best-effort atomic write; a failed write only costs a re-walk next time.
+    fn write(&self, git_common_dir: &Path) {
+        let path = Self::cache_file(git_common_dir, &self.path);
+        let Some(parent) = path.parent() else {
+            return;
+        };
+        if let Err(e) = fs::create_dir_all(parent) {
+            log::debug!("Failed to create disk usage cache dir: {e}");
+            return;
+        }
+        let Ok(json) = serde_json::to_string(self) else {
+            return;
+        };
+        let temp_path = path.with_extension("json.tmp");
+        if fs::write(&temp_path, json).is_ok() {
+            #[cfg(windows)]
+            let _ = fs::remove_file(&path);
+            if fs::rename(&temp_path, &path).is_err() {
+                let _ = fs::remove_file(&temp_path);
+            }
+        }
+    }
+}
+
+/// Size of a worktree in bytes, from cache when fresh.
+pub(crate) fn worktree_size(git_common_dir: &Path, worktree: &Path) -> std::io::Result<u64> {
+    let now = get_now();
+    if let Some(cached) = CachedDiskUsage::read(git_common_dir, worktree)
+        && now.saturating_sub(cached.checked_at) < CachedDiskUsage::TTL_SECS
+    {
+        return Ok(cached.bytes);
+    }
+
+    let bytes = dir_size(worktree, true)?;
+    CachedDiskUsage {
+        path: worktree.to_path_buf(),
+        bytes,
+        checked_at: now,
+    }
+    .write(git_common_dir);
+    Ok(bytes)
+}
+
+/// Clear all cached sizes, returns count cleared.
+pub(crate) fn clear_cache(git_common_dir: &Path) -> usize {
+    let Ok(entries) = fs::read_dir(git_common_dir.join("wt-cache").join("disk-usage")) else {
+        return 0;
+    };
+    entries
+        .flatten()
+        .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "json"))
+        .filter(|entry| fs::remove_file(entry.path()).is_ok())
+        .count()
+}
+
+/// Total size of everything under `dir`. Unreadable subdirectories count as
+/// empty; only a failure to read `dir` itself is an error.
+fn dir_size(dir: &Path, is_root: bool) -> std::io::Result<u64> {
+    let mut total = 0;
+    for entry in fs::read_dir(dir)?.flatten() {
+        if is_root && entry.file_name() == ".git" {
+            continue;
+        }
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        total += allocated_size(&metadata);
+        if metadata.is_dir() {
+            total += dir_size(&entry.path(), false).unwrap_or(0);
+        }
+    }
+    Ok(total)
+}
+
+#[cfg(unix)]
+fn allocated_size(metadata: &fs::Metadata) -> u64 {
+    use std::os::unix::fs::MetadataExt;
+    metadata.blocks() * 512
+}
+
+#[cfg(not(unix))]
+fn allocated_size(metadata: &fs::Metadata) -> u64 {
+    metadata.len()
+}
+
+/// Compact size for the table: `512B`, `4.0K`, `38M`, `1.2G`.
+pub(crate) fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "K", "M", "G", "T"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes}B")
+    } else if value < 10.0 {
+        format!("{value:.1}{}", UNITS[unit])
+    } else {
+        format!("{value:.0}{}", UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_size() {
+        assert_eq!(format_size(0), "0B");
+        assert_eq!(format_size(512), "512B");
+        assert_eq!(format_size(4096), "4.0K");
+        assert_eq!(format_size(38 * 1024 * 1024), "38M");
+        assert_eq!(format_size(1288490189), "1.2G");
+    }
+
+    #[test]
+    fn test_dir_size_skips_root_git_dir_and_caches() {
+        let dir = tempfile::tempdir().unwrap();
+        let worktree = dir.path().join("wt");
+        fs::create_dir_all(worktree.join(".git")).unwrap();
+        fs::create_dir_all(worktree.join("src")).unwrap();
+        fs::write(worktree.join(".git/big"), vec![0u8; 64 * 1024]).unwrap();
+        fs::write(worktree.join("src/lib.rs"), vec![0u8; 8 * 1024]).unwrap();
+
+        let size = dir_size(&worktree, true).unwrap();
+        assert!(size > 0 && size < 64 * 1024, "size = {size}");
+
+        let common_dir = dir.path().join("common");
+        assert_eq!(worktree_size(&common_dir, &worktree).unwrap(), size);
+        fs::write(worktree.join("src/more.rs"), vec![0u8; 64 * 1024]).unwrap();
+        assert_eq!(worktree_size(&common_dir, &worktree).unwrap(), size);
+
+        assert_eq!(clear_cache(&common_dir), 1);
+        assert!(worktree_size(&common_dir, &worktree).unwrap() > size);
+    }
+}
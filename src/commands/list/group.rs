@@ -0,0 +1,211 @@
+//! `wt list --group-by`: rows under section headings instead of one flat table.
+//!
+//! Groups are computed from the finished items, so a grouped table is always
+//! rendered buffered — a row's group isn't known until its status arrives.
+//! Within a group, rows keep their `wt list` order; empty groups are left out.
+
+use std::collections::BTreeMap;
+
+use worktrunk::styling::format_heading;
+
+use super::model::ListItem;
+use crate::cli::GroupBy;
+
+const DAY: i64 = 24 * 60 * 60;
+
+/// One section of a grouped table.
+pub(crate) struct Group<'a> {
+    /// Section heading, without styling
+    pub title: String,
+    /// Shown after the title, e.g. a stack's base branch
+    pub detail: Option<String>,
+    pub items: Vec<&'a ListItem>,
+}
+
+impl Group<'_> {
+    /// The styled heading line, with the row count.
+    pub(crate) fn heading(&self) -> String {
+        let count = self.items.len().to_string();
+        let suffix = match &self.detail {
+            Some(detail) => format!("{count}  {detail}"),
+            None => count,
+        };
+        format_heading(&self.title, Some(&suffix))
+    }
+}
+
+/// Split `items` into groups, in display order.
+///
+/// `stacks` maps each stacked branch to its parent (`wt stack add`); `now` is
+/// the Unix time that commit ages are measured from.
+pub(crate) fn group_items<'a>(
+    items: &'a [ListItem],
+    by: GroupBy,
+    stacks: &BTreeMap<String, String>,
+    now: i64,
+) -> Vec<Group<'a>> {
+    // Keyed by (order, title), so groups come out in display order
+    let mut groups: BTreeMap<(u8, String), Group<'a>> = BTreeMap::new();
+    for item in items {
+        let (key, title, detail) = match by {
+            GroupBy::Status => {
+                let (key, title) = status_group(item);
+                (key, title.to_string(), None)
+            }
+            GroupBy::Age => {
+                let (key, title) = age_group(item, now);
+                (key, title.to_string(), None)
+            }
+            GroupBy::Stack => match item.branch.as_deref().and_then(|b| stack_base(b, stacks)) {
+                Some((bottom, parent)) => (0, bottom.to_string(), Some(format!("on {parent}"))),
+                None => (1, "NOT STACKED".to_string(), None),
+            },
+        };
+        groups
+            .entry((key, title.clone()))
+            .or_insert_with(|| Group {
+                title,
+                detail,
+                items: Vec::new(),
+            })
+            .items
+            .push(item);
+    }
+    groups.into_values().collect()
+}
+
+/// Uncommitted changes first, then unmerged commits, then commits to pull in.
+fn status_group(item: &ListItem) -> (u8, &'static str) {
+    let dirty = item
+        .status_symbols
+        .as_ref()
+        .is_some_and(|s| s.working_tree.is_dirty());
+    let counts = item.counts.unwrap_or_default();
+    if dirty {
+        (0, "DIRTY")
+    } else if counts.ahead > 0 {
+        (1, "AHEAD")
+    } else if counts.behind > 0 {
+        (2, "BEHIND")
+    } else {
+        (3, "CLEAN")
+    }
+}
+
+/// Buckets by the age of the last commit; items without one count as older.
+fn age_group(item: &ListItem, now: i64) -> (u8, &'static str) {
+    let age = item.commit.as_ref().map(|c| now - c.timestamp);
+    match age {
+        Some(age) if age < DAY => (0, "TODAY"),
+        Some(age) if age < 7 * DAY => (1, "THIS WEEK"),
+        Some(age) if age < 30 * DAY => (2, "THIS MONTH"),
+        _ => (3, "OLDER"),
+    }
+}
+
+/// The bottom of `branch`'s stack and the branch it's stacked on.
+///
+/// The bottom is the lowest stacked branch: its parent isn't stacked itself.
+/// A branch that's only ever a parent isn't in a stack. Chains longer than
+/// `stacks` (a cycle from a hand-edited `state.json`) count as unstacked.
+fn stack_base<'a>(
+    branch: &'a str,
+    stacks: &'a BTreeMap<String, String>,
+) -> Option<(&'a str, &'a str)> {
+    let mut current = branch;
+    let mut parent = stacks.get(current)?.as_str();
+    for _ in 0..stacks.len() {
+        match stacks.get(parent) {
+            Some(grandparent) => {
+                current = parent;
+                parent = grandparent;
+            }
+            None => return Some((current, parent)),
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::list::model::{AheadBehind, CommitDetails};
+
+    fn item(branch: &str, ahead: usize, behind: usize, age_days: i64) -> ListItem {
+        let mut item = ListItem::new_branch("abc1234".into(), branch.into());
+        item.counts = Some(AheadBehind { ahead, behind });
+        item.commit = Some(CommitDetails {
+            timestamp: 1_000 * DAY - age_days * DAY,
+            commit_message: "message".into(),
+        });
+        item
+    }
+
+    fn titles<'a>(groups: &'a [Group]) -> Vec<(&'a str, Vec<&'a str>)> {
+        groups
+            .iter()
+            .map(|g| {
+                let branches = g.items.iter().map(|i| i.branch_name()).collect();
+                (g.title.as_str(), branches)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_group_by_status() {
+        let items = [item("a", 0, 3, 0), item("b", 2, 0, 0), item("c", 0, 0, 0)];
+        let groups = group_items(&items, GroupBy::Status, &BTreeMap::new(), 1_000 * DAY);
+        assert_eq!(
+            titles(&groups),
+            [
+                ("AHEAD", vec!["b"]),
+                ("BEHIND", vec!["a"]),
+                ("CLEAN", vec!["c"]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_group_by_age() {
+        let items = [
+            item("old", 0, 0, 90),
+            item("new", 0, 0, 0),
+            item("week", 0, 0, 3),
+        ];
+        let groups = group_items(&items, GroupBy::Age, &BTreeMap::new(), 1_000 * DAY);
+        assert_eq!(
+            titles(&groups),
+            [
+                ("TODAY", vec!["new"]),
+                ("THIS WEEK", vec!["week"]),
+                ("OLDER", vec!["old"]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_group_by_stack() {
+        let stacks: BTreeMap<String, String> = [
+            ("base".to_string(), "main".to_string()),
+            ("top".to_string(), "base".to_string()),
+            ("loop-a".to_string(), "loop-b".to_string()),
+            ("loop-b".to_string(), "loop-a".to_string()),
+        ]
+        .into();
+        let items = [
+            item("main", 0, 0, 0),
+            item("top", 0, 0, 0),
+            item("base", 0, 0, 0),
+            item("loop-a", 0, 0, 0),
+        ];
+        let groups = group_items(&items, GroupBy::Stack, &stacks, 1_000 * DAY);
+        assert_eq!(
+            titles(&groups),
+            [
+                ("base", vec!["top", "base"]),
+                ("NOT STACKED", vec!["main", "loop-a"]),
+            ]
+        );
+        assert_eq!(groups[0].detail.as_deref(), Some("on main"));
+    }
+}
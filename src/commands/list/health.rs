@@ -0,0 +1,208 @@
+//! Worktree health checks for `wt list --health` and `wt doctor --worktrees`.
+//!
+//! Prunable, locked and detached worktrees are known from `git worktree list`.
+//! The other problems need a git call or two per worktree, so `wt list` probes
+//! them in a background task.
+
+use std::path::Path;
+
+use color_print::cformat;
+use worktrunk::git::{NULL_OID, Repository};
+use worktrunk::utils::get_now;
+
+/// A worktree with no commits for this many days is flagged as stale.
+pub(crate) const STALE_DAYS: u64 = 30;
+
+/// A problem with a worktree, in the order the Health column shows them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, strum::IntoStaticStr)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum HealthFlag {
+    /// Directory deleted; git still tracks the worktree
+    Prunable,
+    /// Locked with `git worktree lock`
+    Locked,
+    /// Submodules not checked out, or at the wrong commit
+    Submodules,
+    /// Upstream branch deleted on the remote
+    UpstreamGone,
+    /// No commits in [`STALE_DAYS`] days
+    Stale,
+    /// HEAD not on a branch
+    Detached,
+}
+
+impl HealthFlag {
+    pub(crate) const ALL: [HealthFlag; 6] = [
+        HealthFlag::Prunable,
+        HealthFlag::Locked,
+        HealthFlag::Submodules,
+        HealthFlag::UpstreamGone,
+        HealthFlag::Stale,
+        HealthFlag::Detached,
+    ];
+
+    /// Short label for the Health column.
+    pub(crate) fn label(self) -> &'static str {
+        match self {
+            HealthFlag::UpstreamGone => "gone",
+            flag => flag.into(),
+        }
+    }
+
+    /// What the flag means, for `wt doctor --worktrees`.
+    pub(crate) fn explanation(self) -> String {
+        match self {
+            HealthFlag::Prunable => "directory is missing; git still tracks the worktree".into(),
+            HealthFlag::Locked => "locked, so git won't prune, move or remove it".into(),
+            HealthFlag::Submodules => {
+                "submodules aren't checked out at the recorded commits".into()
+            }
+            HealthFlag::UpstreamGone => {
+                "upstream branch was deleted on the remote, usually after merging".into()
+            }
+            HealthFlag::Stale => format!("no commits in {STALE_DAYS} days"),
+            HealthFlag::Detached => "HEAD is detached; new commits aren't on any branch".into(),
+        }
+    }
+
+    /// How to fix it; `name` is the branch, or the path for detached worktrees.
+    pub(crate) fn fix(self, name: &str, path: &str) -> String {
+        match self {
+            HealthFlag::Prunable => {
+                cformat!("Run <bright-black>git worktree prune</> to remove its metadata")
+            }
+            HealthFlag::Locked => cformat!(
+                "Run <bright-black>git worktree unlock {path}</> once it's no longer needed"
+            ),
+            HealthFlag::Submodules => {
+                cformat!("Run <bright-black>git submodule update --init --recursive</> in {path}")
+            }
+            HealthFlag::UpstreamGone => cformat!(
+                "Remove it with <bright-black>wt remove {name}</>, or keep it with <bright-black>git branch --unset-upstream {name}</>"
+            ),
+            HealthFlag::Stale => cformat!(
+                "Merge it with <bright-black>wt merge</>, or remove it with <bright-black>wt remove {name}</>"
+            ),
+            HealthFlag::Detached => cformat!(
+                "Run <bright-black>git switch -c BRANCH</> in {path} to keep its commits on a branch"
+            ),
+        }
+    }
+}
+
+/// Flags known from `git worktree list`.
+pub(crate) fn state_flags(prunable: bool, locked: bool, detached: bool) -> Vec<HealthFlag> {
+    [
+        (prunable, HealthFlag::Prunable),
+        (locked, HealthFlag::Locked),
+        (detached, HealthFlag::Detached),
+    ]
+    .into_iter()
+    .filter_map(|(set, flag)| set.then_some(flag))
+    .collect()
+}
+
+/// Flags that need git calls: submodules, gone upstream and staleness.
+///
+/// Only for worktrees whose directory exists.
+pub(crate) fn probe(
+    repo: &Repository,
+    path: &Path,
+    branch: Option<&str>,
+    head: &str,
+) -> anyhow::Result<Vec<HealthFlag>> {
+    let mut flags = Vec::new();
+
+    if path.join(".gitmodules").exists() {
+        let status = repo
+            .worktree_at(path)
+            .run_command(&["submodule", "status", "--recursive"])?;
+        if submodules_broken(&status) {
+            flags.push(HealthFlag::Submodules);
+        }
+    }
+
+    if let Some(branch) = branch
+        && repo.branch(branch).upstream_gone()?
+    {
+        flags.push(HealthFlag::UpstreamGone);
+    }
+
+    // An unborn branch has no commits to be stale
+    if head != NULL_OID && is_stale(repo.commit_timestamp(head)?, get_now()) {
+        flags.push(HealthFlag::Stale);
+    }
+
+    Ok(flags)
+}
+
+/// `git submodule status` marks uninitialized submodules with `-`, ones at a
+/// different commit than recorded with `+`, and merge conflicts with `U`.
+fn submodules_broken(status: &str) -> bool {
+    status.lines().any(|line| line.starts_with(['-', '+', 'U']))
+}
+
+fn is_stale(timestamp: i64, now: u64) -> bool {
+    let age = (now as i64).saturating_sub(timestamp);
+    age > (STALE_DAYS * 24 * 60 * 60) as i64
+}
+
+/// All flags for a worktree, in display order.
+pub(crate) fn sorted(mut flags: Vec<HealthFlag>) -> Vec<HealthFlag> {
+    flags.sort_by_key(|flag| HealthFlag::ALL.iter().position(|f| f == flag));
+    flags.dedup();
+    flags
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_submodules_broken() {
+        assert!(!submodules_broken(""));
+        assert!(!submodules_broken(
+            " 1234567890abcdef1234567890abcdef12345678 vendor/lib (v1.0)"
+        ));
+        assert!(submodules_broken(
+            "-1234567890abcdef1234567890abcdef12345678 vendor/lib"
+        ));
+        assert!(submodules_broken(
+            "+1234567890abcdef1234567890abcdef12345678 vendor/lib (v1.1)"
+        ));
+    }
+
+    #[test]
+    fn test_is_stale() {
+        let day = 24 * 60 * 60;
+        let now = 100 * day;
+        assert!(!is_stale((now - day) as i64, now));
+        assert!(!is_stale((now - STALE_DAYS * day) as i64, now));
+        assert!(is_stale((now - (STALE_DAYS + 1) * day) as i64, now));
+    }
+
+    #[test]
+    fn test_flags_sorted_and_labelled() {
+        let flags = sorted(vec![
+            HealthFlag::Detached,
+            HealthFlag::UpstreamGone,
+            HealthFlag::Locked,
+            HealthFlag::Detached,
+        ]);
+        assert_eq!(
+            flags,
+            vec![
+                HealthFlag::Locked,
+                HealthFlag::UpstreamGone,
+                HealthFlag::Detached
+            ]
+        );
+        let labels: Vec<_> = flags.iter().map(|f| f.label()).collect();
+        assert_eq!(labels, ["locked", "gone", "detached"]);
+        assert_eq!(
+            state_flags(true, true, false),
+            vec![HealthFlag::Prunable, HealthFlag::Locked]
+        );
+    }
+}
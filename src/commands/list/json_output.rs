@@ -23,6 +23,7 @@ use worktrunk::git::LineDiff;
 
 use super::ci_status::{CiSource, PrStatus};
 use super::model::{ItemKind, ListItem, UpstreamStatus};
+use crate::commands::jira::Ticket;
 
 /// JSON output for a single list item
 #[derive(Debug, Clone, Serialize, JsonSchema)]
@@ -34,7 +35,7 @@ pub struct JsonItem {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub path: Option<PathBuf>,
 
-    /// Item kind: "worktree" or "branch"
+    /// Item kind: "worktree", "branch", or "remote" (remote branch without a local branch)
     pub kind: &'static str,
 
     /// Commit information
@@ -91,6 +92,22 @@ pub struct JsonItem {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub url_active: Option<bool>,
 
+    /// Branch note, e.g. the issue link from `wt switch --from-issue`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+
+    /// Branch matches `[branches] protected` in the project config
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub protected: bool,
+
+    /// Jira ticket named in the branch (`[jira] list` only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ticket: Option<Ticket>,
+
+    /// Dev container state, e.g. `running` (`wt switch --devcontainer` only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub container: Option<String>,
+
     /// Pre-formatted statusline for statusline tools (tmux, starship)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub statusline: Option<String>,
@@ -134,6 +151,10 @@ pub struct JsonWorkingTree {
     /// Has deleted files (✘)
     pub deleted: bool,
 
+    /// Has submodules with changes (also reported as `modified`)
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub submodule: bool,
+
     /// Lines added/deleted in working tree vs HEAD
     #[serde(skip_serializing_if = "Option::is_none")]
     pub diff: Option<JsonDiff>,
@@ -198,6 +219,14 @@ pub struct JsonWorktree {
 
     /// HEAD is detached (not on a branch)
     pub detached: bool,
+
+    /// On-disk size in bytes (with `--du`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<u64>,
+
+    /// Worktree problems: "prunable", "locked", "submodules", "upstream-gone", "stale", "detached" (with `--health`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub health: Option<Vec<&'static str>>,
 }
 
 /// CI status from PR or branch workflow
@@ -223,6 +252,7 @@ impl JsonItem {
         let (kind_str, worktree_data) = match &item.kind {
             ItemKind::Worktree(data) => ("worktree", Some(data.as_ref())),
             ItemKind::Branch => ("branch", None),
+            ItemKind::Remote => ("remote", None),
         };
 
         let is_main = worktree_data.is_some_and(|d| d.is_main);
@@ -258,6 +288,7 @@ impl JsonItem {
                     untracked: wt.untracked,
                     renamed: wt.renamed,
                     deleted: wt.deleted,
+                    submodule: wt.submodule,
                     diff: data.working_tree_diff.map(JsonDiff::from),
                 }
             })
@@ -304,6 +335,10 @@ impl JsonItem {
                 state,
                 reason,
                 detached: data.detached,
+                size: data.disk_usage,
+                health: data
+                    .health_flags()
+                    .map(|flags| flags.into_iter().map(Into::into).collect()),
             }
         });
 
@@ -343,6 +378,10 @@ impl JsonItem {
             ci,
             url: item.url.clone(),
             url_active: item.url_active,
+            note: item.note.clone(),
+            protected: item.protected,
+            ticket: item.ticket.clone(),
+            container: item.container.clone(),
             statusline,
             symbols,
         }
@@ -374,7 +413,7 @@ fn worktree_state_to_json(
     if let Some(symbols) = status_symbols {
         match symbols.worktree_state {
             WorktreeState::None => {}
-            WorktreeState::Branch => return (Some("no_worktree"), None),
+            WorktreeState::Branch | WorktreeState::Remote => return (Some("no_worktree"), None),
             WorktreeState::BranchWorktreeMismatch => {
                 return (Some("branch_worktree_mismatch"), None);
             }
@@ -428,7 +467,7 @@ fn format_raw_symbols(symbols: &super::model::StatusSymbols) -> String {
         result.push_str(upstream_div);
     }
 
-    // Worktree state (operations ✘⤴⤵ take priority over location /⚑⊟⊞)
+    // Worktree state (operations ✘⤴⤵ take priority over location /⤓⚑⊟⊞)
     let op_state = symbols.operation_state.to_string();
     if !op_state.is_empty() {
         result.push_str(&op_state);
@@ -630,12 +669,15 @@ mod tests {
             is_current: false,
             is_previous: false,
             detached: false,
+            detached_at: None,
             locked: None,
             prunable: None,
             working_tree_diff: None,
             git_operation: ActiveGitOperation::None,
             branch_worktree_mismatch: false,
             working_diff_display: None,
+            disk_usage: None,
+            health: None,
         }
     }
 
@@ -830,6 +872,7 @@ mod tests {
             untracked: true,
             renamed: false,
             deleted: false,
+            submodule: false,
             diff: Some(JsonDiff {
                 added: 10,
                 deleted: 5,
@@ -875,10 +918,13 @@ mod tests {
             state: Some("locked"),
             reason: Some("manual".to_string()),
             detached: false,
+            size: None,
+            health: None,
         };
         let json = serde_json::to_string(&wt).unwrap();
         assert!(json.contains("\"state\":\"locked\""));
         assert!(json.contains("\"reason\":\"manual\""));
+        assert!(!json.contains("size"));
     }
 
     #[test]
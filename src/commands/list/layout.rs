@@ -169,18 +169,31 @@
 //!
 //! - `fit_header()`: Ensures column width ≥ header width to prevent overflow
 //! - `try_allocate()`: Attempts to allocate space, returns 0 if insufficient
+//!
+//! # Table Width
+//!
+//! [`list_width()`] picks the width columns are allocated from: `--width` if
+//! given, else the terminal when stdout is one. When stdout is piped (to
+//! `less`, a file, CI logs) the table doesn't follow whatever terminal stderr
+//! is attached to: it uses `COLUMNS` if set, otherwise no limit, so every
+//! column gets its natural width and output is the same wherever it runs.
+//!
+//! `[list] column-widths` then clamps text columns to a min/max, and replaces
+//! the message column's `MIN_MESSAGE`/`MAX_MESSAGE` bounds.
 
 use std::collections::HashSet;
+use std::io::IsTerminal;
 use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 
 use anstyle::Style;
 use unicode_width::UnicodeWidthStr;
-use worktrunk::styling::{ADDITION, DELETION, Stream, supports_hyperlinks};
+use worktrunk::styling::{Stream, supports_hyperlinks, theme};
 
 use crate::display::{get_terminal_width, shorten_path};
 
 use super::collect::{TaskKind, parse_port_from_url};
-use super::columns::{COLUMN_SPECS, ColumnKind, ColumnSpec, column_display_index};
+use super::columns::{COLUMN_SPECS, ColumnKind, ColumnSpec, WidthLimits, column_display_index};
 
 // Re-export DiffVariant for external use (e.g., select command)
 pub use super::columns::DiffVariant;
@@ -188,6 +201,29 @@ pub use super::columns::DiffVariant;
 /// Width of short commit hash display (first 8 hex characters)
 const COMMIT_HASH_WIDTH: usize = 8;
 
+static WIDTH_OVERRIDE: OnceLock<usize> = OnceLock::new();
+
+/// Lay tables out at `width` regardless of the terminal (`--width`).
+///
+/// Call this once at startup; later calls are ignored.
+pub fn set_width_override(width: usize) {
+    let _ = WIDTH_OVERRIDE.set(width);
+}
+
+/// Width to lay the table out in; see the module docs.
+pub fn list_width() -> usize {
+    if let Some(&width) = WIDTH_OVERRIDE.get() {
+        return width;
+    }
+    if std::io::stdout().is_terminal() {
+        return get_terminal_width();
+    }
+    std::env::var("COLUMNS")
+        .ok()
+        .and_then(|cols| cols.parse().ok())
+        .unwrap_or(usize::MAX)
+}
+
 /// Ensures a column width is at least as wide as its header.
 ///
 /// This is the general solution for preventing header overflow: pass the header
@@ -241,6 +277,11 @@ pub struct ColumnWidths {
     pub status: usize, // Includes both git status symbols and user-defined status
     pub time: usize,
     pub url: usize,
+    pub health: usize,
+    pub disk_usage: usize,
+    pub note: usize,
+    pub ticket: usize,
+    pub docker: usize,
     pub ci_status: usize,
     pub message: usize,
     pub ahead_behind: DiffWidths,
@@ -259,6 +300,11 @@ pub struct ColumnDataFlags {
     pub upstream: bool,
     pub url: bool,
     pub ci_status: bool,
+    pub health: bool,
+    pub disk_usage: bool,
+    pub note: bool,
+    pub ticket: bool,
+    pub docker: bool,
     pub path: bool, // True if any worktree has branch_worktree_mismatch
 }
 
@@ -372,20 +418,20 @@ impl ColumnKind {
         match self {
             ColumnKind::WorkingDiff | ColumnKind::BranchDiff => Some(DiffDisplayConfig {
                 variant: DiffVariant::Signs,
-                positive_style: ADDITION,
-                negative_style: DELETION,
+                positive_style: theme().addition,
+                negative_style: theme().deletion,
                 always_show_zeros: false,
             }),
             ColumnKind::AheadBehind => Some(DiffDisplayConfig {
                 variant: DiffVariant::Arrows,
-                positive_style: ADDITION,
-                negative_style: DELETION.dimmed(),
+                positive_style: theme().addition,
+                negative_style: theme().deletion.dimmed(),
                 always_show_zeros: false,
             }),
             ColumnKind::Upstream => Some(DiffDisplayConfig {
                 variant: DiffVariant::UpstreamArrows,
-                positive_style: ADDITION,
-                negative_style: DELETION.dimmed(),
+                positive_style: theme().addition,
+                negative_style: theme().deletion.dimmed(),
                 always_show_zeros: false, // 0/0 case handled specially with | symbol
             }),
             _ => None,
@@ -407,8 +453,13 @@ impl ColumnKind {
             ColumnKind::AheadBehind => flags.ahead_behind,
             ColumnKind::BranchDiff => flags.branch_diff,
             ColumnKind::Path => flags.path,
+            ColumnKind::Health => flags.health,
+            ColumnKind::DiskUsage => flags.disk_usage,
             ColumnKind::Upstream => flags.upstream,
             ColumnKind::Url => flags.url,
+            ColumnKind::Note => flags.note,
+            ColumnKind::Ticket => flags.ticket,
+            ColumnKind::Docker => flags.docker,
             ColumnKind::Time => true,
             ColumnKind::CiStatus => flags.ci_status,
             ColumnKind::Commit => true,
@@ -447,6 +498,11 @@ impl ColumnKind {
             ColumnKind::Path => text(max_path_width),
             ColumnKind::Time => text(widths.time),
             ColumnKind::Url => text(widths.url),
+            ColumnKind::Health => text(widths.health),
+            ColumnKind::DiskUsage => text(widths.disk_usage),
+            ColumnKind::Note => text(widths.note),
+            ColumnKind::Ticket => text(widths.ticket),
+            ColumnKind::Docker => text(widths.docker),
             ColumnKind::CiStatus => text(widths.ci_status),
             ColumnKind::Commit => text(commit_width),
             ColumnKind::Message => None,
@@ -487,6 +543,8 @@ pub struct LayoutConfig {
     pub max_message_len: usize,
     pub hidden_column_count: usize,
     pub status_position_mask: super::model::PositionMask,
+    /// Primary remote, for linking branch names to the forge (hyperlinks only)
+    pub forge_url: Option<worktrunk::git::GitRemoteUrl>,
 }
 
 #[derive(Clone, Copy)]
@@ -541,6 +599,8 @@ fn build_estimated_widths(
     skip_tasks: &HashSet<TaskKind>,
     has_branch_worktree_mismatch: bool,
     url_width: usize,
+    note_width: usize,
+    docker_width: usize,
 ) -> LayoutMetadata {
     // Fixed widths for slow columns (require expensive git operations)
     // Values exceeding these widths use compact notation (K suffix)
@@ -554,6 +614,9 @@ fn build_estimated_widths(
     let upstream_fixed = fit_header(ColumnKind::Upstream.header(), 7); // "↑99 ↓99"
     let age_estimate = 4; // "11mo" (short format)
     let ci_estimate = fit_header(ColumnKind::CiStatus.header(), 1); // Single indicator symbol
+    let health_estimate = fit_header(ColumnKind::Health.header(), 13); // "locked stale"
+    let disk_usage_estimate = fit_header(ColumnKind::DiskUsage.header(), 5); // "1.2G", "1023M"
+    let ticket_estimate = fit_header(ColumnKind::Ticket.header(), 20); // "PROJ-123 In Progress"
 
     // Assume columns will have data (better to show and hide than to not show).
     // This is a limitation of progressive mode - we can't know which columns have data
@@ -561,6 +624,8 @@ fn build_estimated_widths(
     //
     // Exceptions that we can compute instantly from items:
     // - path: true only if any worktree has branch_worktree_mismatch
+    // - note: true only if any branch has a note (loaded with the skeleton)
+    // - docker: true only if any branch has a dev container (likewise)
     // - branch_diff/ci_status: false if their required task is skipped
     let data_flags = ColumnDataFlags {
         status: true,
@@ -570,6 +635,11 @@ fn build_estimated_widths(
         upstream: true,
         url: !skip_tasks.contains(&TaskKind::UrlStatus),
         ci_status: !skip_tasks.contains(&TaskKind::CiStatus),
+        health: !skip_tasks.contains(&TaskKind::Health),
+        disk_usage: !skip_tasks.contains(&TaskKind::DiskUsage),
+        note: note_width > 0,
+        ticket: !skip_tasks.contains(&TaskKind::Ticket),
+        docker: docker_width > 0,
         path: has_branch_worktree_mismatch,
     };
 
@@ -586,6 +656,19 @@ fn build_estimated_widths(
         status: status_fixed,
         time: age_estimate,
         url: url_estimate,
+        health: health_estimate,
+        disk_usage: disk_usage_estimate,
+        note: if note_width > 0 {
+            fit_header(ColumnKind::Note.header(), note_width)
+        } else {
+            0
+        },
+        ticket: ticket_estimate,
+        docker: if docker_width > 0 {
+            fit_header(ColumnKind::Docker.header(), docker_width)
+        } else {
+            0
+        },
         ci_status: ci_estimate,
         message: 50, // Will be flexible during allocation
         // Commit counts (Arrows): compact notation, 2 digits covers up to 99
@@ -624,28 +707,43 @@ fn build_estimated_widths(
 ///
 /// This is the core allocation algorithm used by `calculate_layout_from_basics()`
 /// with pre-allocated width estimates for expensive-to-compute columns.
+#[allow(clippy::too_many_arguments)]
 fn allocate_columns_with_priority(
     metadata: &LayoutMetadata,
     skip_tasks: &HashSet<TaskKind>,
+    selected: Option<&[ColumnKind]>,
     max_path_width: usize,
     commit_width: usize,
     terminal_width: usize,
+    width_limits: &WidthLimits,
     main_worktree_path: PathBuf,
 ) -> LayoutConfig {
     let spacing = 2;
     let mut remaining = terminal_width;
 
     // Build candidates with priorities
-    // Filter out columns whose required task is being skipped
+    // Filter out columns whose required task is being skipped, and columns the
+    // user didn't select. Selected columns skip the empty penalty: they were
+    // asked for, so they compete on base priority alone.
+    let is_selected = |kind: ColumnKind| {
+        kind == ColumnKind::Gutter || selected.is_none_or(|columns| columns.contains(&kind))
+    };
     let mut candidates: Vec<ColumnCandidate> = COLUMN_SPECS
         .iter()
         .filter(|spec| {
             spec.requires_task
                 .is_none_or(|task| !skip_tasks.contains(&task))
         })
+        .filter(|spec| is_selected(spec.kind))
+        // Notes and dev containers load with the skeleton, so an empty column
+        // has nothing to show at any width and shouldn't count as hidden
+        .filter(|spec| {
+            !matches!(spec.kind, ColumnKind::Note | ColumnKind::Docker)
+                || spec.kind.has_data(&metadata.data_flags)
+        })
         .map(|spec| ColumnCandidate {
             spec,
-            priority: if spec.kind.has_data(&metadata.data_flags) {
+            priority: if selected.is_some() || spec.kind.has_data(&metadata.data_flags) {
                 spec.base_priority
             } else {
                 spec.base_priority + EMPTY_PENALTY
@@ -663,6 +761,12 @@ fn allocate_columns_with_priority(
 
     const MIN_MESSAGE: usize = 10;
     const MAX_MESSAGE: usize = 100;
+    let message_limit = width_limits
+        .get(&ColumnKind::Message)
+        .copied()
+        .unwrap_or_default();
+    let min_message = message_limit.min.unwrap_or(MIN_MESSAGE);
+    let max_message = message_limit.clamp(MAX_MESSAGE.max(min_message));
 
     let mut pending: Vec<PendingColumn> = Vec::new();
 
@@ -696,8 +800,8 @@ fn allocate_columns_with_priority(
             // Allocate at minimum width initially. Post-allocation expansion will
             // bring it up to preferred/max width after empty columns have a chance
             // to be allocated.
-            if available >= MIN_MESSAGE {
-                message_width = MIN_MESSAGE.min(metadata.widths.message);
+            if available >= min_message {
+                message_width = min_message.min(max_message).min(metadata.widths.message);
             }
 
             if message_width > 0 {
@@ -713,12 +817,16 @@ fn allocate_columns_with_priority(
         }
 
         // For non-message columns
-        let Some((ideal_width, format)) =
+        let Some((mut ideal_width, format)) =
             spec.kind
                 .ideal(&metadata.widths, max_path_width, commit_width)
         else {
             continue;
         };
+        // Diff columns are laid out to their digit widths; only text clamps
+        if let (ColumnFormat::Text, Some(limit)) = (format, width_limits.get(&spec.kind)) {
+            ideal_width = limit.clamp(ideal_width);
+        }
 
         let skip_spacing = !needs_spacing(&pending);
         let allocated = try_allocate(&mut remaining, ideal_width, spacing, skip_spacing);
@@ -737,15 +845,24 @@ fn allocate_columns_with_priority(
         .iter_mut()
         .find(|col| col.spec.kind == ColumnKind::Message)
     {
-        if message_col.width < MAX_MESSAGE && remaining > 0 {
-            let expansion = remaining.min(MAX_MESSAGE - message_col.width);
+        if message_col.width < max_message && remaining > 0 {
+            let expansion = remaining.min(max_message - message_col.width);
             message_col.width += expansion;
         }
         max_message_len = message_col.width;
     }
 
-    // Sort by display order to maintain correct visual order
-    pending.sort_by_key(|col| column_display_index(col.spec.kind));
+    // Sort by display order to maintain correct visual order: the user's
+    // order when columns were selected (gutter stays first), else the default
+    match selected {
+        Some(columns) => pending.sort_by_key(|col| {
+            columns
+                .iter()
+                .position(|&kind| kind == col.spec.kind)
+                .map_or(0, |i| i + 1)
+        }),
+        None => pending.sort_by_key(|col| column_display_index(col.spec.kind)),
+    }
 
     // Build final column layouts with positions
     let gap = 2;
@@ -793,6 +910,7 @@ fn allocate_columns_with_priority(
         max_message_len,
         hidden_column_count,
         status_position_mask: metadata.status_position_mask,
+        forge_url: None,
     }
 }
 
@@ -819,34 +937,43 @@ fn allocate_columns_with_priority(
 pub fn calculate_layout_from_basics(
     items: &[super::model::ListItem],
     skip_tasks: &HashSet<TaskKind>,
+    selected: Option<&[ColumnKind]>,
+    width_limits: &WidthLimits,
     main_worktree_path: &Path,
     url_template: Option<&str>,
 ) -> LayoutConfig {
     calculate_layout_with_width(
         items,
         skip_tasks,
-        get_terminal_width(),
+        selected,
+        list_width(),
+        width_limits,
         main_worktree_path,
         url_template,
     )
 }
 
 /// Calculate layout with explicit width (for contexts like skim where available width differs)
+///
+/// `selected` restricts and orders the columns (`--columns`); `None` shows every
+/// column that fits, in the default order.
 pub fn calculate_layout_with_width(
     items: &[super::model::ListItem],
     skip_tasks: &HashSet<TaskKind>,
+    selected: Option<&[ColumnKind]>,
     terminal_width: usize,
+    width_limits: &WidthLimits,
     main_worktree_path: &Path,
     url_template: Option<&str>,
 ) -> LayoutConfig {
     // Calculate actual widths for things we know
     // Include branch names from both worktrees and standalone branches
-    let longest_branch = items
+    let max_branch = items
         .iter()
-        .filter_map(|item| item.branch.as_deref())
-        .max_by_key(|b| b.width());
-
-    let max_branch = longest_branch.map(|b| b.width()).unwrap_or(0);
+        .filter_map(|item| item.branch_label())
+        .map(|label| label.width())
+        .max()
+        .unwrap_or(0);
     let max_branch = fit_header(ColumnKind::Branch.header(), max_branch);
 
     let path_data_width = items
@@ -867,12 +994,27 @@ pub fn calculate_layout_with_width(
     // Estimate URL width from template (heuristic, no expansion needed)
     let url_width = estimate_url_width(url_template, supports_hyperlinks(Stream::Stdout));
 
+    let note_width = items
+        .iter()
+        .filter_map(|item| item.note_label())
+        .map(|label| label.width())
+        .max()
+        .unwrap_or(0);
+    let docker_width = items
+        .iter()
+        .filter_map(|item| item.container.as_deref())
+        .map(|state| state.width())
+        .max()
+        .unwrap_or(0);
+
     // Build pre-allocated width estimates (same as buffered mode)
     let metadata = build_estimated_widths(
         max_branch,
         skip_tasks,
         has_branch_worktree_mismatch,
         url_width,
+        note_width,
+        docker_width,
     );
 
     let commit_width = fit_header(ColumnKind::Commit.header(), COMMIT_HASH_WIDTH);
@@ -880,9 +1022,11 @@ pub fn calculate_layout_with_width(
     allocate_columns_with_priority(
         &metadata,
         skip_tasks,
+        selected,
         max_path_width,
         commit_width,
         terminal_width,
+        width_limits,
         main_worktree_path.to_path_buf(),
     )
 }
@@ -941,6 +1085,11 @@ mod tests {
             upstream: true,
             url: true,
             ci_status: true,
+            health: true,
+            disk_usage: true,
+            note: true,
+            ticket: true,
+            docker: true,
             path: true,
         };
         let all_false = ColumnDataFlags {
@@ -951,6 +1100,11 @@ mod tests {
             upstream: false,
             url: false,
             ci_status: false,
+            health: false,
+            disk_usage: false,
+            note: false,
+            ticket: false,
+            docker: false,
             path: false,
         };
 
@@ -1015,6 +1169,11 @@ mod tests {
             status: 8,
             time: 4,
             url: 0,
+            health: 0,
+            disk_usage: 0,
+            note: 0,
+            ticket: 0,
+            docker: 0,
             ci_status: 2,
             message: 50,
             ahead_behind: DiffWidths {
@@ -1082,6 +1241,11 @@ mod tests {
             status: 0,
             time: 0,
             url: 0,
+            health: 0,
+            disk_usage: 0,
+            note: 0,
+            ticket: 0,
+            docker: 0,
             ci_status: 0,
             message: 0,
             ahead_behind: DiffWidths {
@@ -1115,7 +1279,7 @@ mod tests {
         // Empty skip set means all tasks are computed (equivalent to --full)
         // has_branch_worktree_mismatch=true to test the path flag is passed through
         // url_width=0 since we're not testing URL column here
-        let metadata = build_estimated_widths(20, &HashSet::new(), true, 0);
+        let metadata = build_estimated_widths(20, &HashSet::new(), true, 0, 0, 0);
         let widths = metadata.widths;
 
         // Line diffs (Signs variant: +/-) allocate 3 digits for 100-999 range
@@ -1214,11 +1378,16 @@ mod tests {
             pr_status: None,
             url: None,
             url_active: None,
+            note: None,
+            protected: false,
+            ticket: None,
+            container: None,
             status_symbols: Some(StatusSymbols::default()),
             display: DisplayFields::default(),
             kind: ItemKind::Worktree(Box::new(WorktreeData {
                 path: PathBuf::from("/test/path"),
                 detached: false,
+                detached_at: None,
                 locked: None,
                 prunable: None,
                 working_tree_diff: Some(LineDiff::from((100, 50))),
@@ -1228,6 +1397,8 @@ mod tests {
                 is_previous: false,
                 branch_worktree_mismatch: false,
                 working_diff_display: None,
+                disk_usage: None,
+                health: None,
             })),
         };
 
@@ -1236,7 +1407,14 @@ mod tests {
             .into_iter()
             .collect();
         let main_worktree_path = PathBuf::from("/test");
-        let layout = calculate_layout_from_basics(&items, &skip_tasks, &main_worktree_path, None);
+        let layout = calculate_layout_from_basics(
+            &items,
+            &skip_tasks,
+            None,
+            &WidthLimits::new(),
+            &main_worktree_path,
+            None,
+        );
 
         assert!(
             !layout.columns.is_empty(),
@@ -1312,11 +1490,16 @@ mod tests {
             pr_status: None,
             url: None,
             url_active: None,
+            note: None,
+            protected: false,
+            ticket: None,
+            container: None,
             status_symbols: Some(StatusSymbols::default()),
             display: DisplayFields::default(),
             kind: ItemKind::Worktree(Box::new(WorktreeData {
                 path: PathBuf::from("/test"),
                 detached: false,
+                detached_at: None,
                 locked: None,
                 prunable: None,
                 working_tree_diff: Some(LineDiff::default()),
@@ -1326,6 +1509,8 @@ mod tests {
                 is_previous: false,
                 branch_worktree_mismatch: false,
                 working_diff_display: None,
+                disk_usage: None,
+                health: None,
             })),
         };
 
@@ -1334,7 +1519,14 @@ mod tests {
             .into_iter()
             .collect();
         let main_worktree_path = PathBuf::from("/home/user/project");
-        let layout = calculate_layout_from_basics(&items, &skip_tasks, &main_worktree_path, None);
+        let layout = calculate_layout_from_basics(
+            &items,
+            &skip_tasks,
+            None,
+            &WidthLimits::new(),
+            &main_worktree_path,
+            None,
+        );
 
         assert!(
             layout
@@ -1349,6 +1541,80 @@ mod tests {
         // At narrow widths (80 columns default in tests), Path may not fit
     }
 
+    #[test]
+    fn test_selected_columns_filter_and_order() {
+        let skip_tasks = HashSet::new();
+        let metadata = build_estimated_widths(10, &skip_tasks, false, 0, 0, 0);
+        let selected = [ColumnKind::Message, ColumnKind::Path, ColumnKind::Branch];
+        let layout = allocate_columns_with_priority(
+            &metadata,
+            &skip_tasks,
+            Some(&selected),
+            20,
+            8,
+            200,
+            &WidthLimits::new(),
+            PathBuf::from("/test"),
+        );
+
+        // Gutter stays first; Path shows even though no worktree needs it
+        let kinds: Vec<_> = layout.columns.iter().map(|col| col.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ColumnKind::Gutter,
+                ColumnKind::Message,
+                ColumnKind::Path,
+                ColumnKind::Branch
+            ]
+        );
+        assert_eq!(layout.hidden_column_count, 0);
+    }
+
+    #[test]
+    fn test_width_limits() {
+        use worktrunk::config::ColumnWidthLimit;
+
+        let skip_tasks = HashSet::new();
+        let metadata = build_estimated_widths(40, &skip_tasks, false, 0, 0, 0);
+        let limits = WidthLimits::from([
+            (
+                ColumnKind::Branch,
+                ColumnWidthLimit {
+                    min: None,
+                    max: Some(12),
+                },
+            ),
+            (
+                ColumnKind::Message,
+                ColumnWidthLimit {
+                    min: Some(20),
+                    max: Some(30),
+                },
+            ),
+        ]);
+        let layout = allocate_columns_with_priority(
+            &metadata,
+            &skip_tasks,
+            None,
+            20,
+            8,
+            500,
+            &limits,
+            PathBuf::from("/test"),
+        );
+        let width = |kind| {
+            layout
+                .columns
+                .iter()
+                .find(|col| col.kind == kind)
+                .map(|col| col.width)
+        };
+        assert_eq!(width(ColumnKind::Branch), Some(12));
+        assert_eq!(width(ColumnKind::Message), Some(30));
+        assert_eq!(layout.max_message_len, 30);
+    }
+
     #[test]
     fn test_estimate_url_width_no_template() {
         assert_eq!(estimate_url_width(None, false), 0);
@@ -117,16 +117,22 @@
 //! - Run `git commit-graph write --reachable --changed-paths` to speed up commit counting
 //! - Run `git gc` periodically to consolidate objects into pack files
 //! - Minimize uncommitted changes across worktrees (each dirty worktree adds diff overhead)
+//! - Run `wt daemon`: its filesystem watcher lets `git status` be skipped for
+//!   worktrees that haven't changed
 
 pub mod ci_status;
 pub(crate) mod collect;
 pub(crate) mod columns;
+pub(crate) mod disk_usage;
+pub(crate) mod group;
+pub(crate) mod health;
 pub mod json_output;
 pub(crate) mod layout;
 pub mod model;
 pub mod progressive;
 mod progressive_table;
 pub(crate) mod render;
+pub(crate) mod status_cache;
 
 #[cfg(test)]
 mod spacing_test;
@@ -135,11 +141,13 @@ mod spacing_test;
 use std::collections::HashSet;
 
 use anstyle::Style;
-use anyhow::Context;
+use color_print::cformat;
 use model::{ListData, ListItem};
 use progressive::RenderMode;
-use worktrunk::git::Repository;
-use worktrunk::styling::INFO_SYMBOL;
+use worktrunk::git::{GitError, Repository};
+use worktrunk::path::format_path_for_display;
+use worktrunk::styling::{INFO_SYMBOL, eprintln, warning_message};
+use worktrunk::tr;
 
 use collect::TaskKind;
 
@@ -147,19 +155,161 @@ use collect::TaskKind;
 pub use collect::{CollectOptions, build_worktree_item, populate_item};
 pub use model::StatuslineSegment;
 
+#[allow(clippy::too_many_arguments)]
 pub fn handle_list(
     format: crate::OutputFormat,
     show_branches: bool,
     show_remotes: bool,
     show_full: bool,
+    show_du: bool,
+    show_health: bool,
+    columns: Option<&[String]>,
+    group_by: Option<crate::cli::GroupBy>,
     render_mode: RenderMode,
     config: &worktrunk::config::UserConfig,
 ) -> anyhow::Result<()> {
     let repo = Repository::current()?;
+    let Some(items) = list_repo(
+        &repo,
+        format,
+        show_branches,
+        show_remotes,
+        show_full,
+        show_du,
+        show_health,
+        columns,
+        group_by,
+        render_mode,
+        config,
+    )?
+    else {
+        return Ok(());
+    };
+
+    match format {
+        crate::OutputFormat::Json => {
+            // Convert to new JSON structure
+            let json_items = json_output::to_json_items(&items);
+            worktrunk::styling::print_json(&json_items)?;
+        }
+        crate::OutputFormat::Table | crate::OutputFormat::ClaudeCode => {
+            // Table and summary already rendered in collect() for all modes
+            // Nothing to do here - collect() handles the complete table rendering
+        }
+    }
+
+    Ok(())
+}
+
+/// Handle `wt list --all-repos`: each repository from `[repos]` in turn, as a
+/// table under a heading, or one JSON array of `{repo, path, items}` groups.
+///
+/// A repository that fails to list is reported and skipped, so one broken
+/// checkout doesn't hide the rest.
+#[allow(clippy::too_many_arguments)]
+pub fn handle_list_all_repos(
+    format: crate::OutputFormat,
+    show_branches: bool,
+    show_remotes: bool,
+    show_full: bool,
+    show_du: bool,
+    show_health: bool,
+    columns: Option<&[String]>,
+    group_by: Option<crate::cli::GroupBy>,
+    render_mode: RenderMode,
+    config: &worktrunk::config::UserConfig,
+) -> anyhow::Result<()> {
+    let paths = config
+        .repos
+        .as_ref()
+        .map(|repos| repos.discover())
+        .unwrap_or_default();
+    if paths.is_empty() {
+        return Err(GitError::Other {
+            message: cformat!(
+                "No repositories to list; add <bright-black>paths</> or <bright-black>roots</> to <bright-black>[repos]</> in user config"
+            ),
+        }
+        .into());
+    }
+
+    let mut groups = Vec::new();
+    for (i, path) in paths.iter().enumerate() {
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_else(|| path.display().to_string());
+        if !matches!(format, crate::OutputFormat::Json) {
+            if i > 0 {
+                println!();
+            }
+            println!(
+                "{}",
+                cformat!(
+                    "<bold>{name}</> <bright-black>{}</>",
+                    format_path_for_display(path)
+                )
+            );
+        }
+
+        let items = Repository::at(path).and_then(|repo| {
+            list_repo(
+                &repo,
+                format,
+                show_branches,
+                show_remotes,
+                show_full,
+                show_du,
+                show_health,
+                columns,
+                group_by,
+                render_mode,
+                config,
+            )
+        });
+        match items {
+            Ok(Some(items)) => groups.push(serde_json::json!({
+                "repo": name,
+                "path": path,
+                "items": json_output::to_json_items(&items),
+            })),
+            Ok(None) => {}
+            Err(e) => eprintln!(
+                "{}",
+                warning_message(cformat!(
+                    "Skipped <bold>{name}</>: {}",
+                    e.to_string().lines().next().unwrap_or_default()
+                ))
+            ),
+        }
+    }
+
+    if matches!(format, crate::OutputFormat::Json) {
+        worktrunk::styling::print_json(&groups)?;
+    }
+    Ok(())
+}
+
+/// Collect (and, for table formats, render) one repository's list.
+#[allow(clippy::too_many_arguments)]
+fn list_repo(
+    repo: &Repository,
+    format: crate::OutputFormat,
+    show_branches: bool,
+    show_remotes: bool,
+    show_full: bool,
+    show_du: bool,
+    show_health: bool,
+    columns: Option<&[String]>,
+    group_by: Option<crate::cli::GroupBy>,
+    render_mode: RenderMode,
+    config: &worktrunk::config::UserConfig,
+) -> anyhow::Result<Option<Vec<ListItem>>> {
+    let columns = columns.map(columns::parse_column_names).transpose()?;
 
     // Build skip set based on flags
     // Without --full: skip expensive operations (BranchDiff, CiStatus, WorkingTreeConflicts)
-    let skip_tasks: HashSet<TaskKind> = if show_full {
+    let mut skip_tasks: HashSet<TaskKind> = if show_full {
         HashSet::new() // Compute everything
     } else {
         [
@@ -170,6 +320,30 @@ pub fn handle_list(
         .into_iter()
         .collect()
     };
+    // Walking worktree directories is slow; sizes are opt-in even with --full
+    if !show_du {
+        skip_tasks.insert(TaskKind::DiskUsage);
+    }
+    // Health probes cost a few git calls per worktree; opt-in like sizes
+    if !show_health {
+        skip_tasks.insert(TaskKind::Health);
+    }
+    // Ticket lookups hit the Jira API; only with `[jira] list = true`
+    let project_id = repo.project_identifier().ok();
+    if !config
+        .jira(project_id.as_deref())
+        .is_some_and(|jira| jira.list())
+    {
+        skip_tasks.insert(TaskKind::Ticket);
+    }
+    // Selecting a `--full`, `--du` or `--health` column fetches just that column's data
+    for spec in columns::COLUMN_SPECS {
+        if let Some(task) = spec.requires_task
+            && columns.as_ref().is_some_and(|c| c.contains(&spec.kind))
+        {
+            skip_tasks.remove(&task);
+        }
+    }
 
     // Progressive rendering only for table format with Progressive mode
     let show_progress = match format {
@@ -202,36 +376,20 @@ pub fn handle_list(
     };
 
     let list_data = collect::collect(
-        &repo,
+        repo,
         show_branches,
         show_remotes,
         &skip_tasks,
+        columns.as_deref(),
         show_progress,
         render_table,
+        group_by,
         config,
         command_timeout,
         skip_expensive_for_stale,
     )?;
 
-    let Some(ListData { items, .. }) = list_data else {
-        return Ok(());
-    };
-
-    match format {
-        crate::OutputFormat::Json => {
-            // Convert to new JSON structure
-            let json_items = json_output::to_json_items(&items);
-            let json =
-                serde_json::to_string_pretty(&json_items).context("Failed to serialize to JSON")?;
-            println!("{}", json);
-        }
-        crate::OutputFormat::Table | crate::OutputFormat::ClaudeCode => {
-            // Table and summary already rendered in collect() for all modes
-            // Nothing to do here - collect() handles the complete table rendering
-        }
-    }
-
-    Ok(())
+    Ok(list_data.map(|ListData { items, .. }| items))
 }
 
 #[derive(Default)]
@@ -241,6 +399,8 @@ pub(super) struct SummaryMetrics {
     remote_branches: usize,
     dirty_worktrees: usize,
     ahead_items: usize,
+    /// Total size of measured worktrees (`--du` only)
+    disk_usage: Option<u64>,
 }
 
 impl SummaryMetrics {
@@ -253,7 +413,7 @@ impl SummaryMetrics {
     }
 
     fn update(&mut self, item: &ListItem) {
-        if let Some(_data) = item.worktree_data() {
+        if let Some(data) = item.worktree_data() {
             self.worktrees += 1;
             // Use status_symbols.working_tree which includes untracked files,
             // not just working_tree_diff which only has tracked changes
@@ -264,10 +424,12 @@ impl SummaryMetrics {
             {
                 self.dirty_worktrees += 1;
             }
+            if let Some(bytes) = data.disk_usage {
+                *self.disk_usage.get_or_insert(0) += bytes;
+            }
         } else {
-            // Distinguish local vs remote branches by presence of '/' in name
-            // Remote branches are like "origin/feature", local are like "feature"
-            if item.branch.as_ref().is_some_and(|b| b.contains('/')) {
+            // By kind, not name: local branches like "feature/x" contain '/' too
+            if item.is_remote() {
                 self.remote_branches += 1;
             } else {
                 self.local_branches += 1;
@@ -287,33 +449,71 @@ impl SummaryMetrics {
         let mut parts = Vec::new();
 
         if include_branches {
-            parts.push(format!("{} worktrees", self.worktrees));
+            parts.push(tr!(
+                "list.summary.worktrees",
+                "{count} worktrees",
+                count = self.worktrees
+            ));
             if self.local_branches > 0 {
-                parts.push(format!("{} branches", self.local_branches));
+                parts.push(tr!(
+                    "list.summary.branches",
+                    "{count} branches",
+                    count = self.local_branches
+                ));
             }
             if self.remote_branches > 0 {
-                parts.push(format!("{} remote branches", self.remote_branches));
+                parts.push(tr!(
+                    "list.summary.remote_branches",
+                    "{count} remote branches",
+                    count = self.remote_branches
+                ));
             }
+        } else if self.worktrees == 1 {
+            parts.push(tr!("list.summary.worktree", "{count} worktree", count = 1));
         } else {
-            let plural = if self.worktrees == 1 { "" } else { "s" };
-            parts.push(format!("{} worktree{}", self.worktrees, plural));
+            parts.push(tr!(
+                "list.summary.worktrees",
+                "{count} worktrees",
+                count = self.worktrees
+            ));
         }
 
         if self.dirty_worktrees > 0 {
-            parts.push(format!("{} with changes", self.dirty_worktrees));
+            parts.push(tr!(
+                "list.summary.dirty",
+                "{count} with changes",
+                count = self.dirty_worktrees
+            ));
         }
 
         if self.ahead_items > 0 {
-            parts.push(format!("{} ahead", self.ahead_items));
+            parts.push(tr!(
+                "list.summary.ahead",
+                "{count} ahead",
+                count = self.ahead_items
+            ));
         }
 
-        if hidden_columns > 0 {
-            let plural = if hidden_columns == 1 {
-                "column"
-            } else {
-                "columns"
-            };
-            parts.push(format!("{} {} hidden", hidden_columns, plural));
+        if let Some(bytes) = self.disk_usage {
+            parts.push(tr!(
+                "list.summary.disk",
+                "{size} on disk",
+                size = disk_usage::format_size(bytes)
+            ));
+        }
+
+        if hidden_columns == 1 {
+            parts.push(tr!(
+                "list.summary.hidden_column",
+                "{count} column hidden",
+                count = 1
+            ));
+        } else if hidden_columns > 1 {
+            parts.push(tr!(
+                "list.summary.hidden_columns",
+                "{count} columns hidden",
+                count = hidden_columns
+            ));
         }
 
         parts
@@ -334,23 +534,52 @@ pub(crate) fn format_summary_message(
         .summary_parts(show_branches, hidden_column_count)
         .join(", ");
 
+    let showing = tr!(
+        "list.summary.showing",
+        "Showing {summary}",
+        summary = summary
+    );
+
     if error_count > 0 {
+        let failed = if error_count == 1 {
+            tr!("list.summary.task_failed", "{count} task failed", count = 1)
+        } else {
+            tr!(
+                "list.summary.tasks_failed",
+                "{count} tasks failed",
+                count = error_count
+            )
+        };
         let failure_msg = if error_count == timed_out_count {
             // All failures are timeouts
-            let plural = if timed_out_count == 1 { "" } else { "s" };
-            format!("{timed_out_count} task{plural} timed out")
+            if timed_out_count == 1 {
+                tr!(
+                    "list.summary.task_timed_out",
+                    "{count} task timed out",
+                    count = 1
+                )
+            } else {
+                tr!(
+                    "list.summary.tasks_timed_out",
+                    "{count} tasks timed out",
+                    count = timed_out_count
+                )
+            }
         } else if timed_out_count > 0 {
             // Mix of timeouts and other errors
-            let plural = if error_count == 1 { "" } else { "s" };
-            format!("{error_count} task{plural} failed ({timed_out_count} timed out)")
+            tr!(
+                "list.summary.failed_with_timeouts",
+                "{failed} ({timed_out} timed out)",
+                failed = failed,
+                timed_out = timed_out_count
+            )
         } else {
             // No timeouts, just other errors
-            let plural = if error_count == 1 { "" } else { "s" };
-            format!("{error_count} task{plural} failed")
+            failed
         };
-        format!("{INFO_SYMBOL} {dim}Showing {summary}. {failure_msg}{dim:#}")
+        format!("{INFO_SYMBOL} {dim}{showing}. {failure_msg}{dim:#}")
     } else {
-        format!("{INFO_SYMBOL} {dim}Showing {summary}{dim:#}")
+        format!("{INFO_SYMBOL} {dim}{showing}{dim:#}")
     }
 }
 
@@ -368,6 +597,17 @@ mod tests {
         assert_eq!(metrics.ahead_items, 0);
     }
 
+    #[test]
+    fn test_summary_metrics_remote_by_kind() {
+        let items = [
+            ListItem::new_branch("abc123".into(), "feature/login".into()),
+            ListItem::new_remote_branch("abc123".into(), "origin/feature".into()),
+        ];
+        let metrics = SummaryMetrics::from_items(&items);
+        assert_eq!(metrics.local_branches, 1);
+        assert_eq!(metrics.remote_branches, 1);
+    }
+
     #[test]
     fn test_summary_metrics_summary_parts_single_worktree() {
         let metrics = SummaryMetrics {
@@ -376,6 +616,7 @@ mod tests {
             remote_branches: 0,
             dirty_worktrees: 0,
             ahead_items: 0,
+            disk_usage: None,
         };
         let parts = metrics.summary_parts(false, 0);
         assert_eq!(parts, vec!["1 worktree"]);
@@ -389,6 +630,7 @@ mod tests {
             remote_branches: 0,
             dirty_worktrees: 0,
             ahead_items: 0,
+            disk_usage: None,
         };
         let parts = metrics.summary_parts(false, 0);
         assert_eq!(parts, vec!["3 worktrees"]);
@@ -402,6 +644,7 @@ mod tests {
             remote_branches: 10,
             dirty_worktrees: 0,
             ahead_items: 0,
+            disk_usage: None,
         };
         let parts = metrics.summary_parts(true, 0);
         assert_eq!(
@@ -418,6 +661,7 @@ mod tests {
             remote_branches: 0,
             dirty_worktrees: 2,
             ahead_items: 0,
+            disk_usage: None,
         };
         let parts = metrics.summary_parts(false, 0);
         assert_eq!(parts, vec!["3 worktrees", "2 with changes"]);
@@ -431,6 +675,7 @@ mod tests {
             remote_branches: 0,
             dirty_worktrees: 0,
             ahead_items: 1,
+            disk_usage: None,
         };
         let parts = metrics.summary_parts(false, 0);
         assert_eq!(parts, vec!["2 worktrees", "1 ahead"]);
@@ -444,6 +689,7 @@ mod tests {
             remote_branches: 0,
             dirty_worktrees: 0,
             ahead_items: 0,
+            disk_usage: None,
         };
         let parts = metrics.summary_parts(false, 1);
         assert_eq!(parts, vec!["1 worktree", "1 column hidden"]);
@@ -452,6 +698,17 @@ mod tests {
         assert_eq!(parts, vec!["1 worktree", "3 columns hidden"]);
     }
 
+    #[test]
+    fn test_summary_metrics_summary_parts_with_disk_usage() {
+        let metrics = SummaryMetrics {
+            worktrees: 2,
+            disk_usage: Some(3 * 1024 * 1024 * 1024),
+            ..Default::default()
+        };
+        let parts = metrics.summary_parts(false, 0);
+        assert_eq!(parts, vec!["2 worktrees", "3.0G on disk"]);
+    }
+
     #[test]
     fn test_summary_metrics_summary_parts_branches_no_local() {
         let metrics = SummaryMetrics {
@@ -460,6 +717,7 @@ mod tests {
             remote_branches: 5,
             dirty_worktrees: 0,
             ahead_items: 0,
+            disk_usage: None,
         };
         let parts = metrics.summary_parts(true, 0);
         assert_eq!(parts, vec!["2 worktrees", "5 remote branches"]);
@@ -473,6 +731,7 @@ mod tests {
             remote_branches: 8,
             dirty_worktrees: 2,
             ahead_items: 4,
+            disk_usage: None,
         };
         let parts = metrics.summary_parts(true, 2);
         assert_eq!(
@@ -6,12 +6,15 @@
 use std::path::PathBuf;
 
 use worktrunk::git::{IntegrationReason, IntegrationSignals, LineDiff, check_integration};
+use worktrunk::styling::Symbol;
 
 use super::state::{ActiveGitOperation, Divergence, MainState, OperationState, WorktreeState};
 use super::stats::{AheadBehind, BranchDiffTotals, CommitDetails, UpstreamStatus};
 use super::status_symbols::{StatusSymbols, WorkingTreeStatus};
+use crate::commands::jira::Ticket;
 use crate::commands::list::ci_status::PrStatus;
 use crate::commands::list::columns::ColumnKind;
+use crate::commands::list::health::{self, HealthFlag};
 
 /// Display fields shared between WorktreeInfo and BranchInfo
 /// These contain formatted strings with ANSI colors for json-pretty output
@@ -72,6 +75,9 @@ impl DisplayFields {
 pub struct WorktreeData {
     pub path: PathBuf,
     pub detached: bool,
+    /// Tag a detached HEAD is exactly at, shown in place of the branch
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detached_at: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub locked: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -94,6 +100,12 @@ pub struct WorktreeData {
     pub branch_worktree_mismatch: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub working_diff_display: Option<String>,
+    /// On-disk size in bytes (`--du` only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disk_usage: Option<u64>,
+    /// Problems found by git calls (`--health` only); see [`Self::health_flags`]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub health: Option<Vec<HealthFlag>>,
 }
 
 impl WorktreeData {
@@ -102,6 +114,18 @@ impl WorktreeData {
         self.prunable.is_some()
     }
 
+    /// Every health problem, or None while the `--health` probes are pending.
+    ///
+    /// Prunable worktrees aren't probed, so their flags are known up front.
+    pub(crate) fn health_flags(&self) -> Option<Vec<HealthFlag>> {
+        let mut flags =
+            health::state_flags(self.is_prunable(), self.locked.is_some(), self.detached);
+        if !self.is_prunable() {
+            flags.extend(self.health.as_ref()?);
+        }
+        Some(health::sorted(flags))
+    }
+
     /// Create WorktreeData from a WorktreeInfo, with all computed fields set to None.
     pub(crate) fn from_worktree(
         wt: &worktrunk::git::WorktreeInfo,
@@ -125,7 +149,7 @@ impl WorktreeData {
     }
 }
 
-/// Discriminator for item type (worktree vs branch)
+/// Discriminator for item type (worktree, branch, or remote-only branch)
 ///
 /// WorktreeData is boxed to reduce the size of ItemKind enum (304 bytes → 24 bytes).
 /// This reduces stack pressure when passing ListItem by value and improves cache locality
@@ -135,6 +159,8 @@ impl WorktreeData {
 pub enum ItemKind {
     Worktree(Box<WorktreeData>),
     Branch,
+    /// Remote-tracking branch with no local branch (`--remotes`), e.g. `origin/feature`
+    Remote,
 }
 
 /// Unified item for displaying worktrees and branches in the same table
@@ -195,6 +221,22 @@ pub struct ListItem {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub url_active: Option<bool>,
 
+    /// Branch note from the state store (e.g., an issue link)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+
+    /// Branch matches `[branches] protected` in the project config
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub protected: bool,
+
+    /// Jira ticket named in the branch (`[jira] list` only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ticket: Option<Ticket>,
+
+    /// Dev container state, e.g. `running` (`wt switch --devcontainer` only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub container: Option<String>,
+
     /// Git status symbols - None until all dependencies are ready.
     /// Note: This field is not serialized directly. JSON output converts to JsonItem first.
     #[serde(skip)]
@@ -235,12 +277,56 @@ impl ListItem {
             pr_status: None,
             url: None,
             url_active: None,
+            note: None,
+            protected: false,
+            ticket: None,
+            container: None,
             status_symbols: None,
             display: DisplayFields::default(),
             kind: ItemKind::Branch,
         }
     }
 
+    /// Create a ListItem for a remote-tracking branch without a local branch
+    pub(crate) fn new_remote_branch(head: String, branch: String) -> Self {
+        Self {
+            kind: ItemKind::Remote,
+            ..Self::new_branch(head, branch)
+        }
+    }
+
+    /// Branch name for the Branch column, with a shield on protected branches.
+    ///
+    /// Detached worktrees at a tag (`wt switch --detach v1.0`) show the tag.
+    pub fn branch_label(&self) -> Option<String> {
+        let Some(branch) = self.branch.as_deref() else {
+            let tag = self.worktree_data()?.detached_at.as_deref()?;
+            return Some(format!("(at {tag})"));
+        };
+        Some(if self.protected {
+            format!("{branch} {}", Symbol::Protected.glyph())
+        } else {
+            branch.to_string()
+        })
+    }
+
+    /// Compact label for the note column.
+    ///
+    /// Issue links shorten to `#1234`; other URLs to their last path segment.
+    /// Plain-text notes are shown as-is.
+    pub fn note_label(&self) -> Option<String> {
+        let note = self.note.as_deref()?;
+        if !note.contains("://") {
+            return Some(note.to_string());
+        }
+        let mut segments = note.trim_end_matches('/').rsplit('/');
+        let last = segments.next().unwrap_or(note);
+        Some(match segments.next() {
+            Some("issues") if last.chars().all(|c| c.is_ascii_digit()) => format!("#{last}"),
+            _ => last.to_string(),
+        })
+    }
+
     pub fn branch_name(&self) -> &str {
         self.branch.as_deref().unwrap_or("(detached)")
     }
@@ -249,6 +335,11 @@ impl ListItem {
         matches!(&self.kind, ItemKind::Worktree(data) if data.is_main)
     }
 
+    /// Whether this is a remote branch without a local branch (`--remotes`).
+    pub fn is_remote(&self) -> bool {
+        matches!(self.kind, ItemKind::Remote)
+    }
+
     pub fn head(&self) -> &str {
         &self.head
     }
@@ -264,14 +355,14 @@ impl ListItem {
     pub fn worktree_data(&self) -> Option<&WorktreeData> {
         match &self.kind {
             ItemKind::Worktree(data) => Some(data),
-            ItemKind::Branch => None,
+            ItemKind::Branch | ItemKind::Remote => None,
         }
     }
 
     pub fn worktree_data_mut(&mut self) -> Option<&mut WorktreeData> {
         match &mut self.kind {
             ItemKind::Worktree(data) => Some(data),
-            ItemKind::Branch => None,
+            ItemKind::Branch | ItemKind::Remote => None,
         }
     }
 
@@ -532,7 +623,7 @@ impl ListItem {
                     user_marker,
                 });
             }
-            ItemKind::Branch => {
+            ItemKind::Branch | ItemKind::Remote => {
                 // Simplified status computation for branches
                 // Only compute symbols that apply to branches (no working tree, git operation, or worktree attrs)
 
@@ -558,7 +649,11 @@ impl ListItem {
                 self.status_symbols = Some(StatusSymbols {
                     main_state,
                     operation_state: OperationState::None,
-                    worktree_state: WorktreeState::Branch,
+                    worktree_state: if self.is_remote() {
+                        WorktreeState::Remote
+                    } else {
+                        WorktreeState::Branch
+                    },
                     upstream_divergence,
                     working_tree: WorkingTreeStatus::default(),
                     user_marker,
@@ -628,6 +723,22 @@ mod tests {
         assert_eq!(item.branch_name(), "(detached)");
     }
 
+    #[test]
+    fn test_branch_label_detached_at_tag() {
+        let mut item = ListItem::new_branch("abc123".to_string(), "feature".to_string());
+        item.branch = None;
+        item.kind = ItemKind::Worktree(Box::new(WorktreeData {
+            detached: true,
+            ..Default::default()
+        }));
+        assert_eq!(item.branch_label(), None);
+
+        if let ItemKind::Worktree(data) = &mut item.kind {
+            data.detached_at = Some("v1.2.0".to_string());
+        }
+        assert_eq!(item.branch_label().as_deref(), Some("(at v1.2.0)"));
+    }
+
     #[test]
     fn test_list_item_head() {
         let item = ListItem::new_branch("abc123def".to_string(), "feature".to_string());
@@ -665,6 +776,24 @@ mod tests {
         assert!(upstream.remote.is_none());
     }
 
+    #[test]
+    fn test_list_item_note_label() {
+        let mut item = ListItem::new_branch("abc123".to_string(), "feature".to_string());
+        assert_eq!(item.note_label(), None);
+
+        item.note = Some("https://github.com/org/repo/issues/1234".to_string());
+        assert_eq!(item.note_label().as_deref(), Some("#1234"));
+
+        item.note = Some("https://gitlab.com/org/repo/-/issues/56/".to_string());
+        assert_eq!(item.note_label().as_deref(), Some("#56"));
+
+        item.note = Some("https://tracker.example.com/browse/PROJ-7".to_string());
+        assert_eq!(item.note_label().as_deref(), Some("PROJ-7"));
+
+        item.note = Some("waiting on review".to_string());
+        assert_eq!(item.note_label().as_deref(), Some("waiting on review"));
+    }
+
     #[test]
     fn test_list_item_worktree_data() {
         // Branch item has no worktree data
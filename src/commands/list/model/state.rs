@@ -73,6 +73,7 @@ impl Divergence {
 /// Shows the "location" state of a worktree or branch:
 /// - For worktrees: whether the path matches the template, or has issues
 /// - For branches (without worktree): shows / to distinguish from worktrees
+/// - For remote branches (without local branch): shows ⤓
 ///
 /// Priority order for worktrees: BranchWorktreeMismatch > Prunable > Locked
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, strum::IntoStaticStr)]
@@ -89,6 +90,8 @@ pub enum WorktreeState {
     Locked,
     /// Branch indicator (for branches without worktrees)
     Branch,
+    /// Remote branch indicator (for remote branches without a local branch)
+    Remote,
 }
 
 impl std::fmt::Display for WorktreeState {
@@ -99,6 +102,7 @@ impl std::fmt::Display for WorktreeState {
             Self::Prunable => write!(f, "⊟"),
             Self::Locked => write!(f, "⊞"),
             Self::Branch => write!(f, "/"),
+            Self::Remote => write!(f, "⤓"),
         }
     }
 }
@@ -404,6 +408,7 @@ mod tests {
         assert_eq!(format!("{}", WorktreeState::Prunable), "⊟");
         assert_eq!(format!("{}", WorktreeState::Locked), "⊞");
         assert_eq!(format!("{}", WorktreeState::Branch), "/");
+        assert_eq!(format!("{}", WorktreeState::Remote), "⤓");
     }
 
     #[test]
@@ -27,7 +27,7 @@ impl PositionMask {
     pub(crate) const STAGED: usize = 0; // + (staged changes)
     pub(crate) const MODIFIED: usize = 1; // ! (modified files)
     pub(crate) const UNTRACKED: usize = 2; // ? (untracked files)
-    pub(crate) const WORKTREE_STATE: usize = 3; // Worktree: ✘⤴⤵/⚑⊟⊞
+    pub(crate) const WORKTREE_STATE: usize = 3; // Worktree: ✘⤴⤵/⤓⚑⊟⊞
     pub(crate) const MAIN_STATE: usize = 4; // Main relationship: ^✗_⊂↕↑↓
     pub(crate) const UPSTREAM_DIVERGENCE: usize = 5; // Remote: |⇅⇡⇣
     pub(crate) const USER_MARKER: usize = 6;
@@ -39,7 +39,7 @@ impl PositionMask {
             1, // STAGED: + (1 char)
             1, // MODIFIED: ! (1 char)
             1, // UNTRACKED: ? (1 char)
-            1, // WORKTREE_STATE: ✘⤴⤵/⚑⊟⊞ (1 char, priority: conflicts > rebase > merge > branch_worktree_mismatch > prunable > locked > branch)
+            1, // WORKTREE_STATE: ✘⤴⤵/⤓⚑⊟⊞ (1 char, priority: conflicts > rebase > merge > branch_worktree_mismatch > prunable > locked > branch/remote)
             1, // MAIN_STATE: ^✗_–⊂↕↑↓ (1 char, priority: is_main > would_conflict > empty > same_commit > integrated > diverged > ahead > behind)
             1, // UPSTREAM_DIVERGENCE: |⇡⇣⇅ (1 char)
             2, // USER_MARKER: single emoji or two chars (allocate 2)
@@ -62,6 +62,10 @@ pub struct WorkingTreeStatus {
     pub untracked: bool,
     pub renamed: bool,
     pub deleted: bool,
+    /// A submodule has uncommitted changes or a checked-out commit other than
+    /// the recorded one. Always also counted as `modified`.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub submodule: bool,
 }
 
 impl WorkingTreeStatus {
@@ -79,6 +83,7 @@ impl WorkingTreeStatus {
             untracked,
             renamed,
             deleted,
+            submodule: false,
         }
     }
 
@@ -116,7 +121,7 @@ impl WorkingTreeStatus {
 /// Symbols are categorized to enable vertical alignment in table output.
 /// Display order (left to right):
 /// - Working tree: +, !, ? (staged, modified, untracked - NOT mutually exclusive)
-/// - Worktree state: ✘, ⤴, ⤵, /, ⚑, ⊟, ⊞ (operations + location)
+/// - Worktree state: ✘, ⤴, ⤵, /, ⤓, ⚑, ⊟, ⊞ (operations + location)
 /// - Main state: ^, ✗, _, ⊂, ↕, ↑, ↓ (relationship to default branch - single-stroke vertical arrows)
 /// - Upstream divergence: |, ⇅, ⇡, ⇣ (relationship to remote - vertical arrows)
 /// - User marker: custom labels, emoji
@@ -124,7 +129,7 @@ impl WorkingTreeStatus {
 /// ## Mutual Exclusivity
 ///
 /// **Worktree state (operations take priority over location):**
-/// Priority: ✘ > ⤴ > ⤵ > ⚑ > ⊟ > ⊞ > /
+/// Priority: ✘ > ⤴ > ⤵ > ⚑ > ⊟ > ⊞ > / (or ⤓)
 /// - ✘: Actual conflicts (must resolve)
 /// - ⤴: Rebase in progress
 /// - ⤵: Merge in progress
@@ -132,6 +137,7 @@ impl WorkingTreeStatus {
 /// - ⊟: Prunable (directory missing)
 /// - ⊞: Locked worktree
 /// - /: Branch without worktree
+/// - ⤓: Remote branch without local branch
 ///
 /// **Main state (single position with priority):**
 /// Priority: ^ > ✗ > _ > – > ⊂ > ↕ > ↑ > ↓
@@ -159,10 +165,10 @@ pub struct StatusSymbols {
     pub(crate) main_state: MainState,
 
     /// Worktree operation and location state (single position)
-    /// Operations (✘⤴⤵) take priority over location states (/⚑⊟⊞)
+    /// Operations (✘⤴⤵) take priority over location states (/⤓⚑⊟⊞)
     pub(crate) operation_state: OperationState,
 
-    /// Worktree location state: / for branches, ⚑⊟⊞ for worktrees
+    /// Worktree location state: / for branches, ⤓ for remote branches, ⚑⊟⊞ for worktrees
     pub(crate) worktree_state: WorktreeState,
 
     /// Remote/upstream divergence state (mutually exclusive)
@@ -274,7 +280,7 @@ impl StatusSymbols {
             .styled()
             .map_or((String::new(), false), |s| (s, true));
 
-        // Worktree state: operations (✘⤴⤵) take priority over location (/⚑⊟⊞)
+        // Worktree state: operations (✘⤴⤵) take priority over location (/⤓⚑⊟⊞)
         let (worktree_str, has_worktree) = if self.operation_state != OperationState::None {
             // Operation state takes priority
             (self.operation_state.styled().unwrap_or_default(), true)
@@ -282,8 +288,10 @@ impl StatusSymbols {
             // Fall back to location state
             match self.worktree_state {
                 WorktreeState::None => (String::new(), false),
-                // Branch indicator (/) is informational (dimmed)
-                WorktreeState::Branch => (cformat!("<dim>{}</>", self.worktree_state), true),
+                // Branch indicators (/⤓) are informational (dimmed)
+                WorktreeState::Branch | WorktreeState::Remote => {
+                    (cformat!("<dim>{}</>", self.worktree_state), true)
+                }
                 // Branch-worktree mismatch (⚑) is a stronger warning (red)
                 WorktreeState::BranchWorktreeMismatch => {
                     (cformat!("<red>{}</>", self.worktree_state), true)
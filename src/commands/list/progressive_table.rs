@@ -15,8 +15,23 @@ use crossterm::{
 };
 use std::io::{IsTerminal, Write, stdout};
 
+use worktrunk::styling::{Stream, colors_enabled};
+
 use crate::display::truncate_visible;
 
+/// Truncate `line` to `max_width`, dropping its styles when colors are off.
+///
+/// The table writes to stdout directly rather than through anstream, so it
+/// applies `--color` and `NO_COLOR` itself.
+fn fit(line: &str, max_width: usize, color: bool) -> String {
+    let line = truncate_visible(line, max_width);
+    if color {
+        line
+    } else {
+        anstream::adapter::strip_str(&line).to_string()
+    }
+}
+
 /// Progressive table that updates rows in-place using crossterm cursor control.
 ///
 /// The table structure is:
@@ -39,6 +54,8 @@ pub struct ProgressiveTable {
     total_row_count: usize,
     /// Whether output is going to a TTY
     is_tty: bool,
+    /// Whether lines keep their colors
+    color: bool,
     /// Lines that have been modified since last flush
     dirty: Vec<usize>,
     /// Whether the skeleton was printed (only true in TTY mode after render_skeleton)
@@ -79,6 +96,7 @@ impl ProgressiveTable {
         terminal_height: Option<usize>,
     ) -> Self {
         let is_tty = stdout().is_terminal();
+        let color = colors_enabled(Stream::Stdout);
         let total_row_count = skeletons.len();
 
         // Limit visible rows to fit in terminal: header + rows + spacer + footer = rows + 3
@@ -90,17 +108,17 @@ impl ProgressiveTable {
 
         // Build initial lines: header + visible rows + spacer + footer
         let mut lines = Vec::with_capacity(visible_row_count + 3);
-        lines.push(truncate_visible(&header, max_width));
+        lines.push(fit(&header, max_width, color));
 
         for skeleton in skeletons.into_iter().take(visible_row_count) {
-            lines.push(truncate_visible(&skeleton, max_width));
+            lines.push(fit(&skeleton, max_width, color));
         }
 
         // Spacer (blank line)
         lines.push(String::new());
 
         // Footer
-        lines.push(truncate_visible(&initial_footer, max_width));
+        lines.push(fit(&initial_footer, max_width, color));
 
         Self {
             lines,
@@ -108,6 +126,7 @@ impl ProgressiveTable {
             row_count: visible_row_count,
             total_row_count,
             is_tty,
+            color,
             dirty: Vec::new(),
             rendered: false,
         }
@@ -148,7 +167,7 @@ impl ProgressiveTable {
             return false;
         }
 
-        let truncated = truncate_visible(&content, self.max_width);
+        let truncated = fit(&content, self.max_width, self.color);
 
         // Line index: header (0) + row_idx
         let line_idx = row_idx + 1;
@@ -173,7 +192,7 @@ impl ProgressiveTable {
     /// # Returns
     /// `true` if the content changed, `false` if unchanged.
     pub fn update_footer(&mut self, content: String) -> bool {
-        let truncated = truncate_visible(&content, self.max_width);
+        let truncated = fit(&content, self.max_width, self.color);
 
         // Footer is the last line
         let footer_idx = self.lines.len() - 1;
@@ -249,15 +268,16 @@ impl ProgressiveTable {
     ///
     /// For the normal case, updates rows in-place and redraws the footer.
     /// When overflowing (skeleton showed a subset of rows), erases the skeleton
-    /// and prints the complete table — the output scrolls naturally, avoiding
-    /// the `MoveUp`-into-scrollback problem.
+    /// and prints the complete table through the pager — or, with paging off,
+    /// directly, so it scrolls naturally. Either way avoids the
+    /// `MoveUp`-into-scrollback problem.
     pub fn finalize(
         &mut self,
         final_rows: Vec<String>,
         final_footer: String,
     ) -> std::io::Result<()> {
         if self.row_count < self.total_row_count {
-            // Overflow: erase skeleton, print complete table (scrolls naturally)
+            // Overflow: erase skeleton, page or print the complete table
             debug_assert!(
                 self.rendered,
                 "overflow finalize should only be called after render_skeleton"
@@ -266,17 +286,15 @@ impl ProgressiveTable {
             stdout.execute(MoveUp(self.lines.len() as u16))?;
             stdout.execute(MoveToColumn(0))?;
             stdout.execute(Clear(ClearType::FromCursorDown))?;
-            writeln!(stdout, "{}", self.lines[0])?; // header (unchanged)
+            let mut table = format!("{}\n", self.lines[0]); // header (unchanged)
             for row in &final_rows {
-                writeln!(stdout, "{}", truncate_visible(row, self.max_width))?;
+                table.push_str(&fit(row, self.max_width, self.color));
+                table.push('\n');
             }
-            writeln!(stdout)?;
-            writeln!(
-                stdout,
-                "{}",
-                truncate_visible(&final_footer, self.max_width)
-            )?;
-            stdout.flush()
+            table.push('\n');
+            table.push_str(&fit(&final_footer, self.max_width, self.color));
+            table.push('\n');
+            crate::pager::page_stdout(&table)
         } else {
             // Normal: update rows in-place + footer
             for (idx, row) in final_rows.into_iter().enumerate() {
@@ -1,8 +1,9 @@
 use crate::display::{format_relative_time_short, shorten_path, truncate_to_width};
-use anstyle::Style;
+use anstyle::{AnsiColor, Style};
 use std::path::Path;
 use unicode_width::UnicodeWidthStr;
-use worktrunk::styling::{Stream, StyledLine, hyperlink_stdout, supports_hyperlinks};
+use worktrunk::git::GitRemoteUrl;
+use worktrunk::styling::{Stream, StyledLine, file_url, hyperlink_stdout, supports_hyperlinks};
 
 use super::collect::parse_port_from_url;
 use super::columns::{ColumnKind, DiffVariant};
@@ -243,6 +244,7 @@ impl LayoutConfig {
                 &self.status_position_mask,
                 &self.main_worktree_path,
                 self.max_message_len,
+                self.forge_url.as_ref(),
             )
         })
     }
@@ -278,7 +280,7 @@ impl LayoutConfig {
                 }
                 ColumnKind::Branch => {
                     // Show actual branch name (no dim - start normal, gray out later if removable)
-                    cell.push_raw(branch.to_string());
+                    cell.push_raw(item.branch_label().unwrap_or_else(|| branch.to_string()));
                     cell.pad_to(col.width);
                 }
                 ColumnKind::Path => {
@@ -329,6 +331,19 @@ impl ColumnLayout {
         cell.truncate_to_width(self.width)
     }
 
+    /// Text cell linked to `url` when hyperlinks are supported.
+    ///
+    /// Text that needs truncating is left unlinked, so truncation can't cut
+    /// the closing escape sequence.
+    fn render_link_cell(&self, text: &str, url: Option<&str>, style: Option<Style>) -> StyledLine {
+        match url {
+            Some(url) if text.width() <= self.width && supports_hyperlinks(Stream::Stdout) => {
+                self.render_text_cell(&hyperlink_stdout(url, text), style)
+            }
+            _ => self.render_text_cell(text, style),
+        }
+    }
+
     fn render_diff_cell(&self, positive: usize, negative: usize) -> StyledLine {
         let ColumnFormat::Diff(config) = self.format else {
             return StyledLine::new();
@@ -345,6 +360,7 @@ impl ColumnLayout {
         status_mask: &PositionMask,
         main_worktree_path: &Path,
         max_message_len: usize,
+        forge_url: Option<&GitRemoteUrl>,
     ) -> StyledLine {
         // Compute derived values inline (avoids separate context struct)
         let worktree_data = item.worktree_data();
@@ -369,8 +385,19 @@ impl ColumnLayout {
                 cell
             }
             ColumnKind::Branch => {
-                let text = item.branch.as_deref().unwrap_or("-");
-                self.render_text_cell(text, text_style)
+                let label = item.branch_label();
+                let text = label.as_deref().unwrap_or("-");
+                // Link to the PR/MR when there is one, else to the branch page for
+                // worktrees (branch-only rows may be remote-only)
+                let url = item
+                    .pr_status
+                    .as_ref()
+                    .and_then(|pr| pr.as_ref()?.url.clone())
+                    .or_else(|| {
+                        let branch = worktree_data.and(item.branch.as_deref())?;
+                        Some(forge_url?.branch_web_url(branch))
+                    });
+                self.render_link_cell(text, url.as_deref(), text_style)
             }
             ColumnKind::Status => {
                 let Some(ref status_symbols) = item.status_symbols else {
@@ -413,7 +440,34 @@ impl ColumnLayout {
                     return StyledLine::new();
                 };
                 let path_str = shorten_path(&data.path, main_worktree_path);
-                self.render_text_cell(&path_str, text_style)
+                self.render_link_cell(&path_str, Some(&file_url(&data.path)), text_style)
+            }
+            ColumnKind::Health => {
+                let Some(data) = worktree_data else {
+                    return StyledLine::new();
+                };
+                let Some(flags) = data.health_flags() else {
+                    return self.placeholder_cell("⋯");
+                };
+                let labels: Vec<_> = flags.iter().map(|flag| flag.label()).collect();
+                let warning = Style::new().fg_color(Some(AnsiColor::Yellow.into()));
+                let mut cell = StyledLine::new();
+                cell.push_styled(labels.join(" "), warning);
+                cell.truncate_to_width(self.width)
+            }
+            ColumnKind::DiskUsage => {
+                let Some(data) = worktree_data.filter(|data| !data.is_prunable()) else {
+                    return StyledLine::new();
+                };
+                let Some(bytes) = data.disk_usage else {
+                    return self.placeholder_cell("⋯");
+                };
+                // Right-align so sizes line up by magnitude
+                let size = super::disk_usage::format_size(bytes);
+                let mut cell = StyledLine::new();
+                cell.push_raw(" ".repeat(self.width.saturating_sub(size.width())));
+                cell.push_raw(size);
+                cell
             }
             ColumnKind::Upstream => {
                 let upstream = item.upstream();
@@ -462,6 +516,42 @@ impl ColumnLayout {
                 }
                 cell.truncate_to_width(self.width)
             }
+            ColumnKind::Note => {
+                let Some(label) = item.note_label() else {
+                    return StyledLine::new();
+                };
+                let url = item.note.as_deref().filter(|note| note.contains("://"));
+                self.render_link_cell(&label, url, None)
+            }
+            ColumnKind::Ticket => {
+                let Some(ticket) = &item.ticket else {
+                    return StyledLine::new();
+                };
+                let dim = Style::new().dimmed();
+                let text = format!("{} {}", ticket.key, ticket.status);
+                if text.width() > self.width || !supports_hyperlinks(Stream::Stdout) {
+                    let mut cell = StyledLine::new();
+                    cell.push_raw(ticket.key.clone());
+                    cell.push_styled(format!(" {}", ticket.status), dim);
+                    return cell.truncate_to_width(self.width);
+                }
+                let mut cell = self.render_link_cell(&ticket.key, Some(&ticket.url), None);
+                cell.push_styled(format!(" {}", ticket.status), dim);
+                cell
+            }
+            ColumnKind::Docker => {
+                let Some(state) = &item.container else {
+                    return StyledLine::new();
+                };
+                let mut cell = StyledLine::new();
+                // Anything but a running container is dimmed
+                if state == "running" {
+                    cell.push_raw(state.clone());
+                } else {
+                    cell.push_styled(state.clone(), Style::new().dimmed());
+                }
+                cell.truncate_to_width(self.width)
+            }
             ColumnKind::CiStatus => {
                 // Check display field first for pending indicators during progressive rendering
                 // (works for both worktrees and branches)
@@ -530,7 +620,7 @@ mod tests {
     use super::*;
     use crate::commands::list::layout::DiffDisplayConfig;
     use ansi_str::AnsiStr;
-    use worktrunk::styling::{ADDITION, DELETION};
+    use worktrunk::styling::theme;
 
     fn format_diff_like_column(
         positive: usize,
@@ -547,8 +637,8 @@ mod tests {
 
         let config = DiffDisplayConfig {
             variant: DiffVariant::Signs,
-            positive_style: ADDITION,
-            negative_style: DELETION,
+            positive_style: theme().addition,
+            negative_style: theme().deletion,
             always_show_zeros: false,
         };
 
@@ -593,8 +683,8 @@ mod tests {
 
         let config = DiffDisplayConfig {
             variant: DiffVariant::Signs,
-            positive_style: ADDITION,
-            negative_style: DELETION,
+            positive_style: theme().addition,
+            negative_style: theme().deletion,
             always_show_zeros: false,
         };
 
@@ -639,8 +729,8 @@ mod tests {
                 total_width: total,
                 display: DiffDisplayConfig {
                     variant: DiffVariant::Signs,
-                    positive_style: ADDITION,
-                    negative_style: DELETION,
+                    positive_style: theme().addition,
+                    negative_style: theme().deletion,
                     always_show_zeros: false,
                 },
             },
@@ -662,8 +752,8 @@ mod tests {
                 total_width: total,
                 display: DiffDisplayConfig {
                     variant: DiffVariant::Signs,
-                    positive_style: ADDITION,
-                    negative_style: DELETION,
+                    positive_style: theme().addition,
+                    negative_style: theme().deletion,
                     always_show_zeros: false,
                 },
             },
@@ -685,8 +775,8 @@ mod tests {
                 total_width: total,
                 display: DiffDisplayConfig {
                     variant: DiffVariant::Signs,
-                    positive_style: ADDITION,
-                    negative_style: DELETION,
+                    positive_style: theme().addition,
+                    negative_style: theme().deletion,
                     always_show_zeros: false,
                 },
             },
@@ -708,8 +798,8 @@ mod tests {
                 total_width: total,
                 display: DiffDisplayConfig {
                     variant: DiffVariant::Signs,
-                    positive_style: ADDITION,
-                    negative_style: DELETION,
+                    positive_style: theme().addition,
+                    negative_style: theme().deletion,
                     always_show_zeros: false,
                 },
             },
@@ -733,8 +823,8 @@ mod tests {
                 total_width: total,
                 display: DiffDisplayConfig {
                     variant: DiffVariant::Signs,
-                    positive_style: ADDITION,
-                    negative_style: DELETION,
+                    positive_style: theme().addition,
+                    negative_style: theme().deletion,
                     always_show_zeros: false,
                 },
             },
@@ -853,11 +943,10 @@ mod tests {
     #[test]
     fn test_arrow_variant_alignment_invariant() {
         use super::super::columns::DiffVariant;
-        use worktrunk::styling::{ADDITION, DELETION};
 
         let total = 7;
 
-        let dim_deletion = DELETION.dimmed();
+        let dim_deletion = theme().deletion.dimmed();
         let cases = [(0, 0), (1, 0), (0, 1), (1, 1), (99, 99), (5, 44)];
 
         for (ahead, behind) in cases {
@@ -870,7 +959,7 @@ mod tests {
                     total_width: total,
                     display: DiffDisplayConfig {
                         variant: DiffVariant::Arrows,
-                        positive_style: ADDITION,
+                        positive_style: theme().addition,
                         negative_style: dim_deletion,
                         always_show_zeros: false,
                     },
@@ -883,11 +972,10 @@ mod tests {
     #[test]
     fn test_arrow_variant_respects_header_width() {
         use super::super::columns::DiffVariant;
-        use worktrunk::styling::{ADDITION, DELETION};
 
         let total = 7;
 
-        let dim_deletion = DELETION.dimmed();
+        let dim_deletion = theme().deletion.dimmed();
 
         let empty = format_diff_like_column(
             0,
@@ -898,7 +986,7 @@ mod tests {
                 total_width: total,
                 display: DiffDisplayConfig {
                     variant: DiffVariant::Arrows,
-                    positive_style: ADDITION,
+                    positive_style: theme().addition,
                     negative_style: dim_deletion,
                     always_show_zeros: false,
                 },
@@ -915,7 +1003,7 @@ mod tests {
                 total_width: total,
                 display: DiffDisplayConfig {
                     variant: DiffVariant::Arrows,
-                    positive_style: ADDITION,
+                    positive_style: theme().addition,
                     negative_style: dim_deletion,
                     always_show_zeros: false,
                 },
@@ -927,11 +1015,10 @@ mod tests {
     #[test]
     fn test_always_show_zeros_renders_zero_values() {
         use super::super::columns::DiffVariant;
-        use worktrunk::styling::{ADDITION, DELETION};
 
         let total = 7;
 
-        let dim_deletion = DELETION.dimmed();
+        let dim_deletion = theme().deletion.dimmed();
 
         // With always_show_zeros=false, (0, 0) renders as blank
         let without = format_diff_like_column(
@@ -943,7 +1030,7 @@ mod tests {
                 total_width: total,
                 display: DiffDisplayConfig {
                     variant: DiffVariant::Arrows,
-                    positive_style: ADDITION,
+                    positive_style: theme().addition,
                     negative_style: dim_deletion,
                     always_show_zeros: false,
                 },
@@ -964,7 +1051,7 @@ mod tests {
                 total_width: total,
                 display: DiffDisplayConfig {
                     variant: DiffVariant::Arrows,
-                    positive_style: ADDITION,
+                    positive_style: theme().addition,
                     negative_style: dim_deletion,
                     always_show_zeros: true,
                 },
@@ -1058,8 +1145,8 @@ mod tests {
                 total_width: total,
                 display: DiffDisplayConfig {
                     variant: DiffVariant::Signs,
-                    positive_style: ADDITION,
-                    negative_style: DELETION,
+                    positive_style: theme().addition,
+                    negative_style: theme().deletion,
                     always_show_zeros: false,
                 },
             },
@@ -1078,8 +1165,8 @@ mod tests {
                 total_width: total,
                 display: DiffDisplayConfig {
                     variant: DiffVariant::Signs,
-                    positive_style: ADDITION,
-                    negative_style: DELETION,
+                    positive_style: theme().addition,
+                    negative_style: theme().deletion,
                     always_show_zeros: false,
                 },
             },
@@ -1098,8 +1185,8 @@ mod tests {
                 total_width: total,
                 display: DiffDisplayConfig {
                     variant: DiffVariant::Signs,
-                    positive_style: ADDITION,
-                    negative_style: DELETION,
+                    positive_style: theme().addition,
+                    negative_style: theme().deletion,
                     always_show_zeros: false,
                 },
             },
@@ -1150,8 +1237,8 @@ mod tests {
                 total_width: total,
                 display: DiffDisplayConfig {
                     variant: DiffVariant::Signs,
-                    positive_style: ADDITION,
-                    negative_style: DELETION,
+                    positive_style: theme().addition,
+                    negative_style: theme().deletion,
                     always_show_zeros: false,
                 },
             },
@@ -1170,8 +1257,8 @@ mod tests {
                 total_width: total,
                 display: DiffDisplayConfig {
                     variant: DiffVariant::Signs,
-                    positive_style: ADDITION,
-                    negative_style: DELETION,
+                    positive_style: theme().addition,
+                    negative_style: theme().deletion,
                     always_show_zeros: false,
                 },
             },
@@ -1192,8 +1279,8 @@ mod tests {
                 total_width: total,
                 display: DiffDisplayConfig {
                     variant: DiffVariant::Signs,
-                    positive_style: ADDITION,
-                    negative_style: DELETION,
+                    positive_style: theme().addition,
+                    negative_style: theme().deletion,
                     always_show_zeros: false,
                 },
             },
@@ -1213,8 +1300,8 @@ mod tests {
                 total_width: total,
                 display: DiffDisplayConfig {
                     variant: DiffVariant::Signs,
-                    positive_style: ADDITION,
-                    negative_style: DELETION,
+                    positive_style: theme().addition,
+                    negative_style: theme().deletion,
                     always_show_zeros: false,
                 },
             },
@@ -1242,8 +1329,8 @@ mod tests {
                 total_width: arrow_total,
                 display: DiffDisplayConfig {
                     variant: DiffVariant::Arrows,
-                    positive_style: ADDITION,
-                    negative_style: DELETION,
+                    positive_style: theme().addition,
+                    negative_style: theme().deletion,
                     always_show_zeros: false,
                 },
             },
@@ -1264,8 +1351,8 @@ mod tests {
                 total_width: arrow_total,
                 display: DiffDisplayConfig {
                     variant: DiffVariant::Arrows,
-                    positive_style: ADDITION,
-                    negative_style: DELETION,
+                    positive_style: theme().addition,
+                    negative_style: theme().deletion,
                     always_show_zeros: false,
                 },
             },
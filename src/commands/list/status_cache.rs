@@ -0,0 +1,206 @@
+//! Working tree status cache, kept valid by `wt daemon`'s filesystem watcher.
+//!
+//! `git status` walks the whole working tree, which dominates `wt list` in
+//! large repositories. While a daemon watches the worktrees, each worktree's
+//! status output and diff totals are cached in
+//! `.git/wt-cache/status/<worktree>.json` together with the worktree's
+//! *generation* — a counter the watcher bumps on every change to its files or
+//! index. A cached entry is used only while the watcher is running, lists the
+//! worktree as watched, and the generation it was computed at is still
+//! current; otherwise nothing is read or written and status is computed as
+//! before.
+//!
+//! The generation is read before computing, so a change made while `git
+//! status` runs leaves the new entry already stale.
+
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+
+use fs2::FileExt;
+use serde::{Deserialize, Serialize};
+use worktrunk::git::LineDiff;
+use worktrunk::path::sanitize_for_filename;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct CachedStatus {
+    /// The worktree path, to detect filename collisions after sanitizing
+    path: PathBuf,
+    generation: u64,
+    /// `git status --porcelain` output
+    pub status_output: String,
+    pub working_tree_diff: LineDiff,
+}
+
+fn cache_dir(git_common_dir: &Path) -> PathBuf {
+    git_common_dir.join("wt-cache").join("status")
+}
+
+fn cache_file(git_common_dir: &Path, worktree: &Path, extension: &str) -> PathBuf {
+    let name = sanitize_for_filename(&worktree.to_string_lossy());
+    cache_dir(git_common_dir).join(format!("{name}.{extension}"))
+}
+
+/// Lock file held exclusively by a running watcher.
+pub(crate) fn watcher_lock_path(git_common_dir: &Path) -> PathBuf {
+    cache_dir(git_common_dir).join("watcher.lock")
+}
+
+/// Worktrees the running watcher has registered, one path per line.
+fn watched_list_path(git_common_dir: &Path) -> PathBuf {
+    cache_dir(git_common_dir).join("watched")
+}
+
+/// Record the worktrees the watcher is watching; only their cached statuses
+/// are trusted.
+pub(crate) fn set_watched<'a>(git_common_dir: &Path, worktrees: impl Iterator<Item = &'a Path>) {
+    let list: String = worktrees
+        .map(|path| format!("{}\n", path.display()))
+        .collect();
+    let path = watched_list_path(git_common_dir);
+    let temp_path = path.with_extension("tmp");
+    let result = fs::create_dir_all(cache_dir(git_common_dir))
+        .and_then(|()| fs::write(&temp_path, list))
+        .and_then(|()| fs::rename(&temp_path, &path));
+    if let Err(e) = result {
+        // Without an accurate list nothing can be trusted
+        log::debug!("Failed to record watched worktrees: {e}");
+        let _ = fs::remove_file(&path);
+    }
+}
+
+fn is_watched(git_common_dir: &Path, worktree: &Path) -> bool {
+    fs::read_to_string(watched_list_path(git_common_dir))
+        .is_ok_and(|list| list.lines().any(|line| Path::new(line) == worktree))
+}
+
+/// Whether a watcher currently holds the lock.
+fn watcher_active(git_common_dir: &Path) -> bool {
+    let Ok(file) = File::open(watcher_lock_path(git_common_dir)) else {
+        return false;
+    };
+    if file.try_lock_shared().is_ok() {
+        let _ = FileExt::unlock(&file);
+        return false;
+    }
+    true
+}
+
+/// The worktree's current generation, or `None` when no watcher is running
+/// or it isn't watching this worktree (and caching is off).
+pub(crate) fn generation(git_common_dir: &Path, worktree: &Path) -> Option<u64> {
+    if !watcher_active(git_common_dir) || !is_watched(git_common_dir, worktree) {
+        return None;
+    }
+    Some(read_generation(git_common_dir, worktree))
+}
+
+fn read_generation(git_common_dir: &Path, worktree: &Path) -> u64 {
+    fs::read_to_string(cache_file(git_common_dir, worktree, "gen"))
+        .ok()
+        .and_then(|s| s.trim().parse().ok())
+        .unwrap_or(0)
+}
+
+/// Record a change to the worktree, invalidating its cached status.
+pub(crate) fn bump_generation(git_common_dir: &Path, worktree: &Path) {
+    let next = read_generation(git_common_dir, worktree) + 1;
+    let path = cache_file(git_common_dir, worktree, "gen");
+    if let Err(e) = fs::create_dir_all(cache_dir(git_common_dir))
+        .and_then(|()| fs::write(&path, next.to_string()))
+    {
+        log::debug!("Failed to record change to {}: {e}", worktree.display());
+    }
+}
+
+impl CachedStatus {
+    /// Cached status of `worktree`, if computed at its current generation.
+    pub(crate) fn read(git_common_dir: &Path, worktree: &Path) -> Option<Self> {
+        let generation = generation(git_common_dir, worktree)?;
+        let json = fs::read_to_string(cache_file(git_common_dir, worktree, "json")).ok()?;
+        let cached: Self = serde_json::from_str(&json).ok()?;
+        (cached.path == worktree && cached.generation == generation).then_some(cached)
+    }
+
+    /// Cache a status computed at `generation`. Best-effort: a failed write
+    /// only costs a `git status` next time.
+    pub(crate) fn write(
+        git_common_dir: &Path,
+        worktree: &Path,
+        generation: u64,
+        status_output: &str,
+        working_tree_diff: LineDiff,
+    ) {
+        let cached = Self {
+            path: worktree.to_path_buf(),
+            generation,
+            status_output: status_output.to_string(),
+            working_tree_diff,
+        };
+        let path = cache_file(git_common_dir, worktree, "json");
+        let Ok(json) = serde_json::to_string(&cached) else {
+            return;
+        };
+        let temp_path = path.with_extension("json.tmp");
+        if fs::create_dir_all(cache_dir(git_common_dir)).is_ok()
+            && fs::write(&temp_path, json).is_ok()
+        {
+            #[cfg(windows)]
+            let _ = fs::remove_file(&path);
+            if fs::rename(&temp_path, &path).is_err() {
+                let _ = fs::remove_file(&temp_path);
+            }
+        }
+    }
+}
+
+/// Clear cached statuses, returns count cleared.
+pub(crate) fn clear_cache(git_common_dir: &Path) -> usize {
+    let Ok(entries) = fs::read_dir(cache_dir(git_common_dir)) else {
+        return 0;
+    };
+    entries
+        .flatten()
+        .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "json"))
+        .filter(|entry| fs::remove_file(entry.path()).is_ok())
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cache_requires_watcher_and_current_generation() {
+        let dir = tempfile::tempdir().unwrap();
+        let common_dir = dir.path();
+        let worktree = Path::new("/repo/feature");
+        let diff = LineDiff {
+            added: 3,
+            deleted: 1,
+        };
+
+        // No watcher: nothing is cached
+        assert_eq!(generation(common_dir, worktree), None);
+        CachedStatus::write(common_dir, worktree, 0, " M src/lib.rs\n", diff);
+        assert!(CachedStatus::read(common_dir, worktree).is_none());
+
+        fs::create_dir_all(cache_dir(common_dir)).unwrap();
+        let lock = File::create(watcher_lock_path(common_dir)).unwrap();
+        lock.lock_exclusive().unwrap();
+        // A worktree the watcher couldn't register isn't trusted
+        assert_eq!(generation(common_dir, worktree), None);
+        set_watched(common_dir, [worktree].into_iter());
+        assert_eq!(generation(common_dir, worktree), Some(0));
+        let cached = CachedStatus::read(common_dir, worktree).unwrap();
+        assert_eq!(cached.status_output, " M src/lib.rs\n");
+        assert_eq!(cached.working_tree_diff, diff);
+
+        // A change invalidates it
+        bump_generation(common_dir, worktree);
+        assert!(CachedStatus::read(common_dir, worktree).is_none());
+        assert_eq!(clear_cache(common_dir), 1);
+
+        set_watched(common_dir, std::iter::empty());
+        assert_eq!(generation(common_dir, worktree), None);
+    }
+}
@@ -0,0 +1,90 @@
+//! `wt lock` and `wt unlock`: protect a worktree from removal.
+//!
+//! A thin layer over `git worktree lock`, addressed by branch like the other
+//! commands. `wt remove` refuses locked worktrees and shows the lock reason;
+//! git's own `worktree prune` skips them.
+
+use anyhow::Context;
+use color_print::cformat;
+use worktrunk::git::{GitError, Repository, WorktreeInfo};
+use worktrunk::styling::{eprintln, hint_message, info_message, success_message};
+
+/// The worktree for `branch`, or the current one.
+fn target_worktree(repo: &Repository, branch: Option<&str>) -> anyhow::Result<WorktreeInfo> {
+    let worktrees = repo.list_worktrees()?;
+    let wt = match branch {
+        Some(branch) => {
+            let branch = repo.resolve_worktree_name(branch)?;
+            worktrees
+                .into_iter()
+                .find(|wt| wt.branch.as_deref() == Some(branch.as_str()))
+                .ok_or(GitError::WorktreeMissing { branch })?
+        }
+        None => {
+            let current_path = repo.current_worktree().root()?.to_path_buf();
+            worktrees
+                .into_iter()
+                .find(|wt| wt.path == current_path)
+                .context("Current worktree not found in worktree list")?
+        }
+    };
+    if wt.path.exists() && !repo.worktree_at(&wt.path).is_linked()? {
+        return Err(GitError::Other {
+            message: "The main worktree can't be locked".into(),
+        }
+        .into());
+    }
+    Ok(wt)
+}
+
+/// Branch name, or directory name for a detached worktree.
+fn display_name(wt: &WorktreeInfo) -> String {
+    wt.branch
+        .clone()
+        .unwrap_or_else(|| wt.dir_name().to_string())
+}
+
+/// Handle `wt lock`.
+pub fn handle_lock(branch: Option<&str>, reason: Option<&str>) -> anyhow::Result<()> {
+    let repo = Repository::current().context("Failed to lock worktree")?;
+    let wt = target_worktree(&repo, branch)?;
+    let name = display_name(&wt);
+    if wt.locked.is_some() {
+        eprintln!(
+            "{}",
+            info_message(cformat!("<bold>{name}</> is already locked"))
+        );
+        return Ok(());
+    }
+
+    repo.lock_worktree(&wt.path, reason)?;
+    let reason_text = reason
+        .map(|r| cformat!(" <bright-black>({r})</>"))
+        .unwrap_or_default();
+    eprintln!(
+        "{}",
+        success_message(cformat!("Locked <bold>{name}</>{reason_text}"))
+    );
+    eprintln!(
+        "{}",
+        hint_message(cformat!(
+            "<bright-black>wt remove</> refuses it until <bright-black>wt unlock {name}</>"
+        ))
+    );
+    Ok(())
+}
+
+/// Handle `wt unlock`.
+pub fn handle_unlock(branch: Option<&str>) -> anyhow::Result<()> {
+    let repo = Repository::current().context("Failed to unlock worktree")?;
+    let wt = target_worktree(&repo, branch)?;
+    let name = display_name(&wt);
+    if wt.locked.is_none() {
+        eprintln!("{}", info_message(cformat!("<bold>{name}</> isn't locked")));
+        return Ok(());
+    }
+
+    repo.unlock_worktree(&wt.path)?;
+    eprintln!("{}", success_message(cformat!("Unlocked <bold>{name}</>")));
+    Ok(())
+}
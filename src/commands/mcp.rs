@@ -0,0 +1,381 @@
+//! `wt mcp` — worktree tools for coding agents over the Model Context Protocol.
+//!
+//! The server speaks JSON-RPC 2.0 over stdio, one message per line. Tools
+//! that change worktrees run `wt` itself as a subprocess with `--no-input`,
+//! so an agent goes through the same checks as someone at the terminal: hooks
+//! need prior approval, dirty worktrees aren't removed, unmerged branches are
+//! kept. Nothing here bypasses them. Branch names must pass
+//! `git check-ref-format --branch`, so an agent can't smuggle in options.
+
+use std::io::{BufRead, Write};
+use std::time::Duration;
+
+use ansi_str::AnsiStr;
+use serde_json::{Value, json};
+use worktrunk::config::UserConfig;
+use worktrunk::git::Repository;
+use worktrunk::ops;
+use worktrunk::shell_exec::{Cmd, ShellConfig};
+use worktrunk::styling::ERROR_SYMBOL;
+
+/// Protocol revision answered when the client doesn't ask for one.
+const PROTOCOL_VERSION: &str = "2024-11-05";
+
+/// Limit for `exec` commands when `[timeouts] execute` isn't set.
+const EXEC_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+
+/// Serve MCP requests from stdin until it closes.
+pub fn handle_mcp() -> anyhow::Result<()> {
+    let stdin = std::io::stdin();
+    let mut stdout = std::io::stdout().lock();
+    for line in stdin.lock().lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let response = match serde_json::from_str::<Value>(&line) {
+            Ok(message) => handle_message(&message),
+            Err(e) => Some(error_response(
+                Value::Null,
+                -32700,
+                &format!("Parse error: {e}"),
+            )),
+        };
+        if let Some(response) = response {
+            writeln!(stdout, "{response}")?;
+            stdout.flush()?;
+        }
+    }
+    Ok(())
+}
+
+/// Answer one JSON-RPC message; `None` for notifications.
+fn handle_message(message: &Value) -> Option<Value> {
+    // Notifications (no id) never get a response
+    let id = message.get("id")?.clone();
+    let params = message.get("params").cloned().unwrap_or(Value::Null);
+    let result = match message.get("method").and_then(Value::as_str) {
+        Some("initialize") => initialize(&params),
+        Some("ping") => json!({}),
+        Some("tools/list") => json!({ "tools": tools() }),
+        Some("tools/call") => call_tool(&params),
+        Some(method) => {
+            return Some(error_response(
+                id,
+                -32601,
+                &format!("Method not found: {method}"),
+            ));
+        }
+        None => return Some(error_response(id, -32600, "Invalid request")),
+    };
+    Some(json!({ "jsonrpc": "2.0", "id": id, "result": result }))
+}
+
+fn error_response(id: Value, code: i64, message: &str) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": { "code": code, "message": message },
+    })
+}
+
+fn initialize(params: &Value) -> Value {
+    let version = params
+        .get("protocolVersion")
+        .and_then(Value::as_str)
+        .unwrap_or(PROTOCOL_VERSION);
+    json!({
+        "protocolVersion": version,
+        "capabilities": { "tools": {} },
+        "serverInfo": { "name": "worktrunk", "version": env!("CARGO_PKG_VERSION") },
+    })
+}
+
+/// Tool definitions for `tools/list`.
+fn tools() -> Value {
+    let branch = json!({ "type": "string", "description": "Branch name" });
+    json!([
+        {
+            "name": "list",
+            "description": "List worktrees: path, branch, HEAD commit, and lock reason",
+            "inputSchema": { "type": "object", "properties": {} },
+        },
+        {
+            "name": "create",
+            "description": "Create a branch and a worktree for it, running the project's approved hooks",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "branch": branch,
+                    "base": { "type": "string", "description": "Branch to start from (default: the default branch)" },
+                },
+                "required": ["branch"],
+            },
+        },
+        {
+            "name": "remove",
+            "description": "Remove a branch's worktree; the branch is deleted only if it's merged",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "branch": branch,
+                    "keep_branch": { "type": "boolean", "description": "Keep the branch even if it's merged" },
+                },
+                "required": ["branch"],
+            },
+        },
+        {
+            "name": "exec",
+            "description": "Run a shell command in a branch's worktree; it's killed after 10 minutes unless `[timeouts] execute` says otherwise",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "branch": branch,
+                    "command": { "type": "string", "description": "Shell command" },
+                },
+                "required": ["branch", "command"],
+            },
+        },
+        {
+            "name": "status",
+            "description": "Status of one branch's worktree: uncommitted changes, and commits ahead of and behind the default branch",
+            "inputSchema": {
+                "type": "object",
+                "properties": { "branch": branch },
+                "required": ["branch"],
+            },
+        },
+    ])
+}
+
+/// Run a tool; failures are reported in the result, not as protocol errors.
+fn call_tool(params: &Value) -> Value {
+    let args = params.get("arguments").cloned().unwrap_or(Value::Null);
+    let arg = |name: &str| args.get(name).and_then(Value::as_str);
+    let result = match params.get("name").and_then(Value::as_str) {
+        Some("list") => list(),
+        Some("create") => match arg("branch") {
+            Some(branch) => create(branch, arg("base")),
+            None => Err("Missing argument: branch".into()),
+        },
+        Some("remove") => match arg("branch") {
+            Some(branch) => {
+                let keep_branch = args.get("keep_branch").and_then(Value::as_bool) == Some(true);
+                remove(branch, keep_branch)
+            }
+            None => Err("Missing argument: branch".into()),
+        },
+        Some("exec") => match (arg("branch"), arg("command")) {
+            (Some(branch), Some(command)) => exec(branch, command),
+            _ => Err("Missing argument: branch and command are required".into()),
+        },
+        Some("status") => match arg("branch") {
+            Some(branch) => status(branch),
+            None => Err("Missing argument: branch".into()),
+        },
+        Some(name) => Err(format!("Unknown tool: {name}")),
+        None => Err("Missing tool name".into()),
+    };
+    let (text, is_error) = match result {
+        Ok(text) => (text, false),
+        Err(text) => (text, true),
+    };
+    json!({
+        "content": [{ "type": "text", "text": text }],
+        "isError": is_error,
+    })
+}
+
+fn list() -> Result<String, String> {
+    let repo = current_repo()?;
+    let worktrees = ops::list_worktrees(&repo).map_err(error_text)?;
+    serde_json::to_string_pretty(&worktrees).map_err(|e| e.to_string())
+}
+
+fn create(branch: &str, base: Option<&str>) -> Result<String, String> {
+    check_branch(&current_repo()?, branch)?;
+    let mut wt_args = vec!["switch".to_string(), "--create".into(), "--no-cd".into()];
+    if let Some(base) = base {
+        if base.starts_with('-') {
+            return Err(format!("Invalid base: {base}"));
+        }
+        wt_args.push(format!("--base={base}"));
+    }
+    // No `--` here: `wt switch` passes everything after it to `--execute`.
+    // `check_branch` already refused names starting with `-`.
+    wt_args.push(branch.into());
+    run_wt(&wt_args)
+}
+
+fn remove(branch: &str, keep_branch: bool) -> Result<String, String> {
+    check_branch(&current_repo()?, branch)?;
+    let mut wt_args = vec!["remove", "--foreground"];
+    if keep_branch {
+        wt_args.push("--no-delete-branch");
+    }
+    wt_args.extend(["--", branch]);
+    run_wt(&wt_args)
+}
+
+/// Refuse anything git wouldn't accept as a branch name — in particular
+/// anything `wt` could parse as an option.
+fn check_branch(repo: &Repository, branch: &str) -> Result<(), String> {
+    let valid = !branch.starts_with('-')
+        && repo
+            .run_command(&["check-ref-format", "--branch", branch])
+            .is_ok();
+    if valid {
+        Ok(())
+    } else {
+        Err(format!("Invalid branch name: {branch}"))
+    }
+}
+
+/// Run `wt <args>` without prompts or colors, returning what it printed.
+fn run_wt<S: AsRef<str>>(args: &[S]) -> Result<String, String> {
+    let exe = std::env::current_exe().map_err(|e| e.to_string())?;
+    let output = Cmd::new(exe.to_string_lossy())
+        .arg("--no-input")
+        .args(args.iter().map(AsRef::as_ref))
+        .env("NO_COLOR", "1")
+        .env_remove("CLICOLOR_FORCE")
+        .run()
+        .map_err(|e| e.to_string())?;
+    let text = combine(&output.stdout, &output.stderr);
+    if output.status.success() {
+        Ok(text)
+    } else {
+        Err(text)
+    }
+}
+
+/// Run `command` in the worktree of `branch`, killing it after
+/// `[timeouts] execute` (default [`EXEC_TIMEOUT`]).
+fn exec(branch: &str, command: &str) -> Result<String, String> {
+    let repo = current_repo()?;
+    let path = ops::worktree_status(&repo, branch)
+        .map_err(error_text)?
+        .worktree
+        .path;
+    let timeout = UserConfig::load()
+        .ok()
+        .and_then(|config| config.timeouts(repo.project_identifier().ok().as_deref()))
+        .and_then(|timeouts| timeouts.execute())
+        .unwrap_or(EXEC_TIMEOUT);
+    let shell = ShellConfig::get();
+    let output = Cmd::new(shell.executable.to_string_lossy())
+        .args(shell.args.iter().cloned())
+        .arg(command)
+        .current_dir(path)
+        .context(branch)
+        .timeout(timeout)
+        .run()
+        .map_err(|e| match e.kind() {
+            std::io::ErrorKind::TimedOut => {
+                format!("Timed out after {}s: {command}", timeout.as_secs())
+            }
+            _ => e.to_string(),
+        })?;
+    let text = combine(&output.stdout, &output.stderr);
+    match output.status.code() {
+        Some(0) => Ok(text),
+        Some(code) => Err(format!("{text}\n(exit code {code})")),
+        None => Err(format!("{text}\n(terminated by signal)")),
+    }
+}
+
+/// Uncommitted changes and divergence from the default branch of `branch`'s worktree.
+fn status(branch: &str) -> Result<String, String> {
+    let status = ops::worktree_status(&current_repo()?, branch).map_err(error_text)?;
+    serde_json::to_string_pretty(&status).map_err(|e| e.to_string())
+}
+
+fn current_repo() -> Result<Repository, String> {
+    Repository::current().map_err(error_text)
+}
+
+/// An in-process error as tool output: its message without the CLI's styling.
+fn error_text(err: anyhow::Error) -> String {
+    let text = err.to_string().ansi_strip().into_owned();
+    match text.strip_prefix(ERROR_SYMBOL.glyph()) {
+        Some(message) => message.trim_start().to_string(),
+        None => text,
+    }
+}
+
+fn combine(stdout: &[u8], stderr: &[u8]) -> String {
+    let stdout = String::from_utf8_lossy(stdout);
+    let stderr = String::from_utf8_lossy(stderr);
+    [stdout.trim_end(), stderr.trim_end()]
+        .into_iter()
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_initialize_echoes_protocol_version() {
+        let response = handle_message(&json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "initialize",
+            "params": { "protocolVersion": "2025-03-26" },
+        }))
+        .unwrap();
+        assert_eq!(response["id"], 1);
+        assert_eq!(response["result"]["protocolVersion"], "2025-03-26");
+        assert_eq!(response["result"]["serverInfo"]["name"], "worktrunk");
+    }
+
+    #[test]
+    fn test_notifications_get_no_response() {
+        let message = json!({ "jsonrpc": "2.0", "method": "notifications/initialized" });
+        assert!(handle_message(&message).is_none());
+    }
+
+    #[test]
+    fn test_tools_list() {
+        let response =
+            handle_message(&json!({ "jsonrpc": "2.0", "id": "a", "method": "tools/list" }))
+                .unwrap();
+        let names: Vec<&str> = response["result"]["tools"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|tool| tool["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(names, ["list", "create", "remove", "exec", "status"]);
+    }
+
+    #[test]
+    fn test_unknown_method_and_tool() {
+        let response =
+            handle_message(&json!({ "jsonrpc": "2.0", "id": 2, "method": "resources/list" }))
+                .unwrap();
+        assert_eq!(response["error"]["code"], -32601);
+
+        let response = handle_message(&json!({
+            "jsonrpc": "2.0",
+            "id": 3,
+            "method": "tools/call",
+            "params": { "name": "frobnicate" },
+        }))
+        .unwrap();
+        assert_eq!(response["result"]["isError"], true);
+        assert_eq!(
+            response["result"]["content"][0]["text"],
+            "Unknown tool: frobnicate"
+        );
+    }
+
+    #[test]
+    fn test_combine() {
+        assert_eq!(combine(b"out\n", b""), "out");
+        assert_eq!(combine(b"out\n", b"err\n"), "out\nerr");
+        assert_eq!(combine(b"", b"err"), "err");
+    }
+}
@@ -1,25 +1,34 @@
-use anyhow::Context;
+use anyhow::{Context, bail};
+use color_print::cformat;
 use worktrunk::HookType;
 use worktrunk::config::UserConfig;
-use worktrunk::git::Repository;
-use worktrunk::styling::{eprintln, info_message};
+use worktrunk::git::{GitError, Repository, WorktrunkError, exit_codes};
+use worktrunk::styling::{
+    eprintln, format_with_gutter, hint_message, info_message, progress_message, success_message,
+    warning_message,
+};
 
+use super::backport::{Backport, cherry_pick_onto};
 use super::command_approval::approve_command_batch;
 use super::command_executor::CommandContext;
 use super::commit::CommitOptions;
 use super::context::CommandEnv;
 use super::hooks::{HookFailureStrategy, execute_hook};
 use super::project_config::{HookCommand, collect_commands_for_hooks};
-use super::repository_ext::RepositoryCliExt;
+use super::repository_ext::{RepositoryCliExt, protected_deletion_mode};
+use super::resolve::record_paused;
 use super::worktree::{
     BranchDeletionMode, MergeOperations, RemoveResult, get_path_mismatch, handle_push,
 };
+use crate::output::prompt::assume_yes;
 
 /// Options for the merge command
 ///
 /// All boolean fields are optional CLI overrides. If None, the effective config
 /// (project-specific merged with global) is used. If that's also None, defaults apply.
 pub struct MergeOptions<'a> {
+    /// Target branch, or several separated by commas: the merge goes to the
+    /// first and is backported to the rest.
     pub target: Option<&'a str>,
     /// CLI override for squash. None = use effective config default.
     pub squash: Option<bool>,
@@ -128,8 +137,18 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
     // --no-commit implies --no-squash
     let squash_enabled = squash && commit;
 
+    // `main,release/1.2`: merge into the first, backport to the rest
+    let mut targets = target
+        .into_iter()
+        .flat_map(|target| target.split(','))
+        .map(str::trim)
+        .filter(|target| !target.is_empty());
+    let target = targets.next();
+
     // Get and validate target branch (must be a branch since we're updating it)
     let target_branch = repo.require_target_branch(target)?;
+    let backport_targets =
+        validate_backport_targets(repo, targets, &target_branch, &current_branch)?;
     // Worktree for target is optional: if present we use it for safety checks and as destination.
     let target_worktree_path = repo.worktree_for_branch(&target_branch)?;
 
@@ -143,7 +162,16 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
         collect_merge_commands(repo, commit, verify, remove_effective, squash_enabled)?;
 
     // Approve all commands in a single batch (shows templates, not expanded values)
-    let approved = approve_command_batch(&all_commands, &project_id, config, yes, false)?;
+    let approved = approve_command_batch(
+        &all_commands,
+        &project_id,
+        config,
+        yes || assume_yes(),
+        false,
+    )?;
+
+    // The secret scan is gated on --no-verify, not on hook approval
+    let scan_secrets = verify;
 
     // If commands were declined, skip hooks but continue with merge
     // Shadow verify to gate all subsequent hook execution on approval
@@ -191,11 +219,22 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
 
     // Rebase onto target - track whether rebasing occurred
     let rebased = if rebase {
-        // Auto-rebase onto target
-        matches!(
-            super::step_commands::handle_rebase(Some(&target_branch))?,
-            super::step_commands::RebaseResult::Rebased
-        )
+        // Auto-rebase onto target. In fork workflows, rebase onto upstream/<target>
+        // when the local target is behind it; the fast-forward below then brings
+        // the local target up to date too.
+        let rebase_target = if repo.fork_upstream_ref(&target_branch).is_some() {
+            repo.effective_integration_target(&target_branch)
+        } else {
+            target_branch.clone()
+        };
+        let result = super::step_commands::handle_rebase(Some(&rebase_target));
+        if let Err(e) = &result
+            && let Some(GitError::RebaseConflict { .. }) = e.downcast_ref()
+        {
+            // `wt resolve` suggests rerunning this merge once the rebase is done
+            record_paused(repo, &current_branch);
+        }
+        matches!(result?, super::step_commands::RebaseResult::Rebased)
     } else {
         // --no-rebase: verify already rebased, fail if not
         if !repo.is_rebased_onto(&target_branch)? {
@@ -218,6 +257,17 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
         )?;
     }
 
+    // Where the target was before the merge, to find the commits to backport
+    let merged_from = if backport_targets.is_empty() {
+        None
+    } else {
+        Some(
+            repo.run_command(&["rev-parse", &format!("refs/heads/{target_branch}")])?
+                .trim()
+                .to_string(),
+        )
+    };
+
     // Fast-forward push to target branch with commit/squash/rebase info for consolidated message
     handle_push(
         Some(&target_branch),
@@ -227,8 +277,16 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
             squashed,
             rebased,
         }),
+        scan_secrets,
+        false,
     )?;
 
+    // Backport failures don't stop cleanup: the target is already merged and pushed
+    let backport_exit = match &merged_from {
+        Some(merged_from) => backport_merged(repo, &target_branch, merged_from, &backport_targets)?,
+        None => None,
+    };
+
     // Destination: prefer the target branch's worktree; fall back to home path.
     let destination_path = match target_worktree_path {
         Some(path) => path,
@@ -257,7 +315,11 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
             worktree_path: worktree_root,
             changed_directory: true,
             branch_name: Some(current_branch.clone()),
-            deletion_mode: BranchDeletionMode::SafeDelete,
+            deletion_mode: protected_deletion_mode(
+                repo,
+                Some(&current_branch),
+                BranchDeletionMode::SafeDelete,
+            )?,
             target_branch: Some(target_branch.clone()),
             integration_reason,
             // Don't force removal - if worktree has untracked files added after
@@ -304,5 +366,136 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
         )?;
     }
 
+    if let Some(exit_code) = backport_exit {
+        return Err(WorktrunkError::AlreadyDisplayed { exit_code }.into());
+    }
     Ok(())
 }
+
+/// Check the branches after the first in `wt merge main,release/1.2` before
+/// anything changes.
+///
+/// Each must exist and differ from the merge target and the current branch;
+/// a worktree that has one checked out must be clean, since the cherry-picks
+/// run there.
+fn validate_backport_targets<'a>(
+    repo: &Repository,
+    targets: impl Iterator<Item = &'a str>,
+    target_branch: &str,
+    current_branch: &str,
+) -> anyhow::Result<Vec<String>> {
+    let mut backport_targets: Vec<String> = Vec::new();
+    for target in targets {
+        let branch = repo.require_target_branch(Some(target))?;
+        if branch == target_branch {
+            bail!("{branch} is the merge target; list it once");
+        }
+        if branch == current_branch {
+            bail!("Cannot backport {branch} to itself");
+        }
+        if backport_targets.contains(&branch) {
+            continue;
+        }
+        if let Some(path) = repo.worktree_for_branch(&branch)? {
+            repo.worktree_at(&path)
+                .ensure_clean("backport", Some(&branch), false)?;
+        }
+        backport_targets.push(branch);
+    }
+    Ok(backport_targets)
+}
+
+/// Cherry-pick what the merge brought into `target_branch` onto each of
+/// `backport_targets`.
+///
+/// A conflict or other failure leaves that target unchanged and moves on to
+/// the next. Returns the exit code to finish with when any target didn't get
+/// the backport: [`FAILURE`](exit_codes::FAILURE) when one failed for a reason
+/// other than a conflict, [`CONFLICT`](exit_codes::CONFLICT) otherwise.
+fn backport_merged(
+    repo: &Repository,
+    target_branch: &str,
+    merged_from: &str,
+    backport_targets: &[String],
+) -> anyhow::Result<Option<i32>> {
+    // Merge commits can't be cherry-picked without a mainline; their changes
+    // arrive with the commits they merged
+    let commits: Vec<String> = repo
+        .run_command(&[
+            "rev-list",
+            "--reverse",
+            "--no-merges",
+            &format!("{merged_from}..{target_branch}"),
+        ])?
+        .lines()
+        .map(String::from)
+        .collect();
+    if commits.is_empty() {
+        eprintln!("{}", info_message("Nothing merged, so nothing to backport"));
+        return Ok(None);
+    }
+    let short = |sha: &str| sha.chars().take(7).collect::<String>();
+    let range = format!(
+        "{}..{}",
+        short(merged_from),
+        short(commits.last().map_or(merged_from, String::as_str))
+    );
+
+    let mut exit_code = None;
+    for branch in backport_targets {
+        eprintln!(
+            "{}",
+            progress_message(cformat!("Backporting to <bold>{branch}</>..."))
+        );
+        let backport = match cherry_pick_onto(repo, branch, &commits) {
+            Ok(backport) => backport,
+            Err(e) => {
+                exit_code = Some(exit_codes::FAILURE);
+                eprintln!(
+                    "{}",
+                    warning_message(cformat!(
+                        "Backport to <bold>{branch}</> failed; left unchanged"
+                    ))
+                );
+                eprintln!("{}", format_with_gutter(&format!("{e:#}"), None));
+                continue;
+            }
+        };
+        match backport {
+            Backport::Applied { before, after } if before == after => {
+                eprintln!(
+                    "{}",
+                    info_message(cformat!("<bold>{branch}</> already has these changes"))
+                );
+            }
+            Backport::Applied { before, after } => {
+                let count = repo.count_commits(&before, &after)?;
+                let commit_text = if count == 1 { "commit" } else { "commits" };
+                eprintln!(
+                    "{}",
+                    success_message(cformat!(
+                        "Backported to <bold>{branch}</> <bright-black>({count} {commit_text})</>"
+                    ))
+                );
+            }
+            Backport::Conflict { commit, git_output } => {
+                exit_code.get_or_insert(exit_codes::CONFLICT);
+                eprintln!(
+                    "{}",
+                    warning_message(cformat!(
+                        "Backport to <bold>{branch}</> conflicts at <dim>{}</>; left unchanged",
+                        short(&commit)
+                    ))
+                );
+                eprintln!("{}", format_with_gutter(git_output.trim(), None));
+                eprintln!(
+                    "{}",
+                    hint_message(cformat!(
+                        "To backport by hand, run <bright-black>git cherry-pick -x {range}</> on <bold>{branch}</>"
+                    ))
+                );
+            }
+        }
+    }
+    Ok(exit_code)
+}
@@ -1,48 +1,128 @@
+mod absorb;
+mod activity;
+mod agent;
+mod auth;
+mod backport;
+mod bench;
 pub(crate) mod branch_deletion;
+mod bundle;
+mod ci;
 pub(crate) mod command_approval;
 pub(crate) mod command_executor;
 pub(crate) mod commit;
 pub(crate) mod config;
 pub(crate) mod configure_shell;
 pub(crate) mod context;
+#[cfg(unix)]
+pub(crate) mod daemon;
+mod database;
+mod describe;
+pub(crate) mod devcontainer;
+mod doctor;
+mod done;
 mod for_each;
+pub(crate) mod forge;
+mod frecency;
+mod graph;
+mod group;
 mod handle_switch;
 mod hook_commands;
 mod hook_filter;
+mod hook_logs;
 pub(crate) mod hooks;
 pub(crate) mod init;
+mod init_layout;
+mod issue;
+mod jira;
+mod jump;
 pub(crate) mod list;
+mod lock;
+mod mcp;
 pub(crate) mod merge;
+mod perf;
+pub(crate) mod plugin;
 pub(crate) mod process;
 pub(crate) mod project_config;
+mod prompt;
+mod recent;
 mod relocate;
 pub(crate) mod repository_ext;
+mod resolve;
+mod review;
+mod secret_scan;
 #[cfg(unix)]
 pub(crate) mod select;
+pub(crate) mod snapshot;
+mod split;
+mod stack;
+mod start;
+mod state_store;
 pub(crate) mod statusline;
 pub(crate) mod step_commands;
+mod tmux;
+pub(crate) mod undo;
+#[cfg(unix)]
+mod watch;
 pub(crate) mod worktree;
 
+pub(crate) use absorb::{AbsorbOptions, handle_absorb};
+pub(crate) use activity::handle_activity;
+pub(crate) use agent::{AgentOptions, handle_agent};
+pub(crate) use auth::{handle_auth_login, handle_auth_logout, handle_auth_status};
+pub(crate) use backport::{BackportOptions, handle_backport};
+pub(crate) use bench::handle_bench;
+pub(crate) use bundle::{handle_export, handle_import};
+pub(crate) use ci::{handle_ci, handle_ci_rerun};
 pub(crate) use config::{
-    handle_config_create, handle_config_show, handle_hints_clear, handle_hints_get,
-    handle_logs_get, handle_state_clear, handle_state_clear_all, handle_state_get,
-    handle_state_set, handle_state_show,
+    handle_config_create, handle_config_edit, handle_config_show, handle_hints_clear,
+    handle_hints_get, handle_logs_get, handle_state_clear, handle_state_clear_all,
+    handle_state_get, handle_state_set, handle_state_show,
 };
 pub(crate) use configure_shell::{
     handle_configure_shell, handle_show_theme, handle_unconfigure_shell,
 };
+pub(crate) use database::{handle_gc, step_db_create, step_db_drop};
+pub(crate) use describe::branch_from_description;
+pub(crate) use doctor::handle_doctor;
+pub(crate) use done::{DoneOptions, handle_done};
 pub(crate) use for_each::step_for_each;
+pub(crate) use graph::handle_graph;
+pub(crate) use group::{group_branches, handle_group_add, handle_group_list, handle_group_remove};
 pub(crate) use handle_switch::{SwitchOptions, handle_switch};
-pub(crate) use hook_commands::{add_approvals, clear_approvals, handle_hook_show, run_hook};
+pub(crate) use hook_commands::{
+    add_approvals, clear_approvals, handle_hook_check, handle_hook_show, run_hook,
+};
+pub(crate) use hook_logs::handle_hook_logs;
 pub(crate) use init::{handle_completions, handle_init};
-pub(crate) use list::handle_list;
+pub(crate) use init_layout::handle_init_layout;
+pub(crate) use issue::branch_from_issue;
+pub(crate) use jump::handle_jump;
+pub(crate) use list::{handle_list, handle_list_all_repos};
+pub(crate) use lock::{handle_lock, handle_unlock};
+pub(crate) use mcp::handle_mcp;
 pub(crate) use merge::{MergeOptions, handle_merge};
+pub(crate) use perf::handle_perf_report;
+pub(crate) use prompt::handle_prompt;
+pub(crate) use recent::handle_recent;
+pub(crate) use resolve::{ResolveOptions, handle_resolve};
+pub(crate) use review::{ReviewOptions, handle_review, handle_review_done};
 #[cfg(unix)]
 pub(crate) use select::handle_select;
+pub(crate) use snapshot::{
+    handle_snapshot, handle_snapshot_diff, handle_snapshot_list, handle_snapshot_restore,
+};
+pub(crate) use split::step_split;
+pub(crate) use stack::{
+    handle_list_stacks, handle_stack_add, handle_stack_remove, handle_stack_submit, handle_sync,
+};
+pub(crate) use start::{StartOptions, handle_start};
+pub(crate) use state_store::handle_state_store;
 pub(crate) use step_commands::{
     RebaseResult, SquashResult, handle_rebase, handle_squash, step_commit, step_copy_ignored,
     step_relocate, step_show_squash_prompt,
 };
+pub(crate) use tmux::{handle_tmux, handle_tmux_sync};
+pub(crate) use undo::handle_undo;
 pub(crate) use worktree::{
     OperationMode, handle_remove, handle_remove_current, is_worktree_at_expected_path,
     resolve_worktree_arg, worktree_display_name,
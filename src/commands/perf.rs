@@ -0,0 +1,248 @@
+//! `wt perf report`: summarize the local timing log.
+//!
+//! The log is written by `worktrunk::perf` when `WORKTRUNK_PERF_LOG` is set.
+//! Commands are summarized by median and slowest run; operations (subprocesses
+//! and phases) by total time across all logged commands.
+
+use std::collections::HashMap;
+
+use color_print::cformat;
+use worktrunk::git::Repository;
+use worktrunk::perf::{Entry, PERF_LOG_ENV, PERF_LOG_FILE, SpanKind, read_log};
+use worktrunk::styling::{eprintln, format_heading, hint_message, info_message};
+
+/// Git subcommands whose next word names the operation (`git worktree add`).
+const GIT_COMMAND_GROUPS: &[&str] = &[
+    "config",
+    "lfs",
+    "notes",
+    "remote",
+    "sparse-checkout",
+    "stash",
+    "submodule",
+    "worktree",
+];
+
+/// Handle `wt perf report`.
+pub fn handle_perf_report(limit: usize) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let entries = read_log(&repo.wt_logs_dir().join(PERF_LOG_FILE));
+    if entries.is_empty() {
+        eprintln!("{}", info_message("No timings logged yet"));
+        eprintln!(
+            "{}",
+            hint_message(cformat!(
+                "To record timings, set <bright-black>{PERF_LOG_ENV}=1</> and run some commands"
+            ))
+        );
+        return Ok(());
+    }
+
+    let commands = summarize_commands(&entries);
+    println!(
+        "{}",
+        format_heading("COMMANDS", Some(&format!("{} runs", entries.len())))
+    );
+    let width = commands.iter().map(|c| c.name.len()).max().unwrap_or(0);
+    for command in &commands {
+        println!(
+            "{:width$}  {:>4} runs  median {:>7}  slowest {:>7}",
+            command.name,
+            command.runs,
+            format_duration(command.median_us),
+            format_duration(command.slowest_us),
+        );
+    }
+
+    println!();
+    println!(
+        "{}",
+        format_heading("SLOWEST OPERATIONS", Some("by total time"))
+    );
+    let operations = summarize_operations(&entries);
+    let width = operations
+        .iter()
+        .take(limit)
+        .map(|op| op.name.len())
+        .max()
+        .unwrap_or(0);
+    for op in operations.iter().take(limit) {
+        println!(
+            "{:width$}  {:>5} calls  total {:>7}  slowest {:>7}",
+            op.name,
+            op.calls,
+            format_duration(op.total_us),
+            format_duration(op.slowest_us),
+        );
+    }
+    Ok(())
+}
+
+struct CommandSummary {
+    name: String,
+    runs: usize,
+    median_us: u64,
+    slowest_us: u64,
+}
+
+struct OperationSummary {
+    name: String,
+    calls: usize,
+    total_us: u64,
+    slowest_us: u64,
+}
+
+/// Per-command run counts and durations, slowest first.
+fn summarize_commands(entries: &[Entry]) -> Vec<CommandSummary> {
+    let mut durations: HashMap<&str, Vec<u64>> = HashMap::new();
+    for entry in entries {
+        durations
+            .entry(entry.command.as_str())
+            .or_default()
+            .push(entry.dur_us);
+    }
+    let mut summaries: Vec<_> = durations
+        .into_iter()
+        .map(|(name, mut durations)| {
+            durations.sort_unstable();
+            CommandSummary {
+                name: name.to_string(),
+                runs: durations.len(),
+                median_us: durations[durations.len() / 2],
+                slowest_us: durations[durations.len() - 1],
+            }
+        })
+        .collect();
+    summaries.sort_by(|a, b| b.slowest_us.cmp(&a.slowest_us).then(a.name.cmp(&b.name)));
+    summaries
+}
+
+/// Operations grouped by name, most total time first.
+fn summarize_operations(entries: &[Entry]) -> Vec<OperationSummary> {
+    let mut operations: HashMap<String, OperationSummary> = HashMap::new();
+    for span in entries.iter().flat_map(|entry| &entry.spans) {
+        let name = match span.kind {
+            SpanKind::Subprocess => operation_name(&span.name),
+            SpanKind::Phase => span.name.clone(),
+        };
+        let op = operations
+            .entry(name.clone())
+            .or_insert_with(|| OperationSummary {
+                name,
+                calls: 0,
+                total_us: 0,
+                slowest_us: 0,
+            });
+        op.calls += 1;
+        op.total_us += span.dur_us;
+        op.slowest_us = op.slowest_us.max(span.dur_us);
+    }
+    let mut operations: Vec<_> = operations.into_values().collect();
+    operations.sort_by(|a, b| b.total_us.cmp(&a.total_us).then(a.name.cmp(&b.name)));
+    operations
+}
+
+/// Group a subprocess command line: `git -C path status --porcelain` is `git status`.
+fn operation_name(command: &str) -> String {
+    let mut words = command.split_whitespace();
+    let Some(program) = words.next() else {
+        return String::new();
+    };
+    let program = program.rsplit('/').next().unwrap_or(program);
+    if program != "git" {
+        return program.to_string();
+    }
+
+    let mut name = vec!["git"];
+    while let Some(word) = words.next() {
+        match word {
+            // Global options that take a value
+            "-C" | "-c" | "--git-dir" | "--work-tree" => {
+                words.next();
+            }
+            flag if flag.starts_with('-') => {}
+            subcommand => {
+                name.push(subcommand);
+                if GIT_COMMAND_GROUPS.contains(&subcommand)
+                    && let Some(action) = words.find(|word| !word.starts_with('-'))
+                {
+                    name.push(action);
+                }
+                break;
+            }
+        }
+    }
+    name.join(" ")
+}
+
+fn format_duration(us: u64) -> String {
+    if us >= 1_000_000 {
+        format!("{:.1}s", us as f64 / 1_000_000.0)
+    } else {
+        format!("{}ms", us / 1000)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use worktrunk::perf::Span;
+
+    #[test]
+    fn test_operation_name() {
+        assert_eq!(operation_name("git status --porcelain"), "git status");
+        assert_eq!(
+            operation_name("git -C /tmp/repo -c core.quotepath=false diff --stat"),
+            "git diff"
+        );
+        assert_eq!(
+            operation_name("git worktree add --detach /tmp/x HEAD"),
+            "git worktree add"
+        );
+        assert_eq!(operation_name("/usr/bin/gh pr list"), "gh");
+    }
+
+    #[test]
+    fn test_summaries() {
+        let entry = |command: &str, dur_us, spans: Vec<(&str, u64)>| Entry {
+            ts: 0,
+            command: command.into(),
+            dur_us,
+            spans: spans
+                .into_iter()
+                .map(|(name, dur_us)| Span {
+                    kind: SpanKind::Subprocess,
+                    name: name.into(),
+                    dur_us,
+                })
+                .collect(),
+        };
+        let entries = vec![
+            entry(
+                "list",
+                300,
+                vec![("git status", 100), ("git status -s", 50)],
+            ),
+            entry("list", 100, vec![("git log -1", 20)]),
+            entry("switch", 200, vec![]),
+        ];
+
+        let commands = summarize_commands(&entries);
+        assert_eq!(commands[0].name, "list");
+        assert_eq!(commands[0].runs, 2);
+        assert_eq!(commands[0].median_us, 300);
+        assert_eq!(commands[0].slowest_us, 300);
+
+        let operations = summarize_operations(&entries);
+        assert_eq!(operations[0].name, "git status");
+        assert_eq!(operations[0].calls, 2);
+        assert_eq!(operations[0].total_us, 150);
+        assert_eq!(operations[1].name, "git log");
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(340_000), "340ms");
+        assert_eq!(format_duration(1_240_000), "1.2s");
+    }
+}
@@ -0,0 +1,184 @@
+//! External subcommands: `wt foo` runs `wt-foo` from `PATH`, like git does.
+//!
+//! Plugins get the repository context in environment variables so they don't
+//! have to rediscover it:
+//!
+//! | Variable | Value |
+//! |----------|-------|
+//! | `WORKTRUNK_BIN` | Path of the running `wt`, for calling back into it |
+//! | `WORKTRUNK_CONFIG_PATH` | User config file (also honors `--config`) |
+//! | `WORKTRUNK_WORKTREE` | Root of the current worktree |
+//! | `WORKTRUNK_PRIMARY_WORKTREE` | Root of the primary worktree |
+//! | `WORKTRUNK_BRANCH` | Current branch, unless HEAD is detached |
+//! | `WORKTRUNK_STATE` | JSON: default branch, git common dir, and worktrees |
+//!
+//! The repository variables are only set inside a repository. Built-in
+//! commands always win, so a plugin can't shadow `wt list`.
+
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::process::Command;
+
+use worktrunk::config::get_config_path;
+use worktrunk::git::Repository;
+use worktrunk::ops;
+
+use crate::GlobalArgs;
+
+/// An unknown subcommand and what surrounds it on the command line.
+#[derive(Debug, PartialEq)]
+struct Invocation {
+    /// Arguments after the subcommand
+    args: Vec<OsString>,
+    /// `-C <path>` before the subcommand
+    directory: Option<PathBuf>,
+    /// `--config <path>` before the subcommand
+    config: Option<PathBuf>,
+}
+
+/// The invocation of subcommand `name` in `argv` (program name first).
+fn parse_invocation(argv: &[OsString], name: &str) -> Option<Invocation> {
+    let globals = GlobalArgs::scan(argv);
+    let index = globals.subcommand.filter(|&i| argv[i] == *name)?;
+    Some(Invocation {
+        args: argv[index + 1..].to_vec(),
+        directory: globals.directory,
+        config: globals.config,
+    })
+}
+
+/// Executable implementing `wt <name>`, if one is on `PATH`.
+fn find_plugin(name: &str) -> Option<PathBuf> {
+    if name.starts_with('-') || name.contains(std::path::is_separator) {
+        return None;
+    }
+    which::which(format!("wt-{name}")).ok()
+}
+
+/// Run the plugin for the unknown subcommand `name`.
+///
+/// Returns `None` when there's no such plugin; otherwise doesn't return on
+/// Unix (the plugin replaces `wt`) and returns the plugin's exit code
+/// elsewhere.
+pub fn run_plugin(name: &str) -> Option<i32> {
+    let program = find_plugin(name)?;
+    let argv: Vec<OsString> = std::env::args_os().collect();
+    let invocation = parse_invocation(&argv, name)?;
+
+    let mut cmd = Command::new(&program);
+    cmd.args(&invocation.args);
+    if let Some(dir) = &invocation.directory {
+        cmd.current_dir(dir);
+    }
+    if let Ok(exe) = std::env::current_exe() {
+        cmd.env("WORKTRUNK_BIN", exe);
+    }
+    let config_path = invocation.config.or_else(get_config_path);
+    if let Some(path) = config_path {
+        cmd.env("WORKTRUNK_CONFIG_PATH", path);
+    }
+    let repo_dir = invocation.directory.unwrap_or_else(|| PathBuf::from("."));
+    if let Ok(repo) = Repository::at(repo_dir) {
+        for (key, value) in repo_env(&repo) {
+            cmd.env(key, value);
+        }
+    }
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        let err = cmd.exec();
+        worktrunk::styling::eprintln!(
+            "{}",
+            worktrunk::styling::error_message(format!(
+                "Failed to run {}: {err}",
+                program.display()
+            ))
+        );
+        Some(1)
+    }
+    #[cfg(not(unix))]
+    {
+        let status = cmd.status().ok()?;
+        Some(status.code().unwrap_or(1))
+    }
+}
+
+fn repo_env(repo: &Repository) -> Vec<(&'static str, OsString)> {
+    let mut env = Vec::new();
+    if let Ok(root) = repo.current_worktree().root() {
+        env.push(("WORKTRUNK_WORKTREE", root.into_os_string()));
+    }
+    if let Ok(Some(primary)) = repo.primary_worktree() {
+        env.push(("WORKTRUNK_PRIMARY_WORKTREE", primary.into_os_string()));
+    }
+    if let Ok(Some(branch)) = repo.current_worktree().branch() {
+        env.push(("WORKTRUNK_BRANCH", OsString::from(branch)));
+    }
+    let state = serde_json::json!({
+        "default_branch": repo.default_branch(),
+        "git_common_dir": repo.git_common_dir(),
+        "worktrees": ops::list_worktrees(repo).unwrap_or_default(),
+    });
+    env.push(("WORKTRUNK_STATE", OsString::from(state.to_string())));
+    env
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn argv(args: &[&str]) -> Vec<OsString> {
+        args.iter().map(OsString::from).collect()
+    }
+
+    #[test]
+    fn test_parse_invocation() {
+        assert_eq!(
+            parse_invocation(&argv(&["wt", "foo", "--bar", "baz"]), "foo"),
+            Some(Invocation {
+                args: argv(&["--bar", "baz"]),
+                directory: None,
+                config: None,
+            })
+        );
+        // A global option's value that happens to match isn't the subcommand
+        assert_eq!(
+            parse_invocation(
+                &argv(&["wt", "-C", "foo", "--config=c.toml", "foo", "x"]),
+                "foo"
+            ),
+            Some(Invocation {
+                args: argv(&["x"]),
+                directory: Some(PathBuf::from("foo")),
+                config: Some(PathBuf::from("c.toml")),
+            })
+        );
+        assert_eq!(
+            parse_invocation(&argv(&["wt", "--color", "never", "foo"]), "foo"),
+            Some(Invocation {
+                args: vec![],
+                directory: None,
+                config: None,
+            })
+        );
+        assert_eq!(
+            parse_invocation(&argv(&["wt", "--color", "foo"]), "foo"),
+            None
+        );
+        assert_eq!(
+            parse_invocation(&argv(&["wt", "-Crepo", "-v", "foo"]), "foo"),
+            Some(Invocation {
+                args: vec![],
+                directory: Some(PathBuf::from("repo")),
+                config: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_find_plugin_rejects_paths_and_flags() {
+        assert_eq!(find_plugin("../evil"), None);
+        assert_eq!(find_plugin("--help"), None);
+    }
+}
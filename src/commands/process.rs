@@ -9,6 +9,8 @@ use std::str::FromStr;
 use strum::IntoEnumIterator;
 use worktrunk::git::{HookType, Repository};
 use worktrunk::path::{format_path_for_display, sanitize_for_filename};
+use worktrunk::state::{BackgroundHookRun, StateStore};
+use worktrunk::utils::get_now;
 
 use crate::commands::hook_filter::HookSource;
 
@@ -206,6 +208,10 @@ pub fn spawn_detached(
 ) -> anyhow::Result<std::path::PathBuf> {
     // Create log directory in the common git directory
     let log_dir = repo.wt_logs_dir();
+    if worktrunk::dry_run::is_dry_run() {
+        worktrunk::dry_run::report(&format!("{command} &"), Some(worktree_path));
+        return Ok(hook_log.path(&log_dir, branch));
+    }
     fs::create_dir_all(&log_dir).with_context(|| {
         format!(
             "Failed to create log directory {}",
@@ -240,6 +246,19 @@ pub fn spawn_detached(
         spawn_detached_windows(worktree_path, command, log_file, context_json)?;
     }
 
+    // Best-effort record for `wt state`; the log file is the source of truth
+    let run = BackgroundHookRun {
+        branch: branch.to_string(),
+        hook: hook_log.to_spec(),
+        log: log_path.clone(),
+        started_at: get_now(),
+    };
+    if let Err(e) =
+        StateStore::new(repo.git_common_dir()).update(|state| state.record_background_hook(run))
+    {
+        log::debug!("Failed to record background hook: {e:#}");
+    }
+
     Ok(log_path)
 }
 
@@ -371,6 +390,9 @@ fn spawn_detached_windows(
 
 /// Build shell command for background worktree removal
 ///
+/// The removal itself runs through the hidden `wt remove-detached`, so it
+/// takes the operation lock and journals like a foreground removal.
+///
 /// `branch_to_delete` is the branch to delete after removing the worktree.
 /// Pass `None` for detached HEAD or when branch should be retained.
 /// This decision is computed upfront (checking if branch is merged) before spawning the background process.
@@ -397,12 +419,11 @@ pub fn build_remove_command(
     // significant complexity for marginal benefit.
     let delay = "sleep 1";
 
-    // Stop fsmonitor daemon first (best effort - ignore errors)
-    // This prevents zombie daemons from accumulating when using builtin fsmonitor
-    let stop_fsmonitor = format!(
-        "git -C {} fsmonitor--daemon stop 2>/dev/null || true",
-        worktree_escaped
-    );
+    // Call back into the running binary; fall back to `wt` on PATH
+    let wt = std::env::current_exe()
+        .map(|exe| exe.to_string_lossy().into_owned())
+        .unwrap_or_else(|_| "wt".to_string());
+    let wt_escaped = escape(wt.into());
 
     let force_flag = if force_worktree { " --force" } else { "" };
 
@@ -410,19 +431,45 @@ pub fn build_remove_command(
         Some(branch_name) => {
             let branch_escaped = escape(branch_name.into());
             format!(
-                "{} && {} && git worktree remove{} {} && git branch -D {}",
-                delay, stop_fsmonitor, force_flag, worktree_escaped, branch_escaped
+                "{} && {} remove-detached{} --delete-branch {} {}",
+                delay, wt_escaped, force_flag, branch_escaped, worktree_escaped
             )
         }
         None => {
             format!(
-                "{} && {} && git worktree remove{} {}",
-                delay, stop_fsmonitor, force_flag, worktree_escaped
+                "{} && {} remove-detached{} {}",
+                delay, wt_escaped, force_flag, worktree_escaped
             )
         }
     }
 }
 
+/// Handle the internal `wt remove-detached`
+///
+/// A forced removal discards uncommitted changes, so it snapshots them for
+/// `wt undo` first, as a foreground removal does.
+pub fn handle_remove_detached(
+    path: &Path,
+    delete_branch: Option<&str>,
+    force: bool,
+) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let worktree = repo.worktree_at(path);
+    let branch = worktree.branch().ok().flatten();
+    let snapshot = if force {
+        crate::commands::snapshot::auto_snapshot(&worktree, "remove")
+    } else {
+        None
+    };
+
+    repo.remove_worktree_journaled(path, branch.as_deref(), force, snapshot.as_deref(), || {
+        if let Some(branch) = delete_branch {
+            repo.run_command(&["branch", "-D", branch])?;
+        }
+        Ok(())
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -499,31 +546,29 @@ mod tests {
 
         // Without branch deletion, without force
         let cmd = build_remove_command(&path, None, false);
-        assert!(cmd.contains("git worktree remove"));
+        assert!(cmd.starts_with("sleep 1 && "));
+        assert!(cmd.contains("remove-detached"));
         assert!(cmd.contains("/tmp/test-worktree"));
-        assert!(!cmd.contains("branch -D"));
+        assert!(!cmd.contains("--delete-branch"));
         assert!(!cmd.contains("--force"));
 
         // With branch deletion, without force
         let cmd = build_remove_command(&path, Some("feature-branch"), false);
-        assert!(cmd.contains("git worktree remove"));
-        assert!(cmd.contains("git branch -D"));
-        assert!(cmd.contains("feature-branch"));
+        assert!(cmd.contains("remove-detached --delete-branch feature-branch"));
         assert!(!cmd.contains("--force"));
 
         // With force flag
         let cmd = build_remove_command(&path, None, true);
-        assert!(cmd.contains("git worktree remove --force"));
+        assert!(cmd.contains("remove-detached --force"));
 
         // With branch deletion and force
         let cmd = build_remove_command(&path, Some("feature-branch"), true);
-        assert!(cmd.contains("git worktree remove --force"));
-        assert!(cmd.contains("git branch -D"));
+        assert!(cmd.contains("remove-detached --force --delete-branch feature-branch"));
 
         // Shell escaping for special characters
         let special_path = PathBuf::from("/tmp/test worktree");
         let cmd = build_remove_command(&special_path, Some("feature/branch"), false);
-        assert!(cmd.contains("worktree remove"));
+        assert!(cmd.contains("'/tmp/test worktree'"));
     }
 
     #[test]
@@ -0,0 +1,306 @@
+//! Compact status for shell prompts and tmux status bars.
+//!
+//! `wt prompt` prints `branch* ⇡1⇣2 ●`: the branch, a dirty marker,
+//! commits ahead/behind upstream, and the last known CI status. Unlike
+//! `wt list statusline`, it never computes diffs against the default branch
+//! or queries the forge, so it stays fast enough to run on every prompt:
+//!
+//! - One `git status --porcelain=v2 --branch` supplies branch, dirty state,
+//!   and upstream counts.
+//! - CI comes from the cache `wt list` fills; a missing entry shows nothing.
+//! - The result is cached in the worktree's git dir, keyed on `HEAD` and the
+//!   index, for a few seconds — long enough to cover repeated prompt redraws.
+//!
+//! The git dir is found by walking up from the working directory, without
+//! running git, so outside a repository the command prints nothing quickly.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use anstyle::Style;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use worktrunk::shell_exec::Cmd;
+use worktrunk::utils::get_now;
+
+use super::list::ci_status::PrStatus;
+use crate::cli::PromptFormat;
+
+/// How long a cached prompt stays valid when `HEAD` and the index are unchanged.
+///
+/// Edits to tracked files don't touch the index, so this bounds how stale the
+/// dirty marker can be.
+const CACHE_TTL_SECS: u64 = 5;
+
+/// Status shown in the prompt.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct PromptState {
+    /// Branch name, or `None` for detached HEAD
+    branch: Option<String>,
+    /// Short commit SHA (shown when detached)
+    head: Option<String>,
+    /// Uncommitted changes to tracked files
+    dirty: bool,
+    /// Commits ahead of upstream
+    ahead: usize,
+    /// Commits behind upstream
+    behind: usize,
+    /// Last known CI status for HEAD
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ci: Option<PrStatus>,
+}
+
+/// Cache entry stored at `<git-dir>/wt-cache/prompt.json`.
+#[derive(Serialize, Deserialize)]
+struct CachedPrompt {
+    key: String,
+    checked_at: u64,
+    state: PromptState,
+}
+
+/// Git directories for the worktree containing `start`.
+struct GitDirs {
+    /// Per-worktree git dir (`.git` or `.git/worktrees/<name>`)
+    git_dir: PathBuf,
+    /// Shared git dir, where `wt list` caches CI status
+    common_dir: PathBuf,
+}
+
+/// Handle `wt prompt`.
+///
+/// Prints nothing (and succeeds) outside a git repository, so it's safe to
+/// call unconditionally from a prompt.
+pub fn handle_prompt(format: PromptFormat) -> Result<()> {
+    let start = worktrunk::git::base_path();
+    let Some(dirs) = find_git_dirs(start) else {
+        return Ok(());
+    };
+
+    let state = match read_cache(&dirs.git_dir) {
+        Some(state) => state,
+        None => {
+            let Some(state) = compute_state(start, &dirs) else {
+                return Ok(());
+            };
+            write_cache(&dirs.git_dir, &state);
+            state
+        }
+    };
+
+    // Raw stdout: prompts capture this via $(...), so anstream would strip colors
+    match format {
+        PromptFormat::Text if worktrunk::styling::color_never() => {
+            println!("{}", anstream::adapter::strip_str(&render(&state)))
+        }
+        PromptFormat::Text => println!("{}", render(&state)),
+        PromptFormat::Json => println!("{}", serde_json::to_string(&state)?),
+    }
+    Ok(())
+}
+
+/// Render `branch* ⇡1⇣2 ●`.
+fn render(state: &PromptState) -> String {
+    let bold = Style::new().bold();
+    let name = match (&state.branch, &state.head) {
+        (Some(branch), _) => branch.clone(),
+        (None, Some(head)) => format!("({head})"),
+        (None, None) => "HEAD".to_string(),
+    };
+    let mut parts = vec![format!("{bold}{name}{bold:#}")];
+    if state.dirty {
+        let cyan = Style::new().fg_color(Some(anstyle::AnsiColor::Cyan.into()));
+        parts[0].push_str(&format!("{cyan}*{cyan:#}"));
+    }
+
+    let mut counts = String::new();
+    if state.ahead > 0 {
+        counts.push_str(&format!("⇡{}", state.ahead));
+    }
+    if state.behind > 0 {
+        counts.push_str(&format!("⇣{}", state.behind));
+    }
+    if !counts.is_empty() {
+        let dim = Style::new().dimmed();
+        parts.push(format!("{dim}{counts}{dim:#}"));
+    }
+
+    if let Some(ci) = &state.ci {
+        parts.push(ci.format_indicator(false));
+    }
+    parts.join(" ")
+}
+
+/// Run `git status` once and parse branch, dirty state, and upstream counts.
+fn compute_state(start: &Path, dirs: &GitDirs) -> Option<PromptState> {
+    let output = Cmd::new("git")
+        .args([
+            "status",
+            "--porcelain=v2",
+            "--branch",
+            "--untracked-files=no",
+            "--ignore-submodules=dirty",
+        ])
+        .current_dir(start)
+        .run()
+        .ok()
+        .filter(|output| output.status.success())?;
+    let mut state = parse_porcelain_v2(&String::from_utf8_lossy(&output.stdout));
+
+    if let (Some(branch), Some(head)) = (&state.branch, &state.head) {
+        state.ci = PrStatus::cached(&dirs.common_dir, branch, head);
+    }
+    // Only the short form is displayed
+    if let Some(head) = &mut state.head {
+        head.truncate(7);
+    }
+    Some(state)
+}
+
+/// Parse `git status --porcelain=v2 --branch` output.
+fn parse_porcelain_v2(output: &str) -> PromptState {
+    let mut state = PromptState::default();
+    for line in output.lines() {
+        if let Some(header) = line.strip_prefix("# ") {
+            let (key, value) = header.split_once(' ').unwrap_or((header, ""));
+            match key {
+                "branch.oid" if value != "(initial)" => state.head = Some(value.to_string()),
+                "branch.head" if value != "(detached)" => state.branch = Some(value.to_string()),
+                "branch.ab" => {
+                    let mut counts = value.split(' ');
+                    let mut count = |prefix: char| {
+                        counts
+                            .next()
+                            .and_then(|c| c.strip_prefix(prefix))
+                            .and_then(|c| c.parse().ok())
+                            .unwrap_or(0)
+                    };
+                    state.ahead = count('+');
+                    state.behind = count('-');
+                }
+                _ => {}
+            }
+        } else if !line.is_empty() {
+            state.dirty = true;
+        }
+    }
+    state
+}
+
+/// Locate the git dirs by walking up from `start`, without running git.
+fn find_git_dirs(start: &Path) -> Option<GitDirs> {
+    let start = dunce::canonicalize(start).ok()?;
+    let dot_git = start
+        .ancestors()
+        .map(|dir| dir.join(".git"))
+        .find(|path| path.exists())?;
+
+    let git_dir = if dot_git.is_file() {
+        // Linked worktree: `.git` is a file containing `gitdir: <path>`
+        let contents = fs::read_to_string(&dot_git).ok()?;
+        let target = contents.trim().strip_prefix("gitdir:")?.trim();
+        dot_git.parent()?.join(target)
+    } else {
+        dot_git
+    };
+    let common_dir = fs::read_to_string(git_dir.join("commondir"))
+        .map(|dir| git_dir.join(dir.trim()))
+        .unwrap_or_else(|_| git_dir.clone());
+    Some(GitDirs {
+        git_dir,
+        common_dir,
+    })
+}
+
+/// Cache key: `HEAD` contents and index modification time.
+///
+/// Switching branches changes `HEAD`; committing and staging rewrite the index.
+fn cache_key(git_dir: &Path) -> Option<String> {
+    let head = fs::read_to_string(git_dir.join("HEAD")).ok()?;
+    let index_mtime = fs::metadata(git_dir.join("index"))
+        .and_then(|meta| meta.modified())
+        .ok()
+        .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+        .map_or(0, |d| d.as_nanos());
+    Some(format!("{}:{index_mtime}", head.trim()))
+}
+
+fn cache_path(git_dir: &Path) -> PathBuf {
+    git_dir.join("wt-cache").join("prompt.json")
+}
+
+fn read_cache(git_dir: &Path) -> Option<PromptState> {
+    let key = cache_key(git_dir)?;
+    let json = fs::read_to_string(cache_path(git_dir)).ok()?;
+    let cached: CachedPrompt = serde_json::from_str(&json).ok()?;
+    (cached.key == key && get_now().saturating_sub(cached.checked_at) < CACHE_TTL_SECS)
+        .then_some(cached.state)
+}
+
+/// Best-effort: a failed write just means the next prompt recomputes.
+fn write_cache(git_dir: &Path, state: &PromptState) {
+    let Some(key) = cache_key(git_dir) else {
+        return;
+    };
+    let cached = CachedPrompt {
+        key,
+        checked_at: get_now(),
+        state: state.clone(),
+    };
+    let path = cache_path(git_dir);
+    let Ok(json) = serde_json::to_string(&cached) else {
+        return;
+    };
+    if let Some(parent) = path.parent()
+        && fs::create_dir_all(parent).is_ok()
+    {
+        // Write then rename so concurrent prompts never read a partial file
+        let temp_path = path.with_extension("json.tmp");
+        if fs::write(&temp_path, json).is_ok() && fs::rename(&temp_path, &path).is_err() {
+            let _ = fs::remove_file(&temp_path);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ansi_str::AnsiStr;
+
+    #[test]
+    fn test_parse_porcelain_v2() {
+        let state = parse_porcelain_v2(
+            "# branch.oid 1234567890abcdef\n\
+             # branch.head feature\n\
+             # branch.upstream origin/feature\n\
+             # branch.ab +2 -1\n\
+             1 .M N... 100644 100644 100644 abc abc src/lib.rs\n",
+        );
+        assert_eq!(state.branch.as_deref(), Some("feature"));
+        assert_eq!(state.head.as_deref(), Some("1234567890abcdef"));
+        assert!(state.dirty);
+        assert_eq!((state.ahead, state.behind), (2, 1));
+
+        let detached = parse_porcelain_v2("# branch.oid abcdef1234\n# branch.head (detached)\n");
+        assert_eq!(detached.branch, None);
+        assert!(!detached.dirty);
+    }
+
+    #[test]
+    fn test_render() {
+        let state = PromptState {
+            branch: Some("feature".into()),
+            dirty: true,
+            ahead: 2,
+            behind: 1,
+            ..Default::default()
+        };
+        assert_eq!(render(&state).ansi_strip(), "feature* ⇡2⇣1");
+
+        let detached = PromptState {
+            head: Some("abcdef1".into()),
+            ..Default::default()
+        };
+        assert_eq!(render(&detached).ansi_strip(), "(abcdef1)");
+    }
+}
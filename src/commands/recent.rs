@@ -0,0 +1,50 @@
+//! `wt recent`: list visited worktrees, highest frecency first.
+
+use color_print::cformat;
+use worktrunk::git::{Repository, WorktreeInfo};
+use worktrunk::path::format_path_for_display;
+use worktrunk::styling::{eprintln, info_message, println};
+
+use super::frecency::Frecency;
+use crate::display::format_relative_time_short;
+
+/// Handle `wt recent [-n LIMIT]`.
+pub fn handle_recent(limit: Option<usize>) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let frecency = Frecency::load(repo.git_common_dir());
+
+    let worktrees = repo.list_worktrees()?;
+    let mut visited: Vec<(&WorktreeInfo, f64, u64)> = worktrees
+        .iter()
+        .filter(|wt| !wt.bare && !wt.is_prunable())
+        .filter_map(|wt| {
+            let last_visit = frecency.last_visit(&wt.path)?;
+            Some((wt, frecency.score(&wt.path), last_visit))
+        })
+        .collect();
+    if visited.is_empty() {
+        eprintln!(
+            "{}",
+            info_message(cformat!(
+                "No visits recorded yet; <bright-black>wt switch</> records them"
+            ))
+        );
+        return Ok(());
+    }
+    visited.sort_by(|a, b| b.1.total_cmp(&a.1).then(b.2.cmp(&a.2)));
+    visited.truncate(limit.unwrap_or(usize::MAX));
+
+    let name = |wt: &WorktreeInfo| wt.branch.clone().unwrap_or_else(|| "(detached)".into());
+    let width = visited
+        .iter()
+        .map(|(wt, ..)| name(wt).chars().count())
+        .max()
+        .unwrap_or_default();
+    for (wt, _, last_visit) in &visited {
+        let branch = format!("{:<width$}", name(wt));
+        let path = format_path_for_display(&wt.path);
+        let age = format_relative_time_short(*last_visit as i64);
+        println!("{}", cformat!("<bold>{branch}</>  {path}  <dim>{age}</>"));
+    }
+    Ok(())
+}
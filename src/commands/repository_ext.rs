@@ -10,7 +10,9 @@ use worktrunk::git::{
     GitError, IntegrationReason, Repository, parse_porcelain_z, parse_untracked_files,
 };
 use worktrunk::path::format_path_for_display;
-use worktrunk::styling::{eprintln, format_with_gutter, progress_message, warning_message};
+use worktrunk::styling::{
+    eprintln, format_with_gutter, info_message, progress_message, warning_message,
+};
 
 /// Target for worktree removal.
 #[derive(Debug)]
@@ -78,8 +80,7 @@ impl RepositoryCliExt for Repository {
     ) -> anyhow::Result<RemoveResult> {
         let current_path = self.current_worktree().root()?.to_path_buf();
         let worktrees = self.list_worktrees()?;
-        // Home worktree: prefer default branch's worktree, fall back to first worktree,
-        // then repo base for bare repos with no worktrees.
+        // Home worktree: the default branch's worktree, else the layout root for bare repos.
         let home_worktree_path = self.home_path()?;
 
         // Resolve target to worktree path and branch
@@ -90,15 +91,8 @@ impl RepositoryCliExt for Repository {
                     .find(|wt| wt.branch.as_deref() == Some(branch))
                 {
                     Some(wt) => {
-                        if !wt.path.exists() {
-                            // Directory missing - prune and continue
-                            self.prune_worktrees()?;
-                            return Ok(RemoveResult::BranchOnly {
-                                branch_name: branch.to_string(),
-                                deletion_mode,
-                                pruned: true,
-                            });
-                        }
+                        // Before the missing-directory check: `git worktree prune`
+                        // keeps locked entries, so the branch would go without them
                         if wt.locked.is_some() {
                             return Err(GitError::WorktreeLocked {
                                 branch: branch.into(),
@@ -107,6 +101,19 @@ impl RepositoryCliExt for Repository {
                             }
                             .into());
                         }
+                        if !wt.path.exists() {
+                            // Directory missing - prune and continue
+                            self.prune_worktrees()?;
+                            return Ok(RemoveResult::BranchOnly {
+                                branch_name: branch.to_string(),
+                                deletion_mode: protected_deletion_mode(
+                                    self,
+                                    Some(branch),
+                                    deletion_mode,
+                                )?,
+                                pruned: true,
+                            });
+                        }
                         let is_current = current_path == wt.path;
                         (wt.path.clone(), Some(branch.to_string()), is_current)
                     }
@@ -114,6 +121,15 @@ impl RepositoryCliExt for Repository {
                         // No worktree found - check if the branch exists locally
                         let branch_handle = self.branch(branch);
                         if branch_handle.exists_locally()? {
+                            // Removing only a branch deletes it; refuse outright
+                            if !deletion_mode.should_keep() && self.is_protected_branch(branch) {
+                                return Err(GitError::ProtectedBranch {
+                                    branch: branch.into(),
+                                    action: "delete".into(),
+                                    force_hint: false,
+                                }
+                                .into());
+                            }
                             return Ok(RemoveResult::BranchOnly {
                                 branch_name: branch.to_string(),
                                 deletion_mode,
@@ -172,9 +188,18 @@ impl RepositoryCliExt for Repository {
             target_wt.ensure_clean("remove worktree", branch_name.as_deref(), true)?;
         }
 
+        let deletion_mode = protected_deletion_mode(self, branch_name.as_deref(), deletion_mode)?;
+
         // Compute main_path and changed_directory based on whether we're removing current
         let (main_path, changed_directory) = if is_current {
-            (home_worktree_path, true)
+            // Bare layouts have no main checkout: when the home worktree is the one
+            // being removed, land in the directory holding the worktrees instead
+            let main_path = if home_worktree_path == worktree_path {
+                self.layout_root()
+            } else {
+                home_worktree_path
+            };
+            (main_path, true)
         } else {
             (current_path, false)
         };
@@ -337,6 +362,36 @@ impl RepositoryCliExt for Repository {
     }
 }
 
+/// Apply `[branches] protected` to a removal's branch deletion.
+///
+/// `-D` on a protected branch is refused. Otherwise the worktree is removed
+/// but a protected branch is kept, even when it's integrated.
+pub(crate) fn protected_deletion_mode(
+    repo: &Repository,
+    branch_name: Option<&str>,
+    deletion_mode: BranchDeletionMode,
+) -> anyhow::Result<BranchDeletionMode> {
+    let Some(branch) = branch_name else {
+        return Ok(deletion_mode);
+    };
+    if deletion_mode.should_keep() || !repo.is_protected_branch(branch) {
+        return Ok(deletion_mode);
+    }
+    if deletion_mode.is_force() {
+        return Err(GitError::ProtectedBranch {
+            branch: branch.into(),
+            action: "delete".into(),
+            force_hint: false,
+        }
+        .into());
+    }
+    eprintln!(
+        "{}",
+        info_message(cformat!("Keeping protected branch <bold>{branch}</>"))
+    );
+    Ok(BranchDeletionMode::Keep)
+}
+
 /// Compute integration reason for branch deletion.
 ///
 /// Returns `None` if:
@@ -0,0 +1,365 @@
+//! `wt resolve`: work through the conflicts of a stopped rebase.
+//!
+//! Lists the conflicted files, opens each in git's merge tool (`git mergetool`,
+//! which honors `merge.tool`, or `--tool`), and continues the rebase once every
+//! file is resolved. A rebase that stops again on a later commit starts the
+//! next round. When the rebase was started by `wt merge`, the command that
+//! finishes the merge is suggested at the end.
+//!
+//! With `--explain`, each file's conflicting hunks (both sides plus the base)
+//! go to the commit generation command first, and its summary and suggested
+//! resolution are printed before the merge tool opens. The suggestion is never
+//! applied; the merge tool is where the resolution happens.
+
+use std::process::Stdio;
+
+use anyhow::Context;
+use color_print::cformat;
+use worktrunk::config::{CommitGenerationConfig, UserConfig};
+use worktrunk::git::{GitError, Repository};
+use worktrunk::shell_exec::Cmd;
+use worktrunk::state::StateStore;
+use worktrunk::styling::{
+    eprintln, format_with_gutter, hint_message, info_message, progress_message, success_message,
+    warning_message,
+};
+
+use crate::llm::explain_conflict;
+
+/// Options for `wt resolve`
+pub struct ResolveOptions<'a> {
+    /// Print the conflicted files and exit
+    pub list: bool,
+    /// Merge tool overriding `merge.tool`
+    pub tool: Option<&'a str>,
+    /// Run `git rebase --continue` once everything is resolved
+    pub continue_rebase: bool,
+    /// Explain each conflict with the configured LLM before resolving it
+    pub explain: bool,
+}
+
+/// Lines of conflict hunks sent to the LLM per file.
+const MAX_EXPLAIN_LINES: usize = 400;
+
+/// Remember the command that hit a rebase conflict on `branch`.
+///
+/// Best-effort, like the undo journal: failing to record never fails the
+/// command itself.
+pub(crate) fn record_paused(repo: &Repository, branch: &str) {
+    let command = std::iter::once("wt".to_string())
+        .chain(
+            std::env::args()
+                .skip(1)
+                .map(|arg| shell_escape::escape(arg.into()).into_owned()),
+        )
+        .collect::<Vec<_>>()
+        .join(" ");
+    let result = StateStore::new(repo.git_common_dir()).update(|state| {
+        state.paused.insert(branch.to_string(), command);
+    });
+    if let Err(e) = result {
+        log::debug!("Failed to record paused command: {e:#}");
+    }
+}
+
+/// Files with unresolved conflicts, relative to the worktree root.
+fn conflicted_files(repo: &Repository) -> anyhow::Result<Vec<String>> {
+    let output =
+        repo.current_worktree()
+            .run_command(&["diff", "--name-only", "--diff-filter=U"])?;
+    Ok(output.lines().map(str::to_string).collect())
+}
+
+/// Subject of the commit the rebase stopped on.
+fn stopped_at(repo: &Repository) -> Option<String> {
+    repo.current_worktree()
+        .run_command(&["log", "-1", "--format=%h %s", "REBASE_HEAD"])
+        .ok()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+}
+
+/// The conflicted regions of diff3-style merge output, marker lines included.
+fn extract_hunks(merged: &str) -> String {
+    let mut hunks = Vec::new();
+    let mut current: Option<Vec<&str>> = None;
+    for line in merged.lines() {
+        if line.starts_with("<<<<<<<") {
+            current = Some(Vec::new());
+        }
+        if let Some(hunk) = current.as_mut() {
+            hunk.push(line);
+            if line.starts_with(">>>>>>>") {
+                hunks.push(current.take().unwrap().join("\n"));
+            }
+        }
+    }
+    hunks.join("\n\n")
+}
+
+/// Conflicting hunks of `file` with the base side included, regardless of
+/// `merge.conflictStyle` or edits already made to the working file.
+///
+/// Returns `None` when a side is missing (modify/delete conflicts).
+fn conflict_hunks(
+    repo: &Repository,
+    file: &str,
+    upstream: &str,
+    commit: &str,
+) -> anyhow::Result<Option<String>> {
+    let worktree = repo.current_worktree();
+    let stage = |n: u8| {
+        worktree
+            .run_command(&["show", &format!(":{n}:{file}")])
+            .ok()
+    };
+    let (Some(ours), Some(theirs)) = (stage(2), stage(3)) else {
+        return Ok(None);
+    };
+    let base = stage(1).unwrap_or_default();
+
+    let scratch = worktree.git_dir()?.join("worktrunk-resolve");
+    std::fs::create_dir_all(&scratch)?;
+    let paths = [
+        scratch.join("upstream"),
+        scratch.join("base"),
+        scratch.join("commit"),
+    ];
+    for (path, content) in paths.iter().zip([&ours, &base, &theirs]) {
+        std::fs::write(path, content)?;
+    }
+    // Exits with the number of conflicts, so the status isn't an error
+    let output = Cmd::new("git")
+        .args(["merge-file", "-p", "--diff3"])
+        .args(["-L", upstream, "-L", "base", "-L", commit])
+        .args(paths.iter().map(|p| p.to_string_lossy().into_owned()))
+        .run();
+    let _ = std::fs::remove_dir_all(&scratch);
+    let output = output.context("Failed to run git merge-file")?;
+
+    let hunks = extract_hunks(&String::from_utf8_lossy(&output.stdout));
+    let mut lines: Vec<&str> = hunks.lines().collect();
+    if lines.len() > MAX_EXPLAIN_LINES {
+        lines.truncate(MAX_EXPLAIN_LINES);
+        lines.push("[... truncated]");
+    }
+    Ok(Some(lines.join("\n")))
+}
+
+/// Print the LLM's explanation of the conflicts in `file`.
+fn explain_file(
+    repo: &Repository,
+    generation: &CommitGenerationConfig,
+    file: &str,
+) -> anyhow::Result<()> {
+    let upstream = repo
+        .current_worktree()
+        .run_command(&["rev-parse", "--short", "HEAD"])
+        .map(|s| s.trim().to_string())
+        .unwrap_or_else(|_| "upstream".into());
+    let commit = stopped_at(repo).unwrap_or_else(|| "commit".into());
+    let Some(hunks) = conflict_hunks(repo, file, &upstream, &commit)? else {
+        eprintln!(
+            "{}",
+            info_message(cformat!(
+                "<bold>{file}</> was deleted on one side; nothing to explain"
+            ))
+        );
+        return Ok(());
+    };
+    eprintln!(
+        "{}",
+        progress_message(cformat!("Explaining conflicts in <bold>{file}</>..."))
+    );
+    let explanation = explain_conflict(generation, file, &upstream, &commit, &hunks)?;
+    eprintln!("{}", format_with_gutter(&explanation, None));
+    Ok(())
+}
+
+/// Handle `wt resolve`.
+pub fn handle_resolve(opts: ResolveOptions<'_>) -> anyhow::Result<()> {
+    let ResolveOptions {
+        list,
+        tool,
+        continue_rebase,
+        explain,
+    } = opts;
+    let repo = Repository::current()?;
+    let worktree = repo.current_worktree();
+    if !worktree.is_rebasing()? {
+        return Err(GitError::Other {
+            message: "No rebase in progress; wt resolve works through rebase conflicts".into(),
+        }
+        .into());
+    }
+    let root = worktree.root()?;
+    let generation = if explain {
+        let config = UserConfig::load().context("Failed to load config")?;
+        let generation = config.commit_generation(repo.project_identifier().ok().as_deref());
+        if !generation.is_configured() {
+            return Err(GitError::Other {
+                message: cformat!(
+                    "<bright-black>--explain</> uses the commit generation command; configure <bright-black>[commit.generation]</> first"
+                ),
+            }
+            .into());
+        }
+        Some(generation)
+    } else {
+        None
+    };
+
+    if list {
+        for file in conflicted_files(&repo)? {
+            println!("{file}");
+        }
+        return Ok(());
+    }
+
+    loop {
+        let conflicted = conflicted_files(&repo)?;
+        let total = conflicted.len();
+        if total > 0 {
+            let at = stopped_at(&repo)
+                .map(|commit| cformat!(" applying <bold>{commit}</>"))
+                .unwrap_or_default();
+            eprintln!(
+                "{}",
+                info_message(cformat!(
+                    "{total} conflicted file{}{at}",
+                    if total == 1 { "" } else { "s" }
+                ))
+            );
+            eprintln!("{}", format_with_gutter(&conflicted.join("\n"), None));
+        }
+
+        for (done, file) in conflicted.iter().enumerate() {
+            if let Some(generation) = &generation {
+                explain_file(&repo, generation, file)?;
+            }
+            eprintln!(
+                "{}",
+                progress_message(cformat!("Opening <bold>{file}</> in merge tool..."))
+            );
+            let mut cmd = Cmd::new("git")
+                .args(["mergetool", "--no-prompt"])
+                .current_dir(&root)
+                .stdin(Stdio::inherit());
+            if let Some(tool) = tool {
+                cmd = cmd.arg(format!("--tool={tool}"));
+            }
+            // A tool that exits non-zero leaves the file conflicted; checked below
+            let _ = cmd.args(["--", file.as_str()]).stream();
+
+            if conflicted_files(&repo)?.contains(file) {
+                eprintln!(
+                    "{}",
+                    warning_message(cformat!(
+                        "<bold>{file}</> is still conflicted ({done}/{total} resolved)"
+                    ))
+                );
+                eprintln!(
+                    "{}",
+                    hint_message(cformat!(
+                        "Resolve it and <bright-black>git add</> it, then run <bright-black>wt resolve</> again"
+                    ))
+                );
+                return Ok(());
+            }
+            eprintln!(
+                "{}",
+                success_message(cformat!(
+                    "Resolved <bold>{file}</> ({}/{total} resolved)",
+                    done + 1
+                ))
+            );
+        }
+
+        if !continue_rebase {
+            eprintln!(
+                "{}",
+                hint_message(cformat!(
+                    "All conflicts resolved; run <bright-black>git rebase --continue</> when ready"
+                ))
+            );
+            return Ok(());
+        }
+
+        eprintln!("{}", progress_message("Continuing rebase..."));
+        // Keep the original messages rather than opening an editor per commit
+        let output = Cmd::new("git")
+            .args(["rebase", "--continue"])
+            .current_dir(&root)
+            .env("GIT_EDITOR", "true")
+            .run()
+            .context("Failed to run git rebase --continue")?;
+
+        if !worktree.is_rebasing()? {
+            break;
+        }
+        if conflicted_files(&repo)?.is_empty() {
+            // Stopped for something other than a conflict (e.g. an `edit` step)
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(GitError::Other {
+                message: cformat!(
+                    "Rebase stopped without conflicts:\n{}",
+                    format_with_gutter(stderr.trim(), None)
+                ),
+            }
+            .into());
+        }
+        eprintln!("{}", info_message("Rebase stopped again on a later commit"));
+    }
+
+    eprintln!("{}", success_message("Rebase complete"));
+
+    let Some(branch) = worktree.branch()? else {
+        return Ok(());
+    };
+    let paused = StateStore::new(repo.git_common_dir())
+        .update(|state| state.paused.remove(&branch))
+        .ok()
+        .flatten();
+    if let Some(command) = paused {
+        eprintln!(
+            "{}",
+            hint_message(cformat!(
+                "To finish the interrupted merge, run <bright-black>{command}</>"
+            ))
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_hunks() {
+        let merged = "\
+fn main() {
+<<<<<<< abc1234
+    greet(\"hello\");
+||||||| base
+    greet(\"hi\");
+=======
+    greet(\"hi\", name);
+>>>>>>> def5678 Add name
+}
+unchanged
+<<<<<<< abc1234
+a
+||||||| base
+=======
+b
+>>>>>>> def5678 Add name
+";
+        let hunks = extract_hunks(merged);
+        assert!(hunks.starts_with("<<<<<<< abc1234\n    greet(\"hello\");"));
+        assert!(!hunks.contains("fn main"));
+        assert!(!hunks.contains("unchanged"));
+        assert_eq!(hunks.matches(">>>>>>>").count(), 2);
+        assert_eq!(extract_hunks("no conflicts\n"), "");
+    }
+}
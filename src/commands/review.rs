@@ -0,0 +1,233 @@
+//! Review command handler.
+//!
+//! `wt review <number>` checks out a teammate's PR/MR for local review:
+//! either on its branch, through the same path as `wt switch pr:N`, or
+//! detached at the PR/MR head. Review worktrees are recorded in git config
+//! (`worktrunk.review.<pr-N|mr-N>.*`) so `wt review done` knows what to
+//! clean up — including a branch the review created, which is deleted even
+//! though it isn't merged locally.
+
+use anyhow::Context;
+use color_print::cformat;
+use worktrunk::HookType;
+use worktrunk::config::UserConfig;
+use worktrunk::git::remote_ref::{
+    GitHubProvider, GitLabProvider, RemoteRefInfo, RemoteRefProvider, local_branch_name,
+};
+use worktrunk::git::{GitError, Repository};
+use worktrunk::path::format_path_for_display;
+use worktrunk::styling::{
+    eprintln, format_with_gutter, info_message, progress_message, success_message,
+};
+
+use super::command_approval::approve_hooks;
+use super::context::CommandEnv;
+use super::forge::detect_platform;
+use super::handle_switch::{SwitchOptions, handle_switch};
+use super::list::ci_status::CiPlatform;
+use super::worktree::{
+    base_remote, compute_worktree_path, fetch_options, format_ref_context, handle_remove_current,
+    paths_match,
+};
+use crate::output::{change_directory, handle_remove_output};
+
+/// Options for the review command
+pub struct ReviewOptions {
+    pub number: u32,
+    /// Check out the PR/MR head detached instead of on its branch
+    pub detach: bool,
+    pub yes: bool,
+    pub verify: bool,
+}
+
+/// Short name for a PR/MR (`pr-42`, `mr-7`): the config subsection and the
+/// worktree name of a detached review.
+fn review_label(info: &RemoteRefInfo) -> String {
+    format!("{}-{}", info.ref_type.name().to_lowercase(), info.number)
+}
+
+/// Handle `wt review <number>`.
+pub fn handle_review(
+    opts: ReviewOptions,
+    config: &mut UserConfig,
+    binary_name: &str,
+) -> anyhow::Result<()> {
+    let ReviewOptions {
+        number,
+        detach,
+        yes,
+        verify,
+    } = opts;
+
+    let repo = Repository::current().context("Failed to check out for review")?;
+    let provider: &dyn RemoteRefProvider = match detect_platform(&repo)? {
+        CiPlatform::GitHub => &GitHubProvider,
+        CiPlatform::GitLab => &GitLabProvider,
+    };
+    let ref_type = provider.ref_type();
+
+    // `None` when the review reused a worktree it didn't create; `wt review
+    // done` leaves those alone
+    let (info, created) = if detach {
+        eprintln!(
+            "{}",
+            progress_message(cformat!("Fetching {}...", ref_type.display(number)))
+        );
+        let info = provider.fetch_info(number, repo.repo_path())?;
+        eprintln!("{}", format_with_gutter(&format_ref_context(&info), None));
+        let path = add_detached(&repo, config, provider, &info)?;
+        (info, Some((path, None)))
+    } else {
+        // `wt switch pr:N` fetches the same info again and shows it; this
+        // lookup only tells us whether the review creates the branch
+        let info = provider.fetch_info(number, repo.repo_path())?;
+        let branch = local_branch_name(&info);
+        let existing = repo.worktree_for_branch(&branch)?;
+        let created_branch = !repo.branch(&branch).exists_locally()?;
+        handle_switch(
+            SwitchOptions {
+                branch: &format!("{}{number}", ref_type.syntax()),
+                create: false,
+                base: None,
+                sparse: None,
+                orphan: false,
+                devcontainer: false,
+                detach: false,
+                execute: None,
+                execute_args: &[],
+                open: None,
+                yes,
+                clobber: false,
+                change_dir: true,
+                verify,
+            },
+            config,
+            binary_name,
+        )?;
+        let path = repo
+            .worktree_for_branch(&branch)?
+            .ok_or_else(|| GitError::WorktreeMissing {
+                branch: branch.clone(),
+            })?;
+        let created = existing
+            .is_none()
+            .then(|| (path, created_branch.then_some(branch)));
+        (info, created)
+    };
+
+    if let Some((path, branch)) = created {
+        let key = format!("worktrunk.review.{}", review_label(&info));
+        repo.set_config(&format!("{key}.path"), &path.to_string_lossy())?;
+        if let Some(branch) = branch {
+            repo.set_config(&format!("{key}.branch"), &branch)?;
+        }
+    }
+
+    let body = info.body.trim();
+    if !body.is_empty() {
+        eprintln!("{}", format_with_gutter(body, None));
+    }
+    Ok(())
+}
+
+/// Fetch the PR/MR head and add a detached worktree for it.
+fn add_detached(
+    repo: &Repository,
+    config: &UserConfig,
+    provider: &dyn RemoteRefProvider,
+    info: &RemoteRefInfo,
+) -> anyhow::Result<std::path::PathBuf> {
+    let label = info.ref_type.display(info.number);
+    let path = compute_worktree_path(repo, &review_label(info), config)?;
+    if path.exists() {
+        return Err(GitError::Other {
+            message: cformat!(
+                "{} already exists; finish the previous review with <bright-black>wt review done</>",
+                format_path_for_display(&path)
+            ),
+        }
+        .into());
+    }
+
+    let remote = base_remote(repo, info)?;
+    eprintln!(
+        "{}",
+        progress_message(cformat!("Fetching <bold>{label}</> from {remote}..."))
+    );
+    let fetch = config
+        .fetch(repo.project_identifier().ok().as_deref())
+        .unwrap_or_default();
+    let ref_path = provider.ref_path(info.number);
+    let mut args = vec!["fetch".to_string()];
+    args.extend(fetch_options(repo, &fetch, &remote));
+    args.extend(["--".to_string(), remote.clone(), ref_path]);
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    repo.run_remote_command(&args)
+        .with_context(|| format!("Failed to fetch {label} from {remote}"))?;
+
+    let path_str = path.to_string_lossy();
+    repo.run_command(&[
+        "worktree",
+        "add",
+        "--detach",
+        "--",
+        path_str.as_ref(),
+        "FETCH_HEAD",
+    ])
+    .with_context(|| format!("Failed to create worktree for {label}"))?;
+    eprintln!(
+        "{}",
+        success_message(cformat!(
+            "Created detached worktree for <bold>{label}</> @ <bold>{}</>",
+            format_path_for_display(&path)
+        ))
+    );
+    change_directory(&path)?;
+    Ok(path)
+}
+
+/// Handle `wt review done`: remove the current review worktree.
+pub fn handle_review_done(yes: bool, verify: bool, config: &UserConfig) -> anyhow::Result<()> {
+    let repo = Repository::current().context("Failed to finish review")?;
+    let root = repo.current_worktree().root()?;
+
+    let recorded = repo
+        .run_command(&["config", "--get-regexp", r"^worktrunk\.review\..+\.path$"])
+        .unwrap_or_default();
+    let key = recorded
+        .lines()
+        .filter_map(|line| line.split_once(' '))
+        .find(|(_, path)| paths_match(std::path::Path::new(path), &root))
+        .and_then(|(key, _)| key.strip_suffix(".path"))
+        .map(str::to_string)
+        .ok_or_else(|| GitError::Other {
+            message: cformat!("Not a review worktree; remove it with <bright-black>wt remove</>"),
+        })?;
+
+    // A branch the review created holds a copy of someone else's commits:
+    // safe to delete even though it was never merged here. Branches that
+    // existed before the review are kept.
+    let created_branch = repo.get_config(&format!("{key}.branch"))?;
+    let result = handle_remove_current(created_branch.is_none(), true, false, config)
+        .context("Failed to remove review worktree")?;
+    // Subsection may already be gone (cleaned up by hand); that's fine
+    let _ = repo.run_command(&["config", "--remove-section", &key]);
+
+    let run_hooks = verify && {
+        let env = CommandEnv::for_action_branchless()?;
+        let ctx = env.context(yes);
+        let approved = approve_hooks(
+            &ctx,
+            &[
+                HookType::PreRemove,
+                HookType::PostRemove,
+                HookType::PostSwitch,
+            ],
+        )?;
+        if !approved {
+            eprintln!("{}", info_message("Commands declined, continuing removal"));
+        }
+        approved
+    };
+    handle_remove_output(&result, true, run_hooks)
+}
@@ -9,7 +9,7 @@ use ansi_str::AnsiStr;
 use unicode_width::UnicodeWidthStr;
 use worktrunk::git::{Repository, parse_numstat_line};
 use worktrunk::shell_exec::Cmd;
-use worktrunk::styling::{ADDITION, DELETION};
+use worktrunk::styling::theme;
 
 use crate::display::format_relative_time_short;
 
@@ -266,8 +266,8 @@ where
         // Use the same diff formatting as wt list (aligned columns)
         let diff_config = DiffDisplayConfig {
             variant: DiffVariant::Signs,
-            positive_style: ADDITION,
-            negative_style: DELETION,
+            positive_style: theme().addition,
+            negative_style: theme().deletion,
             always_show_zeros: false,
         };
         let stat_str = format!(" {}", diff_config.format_aligned(insertions, deletions));
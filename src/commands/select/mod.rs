@@ -7,15 +7,16 @@ mod log_formatter;
 mod pager;
 mod preview;
 
-use std::io::IsTerminal;
 use std::sync::Arc;
 
 use anyhow::Context;
+use color_print::cformat;
 use dashmap::DashMap;
 use skim::prelude::*;
 use worktrunk::config::UserConfig;
 use worktrunk::git::Repository;
 
+use super::frecency::Frecency;
 use super::handle_switch::{
     approve_switch_hooks, spawn_switch_background_hooks, switch_extra_vars,
 };
@@ -24,6 +25,7 @@ use super::worktree::{
     SwitchBranchInfo, SwitchResult, execute_switch, get_path_mismatch, plan_switch,
 };
 use crate::output::handle_switch_output;
+use crate::output::prompt::require_input;
 
 use items::{HeaderSkimItem, PreviewCache, WorktreeSkimItem};
 use preview::{PreviewLayout, PreviewMode, PreviewState};
@@ -34,9 +36,10 @@ pub fn handle_select(
     config: &UserConfig,
 ) -> anyhow::Result<()> {
     // Interactive picker requires a terminal for the TUI
-    if !std::io::stdin().is_terminal() {
-        anyhow::bail!("Interactive picker requires an interactive terminal");
-    }
+    require_input(
+        "Select a worktree",
+        cformat!("Pass a branch: <bright-black>wt switch {}</>", "<branch>"),
+    )?;
 
     let repo = Repository::current()?;
 
@@ -49,6 +52,9 @@ pub fn handle_select(
         collect::TaskKind::BranchDiff,
         collect::TaskKind::CiStatus,
         collect::TaskKind::MergeTreeConflicts,
+        collect::TaskKind::DiskUsage,
+        collect::TaskKind::Health,
+        collect::TaskKind::Ticket,
     ]
     .into_iter()
     .collect();
@@ -59,13 +65,15 @@ pub fn handle_select(
     // Operations that timeout fail silently (data not shown), but TUI stays responsive.
     let command_timeout = Some(std::time::Duration::from_millis(500));
 
-    let Some(list_data) = collect::collect(
+    let Some(mut list_data) = collect::collect(
         &repo,
         show_branches,
         show_remotes,
         &skip_tasks,
+        None,  // columns (select uses its own layout)
         false, // show_progress (no progress bars)
         false, // render_table (select renders its own UI)
+        None,  // group_by (select renders its own UI)
         config,
         command_timeout,
         true, // skip_expensive_for_stale (faster for repos with many stale branches)
@@ -74,6 +82,17 @@ pub fn handle_select(
         return Ok(());
     };
 
+    // Most-used worktrees first; branches without worktrees stay at the end.
+    // Stable sort keeps the `wt list` order among unvisited worktrees.
+    let frecency = Frecency::load(repo.git_common_dir());
+    list_data.items.sort_by(|a, b| {
+        let score = |item: &super::list::model::ListItem| {
+            item.worktree_path()
+                .map_or(f64::NEG_INFINITY, |path| frecency.score(path))
+        };
+        score(b).total_cmp(&score(a))
+    });
+
     // Use the same layout system as `wt list` for proper column alignment
     // List width depends on preview position:
     // - Right layout: skim splits ~50% for list, ~50% for preview
@@ -83,10 +102,15 @@ pub fn handle_select(
         PreviewLayout::Right => terminal_width / 2,
         PreviewLayout::Down => terminal_width,
     };
+    let project_id = repo.project_identifier().ok();
+    let width_limits =
+        super::list::columns::configured_width_limits(config, project_id.as_deref())?;
     let layout = super::list::layout::calculate_layout_with_width(
         &list_data.items,
         &skip_tasks,
+        None,
         skim_list_width,
+        &width_limits,
         &list_data.main_worktree_path,
         None, // URL column not shown in select
     );
@@ -279,6 +303,7 @@ pub fn handle_select(
         let plan = plan_switch(&repo, &identifier, should_create, None, false, &config)?;
         let skip_hooks = !approve_switch_hooks(&repo, &config, &plan, false, true)?;
         let (result, branch_info) = execute_switch(&repo, plan, &config, false, skip_hooks)?;
+        super::frecency::record_visit(repo.git_common_dir(), result.path());
 
         // Compute path mismatch lazily (deferred from plan_switch for existing worktrees)
         let branch_info = match &result {
@@ -0,0 +1,470 @@
+//! `wt snapshot` — work-in-progress checkpoints for a worktree.
+//!
+//! A snapshot is a stash commit, as `git stash push --include-untracked`
+//! would make it: the working tree's tracked changes, with the index as its
+//! second parent and untracked files as its third, so staged, unstaged and
+//! untracked changes all come back as they were. Creating one touches
+//! neither the index nor `HEAD`; untracked files are collected in a scratch
+//! index. Snapshots are kept as numbered refs under
+//! `refs/wt-snapshot/<branch>/`, outside the stash list and safe from gc,
+//! and numbered per branch from 1.
+//!
+//! Rebase, squash and forced removal take one automatically first (unless
+//! `[snapshot] auto = false`) and journal it, so `wt undo` restores
+//! uncommitted work along with the branch.
+
+use anyhow::Context;
+use color_print::cformat;
+use worktrunk::config::UserConfig;
+use worktrunk::git::{GitError, Repository, WorkingTree};
+use worktrunk::shell_exec::Cmd;
+use worktrunk::styling::{
+    eprintln, hint_message, info_message, println, success_message, warning_message,
+};
+
+use crate::display::format_relative_time_short;
+
+/// A recorded snapshot.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Snapshot {
+    pub id: u32,
+    pub sha: String,
+    /// Commit time, in seconds since the epoch
+    pub at: i64,
+    pub message: String,
+}
+
+/// Ref namespace for `branch`'s snapshots, keeping the branch's own path so
+/// distinct branches never share one. `feature`'s listing also sees
+/// `feature/x`'s refs; their ids aren't numbers, so they're skipped.
+fn ref_prefix(branch: &str) -> String {
+    format!("refs/wt-snapshot/{branch}")
+}
+
+/// The worktree's branch; snapshots are kept per branch.
+fn current_branch(worktree: &WorkingTree) -> anyhow::Result<String> {
+    Ok(worktree.branch()?.ok_or(GitError::DetachedHead {
+        action: Some("snapshot".into()),
+    })?)
+}
+
+/// The stash commit's subject without git's `On <branch>: ` prefix.
+fn strip_subject<'a>(subject: &'a str, branch: &str) -> &'a str {
+    subject
+        .strip_prefix(&format!("On {branch}: "))
+        .unwrap_or(subject)
+}
+
+/// Parse `for-each-ref` output (`refname NUL sha NUL time NUL subject`).
+fn parse_snapshots(output: &str, prefix: &str, branch: &str) -> Vec<Snapshot> {
+    let mut snapshots: Vec<Snapshot> = output
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split('\0');
+            let id = fields.next()?.strip_prefix(prefix)?.strip_prefix('/')?;
+            Some(Snapshot {
+                id: id.parse().ok()?,
+                sha: fields.next()?.to_string(),
+                at: fields.next()?.parse().ok()?,
+                message: strip_subject(fields.next().unwrap_or_default(), branch).to_string(),
+            })
+        })
+        .collect();
+    snapshots.sort_by(|a, b| b.id.cmp(&a.id));
+    snapshots
+}
+
+/// `branch`'s snapshots, newest first.
+pub(crate) fn list_snapshots(repo: &Repository, branch: &str) -> anyhow::Result<Vec<Snapshot>> {
+    let prefix = ref_prefix(branch);
+    let output = repo.run_command(&[
+        "for-each-ref",
+        "--format=%(refname)%00%(objectname)%00%(creatordate:unix)%00%(subject)",
+        &prefix,
+    ])?;
+    Ok(parse_snapshots(&output, &prefix, branch))
+}
+
+/// Run git in `worktree` against the scratch index at `index`.
+fn git_in_index(
+    worktree: &WorkingTree,
+    index: &std::path::Path,
+    args: &[&str],
+    stdin: Option<&str>,
+) -> anyhow::Result<String> {
+    let mut cmd = Cmd::new("git")
+        .args(args.iter().copied())
+        .current_dir(worktree.root()?)
+        .env("GIT_INDEX_FILE", index.to_string_lossy());
+    if let Some(stdin) = stdin {
+        cmd = cmd.stdin_bytes(stdin);
+    }
+    let output = cmd
+        .run()
+        .with_context(|| format!("Failed to run git {}", args.join(" ")))?;
+    if !output.status.success() {
+        return Err(GitError::Other {
+            message: format!(
+                "git {} failed: {}",
+                args.first().unwrap_or(&""),
+                String::from_utf8_lossy(&output.stderr).trim()
+            ),
+        }
+        .into());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Add `untracked` (NUL-separated paths) to the stash commit `stash` as its
+/// third parent. With no `stash` (no tracked changes), the stash is built
+/// on `HEAD` with an unchanged index.
+fn with_untracked(
+    worktree: &WorkingTree,
+    branch: &str,
+    stash: Option<&str>,
+    untracked: &str,
+    message: Option<&str>,
+) -> anyhow::Result<String> {
+    let run = |args: &[&str]| -> anyhow::Result<String> {
+        Ok(worktree.run_command(args)?.trim().to_string())
+    };
+
+    let scratch = worktree.git_dir()?.join("worktrunk-snapshot-index");
+    let _ = std::fs::remove_file(&scratch);
+    let tree = (|| {
+        git_in_index(
+            worktree,
+            &scratch,
+            &["update-index", "--add", "-z", "--stdin"],
+            Some(untracked),
+        )?;
+        git_in_index(worktree, &scratch, &["write-tree"], None)
+    })();
+    let _ = std::fs::remove_file(&scratch);
+    let untracked_commit = run(&[
+        "commit-tree",
+        &tree?,
+        "-m",
+        &format!("untracked files on {branch}"),
+    ])?;
+
+    let (head, index, work_tree, subject) = match stash {
+        Some(stash) => (
+            run(&["rev-parse", &format!("{stash}^1")])?,
+            run(&["rev-parse", &format!("{stash}^2")])?,
+            format!("{stash}^{{tree}}"),
+            run(&["log", "-1", "--format=%s", stash])?,
+        ),
+        None => {
+            let head = run(&["rev-parse", "HEAD"])?;
+            let index = run(&[
+                "commit-tree",
+                "HEAD^{tree}",
+                "-p",
+                &head,
+                "-m",
+                &format!("index on {branch}"),
+            ])?;
+            let subject = match message {
+                Some(message) => format!("On {branch}: {message}"),
+                None => format!("WIP on {branch}"),
+            };
+            (head, index, "HEAD^{tree}".to_string(), subject)
+        }
+    };
+    run(&[
+        "commit-tree",
+        &work_tree,
+        "-p",
+        &head,
+        "-p",
+        &index,
+        "-p",
+        &untracked_commit,
+        "-m",
+        &subject,
+    ])
+}
+
+/// Commit the worktree's staged, unstaged and untracked changes in stash
+/// format, without keeping a ref to the commit.
+///
+/// Returns `None` when there's nothing to record.
+pub(crate) fn snapshot_commit(
+    worktree: &WorkingTree,
+    branch: &str,
+    message: Option<&str>,
+) -> anyhow::Result<Option<String>> {
+    // Refresh stat info first: with files that are only touched, older git's
+    // `stash create` exits 1 without recording anything
+    let _ = worktree.run_command(&["update-index", "-q", "--refresh"]);
+    let mut args = vec!["stash", "create"];
+    args.extend(message);
+    let stash = worktree.run_command(&args)?.trim().to_string();
+    let stash = (!stash.is_empty()).then_some(stash);
+    let untracked = worktree.run_command(&["ls-files", "--others", "--exclude-standard", "-z"])?;
+    Ok(match (stash, untracked.is_empty()) {
+        (None, true) => None,
+        (Some(stash), true) => Some(stash),
+        (stash, false) => Some(with_untracked(
+            worktree,
+            branch,
+            stash.as_deref(),
+            &untracked,
+            message,
+        )?),
+    })
+}
+
+/// Record the worktree's staged, unstaged and untracked changes as a new
+/// snapshot.
+///
+/// Returns `None` when there's nothing to record.
+pub(crate) fn create_snapshot(
+    worktree: &WorkingTree,
+    branch: &str,
+    message: Option<&str>,
+) -> anyhow::Result<Option<Snapshot>> {
+    let Some(sha) = snapshot_commit(worktree, branch, message)? else {
+        return Ok(None);
+    };
+
+    let repo = worktree.repo();
+    let id = list_snapshots(repo, branch)?
+        .first()
+        .map_or(1, |newest| newest.id + 1);
+    let ref_name = format!("{}/{id}", ref_prefix(branch));
+    repo.run_command(&["update-ref", &ref_name, &sha])?;
+
+    let subject = repo.run_command(&["log", "-1", "--format=%ct%x00%s", &sha])?;
+    let (at, subject) = subject.trim_end().split_once('\0').unwrap_or_default();
+    Ok(Some(Snapshot {
+        id,
+        sha,
+        at: at.parse().unwrap_or_default(),
+        message: strip_subject(subject, branch).to_string(),
+    }))
+}
+
+/// The snapshot numbered `id`, or the newest one.
+fn find_snapshot(repo: &Repository, branch: &str, id: Option<u32>) -> anyhow::Result<Snapshot> {
+    let snapshots = list_snapshots(repo, branch)?;
+    let found = match id {
+        Some(id) => snapshots.into_iter().find(|snapshot| snapshot.id == id),
+        None => snapshots.into_iter().next(),
+    };
+    found.ok_or_else(|| {
+        let message = match id {
+            Some(id) => cformat!("No snapshot <bold>{id}</> for <bold>{branch}</>"),
+            None => cformat!("No snapshots for <bold>{branch}</>"),
+        };
+        GitError::Other { message }.into()
+    })
+}
+
+/// Apply the snapshot `sha` to `worktree`, which has no tracked changes.
+///
+/// Untracked files the snapshot recorded replace any at the same paths;
+/// `git stash apply` refuses to overwrite them otherwise.
+pub(crate) fn apply_snapshot(worktree: &WorkingTree, sha: &str) -> anyhow::Result<()> {
+    let untracked = format!("{sha}^3");
+    if !worktrunk::dry_run::is_dry_run()
+        && worktree
+            .run_command(&["rev-parse", "--verify", "--quiet", &untracked])
+            .is_ok()
+    {
+        let root = worktree.root()?;
+        let paths = worktree.run_command(&[
+            "ls-tree",
+            "-r",
+            "-z",
+            "--full-tree",
+            "--name-only",
+            &untracked,
+        ])?;
+        for path in paths.split('\0').filter(|path| !path.is_empty()) {
+            let _ = std::fs::remove_file(root.join(path));
+        }
+    }
+    worktree.run_command(&["stash", "apply", "--index", "--quiet", sha])?;
+    Ok(())
+}
+
+/// Put `worktree` back to `commit` plus the snapshot `sha`.
+///
+/// Nothing in the worktree is lost: its current changes are saved as a
+/// snapshot first, described by `reason`.
+pub(crate) fn restore_snapshot(
+    worktree: &WorkingTree,
+    branch: &str,
+    commit: &str,
+    sha: &str,
+    reason: &str,
+) -> anyhow::Result<()> {
+    if let Some(saved) = create_snapshot(worktree, branch, Some(reason))? {
+        eprintln!(
+            "{}",
+            info_message(cformat!(
+                "Saved current changes as snapshot <bold>{}</>",
+                saved.id
+            ))
+        );
+    }
+    worktree.run_command(&["reset", "--hard", "--quiet", commit])?;
+    apply_snapshot(worktree, sha)
+}
+
+/// Snapshot `worktree` before `operation`, unless `[snapshot] auto = false`.
+///
+/// Best-effort: a failure warns and lets the operation go ahead. Returns the
+/// snapshot commit for the undo journal, or `None` when nothing was saved
+/// (no changes, a detached `HEAD`, or snapshots turned off).
+pub(crate) fn auto_snapshot(worktree: &WorkingTree, operation: &str) -> Option<String> {
+    let enabled = UserConfig::load()
+        .ok()
+        .and_then(|config| config.snapshot)
+        .is_none_or(|snapshot| snapshot.auto());
+    if !enabled {
+        return None;
+    }
+    let branch = worktree.branch().ok().flatten()?;
+    match create_snapshot(worktree, &branch, Some(&format!("Before {operation}"))) {
+        Ok(Some(snapshot)) => {
+            eprintln!(
+                "{}",
+                hint_message(cformat!(
+                    "Saved uncommitted changes as snapshot <bold>{}</>",
+                    snapshot.id
+                ))
+            );
+            Some(snapshot.sha)
+        }
+        Ok(None) => None,
+        Err(e) => {
+            eprintln!(
+                "{}",
+                warning_message(format!("Could not snapshot before {operation}: {e}"))
+            );
+            None
+        }
+    }
+}
+
+/// Handle `wt snapshot [message]`.
+pub(crate) fn handle_snapshot(message: Option<&str>) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let worktree = repo.current_worktree();
+    let branch = current_branch(&worktree)?;
+    match create_snapshot(&worktree, &branch, message)? {
+        Some(snapshot) => eprintln!(
+            "{}",
+            success_message(cformat!(
+                "Saved snapshot <bold>{}</> of <bold>{branch}</>",
+                snapshot.id
+            ))
+        ),
+        None => eprintln!(
+            "{}",
+            info_message("Nothing to snapshot; no uncommitted changes")
+        ),
+    }
+    Ok(())
+}
+
+/// Handle `wt snapshot list`.
+pub(crate) fn handle_snapshot_list() -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let branch = current_branch(&repo.current_worktree())?;
+    let snapshots = list_snapshots(&repo, &branch)?;
+    if snapshots.is_empty() {
+        eprintln!(
+            "{}",
+            info_message(cformat!("No snapshots for <bold>{branch}</>"))
+        );
+    }
+    for snapshot in snapshots {
+        println!(
+            "{:>3}  {}  {}",
+            snapshot.id,
+            format_relative_time_short(snapshot.at),
+            snapshot.message
+        );
+    }
+    Ok(())
+}
+
+/// Handle `wt snapshot restore [id]`.
+pub(crate) fn handle_snapshot_restore(id: Option<u32>) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let worktree = repo.current_worktree();
+    let branch = current_branch(&worktree)?;
+    let snapshot = find_snapshot(&repo, &branch, id)?;
+    let _lock = repo.lock_operation("snapshot")?;
+    restore_snapshot(
+        &worktree,
+        &branch,
+        "HEAD",
+        &snapshot.sha,
+        &format!("Before restoring snapshot {}", snapshot.id),
+    )?;
+
+    eprintln!(
+        "{}",
+        success_message(cformat!(
+            "Restored snapshot <bold>{}</> of <bold>{branch}</>",
+            snapshot.id
+        ))
+    );
+    Ok(())
+}
+
+/// Handle `wt snapshot diff [id]`.
+pub(crate) fn handle_snapshot_diff(id: Option<u32>) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let worktree = repo.current_worktree();
+    let branch = current_branch(&worktree)?;
+    let snapshot = find_snapshot(&repo, &branch, id)?;
+    let diff = worktree.run_command(&["diff", "--color=always", &snapshot.sha])?;
+    if diff.trim().is_empty() {
+        eprintln!(
+            "{}",
+            hint_message(cformat!(
+                "Worktree matches snapshot <bold>{}</>",
+                snapshot.id
+            ))
+        );
+    }
+    // Colors are dropped when stdout isn't a terminal or `--color=never`
+    crate::pager::page_stdout(&diff)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_snapshots() {
+        let prefix = ref_prefix("feature/x");
+        assert_eq!(prefix, "refs/wt-snapshot/feature/x");
+        // `feature/x` and `feature-x` are different branches
+        assert_ne!(ref_prefix("feature-x"), prefix);
+        let output = "refs/wt-snapshot/feature/x/2\x00bbb\x00200\x00On feature/x: try the cache\n\
+                      refs/wt-snapshot/feature/x/10\x00ccc\x00300\x00WIP on feature/x: 1234567 Add x\n\
+                      refs/wt-snapshot/feature/x/1\x00aaa\x00100\x00On feature/x: first\n\
+                      refs/wt-snapshot/feature/x/junk\x00ddd\x00400\x00ignored\n";
+        let snapshots = parse_snapshots(output, &prefix, "feature/x");
+        let ids: Vec<u32> = snapshots.iter().map(|s| s.id).collect();
+        assert_eq!(ids, [10, 2, 1]);
+        assert_eq!(snapshots[1].message, "try the cache");
+        assert_eq!(snapshots[1].at, 200);
+        assert_eq!(snapshots[0].message, "WIP on feature/x: 1234567 Add x");
+
+        // A nested branch's snapshots aren't the parent branch's
+        let output = "refs/wt-snapshot/feature/3\x00aaa\x00100\x00On feature: mine\n\
+                      refs/wt-snapshot/feature/x/1\x00bbb\x00200\x00On feature/x: nested\n";
+        let snapshots = parse_snapshots(output, &ref_prefix("feature"), "feature");
+        let ids: Vec<u32> = snapshots.iter().map(|s| s.id).collect();
+        assert_eq!(ids, [3]);
+    }
+}
@@ -0,0 +1,312 @@
+//! `wt step split`: turn a worktree's uncommitted changes into a commit series.
+//!
+//! The changed files are grouped into commits — by the commit generation
+//! command when one is configured, otherwise by top-level directory — and the
+//! plan is shown for confirmation. Each group is then staged (whole files, or
+//! hunk by hunk with `--patch`) and committed. A safety backup of the working
+//! tree is taken first, as `wt step squash` does.
+
+use std::collections::BTreeMap;
+use std::process::Stdio;
+
+use anyhow::Context;
+use color_print::cformat;
+use worktrunk::config::UserConfig;
+use worktrunk::git::{GitError, Repository};
+use worktrunk::shell_exec::Cmd;
+use worktrunk::styling::{
+    eprintln, format_with_gutter, hint_message, info_message, progress_message, success_message,
+    warning_message,
+};
+
+use crate::llm::suggest_split;
+use crate::output::prompt::{PromptResponse, assume_yes, prompt_yes_no_preview};
+
+/// One planned commit.
+#[derive(Debug, Clone, PartialEq)]
+struct SplitGroup {
+    message: String,
+    files: Vec<String>,
+}
+
+/// Parse the LLM's `## <subject>` / path lines into groups.
+///
+/// Paths not in `files`, or already claimed by an earlier group, are dropped;
+/// files the LLM didn't place are left for the caller.
+fn parse_groups(output: &str, files: &[String]) -> Vec<SplitGroup> {
+    let mut groups: Vec<SplitGroup> = Vec::new();
+    let mut claimed = std::collections::HashSet::new();
+    for line in output.lines().map(str::trim) {
+        if let Some(subject) = line.strip_prefix("## ") {
+            groups.push(SplitGroup {
+                message: subject.trim().to_string(),
+                files: Vec::new(),
+            });
+            continue;
+        }
+        let path = line.trim_start_matches("- ").trim_matches('`');
+        if let Some(group) = groups.last_mut()
+            && files.iter().any(|f| f == path)
+            && claimed.insert(path.to_string())
+        {
+            group.files.push(path.to_string());
+        }
+    }
+    groups.retain(|g| !g.files.is_empty() && !g.message.is_empty());
+    groups
+}
+
+/// Group files by top-level directory; root files form their own group.
+fn group_by_directory(files: &[String]) -> Vec<SplitGroup> {
+    let mut by_dir: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+    for file in files {
+        let dir = match file.split_once('/') {
+            Some((dir, _)) => dir,
+            None => "",
+        };
+        by_dir.entry(dir).or_default().push(file.clone());
+    }
+    by_dir
+        .into_iter()
+        .map(|(dir, files)| SplitGroup {
+            message: if dir.is_empty() {
+                "Update top-level files".to_string()
+            } else {
+                format!("Update {dir}")
+            },
+            files,
+        })
+        .collect()
+}
+
+/// Changed files (tracked and untracked) relative to `HEAD`.
+fn changed_files(repo: &Repository) -> anyhow::Result<Vec<String>> {
+    let worktree = repo.current_worktree();
+    let tracked = worktree.run_command(&["diff", "--name-only", "HEAD"])?;
+    let untracked = worktree.run_command(&["ls-files", "--others", "--exclude-standard"])?;
+    let mut files: Vec<String> = tracked
+        .lines()
+        .chain(untracked.lines())
+        .map(str::to_string)
+        .collect();
+    files.sort();
+    files.dedup();
+    Ok(files)
+}
+
+fn format_plan(groups: &[SplitGroup]) -> String {
+    groups
+        .iter()
+        .enumerate()
+        .map(|(i, group)| {
+            let files = group
+                .files
+                .iter()
+                .map(|f| format!("    {f}"))
+                .collect::<Vec<_>>()
+                .join("\n");
+            format!("{}. {}\n{files}", i + 1, group.message)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Handle `wt step split`.
+pub fn step_split(yes: bool, patch: bool, dry_run: bool) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let worktree = repo.current_worktree();
+    let Some(branch) = worktree.branch()? else {
+        return Err(GitError::DetachedHead {
+            action: Some("split".into()),
+        }
+        .into());
+    };
+    if !worktree.is_dirty()? {
+        eprintln!(
+            "{}",
+            info_message("Nothing to split; no uncommitted changes")
+        );
+        return Ok(());
+    }
+
+    let files = changed_files(&repo)?;
+    let config = UserConfig::load().context("Failed to load config")?;
+    let generation = config.commit_generation(repo.project_identifier().ok().as_deref());
+
+    let mut groups = if generation.is_configured() {
+        eprintln!(
+            "{}",
+            progress_message(cformat!("Grouping {} files into commits...", files.len()))
+        );
+        match suggest_split(&generation, &repo, &files, &branch) {
+            Ok(output) => parse_groups(&output, &files),
+            Err(e) => {
+                eprintln!(
+                    "{}",
+                    warning_message(format!(
+                        "Could not get a grouping from the LLM; grouping by directory: {e}"
+                    ))
+                );
+                Vec::new()
+            }
+        }
+    } else {
+        Vec::new()
+    };
+    let placed: Vec<&String> = groups.iter().flat_map(|g| &g.files).collect();
+    let unplaced: Vec<String> = files
+        .iter()
+        .filter(|f| !placed.contains(f))
+        .cloned()
+        .collect();
+    groups.extend(group_by_directory(&unplaced));
+
+    eprintln!(
+        "{}",
+        info_message(cformat!("Split into <bold>{}</> commits:", groups.len()))
+    );
+    eprintln!("{}", format_with_gutter(&format_plan(&groups), None));
+    if dry_run {
+        return Ok(());
+    }
+
+    if !yes && !assume_yes() {
+        let response = prompt_yes_no_preview("Commit this series?", || {
+            if let Ok(stat) = repo.run_command(&["--no-pager", "diff", "HEAD", "--stat"]) {
+                eprintln!("{}", format_with_gutter(stat.trim_end(), None));
+            }
+        })?;
+        if response == PromptResponse::Declined {
+            eprintln!("{}", info_message("Split cancelled; nothing committed"));
+            return Ok(());
+        }
+    }
+
+    // Stage everything so the backup has new files too; `stash create` leaves
+    // untracked files out
+    worktree.run_command(&["add", "--all"])?;
+    let backup = worktree.create_safety_backup(&format!("{branch} (split)"))?;
+    // Start from an empty index so each group is staged on its own; new files
+    // are marked intent-to-add so `git add --patch` offers them
+    worktree.run_command(&["reset", "--quiet"])?;
+    worktree.run_command(&["add", "--intent-to-add", "--all"])?;
+    let root = worktree.root()?;
+    let mut committed = 0;
+    for (i, group) in groups.iter().enumerate() {
+        if patch {
+            eprintln!(
+                "{}",
+                progress_message(cformat!(
+                    "Select hunks for <bold>{}</> ({}/{})",
+                    group.message,
+                    i + 1,
+                    groups.len()
+                ))
+            );
+            Cmd::new("git")
+                .args(["add", "--patch", "--"])
+                .args(group.files.iter().cloned())
+                .current_dir(&root)
+                .stdin(Stdio::inherit())
+                .stream()
+                .context("Failed to select hunks")?;
+        } else {
+            let mut args = vec!["add", "--all", "--"];
+            args.extend(group.files.iter().map(String::as_str));
+            worktree.run_command(&args)?;
+        }
+
+        if !worktree.has_staged_changes()? {
+            eprintln!(
+                "{}",
+                info_message(cformat!(
+                    "Skipped <bold>{}</>; nothing staged",
+                    group.message
+                ))
+            );
+            continue;
+        }
+        let mut args = vec!["commit", "--quiet", "-m", group.message.as_str()];
+        args.extend(repo.signing_args());
+        worktree
+            .run_command(&args)
+            .map_err(|e| repo.signing_error("split", e))?;
+        committed += 1;
+        eprintln!(
+            "{}",
+            success_message(cformat!(
+                "Committed <bold>{}</> ({}/{})",
+                group.message,
+                i + 1,
+                groups.len()
+            ))
+        );
+    }
+
+    if worktree.is_dirty()? {
+        eprintln!(
+            "{}",
+            hint_message(
+                "Some changes were left uncommitted; commit them or run wt step split again"
+            )
+        );
+    }
+    eprintln!(
+        "{}",
+        success_message(cformat!(
+            "Split into {committed} commit{} on <bold>{branch}</> (backup <bold>{backup}</>)",
+            if committed == 1 { "" } else { "s" }
+        ))
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn files(paths: &[&str]) -> Vec<String> {
+        paths.iter().map(|p| p.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_groups() {
+        let files = files(&["src/a.rs", "src/b.rs", "docs/a.md", "README.md"]);
+        let output = "\
+## Add parser
+src/a.rs
+- `src/b.rs`
+src/missing.rs
+
+## Document parser
+docs/a.md
+src/a.rs
+
+## Empty group
+";
+        let groups = parse_groups(output, &files);
+        assert_eq!(
+            groups,
+            vec![
+                SplitGroup {
+                    message: "Add parser".into(),
+                    files: vec!["src/a.rs".into(), "src/b.rs".into()],
+                },
+                SplitGroup {
+                    message: "Document parser".into(),
+                    files: vec!["docs/a.md".into()],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_group_by_directory() {
+        let groups = group_by_directory(&files(&["src/a.rs", "README.md", "src/b/c.rs"]));
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].message, "Update top-level files");
+        assert_eq!(groups[0].files, vec!["README.md"]);
+        assert_eq!(groups[1].message, "Update src");
+        assert_eq!(groups[1].files, vec!["src/a.rs", "src/b/c.rs"]);
+    }
+}
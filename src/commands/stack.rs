@@ -0,0 +1,506 @@
+//! `wt stack` and `wt sync` — branches built on other branches.
+//!
+//! A stack is recorded as a parent per branch in the state store, so it
+//! survives worktrees coming and going. `wt list --stacks` draws the recorded
+//! parents as a tree; `wt sync` rebases each stacked branch onto its parent,
+//! parents first, so a change low in the stack reaches every branch above it.
+//! `wt stack submit` pushes a stack and opens or retargets one PR/MR per
+//! branch, each based on its parent and linking the rest of the stack.
+
+use std::collections::BTreeMap;
+
+use anyhow::Context;
+use color_print::cformat;
+use worktrunk::config::UserConfig;
+use worktrunk::git::{GitError, Repository};
+use worktrunk::state::{Operation, StateStore};
+use worktrunk::styling::{
+    eprintln, format_with_gutter, info_message, println, progress_message, success_message,
+    warning_message,
+};
+
+use super::forge::{
+    PrContent, create_pr, detect_platform, find_open_pr, push_with_upstream, update_pr,
+};
+use super::resolve::record_paused;
+use super::step_commands::run_rebase;
+use super::undo::record;
+use crate::output::Progress;
+
+/// The current worktree's branch, or `branch` when given.
+fn branch_or_current(repo: &Repository, branch: Option<&str>) -> anyhow::Result<String> {
+    if let Some(branch) = branch {
+        return Ok(branch.to_string());
+    }
+    Ok(repo
+        .current_worktree()
+        .branch()?
+        .ok_or(GitError::DetachedHead {
+            action: Some("stack".into()),
+        })?)
+}
+
+fn require_local(repo: &Repository, branch: &str) -> anyhow::Result<()> {
+    if !repo.branch(branch).exists_locally()? {
+        return Err(GitError::BranchNotFound {
+            branch: branch.to_string(),
+            show_create_hint: false,
+        }
+        .into());
+    }
+    Ok(())
+}
+
+/// Stacked branches with their parents, every parent before its children.
+///
+/// Branches are otherwise in name order. Entries caught in a cycle (only
+/// possible if `state.json` was edited by hand) are left out.
+fn stack_order(stacks: &BTreeMap<String, String>) -> Vec<(&str, &str)> {
+    let depth = |branch: &str| {
+        let mut depth = 0;
+        let mut current = branch;
+        while let Some(parent) = stacks.get(current) {
+            depth += 1;
+            if depth > stacks.len() {
+                return None;
+            }
+            current = parent.as_str();
+        }
+        Some(depth)
+    };
+    let mut order: Vec<(usize, &str, &str)> = stacks
+        .iter()
+        .filter_map(|(branch, parent)| Some((depth(branch)?, branch.as_str(), parent.as_str())))
+        .collect();
+    order.sort();
+    order
+        .into_iter()
+        .map(|(_, branch, parent)| (branch, parent))
+        .collect()
+}
+
+/// The branch at the bottom of `branch`'s stack: its first ancestor that
+/// isn't stacked itself.
+fn stack_root<'a>(stacks: &'a BTreeMap<String, String>, branch: &'a str) -> &'a str {
+    let mut current = branch;
+    for _ in 0..=stacks.len() {
+        match stacks.get(current) {
+            Some(parent) => current = parent.as_str(),
+            None => break,
+        }
+    }
+    current
+}
+
+/// Marks the part of a PR/MR description that `wt stack submit` owns.
+const STACK_START: &str = "<!-- worktrunk-stack -->";
+const STACK_END: &str = "<!-- /worktrunk-stack -->";
+
+/// Links to every PR/MR in the stack, base first, marking `current`.
+fn stack_section(links: &[(&str, &str)], current: &str) -> String {
+    let mut section = format!("{STACK_START}\n**Stack**, base first:\n");
+    for (branch, url) in links {
+        section.push_str(&format!("- `{branch}` {url}"));
+        if *branch == current {
+            section.push_str(" ← this one");
+        }
+        section.push('\n');
+    }
+    section.push_str(STACK_END);
+    section
+}
+
+/// `body` with its stack section replaced by `section`, or `section`
+/// appended when there's none yet. The rest of the description is kept.
+fn with_stack_section(body: &str, section: &str) -> String {
+    if let Some(start) = body.find(STACK_START)
+        && let Some(end) = body[start..].find(STACK_END)
+    {
+        let end = start + end + STACK_END.len();
+        return format!("{}{section}{}", &body[..start], &body[end..]);
+    }
+    let body = body.trim_end();
+    if body.is_empty() {
+        section.to_string()
+    } else {
+        format!("{body}\n\n{section}")
+    }
+}
+
+/// Handle `wt stack add`.
+pub(crate) fn handle_stack_add(parent: &str, branch: Option<&str>) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let branch = branch_or_current(&repo, branch)?;
+    require_local(&repo, parent)?;
+    require_local(&repo, &branch)?;
+
+    let store = StateStore::new(repo.git_common_dir());
+    let cycle = store.update(|state| {
+        // Walking up from the new parent must not reach the branch itself
+        let mut current = parent;
+        loop {
+            if current == branch.as_str() {
+                return true;
+            }
+            match state.stacks.get(current) {
+                Some(next) => current = next.as_str(),
+                None => break,
+            }
+        }
+        state.stacks.insert(branch.clone(), parent.to_string());
+        false
+    })?;
+    if cycle {
+        return Err(GitError::Other {
+            message: cformat!(
+                "Can't stack <bold>{branch}</> on <bold>{parent}</>; <bold>{parent}</> is already stacked on it"
+            ),
+        }
+        .into());
+    }
+    eprintln!(
+        "{}",
+        success_message(cformat!("Stacked <bold>{branch}</> on <bold>{parent}</>"))
+    );
+    Ok(())
+}
+
+/// Handle `wt stack remove`.
+///
+/// Branches stacked on the removed one move down to its parent, keeping the
+/// rest of the stack connected.
+pub(crate) fn handle_stack_remove(branch: Option<&str>) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let branch = branch_or_current(&repo, branch)?;
+    let store = StateStore::new(repo.git_common_dir());
+    let parent = store.update(|state| {
+        let parent = state.stacks.remove(&branch)?;
+        for child_parent in state.stacks.values_mut() {
+            if *child_parent == branch {
+                *child_parent = parent.clone();
+            }
+        }
+        Some(parent)
+    })?;
+    let Some(parent) = parent else {
+        eprintln!(
+            "{}",
+            info_message(cformat!(
+                "<bold>{branch}</> isn't stacked on another branch"
+            ))
+        );
+        return Ok(());
+    };
+    eprintln!(
+        "{}",
+        success_message(cformat!(
+            "Unstacked <bold>{branch}</> from <bold>{parent}</>"
+        ))
+    );
+    Ok(())
+}
+
+/// Handle `wt list --stacks`: the recorded stacks as an indented tree.
+pub(crate) fn handle_list_stacks() -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let stacks = StateStore::new(repo.git_common_dir()).load()?.stacks;
+    if stacks.is_empty() {
+        eprintln!(
+            "{}",
+            info_message(cformat!(
+                "No stacked branches; stack one with <bright-black>wt stack add</>"
+            ))
+        );
+        return Ok(());
+    }
+
+    let mut children: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    for (branch, parent) in &stacks {
+        children.entry(parent).or_default().push(branch);
+    }
+    let roots: Vec<&str> = children
+        .keys()
+        .copied()
+        .filter(|parent| !stacks.contains_key(*parent))
+        .collect();
+
+    let mut lines = Vec::new();
+    for root in roots {
+        lines.push(cformat!("<bold>{root}</>"));
+        draw(&repo, &children, root, "", &mut lines);
+    }
+    for line in lines {
+        println!("{line}");
+    }
+    Ok(())
+}
+
+/// Append the branches stacked on `parent`, and theirs, to `lines`.
+fn draw(
+    repo: &Repository,
+    children: &BTreeMap<&str, Vec<&str>>,
+    parent: &str,
+    prefix: &str,
+    lines: &mut Vec<String>,
+) {
+    let Some(branches) = children.get(parent) else {
+        return;
+    };
+    for (i, branch) in branches.iter().enumerate() {
+        let last = i + 1 == branches.len();
+        let mut status = String::new();
+        if let Ok(ahead) = repo.count_commits(parent, branch) {
+            status.push_str(&cformat!("  <green>↑{ahead}</>"));
+        }
+        if repo.is_ancestor(parent, branch).ok() == Some(false) {
+            status.push_str(&cformat!("  <yellow>needs sync</>"));
+        }
+        lines.push(cformat!(
+            "{prefix}{}<bold>{branch}</>{status}",
+            if last { "└─ " } else { "├─ " }
+        ));
+        let prefix = format!("{prefix}{}", if last { "   " } else { "│  " });
+        draw(repo, children, branch, &prefix, lines);
+    }
+}
+
+/// Handle `wt sync`: rebase stacked branches onto their parents in order.
+///
+/// Each rebase runs in the branch's worktree with `--fork-point`, so commits
+/// a parent dropped or rewrote since the child was built on it aren't
+/// replayed. Branches without a worktree are skipped. A conflict stops the
+/// sync; after `wt resolve`, rerunning `wt sync` picks up where it stopped.
+pub(crate) fn handle_sync() -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let stacks = StateStore::new(repo.git_common_dir()).load()?.stacks;
+    if stacks.is_empty() {
+        eprintln!(
+            "{}",
+            info_message(cformat!(
+                "No stacked branches to sync; stack one with <bright-black>wt stack add</>"
+            ))
+        );
+        return Ok(());
+    }
+
+    let mut rebased = 0;
+    let order = stack_order(&stacks);
+    for (position, (branch, parent)) in order.iter().copied().enumerate() {
+        if !repo.branch(parent).exists_locally()? || !repo.branch(branch).exists_locally()? {
+            eprintln!(
+                "{}",
+                warning_message(cformat!(
+                    "Skipping <bold>{branch}</>; it or its parent <bold>{parent}</> no longer exists"
+                ))
+            );
+            continue;
+        }
+        if repo.is_ancestor(parent, branch)? {
+            continue;
+        }
+        let Some(path) = repo.worktree_for_branch(branch)? else {
+            eprintln!(
+                "{}",
+                warning_message(cformat!(
+                    "Skipping <bold>{branch}</>; it has no worktree to rebase in"
+                ))
+            );
+            continue;
+        };
+
+        let branch_repo = Repository::at(&path)?;
+        branch_repo
+            .current_worktree()
+            .ensure_clean("sync", Some(branch), false)?;
+        eprintln!(
+            "{}",
+            progress_message(cformat!(
+                "Rebasing <bold>{branch}</> onto <bold>{parent}</>..."
+            ))
+        );
+        let before = branch_repo
+            .run_command(&["rev-parse", "HEAD"])?
+            .trim()
+            .to_string();
+        let progress = Progress::spinner(format!(
+            "Syncing stack ({}/{})...",
+            position + 1,
+            order.len()
+        ));
+        let result = run_rebase(&branch_repo, &["--fork-point"], parent);
+        progress.finish();
+        if let Err(e) = result {
+            if let Some(GitError::RebaseConflict { .. }) = e.downcast_ref() {
+                record_paused(&repo, branch);
+            }
+            return Err(e);
+        }
+        if let Ok(after) = branch_repo.run_command(&["rev-parse", "HEAD"]) {
+            record(
+                &repo,
+                Operation::RefMoved {
+                    branch: branch.to_string(),
+                    before,
+                    after: after.trim().to_string(),
+                    operation: "sync".into(),
+                    snapshot: None,
+                },
+            );
+        }
+        rebased += 1;
+        eprintln!(
+            "{}",
+            success_message(cformat!("Rebased <bold>{branch}</> onto <bold>{parent}</>"))
+        );
+    }
+
+    if rebased == 0 {
+        eprintln!("{}", info_message("All stacked branches are up to date"));
+    }
+    Ok(())
+}
+
+/// Handle `wt stack submit`: push the stack containing `branch` and open or
+/// update a PR/MR per branch, based on its parent.
+///
+/// Runs in two passes: pushing and creating PRs first, so that every
+/// description can then link the whole stack.
+pub(crate) fn handle_stack_submit(
+    branch: Option<&str>,
+    draft: bool,
+    yes: bool,
+    dry_run: bool,
+) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let branch = branch_or_current(&repo, branch)?;
+    let stacks = StateStore::new(repo.git_common_dir()).load()?.stacks;
+    if !stacks.contains_key(&branch) && !stacks.values().any(|parent| *parent == branch) {
+        return Err(GitError::Other {
+            message: cformat!(
+                "<bold>{branch}</> isn't part of a stack; stack it with <bright-black>wt stack add</>"
+            ),
+        }
+        .into());
+    }
+    let root = stack_root(&stacks, &branch);
+    let members: Vec<(&str, &str)> = stack_order(&stacks)
+        .into_iter()
+        .filter(|(member, _)| stack_root(&stacks, member) == root)
+        .collect();
+
+    let plan = members
+        .iter()
+        .map(|(member, parent)| format!("{member} → {parent}"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    eprintln!(
+        "{}",
+        info_message(cformat!(
+            "Submitting {} branch{} stacked on <bold>{root}</>:",
+            members.len(),
+            if members.len() == 1 { "" } else { "es" }
+        ))
+    );
+    eprintln!("{}", format_with_gutter(&plan, None));
+    if dry_run {
+        return Ok(());
+    }
+
+    let platform = detect_platform(&repo)?;
+    let config = UserConfig::load().context("Failed to load config")?;
+    let push_config = config
+        .push(repo.project_identifier().ok().as_deref())
+        .unwrap_or_default();
+    let current_root = repo.current_worktree().root()?;
+
+    // Pass 1: push, and find or open each PR/MR
+    let mut submitted = Vec::new();
+    for &(member, parent) in &members {
+        let path = repo
+            .worktree_for_branch(member)?
+            .unwrap_or_else(|| current_root.clone());
+        push_with_upstream(&repo, &path, member, &push_config, yes)?;
+        let pr = match find_open_pr(platform, &path, member)? {
+            Some(pr) => pr,
+            None => {
+                create_pr(
+                    platform,
+                    &path,
+                    member,
+                    PrContent {
+                        draft,
+                        base: Some(parent),
+                        ..Default::default()
+                    },
+                )?;
+                // Re-read it for the description the CLI filled in
+                find_open_pr(platform, &path, member)?
+                    .with_context(|| format!("Opened a PR/MR for {member} but could not find it"))?
+            }
+        };
+        submitted.push((member, parent, path, pr));
+    }
+
+    // Pass 2: base each on its parent and link the whole stack
+    let links: Vec<(&str, &str)> = submitted
+        .iter()
+        .map(|(member, _, _, pr)| (*member, pr.url.as_str()))
+        .collect();
+    for (member, parent, path, pr) in &submitted {
+        let body = with_stack_section(&pr.body, &stack_section(&links, member));
+        update_pr(platform, path, member, parent, &body)?;
+        eprintln!(
+            "{}",
+            success_message(cformat!(
+                "Submitted <bold>{member}</> onto <bold>{parent}</> {}",
+                pr.url
+            ))
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stack_order() {
+        let stacks: BTreeMap<String, String> = [
+            ("c", "b"),
+            ("b", "a"),
+            ("a", "main"),
+            ("x", "main"),
+            ("loop1", "loop2"),
+            ("loop2", "loop1"),
+        ]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+        assert_eq!(
+            stack_order(&stacks),
+            vec![("a", "main"), ("x", "main"), ("b", "a"), ("c", "b")]
+        );
+        assert_eq!(stack_root(&stacks, "c"), "main");
+        assert_eq!(stack_root(&stacks, "main"), "main");
+    }
+
+    #[test]
+    fn test_with_stack_section() {
+        let links = [("api", "https://x/pull/1"), ("ui", "https://x/pull/2")];
+        let section = stack_section(&links, "ui");
+        assert_eq!(
+            section,
+            "<!-- worktrunk-stack -->\n**Stack**, base first:\n- `api` https://x/pull/1\n- `ui` https://x/pull/2 ← this one\n<!-- /worktrunk-stack -->"
+        );
+
+        assert_eq!(with_stack_section("", &section), section);
+        let body = with_stack_section("Adds the UI.\n", &section);
+        assert_eq!(body, format!("Adds the UI.\n\n{section}"));
+
+        // A later submit replaces only its own section
+        let updated = stack_section(&links[..1], "api");
+        let body = with_stack_section(&format!("{body}\n\nFooter"), &updated);
+        assert_eq!(body, format!("Adds the UI.\n\n{updated}\n\nFooter"));
+    }
+}
@@ -0,0 +1,242 @@
+//! Start command handler.
+//!
+//! `wt start` chains the steps of beginning a piece of work: resolve an issue
+//! to a branch name, create the worktree, run setup, publish a draft PR/MR,
+//! and launch an editor. Completed steps are recorded in the state store so
+//! a failed run can be resumed by rerunning the same command.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use anyhow::Context;
+use color_print::cformat;
+use worktrunk::config::{PushConfig, StartConfig, StartStep, UserConfig, expand_template};
+use worktrunk::git::{GitError, Repository};
+use worktrunk::state::StateStore;
+use worktrunk::styling::{
+    eprintln, format_bash_with_gutter, info_message, progress_message, success_message,
+};
+
+use super::command_executor::{CommandContext, build_hook_context};
+use super::forge::{PrContent, create_pr, detect_platform, push_with_upstream};
+use super::handle_switch::{SwitchOptions, handle_switch};
+use super::issue::{Issue, fetch_issue, issue_branch, parse_issue_ref, record_issue_note};
+use crate::output::{execute_command_in_worktree, execute_user_command, post_hook_display_path};
+
+/// Options for the start command
+pub struct StartOptions<'a> {
+    pub target: &'a str,
+    pub base: Option<&'a str>,
+    /// Steps skipped in addition to `[start] skip`
+    pub skip: &'a [StartStep],
+    pub draft_pr: bool,
+    pub yes: bool,
+    pub verify: bool,
+}
+
+/// Steps recorded as completed by a previous `wt start` for this branch.
+fn completed_steps(repo: &Repository, branch: &str) -> Vec<StartStep> {
+    StateStore::new(repo.git_common_dir())
+        .load()
+        .ok()
+        .and_then(|mut state| state.starts.remove(branch))
+        .unwrap_or_default()
+}
+
+fn record_steps(repo: &Repository, branch: &str, steps: &[StartStep]) -> anyhow::Result<()> {
+    StateStore::new(repo.git_common_dir()).update(|state| {
+        state.starts.insert(branch.to_string(), steps.to_vec());
+    })
+}
+
+fn clear_progress(repo: &Repository, branch: &str) {
+    let store = StateStore::new(repo.git_common_dir());
+    if let Err(e) = store.update(|state| state.starts.remove(branch)) {
+        log::debug!("Failed to clear wt start progress of {branch}: {e}");
+    }
+}
+
+/// Handle the start command.
+pub fn handle_start(
+    opts: StartOptions<'_>,
+    config: &mut UserConfig,
+    binary_name: &str,
+) -> anyhow::Result<()> {
+    let StartOptions {
+        target,
+        base,
+        skip,
+        draft_pr,
+        yes,
+        verify,
+    } = opts;
+
+    let repo = Repository::current().context("Failed to start work")?;
+    let project_id = repo.project_identifier().ok();
+    let start_config: StartConfig = config.start(project_id.as_deref()).unwrap_or_default();
+
+    // Resolve: issue reference → branch name via template
+    let issue = match parse_issue_ref(target) {
+        Some(_) => Some(fetch_issue(&repo, config, &start_config, target)?),
+        None => None,
+    };
+    let issue_vars = issue.as_ref().map(Issue::vars).unwrap_or_default();
+    let branch = match &issue {
+        Some(issue) => issue_branch(&repo, &start_config, issue)?,
+        None => target.to_string(),
+    };
+
+    // Which steps run this time: configured, not skipped, not already done
+    let mut completed = completed_steps(&repo, &branch);
+    if !completed.is_empty() {
+        let done = completed
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        eprintln!(
+            "{}",
+            info_message(cformat!(
+                "Resuming <bold>{branch}</>; already completed: {done}"
+            ))
+        );
+    }
+    let wants = |step: StartStep| {
+        let configured = match step {
+            StartStep::Setup => start_config.setup().is_some(),
+            StartStep::Publish => draft_pr || start_config.draft_pr(),
+            StartStep::Open => start_config.open().is_some(),
+        };
+        configured
+            && !skip.contains(&step)
+            && !start_config.skip().contains(&step)
+            && !completed.contains(&step)
+    };
+    let (run_setup, run_publish, run_open) = (
+        wants(StartStep::Setup),
+        wants(StartStep::Publish),
+        wants(StartStep::Open),
+    );
+
+    // Create: same as `wt switch [--create]`; switches if the worktree exists
+    let create = !repo.branch(&branch).exists()?;
+    handle_switch(
+        SwitchOptions {
+            branch: &branch,
+            create,
+            base,
+            sparse: None,
+            orphan: false,
+            devcontainer: false,
+            detach: false,
+            execute: None,
+            execute_args: &[],
+            open: None,
+            yes,
+            clobber: false,
+            change_dir: true,
+            verify,
+        },
+        config,
+        binary_name,
+    )?;
+    if let Some(issue) = &issue {
+        record_issue_note(&repo, &branch, issue)?;
+    }
+
+    let worktree_path =
+        repo.worktree_for_branch(&branch)?
+            .ok_or_else(|| GitError::WorktreeMissing {
+                branch: branch.clone(),
+            })?;
+    let ctx = CommandContext::new(&repo, config, Some(&branch), &worktree_path, yes);
+    let extra_vars: Vec<(&str, &str)> = issue_vars.iter().map(|(k, v)| (*k, v.as_str())).collect();
+    let template_vars = build_hook_context(&ctx, &extra_vars);
+    let vars: HashMap<&str, &str> = template_vars
+        .iter()
+        .map(|(k, v)| (k.as_str(), v.as_str()))
+        .collect();
+
+    // Setup: blocking command in the new worktree; failures abort so the
+    // user can fix and rerun
+    if run_setup && let Some(setup) = start_config.setup() {
+        let command = expand_template(setup, &vars, true, &repo, "start setup command")?;
+        eprintln!("{}", progress_message("Running setup..."));
+        eprintln!("{}", format_bash_with_gutter(&command));
+        execute_command_in_worktree(&worktree_path, &command, None, None, None)?;
+        completed.push(StartStep::Setup);
+        record_steps(&repo, &branch, &completed)?;
+    }
+
+    if run_publish {
+        let push_config = config.push(project_id.as_deref()).unwrap_or_default();
+        publish_draft(
+            &repo,
+            &branch,
+            &worktree_path,
+            issue.as_ref(),
+            &push_config,
+            yes,
+        )?;
+        completed.push(StartStep::Publish);
+        record_steps(&repo, &branch, &completed)?;
+    }
+
+    // Everything that can fail is done; open replaces the process, so clear first
+    clear_progress(&repo, &branch);
+
+    if run_open && let Some(open) = start_config.open() {
+        let command = expand_template(open, &vars, true, &repo, "start open command")?;
+        execute_user_command(&command, post_hook_display_path(&worktree_path), None)?;
+    }
+
+    Ok(())
+}
+
+/// Push the branch and open a draft PR/MR.
+fn publish_draft(
+    repo: &Repository,
+    branch: &str,
+    worktree_path: &Path,
+    issue: Option<&Issue>,
+    push_config: &PushConfig,
+    yes: bool,
+) -> anyhow::Result<()> {
+    let platform = detect_platform(repo)?;
+
+    // Forges reject PRs without changes; give a fresh branch an empty commit
+    if let Some(default_branch) = repo.default_branch()
+        && repo.count_commits(&default_branch, branch)? == 0
+    {
+        let message = match issue {
+            Some(issue) => format!("Start {}: {}", issue.reference(), issue.title),
+            None => format!("Start {branch}"),
+        };
+        repo.worktree_at(worktree_path).run_command(&[
+            "commit",
+            "--allow-empty",
+            "-m",
+            &message,
+        ])?;
+    }
+
+    push_with_upstream(repo, worktree_path, branch, push_config, yes)?;
+
+    let body = issue.map(|issue| format!("Closes {}", issue.reference()));
+    let url = create_pr(
+        platform,
+        worktree_path,
+        branch,
+        PrContent {
+            draft: true,
+            title: issue.map(|issue| issue.title.as_str()),
+            body: body.as_deref(),
+            base: None,
+        },
+    )?;
+    eprintln!(
+        "{}",
+        success_message(cformat!("Opened draft for <bold>{branch}</> {url}"))
+    );
+    Ok(())
+}
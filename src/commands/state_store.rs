@@ -0,0 +1,19 @@
+//! `wt state` — debug view of the per-repository state store.
+//!
+//! Prints `.git/worktrunk/state.json` as the running worktrunk sees it, i.e.
+//! after schema migration, without writing anything back.
+
+use worktrunk::git::Repository;
+use worktrunk::state::StateStore;
+
+pub(crate) fn handle_state_store(path: bool) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let store = StateStore::new(repo.git_common_dir());
+    if path {
+        println!("{}", store.path().display());
+        return Ok(());
+    }
+    let state = store.load()?;
+    worktrunk::styling::print_json(&state)?;
+    Ok(())
+}
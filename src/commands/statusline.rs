@@ -17,6 +17,7 @@ use anyhow::{Context, Result};
 use worktrunk::git::Repository;
 use worktrunk::styling::{fix_dim_after_color_reset, get_terminal_width, truncate_visible};
 
+use super::list::collect::TaskKind;
 use super::list::{self, CollectOptions, StatuslineSegment, json_output};
 use crate::cli::OutputFormat;
 
@@ -152,7 +153,8 @@ fn format_context_gauge(percentage: f64) -> String {
 /// Run the statusline command.
 ///
 /// Output uses `println!` for raw stdout (bypasses anstream color detection).
-/// Shell prompts (PS1) and Claude Code always expect ANSI codes.
+/// Shell prompts (PS1) and Claude Code always expect ANSI codes, so only
+/// `--color=never` makes it plain.
 pub fn run(format: OutputFormat) -> Result<()> {
     // JSON format: output current worktree as JSON
     if matches!(format, OutputFormat::Json) {
@@ -244,7 +246,11 @@ pub fn run(format: OutputFormat) -> Result<()> {
     let output = fix_dim_after_color_reset(&output);
     let output = truncate_visible(&format!("{reset} {output}"), max_width);
 
-    println!("{}", output);
+    if worktrunk::styling::color_never() {
+        println!("{}", anstream::adapter::strip_str(&output));
+    } else {
+        println!("{}", output);
+    }
 
     Ok(())
 }
@@ -293,9 +299,13 @@ fn run_json() -> Result<()> {
     // Load URL template from project config (if configured)
     let url_template = repo.url_template();
 
-    // Build collect options with URL template (compute everything for complete data)
+    // Build collect options with URL template (compute everything except sizes and health)
     let options = CollectOptions {
         url_template,
+        // Directory walks are too slow for a statusline, and it has no Health column
+        skip_tasks: [TaskKind::DiskUsage, TaskKind::Health]
+            .into_iter()
+            .collect(),
         ..Default::default()
     };
 
@@ -306,8 +316,7 @@ fn run_json() -> Result<()> {
     let json_item = json_output::JsonItem::from_list_item(&item);
 
     // Output as JSON array (consistent with wt list --format=json)
-    let output = serde_json::to_string_pretty(&[json_item])?;
-    println!("{output}");
+    worktrunk::styling::print_json(&[json_item])?;
 
     Ok(())
 }
@@ -394,6 +403,10 @@ fn get_git_status_segments(
     // Build collect options with URL template
     let options = CollectOptions {
         url_template,
+        // Directory walks are too slow for a statusline, and it has no Health column
+        skip_tasks: [TaskKind::DiskUsage, TaskKind::Health]
+            .into_iter()
+            .collect(),
         ..Default::default()
     };
 
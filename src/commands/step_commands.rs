@@ -5,6 +5,7 @@
 //! - `handle_squash` - Squash commits into one
 //! - `step_show_squash_prompt` - Show squash prompt without executing
 //! - `handle_rebase` - Rebase onto target branch
+//! - `run_rebase` - The rebase itself, shared with `wt absorb`
 //! - `step_copy_ignored` - Copy gitignored files matching .worktreeinclude
 
 use std::fs;
@@ -17,6 +18,7 @@ use ignore::gitignore::GitignoreBuilder;
 use worktrunk::HookType;
 use worktrunk::config::UserConfig;
 use worktrunk::git::Repository;
+use worktrunk::state::Operation;
 use worktrunk::styling::{
     eprintln, format_with_gutter, hint_message, info_message, progress_message, success_message,
 };
@@ -26,6 +28,8 @@ use super::commit::{CommitGenerator, CommitOptions, StageMode};
 use super::context::CommandEnv;
 use super::hooks::{HookFailureStrategy, run_hook_with_filter};
 use super::repository_ext::RepositoryCliExt;
+use super::snapshot::auto_snapshot;
+use super::undo::record;
 use worktrunk::shell_exec::Cmd;
 
 /// Handle `wt step commit` command
@@ -161,6 +165,9 @@ pub fn handle_squash(
     // Get and validate target ref (any commit-ish for merge-base calculation)
     let integration_target = repo.require_target_ref(target)?;
 
+    // Snapshot before staging, so undo brings back what was staged and what wasn't
+    let snapshot = auto_snapshot(&repo.current_worktree(), "squash");
+
     // Auto-stage changes before running pre-commit hooks so both beta and merge paths behave identically
     match stage_mode {
         StageMode::All => {
@@ -310,6 +317,7 @@ pub fn handle_squash(
     // the consequence is minor (unexpected content in squash commit). The commit message
     // generated above accurately reflects the original commits being squashed, so any
     // discrepancy would be visible in the diff. Considered acceptable risk.
+    let before = repo.run_command(&["rev-parse", "HEAD"])?.trim().to_string();
     repo.run_command(&["reset", "--soft", &merge_base])
         .context("Failed to reset to merge base")?;
 
@@ -324,9 +332,28 @@ pub fn handle_squash(
         return Ok(SquashResult::NoNetChanges);
     }
 
-    // Commit with the generated message
-    repo.run_command(&["commit", "-m", &commit_message])
-        .context("Failed to create squash commit")?;
+    // Commit with the generated message, signed when `commit.gpgsign` is on
+    let mut commit_args = vec!["commit", "-m", commit_message.as_str()];
+    commit_args.extend(repo.signing_args());
+    if let Err(e) = repo.run_command(&commit_args) {
+        // Put the branch back so a failed commit doesn't leave it at the merge base
+        let _ = repo.run_command(&["reset", "--soft", &before]);
+        return Err(repo
+            .signing_error("squash", e)
+            .context("Failed to create squash commit"));
+    }
+
+    let after = repo.run_command(&["rev-parse", "HEAD"])?.trim().to_string();
+    record(
+        repo,
+        Operation::RefMoved {
+            branch: current_branch.clone(),
+            before,
+            after,
+            operation: "squash".into(),
+            snapshot,
+        },
+    );
 
     // Get commit hash for display
     let commit_hash = repo
@@ -422,10 +449,62 @@ pub fn handle_rebase(target: Option<&str>) -> anyhow::Result<RebaseResult> {
         );
     }
 
-    let rebase_result = repo.run_command(&["rebase", &integration_target]);
+    let snapshot = auto_snapshot(&repo.current_worktree(), "rebase");
+    run_rebase(&repo, &[], &integration_target)?;
+
+    if let Some(branch) = repo.current_worktree().branch()?
+        && let Ok(after) = repo.run_command(&["rev-parse", "HEAD"])
+    {
+        record(
+            &repo,
+            Operation::RefMoved {
+                branch,
+                before: head_sha,
+                after: after.trim().to_string(),
+                operation: "rebase".into(),
+                snapshot,
+            },
+        );
+    }
+
+    // Success
+    let msg = if is_fast_forward {
+        cformat!("Fast-forwarded to <bold>{integration_target}</>")
+    } else {
+        cformat!("Rebased onto <bold>{integration_target}</>")
+    };
+    eprintln!("{}", success_message(msg));
+
+    Ok(RebaseResult::Rebased)
+}
+
+/// Run `git rebase <extra_args> <onto>`, mapping failures to typed errors.
+///
+/// A signing failure aborts the rebase and returns
+/// [`GitError::SigningFailed`](worktrunk::git::GitError::SigningFailed); a
+/// stop on conflicts leaves the rebase in progress and returns
+/// [`GitError::RebaseConflict`](worktrunk::git::GitError::RebaseConflict).
+pub(crate) fn run_rebase(repo: &Repository, extra_args: &[&str], onto: &str) -> anyhow::Result<()> {
+    // An interactive rebase (e.g. `--autosquash`) takes the todo list as is
+    let mut rebase_args = if extra_args.contains(&"--interactive") {
+        vec!["-c", "sequence.editor=:", "rebase"]
+    } else {
+        vec!["rebase"]
+    };
+    // Rebased commits are re-signed when `commit.gpgsign` is on
+    rebase_args.extend(repo.signing_args());
+    rebase_args.extend(extra_args);
+    rebase_args.push(onto);
+    let rebase_result = repo.run_command(&rebase_args);
 
-    // If rebase failed, check if it's due to conflicts
+    // If rebase failed, check if it's due to signing or conflicts
     if let Err(e) = rebase_result {
+        let e = repo.signing_error("rebase", e);
+        if let Some(worktrunk::git::GitError::SigningFailed { .. }) = e.downcast_ref() {
+            // A signing failure stops the rebase mid-way; restore the branch
+            let _ = repo.run_command(&["rebase", "--abort"]);
+            return Err(e);
+        }
         // Check if it's a rebase conflict
         let is_rebasing = repo
             .worktree_state()?
@@ -434,18 +513,15 @@ pub fn handle_rebase(target: Option<&str>) -> anyhow::Result<RebaseResult> {
             // Extract git's stderr output from the error
             let git_output = e.to_string();
             return Err(worktrunk::git::GitError::RebaseConflict {
-                target_branch: integration_target,
+                target_branch: onto.to_string(),
                 git_output,
+                source: worktrunk::git::GitCommandError::find(&e).cloned(),
             }
             .into());
         }
         // Not a rebase conflict, return original error
         return Err(worktrunk::git::GitError::Other {
-            message: cformat!(
-                "Failed to rebase onto <bold>{}</>: {}",
-                integration_target,
-                e
-            ),
+            message: cformat!("Failed to rebase onto <bold>{}</>: {}", onto, e),
         }
         .into());
     }
@@ -453,21 +529,14 @@ pub fn handle_rebase(target: Option<&str>) -> anyhow::Result<RebaseResult> {
     // Verify rebase completed successfully (safety check for edge cases)
     if repo.worktree_state()?.is_some() {
         return Err(worktrunk::git::GitError::RebaseConflict {
-            target_branch: integration_target,
+            target_branch: onto.to_string(),
             git_output: String::new(),
+            source: None,
         }
         .into());
     }
 
-    // Success
-    let msg = if is_fast_forward {
-        cformat!("Fast-forwarded to <bold>{integration_target}</>")
-    } else {
-        cformat!("Rebased onto <bold>{integration_target}</>")
-    };
-    eprintln!("{}", success_message(msg));
-
-    Ok(RebaseResult::Rebased)
+    Ok(())
 }
 
 /// Handle `wt step copy-ignored` command
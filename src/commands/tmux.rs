@@ -0,0 +1,278 @@
+//! tmux integration: one window per worktree.
+//!
+//! `wt tmux [BRANCH]` focuses the worktree's window, creating it (started in
+//! the worktree directory) if needed. Inside tmux, windows go in the current
+//! session; outside, in a session named by `[tmux] session`, which is created
+//! and attached. `wt tmux sync` creates windows for every worktree without
+//! switching to them.
+//!
+//! Windows are matched by name, so renaming a window detaches it from its
+//! worktree — the next `wt tmux` creates a fresh one.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::process::Stdio;
+
+use anyhow::Context;
+use color_print::cformat;
+use worktrunk::config::{TmuxConfig, UserConfig, expand_template};
+use worktrunk::git::{GitError, Repository, ResolvedWorktree, WorktreeInfo};
+use worktrunk::shell_exec::Cmd;
+use worktrunk::styling::{eprintln, info_message, success_message};
+
+use super::command_executor::{CommandContext, build_hook_context};
+use super::worktree::{OperationMode, resolve_worktree_arg};
+
+/// Handle `wt tmux [BRANCH]`: open or focus the worktree's window.
+pub fn handle_tmux(branch: Option<&str>, config: &UserConfig) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let tmux_config = tmux_config(&repo, config);
+
+    let (path, branch) = match branch {
+        Some(name) => match resolve_worktree_arg(&repo, name, config, OperationMode::Remove)? {
+            ResolvedWorktree::Worktree { path, branch } => (path, branch),
+            ResolvedWorktree::BranchOnly { branch } => {
+                return Err(GitError::WorktreeNotFound { branch }.into());
+            }
+        },
+        None => (
+            repo.current_worktree().root()?,
+            repo.current_worktree().branch()?,
+        ),
+    };
+
+    let session = session_name(&repo, config, &tmux_config, &path, branch.as_deref())?;
+    let window = window_name(&repo, config, &tmux_config, &path, branch.as_deref())?;
+
+    let created = ensure_session_window(&session, &window, &path)?;
+    tmux(&["select-window", "-t", &window_target(&session, &window)])?;
+
+    let verb = if created { "Opened" } else { "Focused" };
+    eprintln!(
+        "{}",
+        success_message(cformat!("{verb} tmux window <bold>{window}</>"))
+    );
+
+    if !inside_tmux() {
+        // Outside tmux, attach so the user lands in the window
+        Cmd::new("tmux")
+            .args(["attach-session", "-t", &session_target(&session)])
+            .stdin(Stdio::inherit())
+            .stream()
+            .context("Failed to attach to tmux session")?;
+    }
+    Ok(())
+}
+
+/// Handle `wt tmux sync`: create a window for every worktree.
+pub fn handle_tmux_sync(config: &UserConfig) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let tmux_config = tmux_config(&repo, config);
+    // Worktrees whose directory was deleted can't host a window
+    let worktrees: Vec<WorktreeInfo> = repo
+        .list_worktrees()?
+        .into_iter()
+        .filter(|wt| !wt.bare && !wt.is_prunable())
+        .collect();
+
+    let mut created = Vec::new();
+    for wt in &worktrees {
+        let branch = wt.branch.as_deref();
+        let session = session_name(&repo, config, &tmux_config, &wt.path, branch)?;
+        let window = window_name(&repo, config, &tmux_config, &wt.path, branch)?;
+        if ensure_session_window(&session, &window, &wt.path)? {
+            created.push(window);
+        }
+    }
+
+    if created.is_empty() {
+        eprintln!(
+            "{}",
+            info_message(format!(
+                "All {} worktrees already have tmux windows",
+                worktrees.len()
+            ))
+        );
+    } else {
+        eprintln!(
+            "{}",
+            success_message(cformat!(
+                "Created tmux windows: <bold>{}</>",
+                created.join(", ")
+            ))
+        );
+    }
+    Ok(())
+}
+
+fn tmux_config(repo: &Repository, config: &UserConfig) -> TmuxConfig {
+    let project_id = repo.project_identifier().ok();
+    config.tmux(project_id.as_deref()).unwrap_or_default()
+}
+
+/// Create the session and window if missing. Returns whether the window was created.
+fn ensure_session_window(session: &str, window: &str, path: &Path) -> anyhow::Result<bool> {
+    let path = path.to_string_lossy();
+    if !inside_tmux() && !session_exists(session) {
+        tmux(&[
+            "new-session",
+            "-d",
+            "-s",
+            session,
+            "-n",
+            window,
+            "-c",
+            &path,
+        ])?;
+        return Ok(true);
+    }
+    if window_names(session)?.iter().any(|name| name == window) {
+        return Ok(false);
+    }
+    // `session:` (trailing colon) appends at the next free index
+    tmux(&[
+        "new-window",
+        "-d",
+        "-t",
+        &format!("{}:", session_target(session)),
+        "-n",
+        window,
+        "-c",
+        &path,
+    ])?;
+    Ok(true)
+}
+
+fn inside_tmux() -> bool {
+    std::env::var_os("TMUX").is_some_and(|v| !v.is_empty())
+}
+
+fn session_exists(session: &str) -> bool {
+    tmux(&["has-session", "-t", &session_target(session)]).is_ok()
+}
+
+fn window_names(session: &str) -> anyhow::Result<Vec<String>> {
+    let output = tmux(&[
+        "list-windows",
+        "-t",
+        &session_target(session),
+        "-F",
+        "#{window_name}",
+    ])?;
+    Ok(output.lines().map(str::to_string).collect())
+}
+
+/// Exact-match target (`=name`), so `feat` doesn't match a `feature` session.
+fn session_target(session: &str) -> String {
+    format!("={session}")
+}
+
+fn window_target(session: &str, window: &str) -> String {
+    format!("={session}:={window}")
+}
+
+/// Run tmux and return stdout, turning a missing binary or failure into an error.
+fn tmux(args: &[&str]) -> anyhow::Result<String> {
+    let output = Cmd::new("tmux")
+        .args(args.iter().copied())
+        .run()
+        .map_err(|e| {
+            if e.kind() == std::io::ErrorKind::NotFound {
+                anyhow::Error::from(GitError::Other {
+                    message: "tmux not found; install tmux to use wt tmux".into(),
+                })
+            } else {
+                anyhow::Error::from(e).context("Failed to run tmux")
+            }
+        })?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(GitError::Other {
+            message: format!("tmux {} failed: {}", args[0], stderr.trim()),
+        }
+        .into());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Session to use: the current one inside tmux, else the `[tmux] session` template.
+fn session_name(
+    repo: &Repository,
+    config: &UserConfig,
+    tmux_config: &TmuxConfig,
+    path: &Path,
+    branch: Option<&str>,
+) -> anyhow::Result<String> {
+    if inside_tmux() {
+        return Ok(tmux(&["display-message", "-p", "#{session_name}"])?
+            .trim()
+            .to_string());
+    }
+    expand_name(
+        repo,
+        config,
+        tmux_config.session(),
+        path,
+        branch,
+        "tmux session",
+    )
+}
+
+fn window_name(
+    repo: &Repository,
+    config: &UserConfig,
+    tmux_config: &TmuxConfig,
+    path: &Path,
+    branch: Option<&str>,
+) -> anyhow::Result<String> {
+    expand_name(
+        repo,
+        config,
+        tmux_config.window(),
+        path,
+        branch,
+        "tmux window",
+    )
+}
+
+/// Expand a name template with hook variables and make it usable as a tmux target.
+fn expand_name(
+    repo: &Repository,
+    config: &UserConfig,
+    template: &str,
+    path: &Path,
+    branch: Option<&str>,
+    name: &str,
+) -> anyhow::Result<String> {
+    let ctx = CommandContext::new(repo, config, branch, path, false);
+    let context_map = build_hook_context(&ctx, &[]);
+    let vars: HashMap<&str, &str> = context_map
+        .iter()
+        .map(|(k, v)| (k.as_str(), v.as_str()))
+        .collect();
+    let expanded = expand_template(template, &vars, false, repo, name)?;
+    Ok(sanitize_tmux_name(&expanded))
+}
+
+/// tmux reads `.` and `:` in targets as window/pane separators.
+fn sanitize_tmux_name(name: &str) -> String {
+    name.trim().replace(['.', ':'], "-")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_tmux_name() {
+        assert_eq!(sanitize_tmux_name("feature/auth"), "feature/auth");
+        assert_eq!(sanitize_tmux_name("release-1.2"), "release-1-2");
+        assert_eq!(sanitize_tmux_name(" repo:main "), "repo-main");
+    }
+
+    #[test]
+    fn test_targets_match_exactly() {
+        assert_eq!(session_target("repo"), "=repo");
+        assert_eq!(window_target("repo", "feat"), "=repo:=feat");
+    }
+}
@@ -0,0 +1,218 @@
+//! `wt undo`: reverse the most recent mutating operation.
+//!
+//! Operations append to the journal in the state store (`.git/worktrunk/state.json`)
+//! as they complete. Undo reverses the newest entry and drops it, so repeated
+//! `wt undo` walks back through history. Each reversal checks that nothing
+//! has moved since — a branch that gained commits or a worktree with changes
+//! is left alone rather than clobbered.
+
+use anyhow::Context;
+use color_print::cformat;
+use worktrunk::git::{GitError, Repository};
+use worktrunk::path::format_path_for_display;
+use worktrunk::state::{JournalEntry, Operation, StateStore};
+use worktrunk::styling::{eprintln, info_message, success_message};
+
+use super::snapshot::{apply_snapshot, restore_snapshot};
+use crate::display::format_relative_time_short;
+
+/// Record a completed operation for `wt undo`.
+///
+/// Best-effort: a journal write failure never fails the operation itself.
+pub(crate) fn record(repo: &Repository, operation: Operation) {
+    StateStore::new(repo.git_common_dir()).record(operation);
+}
+
+/// Handle `wt undo`.
+pub fn handle_undo(list: bool) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let store = StateStore::new(repo.git_common_dir());
+    let mut journal = store.load()?.journal;
+    if list {
+        if journal.is_empty() {
+            eprintln!("{}", info_message("Nothing to undo"));
+        }
+        for entry in journal.iter().rev() {
+            println!(
+                "{}  {}",
+                format_relative_time_short(entry.at as i64),
+                describe(entry)
+            );
+        }
+        return Ok(());
+    }
+    let Some(entry) = journal.pop() else {
+        eprintln!("{}", info_message("Nothing to undo"));
+        return Ok(());
+    };
+
+    let _lock = repo.lock_operation("undo")?;
+    let message = reverse(&repo, &entry.operation)?;
+
+    // Drop the entry only if no other process appended since
+    store.update(|state| {
+        if state.journal.last() == Some(&entry) {
+            state.journal.pop();
+        }
+    })?;
+    eprintln!("{}", success_message(message));
+    Ok(())
+}
+
+/// Reverse one operation, returning the success message.
+fn reverse(repo: &Repository, operation: &Operation) -> anyhow::Result<String> {
+    match operation {
+        Operation::WorktreeCreated {
+            branch,
+            path,
+            commit,
+            created_branch,
+        } => {
+            let path_display = format_path_for_display(path);
+            if path.exists() {
+                // No --force: git refuses if the worktree has changes
+                repo.remove_worktree(path, false)
+                    .map_err(|e| GitError::Other {
+                        message: cformat!("Cannot undo creation of <bold>{path_display}</>: {e}"),
+                    })?;
+            }
+            let tip = branch_tip(repo, branch);
+            if *created_branch && tip.as_deref() == Some(commit.as_str()) {
+                repo.run_command(&["branch", "-D", branch])?;
+                Ok(cformat!(
+                    "Removed worktree & branch <bold>{branch}</> (undid creation)"
+                ))
+            } else {
+                Ok(cformat!(
+                    "Removed worktree for <bold>{branch}</> @ <bold>{path_display}</> (undid creation)"
+                ))
+            }
+        }
+        Operation::WorktreeRemoved {
+            branch,
+            path,
+            commit,
+            snapshot,
+        } => {
+            let path_display = format_path_for_display(path);
+            if path.exists() {
+                return Err(GitError::WorktreePathOccupied {
+                    branch: branch.clone().unwrap_or_else(|| "HEAD".into()),
+                    path: path.clone(),
+                    occupant: None,
+                }
+                .into());
+            }
+            let path_str = path.to_string_lossy();
+            match branch {
+                Some(branch) => {
+                    let restored_branch = branch_tip(repo, branch).is_none();
+                    if restored_branch {
+                        repo.run_command(&["branch", branch, commit])
+                            .context("Failed to restore branch")?;
+                    }
+                    repo.run_command(&["worktree", "add", &path_str, branch])?;
+                    let what = if restored_branch {
+                        "worktree & branch"
+                    } else {
+                        "worktree"
+                    };
+                    let with_changes = match snapshot {
+                        Some(sha) => {
+                            apply_snapshot(&repo.worktree_at(path), sha)
+                                .context("Failed to restore uncommitted changes")?;
+                            " with its uncommitted changes"
+                        }
+                        None => "",
+                    };
+                    Ok(cformat!(
+                        "Restored {what} <bold>{branch}</> @ <bold>{path_display}</>{with_changes}"
+                    ))
+                }
+                None => {
+                    repo.run_command(&["worktree", "add", "--detach", &path_str, commit])?;
+                    Ok(cformat!(
+                        "Restored detached worktree @ <bold>{path_display}</>"
+                    ))
+                }
+            }
+        }
+        Operation::RefMoved {
+            branch,
+            before,
+            after,
+            operation,
+            snapshot,
+        } => {
+            if branch_tip(repo, branch).as_deref() != Some(after.as_str()) {
+                return Err(GitError::Other {
+                    message: cformat!(
+                        "Cannot undo {operation} of <bold>{branch}</>: it has moved since"
+                    ),
+                }
+                .into());
+            }
+            let short = &before[..before.len().min(7)];
+            match (repo.worktree_for_branch(branch)?, snapshot) {
+                // The snapshot has the uncommitted changes from before the
+                // operation, staged and unstaged as they were
+                (Some(path), Some(sha)) => {
+                    restore_snapshot(
+                        &repo.worktree_at(&path),
+                        branch,
+                        before,
+                        sha,
+                        &format!("Before undoing {operation}"),
+                    )?;
+                    return Ok(cformat!(
+                        "Reset <bold>{branch}</> to <dim>{short}</> and restored its uncommitted changes (undid {operation})"
+                    ));
+                }
+                (Some(path), None) => {
+                    // A squash folded uncommitted changes into its commit, so a
+                    // soft reset restores them as staged changes; otherwise keep
+                    // local changes and refuse if they'd be overwritten
+                    let mode = if operation == "squash" {
+                        "--soft"
+                    } else {
+                        "--keep"
+                    };
+                    repo.worktree_at(&path)
+                        .run_command(&["reset", mode, before])?;
+                }
+                (None, _) => {
+                    let full_ref = format!("refs/heads/{branch}");
+                    repo.run_command(&["update-ref", &full_ref, before, after])?;
+                }
+            }
+            Ok(cformat!(
+                "Reset <bold>{branch}</> to <dim>{short}</> (undid {operation})"
+            ))
+        }
+    }
+}
+
+fn branch_tip(repo: &Repository, branch: &str) -> Option<String> {
+    repo.run_command(&[
+        "rev-parse",
+        "--verify",
+        "--quiet",
+        &format!("refs/heads/{branch}"),
+    ])
+    .ok()
+    .map(|sha| sha.trim().to_string())
+}
+
+/// One-line description of a journal entry, for `wt undo --list`.
+fn describe(entry: &JournalEntry) -> String {
+    match &entry.operation {
+        Operation::WorktreeCreated { branch, .. } => cformat!("create <bold>{branch}</>"),
+        Operation::WorktreeRemoved { branch, path, .. } => match branch {
+            Some(branch) => cformat!("remove <bold>{branch}</>"),
+            None => cformat!("remove <bold>{}</>", format_path_for_display(path)),
+        },
+        Operation::RefMoved {
+            branch, operation, ..
+        } => cformat!("{operation} <bold>{branch}</>"),
+    }
+}
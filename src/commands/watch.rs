@@ -0,0 +1,245 @@
+//! Filesystem watcher behind `wt daemon`'s working tree status cache.
+//!
+//! Watches every worktree (and, for linked worktrees, the git dir holding its
+//! index) and bumps the worktree's generation in
+//! [`status_cache`](super::list::status_cache) whenever something in it
+//! changes, so `wt list` can reuse the last `git status` of worktrees nobody
+//! touched. Changes inside git dirs other than to `index` and `HEAD` —
+//! objects, refs, worktrunk's own caches — don't affect status and are
+//! ignored.
+//!
+//! While it runs, the watcher holds the status cache's lock and records which
+//! worktrees it actually watches; `wt list` only trusts cached statuses of
+//! those worktrees while the lock is held, so a stopped or crashed watcher —
+//! or a worktree it failed to watch — can't leave stale results behind. When
+//! the watcher reports an error or asks for a rescan (e.g. the event queue
+//! overflowed), every worktree's generation is bumped.
+
+use std::fs::File;
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use fs2::FileExt;
+use notify::{Event, RecommendedWatcher, RecursiveMode, Watcher};
+use worktrunk::git::Repository;
+
+use super::list::status_cache;
+
+/// A watched worktree, with paths as the filesystem reports them in events.
+#[derive(Debug, Clone)]
+struct Watched {
+    /// Path as `git worktree list` reports it — the status cache key
+    path: PathBuf,
+    root: PathBuf,
+    git_dir: PathBuf,
+}
+
+pub(crate) struct StatusWatcher {
+    watcher: RecommendedWatcher,
+    watched: Arc<Mutex<Vec<Watched>>>,
+    git_common_dir: PathBuf,
+    _lock: File,
+}
+
+impl StatusWatcher {
+    /// Start watching the repository's worktrees.
+    ///
+    /// Returns `None` when another watcher already holds the lock.
+    pub(crate) fn start(repo: &Repository) -> anyhow::Result<Option<Self>> {
+        let git_common_dir = repo.git_common_dir().to_path_buf();
+        let lock_path = status_cache::watcher_lock_path(&git_common_dir);
+        if let Some(parent) = lock_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let lock = File::create(&lock_path)?;
+        if lock.try_lock_exclusive().is_err() {
+            return Ok(None);
+        }
+        // A crashed watcher's list is stale until the first sync
+        status_cache::set_watched(&git_common_dir, std::iter::empty());
+
+        let watched: Arc<Mutex<Vec<Watched>>> = Arc::default();
+        let watcher = {
+            let watched = Arc::clone(&watched);
+            let git_common_dir = git_common_dir.clone();
+            notify::recommended_watcher(move |event: notify::Result<Event>| {
+                let watched = watched.lock().unwrap().clone();
+                // Events may have been lost; assume everything changed
+                let event = match event {
+                    Ok(event) if !event.need_rescan() => event,
+                    Ok(_) => {
+                        log::debug!("watch: rescan requested");
+                        bump_all(&git_common_dir, &watched);
+                        return;
+                    }
+                    Err(e) => {
+                        log::debug!("watch: {e}");
+                        bump_all(&git_common_dir, &watched);
+                        return;
+                    }
+                };
+                let mut changed: Vec<&Path> = event
+                    .paths
+                    .iter()
+                    .filter_map(|path| affected(path, &watched))
+                    .collect();
+                changed.dedup();
+                for worktree in changed {
+                    status_cache::bump_generation(&git_common_dir, worktree);
+                }
+            })?
+        };
+
+        let mut watcher = Self {
+            watcher,
+            watched,
+            git_common_dir,
+            _lock: lock,
+        };
+        watcher.sync(repo);
+        Ok(Some(watcher))
+    }
+
+    /// Watch worktrees added since the last sync and forget removed ones.
+    pub(crate) fn sync(&mut self, repo: &Repository) {
+        let Ok(worktrees) = repo.list_worktrees() else {
+            return;
+        };
+        let current: Vec<PathBuf> = worktrees
+            .into_iter()
+            .filter(|wt| !wt.bare && !wt.is_prunable())
+            .map(|wt| wt.path)
+            .collect();
+
+        let mut watched = self.watched.lock().unwrap();
+        for gone in watched
+            .iter()
+            .filter(|w| !current.contains(&w.path))
+            .cloned()
+            .collect::<Vec<_>>()
+        {
+            let _ = self.watcher.unwatch(&gone.root);
+            if !gone.git_dir.starts_with(&gone.root) {
+                let _ = self.watcher.unwatch(&gone.git_dir);
+            }
+            watched.retain(|w| w.path != gone.path);
+        }
+
+        for path in current {
+            if watched.iter().any(|w| w.path == path) {
+                continue;
+            }
+            let Some(entry) = watch_target(repo, &path) else {
+                continue;
+            };
+            // Left out of the watched list, so its status is never cached
+            if let Err(e) = watch(&mut self.watcher, &entry) {
+                log::debug!("watch: can't watch {}: {e}", path.display());
+                continue;
+            }
+            // Changes made while nothing watched went unrecorded
+            status_cache::bump_generation(&self.git_common_dir, &path);
+            watched.push(entry);
+        }
+        status_cache::set_watched(
+            &self.git_common_dir,
+            watched.iter().map(|w| w.path.as_path()),
+        );
+    }
+}
+
+/// Watch a worktree and, for a linked worktree, the git dir holding its
+/// index — both or neither.
+fn watch(watcher: &mut RecommendedWatcher, entry: &Watched) -> notify::Result<()> {
+    watcher.watch(&entry.root, RecursiveMode::Recursive)?;
+    if !entry.git_dir.starts_with(&entry.root)
+        && let Err(e) = watcher.watch(&entry.git_dir, RecursiveMode::NonRecursive)
+    {
+        let _ = watcher.unwatch(&entry.root);
+        return Err(e);
+    }
+    Ok(())
+}
+
+/// Invalidate every watched worktree's cached status.
+fn bump_all(git_common_dir: &Path, watched: &[Watched]) {
+    for worktree in watched {
+        status_cache::bump_generation(git_common_dir, &worktree.path);
+    }
+}
+
+fn watch_target(repo: &Repository, path: &Path) -> Option<Watched> {
+    let git_dir = repo
+        .worktree_at(path)
+        .run_command(&["rev-parse", "--absolute-git-dir"])
+        .ok()?;
+    Some(Watched {
+        path: path.to_path_buf(),
+        root: dunce::canonicalize(path).ok()?,
+        git_dir: dunce::canonicalize(git_dir.trim()).ok()?,
+    })
+}
+
+/// The worktree whose status a change to `path` may affect.
+fn affected<'a>(path: &Path, watched: &'a [Watched]) -> Option<&'a Path> {
+    // Inside a worktree's git dir only its index and HEAD matter
+    if let Some(owner) = watched
+        .iter()
+        .filter(|w| path.starts_with(&w.git_dir))
+        .max_by_key(|w| w.git_dir.components().count())
+    {
+        let name = path.file_name()?;
+        return (path.parent() == Some(&owner.git_dir) && (name == "index" || name == "HEAD"))
+            .then_some(owner.path.as_path());
+    }
+    // Some other git dir: a submodule's, or one we don't know about
+    if path
+        .components()
+        .any(|c| c == Component::Normal(".git".as_ref()))
+    {
+        return None;
+    }
+    // Nested worktrees: the innermost one contains the change
+    watched
+        .iter()
+        .filter(|w| path.starts_with(&w.root))
+        .max_by_key(|w| w.root.components().count())
+        .map(|w| w.path.as_path())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_affected() {
+        let watched = vec![
+            Watched {
+                path: PathBuf::from("/repo"),
+                root: PathBuf::from("/repo"),
+                git_dir: PathBuf::from("/repo/.git"),
+            },
+            Watched {
+                path: PathBuf::from("/repo/.worktrees/feature"),
+                root: PathBuf::from("/repo/.worktrees/feature"),
+                git_dir: PathBuf::from("/repo/.git/worktrees/feature"),
+            },
+        ];
+        let affected = |path: &str| affected(Path::new(path), &watched).map(Path::to_path_buf);
+
+        assert_eq!(affected("/repo/src/lib.rs"), Some("/repo".into()));
+        assert_eq!(
+            affected("/repo/.worktrees/feature/src/lib.rs"),
+            Some("/repo/.worktrees/feature".into())
+        );
+        assert_eq!(affected("/repo/.git/index"), Some("/repo".into()));
+        assert_eq!(
+            affected("/repo/.git/worktrees/feature/index"),
+            Some("/repo/.worktrees/feature".into())
+        );
+        assert_eq!(affected("/repo/.git/objects/ab/cdef"), None);
+        assert_eq!(affected("/repo/.git/wt-cache/status/repo.gen"), None);
+        assert_eq!(affected("/repo/vendor/lib/.git/index"), None);
+        assert_eq!(affected("/elsewhere/file"), None);
+    }
+}
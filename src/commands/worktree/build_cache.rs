@@ -0,0 +1,226 @@
+//! Shared build cache (`[build-cache]` in the project config).
+//!
+//! Each listed directory lives once in `.git/wt-cache/build-cache/<dir>` and
+//! is linked into new worktrees, so `target/` or `node_modules/` doesn't have
+//! to be rebuilt per worktree. The cache is seeded from the primary worktree
+//! (with hardlinks, so seeding copies no data) the first time it's needed.
+//!
+//! Safeguards: directories must be relative paths inside the worktree and
+//! gitignored, so tracked content is never replaced, and existing paths in
+//! the worktree are left alone.
+
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use color_print::cformat;
+use worktrunk::config::{BuildCacheMode, ProjectBuildCacheConfig};
+use worktrunk::git::{Repository, WorkingTree};
+use worktrunk::path::sanitize_for_filename;
+use worktrunk::styling::{eprintln, info_message, warning_message};
+
+/// Link the configured cache directories into a new worktree.
+///
+/// Best-effort: a directory that can't be linked is reported and left for
+/// the project's own setup (e.g. a `post-create` install) to build.
+pub(crate) fn link_build_cache(
+    repo: &Repository,
+    wt: &WorkingTree<'_>,
+    config: &ProjectBuildCacheConfig,
+) {
+    let primary = repo.primary_worktree().ok().flatten();
+    let mut linked = Vec::new();
+    for dir in &config.dirs {
+        match link_dir(repo, wt, primary.as_deref(), dir, config.mode) {
+            Ok(true) => linked.push(dir.as_str()),
+            Ok(false) => {}
+            Err(reason) => eprintln!(
+                "{}",
+                warning_message(cformat!("Skipped build cache for <bold>{dir}</>: {reason}"))
+            ),
+        }
+    }
+    if !linked.is_empty() {
+        let how = match config.mode {
+            BuildCacheMode::Symlink => "Symlinked",
+            BuildCacheMode::Hardlink => "Hardlinked",
+        };
+        eprintln!(
+            "{}",
+            info_message(cformat!(
+                "{how} <bold>{}</> from the shared build cache",
+                linked.join(", ")
+            ))
+        );
+    }
+}
+
+/// Link one directory. Returns `Ok(false)` when the worktree already has it.
+fn link_dir(
+    repo: &Repository,
+    wt: &WorkingTree<'_>,
+    primary: Option<&Path>,
+    dir: &str,
+    mode: BuildCacheMode,
+) -> Result<bool, String> {
+    let dir = dir.trim_end_matches('/');
+    let relative = validate_dir(dir)?;
+    // check-ignore consults the index, so tracked directories never count as ignored
+    if wt
+        .run_command(&["check-ignore", "-q", &format!("{dir}/")])
+        .is_err()
+    {
+        return Err("not gitignored; only ignored directories can be shared".into());
+    }
+    let dest = wt.path().join(relative);
+    if dest.symlink_metadata().is_ok() {
+        return Ok(false);
+    }
+
+    let cache = cache_dir(repo.git_common_dir(), dir);
+    if !cache.exists() {
+        seed_cache(&cache, primary.map(|p| p.join(relative)))
+            .map_err(|e| format!("failed to create cache: {e}"))?;
+    }
+    if let Some(parent) = dest.parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    match mode {
+        BuildCacheMode::Symlink => symlink_dir(&cache, &dest),
+        BuildCacheMode::Hardlink => hardlink_tree(&cache, &dest),
+    }
+    .map_err(|e| format!("failed to link: {e}"))?;
+    Ok(true)
+}
+
+/// Reject absolute paths and paths that escape the worktree.
+fn validate_dir(dir: &str) -> Result<&Path, String> {
+    let path = Path::new(dir);
+    if dir.is_empty() || !path.components().all(|c| matches!(c, Component::Normal(_))) {
+        return Err("must be a relative path inside the worktree".into());
+    }
+    Ok(path)
+}
+
+/// Cache location for a configured directory: `.git/wt-cache/build-cache/<dir>`.
+fn cache_dir(git_common_dir: &Path, dir: &str) -> PathBuf {
+    git_common_dir
+        .join("wt-cache")
+        .join("build-cache")
+        .join(sanitize_for_filename(dir))
+}
+
+/// Create the cache, starting from the primary worktree's copy when it has a
+/// real one (a symlink there already points at a cache).
+fn seed_cache(cache: &Path, source: Option<PathBuf>) -> std::io::Result<()> {
+    let source = source.filter(|s| s.symlink_metadata().is_ok_and(|m| m.is_dir()));
+    let Some(source) = source else {
+        return fs::create_dir_all(cache);
+    };
+    let temp = cache.with_extension("seeding");
+    let _ = fs::remove_dir_all(&temp);
+    hardlink_tree(&source, &temp)?;
+    // Rename last so an interrupted seed never leaves a partial cache
+    fs::rename(&temp, cache)
+}
+
+/// Recreate `src` at `dst` with directories copied and files hardlinked.
+fn hardlink_tree(src: &Path, dst: &Path) -> std::io::Result<()> {
+    fs::create_dir_all(dst)?;
+    for entry in fs::read_dir(src)? {
+        let entry = entry?;
+        let file_type = entry.file_type()?;
+        let target = dst.join(entry.file_name());
+        if file_type.is_dir() {
+            hardlink_tree(&entry.path(), &target)?;
+        } else if file_type.is_symlink() {
+            copy_symlink(&entry.path(), &target)?;
+        } else {
+            fs::hard_link(entry.path(), &target)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(unix)]
+fn symlink_dir(target: &Path, link: &Path) -> std::io::Result<()> {
+    std::os::unix::fs::symlink(target, link)
+}
+
+#[cfg(windows)]
+fn symlink_dir(target: &Path, link: &Path) -> std::io::Result<()> {
+    std::os::windows::fs::symlink_dir(target, link)
+}
+
+#[cfg(unix)]
+fn copy_symlink(src: &Path, dst: &Path) -> std::io::Result<()> {
+    std::os::unix::fs::symlink(fs::read_link(src)?, dst)
+}
+
+#[cfg(windows)]
+fn copy_symlink(src: &Path, dst: &Path) -> std::io::Result<()> {
+    // Windows needs to know whether the link is to a directory; copy the
+    // resolved file instead, as symlinks inside build outputs are rare there
+    fs::copy(src, dst).map(|_| ())
+}
+
+/// Cache symlinks in existing worktrees whose target is gone, for `wt doctor`.
+pub(crate) fn broken_links(repo: &Repository, config: &ProjectBuildCacheConfig) -> Vec<PathBuf> {
+    let Ok(worktrees) = repo.list_worktrees() else {
+        return Vec::new();
+    };
+    worktrees
+        .iter()
+        .filter(|wt| !wt.bare && !wt.is_prunable())
+        .flat_map(|wt| {
+            config
+                .dirs
+                .iter()
+                .map(|dir| dir.trim_end_matches('/'))
+                .filter(|dir| validate_dir(dir).is_ok())
+                .map(|dir| wt.path.join(dir))
+                .collect::<Vec<_>>()
+        })
+        .filter(|path| {
+            path.symlink_metadata()
+                .is_ok_and(|m| m.file_type().is_symlink())
+                && !path.exists()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_dir() {
+        assert!(validate_dir("target").is_ok());
+        assert!(validate_dir("packages/web/node_modules").is_ok());
+        assert!(validate_dir("").is_err());
+        assert!(validate_dir("/tmp/target").is_err());
+        assert!(validate_dir("../target").is_err());
+        assert!(validate_dir("./target").is_err());
+    }
+
+    #[test]
+    fn test_hardlink_tree_shares_file_contents() {
+        let dir = tempfile::tempdir().unwrap();
+        let src = dir.path().join("src");
+        fs::create_dir_all(src.join("nested")).unwrap();
+        fs::write(src.join("nested/file"), "cached").unwrap();
+
+        let dst = dir.path().join("dst");
+        hardlink_tree(&src, &dst).unwrap();
+        assert_eq!(
+            fs::read_to_string(dst.join("nested/file")).unwrap(),
+            "cached"
+        );
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::MetadataExt;
+            let nlink = fs::metadata(src.join("nested/file")).unwrap().nlink();
+            assert_eq!(nlink, 2);
+        }
+    }
+}
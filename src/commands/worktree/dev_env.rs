@@ -0,0 +1,105 @@
+//! direnv and mise in new worktrees (`[dev-env]` in the user config).
+//!
+//! Both tools refuse to load configuration from a directory they haven't
+//! been told to trust, and every worktree is a new directory. With the
+//! settings on, `wt switch --create` runs `direnv allow` for an `.envrc` and
+//! `mise trust` plus `mise install` for a mise config, so the shell picks up
+//! the environment as soon as it lands in the worktree.
+
+use std::path::Path;
+
+use color_print::cformat;
+use worktrunk::config::DevEnvConfig;
+use worktrunk::shell_exec::Cmd;
+use worktrunk::styling::{eprintln, hint_message, warning_message};
+
+use crate::output::Progress;
+
+/// Files that make mise load a directory's configuration.
+const MISE_CONFIG_FILES: &[&str] = &[
+    "mise.toml",
+    ".mise.toml",
+    "mise/config.toml",
+    ".config/mise.toml",
+    ".tool-versions",
+];
+
+/// Whether the worktree at `path` has an `.envrc`.
+pub(crate) fn uses_direnv(path: &Path) -> bool {
+    path.join(".envrc").is_file()
+}
+
+/// Whether the worktree at `path` has a mise config.
+pub(crate) fn uses_mise(path: &Path) -> bool {
+    MISE_CONFIG_FILES
+        .iter()
+        .any(|file| path.join(file).is_file())
+}
+
+/// Trust direnv and mise configs in a new worktree, as configured; failures
+/// only warn.
+pub(super) fn setup_dev_env(path: &Path, dev_env: &DevEnvConfig) {
+    if dev_env.direnv() && uses_direnv(path) {
+        run_tool(path, "direnv", &["allow"], "https://direnv.net");
+    }
+    if dev_env.mise()
+        && uses_mise(path)
+        && run_tool(path, "mise", &["trust"], "https://mise.jdx.dev")
+    {
+        let progress = Progress::spinner("Installing mise tools...");
+        let installed = run_tool(path, "mise", &["install"], "https://mise.jdx.dev");
+        progress.finish();
+        if !installed {
+            eprintln!(
+                "{}",
+                hint_message(cformat!(
+                    "To retry, run <bright-black>mise install</> in the new worktree"
+                ))
+            );
+        }
+    }
+}
+
+/// Run `program args` in `path`, warning on failure. Returns whether it ran
+/// successfully.
+fn run_tool(path: &Path, program: &str, args: &[&str], homepage: &str) -> bool {
+    if which::which(program).is_err() {
+        eprintln!(
+            "{}",
+            warning_message(cformat!(
+                "<bold>[dev-env] {program}</> is enabled, but {program} is not installed"
+            ))
+        );
+        eprintln!(
+            "{}",
+            hint_message(format!("Install {program} from {homepage}"))
+        );
+        return false;
+    }
+    let command = format!("{program} {}", args.join(" "));
+    let result = Cmd::new(program)
+        .args(args.iter().copied())
+        .current_dir(path)
+        .run();
+    match result {
+        Ok(output) if output.status.success() => true,
+        Ok(output) => {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            eprintln!(
+                "{}",
+                warning_message(cformat!(
+                    "<bright-black>{command}</> failed: {}",
+                    stderr.trim()
+                ))
+            );
+            false
+        }
+        Err(e) => {
+            eprintln!(
+                "{}",
+                warning_message(cformat!("Failed to run <bright-black>{command}</>: {e}"))
+            );
+            false
+        }
+    }
+}
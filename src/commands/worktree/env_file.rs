@@ -0,0 +1,169 @@
+//! Per-worktree environment (`[env]` in the project config).
+//!
+//! Values are expanded with the hook variables when a worktree is created and
+//! written to `.wt.env` at its root, one `KEY=value` per line. Whenever `wt`
+//! changes the shell into a worktree, shell integration exports what the file
+//! holds (see `output::change_directory`), so each worktree can get its own
+//! `PORT` or `DATABASE_URL`. The file is excluded through `info/exclude`, so
+//! it never shows up as an untracked change.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::Path;
+
+use color_print::cformat;
+use worktrunk::config::expand_template;
+use worktrunk::path::format_path_for_display;
+use worktrunk::styling::{eprintln, info_message, warning_message};
+
+use crate::commands::command_executor::{CommandContext, build_hook_context};
+
+/// Name of the env file at the root of each worktree.
+pub(crate) const ENV_FILE: &str = ".wt.env";
+
+/// Whether `name` can be exported by every supported shell.
+fn is_valid_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Expand `env` for the worktree in `ctx` and write its `.wt.env`.
+///
+/// Best-effort: variables that fail to expand, or expand to more than one
+/// line, are reported and left out.
+pub(crate) fn write_env_file(ctx: &CommandContext<'_>, env: &BTreeMap<String, String>) {
+    let context = build_hook_context(ctx, &[]);
+    let vars: HashMap<&str, &str> = context
+        .iter()
+        .map(|(k, v)| (k.as_str(), v.as_str()))
+        .collect();
+
+    let mut content = String::new();
+    for (name, template) in env {
+        if !is_valid_name(name) {
+            eprintln!(
+                "{}",
+                warning_message(cformat!(
+                    "Skipped env var <bold>{name}</>: not a valid variable name"
+                ))
+            );
+            continue;
+        }
+        match expand_template(template, &vars, false, ctx.repo, name) {
+            Ok(value) if !value.contains('\n') => {
+                content.push_str(&format!("{name}={value}\n"));
+            }
+            Ok(_) => eprintln!(
+                "{}",
+                warning_message(cformat!(
+                    "Skipped env var <bold>{name}</>: value spans several lines"
+                ))
+            ),
+            Err(e) => eprintln!(
+                "{}",
+                warning_message(cformat!("Skipped env var <bold>{name}</>: {e}"))
+            ),
+        }
+    }
+    if content.is_empty()
+        || worktrunk::dry_run::skip(&cformat!(
+            "write <bold>{ENV_FILE}</> in {}",
+            format_path_for_display(ctx.worktree_path)
+        ))
+    {
+        return;
+    }
+
+    exclude_env_file(ctx);
+    if let Err(e) = fs::write(ctx.worktree_path.join(ENV_FILE), content) {
+        eprintln!(
+            "{}",
+            warning_message(cformat!("Failed to write <bold>{ENV_FILE}</>: {e}"))
+        );
+        return;
+    }
+    eprintln!(
+        "{}",
+        info_message(cformat!(
+            "Wrote {} to <bold>{ENV_FILE}</>",
+            env.keys().cloned().collect::<Vec<_>>().join(", ")
+        ))
+    );
+}
+
+/// Add `.wt.env` to the repository's `info/exclude` if it isn't there.
+fn exclude_env_file(ctx: &CommandContext<'_>) {
+    let exclude = ctx.repo.git_common_dir().join("info").join("exclude");
+    let pattern = format!("/{ENV_FILE}");
+    let existing = fs::read_to_string(&exclude).unwrap_or_default();
+    if existing.lines().any(|line| line.trim() == pattern) {
+        return;
+    }
+    let separator = if existing.is_empty() || existing.ends_with('\n') {
+        ""
+    } else {
+        "\n"
+    };
+    let result = fs::create_dir_all(exclude.parent().unwrap())
+        .and_then(|()| fs::write(&exclude, format!("{existing}{separator}{pattern}\n")));
+    if let Err(e) = result {
+        log::debug!("Failed to exclude {ENV_FILE}: {e}");
+    }
+}
+
+/// Variables in the `.wt.env` of the worktree at `worktree_path`, if any.
+///
+/// Blank lines, `#` comments and lines without a valid `KEY=` are skipped.
+pub(crate) fn read_env_file(worktree_path: &Path) -> Vec<(String, String)> {
+    let Ok(content) = fs::read_to_string(worktree_path.join(ENV_FILE)) else {
+        return Vec::new();
+    };
+    parse_env_file(&content)
+}
+
+fn parse_env_file(content: &str) -> Vec<(String, String)> {
+    content
+        .lines()
+        .filter(|line| !line.trim_start().starts_with('#'))
+        .filter_map(|line| line.split_once('='))
+        .filter(|(name, _)| is_valid_name(name))
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_env_file() {
+        let content = "\
+PORT=12345
+# a comment
+DATABASE_URL=postgres://localhost/app?x=1
+
+not valid=1
+EMPTY=
+";
+        assert_eq!(
+            parse_env_file(content),
+            vec![
+                ("PORT".into(), "12345".into()),
+                ("DATABASE_URL".into(), "postgres://localhost/app?x=1".into()),
+                ("EMPTY".into(), String::new()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_is_valid_name() {
+        assert!(is_valid_name("PORT"));
+        assert!(is_valid_name("_private_1"));
+        assert!(!is_valid_name("1PORT"));
+        assert!(!is_valid_name("MY-VAR"));
+        assert!(!is_valid_name(""));
+    }
+}
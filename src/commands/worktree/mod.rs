@@ -78,6 +78,9 @@
 //!
 //! The shell wrapper is generated by `wt config shell init <shell>` from templates in `templates/`.
 
+mod build_cache;
+mod dev_env;
+mod env_file;
 mod hooks;
 mod push;
 mod remove;
@@ -86,13 +89,17 @@ mod switch;
 mod types;
 
 // Re-export public types and functions
-pub use push::handle_push;
+pub(crate) use build_cache::broken_links as broken_build_cache_links;
+pub(crate) use dev_env::{uses_direnv, uses_mise};
+pub(crate) use env_file::read_env_file;
+pub use push::{handle_push, handle_push_remote};
 pub use remove::{handle_remove, handle_remove_current};
 pub(crate) use resolve::paths_match;
 pub use resolve::{
     compute_worktree_path, get_path_mismatch, is_worktree_at_expected_path, resolve_worktree_arg,
     worktree_display_name,
 };
+pub(crate) use switch::{base_remote, fetch_options, format_ref_context};
 pub use switch::{execute_switch, plan_switch};
 pub use types::{
     BranchDeletionMode, MergeOperations, OperationMode, RemoveResult, SwitchBranchInfo, SwitchPlan,
@@ -2,20 +2,32 @@
 //!
 //! Push changes to target branch with safety checks.
 
+use anyhow::Context;
 use color_print::cformat;
-use worktrunk::git::{GitError, Repository};
+use worktrunk::config::UserConfig;
+use worktrunk::git::{GitCommandError, GitError, Repository};
+use worktrunk::state::Operation;
 use worktrunk::styling::{
     eprintln, format_with_gutter, info_message, progress_message, success_message,
 };
 
 use super::types::MergeOperations;
+use crate::commands::forge::push_with_upstream;
 use crate::commands::repository_ext::RepositoryCliExt;
+use crate::commands::secret_scan::scan_outgoing;
+use crate::commands::undo::record;
 
 /// Push changes to target branch
 ///
 /// The `operations` parameter indicates which merge operations occurred (commit, squash, rebase).
 /// Pass `None` for standalone push operations where these concepts don't apply.
 ///
+/// With `verify`, outgoing commits are scanned for secrets when `[secret-scan]`
+/// is enabled.
+///
+/// Standalone pushes to a protected branch (`[branches] protected`) are refused
+/// unless `force` is set; `wt merge` is the sanctioned way to update them.
+///
 /// During the push stage we temporarily `git stash` non-overlapping changes in the
 /// target worktree (if present) so that concurrent edits there do not block the
 /// fast-forward. The stash is restored afterward and we bail out early if any file
@@ -24,17 +36,41 @@ pub fn handle_push(
     target: Option<&str>,
     verb: &str,
     operations: Option<MergeOperations>,
+    verify: bool,
+    force: bool,
 ) -> anyhow::Result<()> {
     let repo = Repository::current()?;
 
     // Get and validate target branch (must be a branch since we're updating it)
     let target_branch = repo.require_target_branch(target)?;
 
+    if operations.is_none() && !force && repo.is_protected_branch(&target_branch) {
+        return Err(GitError::ProtectedBranch {
+            branch: target_branch,
+            action: "push to".into(),
+            force_hint: true,
+        }
+        .into());
+    }
+
     // A worktree for the target branch is optional for push:
     // - If present, we use it to check for overlapping dirty files.
     // - If absent, we skip that safety step but still allow the push (git itself is fine).
     let target_worktree_path = repo.worktree_for_branch(&target_branch)?;
 
+    if verify {
+        let config = UserConfig::load().context("Failed to load config")?;
+        let project_id = repo.project_identifier().ok();
+        let scan_config = config
+            .secret_scan(project_id.as_deref())
+            .unwrap_or_default();
+        scan_outgoing(&repo, &scan_config, &target_branch)?;
+    }
+
+    // Held from the fast-forward check until the target branch is updated
+    // and any stash restored; the secret scan above can take a while
+    let lock = repo.lock_operation("push")?;
+
     // Check if it's a fast-forward
     if !repo.is_ancestor(&target_branch, "HEAD")? {
         // Get formatted commit log (commits in target that we don't have)
@@ -133,6 +169,11 @@ pub fn handle_push(
         crate::commands::show_diffstat(&repo, &format!("{}..HEAD", target_branch))?;
     }
 
+    let before = repo
+        .run_command(&["rev-parse", &format!("refs/heads/{target_branch}")])?
+        .trim()
+        .to_string();
+
     // Get git common dir for the push
     let git_common_dir = repo.git_common_dir();
     let git_common_dir_str = git_common_dir.to_string_lossy();
@@ -151,13 +192,29 @@ pub fn handle_push(
         GitError::PushFailed {
             target_branch: target_branch.clone(),
             error: e.to_string(),
+            source: GitCommandError::find(&e).cloned(),
         }
     })?;
 
+    if commit_count > 0 {
+        let after = repo.run_command(&["rev-parse", "HEAD"])?.trim().to_string();
+        record(
+            &repo,
+            Operation::RefMoved {
+                branch: target_branch.clone(),
+                before,
+                after,
+                operation: "push".into(),
+                snapshot: None,
+            },
+        );
+    }
+
     // Restore stash before success message (Drop handles error paths automatically)
     if let Some(guard) = stash_guard.as_mut() {
         guard.restore_now();
     }
+    drop(lock);
 
     // Show success message after push completes
     if commit_count > 0 {
@@ -209,3 +266,34 @@ pub fn handle_push(
 
     Ok(())
 }
+
+/// Push the current branch to the primary remote (`wt step push --remote`).
+///
+/// With `verify`, commits the remote doesn't have yet are scanned for secrets
+/// first: those past `<remote>/<branch>`, or past the default branch before
+/// the first push. [`push_with_upstream`] force-pushes with a lease when the
+/// branch was rewritten.
+pub fn handle_push_remote(verify: bool) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let branch = repo.require_current_branch("push")?;
+    let remote = repo.primary_remote()?;
+
+    let config = UserConfig::load().context("Failed to load config")?;
+    let project_id = repo.project_identifier().ok();
+    if verify {
+        let scan_config = config
+            .secret_scan(project_id.as_deref())
+            .unwrap_or_default();
+        let tracking = format!("{remote}/{branch}");
+        let base = if repo.is_remote_tracking_branch(&tracking) {
+            tracking
+        } else {
+            repo.require_target_branch(None)?
+        };
+        scan_outgoing(&repo, &scan_config, &base)?;
+    }
+
+    let push_config = config.push(project_id.as_deref()).unwrap_or_default();
+    let worktree_path = repo.current_worktree().root()?;
+    push_with_upstream(&repo, &worktree_path, &branch, &push_config, false)
+}
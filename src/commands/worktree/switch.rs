@@ -7,19 +7,25 @@ use std::path::Path;
 use anyhow::Context;
 use color_print::cformat;
 use dunce::canonicalize;
-use worktrunk::config::UserConfig;
+use worktrunk::config::{FetchConfig, LfsConfig, UserConfig};
 use worktrunk::git::remote_ref::{
     self, GitHubProvider, GitLabProvider, RemoteRefInfo, RemoteRefProvider,
 };
-use worktrunk::git::{GitError, RefContext, RefType, Repository};
+use worktrunk::git::{GitError, RefContext, RefType, Repository, WorkingTree, is_interrupted};
+use worktrunk::state::Operation;
 use worktrunk::styling::{
     eprintln, format_with_gutter, hint_message, info_message, progress_message, suggest_command,
     warning_message,
 };
 
+use super::build_cache::link_build_cache;
+use super::dev_env::setup_dev_env;
+use super::env_file::write_env_file;
 use super::resolve::{compute_clobber_backup, compute_worktree_path};
 use super::types::{CreationMethod, SwitchBranchInfo, SwitchPlan, SwitchResult};
 use crate::commands::command_executor::CommandContext;
+use crate::commands::undo::record;
+use crate::output::Progress;
 
 /// Result of resolving the switch target.
 struct ResolvedTarget {
@@ -36,7 +42,7 @@ struct ResolvedTarget {
 ///  ┃ Fix authentication bug in login flow (#101)
 ///  ┃ by @alice · open · feature-auth · https://github.com/owner/repo/pull/101
 /// ```
-fn format_ref_context(ctx: &impl RefContext) -> String {
+pub(crate) fn format_ref_context(ctx: &impl RefContext) -> String {
     let mut status_parts = vec![format!("by @{}", ctx.author()), ctx.state().to_string()];
     if ctx.draft() {
         status_parts.push("draft".to_string());
@@ -60,6 +66,7 @@ fn resolve_remote_ref(
     number: u32,
     create: bool,
     base: Option<&str>,
+    fetch: &FetchConfig,
 ) -> anyhow::Result<ResolvedTarget> {
     let ref_type = provider.ref_type();
     let symbol = ref_type.symbol();
@@ -96,7 +103,7 @@ fn resolve_remote_ref(
     }
 
     // Same-repo ref: fetch the branch to ensure remote tracking refs exist
-    resolve_same_repo_ref(repo, &info)
+    resolve_same_repo_ref(repo, &info, fetch)
 }
 
 /// Resolve a fork (cross-repo) PR/MR.
@@ -267,16 +274,19 @@ fn find_github_remote(repo: &Repository, info: &RemoteRefInfo) -> anyhow::Result
         })
 }
 
-/// Resolve a same-repo (non-fork) PR/MR.
-fn resolve_same_repo_ref(
-    repo: &Repository,
-    info: &RemoteRefInfo,
-) -> anyhow::Result<ResolvedTarget> {
+/// `[fetch]` options that apply to fetching from `remote` in this repository.
+pub(crate) fn fetch_options(repo: &Repository, fetch: &FetchConfig, remote: &str) -> Vec<String> {
+    if fetch.filter.is_none() && fetch.depth.is_none() {
+        return Vec::new();
+    }
+    fetch.fetch_args(repo.is_partial_clone_remote(remote), repo.is_shallow())
+}
+
+/// The remote holding the PR/MR's target repository, where `refs/pull/N/head`
+/// and `refs/merge-requests/N/head` live.
+pub(crate) fn base_remote(repo: &Repository, info: &RemoteRefInfo) -> anyhow::Result<String> {
     use worktrunk::git::remote_ref::PlatformData;
 
-    // Find the remote for the same-repo PR/MR and fetch the branch with an
-    // explicit refspec. This ensures the remote tracking branch is created even
-    // in repos with limited fetch refspecs (single-branch clones, bare repos).
     let remote = match &info.platform_data {
         PlatformData::GitHub {
             host,
@@ -306,6 +316,19 @@ fn resolve_same_repo_ref(
                 suggested_url: format!("https://{host}/{base_owner}/{base_repo}.git"),
             })?,
     };
+    Ok(remote)
+}
+
+/// Resolve a same-repo (non-fork) PR/MR.
+fn resolve_same_repo_ref(
+    repo: &Repository,
+    info: &RemoteRefInfo,
+    fetch: &FetchConfig,
+) -> anyhow::Result<ResolvedTarget> {
+    // Find the remote for the same-repo PR/MR and fetch the branch with an
+    // explicit refspec. This ensures the remote tracking branch is created even
+    // in repos with limited fetch refspecs (single-branch clones, bare repos).
+    let remote = base_remote(repo, info)?;
 
     let branch = &info.source_branch;
     eprintln!(
@@ -316,7 +339,11 @@ fn resolve_same_repo_ref(
     // the configured fetch refspec (e.g., single-branch clones, bare repos).
     let refspec = format!("+refs/heads/{branch}:refs/remotes/{remote}/{branch}");
     // Use -- to prevent branch names starting with - from being interpreted as flags
-    repo.run_command(&["fetch", "--", &remote, &refspec])
+    let mut args = vec!["fetch".to_string()];
+    args.extend(fetch_options(repo, fetch, &remote));
+    args.extend(["--".to_string(), remote.clone(), refspec]);
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    repo.run_remote_command(&args)
         .with_context(|| format!("Failed to fetch branch '{}' from {}", branch, remote))?;
 
     Ok(ResolvedTarget {
@@ -337,19 +364,20 @@ fn resolve_switch_target(
     branch: &str,
     create: bool,
     base: Option<&str>,
+    fetch: &FetchConfig,
 ) -> anyhow::Result<ResolvedTarget> {
     // Handle pr:<number> syntax
     if let Some(suffix) = branch.strip_prefix("pr:")
         && let Ok(number) = suffix.parse::<u32>()
     {
-        return resolve_remote_ref(repo, &GitHubProvider, number, create, base);
+        return resolve_remote_ref(repo, &GitHubProvider, number, create, base, fetch);
     }
 
     // Handle mr:<number> syntax (GitLab MRs)
     if let Some(suffix) = branch.strip_prefix("mr:")
         && let Ok(number) = suffix.parse::<u32>()
     {
-        return resolve_remote_ref(repo, &GitLabProvider, number, create, base);
+        return resolve_remote_ref(repo, &GitLabProvider, number, create, base, fetch);
     }
 
     // Regular branch switch
@@ -581,7 +609,10 @@ pub fn plan_switch(
     let new_previous = repo.current_worktree().branch().ok().flatten();
 
     // Phase 1: Resolve target (handles pr:, validates --create/--base, may do network)
-    let target = resolve_switch_target(repo, branch, create, base)?;
+    let fetch = config
+        .fetch(repo.project_identifier().ok().as_deref())
+        .unwrap_or_default();
+    let target = resolve_switch_target(repo, branch, create, base, &fetch)?;
 
     // Phase 2: Check if worktree already exists for this branch (fast path)
     // This avoids computing the worktree path template (~7 git commands) for existing switches.
@@ -621,9 +652,93 @@ pub fn plan_switch(
         method: target.method,
         clobber_backup,
         new_previous,
+        sparse: None,
+        orphan: false,
     })
 }
 
+/// Check out only `patterns` (cone mode) in a worktree.
+///
+/// For a worktree created with `--no-checkout`, this also populates it:
+/// `read-tree -mu HEAD` fills the index and checks out the matching files.
+fn apply_sparse_checkout(
+    repo: &Repository,
+    worktree_path: &Path,
+    patterns: &[String],
+) -> anyhow::Result<()> {
+    let wt = repo.worktree_at(worktree_path);
+    let mut args = vec!["sparse-checkout", "set", "--cone"];
+    args.extend(patterns.iter().map(String::as_str));
+    wt.run_command(&args)
+        .context("Failed to set sparse-checkout patterns")?;
+    wt.run_command(&["read-tree", "-mu", "HEAD"])
+        .context("Failed to check out sparse worktree")?;
+    Ok(())
+}
+
+/// Check out submodules in a new worktree; failures only warn.
+fn init_submodules(wt: &WorkingTree<'_>) {
+    let progress = Progress::spinner("Initializing submodules...");
+    let result = wt.update_submodules();
+    progress.finish();
+    if let Err(e) = result {
+        eprintln!(
+            "{}",
+            warning_message(format!("Failed to initialize submodules: {e}"))
+        );
+        eprintln!(
+            "{}",
+            hint_message(cformat!(
+                "To retry, run <bright-black>git submodule update --init --recursive</> in the new worktree"
+            ))
+        );
+    }
+}
+
+/// Install missing LFS hooks and pull LFS content in a new worktree, as
+/// configured; failures only warn.
+fn setup_lfs(wt: &WorkingTree<'_>, lfs: &LfsConfig) {
+    if wt.run_command(&["lfs", "version"]).is_err() {
+        eprintln!(
+            "{}",
+            warning_message(
+                "Repository uses Git LFS, but git-lfs is not installed; LFS files are pointers"
+            )
+        );
+        eprintln!(
+            "{}",
+            hint_message("Install git-lfs from https://git-lfs.com")
+        );
+        return;
+    }
+    if lfs.install()
+        && !wt.lfs_installed().unwrap_or(false)
+        && let Err(e) = wt.run_command(&["lfs", "install", "--local"])
+    {
+        eprintln!(
+            "{}",
+            warning_message(format!("Failed to install Git LFS hooks: {e}"))
+        );
+    }
+    if lfs.pull() {
+        let progress = Progress::spinner("Pulling LFS objects...");
+        let result = wt.run_command(&["lfs", "pull"]);
+        progress.finish();
+        if let Err(e) = result {
+            eprintln!(
+                "{}",
+                warning_message(format!("Failed to pull LFS objects: {e}"))
+            );
+            eprintln!(
+                "{}",
+                hint_message(cformat!(
+                    "To retry, run <bright-black>git lfs pull</> in the new worktree"
+                ))
+            );
+        }
+    }
+}
+
 /// Execute a validated switch plan.
 ///
 /// Takes a `SwitchPlan` from `plan_switch()` and executes it.
@@ -682,7 +797,22 @@ pub fn execute_switch(
             method,
             clobber_backup,
             new_previous,
+            sparse,
+            orphan,
         } => {
+            // The plan was made without the lock, so another `wt` may have
+            // created the worktree since; if so, switch to it instead
+            let lock = repo.lock_operation("switch --create")?;
+            if let Some(path) = repo.worktree_for_branch(&branch)? {
+                return Ok((
+                    SwitchResult::Existing { path },
+                    SwitchBranchInfo {
+                        branch,
+                        expected_path: None,
+                    },
+                ));
+            }
+
             // Handle --clobber backup if needed (shared for all creation methods)
             if let Some(backup_path) = &clobber_backup {
                 let path_display = worktrunk::path::format_path_for_display(&worktree_path);
@@ -694,9 +824,16 @@ pub fn execute_switch(
                     ))
                 );
 
-                std::fs::rename(&worktree_path, backup_path).with_context(|| {
-                    format!("Failed to move {path_display} to {backup_display}")
-                })?;
+                if worktrunk::dry_run::is_dry_run() {
+                    worktrunk::dry_run::report(
+                        &format!("mv {path_display} {backup_display}"),
+                        None,
+                    );
+                } else {
+                    std::fs::rename(&worktree_path, backup_path).with_context(|| {
+                        format!("Failed to move {path_display} to {backup_display}")
+                    })?;
+                }
             }
 
             // Execute based on creation method
@@ -713,6 +850,10 @@ pub fn execute_switch(
                     // Build git worktree add command
                     let worktree_path_str = worktree_path.to_string_lossy();
                     let mut args = vec!["worktree", "add", worktree_path_str.as_ref()];
+                    // Sparse worktrees are populated after the patterns are set
+                    if sparse.is_some() {
+                        args.insert(2, "--no-checkout");
+                    }
 
                     // For DWIM fallback: when the branch doesn't exist locally,
                     // git worktree add relies on DWIM to auto-create it from a
@@ -721,7 +862,11 @@ pub fn execute_switch(
                     // create from the tracking ref in that case.
                     let tracking_ref;
 
-                    if *create_branch {
+                    if orphan {
+                        // `git worktree add --orphan` needs git 2.42; instead add an
+                        // empty detached worktree and switch it to the orphan below
+                        args.extend(["--no-checkout", "--detach"]);
+                    } else if *create_branch {
                         args.push("-b");
                         args.push(&branch);
                         if let Some(base) = base_branch {
@@ -761,6 +906,18 @@ pub fn execute_switch(
                         .into());
                     }
 
+                    if orphan
+                        && let Err(e) = repo
+                            .worktree_at(&worktree_path)
+                            .run_command(&["switch", "--orphan", &branch])
+                    {
+                        let _ = repo.remove_worktree(&worktree_path, true);
+                        return Err(e.context(format!("Failed to create orphan branch {branch}")));
+                    }
+                    if let Some(patterns) = &sparse {
+                        apply_sparse_checkout(repo, &worktree_path, patterns)?;
+                    }
+
                     // Safety: unset unsafe upstream when creating a new branch from a remote
                     // tracking branch. When `git worktree add -b feature origin/main` runs,
                     // git sets feature to track origin/main. This is dangerous because
@@ -796,8 +953,17 @@ pub fn execute_switch(
 
                     // Fetch the ref (remote was resolved during planning)
                     // Use -- to prevent refs starting with - from being interpreted as flags
-                    repo.run_command(&["fetch", "--", remote, ref_path])
+                    let progress = Progress::spinner(format!("Fetching {label} from {remote}..."));
+                    let fetch = config
+                        .fetch(repo.project_identifier().ok().as_deref())
+                        .unwrap_or_default();
+                    let mut args = vec!["fetch".to_string()];
+                    args.extend(fetch_options(repo, &fetch, remote));
+                    args.extend(["--".to_string(), remote.clone(), ref_path.clone()]);
+                    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+                    repo.run_remote_command(&args)
                         .with_context(|| format!("Failed to fetch {} from {}", label, remote))?;
+                    progress.finish();
 
                     // Execute branch creation and configuration with cleanup on failure.
                     let setup_result = setup_fork_branch(
@@ -815,6 +981,9 @@ pub fn execute_switch(
                         let _ = repo.run_command(&["branch", "-D", "--", &branch]);
                         return Err(e);
                     }
+                    if let Some(patterns) = &sparse {
+                        apply_sparse_checkout(repo, &worktree_path, patterns)?;
+                    }
 
                     // Show push configuration or warning about prefixed branch
                     if let Some(url) = fork_push_url {
@@ -844,6 +1013,45 @@ pub fn execute_switch(
                 }
             };
 
+            drop(lock);
+
+            // New worktrees start with empty submodule directories and, without
+            // the LFS hooks, with LFS pointer files
+            let new_wt = repo.worktree_at(&worktree_path);
+            let project_id = repo.project_identifier().ok();
+            if new_wt.has_submodules()
+                && config
+                    .submodules(project_id.as_deref())
+                    .unwrap_or_default()
+                    .update()
+            {
+                init_submodules(&new_wt);
+            }
+            if new_wt.uses_lfs() {
+                setup_lfs(
+                    &new_wt,
+                    &config.lfs(project_id.as_deref()).unwrap_or_default(),
+                );
+            }
+            if let Some(dev_env) = config.dev_env(project_id.as_deref()) {
+                setup_dev_env(&worktree_path, &dev_env);
+            }
+            // Before post-create hooks, so builds and installs start from the cache
+            if !worktrunk::dry_run::is_dry_run()
+                && let Ok(Some(project_config)) = repo.load_project_config()
+                && let Some(cache) = project_config.build_cache()
+            {
+                link_build_cache(repo, &new_wt, cache);
+            }
+            // Also before hooks, which can `source .wt.env`
+            if !worktrunk::dry_run::is_dry_run()
+                && let Ok(Some(project_config)) = repo.load_project_config()
+                && !project_config.env.is_empty()
+            {
+                let ctx = CommandContext::new(repo, config, Some(&branch), &worktree_path, force);
+                write_env_file(&ctx, &project_config.env);
+            }
+
             // Compute base worktree path for hooks and result
             let base_worktree_path = base_branch
                 .as_ref()
@@ -854,7 +1062,7 @@ pub fn execute_switch(
             if !no_verify {
                 let ctx = CommandContext::new(repo, config, Some(&branch), &worktree_path, force);
 
-                match &method {
+                let result = match &method {
                     CreationMethod::Regular { base_branch, .. } => {
                         let extra_vars: Vec<(&str, &str)> = [
                             base_branch.as_ref().map(|b| ("base", b.as_str())),
@@ -865,7 +1073,7 @@ pub fn execute_switch(
                         .into_iter()
                         .flatten()
                         .collect();
-                        ctx.execute_post_create_commands(&extra_vars)?;
+                        ctx.execute_post_create_commands(&extra_vars)
                     }
                     CreationMethod::ForkRef {
                         ref_type,
@@ -880,11 +1088,37 @@ pub fn execute_switch(
                         };
                         let extra_vars: Vec<(&str, &str)> =
                             vec![(num_key, &num_str), (url_key, ref_url)];
-                        ctx.execute_post_create_commands(&extra_vars)?;
+                        ctx.execute_post_create_commands(&extra_vars)
+                    }
+                };
+                if let Err(err) = result {
+                    if is_interrupted(&err) {
+                        rollback_interrupted_creation(
+                            repo,
+                            &branch,
+                            &worktree_path,
+                            created_branch,
+                        );
                     }
+                    return Err(err);
                 }
             }
 
+            if let Ok(commit) = repo
+                .worktree_at(&worktree_path)
+                .run_command(&["rev-parse", "HEAD"])
+            {
+                record(
+                    repo,
+                    Operation::WorktreeCreated {
+                        branch: branch.clone(),
+                        path: worktree_path.clone(),
+                        commit: commit.trim().to_string(),
+                        created_branch,
+                    },
+                );
+            }
+
             // Record successful switch in history
             let _ = repo.set_switch_previous(new_previous.as_deref());
 
@@ -905,6 +1139,33 @@ pub fn execute_switch(
     }
 }
 
+/// Remove a worktree whose post-create hooks were interrupted, so a Ctrl-C
+/// doesn't leave a half-set-up worktree (and a new branch) behind.
+///
+/// Best-effort: failures are logged and the original interruption is what
+/// the caller reports.
+fn rollback_interrupted_creation(
+    repo: &Repository,
+    branch: &str,
+    worktree_path: &Path,
+    created_branch: bool,
+) {
+    if let Err(e) = repo.remove_worktree(worktree_path, true) {
+        log::debug!("Failed to remove interrupted worktree: {e:#}");
+        return;
+    }
+    if created_branch && let Err(e) = repo.run_command(&["branch", "-D", "--", branch]) {
+        log::debug!("Failed to delete interrupted branch {branch}: {e:#}");
+    }
+    let path_display = worktrunk::path::format_path_for_display(worktree_path);
+    eprintln!(
+        "{}",
+        warning_message(cformat!(
+            "Interrupted; removed partially-created worktree @ <bold>{path_display}</>"
+        ))
+    );
+}
+
 /// Resolve the deferred path mismatch for existing worktree switches.
 ///
 fn worktree_creation_error(
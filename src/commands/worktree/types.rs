@@ -110,6 +110,10 @@ pub enum SwitchPlan {
         clobber_backup: Option<PathBuf>,
         /// Branch to record as "previous" for `wt switch -`
         new_previous: Option<String>,
+        /// Directories to check out (`--sparse`); `None` checks out everything
+        sparse: Option<Vec<String>>,
+        /// Start the branch with no history (`--orphan`)
+        orphan: bool,
     },
 }
 
@@ -133,6 +137,31 @@ impl SwitchPlan {
     pub fn is_create(&self) -> bool {
         matches!(self, SwitchPlan::Create { .. })
     }
+
+    /// Restrict a new worktree to the given directories (sparse checkout).
+    ///
+    /// No effect on plans for existing worktrees.
+    pub fn set_sparse(&mut self, patterns: Vec<String>) {
+        if let SwitchPlan::Create { sparse, .. } = self {
+            *sparse = Some(patterns);
+        }
+    }
+
+    /// Create the new branch as an orphan: no parent commit, empty tree.
+    ///
+    /// Drops the base branch, which an orphan doesn't start from. No effect on
+    /// plans for existing worktrees.
+    pub fn set_orphan(&mut self) {
+        if let SwitchPlan::Create {
+            method: CreationMethod::Regular { base_branch, .. },
+            orphan,
+            ..
+        } = self
+        {
+            *base_branch = None;
+            *orphan = true;
+        }
+    }
 }
 
 /// How the branch should be handled after worktree removal.
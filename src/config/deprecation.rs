@@ -801,18 +801,18 @@ pub fn warn_unknown_fields<C: WorktrunkConfig>(
 
     for key in keys {
         if let Some(other_location) = key_belongs_in::<C>(key) {
-            eprintln!(
-                "{}",
-                warning_message(cformat!(
+            crate::ops::warn(
+                &warning_message(cformat!(
                     "{label} has key <bold>{key}</> which belongs in {other_location} (will be ignored)"
                 ))
+                .to_string(),
             );
         } else {
-            eprintln!(
-                "{}",
-                warning_message(cformat!(
+            crate::ops::warn(
+                &warning_message(cformat!(
                     "{label} has unknown field <bold>{key}</> (will be ignored)"
                 ))
+                .to_string(),
             );
         }
     }
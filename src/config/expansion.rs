@@ -9,12 +9,15 @@
 //! See `wt hook --help` for available filters and functions.
 
 use std::borrow::Cow;
+use std::path::Path;
+use std::sync::LazyLock;
 
 use color_print::cformat;
 use minijinja::{Environment, ErrorKind, UndefinedBehavior, Value};
 use regex::Regex;
 use shell_escape::escape;
 
+use super::project::wildcard_match;
 use crate::git::Repository;
 use crate::path::to_posix_path;
 use crate::styling::{
@@ -30,6 +33,7 @@ use crate::styling::{
 pub const TEMPLATE_VARS: &[&str] = &[
     "repo",
     "branch",
+    "branch_slug",
     "worktree_name",
     "repo_path",
     "worktree_path",
@@ -40,9 +44,13 @@ pub const TEMPLATE_VARS: &[&str] = &[
     "remote",
     "remote_url",
     "upstream",
+    "port",
+    "port_end",
     "target",             // Added by merge/rebase hooks via extra_vars
     "base",               // Added by creation hooks via extra_vars
     "base_worktree_path", // Added by creation hooks via extra_vars
+    "changed_files",      // Added for commands with `paths` filters
+    "issue_key",          // Only when the branch names a ticket
 ];
 
 /// Deprecated template variable aliases (still valid for backward compatibility).
@@ -86,6 +94,37 @@ pub fn sanitize_branch_name(branch: &str) -> String {
     branch.replace(['/', '\\'], "-")
 }
 
+/// Variables derived from a branch name, shared by every template that knows
+/// the branch (hooks, `worktree-path`, `url`, the `[start] branch` template).
+///
+/// Always sets `branch` and `branch_slug`; sets `issue_key` when `key_pattern`
+/// (the `[jira] key-pattern`) finds a ticket key in the branch name.
+///
+/// # Examples
+/// ```
+/// use worktrunk::config::branch_vars;
+///
+/// let vars = branch_vars("feature/PROJ-42-login", "[A-Z][A-Z0-9]+-[0-9]+");
+/// assert!(vars.contains(&("issue_key", "PROJ-42".to_string())));
+/// assert!(!branch_vars("main", "[A-Z][A-Z0-9]+-[0-9]+").iter().any(|(k, _)| *k == "issue_key"));
+/// ```
+pub fn branch_vars(branch: &str, key_pattern: &str) -> Vec<(&'static str, String)> {
+    let mut vars = vec![
+        ("branch", branch.to_string()),
+        ("branch_slug", sanitize_db(branch)),
+    ];
+    // An invalid pattern is reported where tickets are fetched; here it just
+    // leaves `issue_key` unset
+    if let Some(key) = Regex::new(key_pattern)
+        .ok()
+        .and_then(|re| re.find(branch))
+        .filter(|m| !m.is_empty())
+    {
+        vars.push(("issue_key", key.as_str().to_string()));
+    }
+    vars
+}
+
 /// Sanitize a string for use as a database identifier.
 ///
 /// Transforms input into an identifier compatible with most SQL databases
@@ -221,6 +260,8 @@ pub struct TemplateExpandError {
     pub source_line: Option<String>,
     /// Variable names available in this template context.
     pub available_vars: Vec<String>,
+    /// Close match for a misspelled variable, as `(typed, suggested)`.
+    pub suggestion: Option<(String, String)>,
 }
 
 impl std::fmt::Display for TemplateExpandError {
@@ -229,6 +270,14 @@ impl std::fmt::Display for TemplateExpandError {
         if let Some(ref line) = self.source_line {
             parts.push(format_with_gutter(line, None));
         }
+        if let Some((ref typed, ref suggested)) = self.suggestion {
+            parts.push(
+                hint_message(cformat!(
+                    "<bright-black>{typed}</> is not defined; did you mean <bright-black>{suggested}</>?"
+                ))
+                .to_string(),
+            );
+        }
         if !self.available_vars.is_empty() {
             parts.push(
                 hint_message(cformat!(
@@ -283,16 +332,115 @@ fn build_template_error(
         None => format!("Failed to expand {name}: {detail}"),
     };
 
+    // Only show available vars for undefined errors (actionable hint)
+    let (available_vars, suggestion) = if is_undefined {
+        let suggestion = source_line
+            .as_deref()
+            .and_then(|line| suggest_variable(line, &available_vars));
+        (available_vars, suggestion)
+    } else {
+        (Vec::new(), None)
+    };
+
     TemplateExpandError {
         message,
         source_line,
-        // Only show available vars for undefined errors (actionable hint)
-        available_vars: if is_undefined {
+        available_vars,
+        suggestion,
+    }
+}
+
+/// Find a name in `line` that isn't an available variable but is close to
+/// one, returning `(typed, suggested)`.
+///
+/// minijinja doesn't say which name was undefined, so this scans the
+/// expressions on the failing line. Filters, tests, attributes, function
+/// calls, string literals, loop targets and keywords are skipped.
+fn suggest_variable(line: &str, available_vars: &[String]) -> Option<(String, String)> {
+    static TOKEN: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(concat!(
+            r#"'[^']*'|"[^"]*""#,
+            r"|\|\s*\w+|\bis\s+(?:not\s+)?\w+|\.\w+|\w+\s*\(|\b(?:for|set)\s+\w+",
+            r"|(?P<name>\b[A-Za-z_]\w*)",
+        ))
+        .unwrap()
+    });
+    const KEYWORDS: &[&str] = &[
+        "if", "elif", "else", "endif", "for", "endfor", "in", "not", "and", "or", "is", "set",
+        "endset", "true", "false", "none", "True", "False", "None", "loop",
+    ];
+
+    // Templates: only the text inside `{{ }}` and `{% %}`. Conditions are a
+    // bare expression.
+    static BLOCK: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"\{\{(.*?)\}\}|\{%(.*?)%\}").unwrap());
+    let expressions: Vec<&str> = if line.contains("{{") || line.contains("{%") {
+        BLOCK
+            .captures_iter(line)
+            .filter_map(|caps| caps.get(1).or_else(|| caps.get(2)))
+            .map(|m| m.as_str())
+            .collect()
+    } else {
+        vec![line]
+    };
+
+    expressions
+        .into_iter()
+        .flat_map(|expr| TOKEN.captures_iter(expr))
+        .filter_map(|caps| caps.name("name").map(|m| m.as_str()))
+        .filter(|name| !KEYWORDS.contains(name) && !available_vars.iter().any(|v| v == name))
+        .find_map(|name| {
+            // Allow roughly one typo per three characters
+            let max_distance = (name.len() / 3).max(1);
             available_vars
-        } else {
-            Vec::new()
-        },
+                .iter()
+                .map(|var| (edit_distance(name, var), var))
+                .filter(|(distance, _)| *distance <= max_distance)
+                .min_by_key(|(distance, _)| *distance)
+                .map(|(_, var)| (name.to_string(), var.clone()))
+        })
+}
+
+/// Levenshtein distance between two strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut prev = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = prev + usize::from(ca != *cb);
+            prev = row[j + 1];
+            row[j + 1] = substitution.min(prev + 1).min(row[j] + 1);
+        }
     }
+    row[b.len()]
+}
+
+/// Environment with worktrunk's custom filters and functions registered.
+fn template_env<'source>(repo: &Repository) -> Environment<'source> {
+    let mut env = Environment::new();
+    // Register custom filters
+    env.add_filter("sanitize", |value: Value| -> String {
+        sanitize_branch_name(value.as_str().unwrap_or_default())
+    });
+    env.add_filter("sanitize_db", |value: Value| -> String {
+        sanitize_db(value.as_str().unwrap_or_default())
+    });
+    env.add_filter("hash_port", |value: String| string_to_port(&value));
+
+    // Register worktree_path_of_branch function for looking up branch worktree paths.
+    // Returns raw paths — shell escaping is applied by the formatter at output time.
+    let repo_clone = repo.clone();
+    env.add_function("worktree_path_of_branch", move |branch: String| -> String {
+        repo_clone
+            .worktree_for_branch(&branch)
+            .ok()
+            .flatten()
+            .map(|p| to_posix_path(&p.to_string_lossy()))
+            .unwrap_or_default()
+    });
+    env
 }
 
 /// Expand a template with variable substitution.
@@ -331,7 +479,7 @@ pub fn expand_template(
     }
 
     // Render template with minijinja
-    let mut env = Environment::new();
+    let mut env = template_env(repo);
     // SemiStrict: errors on undefined variable use (printing, iteration) but allows
     // truthiness checks ({% if var %}). This catches typos while supporting optional vars.
     env.set_undefined_behavior(UndefinedBehavior::SemiStrict);
@@ -354,27 +502,6 @@ pub fn expand_template(
         });
     }
 
-    // Register custom filters
-    env.add_filter("sanitize", |value: Value| -> String {
-        sanitize_branch_name(value.as_str().unwrap_or_default())
-    });
-    env.add_filter("sanitize_db", |value: Value| -> String {
-        sanitize_db(value.as_str().unwrap_or_default())
-    });
-    env.add_filter("hash_port", |value: String| string_to_port(&value));
-
-    // Register worktree_path_of_branch function for looking up branch worktree paths.
-    // Returns raw paths — shell escaping is applied by the formatter at output time.
-    let repo_clone = repo.clone();
-    env.add_function("worktree_path_of_branch", move |branch: String| -> String {
-        repo_clone
-            .worktree_for_branch(&branch)
-            .ok()
-            .flatten()
-            .map(|p| to_posix_path(&p.to_string_lossy()))
-            .unwrap_or_default()
-    });
-
     // Cache verbosity level for consistent behavior within this call
     let verbose = verbosity();
 
@@ -432,6 +559,44 @@ pub fn expand_template(
     Ok(result)
 }
 
+/// Evaluate a hook's `when` condition: a Jinja expression over the template
+/// variables, true when the command should run.
+///
+/// Besides the template filters and functions, conditions can use:
+/// - `file_exists(path)` — Whether `path` exists, relative to `dir`
+/// - `is matching` — Test where `*` matches any run of characters
+///   (`branch is matching 'release/*'`)
+///
+/// The `name` parameter appears in error messages, as for [`expand_template`].
+pub fn evaluate_condition(
+    condition: &str,
+    vars: &HashMap<&str, &str>,
+    dir: &Path,
+    repo: &Repository,
+    name: &str,
+) -> Result<bool, TemplateExpandError> {
+    let mut env = template_env(repo);
+    // As for templates: typos error, but `when = "upstream"` checks whether it's set
+    env.set_undefined_behavior(UndefinedBehavior::SemiStrict);
+    let dir = dir.to_path_buf();
+    env.add_function("file_exists", move |path: String| dir.join(path).exists());
+    env.add_test("matching", |value: String, pattern: String| {
+        wildcard_match(&pattern, &value)
+    });
+
+    let expr = env
+        .compile_expression(condition)
+        .map_err(|e| build_template_error(&e, condition, name, Vec::new()))?;
+    let result = expr.eval(vars).map_err(|e| {
+        let mut keys: Vec<String> = vars.keys().map(|k| k.to_string()).collect();
+        keys.sort();
+        build_template_error(&e, condition, name, keys)
+    })?;
+
+    log::debug!("[condition:{name}] {condition:?} → {}", result.is_true());
+    Ok(result.is_true())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -691,6 +856,73 @@ mod tests {
         );
         assert!(display.contains("Available variables:"), "should show hint");
         assert!(display.contains("branch"), "should list available vars");
+        // `target` isn't close to any available name
+        assert_eq!(err.suggestion, None);
+    }
+
+    #[test]
+    fn test_expand_template_suggests_misspelled_var() {
+        let test = test_repo();
+        let mut vars = HashMap::new();
+        vars.insert("branch", "main");
+        vars.insert("worktree_path", "/tmp/wt");
+
+        let err = expand_template(
+            "cd {{ worktree_path }} && echo {{ brnach }}",
+            &vars,
+            false,
+            &test.repo,
+            "test",
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.suggestion,
+            Some(("brnach".to_string(), "branch".to_string()))
+        );
+        assert!(err.to_string().contains("did you mean"));
+
+        // Conditions are bare expressions; filters, tests, function calls,
+        // literals and loop targets aren't variables
+        let available = ["branch".to_string(), "port".to_string()];
+        assert_eq!(
+            suggest_variable("brach is matching 'release/*'", &available),
+            Some(("brach".to_string(), "branch".to_string()))
+        );
+        assert_eq!(
+            suggest_variable(
+                "{% for prt in ports() %}{{ 'brach' | sanitize }}{{ pot }}{% endfor %}",
+                &available
+            ),
+            Some(("pot".to_string(), "port".to_string()))
+        );
+        assert_eq!(suggest_variable("{{ branch }}", &available), None);
+    }
+
+    #[test]
+    fn test_branch_vars() {
+        let pattern = "[A-Z][A-Z0-9]+-[0-9]+";
+        let vars = branch_vars("feature/PROJ-42-login", pattern);
+        assert!(vars.contains(&("branch", "feature/PROJ-42-login".to_string())));
+        assert!(vars.contains(&("branch_slug", sanitize_db("feature/PROJ-42-login"))));
+        assert!(vars.contains(&("issue_key", "PROJ-42".to_string())));
+
+        // No key, an invalid pattern, or a pattern matching nothing leave it unset
+        for (branch, pattern) in [("main", pattern), ("PROJ-42", "["), ("main", "x*")] {
+            assert!(
+                !branch_vars(branch, pattern)
+                    .iter()
+                    .any(|(k, _)| *k == "issue_key"),
+                "{branch} / {pattern}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("branch", "branch"), 0);
+        assert_eq!(edit_distance("brnach", "branch"), 2);
+        assert_eq!(edit_distance("repo", "repo_path"), 5);
+        assert_eq!(edit_distance("", "abc"), 3);
     }
 
     #[test]
@@ -953,6 +1185,36 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_evaluate_condition() {
+        let test = test_repo();
+        let dir = test._dir.path();
+        std::fs::write(dir.join("package.json"), "{}").unwrap();
+        let mut vars = HashMap::new();
+        vars.insert("branch", "release/2.0");
+
+        let eval = |condition: &str, vars: &HashMap<&str, &str>| {
+            evaluate_condition(condition, vars, dir, &test.repo, "test")
+        };
+        assert!(eval("file_exists('package.json')", &vars).unwrap());
+        assert!(!eval("file_exists('Cargo.toml')", &vars).unwrap());
+        assert!(eval("branch is matching 'release/*'", &vars).unwrap());
+        assert!(!eval("branch is matching 'hotfix/*'", &vars).unwrap());
+        assert!(
+            eval(
+                "branch is matching 'release/*' and not file_exists('Cargo.toml')",
+                &vars
+            )
+            .unwrap()
+        );
+        // Optional variables can be checked for truthiness
+        assert!(!eval("upstream", &vars).unwrap());
+
+        let err = eval("branch is matching", &vars).unwrap_err();
+        assert!(err.message.starts_with("Failed to expand test:"), "{err}");
+        assert!(eval("file_exists(", &vars).is_err());
+    }
+
     #[test]
     fn test_hash_port_filter() {
         let test = test_repo();
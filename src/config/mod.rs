@@ -83,18 +83,23 @@ pub use deprecation::normalize_template_vars;
 pub use deprecation::write_migration_file;
 pub use deprecation::{DEPRECATED_SECTION_KEYS, key_belongs_in, warn_unknown_fields};
 pub use expansion::{
-    DEPRECATED_TEMPLATE_VARS, TEMPLATE_VARS, TemplateExpandError, expand_template,
-    redact_credentials, sanitize_branch_name, sanitize_db, short_hash,
+    DEPRECATED_TEMPLATE_VARS, TEMPLATE_VARS, TemplateExpandError, branch_vars, evaluate_condition,
+    expand_template, redact_credentials, sanitize_branch_name, sanitize_db, short_hash,
 };
 pub use hooks::HooksConfig;
 pub use project::{
-    ProjectCiConfig, ProjectConfig, ProjectListConfig,
+    BuildCacheMode, ProjectBranchesConfig, ProjectBuildCacheConfig, ProjectCiConfig, ProjectConfig,
+    ProjectDatabaseConfig, ProjectListConfig, ProjectUserHooksConfig,
     find_unknown_keys as find_unknown_project_keys,
 };
 pub use user::{
-    CommitConfig, CommitGenerationConfig, ListConfig, MergeConfig, OverridableConfig,
-    ResolvedConfig, SelectConfig, StageMode, UserConfig, UserProjectOverrides,
-    find_unknown_keys as find_unknown_user_keys, get_config_path, set_config_path,
+    AgentConfig, ColumnWidthLimit, CommitConfig, CommitGenerationConfig, DevEnvConfig,
+    DisplayConfig, DoneConfig, EditorConfig, FetchConfig, FinishMode, IssueTracker, JiraConfig,
+    LfsConfig, ListConfig, MergeConfig, NetworkConfig, OverridableConfig, PathStyle, PushConfig,
+    ReposConfig, ResolvedConfig, SecretScanConfig, SelectConfig, SetUpstream, SnapshotConfig,
+    StageMode, StartConfig, StartStep, SubmodulesConfig, ThemeConfig, TimeoutsConfig, TmuxConfig,
+    UiConfig, UserConfig, UserProjectOverrides, find_unknown_keys as find_unknown_user_keys,
+    get_config_path, set_config_path,
 };
 
 #[cfg(test)]
@@ -2,6 +2,8 @@
 //!
 //! Configuration that is checked into the repository and shared across all developers.
 
+use std::collections::BTreeMap;
+
 use config::ConfigError;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -53,6 +55,152 @@ pub struct ProjectCiConfig {
     pub platform: Option<String>,
 }
 
+/// Project-level shared build cache.
+///
+/// Directories like `target/` or `node_modules/` are linked from a cache
+/// shared by all worktrees instead of being rebuilt in each one.
+///
+/// # Example
+///
+/// ```toml
+/// [build-cache]
+/// dirs = ["target", "node_modules"]
+/// mode = "symlink"  # or "hardlink"
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, JsonSchema)]
+pub struct ProjectBuildCacheConfig {
+    /// Gitignored directories, relative to the worktree root, to link from the cache.
+    #[serde(default)]
+    pub dirs: Vec<String>,
+
+    /// How directories are linked into new worktrees.
+    #[serde(default)]
+    pub mode: BuildCacheMode,
+}
+
+/// How `[build-cache]` directories are linked into new worktrees.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum BuildCacheMode {
+    /// The directory is a symlink to the cache; all worktrees share one copy
+    #[default]
+    Symlink,
+    /// The directory is a real tree whose files are hardlinks into the cache,
+    /// so tools that reject symlinked directories still share file contents
+    Hardlink,
+}
+
+/// Branches guarded against deletion and direct pushes, and how new ones
+/// are named.
+///
+/// # Example
+///
+/// ```toml
+/// [branches]
+/// protected = ["main", "release/*"]
+/// naming = ["feature/*", "fix/*"]
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, JsonSchema)]
+pub struct ProjectBranchesConfig {
+    /// Branch names or patterns, where `*` matches any run of characters
+    /// (including `/`).
+    #[serde(default)]
+    pub protected: Vec<String>,
+
+    /// Patterns new branch names follow, as for `protected`. Names generated
+    /// by `wt switch --create --describe` are asked to match one.
+    #[serde(default)]
+    pub naming: Vec<String>,
+}
+
+/// A development database per worktree, for `wt step db-create` and
+/// `wt step db-drop`.
+///
+/// # Example
+///
+/// ```toml
+/// [database]
+/// create = "createdb -T app_dev app_{{ branch_slug }}"
+/// drop = "dropdb --if-exists app_{{ branch_slug }}"
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, JsonSchema)]
+pub struct ProjectDatabaseConfig {
+    /// Command that creates the worktree's database
+    #[serde(default)]
+    pub create: Option<String>,
+
+    /// Command that drops it again
+    #[serde(default)]
+    pub drop: Option<String>,
+}
+
+/// User hooks this project opts out of.
+///
+/// # Example
+///
+/// ```toml
+/// [user-hooks]
+/// skip = ["direnv", "copy-*"]
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, JsonSchema)]
+pub struct ProjectUserHooksConfig {
+    /// Names of user hooks not to run in this project; `*` matches any run
+    /// of characters, so `["*"]` skips them all.
+    #[serde(default)]
+    pub skip: Vec<String>,
+}
+
+impl ProjectUserHooksConfig {
+    /// Whether the user hook called `name` is skipped. Unnamed hooks are
+    /// only skipped by a pattern matching everything.
+    pub fn skips(&self, name: Option<&str>) -> bool {
+        self.skip.iter().any(|pattern| match name {
+            Some(name) => wildcard_match(pattern, name),
+            None => pattern.chars().all(|c| c == '*'),
+        })
+    }
+}
+
+impl ProjectBranchesConfig {
+    /// Whether `branch` matches one of the protected patterns.
+    pub fn is_protected(&self, branch: &str) -> bool {
+        self.protected
+            .iter()
+            .any(|pattern| wildcard_match(pattern, branch))
+    }
+
+    /// Whether `branch` matches one of the naming patterns (true when there
+    /// are none).
+    pub fn follows_naming(&self, branch: &str) -> bool {
+        self.naming.is_empty()
+            || self
+                .naming
+                .iter()
+                .any(|pattern| wildcard_match(pattern, branch))
+    }
+}
+
+/// Match `text` against `pattern`, where `*` matches any run of characters.
+pub(crate) fn wildcard_match(pattern: &str, text: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = text.strip_prefix(first) else {
+        return false;
+    };
+    let parts: Vec<&str> = parts.collect();
+    let Some((last, middle)) = parts.split_last() else {
+        // No `*`: exact match
+        return rest.is_empty();
+    };
+    for part in middle {
+        match rest.find(part) {
+            Some(idx) => rest = &rest[idx + part.len()..],
+            None => return false,
+        }
+    }
+    rest.len() >= last.len() && rest.ends_with(last)
+}
+
 impl ProjectListConfig {
     /// Returns true if any list configuration is set.
     pub fn is_configured(&self) -> bool {
@@ -65,6 +213,39 @@ impl ProjectConfig {
     pub fn ci_platform(&self) -> Option<&str> {
         self.ci.as_ref().and_then(|ci| ci.platform.as_deref())
     }
+
+    /// Get the directories of a sparse-checkout profile.
+    pub fn sparse_profile(&self, name: &str) -> Option<&[String]> {
+        self.sparse_profiles.get(name).map(Vec::as_slice)
+    }
+
+    /// Whether `branch` is listed under `[branches] protected`.
+    pub fn is_protected_branch(&self, branch: &str) -> bool {
+        self.branches
+            .as_ref()
+            .is_some_and(|branches| branches.is_protected(branch))
+    }
+
+    /// Get the shared build cache configuration, if any directories are listed.
+    pub fn build_cache(&self) -> Option<&ProjectBuildCacheConfig> {
+        self.build_cache
+            .as_ref()
+            .filter(|cache| !cache.dirs.is_empty())
+    }
+
+    /// Whether the project opts out of the user hook called `name`.
+    pub fn skips_user_hook(&self, name: Option<&str>) -> bool {
+        self.user_hooks
+            .as_ref()
+            .is_some_and(|user_hooks| user_hooks.skips(name))
+    }
+
+    /// Get the per-worktree database commands, if any are configured.
+    pub fn database(&self) -> Option<&ProjectDatabaseConfig> {
+        self.database
+            .as_ref()
+            .filter(|db| db.create.is_some() || db.drop.is_some())
+    }
 }
 
 /// Project-specific configuration with hooks.
@@ -87,6 +268,8 @@ impl ProjectConfig {
 /// - `{{ short_commit }}` - Current HEAD commit SHA (short 7-character hash)
 /// - `{{ remote }}` - Primary remote name (e.g., "origin")
 /// - `{{ upstream }}` - Upstream tracking branch (e.g., "origin/feature"), if configured
+/// - `{{ port }}` / `{{ port_end }}` - The branch's block of 10 ports (e.g., 3010 and 3019)
+/// - `{{ branch_slug }}` - Branch as a database-safe identifier (e.g., "feature_auth_x7k")
 ///
 /// Merge-related hooks (`pre-commit`, `pre-merge`, `post-merge`) also support:
 /// - `{{ target }}` - Target branch for the merge (e.g., "main")
@@ -101,6 +284,14 @@ pub struct ProjectConfig {
     #[serde(flatten, default)]
     pub hooks: HooksConfig,
 
+    /// Default branch, overriding detection (e.g. `"develop"`)
+    #[serde(
+        rename = "default-branch",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub default_branch: Option<String>,
+
     /// Configuration for `wt list` output
     #[serde(default)]
     pub list: Option<ProjectListConfig>,
@@ -108,6 +299,41 @@ pub struct ProjectConfig {
     /// CI configuration (platform override)
     #[serde(default)]
     pub ci: Option<ProjectCiConfig>,
+
+    /// Sparse-checkout profiles for `wt switch --create --sparse <profile>`.
+    ///
+    /// Maps each profile name to the directories (cone-mode patterns) its
+    /// worktrees check out.
+    #[serde(
+        rename = "sparse-profiles",
+        default,
+        skip_serializing_if = "BTreeMap::is_empty"
+    )]
+    pub sparse_profiles: BTreeMap<String, Vec<String>>,
+
+    /// Directories linked from a cache shared by all worktrees
+    #[serde(rename = "build-cache", default)]
+    pub build_cache: Option<ProjectBuildCacheConfig>,
+
+    /// Protected branches
+    #[serde(default)]
+    pub branches: Option<ProjectBranchesConfig>,
+
+    /// Per-worktree environment variables.
+    ///
+    /// Values are templates with the hook variables and filters (e.g.
+    /// `PORT = "{{ branch | hash_port }}"`). They're written to `.wt.env` in
+    /// each new worktree and exported by shell integration on `cd` into it.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub env: BTreeMap<String, String>,
+
+    /// Per-worktree database commands
+    #[serde(default)]
+    pub database: Option<ProjectDatabaseConfig>,
+
+    /// User hooks to skip in this project
+    #[serde(rename = "user-hooks", default)]
+    pub user_hooks: Option<ProjectUserHooksConfig>,
 }
 
 impl ProjectConfig {
@@ -388,6 +614,145 @@ platform = "gitlab"
         assert!(config.platform.is_none());
     }
 
+    // ============================================================================
+    // Sparse Profile Tests
+    // ============================================================================
+
+    #[test]
+    fn test_deserialize_sparse_profiles() {
+        let contents = r#"
+[sparse-profiles]
+web = ["packages/web", "packages/ui"]
+"#;
+        let config: ProjectConfig = toml::from_str(contents).unwrap();
+        assert_eq!(
+            config.sparse_profile("web"),
+            Some(["packages/web".to_string(), "packages/ui".to_string()].as_slice())
+        );
+        assert_eq!(config.sparse_profile("api"), None);
+        assert!(find_unknown_keys(contents).is_empty());
+    }
+
+    // ============================================================================
+    // Protected Branch Tests
+    // ============================================================================
+
+    #[test]
+    fn test_protected_branches() {
+        let contents = r#"
+[branches]
+protected = ["main", "release/*", "*-stable"]
+"#;
+        let config: ProjectConfig = toml::from_str(contents).unwrap();
+        assert!(config.is_protected_branch("main"));
+        assert!(config.is_protected_branch("release/1.0"));
+        assert!(config.is_protected_branch("release/1.0/hotfix"));
+        assert!(config.is_protected_branch("2.x-stable"));
+        assert!(!config.is_protected_branch("mainline"));
+        assert!(!config.is_protected_branch("release"));
+        assert!(!config.is_protected_branch("feature/main"));
+        assert!(!ProjectConfig::default().is_protected_branch("main"));
+        assert!(find_unknown_keys(contents).is_empty());
+    }
+
+    #[test]
+    fn test_branch_naming() {
+        let contents = r#"
+[branches]
+naming = ["feature/*", "fix/*"]
+"#;
+        let config: ProjectConfig = toml::from_str(contents).unwrap();
+        let branches = config.branches.unwrap();
+        assert!(branches.follows_naming("feature/dark-mode"));
+        assert!(branches.follows_naming("fix/flaky-login"));
+        assert!(!branches.follows_naming("dark-mode"));
+        assert!(ProjectBranchesConfig::default().follows_naming("anything"));
+        assert!(find_unknown_keys(contents).is_empty());
+    }
+
+    #[test]
+    fn test_skipped_user_hooks() {
+        let contents = r#"
+[user-hooks]
+skip = ["direnv", "copy-*"]
+"#;
+        let config: ProjectConfig = toml::from_str(contents).unwrap();
+        assert!(config.skips_user_hook(Some("direnv")));
+        assert!(config.skips_user_hook(Some("copy-tool-versions")));
+        assert!(!config.skips_user_hook(Some("notify")));
+        assert!(!config.skips_user_hook(None));
+        assert!(!ProjectConfig::default().skips_user_hook(Some("direnv")));
+        assert!(find_unknown_keys(contents).is_empty());
+
+        let config: ProjectConfig = toml::from_str("[user-hooks]\nskip = [\"*\"]").unwrap();
+        assert!(config.skips_user_hook(None));
+    }
+
+    #[test]
+    fn test_wildcard_match() {
+        assert!(wildcard_match("*", "anything"));
+        assert!(wildcard_match("a*b*c", "a-b-c"));
+        assert!(wildcard_match("a*a", "aa"));
+        assert!(!wildcard_match("a*a", "a"));
+        assert!(!wildcard_match("a*b", "a-c"));
+    }
+
+    // ============================================================================
+    // Build Cache Tests
+    // ============================================================================
+
+    #[test]
+    fn test_deserialize_build_cache() {
+        let contents = r#"
+[build-cache]
+dirs = ["target"]
+mode = "hardlink"
+"#;
+        let config: ProjectConfig = toml::from_str(contents).unwrap();
+        let cache = config.build_cache().unwrap();
+        assert_eq!(cache.dirs, vec!["target".to_string()]);
+        assert_eq!(cache.mode, BuildCacheMode::Hardlink);
+        assert!(find_unknown_keys(contents).is_empty());
+
+        // Mode defaults to symlink; an empty list disables the cache
+        let config: ProjectConfig = toml::from_str("[build-cache]\ndirs = []").unwrap();
+        assert_eq!(config.build_cache.unwrap().mode, BuildCacheMode::Symlink);
+    }
+
+    #[test]
+    fn test_deserialize_env() {
+        let contents = r#"
+[env]
+PORT = "{{ branch | hash_port }}"
+DATABASE_URL = "postgres://localhost/app_{{ branch | sanitize_db }}"
+"#;
+        let config: ProjectConfig = toml::from_str(contents).unwrap();
+        assert_eq!(
+            config.env.keys().collect::<Vec<_>>(),
+            vec!["DATABASE_URL", "PORT"]
+        );
+        assert!(find_unknown_keys(contents).is_empty());
+    }
+
+    #[test]
+    fn test_deserialize_database() {
+        let contents = r#"
+[database]
+create = "createdb app_{{ branch_slug }}"
+"#;
+        let config: ProjectConfig = toml::from_str(contents).unwrap();
+        let database = config.database().unwrap();
+        assert_eq!(
+            database.create.as_deref(),
+            Some("createdb app_{{ branch_slug }}")
+        );
+        assert!(database.drop.is_none());
+        assert!(find_unknown_keys(contents).is_empty());
+
+        let config: ProjectConfig = toml::from_str("[database]").unwrap();
+        assert!(config.database().is_none());
+    }
+
     // ============================================================================
     // find_unknown_keys Tests
     // ============================================================================
@@ -6,12 +6,14 @@
 use std::collections::HashMap;
 
 use crate::config::HooksConfig;
-use crate::config::expansion::{TemplateExpandError, expand_template};
+use crate::config::expansion::{TemplateExpandError, branch_vars, expand_template};
 
 use super::UserConfig;
 use super::merge::{Merge, merge_optional};
 use super::sections::{
-    CommitConfig, CommitGenerationConfig, ListConfig, MergeConfig, SelectConfig,
+    AgentConfig, CommitConfig, CommitGenerationConfig, DevEnvConfig, DoneConfig, EditorConfig,
+    FetchConfig, JiraConfig, LfsConfig, ListConfig, MergeConfig, PushConfig, SecretScanConfig,
+    SelectConfig, StartConfig, SubmodulesConfig, TimeoutsConfig, TmuxConfig,
 };
 
 /// Default worktree path template
@@ -123,6 +125,149 @@ impl UserConfig {
         merge_optional(self.configs.select.as_ref(), project_config)
     }
 
+    /// Returns the start config for a specific project.
+    ///
+    /// Merges project-specific settings with global settings, where project
+    /// settings take precedence for fields that are set.
+    pub fn start(&self, project: Option<&str>) -> Option<StartConfig> {
+        let project_config = project
+            .and_then(|p| self.projects.get(p))
+            .and_then(|c| c.overrides.start.as_ref());
+        merge_optional(self.configs.start.as_ref(), project_config)
+    }
+
+    /// Returns the done config for a specific project.
+    ///
+    /// Merges project-specific settings with global settings, where project
+    /// settings take precedence for fields that are set.
+    pub fn done(&self, project: Option<&str>) -> Option<DoneConfig> {
+        let project_config = project
+            .and_then(|p| self.projects.get(p))
+            .and_then(|c| c.overrides.done.as_ref());
+        merge_optional(self.configs.done.as_ref(), project_config)
+    }
+
+    /// Returns the tmux config for a specific project.
+    ///
+    /// Merges project-specific settings with global settings, where project
+    /// settings take precedence for fields that are set.
+    pub fn tmux(&self, project: Option<&str>) -> Option<TmuxConfig> {
+        let project_config = project
+            .and_then(|p| self.projects.get(p))
+            .and_then(|c| c.overrides.tmux.as_ref());
+        merge_optional(self.configs.tmux.as_ref(), project_config)
+    }
+
+    /// Returns the editor config for a specific project.
+    ///
+    /// Merges project-specific settings with global settings, where project
+    /// settings take precedence for fields that are set.
+    pub fn editor(&self, project: Option<&str>) -> Option<EditorConfig> {
+        let project_config = project
+            .and_then(|p| self.projects.get(p))
+            .and_then(|c| c.overrides.editor.as_ref());
+        merge_optional(self.configs.editor.as_ref(), project_config)
+    }
+
+    /// Returns the submodules config for a specific project.
+    ///
+    /// Merges project-specific settings with global settings, where project
+    /// settings take precedence for fields that are set.
+    pub fn submodules(&self, project: Option<&str>) -> Option<SubmodulesConfig> {
+        let project_config = project
+            .and_then(|p| self.projects.get(p))
+            .and_then(|c| c.overrides.submodules.as_ref());
+        merge_optional(self.configs.submodules.as_ref(), project_config)
+    }
+
+    /// Returns the lfs config for a specific project.
+    ///
+    /// Merges project-specific settings with global settings, where project
+    /// settings take precedence for fields that are set.
+    pub fn lfs(&self, project: Option<&str>) -> Option<LfsConfig> {
+        let project_config = project
+            .and_then(|p| self.projects.get(p))
+            .and_then(|c| c.overrides.lfs.as_ref());
+        merge_optional(self.configs.lfs.as_ref(), project_config)
+    }
+
+    /// Returns the fetch config for a specific project.
+    ///
+    /// Merges project-specific settings with global settings, where project
+    /// settings take precedence for fields that are set.
+    pub fn fetch(&self, project: Option<&str>) -> Option<FetchConfig> {
+        let project_config = project
+            .and_then(|p| self.projects.get(p))
+            .and_then(|c| c.overrides.fetch.as_ref());
+        merge_optional(self.configs.fetch.as_ref(), project_config)
+    }
+
+    /// Returns the timeouts config for a specific project.
+    ///
+    /// Merges project-specific settings with global settings, where project
+    /// settings take precedence for fields that are set.
+    pub fn timeouts(&self, project: Option<&str>) -> Option<TimeoutsConfig> {
+        let project_config = project
+            .and_then(|p| self.projects.get(p))
+            .and_then(|c| c.overrides.timeouts.as_ref());
+        merge_optional(self.configs.timeouts.as_ref(), project_config)
+    }
+
+    /// Returns the jira config for a specific project.
+    ///
+    /// Merges project-specific settings with global settings, where project
+    /// settings take precedence for fields that are set.
+    pub fn jira(&self, project: Option<&str>) -> Option<JiraConfig> {
+        let project_config = project
+            .and_then(|p| self.projects.get(p))
+            .and_then(|c| c.overrides.jira.as_ref());
+        merge_optional(self.configs.jira.as_ref(), project_config)
+    }
+
+    /// Returns the secret-scan config for a specific project.
+    ///
+    /// Merges project-specific settings with global settings, where project
+    /// settings take precedence for fields that are set.
+    pub fn secret_scan(&self, project: Option<&str>) -> Option<SecretScanConfig> {
+        let project_config = project
+            .and_then(|p| self.projects.get(p))
+            .and_then(|c| c.overrides.secret_scan.as_ref());
+        merge_optional(self.configs.secret_scan.as_ref(), project_config)
+    }
+
+    /// Returns the push config for a specific project.
+    ///
+    /// Merges project-specific settings with global settings, where project
+    /// settings take precedence for fields that are set.
+    pub fn push(&self, project: Option<&str>) -> Option<PushConfig> {
+        let project_config = project
+            .and_then(|p| self.projects.get(p))
+            .and_then(|c| c.overrides.push.as_ref());
+        merge_optional(self.configs.push.as_ref(), project_config)
+    }
+
+    /// Returns the dev-env config for a specific project.
+    ///
+    /// Merges project-specific settings with global settings, where project
+    /// settings take precedence for fields that are set.
+    pub fn dev_env(&self, project: Option<&str>) -> Option<DevEnvConfig> {
+        let project_config = project
+            .and_then(|p| self.projects.get(p))
+            .and_then(|c| c.overrides.dev_env.as_ref());
+        merge_optional(self.configs.dev_env.as_ref(), project_config)
+    }
+
+    /// Returns the agent config for a specific project.
+    ///
+    /// Merges project-specific settings with global settings, where project
+    /// settings take precedence for fields that are set.
+    pub fn agent(&self, project: Option<&str>) -> Option<AgentConfig> {
+        let project_config = project
+            .and_then(|p| self.projects.get(p))
+            .and_then(|c| c.overrides.agent.as_ref());
+        merge_optional(self.configs.agent.as_ref(), project_config)
+    }
+
     /// Returns effective hooks for a specific project.
     ///
     /// Merges global hooks with per-project hooks using append semantics.
@@ -169,11 +314,21 @@ impl UserConfig {
         };
         // Use native path format (not POSIX) since this is used for filesystem operations
         let repo_path = repo.repo_path().to_string_lossy().to_string();
-        let mut vars = HashMap::new();
+        let jira = self.jira(project).unwrap_or_default();
+        let branch_vars = branch_vars(branch, jira.key_pattern());
+        // Detection can query the remote; only pay for it when the template asks
+        let default_branch = template
+            .contains("default_branch")
+            .then(|| repo.default_branch())
+            .flatten();
+        let mut vars: HashMap<&str, &str> =
+            branch_vars.iter().map(|(k, v)| (*k, v.as_str())).collect();
         vars.insert("main_worktree", main_worktree);
         vars.insert("repo", main_worktree);
-        vars.insert("branch", branch);
         vars.insert("repo_path", repo_path.as_str());
+        if let Some(default_branch) = &default_branch {
+            vars.insert("default_branch", default_branch);
+        }
         expand_template(&template, &vars, false, repo, "worktree-path")
             .map(|p| shellexpand::tilde(&p).into_owned())
     }
@@ -23,8 +23,12 @@ pub use path::{get_config_path, set_config_path};
 pub use resolved::ResolvedConfig;
 pub use schema::{find_unknown_keys, valid_user_config_keys};
 pub use sections::{
-    CommitConfig, CommitGenerationConfig, ListConfig, MergeConfig, OverridableConfig, SelectConfig,
-    StageMode, UserProjectOverrides,
+    AgentConfig, ColumnWidthLimit, CommitConfig, CommitGenerationConfig, DevEnvConfig,
+    DisplayConfig, DoneConfig, EditorConfig, FetchConfig, FinishMode, IssueTracker, JiraConfig,
+    LfsConfig, ListConfig, MergeConfig, NetworkConfig, OverridableConfig, PathStyle, PushConfig,
+    ReposConfig, SecretScanConfig, SelectConfig, SetUpstream, SnapshotConfig, StageMode,
+    StartConfig, StartStep, SubmodulesConfig, ThemeConfig, TimeoutsConfig, TmuxConfig, UiConfig,
+    UserProjectOverrides,
 };
 
 /// User-level configuration for worktree path formatting and LLM integration.
@@ -84,7 +88,7 @@ pub struct UserConfig {
     #[serde(default)]
     pub projects: std::collections::BTreeMap<String, UserProjectOverrides>,
 
-    /// Settings that can be overridden per-project (worktree-path, list, commit, merge, select, hooks)
+    /// Settings that can be overridden per-project (worktree-path, list, commit, merge, select, start, done, tmux, editor, submodules, lfs, fetch, timeouts, jira, secret-scan, push, dev-env, agent, hooks)
     #[serde(flatten, default)]
     pub configs: OverridableConfig,
 
@@ -103,6 +107,34 @@ pub struct UserConfig {
         skip_serializing_if = "std::ops::Not::not"
     )]
     pub skip_commit_generation_prompt: bool,
+
+    /// Output colors (global only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub theme: Option<ThemeConfig>,
+
+    /// Display settings (global only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ui: Option<UiConfig>,
+
+    /// Path display (global only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display: Option<DisplayConfig>,
+
+    /// Retries for remote git commands (global only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network: Option<NetworkConfig>,
+
+    /// Automatic snapshots before destructive operations (global only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snapshot: Option<SnapshotConfig>,
+
+    /// Repositories for `wt list --all-repos` (global only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repos: Option<ReposConfig>,
+
+    /// Command aliases: name → built-in command line (global only)
+    #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+    pub alias: std::collections::BTreeMap<String, String>,
 }
 
 impl UserConfig {
@@ -158,12 +190,12 @@ impl UserConfig {
             && path::is_config_path_explicit()
         {
             // Warn if user explicitly specified a config path that doesn't exist
-            crate::styling::eprintln!(
-                "{}",
-                crate::styling::warning_message(format!(
+            crate::ops::warn(
+                &crate::styling::warning_message(format!(
                     "Config file not found: {}",
                     config_path.display()
                 ))
+                .to_string(),
             );
         }
 
@@ -188,9 +220,20 @@ impl UserConfig {
         Ok(config)
     }
 
-    /// Load configuration from a TOML string for testing.
-    #[cfg(test)]
-    pub(crate) fn load_from_str(content: &str) -> Result<Self, ConfigError> {
+    /// Read a single top-level section of the config file, such as `theme`.
+    ///
+    /// Used at startup for display settings, before the full config is
+    /// loaded, so they apply from the first line of output. Parse problems
+    /// are left for [`load`](Self::load) to report.
+    pub fn load_section<T: serde::de::DeserializeOwned>(key: &str) -> Option<T> {
+        let content = std::fs::read_to_string(get_config_path()?).ok()?;
+        let table: toml::Table = content.parse().ok()?;
+        table.get(key)?.clone().try_into().ok()
+    }
+
+    /// Parse and validate configuration from a TOML string, as `load` does
+    /// for the file (without environment overrides).
+    pub fn load_from_str(content: &str) -> Result<Self, ConfigError> {
         let config: Self =
             toml::from_str(content).map_err(|e| ConfigError::Message(e.to_string()))?;
         config.validate()?;
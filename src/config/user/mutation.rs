@@ -249,6 +249,26 @@ impl UserConfig {
         })
     }
 
+    /// Set a project's `worktree-path` template and save.
+    ///
+    /// Acquires lock, reloads from disk, sets the template, and saves.
+    /// Pass `None` for default config path, or `Some(path)` for testing.
+    pub fn set_project_worktree_path(
+        &mut self,
+        project: &str,
+        template: &str,
+        config_path: Option<&std::path::Path>,
+    ) -> Result<(), ConfigError> {
+        self.with_locked_mutation(config_path, |config| {
+            let project_config = config.projects.entry(project.to_string()).or_default();
+            if project_config.overrides.worktree_path.as_deref() == Some(template) {
+                return false;
+            }
+            project_config.overrides.worktree_path = Some(template.to_string());
+            true
+        })
+    }
+
     /// Set commit generation command and save.
     ///
     /// Sets `[commit.generation] command = ...` in the user config.
@@ -238,6 +238,13 @@ impl UserConfig {
     /// but not handled here, changes won't persist. Consider using a diff-based approach:
     /// compare self vs existing config and only update what changed.
     pub fn save_to(&self, config_path: &std::path::Path) -> Result<(), ConfigError> {
+        if crate::dry_run::skip(&format!(
+            "update {}",
+            crate::path::format_path_for_display(config_path)
+        )) {
+            return Ok(());
+        }
+
         // Create parent directory if it doesn't exist
         if let Some(parent) = config_path.parent() {
             std::fs::create_dir_all(parent).map_err(|e| {
@@ -6,7 +6,9 @@
 
 use super::UserConfig;
 use super::sections::{
-    CommitConfig, CommitGenerationConfig, ListConfig, MergeConfig, SelectConfig,
+    AgentConfig, CommitConfig, CommitGenerationConfig, DevEnvConfig, DoneConfig, EditorConfig,
+    FetchConfig, JiraConfig, LfsConfig, ListConfig, MergeConfig, PushConfig, SecretScanConfig,
+    SelectConfig, StartConfig, SubmodulesConfig, TimeoutsConfig, TmuxConfig,
 };
 
 /// All resolved configuration for a specific project context.
@@ -30,6 +32,19 @@ pub struct ResolvedConfig {
     /// Resolved commit generation config (handles deprecated `[commit-generation]` fallback)
     pub commit_generation: CommitGenerationConfig,
     pub select: SelectConfig,
+    pub start: StartConfig,
+    pub done: DoneConfig,
+    pub tmux: TmuxConfig,
+    pub editor: EditorConfig,
+    pub submodules: SubmodulesConfig,
+    pub lfs: LfsConfig,
+    pub fetch: FetchConfig,
+    pub timeouts: TimeoutsConfig,
+    pub jira: JiraConfig,
+    pub secret_scan: SecretScanConfig,
+    pub push: PushConfig,
+    pub dev_env: DevEnvConfig,
+    pub agent: AgentConfig,
 }
 
 impl ResolvedConfig {
@@ -41,6 +56,19 @@ impl ResolvedConfig {
             commit: config.commit(project).unwrap_or_default(),
             commit_generation: config.commit_generation(project),
             select: config.select(project).unwrap_or_default(),
+            start: config.start(project).unwrap_or_default(),
+            done: config.done(project).unwrap_or_default(),
+            tmux: config.tmux(project).unwrap_or_default(),
+            editor: config.editor(project).unwrap_or_default(),
+            submodules: config.submodules(project).unwrap_or_default(),
+            lfs: config.lfs(project).unwrap_or_default(),
+            fetch: config.fetch(project).unwrap_or_default(),
+            timeouts: config.timeouts(project).unwrap_or_default(),
+            jira: config.jira(project).unwrap_or_default(),
+            secret_scan: config.secret_scan(project).unwrap_or_default(),
+            push: config.push(project).unwrap_or_default(),
+            dev_env: config.dev_env(project).unwrap_or_default(),
+            agent: config.agent(project).unwrap_or_default(),
         }
     }
 }
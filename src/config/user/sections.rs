@@ -3,6 +3,10 @@
 //! These structs represent individual configuration sections that can be set
 //! globally or per-project. Each implements the `Merge` trait for layering.
 
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -134,6 +138,35 @@ pub struct ListConfig {
     /// (useful to override a global setting). Disabled when --full is used.
     #[serde(rename = "timeout-ms", skip_serializing_if = "Option::is_none")]
     pub timeout_ms: Option<u64>,
+
+    /// Columns to show, in order (e.g., `["branch", "ahead", "ci"]`).
+    /// Unset shows every column that fits.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub columns: Option<Vec<String>>,
+
+    /// Width limits for text columns, by column name
+    /// (e.g., `{ message = { min = 20, max = 60 }, branch = { max = 30 } }`).
+    /// Longer values are truncated.
+    #[serde(rename = "column-widths", skip_serializing_if = "Option::is_none")]
+    pub column_widths: Option<BTreeMap<String, ColumnWidthLimit>>,
+}
+
+/// Minimum and maximum width of one `wt list` column.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default, JsonSchema)]
+pub struct ColumnWidthLimit {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min: Option<usize>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max: Option<usize>,
+}
+
+impl ColumnWidthLimit {
+    /// Bring `width` within the limits; `max` wins if they conflict.
+    pub fn clamp(&self, width: usize) -> usize {
+        let width = self.min.map_or(width, |min| width.max(min));
+        self.max.map_or(width, |max| width.min(max))
+    }
 }
 
 impl ListConfig {
@@ -156,6 +189,16 @@ impl ListConfig {
     pub fn timeout_ms(&self) -> Option<u64> {
         self.timeout_ms
     }
+
+    /// Columns to show, in order (default: None, all columns)
+    pub fn columns(&self) -> Option<&[String]> {
+        self.columns.as_deref()
+    }
+
+    /// Width limits by column name (default: None, built-in widths)
+    pub fn column_widths(&self) -> Option<&BTreeMap<String, ColumnWidthLimit>> {
+        self.column_widths.as_ref()
+    }
 }
 
 impl Merge for ListConfig {
@@ -165,6 +208,11 @@ impl Merge for ListConfig {
             branches: other.branches.or(self.branches),
             remotes: other.remotes.or(self.remotes),
             timeout_ms: other.timeout_ms.or(self.timeout_ms),
+            columns: other.columns.clone().or_else(|| self.columns.clone()),
+            column_widths: other
+                .column_widths
+                .clone()
+                .or_else(|| self.column_widths.clone()),
         }
     }
 }
@@ -303,6 +351,658 @@ impl Merge for SelectConfig {
     }
 }
 
+/// A skippable step of the `wt start` workflow, in execution order.
+///
+/// Creating the worktree isn't listed: it always runs, and switches to the
+/// worktree when it already exists.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    clap::ValueEnum,
+    serde::Serialize,
+    serde::Deserialize,
+    JsonSchema,
+    strum::Display,
+    strum::EnumString,
+)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum StartStep {
+    /// Run the `setup` command in the new worktree
+    Setup,
+    /// Push the branch and open a draft PR/MR
+    Publish,
+    /// Launch the `open` command in the new worktree
+    Open,
+}
+
+impl StartStep {
+    /// All steps, in execution order.
+    pub const ALL: [StartStep; 3] = [Self::Setup, Self::Publish, Self::Open];
+}
+
+/// Where issues referenced by `wt start #N` and `wt switch --from-issue` live.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    JsonSchema,
+    strum::Display,
+)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum IssueTracker {
+    /// GitHub issues, via `gh`
+    Github,
+    /// GitLab issues, via `glab`
+    Gitlab,
+    /// Jira tickets, via the REST API (see `[jira]`)
+    Jira,
+}
+
+/// Configuration for the `wt start` command
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, JsonSchema)]
+pub struct StartConfig {
+    /// Branch name template when starting from an issue
+    ///
+    /// Variables: `{{ issue }}` (number), `{{ issue_key }}` (the same, as
+    /// named in hooks), `{{ title }}`, `{{ slug }}` (title lowercased,
+    /// non-alphanumerics collapsed to `-`), `{{ repo }}`, `{{ default_branch }}`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+
+    /// Issue tracker to fetch issues from (default: the forge of the remote)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tracker: Option<IssueTracker>,
+
+    /// Command run (blocking) in the new worktree after creation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub setup: Option<String>,
+
+    /// Command that replaces `wt` once the worktree is ready (editor, tmux, agent)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub open: Option<String>,
+
+    /// Push the branch and open a draft PR/MR (default: false)
+    #[serde(rename = "draft-pr", skip_serializing_if = "Option::is_none")]
+    pub draft_pr: Option<bool>,
+
+    /// Steps to skip by default
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skip: Option<Vec<StartStep>>,
+}
+
+impl StartConfig {
+    /// Default branch template for issues
+    pub const DEFAULT_BRANCH_TEMPLATE: &'static str = "{{ issue }}-{{ slug }}";
+
+    /// Branch name template for issues (default: `{{ issue }}-{{ slug }}`)
+    pub fn branch(&self) -> &str {
+        self.branch
+            .as_deref()
+            .unwrap_or(Self::DEFAULT_BRANCH_TEMPLATE)
+    }
+
+    /// Issue tracker (default: None, detected from the remote)
+    pub fn tracker(&self) -> Option<IssueTracker> {
+        self.tracker
+    }
+
+    /// Setup command (default: None)
+    pub fn setup(&self) -> Option<&str> {
+        self.setup.as_deref()
+    }
+
+    /// Open command (default: None)
+    pub fn open(&self) -> Option<&str> {
+        self.open.as_deref()
+    }
+
+    /// Open a draft PR/MR (default: false)
+    pub fn draft_pr(&self) -> bool {
+        self.draft_pr.unwrap_or(false)
+    }
+
+    /// Steps skipped by default (default: none)
+    pub fn skip(&self) -> &[StartStep] {
+        self.skip.as_deref().unwrap_or_default()
+    }
+}
+
+impl Merge for StartConfig {
+    fn merge_with(&self, other: &Self) -> Self {
+        Self {
+            branch: other.branch.clone().or_else(|| self.branch.clone()),
+            tracker: other.tracker.or(self.tracker),
+            setup: other.setup.clone().or_else(|| self.setup.clone()),
+            open: other.open.clone().or_else(|| self.open.clone()),
+            draft_pr: other.draft_pr.or(self.draft_pr),
+            skip: other.skip.clone().or_else(|| self.skip.clone()),
+        }
+    }
+}
+
+/// Configuration for the Jira issue tracker
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, JsonSchema)]
+pub struct JiraConfig {
+    /// Site URL, e.g. `https://acme.atlassian.net`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+
+    /// Account email for Jira Cloud; without it the token is sent as a
+    /// bearer token (Data Center personal access tokens)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+
+    /// Regex matching ticket keys in branch names (default: `[A-Z][A-Z0-9]+-[0-9]+`)
+    #[serde(rename = "key-pattern", skip_serializing_if = "Option::is_none")]
+    pub key_pattern: Option<String>,
+
+    /// Show a Ticket column in `wt list` (default: false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub list: Option<bool>,
+}
+
+impl JiraConfig {
+    /// Default ticket key pattern
+    pub const DEFAULT_KEY_PATTERN: &'static str = "[A-Z][A-Z0-9]+-[0-9]+";
+
+    /// Site URL without a trailing slash (default: None)
+    pub fn url(&self) -> Option<&str> {
+        self.url.as_deref().map(|url| url.trim_end_matches('/'))
+    }
+
+    /// Account email (default: None)
+    pub fn user(&self) -> Option<&str> {
+        self.user.as_deref()
+    }
+
+    /// Ticket key pattern (default: `[A-Z][A-Z0-9]+-[0-9]+`)
+    pub fn key_pattern(&self) -> &str {
+        self.key_pattern
+            .as_deref()
+            .unwrap_or(Self::DEFAULT_KEY_PATTERN)
+    }
+
+    /// Show the Ticket column in `wt list` (default: false)
+    pub fn list(&self) -> bool {
+        self.list.unwrap_or(false)
+    }
+}
+
+impl Merge for JiraConfig {
+    fn merge_with(&self, other: &Self) -> Self {
+        Self {
+            url: other.url.clone().or_else(|| self.url.clone()),
+            user: other.user.clone().or_else(|| self.user.clone()),
+            key_pattern: other
+                .key_pattern
+                .clone()
+                .or_else(|| self.key_pattern.clone()),
+            list: other.list.or(self.list),
+        }
+    }
+}
+
+/// Secret scanning of outgoing commits before `wt merge` and `wt step push`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, JsonSchema)]
+pub struct SecretScanConfig {
+    /// Scan outgoing commits for secrets (default: false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+
+    /// Minimum Shannon entropy, in bits per character, for a long token to
+    /// be reported (default: 4.0; 0 disables entropy checks)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entropy: Option<f64>,
+
+    /// Allowlist file, relative to the repository root (default: `.config/wt-secrets-allow`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowlist: Option<String>,
+
+    /// Extra patterns to report, as name = regex
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub rules: BTreeMap<String, String>,
+}
+
+impl SecretScanConfig {
+    /// Default entropy threshold
+    pub const DEFAULT_ENTROPY: f64 = 4.0;
+
+    /// Default allowlist file
+    pub const DEFAULT_ALLOWLIST: &'static str = ".config/wt-secrets-allow";
+
+    /// Whether to scan before pushing (default: false)
+    pub fn enabled(&self) -> bool {
+        self.enabled.unwrap_or(false)
+    }
+
+    /// Entropy threshold (default: 4.0)
+    pub fn entropy(&self) -> f64 {
+        self.entropy.unwrap_or(Self::DEFAULT_ENTROPY)
+    }
+
+    /// Allowlist file (default: `.config/wt-secrets-allow`)
+    pub fn allowlist(&self) -> &str {
+        self.allowlist.as_deref().unwrap_or(Self::DEFAULT_ALLOWLIST)
+    }
+
+    /// Extra patterns, as name → regex
+    pub fn rules(&self) -> &BTreeMap<String, String> {
+        &self.rules
+    }
+}
+
+impl Merge for SecretScanConfig {
+    fn merge_with(&self, other: &Self) -> Self {
+        let mut rules = self.rules.clone();
+        rules.extend(other.rules.clone());
+        Self {
+            enabled: other.enabled.or(self.enabled),
+            entropy: other.entropy.or(self.entropy),
+            allowlist: other.allowlist.clone().or_else(|| self.allowlist.clone()),
+            rules,
+        }
+    }
+}
+
+/// How `wt done` integrates the branch.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    clap::ValueEnum,
+    serde::Serialize,
+    serde::Deserialize,
+    JsonSchema,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum FinishMode {
+    /// Merge locally into the target, like `wt merge`
+    #[default]
+    Merge,
+    /// Push and open (or mark ready) a PR/MR
+    Pr,
+}
+
+/// Configuration for the `wt done` command
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, JsonSchema)]
+pub struct DoneConfig {
+    /// How to integrate the branch (default: merge)
+    /// Values: "merge", "pr"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub finish: Option<FinishMode>,
+
+    /// Wait for CI to pass before cleaning up (PR mode only, default: false)
+    #[serde(rename = "wait-ci", skip_serializing_if = "Option::is_none")]
+    pub wait_ci: Option<bool>,
+
+    /// Maximum time to wait for CI, in seconds (default: 1800)
+    #[serde(rename = "ci-timeout", skip_serializing_if = "Option::is_none")]
+    pub ci_timeout: Option<u64>,
+
+    /// Remove the worktree when finished (default: true)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remove: Option<bool>,
+}
+
+impl DoneConfig {
+    /// How to integrate the branch (default: Merge)
+    pub fn finish(&self) -> FinishMode {
+        self.finish.unwrap_or_default()
+    }
+
+    /// Wait for CI to pass before cleaning up (default: false)
+    pub fn wait_ci(&self) -> bool {
+        self.wait_ci.unwrap_or(false)
+    }
+
+    /// Maximum time to wait for CI, in seconds (default: 1800)
+    pub fn ci_timeout(&self) -> u64 {
+        self.ci_timeout.unwrap_or(1800)
+    }
+
+    /// Remove the worktree when finished (default: true)
+    pub fn remove(&self) -> bool {
+        self.remove.unwrap_or(true)
+    }
+}
+
+impl Merge for DoneConfig {
+    fn merge_with(&self, other: &Self) -> Self {
+        Self {
+            finish: other.finish.or(self.finish),
+            wait_ci: other.wait_ci.or(self.wait_ci),
+            ci_timeout: other.ci_timeout.or(self.ci_timeout),
+            remove: other.remove.or(self.remove),
+        }
+    }
+}
+
+/// Whether a branch pushed for the first time gets an upstream
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    clap::ValueEnum,
+    serde::Serialize,
+    serde::Deserialize,
+    JsonSchema,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum SetUpstream {
+    /// Prompt when interactive; set it otherwise
+    #[default]
+    Ask,
+    /// Always set `<remote>/<branch>` as upstream
+    Always,
+    /// Push without configuring tracking
+    Never,
+}
+
+/// Configuration for pushes to the remote (`wt start --draft-pr`, `wt done`)
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, JsonSchema)]
+pub struct PushConfig {
+    /// Upstream for a branch without one (default: ask)
+    /// Values: "ask", "always", "never"
+    #[serde(rename = "set-upstream", skip_serializing_if = "Option::is_none")]
+    pub set_upstream: Option<SetUpstream>,
+}
+
+impl PushConfig {
+    /// Upstream for a branch without one (default: Ask)
+    pub fn set_upstream(&self) -> SetUpstream {
+        self.set_upstream.unwrap_or_default()
+    }
+}
+
+impl Merge for PushConfig {
+    fn merge_with(&self, other: &Self) -> Self {
+        Self {
+            set_upstream: other.set_upstream.or(self.set_upstream),
+        }
+    }
+}
+
+/// Configuration for `wt tmux`
+///
+/// Names are templates with the same variables as hooks (`{{ branch }}`,
+/// `{{ repo }}`, `{{ worktree_name }}`, ...).
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, JsonSchema)]
+pub struct TmuxConfig {
+    /// Session used when running outside tmux (default: "{{ repo }}")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session: Option<String>,
+
+    /// Window name for a worktree (default: "{{ branch }}")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub window: Option<String>,
+}
+
+impl TmuxConfig {
+    /// Session name template (default: "{{ repo }}")
+    pub fn session(&self) -> &str {
+        self.session.as_deref().unwrap_or("{{ repo }}")
+    }
+
+    /// Window name template (default: "{{ branch }}")
+    pub fn window(&self) -> &str {
+        self.window.as_deref().unwrap_or("{{ branch }}")
+    }
+}
+
+impl Merge for TmuxConfig {
+    fn merge_with(&self, other: &Self) -> Self {
+        Self {
+            session: other.session.clone().or_else(|| self.session.clone()),
+            window: other.window.clone().or_else(|| self.window.clone()),
+        }
+    }
+}
+
+/// Configuration for `wt switch --open`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, JsonSchema)]
+pub struct EditorConfig {
+    /// Editor command, run with the worktree path appended (default: `$VISUAL`, then `$EDITOR`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub command: Option<String>,
+
+    /// Reuse an existing editor window instead of opening a new one (default: false)
+    #[serde(rename = "reuse-window", skip_serializing_if = "Option::is_none")]
+    pub reuse_window: Option<bool>,
+}
+
+impl EditorConfig {
+    /// Editor command, falling back to `$VISUAL` then `$EDITOR`
+    pub fn command(&self) -> Option<String> {
+        self.command
+            .clone()
+            .or_else(|| std::env::var("VISUAL").ok())
+            .or_else(|| std::env::var("EDITOR").ok())
+            .filter(|cmd| !cmd.trim().is_empty())
+    }
+
+    /// Whether to reuse an existing window (default: false)
+    pub fn reuse_window(&self) -> bool {
+        self.reuse_window.unwrap_or(false)
+    }
+}
+
+impl Merge for EditorConfig {
+    fn merge_with(&self, other: &Self) -> Self {
+        Self {
+            command: other.command.clone().or_else(|| self.command.clone()),
+            reuse_window: other.reuse_window.or(self.reuse_window),
+        }
+    }
+}
+
+/// Submodule handling for new and removed worktrees
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, JsonSchema)]
+pub struct SubmodulesConfig {
+    /// Run `git submodule update --init --recursive` in new worktrees (default: true)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub update: Option<bool>,
+}
+
+impl SubmodulesConfig {
+    /// Whether to initialize submodules after creating a worktree (default: true)
+    pub fn update(&self) -> bool {
+        self.update.unwrap_or(true)
+    }
+}
+
+impl Merge for SubmodulesConfig {
+    fn merge_with(&self, other: &Self) -> Self {
+        Self {
+            update: other.update.or(self.update),
+        }
+    }
+}
+
+/// Git LFS handling for new worktrees
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, JsonSchema)]
+pub struct LfsConfig {
+    /// Run `git lfs install --local` when the LFS hooks are missing (default: true)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub install: Option<bool>,
+
+    /// Run `git lfs pull` in new worktrees (default: true)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pull: Option<bool>,
+}
+
+impl LfsConfig {
+    /// Whether to install missing LFS hooks (default: true)
+    pub fn install(&self) -> bool {
+        self.install.unwrap_or(true)
+    }
+
+    /// Whether to pull LFS objects after creating a worktree (default: true)
+    pub fn pull(&self) -> bool {
+        self.pull.unwrap_or(true)
+    }
+}
+
+impl Merge for LfsConfig {
+    fn merge_with(&self, other: &Self) -> Self {
+        Self {
+            install: other.install.or(self.install),
+            pull: other.pull.or(self.pull),
+        }
+    }
+}
+
+/// direnv and mise setup in new worktrees
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, JsonSchema)]
+pub struct DevEnvConfig {
+    /// Run `direnv allow` in new worktrees with an `.envrc` (default: false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub direnv: Option<bool>,
+
+    /// Run `mise trust` and `mise install` in new worktrees with a mise
+    /// config (default: false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mise: Option<bool>,
+}
+
+impl DevEnvConfig {
+    /// Whether to allow `.envrc` files in new worktrees (default: false)
+    pub fn direnv(&self) -> bool {
+        self.direnv.unwrap_or(false)
+    }
+
+    /// Whether to trust mise configs and install their tools (default: false)
+    pub fn mise(&self) -> bool {
+        self.mise.unwrap_or(false)
+    }
+}
+
+impl Merge for DevEnvConfig {
+    fn merge_with(&self, other: &Self) -> Self {
+        Self {
+            direnv: other.direnv.or(self.direnv),
+            mise: other.mise.or(self.mise),
+        }
+    }
+}
+
+/// `wt agent` settings
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, JsonSchema)]
+pub struct AgentConfig {
+    /// Agent command, run in the new worktree; `{{ task }}` is the task
+    /// prompt, shell-quoted (e.g. `claude {{ task }}`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub command: Option<String>,
+}
+
+impl AgentConfig {
+    /// Agent command template (default: None)
+    pub fn command(&self) -> Option<&str> {
+        self.command.as_deref()
+    }
+}
+
+impl Merge for AgentConfig {
+    fn merge_with(&self, other: &Self) -> Self {
+        Self {
+            command: other.command.clone().or_else(|| self.command.clone()),
+        }
+    }
+}
+
+/// Fetch options for large repositories
+///
+/// Applied to the fetches `wt switch` runs to create worktrees (`pr:`/`mr:`
+/// refs). Only take effect where they can't change the repository's shape:
+/// `filter` in partial clones, `depth` in shallow clones.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, JsonSchema)]
+pub struct FetchConfig {
+    /// Partial clone filter, e.g. `blob:none` (blobless) or `tree:0` (treeless)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+
+    /// History depth for fetches in shallow clones
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depth: Option<u32>,
+}
+
+impl FetchConfig {
+    /// Extra `git fetch` arguments for a remote.
+    ///
+    /// `is_partial` and `is_shallow` describe the repository; options that
+    /// don't apply are dropped so a full clone is never made partial or shallow.
+    pub fn fetch_args(&self, is_partial: bool, is_shallow: bool) -> Vec<String> {
+        let mut args = Vec::new();
+        if is_partial && let Some(filter) = &self.filter {
+            args.push(format!("--filter={filter}"));
+        }
+        if is_shallow && let Some(depth) = self.depth {
+            args.push(format!("--depth={depth}"));
+        }
+        args
+    }
+}
+
+impl Merge for FetchConfig {
+    fn merge_with(&self, other: &Self) -> Self {
+        Self {
+            filter: other.filter.clone().or_else(|| self.filter.clone()),
+            depth: other.depth.or(self.depth),
+        }
+    }
+}
+
+/// Time limits for commands worktrunk runs in the foreground
+///
+/// Commands exceeding their limit are killed along with their process group.
+/// Unset means no limit.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, JsonSchema)]
+pub struct TimeoutsConfig {
+    /// Seconds before a hook command is killed; a hook's own `timeout` takes precedence
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hook: Option<u64>,
+
+    /// Seconds before a `wt switch --execute` command is killed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub execute: Option<u64>,
+}
+
+impl TimeoutsConfig {
+    /// Limit for hook commands
+    pub fn hook(&self) -> Option<Duration> {
+        self.hook.map(Duration::from_secs)
+    }
+
+    /// Limit for `--execute` commands
+    pub fn execute(&self) -> Option<Duration> {
+        self.execute.map(Duration::from_secs)
+    }
+}
+
+impl Merge for TimeoutsConfig {
+    fn merge_with(&self, other: &Self) -> Self {
+        Self {
+            hook: other.hook.or(self.hook),
+            execute: other.execute.or(self.execute),
+        }
+    }
+}
+
 /// Settings that can be set globally or per-project.
 ///
 /// This struct is flattened into both `UserConfig` (global) and `UserProjectOverrides`
@@ -345,6 +1045,62 @@ pub struct OverridableConfig {
     /// Configuration for the `wt switch` interactive picker
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub select: Option<SelectConfig>,
+
+    /// Configuration for the `wt start` command
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start: Option<StartConfig>,
+
+    /// Configuration for the `wt done` command
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub done: Option<DoneConfig>,
+
+    /// Configuration for the `wt tmux` command
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tmux: Option<TmuxConfig>,
+
+    /// Editor launched by `wt switch --open`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub editor: Option<EditorConfig>,
+
+    /// Submodule handling for new worktrees
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub submodules: Option<SubmodulesConfig>,
+
+    /// Git LFS handling for new worktrees
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lfs: Option<LfsConfig>,
+
+    /// Fetch options for large repositories
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fetch: Option<FetchConfig>,
+
+    /// Time limits for hooks and `--execute` commands
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeouts: Option<TimeoutsConfig>,
+
+    /// Configuration for the Jira issue tracker
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jira: Option<JiraConfig>,
+
+    /// Secret scanning before pushing
+    #[serde(
+        rename = "secret-scan",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub secret_scan: Option<SecretScanConfig>,
+
+    /// Configuration for pushes to the remote
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub push: Option<PushConfig>,
+
+    /// direnv and mise setup in new worktrees
+    #[serde(rename = "dev-env", default, skip_serializing_if = "Option::is_none")]
+    pub dev_env: Option<DevEnvConfig>,
+
+    /// `wt agent` command
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agent: Option<AgentConfig>,
 }
 
 impl OverridableConfig {
@@ -358,6 +1114,19 @@ impl OverridableConfig {
             && self.commit.is_none()
             && self.merge.is_none()
             && self.select.is_none()
+            && self.start.is_none()
+            && self.done.is_none()
+            && self.tmux.is_none()
+            && self.editor.is_none()
+            && self.submodules.is_none()
+            && self.lfs.is_none()
+            && self.fetch.is_none()
+            && self.timeouts.is_none()
+            && self.jira.is_none()
+            && self.secret_scan.is_none()
+            && self.push.is_none()
+            && self.dev_env.is_none()
+            && self.agent.is_none()
     }
 }
 
@@ -375,6 +1144,19 @@ impl Merge for OverridableConfig {
             commit: merge_optional(self.commit.as_ref(), other.commit.as_ref()),
             merge: merge_optional(self.merge.as_ref(), other.merge.as_ref()),
             select: merge_optional(self.select.as_ref(), other.select.as_ref()),
+            start: merge_optional(self.start.as_ref(), other.start.as_ref()),
+            done: merge_optional(self.done.as_ref(), other.done.as_ref()),
+            tmux: merge_optional(self.tmux.as_ref(), other.tmux.as_ref()),
+            editor: merge_optional(self.editor.as_ref(), other.editor.as_ref()),
+            submodules: merge_optional(self.submodules.as_ref(), other.submodules.as_ref()),
+            lfs: merge_optional(self.lfs.as_ref(), other.lfs.as_ref()),
+            fetch: merge_optional(self.fetch.as_ref(), other.fetch.as_ref()),
+            timeouts: merge_optional(self.timeouts.as_ref(), other.timeouts.as_ref()),
+            jira: merge_optional(self.jira.as_ref(), other.jira.as_ref()),
+            secret_scan: merge_optional(self.secret_scan.as_ref(), other.secret_scan.as_ref()),
+            push: merge_optional(self.push.as_ref(), other.push.as_ref()),
+            dev_env: merge_optional(self.dev_env.as_ref(), other.dev_env.as_ref()),
+            agent: merge_optional(self.agent.as_ref(), other.agent.as_ref()),
         }
     }
 }
@@ -436,3 +1218,243 @@ impl UserProjectOverrides {
             && self.overrides.is_empty()
     }
 }
+
+/// Output color overrides (`[theme]`).
+///
+/// Global only — not overridable per project, since colors follow the
+/// terminal rather than the repository.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, JsonSchema)]
+pub struct ThemeConfig {
+    /// Base palette: "dark" (default) or "light"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preset: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warning: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hint: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub success: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub progress: Option<String>,
+
+    /// Style of the info symbol (info text is unstyled)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub info: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prompt: Option<String>,
+
+    /// Section headings in `wt config show`, `wt perf report`, etc.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub heading: Option<String>,
+
+    /// Added-line counts in diff stats
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub addition: Option<String>,
+
+    /// Deleted-line counts in diff stats
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deletion: Option<String>,
+
+    /// Gutter beside quoted commands and config, e.g. `"on_bright_black"`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gutter: Option<String>,
+}
+
+impl ThemeConfig {
+    /// Build the theme: the preset, then per-key overrides.
+    ///
+    /// Invalid entries are skipped and reported as `key: reason`.
+    pub fn to_theme(&self) -> (crate::styling::Theme, Vec<String>) {
+        use crate::styling::{Theme, parse_style};
+
+        let mut errors = Vec::new();
+        let mut theme = match self.preset.as_deref() {
+            None => Theme::default(),
+            Some(name) => Theme::preset(name).unwrap_or_else(|| {
+                errors.push(format!(
+                    "preset: unknown preset `{name}` (expected dark or light)"
+                ));
+                Theme::default()
+            }),
+        };
+        for (key, value, slot) in [
+            ("error", &self.error, &mut theme.error),
+            ("warning", &self.warning, &mut theme.warning),
+            ("hint", &self.hint, &mut theme.hint),
+            ("success", &self.success, &mut theme.success),
+            ("progress", &self.progress, &mut theme.progress),
+            ("info", &self.info, &mut theme.info),
+            ("prompt", &self.prompt, &mut theme.prompt),
+            ("heading", &self.heading, &mut theme.heading),
+            ("addition", &self.addition, &mut theme.addition),
+            ("deletion", &self.deletion, &mut theme.deletion),
+            ("gutter", &self.gutter, &mut theme.gutter),
+        ] {
+            if let Some(spec) = value {
+                match parse_style(spec) {
+                    Ok(style) => *slot = style,
+                    Err(e) => errors.push(format!("{key}: {e}")),
+                }
+            }
+        }
+        (theme, errors)
+    }
+}
+
+/// Display settings (`[ui]`). Global only, like `[theme]`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, JsonSchema)]
+pub struct UiConfig {
+    /// Unicode message symbols (default: true). Set to false for ASCII
+    /// markers (`ERROR:`, `OK:`), same as `--no-emoji`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<bool>,
+
+    /// Clickable OSC 8 links for paths, branches, and CI in terminals that
+    /// support them (default: true)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hyperlinks: Option<bool>,
+
+    /// Language for messages, such as "fr" (default: English). Languages
+    /// without a catalog fall back to English.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lang: Option<String>,
+
+    /// Page `wt list`, `wt activity`, and `wt snapshot diff` output taller
+    /// than the terminal (default: true). Set to false to print it directly,
+    /// same as `--no-pager`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pager: Option<bool>,
+}
+
+impl UiConfig {
+    /// Unicode message symbols (default: true)
+    pub fn emoji(&self) -> bool {
+        self.emoji.unwrap_or(true)
+    }
+
+    /// Clickable links where supported (default: true)
+    pub fn hyperlinks(&self) -> bool {
+        self.hyperlinks.unwrap_or(true)
+    }
+
+    /// Page long output (default: true)
+    pub fn pager(&self) -> bool {
+        self.pager.unwrap_or(true)
+    }
+}
+
+/// Remote operations (`[network]`). Global only, like `[ui]`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, JsonSchema)]
+pub struct NetworkConfig {
+    /// Tries for `git fetch`, `push`, and `ls-remote` when they fail for
+    /// network reasons (default: 3). Set to 1 to turn retries off.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attempts: Option<u32>,
+
+    /// Skip network access and use cached data, like `--offline` (default: false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offline: Option<bool>,
+}
+
+impl NetworkConfig {
+    /// Tries per remote command (default: 3)
+    pub fn attempts(&self) -> u32 {
+        self.attempts.unwrap_or(crate::git::DEFAULT_ATTEMPTS)
+    }
+
+    /// Whether to stay offline (default: false)
+    pub fn offline(&self) -> bool {
+        self.offline.unwrap_or(false)
+    }
+}
+
+/// Automatic snapshots (`[snapshot]`). Global only, like `[ui]`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, JsonSchema)]
+pub struct SnapshotConfig {
+    /// Snapshot uncommitted changes before rebase, squash and forced removal,
+    /// so `wt undo` can bring them back (default: true)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto: Option<bool>,
+}
+
+impl SnapshotConfig {
+    /// Whether to snapshot before destructive operations (default: true)
+    pub fn auto(&self) -> bool {
+        self.auto.unwrap_or(true)
+    }
+}
+
+/// How paths appear in messages and `wt list`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum PathStyle {
+    /// Relative to the main worktree: `../repo.feature`
+    Relative,
+    /// Home directory as `~`: `~/code/repo.feature`
+    Home,
+    /// Full path: `/Users/alex/code/repo.feature`
+    Absolute,
+}
+
+/// Path display settings (`[display]`). Global only, like `[ui]`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, JsonSchema)]
+pub struct DisplayConfig {
+    /// Path style for messages and `wt list` (default: `~/...` in messages,
+    /// relative in `wt list`). Values: "relative", "home", "absolute"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paths: Option<PathStyle>,
+}
+
+/// Repositories shown by `wt list --all-repos` (`[repos]`). Global only.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, JsonSchema)]
+pub struct ReposConfig {
+    /// Repository paths (`~` expands)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub paths: Vec<String>,
+    /// Directories whose immediate subdirectories are scanned for repositories
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub roots: Vec<String>,
+}
+
+impl ReposConfig {
+    /// Configured repositories plus those found under `roots`, in config
+    /// order, deduplicated.
+    ///
+    /// A directory counts as a repository when it has a `.git` directory.
+    /// Linked worktrees (a `.git` file) are skipped, since they're listed with
+    /// their repository. Missing paths are skipped silently.
+    pub fn discover(&self) -> Vec<PathBuf> {
+        let expand = |p: &String| PathBuf::from(shellexpand::tilde(p).as_ref());
+        let is_repo = |p: &Path| p.join(".git").is_dir();
+
+        let mut found: Vec<PathBuf> = self
+            .paths
+            .iter()
+            .map(expand)
+            .filter(|p| is_repo(p))
+            .collect();
+        for root in self.roots.iter().map(expand) {
+            let Ok(entries) = std::fs::read_dir(&root) else {
+                continue;
+            };
+            let mut children: Vec<PathBuf> = entries
+                .filter_map(|e| e.ok().map(|e| e.path()))
+                .filter(|p| is_repo(p))
+                .collect();
+            children.sort();
+            found.extend(children);
+        }
+
+        let mut seen = std::collections::HashSet::new();
+        found.retain(|p| seen.insert(dunce::canonicalize(p).unwrap_or_else(|_| p.clone())));
+        found
+    }
+}
@@ -281,6 +281,8 @@ fn test_list_config_serde() {
         branches: Some(false),
         remotes: None,
         timeout_ms: Some(500),
+        columns: None,
+        column_widths: None,
     };
     let json = serde_json::to_string(&config).unwrap();
     let parsed: ListConfig = serde_json::from_str(&json).unwrap();
@@ -588,12 +590,16 @@ fn test_merge_list_config() {
         branches: Some(false),
         remotes: None,
         timeout_ms: Some(1000),
+        columns: None,
+        column_widths: None,
     };
     let override_config = ListConfig {
         full: None,           // Should fall back to base
         branches: Some(true), // Should override
         remotes: Some(true),  // Should override (base was None)
         timeout_ms: None,     // Should fall back to base
+        columns: None,
+        column_widths: None,
     };
 
     let merged = base.merge_with(&override_config);
@@ -603,6 +609,30 @@ fn test_merge_list_config() {
     assert_eq!(merged.timeout_ms, Some(1000)); // From base
 }
 
+#[test]
+fn test_list_column_widths() {
+    let config: UserConfig = toml::from_str(
+        r#"
+[list.column-widths]
+message = { min = 20, max = 60 }
+branch = { max = 30 }
+"#,
+    )
+    .unwrap();
+    let widths = config.list(None).unwrap().column_widths.unwrap();
+    let message = widths["message"];
+    assert_eq!(message.clamp(10), 20);
+    assert_eq!(message.clamp(40), 40);
+    assert_eq!(message.clamp(100), 60);
+    assert_eq!(widths["branch"].clamp(45), 30);
+    // Conflicting limits: max wins
+    let limit = ColumnWidthLimit {
+        min: Some(50),
+        max: Some(40),
+    };
+    assert_eq!(limit.clamp(10), 40);
+}
+
 #[test]
 fn test_merge_commit_config() {
     let base = CommitConfig {
@@ -939,6 +969,8 @@ fn test_effective_list_project_only() {
                     branches: None,
                     remotes: None,
                     timeout_ms: None,
+                    columns: None,
+                    column_widths: None,
                 }),
                 ..Default::default()
             },
@@ -1031,6 +1063,8 @@ fn test_list_config_accessor_methods_with_values() {
         branches: Some(true),
         remotes: Some(false),
         timeout_ms: Some(5000),
+        columns: None,
+        column_widths: None,
     };
     assert!(config.full());
     assert!(config.branches());
@@ -1829,7 +1863,10 @@ fn test_valid_user_config_keys_all_deserialize() {
             "worktree-path" => {
                 scalar_lines.push(format!("{key} = \"test-value\""));
             }
-            "list" | "commit" | "merge" | "select" | "commit-generation" => {
+            "list" | "commit" | "merge" | "select" | "start" | "done" | "theme" | "ui"
+            | "display" | "repos" | "tmux" | "editor" | "submodules" | "lfs" | "fetch"
+            | "timeouts" | "jira" | "secret-scan" | "push" | "dev-env" | "agent"
+            | "commit-generation" | "alias" | "network" | "snapshot" => {
                 // Table sections with minimal content
                 table_lines.push(format!("[{key}]"));
             }
@@ -2039,3 +2076,176 @@ fn test_reload_projects_from_permission_error() {
         "Expected path in error, got: {err}"
     );
 }
+
+#[test]
+fn test_theme_config() {
+    use crate::styling::{Theme, parse_style};
+
+    let config = UserConfig::load_from_str(
+        r#"
+[theme]
+preset = "light"
+error = "bright_red"
+hint = "blue dim"
+warning = "not-a-color"
+addition = "bright_green"
+gutter = "on_bright_black"
+"#,
+    )
+    .unwrap();
+    let (theme, errors) = config.theme.unwrap().to_theme();
+    assert_eq!(theme.error, parse_style("bright_red").unwrap());
+    assert_eq!(theme.hint, parse_style("blue dim").unwrap());
+    assert_eq!(theme.addition, parse_style("bright_green").unwrap());
+    assert_eq!(theme.gutter, parse_style("on_bright_black").unwrap());
+    assert_eq!(theme.heading, Theme::light().heading);
+    // Invalid entries fall back to the preset
+    assert_eq!(theme.warning, Theme::light().warning);
+    assert_eq!(
+        errors,
+        vec!["warning: unknown color or effect `not_a_color`"]
+    );
+
+    let (theme, errors) = ThemeConfig {
+        preset: Some("solarized".into()),
+        ..Default::default()
+    }
+    .to_theme();
+    assert_eq!(theme, Theme::dark());
+    assert_eq!(errors.len(), 1);
+}
+
+#[test]
+fn test_display_config() {
+    let config: UserConfig = toml::from_str("[display]\npaths = \"absolute\"\n").unwrap();
+    assert_eq!(
+        config.display.and_then(|display| display.paths),
+        Some(PathStyle::Absolute)
+    );
+    assert!(toml::from_str::<UserConfig>("[display]\npaths = \"short\"\n").is_err());
+}
+
+#[test]
+fn test_editor_config_project_override() {
+    let config: UserConfig = toml::from_str(
+        r#"
+[editor]
+command = "code"
+
+[projects."github.com/user/repo".editor]
+reuse-window = true
+"#,
+    )
+    .unwrap();
+    let editor = config.editor(Some("github.com/user/repo")).unwrap();
+    assert_eq!(editor.command().as_deref(), Some("code"));
+    assert!(editor.reuse_window());
+    assert!(!config.editor(None).unwrap().reuse_window());
+}
+
+#[test]
+fn test_lfs_config_defaults_and_override() {
+    let config: UserConfig = toml::from_str(
+        r#"
+[projects."github.com/user/repo".lfs]
+pull = false
+"#,
+    )
+    .unwrap();
+    let lfs = config.lfs(None).unwrap_or_default();
+    assert!(lfs.install());
+    assert!(lfs.pull());
+    let lfs = config.lfs(Some("github.com/user/repo")).unwrap();
+    assert!(lfs.install());
+    assert!(!lfs.pull());
+}
+
+#[test]
+fn test_dev_env_config_defaults_and_override() {
+    let config: UserConfig = toml::from_str(
+        r#"
+[dev-env]
+direnv = true
+
+[projects."github.com/user/repo".dev-env]
+mise = true
+"#,
+    )
+    .unwrap();
+    let dev_env = config.dev_env(None).unwrap();
+    assert!(dev_env.direnv());
+    assert!(!dev_env.mise());
+    let dev_env = config.dev_env(Some("github.com/user/repo")).unwrap();
+    assert!(dev_env.direnv());
+    assert!(dev_env.mise());
+    assert!(!DevEnvConfig::default().direnv());
+}
+
+#[test]
+fn test_push_config_set_upstream() {
+    let config: UserConfig = toml::from_str(
+        r#"
+[push]
+set-upstream = "never"
+
+[projects."github.com/user/repo".push]
+set-upstream = "always"
+"#,
+    )
+    .unwrap();
+    assert_eq!(PushConfig::default().set_upstream(), SetUpstream::Ask);
+    assert_eq!(
+        config.push(None).unwrap().set_upstream(),
+        SetUpstream::Never
+    );
+    assert_eq!(
+        config
+            .push(Some("github.com/user/repo"))
+            .unwrap()
+            .set_upstream(),
+        SetUpstream::Always
+    );
+}
+
+#[test]
+fn test_fetch_config_args_only_where_applicable() {
+    let fetch = FetchConfig {
+        filter: Some("blob:none".into()),
+        depth: Some(50),
+    };
+    assert!(fetch.fetch_args(false, false).is_empty());
+    assert_eq!(fetch.fetch_args(true, false), vec!["--filter=blob:none"]);
+    assert_eq!(
+        fetch.fetch_args(true, true),
+        vec!["--filter=blob:none", "--depth=50"]
+    );
+}
+
+#[test]
+fn test_repos_config_discover() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path().join("code");
+    for name in ["b-repo", "a-repo", "not-a-repo"] {
+        std::fs::create_dir_all(root.join(name)).unwrap();
+    }
+    std::fs::create_dir(root.join("a-repo/.git")).unwrap();
+    std::fs::create_dir(root.join("b-repo/.git")).unwrap();
+    // A linked worktree has a `.git` file, and is listed with its repository
+    std::fs::create_dir(root.join("a-repo.feature")).unwrap();
+    std::fs::write(root.join("a-repo.feature/.git"), "gitdir: ../a-repo/.git").unwrap();
+    let explicit = dir.path().join("explicit");
+    std::fs::create_dir_all(explicit.join(".git")).unwrap();
+
+    let repos = ReposConfig {
+        paths: vec![
+            explicit.to_string_lossy().into_owned(),
+            root.join("b-repo").to_string_lossy().into_owned(),
+            dir.path().join("missing").to_string_lossy().into_owned(),
+        ],
+        roots: vec![root.to_string_lossy().into_owned()],
+    };
+    assert_eq!(
+        repos.discover(),
+        vec![explicit, root.join("b-repo"), root.join("a-repo")]
+    );
+}
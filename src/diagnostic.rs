@@ -48,6 +48,7 @@ use ansi_str::AnsiStr;
 use anyhow::Context;
 use color_print::cformat;
 use minijinja::{Environment, context};
+use worktrunk::forge::forge_cli;
 use worktrunk::git::Repository;
 use worktrunk::path::format_path_for_display;
 use worktrunk::shell_exec::Cmd;
@@ -259,7 +260,7 @@ pub(crate) fn write_if_verbose(verbose: u8, command_line: &str, error_msg: Optio
 
 /// Check if the GitHub CLI (gh) is installed.
 fn is_gh_installed() -> bool {
-    Cmd::new("gh")
+    forge_cli("gh")
         .arg("--version")
         .run()
         .map(|o| o.status.success())
@@ -6,9 +6,10 @@
 //! - Text truncation with word boundaries
 //! - Terminal width detection
 
-use std::path::{Component, Path};
+use std::path::Path;
 
 use unicode_width::UnicodeWidthChar;
+use worktrunk::config::PathStyle;
 use worktrunk::path::format_path_for_display;
 use worktrunk::styling::visual_width;
 use worktrunk::utils::get_now;
@@ -63,24 +64,17 @@ fn format_relative_time_impl(timestamp: i64, now: i64) -> String {
 /// - Child of main: `./subdir`
 /// - Sibling: `../sibling`
 /// - Unrelated paths fall back to `~/...` or absolute
+///
+/// `[display] paths = "home"` or `"absolute"` replaces this with
+/// [`format_path_for_display`].
 pub(crate) fn shorten_path(path: &Path, main_worktree_path: &Path) -> String {
-    // Same path = main worktree
-    if path == main_worktree_path {
-        return ".".to_string();
-    }
-
-    // Try to compute relative path
-    if let Some(relative) = pathdiff::diff_paths(path, main_worktree_path) {
-        // If relative path starts with "..", it's a sibling/ancestor
-        // Otherwise prefix with "./" (or ".\" on Windows) for clarity
-        if relative.components().next() == Some(Component::ParentDir) {
-            relative.display().to_string()
-        } else {
-            format!(".{}{}", std::path::MAIN_SEPARATOR, relative.display())
+    match worktrunk::path::configured_path_style() {
+        Some((PathStyle::Home | PathStyle::Absolute, _)) => format_path_for_display(path),
+        Some((PathStyle::Relative, _)) | None => {
+            worktrunk::path::format_path_relative(path, main_worktree_path)
+                // Can't compute relative path (e.g., different drives on Windows)
+                .unwrap_or_else(|| format_path_for_display(path))
         }
-    } else {
-        // Can't compute relative path (e.g., different drives on Windows)
-        format_path_for_display(path)
     }
 }
 
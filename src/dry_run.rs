@@ -0,0 +1,299 @@
+//! Global `--dry-run` mode.
+//!
+//! Dry-run is enforced where processes are spawned rather than in each
+//! command. [`Cmd`](crate::shell_exec::Cmd) is default-deny: it only runs
+//! commands that [`is_read_only`] recognizes (read-only git, forge lookups,
+//! ...) or that the caller marked with
+//! [`Cmd::read_only`](crate::shell_exec::Cmd::read_only), and prints
+//! everything else instead of running it. Streamed shell commands (hooks,
+//! `--execute`) are always printed. Read-only commands still run, so commands
+//! can plan exactly as they would for real. Filesystem changes made directly
+//! go through [`skip`].
+//!
+//! Skipped commands report success with empty output. Commands that read
+//! back what a skipped command would have created (e.g. a worktree that was
+//! never added) see the repository as it is, so later steps may be reported
+//! against the current state.
+
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use color_print::cformat;
+
+use crate::path::format_path_for_display;
+use crate::styling::{eprintln, info_message};
+
+static DRY_RUN: AtomicBool = AtomicBool::new(false);
+
+/// Enable dry-run mode for the rest of the process.
+pub fn set_dry_run(enabled: bool) {
+    DRY_RUN.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether `--dry-run` is active.
+pub fn is_dry_run() -> bool {
+    DRY_RUN.load(Ordering::Relaxed)
+}
+
+/// Print a command that dry-run mode skipped.
+pub fn report(command: &str, dir: Option<&Path>) {
+    let location = dir
+        .map(|dir| cformat!(" in <bold>{}</>", format_path_for_display(dir)))
+        .unwrap_or_default();
+    eprintln!(
+        "{}",
+        info_message(cformat!("Would run <bright-black>{command}</>{location}"))
+    );
+}
+
+/// In dry-run mode, print a filesystem change worktrunk makes directly
+/// (`action` completes "Would ...") and return `true` so the caller skips it.
+pub fn skip(action: &str) -> bool {
+    if !is_dry_run() {
+        return false;
+    }
+    eprintln!("{}", info_message(format!("Would {action}")));
+    true
+}
+
+/// Result reported for a skipped command: success, no output.
+pub(crate) fn skipped_output() -> std::process::Output {
+    #[cfg(unix)]
+    use std::os::unix::process::ExitStatusExt;
+    #[cfg(windows)]
+    use std::os::windows::process::ExitStatusExt;
+
+    std::process::Output {
+        status: std::process::ExitStatus::from_raw(0),
+        stdout: Vec::new(),
+        stderr: Vec::new(),
+    }
+}
+
+/// Whether `program <args>` is known not to change anything, so it may run
+/// under `--dry-run`.
+///
+/// Covers read-only git, the `gh`/`glab` lookups worktrunk makes (views,
+/// lists, `api` GETs, `auth status`) and tmux/docker queries. Anything not
+/// listed counts as mutating.
+pub fn is_read_only<S: AsRef<str>>(program: &str, args: &[S]) -> bool {
+    let args: Vec<&str> = args.iter().map(AsRef::as_ref).collect();
+    let sub = |i: usize| args.get(i).copied().unwrap_or_default();
+    match program {
+        "git" => !git_mutates(&args),
+        "gh" | "glab" => match (sub(0), sub(1)) {
+            ("--version", _) | ("auth", "status") | ("config", "get") => true,
+            ("api", _) => api_is_get(&args[1..]),
+            ("pr" | "mr", "view" | "list" | "checks" | "status" | "diff")
+            | ("run", "view" | "list")
+            | ("ci", "get" | "list" | "view" | "status" | "trace")
+            | ("issue", "view" | "list")
+            | ("repo", "view") => true,
+            _ => false,
+        },
+        "tmux" => matches!(
+            sub(0),
+            "-V" | "has-session" | "list-sessions" | "list-windows" | "display-message"
+        ),
+        "docker" => matches!(sub(0), "ps" | "inspect" | "version"),
+        _ => false,
+    }
+}
+
+/// Whether `gh api`/`glab api` arguments make a GET request. Both CLIs
+/// switch to POST when fields are passed.
+fn api_is_get(args: &[&str]) -> bool {
+    let mut args = args.iter();
+    while let Some(&arg) = args.next() {
+        let method = match arg {
+            "-X" | "--method" => Some(args.next().copied().unwrap_or_default()),
+            _ => arg.strip_prefix("--method="),
+        };
+        if method.is_some_and(|m| !m.eq_ignore_ascii_case("GET")) {
+            return false;
+        }
+        let field = ["-f", "-F", "--field", "--raw-field", "--input"]
+            .iter()
+            .any(|f| arg == *f || arg.starts_with(&format!("{f}=")));
+        if field {
+            return false;
+        }
+    }
+    true
+}
+
+/// Whether `git <args>` changes refs, the index, the working tree, config or
+/// a remote.
+///
+/// Subcommands that are always read-only (`rev-parse`, `log`, `diff`, ...)
+/// and the read-only forms of mixed subcommands (`branch --list`,
+/// `worktree list`, `config --get`) return `false`. Everything else,
+/// including subcommands not listed here, is treated as mutating.
+pub fn git_mutates<S: AsRef<str>>(args: &[S]) -> bool {
+    let args: Vec<&str> = args.iter().map(AsRef::as_ref).collect();
+    // Skip global options: `-C <dir>`, `-c <key=value>`, `--no-pager`, ...
+    let mut rest = args.as_slice();
+    while let [first, tail @ ..] = rest {
+        match *first {
+            "-C" | "-c" | "--git-dir" | "--work-tree" => rest = tail.get(1..).unwrap_or(&[]),
+            flag if flag.starts_with('-') => rest = tail,
+            _ => break,
+        }
+    }
+    let [subcommand, rest @ ..] = rest else {
+        return false;
+    };
+    let has = |flags: &[&str]| {
+        rest.iter().any(|arg| {
+            flags
+                .iter()
+                .any(|f| arg == f || arg.starts_with(&format!("{f}=")))
+        })
+    };
+    let positionals = || rest.iter().filter(|arg| !arg.starts_with('-')).count();
+    let first = rest.first().copied();
+
+    match *subcommand {
+        "blame" | "cat-file" | "check-attr" | "check-ignore" | "check-ref-format" | "cherry"
+        | "count-objects" | "describe" | "diff" | "diff-files" | "diff-index" | "diff-tree"
+        | "for-each-ref" | "grep" | "log" | "ls-files" | "ls-remote" | "ls-tree" | "merge-base"
+        | "merge-tree" | "name-rev" | "range-diff" | "rev-list" | "rev-parse" | "shortlog"
+        | "show" | "show-ref" | "status" | "var" | "version" => false,
+        "apply" => !has(&["--check", "--stat", "--numstat", "--summary"]),
+        "branch" => {
+            has(&[
+                "-d",
+                "-D",
+                "--delete",
+                "-m",
+                "-M",
+                "--move",
+                "-c",
+                "-C",
+                "--copy",
+                "-f",
+                "--force",
+                "-u",
+                "--set-upstream-to",
+                "--unset-upstream",
+                "--edit-description",
+            ]) || (positionals() > 0
+                && !has(&[
+                    "-l",
+                    "--list",
+                    "-a",
+                    "--all",
+                    "-r",
+                    "--remotes",
+                    "--contains",
+                    "--merged",
+                    "--no-merged",
+                    "--points-at",
+                    "--show-current",
+                ]))
+        }
+        "bundle" => !matches!(first, Some("verify" | "list-heads")),
+        "config" => {
+            has(&[
+                "--unset",
+                "--unset-all",
+                "--add",
+                "--replace-all",
+                "--remove-section",
+                "--rename-section",
+            ]) || (positionals() >= 2 && !has(&["--get", "--get-all", "--get-regexp"]))
+        }
+        "credential" => !matches!(first, Some("fill")),
+        "hash-object" => has(&["-w"]),
+        "lfs" => !matches!(first, Some("ls-files" | "env" | "version" | "status")),
+        "merge-file" => !has(&["-p", "--stdout"]),
+        "notes" => !matches!(first, None | Some("list" | "show")),
+        "reflog" => matches!(first, Some("expire" | "delete")),
+        "remote" => matches!(
+            first,
+            Some(
+                "add"
+                    | "remove"
+                    | "rm"
+                    | "rename"
+                    | "set-url"
+                    | "set-head"
+                    | "set-branches"
+                    | "prune"
+                    | "update"
+            )
+        ),
+        "sparse-checkout" => !matches!(first, Some("list")),
+        "stash" => !matches!(first, Some("list" | "show" | "create")),
+        "submodule" => !matches!(first, None | Some("status" | "summary")),
+        "symbolic-ref" => has(&["-d", "--delete"]) || positionals() >= 2,
+        "tag" => {
+            has(&["-d", "--delete", "-a", "-s", "-m", "-f"])
+                || (positionals() > 0 && !has(&["-l", "--list", "--contains", "--points-at"]))
+        }
+        "worktree" => !matches!(first, Some("list")),
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_git_mutates() {
+        let mutates = |cmd: &str| git_mutates(&cmd.split_whitespace().collect::<Vec<_>>());
+
+        assert!(mutates("worktree add ../repo.feature -b feature main"));
+        assert!(mutates("worktree remove ../repo.feature"));
+        assert!(!mutates("worktree list --porcelain"));
+        assert!(mutates("branch -D feature"));
+        assert!(mutates("branch feature main"));
+        assert!(!mutates("branch --list feature"));
+        assert!(!mutates("branch --show-current"));
+        assert!(mutates("config worktrunk.history main"));
+        assert!(mutates("config --unset worktrunk.history"));
+        assert!(!mutates("config --get worktrunk.history"));
+        assert!(!mutates("config worktrunk.history"));
+        assert!(mutates("-C /repo push origin feature"));
+        assert!(!mutates("-C /repo rev-parse HEAD"));
+        assert!(mutates("stash push -m wt"));
+        assert!(!mutates("stash list"));
+        assert!(!mutates("log --oneline HEAD..main"));
+        assert!(!mutates("credential fill"));
+        assert!(mutates("credential approve"));
+        assert!(mutates("bundle create repo.bundle --all"));
+        assert!(!mutates("merge-file -p ours base theirs"));
+        assert!(mutates("mergetool --no-prompt"));
+        assert!(mutates("commit-tree abc -m wt"));
+        assert!(mutates("some-future-subcommand"));
+        assert!(!mutates(""));
+    }
+
+    #[test]
+    fn test_is_read_only() {
+        let read_only =
+            |program: &str, cmd: &str| is_read_only(program, &cmd.split(' ').collect::<Vec<_>>());
+
+        assert!(read_only("git", "rev-parse HEAD"));
+        assert!(!read_only("git", "push origin feature"));
+        assert!(read_only("gh", "pr view feature --json url"));
+        assert!(read_only("gh", "pr list --head feature"));
+        assert!(!read_only("gh", "pr create --head feature"));
+        assert!(!read_only("gh", "pr edit feature --base main"));
+        assert!(!read_only("gh", "run rerun 1 --failed"));
+        assert!(read_only("gh", "api repos/o/r/commits/abc/check-runs"));
+        assert!(!read_only("gh", "api -f name=x repos/o/r/labels"));
+        assert!(read_only("glab", "ci list --ref feature"));
+        assert!(!read_only("glab", "mr create --yes"));
+        assert!(!read_only(
+            "glab",
+            "api --method POST projects/:id/pipelines/1/retry"
+        ));
+        assert!(read_only("glab", "api --method GET projects/:id"));
+        assert!(read_only("tmux", "has-session -t wt"));
+        assert!(!read_only("tmux", "new-session -d -s wt"));
+        assert!(!read_only("devcontainer", "up --workspace-folder ."));
+        assert!(!read_only("sh", "-c true"));
+    }
+}
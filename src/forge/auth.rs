@@ -0,0 +1,236 @@
+//! Tokens for GitHub, GitLab, Bitbucket and Jira.
+//!
+//! Worktrunk talks to forges through their CLIs (`gh`, `glab`), which have
+//! their own logins. `wt auth login` adds a token that takes precedence over
+//! those logins without touching them: it goes to git's credential store
+//! (`git credential approve`), so it lands in whatever helper the user has
+//! configured — the macOS keychain, libsecret, Git Credential Manager — and
+//! is handed to each CLI invocation as `GH_TOKEN` / `GITLAB_TOKEN`.
+//!
+//! Tokens are filed under a dedicated `https://worktrunk/<forge>` key rather
+//! than the forge's host, so they never collide with the user's own git
+//! credentials or trigger a helper's host-specific login flow. Without a
+//! configured helper `git credential approve` silently stores nothing, so
+//! login checks for one up front and reads the token back afterwards.
+//!
+//! Resolution order per forge:
+//!
+//! 1. The forge's token environment variable, if set (passed through as is)
+//! 2. A token stored with `wt auth login`
+//! 3. Nothing — the CLI falls back to its own login
+//!
+//! Stored tokens are marked with `worktrunk.auth.<forge>` in the global git
+//! config, so the credential helper is only consulted for forges that have
+//! one; otherwise every `gh` call would start by querying (and possibly
+//! unlocking) the keychain.
+
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+
+use anyhow::{Context, bail};
+
+use crate::shell_exec::Cmd;
+
+/// A forge worktrunk can hold a token for.
+///
+/// Jira isn't a forge, but its API token lives in the same store.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    clap::ValueEnum,
+    strum::Display,
+    strum::EnumIter,
+    strum::IntoStaticStr,
+)]
+#[strum(serialize_all = "lowercase")]
+pub enum Forge {
+    #[value(name = "github")]
+    GitHub,
+    #[value(name = "gitlab")]
+    GitLab,
+    Bitbucket,
+    Jira,
+}
+
+impl Forge {
+    /// Environment variables the forge's tooling reads a token from, in
+    /// precedence order. The first is the one worktrunk sets.
+    pub fn token_env_vars(self) -> &'static [&'static str] {
+        match self {
+            Self::GitHub => &["GH_TOKEN", "GITHUB_TOKEN"],
+            Self::GitLab => &["GITLAB_TOKEN", "GITLAB_ACCESS_TOKEN"],
+            Self::Bitbucket => &["BITBUCKET_TOKEN"],
+            Self::Jira => &["JIRA_API_TOKEN"],
+        }
+    }
+
+    /// The forge whose CLI `program` is.
+    pub(super) fn for_cli(program: &str) -> Option<Self> {
+        match program {
+            "gh" => Some(Self::GitHub),
+            "glab" => Some(Self::GitLab),
+            _ => None,
+        }
+    }
+}
+
+/// Where a forge token came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenSource {
+    /// Set in the named environment variable
+    Env(&'static str),
+    /// Stored with `wt auth login`
+    Stored,
+}
+
+/// Host and username stored tokens are filed under; the path names the
+/// forge.
+const CREDENTIAL_HOST: &str = "worktrunk";
+const CREDENTIAL_USER: &str = "worktrunk";
+
+fn marker_key(forge: Forge) -> String {
+    format!("worktrunk.auth.{forge}")
+}
+
+/// The token worktrunk uses for `forge`, and where it came from.
+///
+/// Stored tokens are looked up once per process.
+pub fn token(forge: Forge) -> Option<(String, TokenSource)> {
+    for var in forge.token_env_vars() {
+        if let Ok(token) = std::env::var(var)
+            && !token.is_empty()
+        {
+            return Some((token, TokenSource::Env(var)));
+        }
+    }
+    static STORED: OnceLock<Mutex<HashMap<Forge, Option<String>>>> = OnceLock::new();
+    let mut stored = STORED
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+    stored
+        .entry(forge)
+        .or_insert_with(|| stored_token(forge))
+        .clone()
+        .map(|token| (token, TokenSource::Stored))
+}
+
+fn stored_token(forge: Forge) -> Option<String> {
+    let marked = git(&["config", "--global", "--get", &marker_key(forge)], None)
+        .is_ok_and(|out| out.trim() == "true");
+    if !marked {
+        return None;
+    }
+    fill(forge)
+}
+
+/// Ask the credential helper for the token filed under `forge`'s key.
+fn fill(forge: Forge) -> Option<String> {
+    let out = git(&["credential", "fill"], Some(&credential_request(forge)))
+        .map_err(|e| log::debug!("No stored {forge} token: {e:#}"))
+        .ok()?;
+    out.lines()
+        .find_map(|line| line.strip_prefix("password="))
+        .map(str::to_string)
+}
+
+/// Store `token` for `forge` in git's credential store.
+pub fn store_token(forge: Forge, token: &str) -> anyhow::Result<()> {
+    let token = token.trim();
+    if token.is_empty() || token.contains('\n') {
+        bail!("Token must be a single non-empty line");
+    }
+    let helper = git(
+        &[
+            "config",
+            "--get-urlmatch",
+            "credential.helper",
+            &credential_url(forge),
+        ],
+        None,
+    );
+    if !helper.is_ok_and(|out| !out.trim().is_empty()) {
+        bail!(
+            "No git credential helper is configured, so the {forge} token can't be stored; set `credential.helper` (e.g. osxkeychain, libsecret or manager) or export {} instead",
+            forge.token_env_vars()[0]
+        );
+    }
+    let request = format!("{}password={token}\n", credential_request(forge));
+    git(&["credential", "approve"], Some(&request))
+        .with_context(|| format!("Failed to store the {forge} token"))?;
+    if fill(forge).as_deref() != Some(token) {
+        bail!("The git credential helper didn't keep the {forge} token");
+    }
+    git(&["config", "--global", &marker_key(forge), "true"], None)?;
+    Ok(())
+}
+
+/// Remove the stored token for `forge`. Returns whether one was marked as
+/// stored.
+pub fn erase_token(forge: Forge) -> anyhow::Result<bool> {
+    let key = marker_key(forge);
+    let marked = git(&["config", "--global", "--get", &key], None).is_ok();
+    git(&["credential", "reject"], Some(&credential_request(forge)))
+        .with_context(|| format!("Failed to remove the {forge} token"))?;
+    if marked {
+        git(&["config", "--global", "--unset", &key], None)?;
+    }
+    Ok(marked)
+}
+
+fn credential_request(forge: Forge) -> String {
+    format!("protocol=https\nhost={CREDENTIAL_HOST}\npath={forge}\nusername={CREDENTIAL_USER}\n")
+}
+
+fn credential_url(forge: Forge) -> String {
+    format!("https://{CREDENTIAL_HOST}/{forge}")
+}
+
+/// Run a git command that must never prompt, feeding `stdin` if given.
+///
+/// `credential.useHttpPath` keeps the forge path in the credential key;
+/// git drops it for https otherwise.
+fn git(args: &[&str], stdin: Option<&str>) -> anyhow::Result<String> {
+    let mut cmd = Cmd::new("git")
+        .args(["-c", "credential.interactive=false"])
+        .args(["-c", "credential.useHttpPath=true"])
+        .args(args.iter().copied())
+        .env("GIT_TERMINAL_PROMPT", "0")
+        .env_remove("GIT_ASKPASS")
+        .env_remove("SSH_ASKPASS");
+    if let Some(stdin) = stdin {
+        cmd = cmd.stdin_bytes(format!("{stdin}\n"));
+    }
+    let output = cmd.run().context("Failed to run git")?;
+    if !output.status.success() {
+        bail!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_forge_names_and_cli_mapping() {
+        assert_eq!(Forge::GitHub.to_string(), "github");
+        assert_eq!(marker_key(Forge::GitLab), "worktrunk.auth.gitlab");
+        assert_eq!(Forge::for_cli("gh"), Some(Forge::GitHub));
+        assert_eq!(Forge::for_cli("glab"), Some(Forge::GitLab));
+        assert_eq!(Forge::for_cli("git"), None);
+        assert_eq!(
+            credential_request(Forge::Bitbucket),
+            "protocol=https\nhost=worktrunk\npath=bitbucket\nusername=worktrunk\n"
+        );
+        assert_eq!(credential_url(Forge::Jira), "https://worktrunk/jira");
+    }
+}
@@ -0,0 +1,29 @@
+//! Forge (GitHub, GitLab, Bitbucket) access.
+//!
+//! Worktrunk reaches forges through their CLIs rather than HTTP. Every CLI
+//! invocation is built with [`forge_cli`], which adds the stored token
+//! (`auth`) and the retry policy (`retry`), so CI status, PR creation and
+//! `pr:`/`mr:` lookups all authenticate and back off the same way.
+
+mod auth;
+mod retry;
+
+pub use auth::{Forge, TokenSource, erase_token, store_token, token};
+
+use crate::shell_exec::Cmd;
+
+/// A forge CLI command with the stored token, if any, in its environment,
+/// retrying transient failures.
+///
+/// Tokens from the environment already reach the CLI, so only stored tokens
+/// are added.
+pub fn forge_cli(program: &str) -> Cmd {
+    let cmd = Cmd::new(program).retry(retry::FORGE_RETRY).network();
+    let Some(forge) = Forge::for_cli(program) else {
+        return cmd;
+    };
+    match token(forge) {
+        Some((token, TokenSource::Stored)) => cmd.env(forge.token_env_vars()[0], token),
+        _ => cmd,
+    }
+}
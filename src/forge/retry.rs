@@ -0,0 +1,119 @@
+//! When to retry a failed forge CLI call.
+//!
+//! The CLIs surface HTTP failures as text, so classification works on their
+//! output:
+//!
+//! - Transient failures (network errors, gateway errors, timeouts) are
+//!   retried with exponential backoff.
+//! - Rate limits are retried only when the response says when the limit
+//!   lifts (`Retry-After`, `X-RateLimit-Reset`) and that's soon. A primary
+//!   rate limit that resets in half an hour fails immediately — the caller
+//!   degrades (e.g. `wt list` shows `⚠`) rather than hanging.
+//! - Anything else (auth, not found, validation) fails immediately.
+
+use std::process::Output;
+use std::time::Duration;
+
+use crate::shell_exec::RetryPolicy;
+
+/// Retries after the first attempt.
+const MAX_RETRIES: u32 = 2;
+
+/// First backoff delay; doubles each retry.
+const BASE_DELAY: Duration = Duration::from_millis(500);
+
+/// Longest rate-limit wait worth blocking on.
+const MAX_RATE_LIMIT_WAIT: Duration = Duration::from_secs(10);
+
+/// Retry policy for [`Cmd::retry`](crate::shell_exec::Cmd::retry) on forge
+/// CLI calls.
+pub(super) const FORGE_RETRY: RetryPolicy = RetryPolicy {
+    attempts: || MAX_RETRIES + 1,
+    delay: forge_retry_delay,
+};
+
+/// How long to wait before retrying a failed forge CLI call, or `None` to
+/// give up.
+fn forge_retry_delay(attempt: u32, output: &Output) -> Option<Duration> {
+    let text = format!(
+        "{}\n{}",
+        String::from_utf8_lossy(&output.stderr),
+        String::from_utf8_lossy(&output.stdout)
+    );
+    retry_delay(attempt, &text, crate::utils::get_now())
+}
+
+fn retry_delay(attempt: u32, text: &str, now: u64) -> Option<Duration> {
+    if attempt >= MAX_RETRIES {
+        return None;
+    }
+    let lower = text.to_ascii_lowercase();
+    if ["rate limit", "too many requests"]
+        .iter()
+        .any(|p| lower.contains(p))
+    {
+        let wait = header(&lower, "retry-after")
+            .map(Duration::from_secs)
+            .or_else(|| {
+                header(&lower, "x-ratelimit-reset")
+                    .map(|reset| Duration::from_secs(reset.saturating_sub(now)))
+            })?;
+        return (wait <= MAX_RATE_LIMIT_WAIT).then_some(wait);
+    }
+    let transient = [
+        "timeout",
+        "timed out",
+        "connection reset",
+        "connection refused",
+        "network is unreachable",
+        "tls handshake",
+        "unexpected eof",
+        "bad gateway",
+        "service unavailable",
+    ];
+    transient
+        .iter()
+        .any(|p| lower.contains(p))
+        .then(|| BASE_DELAY * 2u32.pow(attempt))
+}
+
+/// Numeric value of a `name: value` header line in (lowercased) CLI output.
+fn header(text: &str, name: &str) -> Option<u64> {
+    text.lines().find_map(|line| {
+        let (key, value) = line.split_once(':')?;
+        if key.trim() == name {
+            value.trim().parse().ok()
+        } else {
+            None
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_delay() {
+        // Transient: exponential backoff, then give up
+        let err = "error connecting to api.github.com: connection reset by peer";
+        assert_eq!(retry_delay(0, err, 0), Some(Duration::from_millis(500)));
+        assert_eq!(retry_delay(1, err, 0), Some(Duration::from_secs(1)));
+        assert_eq!(retry_delay(2, err, 0), None);
+
+        // Rate limit with a near reset waits for it; a far one gives up
+        let limited = "HTTP 429: Too Many Requests\nRetry-After: 3\n";
+        assert_eq!(retry_delay(0, limited, 0), Some(Duration::from_secs(3)));
+        let reset = "API rate limit exceeded\nX-RateLimit-Reset: 1000005\n";
+        assert_eq!(
+            retry_delay(0, reset, 1_000_000),
+            Some(Duration::from_secs(5))
+        );
+        assert_eq!(retry_delay(0, reset, 0), None);
+        assert_eq!(retry_delay(0, "API rate limit exceeded", 0), None);
+
+        // Permanent failures aren't retried
+        assert_eq!(retry_delay(0, "HTTP 404: Not Found", 0), None);
+        assert_eq!(retry_delay(0, "", 0), None);
+    }
+}
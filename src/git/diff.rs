@@ -1,10 +1,11 @@
 //! Git diff utilities for parsing and formatting diff statistics.
 
 use ansi_str::AnsiStr;
-use color_print::cformat;
+
+use crate::styling::{paint, theme};
 
 /// Line-level diff totals (added/deleted counts) used across git operations.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
 pub struct LineDiff {
     pub added: usize,
     pub deleted: usize,
@@ -106,10 +107,10 @@ impl DiffStats {
             parts.push(format!("{} file{}", self.files, s));
         }
         if self.insertions > 0 {
-            parts.push(cformat!("<green>+{}</>", self.insertions));
+            parts.push(paint(theme().addition, &format!("+{}", self.insertions)));
         }
         if self.deletions > 0 {
-            parts.push(cformat!("<red>-{}</>", self.deletions));
+            parts.push(paint(theme().deletion, &format!("-{}", self.deletions)));
         }
         parts
     }
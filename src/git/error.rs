@@ -8,24 +8,52 @@
 //!
 //! - **`WorktrunkError`** - A minimal enum for semantic errors that need
 //!   special handling (exit codes, silent errors).
+//!
+//! [`exit_code`] maps both to the process exit status; see [`exit_codes`].
 
 use std::borrow::Cow;
 use std::path::PathBuf;
 
-use color_print::{cformat, cwrite};
+use color_print::cformat;
 use shell_escape::escape;
 
 use super::HookType;
 use crate::path::format_path_for_display;
 use crate::styling::{
-    ERROR_SYMBOL, HINT_SYMBOL, error_message, format_bash_with_gutter, format_with_gutter,
-    hint_message, info_message, suggest_command,
+    error_message, format_bash_with_gutter, format_with_gutter, hint_message, info_message,
+    suggest_command,
 };
 
+/// Process exit codes by class of failure.
+///
+/// Scripts can branch on these without parsing stderr. Commands run on the
+/// user's behalf (`--execute`, `wt step for-each`) exit with the child's own
+/// code, and a child killed by a signal exits with `128 + signal`.
+pub mod exit_codes {
+    /// Anything not covered by a more specific class
+    pub const FAILURE: i32 = 1;
+    /// Invalid arguments or flag combinations (matches clap)
+    pub const USAGE: i32 = 2;
+    /// The repository isn't in a state the command can act on: missing or
+    /// existing branch, dirty or locked worktree, detached HEAD
+    pub const GIT_STATE: i32 = 3;
+    /// A hook command failed
+    pub const HOOK_FAILED: i32 = 4;
+    /// Merge, rebase or push would conflict
+    pub const CONFLICT: i32 = 5;
+    /// Commands needed approval (or other input) that wasn't given
+    pub const NOT_APPROVED: i32 = 6;
+    /// A remote or forge API couldn't be reached or refused the request
+    pub const NETWORK: i32 = 7;
+    /// A command exceeded its timeout (matches `timeout(1)`)
+    pub const TIMED_OUT: i32 = 124;
+}
+
 /// Platform-specific reference type (PR vs MR).
 ///
 /// Used to unify error handling for GitHub PRs and GitLab MRs.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum RefType {
     /// GitHub Pull Request
     Pr,
@@ -97,7 +125,7 @@ pub trait RefContext {
 ///
 /// Separates the command string from exit information so Display impls
 /// can style each part differently (bold command, gray exit code).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize)]
 pub struct FailedCommand {
     /// The full command string, e.g., "git worktree add /path -b fix main"
     pub command: String,
@@ -105,6 +133,73 @@ pub struct FailedCommand {
     pub exit_info: String,
 }
 
+/// A git command that exited unsuccessfully.
+///
+/// Displays as git's own output, which is what most messages quote. Errors
+/// built from one (`PushFailed`, say) keep it as their [`source`], so `-v`
+/// and JSON errors can show exactly what ran.
+///
+/// [`source`]: std::error::Error::source
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct GitCommandError {
+    /// Arguments after `git`
+    pub args: Vec<String>,
+    /// Directory the command ran in
+    pub dir: PathBuf,
+    /// Exit code, or `None` when killed by a signal
+    pub exit_code: Option<i32>,
+    /// stderr then stdout, trimmed
+    pub output: String,
+}
+
+impl GitCommandError {
+    pub(crate) fn from_output(
+        args: &[&str],
+        dir: &std::path::Path,
+        output: &std::process::Output,
+    ) -> Self {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        // Normalize carriage returns to newlines for consistent output
+        // Git uses \r for progress updates; in non-TTY contexts this causes snapshot instability
+        let stderr = stderr.replace('\r', "\n");
+        // Some git commands print errors to stdout (e.g., `commit` with nothing to commit)
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let output_text = [stderr.trim(), stdout.trim()]
+            .into_iter()
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n");
+        Self {
+            args: args.iter().map(|arg| arg.to_string()).collect(),
+            dir: dir.to_path_buf(),
+            exit_code: output.status.code(),
+            output: output_text,
+        }
+    }
+
+    /// The command line, shell-quoted.
+    pub fn command(&self) -> String {
+        std::iter::once("git".into())
+            .chain(self.args.iter().map(|arg| escape(arg.into())))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// The failed git command behind `err`, if any.
+    pub fn find(err: &anyhow::Error) -> Option<&GitCommandError> {
+        err.chain()
+            .find_map(|e| e.downcast_ref::<GitCommandError>())
+    }
+}
+
+impl std::fmt::Display for GitCommandError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.output)
+    }
+}
+
+impl std::error::Error for GitCommandError {}
+
 /// Domain errors for git and worktree operations.
 ///
 /// This enum provides structured error data that can be pattern-matched and tested.
@@ -122,7 +217,11 @@ pub struct FailedCommand {
 ///     println!("Branch {} exists", branch);
 /// }
 /// ```
-#[derive(Debug, Clone)]
+///
+/// Serializes as an object with the variant name in `kind` (kebab-case)
+/// alongside its fields; see [`error_json`].
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
 pub enum GitError {
     // Git state errors
     DetachedHead {
@@ -182,6 +281,8 @@ pub enum GitError {
         branch: String,
         path: PathBuf,
         error: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        source: Option<GitCommandError>,
     },
     CannotRemoveMainWorktree,
     WorktreeLocked {
@@ -189,6 +290,14 @@ pub enum GitError {
         path: PathBuf,
         reason: Option<String>,
     },
+    /// Branch matches `[branches] protected` in the project config
+    ProtectedBranch {
+        branch: String,
+        /// What was refused, e.g. "delete" or "push to"
+        action: String,
+        /// Whether `--force` overrides the protection
+        force_hint: bool,
+    },
 
     // Merge/push errors
     ConflictingChanges {
@@ -204,17 +313,58 @@ pub enum GitError {
     RebaseConflict {
         target_branch: String,
         git_output: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        source: Option<GitCommandError>,
     },
     NotRebased {
         target_branch: String,
     },
+    /// `wt backport` stopped on a commit that didn't apply cleanly
+    CherryPickConflict {
+        target_branch: String,
+        commit: String,
+        git_output: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        source: Option<GitCommandError>,
+    },
     PushFailed {
         target_branch: String,
         error: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        source: Option<GitCommandError>,
+    },
+    /// `[secret-scan]` found possible secrets in the commits being pushed
+    SecretsDetected {
+        target_branch: String,
+        count: usize,
+        /// One `path:line  rule  redacted` entry per finding
+        report: String,
+        /// Allowlist file for false positives
+        allowlist: String,
+    },
+    /// `commit.gpgsign` is on but git could not sign a commit
+    SigningFailed {
+        /// What was being committed, e.g. "squash"
+        operation: String,
+        /// The key git tried, e.g. "ssh key ~/.ssh/id_ed25519.pub"
+        key: String,
+        git_output: String,
     },
 
     // Validation/other errors
     NotInteractive,
+    /// A confirmation or picker was needed under `--no-input` or without a terminal
+    InputRequired {
+        /// The question that would have been asked
+        prompt: String,
+        /// How to answer it up front, e.g. "Add --yes to accept"
+        hint: String,
+    },
+    /// Another `wt` process holds the repository's operation lock
+    OperationInProgress {
+        /// What the holder recorded, e.g. "wt remove (pid 4242)"
+        holder: Option<String>,
+    },
     HookCommandNotFound {
         name: String,
         available: Vec<String>,
@@ -274,7 +424,65 @@ pub enum GitError {
     },
 }
 
-impl std::error::Error for GitError {}
+impl std::error::Error for GitError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            GitError::WorktreeRemovalFailed { source, .. }
+            | GitError::RebaseConflict { source, .. }
+            | GitError::CherryPickConflict { source, .. }
+            | GitError::PushFailed { source, .. } => source
+                .as_ref()
+                .map(|e| e as &(dyn std::error::Error + 'static)),
+            _ => None,
+        }
+    }
+}
+
+impl GitError {
+    /// Exit code for this error's class, or `None` for a general failure.
+    pub fn exit_code(&self) -> Option<i32> {
+        match self {
+            GitError::DetachedHead { .. }
+            | GitError::UncommittedChanges { .. }
+            | GitError::BranchAlreadyExists { .. }
+            | GitError::BranchNotFound { .. }
+            | GitError::ReferenceNotFound { .. }
+            | GitError::NotInWorktree { .. }
+            | GitError::WorktreeMissing { .. }
+            | GitError::RemoteOnlyBranch { .. }
+            | GitError::WorktreePathOccupied { .. }
+            | GitError::WorktreePathExists { .. }
+            | GitError::CannotRemoveMainWorktree
+            | GitError::WorktreeLocked { .. }
+            | GitError::ProtectedBranch { .. }
+            | GitError::NotRebased { .. }
+            | GitError::OperationInProgress { .. }
+            | GitError::WorktreeNotFound { .. }
+            | GitError::BranchTracksDifferentRef { .. } => Some(exit_codes::GIT_STATE),
+            GitError::ConflictingChanges { .. }
+            | GitError::NotFastForward { .. }
+            | GitError::RebaseConflict { .. }
+            | GitError::CherryPickConflict { .. } => Some(exit_codes::CONFLICT),
+            GitError::PushFailed { .. } | GitError::CliApiError { .. } => Some(exit_codes::NETWORK),
+            GitError::NotInteractive | GitError::InputRequired { .. } => {
+                Some(exit_codes::NOT_APPROVED)
+            }
+            GitError::HookCommandNotFound { .. }
+            | GitError::RefCreateConflict { .. }
+            | GitError::RefBaseConflict { .. } => Some(exit_codes::USAGE),
+            GitError::WorktreeCreationFailed { .. }
+            | GitError::WorktreeRemovalFailed { .. }
+            | GitError::ParseError { .. }
+            | GitError::WorktreeIncludeParseError { .. }
+            | GitError::LlmCommandFailed { .. }
+            | GitError::SigningFailed { .. }
+            | GitError::SecretsDetected { .. }
+            | GitError::ProjectConfigNotFound { .. }
+            | GitError::NoRemoteForRepo { .. }
+            | GitError::Other { .. } => None,
+        }
+    }
+}
 
 impl std::fmt::Display for GitError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -329,7 +537,11 @@ impl std::fmt::Display for GitError {
                 write!(
                     f,
                     "{}\n{}",
-                    error_message(cformat!("Branch <bold>{branch}</> already exists")),
+                    error_message(crate::tr!(
+                        "error.branch_exists",
+                        "Branch {branch} already exists",
+                        branch = cformat!("<bold>{branch}</>")
+                    )),
                     hint_message(cformat!(
                         "To switch to the existing branch, run without <bright-black>--create</>: <bright-black>{switch_cmd}</>"
                     ))
@@ -352,7 +564,11 @@ impl std::fmt::Display for GitError {
                 write!(
                     f,
                     "{}\n{}",
-                    error_message(cformat!("No branch named <bold>{branch}</>")),
+                    error_message(crate::tr!(
+                        "error.branch_not_found",
+                        "No branch named {branch}",
+                        branch = cformat!("<bold>{branch}</>")
+                    )),
                     hint_message(hint)
                 )
             }
@@ -361,8 +577,10 @@ impl std::fmt::Display for GitError {
                 write!(
                     f,
                     "{}",
-                    error_message(cformat!(
-                        "No branch, tag, or commit named <bold>{reference}</>"
+                    error_message(crate::tr!(
+                        "error.reference_not_found",
+                        "No branch, tag, or commit named {reference}",
+                        reference = cformat!("<bold>{reference}</>")
                     ))
                 )
             }
@@ -386,7 +604,11 @@ impl std::fmt::Display for GitError {
                 write!(
                     f,
                     "{}\n{}",
-                    error_message(cformat!("Worktree directory missing for <bold>{branch}</>")),
+                    error_message(crate::tr!(
+                        "error.worktree_missing",
+                        "Worktree directory missing for {branch}",
+                        branch = cformat!("<bold>{branch}</>")
+                    )),
                     hint_message(cformat!(
                         "To clean up, run <bright-black>git worktree prune</>"
                     ))
@@ -395,9 +617,15 @@ impl std::fmt::Display for GitError {
 
             GitError::RemoteOnlyBranch { branch, remote } => {
                 let cmd = suggest_command("switch", &[branch], &[]);
-                cwrite!(
+                write!(
                     f,
-                    "{ERROR_SYMBOL} <red>Branch <bold>{branch}</> exists only on remote ({remote}/{branch})</>\n{HINT_SYMBOL} <dim>To create a local worktree, run <bright-black>{cmd}</></>"
+                    "{}\n{}",
+                    error_message(cformat!(
+                        "Branch <bold>{branch}</> exists only on remote ({remote}/{branch})"
+                    )),
+                    hint_message(cformat!(
+                        "To create a local worktree, run <bright-black>{cmd}</>"
+                    ))
                 )
             }
 
@@ -483,6 +711,7 @@ impl std::fmt::Display for GitError {
                 branch,
                 path,
                 error,
+                ..
             } => {
                 let path_display = format_path_for_display(path);
                 let header = error_message(cformat!(
@@ -521,6 +750,30 @@ impl std::fmt::Display for GitError {
                 )
             }
 
+            GitError::ProtectedBranch {
+                branch,
+                action,
+                force_hint,
+            } => {
+                let hint = if *force_hint {
+                    cformat!(
+                        "Protected by <bright-black>[branches] protected</> in .config/wt.toml; to {action} it anyway, add <bright-black>--force</>"
+                    )
+                } else {
+                    cformat!(
+                        "Protected by <bright-black>[branches] protected</> in .config/wt.toml"
+                    )
+                };
+                write!(
+                    f,
+                    "{}\n{}",
+                    error_message(cformat!(
+                        "Cannot {action} protected branch <bold>{branch}</>"
+                    )),
+                    hint_message(hint)
+                )
+            }
+
             GitError::ConflictingChanges {
                 target_branch,
                 files,
@@ -587,6 +840,7 @@ impl std::fmt::Display for GitError {
             GitError::RebaseConflict {
                 target_branch,
                 git_output,
+                ..
             } => {
                 write!(
                     f,
@@ -594,11 +848,21 @@ impl std::fmt::Display for GitError {
                     error_message(cformat!("Rebase onto <bold>{target_branch}</> incomplete"))
                 )?;
                 if !git_output.is_empty() {
-                    write!(f, "\n{}", format_with_gutter(git_output, None))
-                } else {
                     write!(
                         f,
                         "\n{}\n{}",
+                        format_with_gutter(git_output, None),
+                        hint_message(cformat!(
+                            "To resolve conflicts file by file, run <bright-black>wt resolve</>"
+                        ))
+                    )
+                } else {
+                    write!(
+                        f,
+                        "\n{}\n{}\n{}",
+                        hint_message(cformat!(
+                            "To resolve conflicts file by file, run <bright-black>wt resolve</>"
+                        )),
                         hint_message(cformat!(
                             "To continue after resolving conflicts, run <bright-black>git rebase --continue</>"
                         )),
@@ -609,6 +873,35 @@ impl std::fmt::Display for GitError {
                 }
             }
 
+            GitError::CherryPickConflict {
+                target_branch,
+                commit,
+                git_output,
+                ..
+            } => {
+                let short = &commit[..commit.len().min(7)];
+                write!(
+                    f,
+                    "{}",
+                    error_message(cformat!(
+                        "Cherry-pick onto <bold>{target_branch}</> stopped at <dim>{short}</>"
+                    ))
+                )?;
+                if !git_output.is_empty() {
+                    write!(f, "\n{}", format_with_gutter(git_output, None))?;
+                }
+                write!(
+                    f,
+                    "\n{}\n{}",
+                    hint_message(cformat!(
+                        "To continue after resolving conflicts, run <bright-black>git cherry-pick --continue</>"
+                    )),
+                    hint_message(cformat!(
+                        "To abort, run <bright-black>git cherry-pick --abort</>"
+                    ))
+                )
+            }
+
             GitError::NotRebased { target_branch } => {
                 let rebase_cmd = suggest_command("step", &["rebase", target_branch], &[]);
                 write!(
@@ -624,6 +917,7 @@ impl std::fmt::Display for GitError {
             GitError::PushFailed {
                 target_branch,
                 error,
+                ..
             } => {
                 let header = error_message(cformat!(
                     "Can't push to local <bold>{target_branch}</> branch"
@@ -631,6 +925,44 @@ impl std::fmt::Display for GitError {
                 write!(f, "{}", format_error_block(header, error))
             }
 
+            GitError::SecretsDetected {
+                target_branch,
+                count,
+                report,
+                allowlist,
+            } => {
+                let noun = if *count == 1 { "secret" } else { "secrets" };
+                write!(
+                    f,
+                    "{}\n{}\n{}",
+                    error_message(cformat!(
+                        "Not pushing to <bold>{target_branch}</>: found {count} possible {noun}"
+                    )),
+                    format_with_gutter(report, None),
+                    hint_message(cformat!(
+                        "Add false positives to <bright-black>{allowlist}</>, or skip the scan with <bright-black>--no-verify</>"
+                    ))
+                )
+            }
+
+            GitError::SigningFailed {
+                operation,
+                key,
+                git_output,
+            } => {
+                let header = error_message(cformat!(
+                    "Failed to sign {operation} commit with <bold>{key}</>"
+                ));
+                write!(
+                    f,
+                    "{}\n{}",
+                    format_error_block(header, git_output),
+                    hint_message(cformat!(
+                        "The branch is unchanged; run <bright-black>wt doctor</> to check the signing setup, or unlock the key and retry"
+                    ))
+                )
+            }
+
             GitError::NotInteractive => {
                 let approvals_cmd = suggest_command("hook", &["approvals", "add"], &[]);
                 write!(
@@ -643,6 +975,32 @@ impl std::fmt::Display for GitError {
                 )
             }
 
+            GitError::InputRequired { prompt, hint } => {
+                write!(
+                    f,
+                    "{}\n{}",
+                    error_message(cformat!(
+                        "Cannot prompt in non-interactive mode: <bold>{prompt}</>"
+                    )),
+                    hint_message(hint)
+                )
+            }
+
+            GitError::OperationInProgress { holder } => {
+                let holder_text = match holder {
+                    Some(holder) => cformat!(": <bold>{holder}</>"),
+                    None => String::new(),
+                };
+                write!(
+                    f,
+                    "{}\n{}",
+                    error_message(cformat!(
+                        "Another worktrunk operation is in progress{holder_text}"
+                    )),
+                    hint_message("Wait for it to finish, then retry")
+                )
+            }
+
             GitError::HookCommandNotFound { name, available } => {
                 if available.is_empty() {
                     write!(
@@ -809,16 +1167,36 @@ impl std::fmt::Display for GitError {
 ///
 /// Most errors use anyhow::bail! with formatted messages. This enum is only
 /// for cases that need exit code extraction or special handling.
-#[derive(Debug)]
+///
+/// Serializes like [`GitError`], with timeouts in seconds.
+#[derive(Debug, serde::Serialize)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
 pub enum WorktrunkError {
     /// Child process exited with non-zero code (preserves exit code for signals)
     ChildProcessExited { code: i32, message: String },
+    /// Child process was killed after exceeding its timeout
+    ChildProcessTimedOut {
+        #[serde(serialize_with = "serialize_secs")]
+        timeout: std::time::Duration,
+    },
+    /// Hook command was killed after exceeding its timeout
+    HookTimedOut {
+        hook_type: HookType,
+        command_name: Option<String>,
+        #[serde(serialize_with = "serialize_secs")]
+        timeout: std::time::Duration,
+        /// Tail of the command's output before it was killed
+        output: Option<String>,
+        log: Option<PathBuf>,
+    },
     /// Hook command failed
     HookCommandFailed {
         hook_type: HookType,
         command_name: Option<String>,
         error: String,
         exit_code: Option<i32>,
+        /// Log file holding the command's output
+        log: Option<PathBuf>,
     },
     /// Command was not approved by user (silent error)
     CommandNotApproved,
@@ -832,10 +1210,55 @@ impl std::fmt::Display for WorktrunkError {
             WorktrunkError::ChildProcessExited { message, .. } => {
                 write!(f, "{}", error_message(message))
             }
+            WorktrunkError::ChildProcessTimedOut { timeout } => {
+                write!(
+                    f,
+                    "{}\n{}",
+                    error_message(format!("Command timed out after {}s", timeout.as_secs())),
+                    hint_message(cformat!(
+                        "To allow longer, raise <bright-black>[timeouts] execute</> in user config"
+                    ))
+                )
+            }
+            WorktrunkError::HookTimedOut {
+                hook_type,
+                command_name,
+                timeout,
+                output,
+                log,
+            } => {
+                let secs = timeout.as_secs();
+                let message = match command_name {
+                    Some(name) => {
+                        cformat!("{hook_type} command timed out after {secs}s: <bold>{name}</>")
+                    }
+                    None => format!("{hook_type} command timed out after {secs}s"),
+                };
+                write!(f, "{}", error_message(message))?;
+                if let Some(output) = output.as_deref().filter(|o| !o.trim().is_empty()) {
+                    write!(f, "\n{}", format_with_gutter(output.trim_end(), None))?;
+                }
+                if let Some(log) = log {
+                    let log = format_path_for_display(log);
+                    write!(
+                        f,
+                        "\n{}",
+                        hint_message(cformat!("Output saved to <bright-black>{log}</>"))
+                    )?;
+                }
+                write!(
+                    f,
+                    "\n{}",
+                    hint_message(cformat!(
+                        "To allow longer, set <bright-black>timeout</> on the command or <bright-black>[timeouts] hook</> in user config"
+                    ))
+                )
+            }
             WorktrunkError::HookCommandFailed {
                 hook_type,
                 command_name,
                 error,
+                log,
                 ..
             } => {
                 // Note: Callers that support --no-verify should add the hint themselves
@@ -846,14 +1269,23 @@ impl std::fmt::Display for WorktrunkError {
                         error_message(cformat!(
                             "{hook_type} command failed: <bold>{name}</>: {error}"
                         ))
-                    )
+                    )?;
                 } else {
                     write!(
                         f,
                         "{}",
                         error_message(format!("{hook_type} command failed: {error}"))
-                    )
+                    )?;
                 }
+                if let Some(log) = log {
+                    let log = format_path_for_display(log);
+                    write!(
+                        f,
+                        "\n{}",
+                        hint_message(cformat!("Output saved to <bright-black>{log}</>"))
+                    )?;
+                }
+                Ok(())
             }
             WorktrunkError::CommandNotApproved => {
                 Ok(()) // on_skip callback handles the printing
@@ -867,20 +1299,90 @@ impl std::fmt::Display for WorktrunkError {
 
 impl std::error::Error for WorktrunkError {}
 
-/// Extract exit code from WorktrunkError, if applicable
+fn serialize_secs<S: serde::Serializer>(
+    duration: &std::time::Duration,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64(duration.as_secs_f64())
+}
+
+/// An error as JSON, for `--format=json` output and tests.
+///
+/// Typed errors ([`GitError`], [`WorktrunkError`]) serialize with their `kind`
+/// and fields; anything else is `{"kind": "other"}`. Every error also has the
+/// unstyled `message` and the process `exit_code`, plus the failed git command
+/// as `source` when there was one.
+pub fn error_json(err: &anyhow::Error) -> serde_json::Value {
+    if let Some(wrapper) = err.downcast_ref::<HookErrorWithHint>() {
+        return error_json(&wrapper.inner);
+    }
+    // Typed errors already say what their source said
+    let (typed, message) = if let Some(e) = err.downcast_ref::<GitError>() {
+        (serde_json::to_value(e), e.to_string())
+    } else if let Some(e) = err.downcast_ref::<WorktrunkError>() {
+        (serde_json::to_value(e), e.to_string())
+    } else {
+        (
+            Ok(serde_json::json!({ "kind": "other" })),
+            format!("{err:#}"),
+        )
+    };
+    let mut value = typed.unwrap_or_else(|_| serde_json::json!({ "kind": "other" }));
+    if let Some(object) = value.as_object_mut() {
+        if !object.contains_key("source")
+            && let Some(source) = GitCommandError::find(err)
+            && let Ok(source) = serde_json::to_value(source)
+        {
+            object.insert("source".into(), source);
+        }
+        object.insert(
+            "message".into(),
+            serde_json::Value::String(ansi_str::AnsiStr::ansi_strip(&message).into_owned()),
+        );
+        object.insert(
+            "exit_code".into(),
+            exit_code(err).unwrap_or(exit_codes::FAILURE).into(),
+        );
+    }
+    value
+}
+
+/// Exit code for an error, if it belongs to a class in [`exit_codes`] or
+/// carries a child's code. `None` means [`exit_codes::FAILURE`].
 pub fn exit_code(err: &anyhow::Error) -> Option<i32> {
     // Check for wrapped HookErrorWithHint first
     if let Some(wrapper) = err.downcast_ref::<HookErrorWithHint>() {
         return exit_code(&wrapper.inner);
     }
-    err.downcast_ref::<WorktrunkError>().and_then(|e| match e {
-        WorktrunkError::ChildProcessExited { code, .. } => Some(*code),
-        WorktrunkError::HookCommandFailed { exit_code, .. } => *exit_code,
-        WorktrunkError::CommandNotApproved => None,
-        WorktrunkError::AlreadyDisplayed { exit_code } => Some(*exit_code),
+    if let Some(e) = err.downcast_ref::<GitError>() {
+        return e.exit_code();
+    }
+    err.downcast_ref::<WorktrunkError>().map(|e| match e {
+        WorktrunkError::ChildProcessExited { code, .. } => *code,
+        WorktrunkError::ChildProcessTimedOut { .. } | WorktrunkError::HookTimedOut { .. } => {
+            exit_codes::TIMED_OUT
+        }
+        // An interrupted hook keeps its `128 + signal` code
+        WorktrunkError::HookCommandFailed {
+            exit_code: Some(code),
+            ..
+        } if *code > 128 => *code,
+        WorktrunkError::HookCommandFailed { .. } => exit_codes::HOOK_FAILED,
+        WorktrunkError::CommandNotApproved => exit_codes::NOT_APPROVED,
+        WorktrunkError::AlreadyDisplayed { exit_code } => *exit_code,
     })
 }
 
+/// Whether the error came from a child killed by an interrupt (Ctrl-C, a
+/// closed terminal, `kill`), judged by its `128 + signal` exit code.
+///
+/// Interrupted operations should stop and undo partial work rather than
+/// carry on to the next step.
+pub fn is_interrupted(err: &anyhow::Error) -> bool {
+    // SIGHUP, SIGINT, SIGQUIT, SIGTERM
+    matches!(exit_code(err), Some(129 | 130 | 131 | 143))
+}
+
 /// If the error is a HookCommandFailed, wrap it to add a hint about using --no-verify.
 ///
 /// ## When to use
@@ -899,7 +1401,8 @@ pub fn add_hook_skip_hint(err: anyhow::Error) -> anyhow::Error {
     let hook_type = err
         .downcast_ref::<WorktrunkError>()
         .and_then(|wt_err| match wt_err {
-            WorktrunkError::HookCommandFailed { hook_type, .. } => Some(*hook_type),
+            WorktrunkError::HookCommandFailed { hook_type, .. }
+            | WorktrunkError::HookTimedOut { hook_type, .. } => Some(*hook_type),
             _ => None,
         });
 
@@ -923,7 +1426,7 @@ pub struct HookErrorWithHint {
 
 impl std::fmt::Display for HookErrorWithHint {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        // Display the original error (always HookCommandFailed - validated by add_hook_skip_hint)
+        // Display the original error (a hook failure or timeout - validated by add_hook_skip_hint)
         write!(f, "{}", self.inner)?;
         // Can't derive command from hook type (e.g., PreRemove is used by both `wt remove` and `wt merge`)
         write!(
@@ -1001,6 +1504,69 @@ mod tests {
         ");
     }
 
+    #[test]
+    fn test_error_json() {
+        let err: anyhow::Error = GitError::RefCreateConflict {
+            ref_type: RefType::Pr,
+            number: 42,
+            branch: "fix".into(),
+        }
+        .into();
+        let json = error_json(&err);
+        assert_eq!(json["kind"], "ref-create-conflict");
+        assert_eq!(json["ref_type"], "pr");
+        assert_eq!(json["number"], 42);
+        assert_eq!(json["exit_code"], exit_codes::USAGE);
+        assert!(json["message"].as_str().unwrap().contains("fix"));
+
+        let err: anyhow::Error = WorktrunkError::HookTimedOut {
+            hook_type: HookType::PreMerge,
+            command_name: None,
+            timeout: std::time::Duration::from_millis(1500),
+            output: None,
+            log: None,
+        }
+        .into();
+        let json = error_json(&err);
+        assert_eq!(json["kind"], "hook-timed-out");
+        assert_eq!(json["hook_type"], "pre-merge");
+        assert_eq!(json["timeout"], 1.5);
+
+        let json = error_json(&anyhow::anyhow!("plain").context("while testing"));
+        assert_eq!(json["kind"], "other");
+        assert_eq!(json["message"], "while testing: plain");
+        assert_eq!(json["exit_code"], exit_codes::FAILURE);
+    }
+
+    #[test]
+    fn test_git_command_error_source() {
+        let source = GitCommandError {
+            args: vec!["push".into(), "origin".into(), "HEAD:my branch".into()],
+            dir: PathBuf::from("/repo"),
+            exit_code: Some(1),
+            output: "! [rejected]".into(),
+        };
+        assert_eq!(source.command(), "git push origin 'HEAD:my branch'");
+
+        let err: anyhow::Error = GitError::PushFailed {
+            target_branch: "main".into(),
+            error: source.to_string(),
+            source: Some(source),
+        }
+        .into();
+        // The friendly message stands alone; the command is kept for -v and JSON
+        assert!(!err.to_string().contains("git push"));
+        assert_eq!(GitCommandError::find(&err).unwrap().exit_code, Some(1));
+        let json = error_json(&err);
+        assert_eq!(json["source"]["args"][0], "push");
+        assert_eq!(json["source"]["exit_code"], 1);
+
+        // Untyped errors carry it too
+        let err = anyhow::Error::from(GitCommandError::find(&err).unwrap().clone())
+            .context("Failed to sync");
+        assert_eq!(error_json(&err)["source"]["output"], "! [rejected]");
+    }
+
     #[test]
     fn test_exit_code() {
         // ChildProcessExited
@@ -1011,15 +1577,25 @@ mod tests {
         .into();
         assert_eq!(exit_code(&err), Some(42));
 
-        // HookCommandFailed with code
+        // HookCommandFailed with code: the hook class, unless interrupted
         let err: anyhow::Error = WorktrunkError::HookCommandFailed {
             hook_type: HookType::PreMerge,
             command_name: Some("test".into()),
             error: "failed".into(),
             exit_code: Some(1),
+            log: None,
+        }
+        .into();
+        assert_eq!(exit_code(&err), Some(exit_codes::HOOK_FAILED));
+        let err: anyhow::Error = WorktrunkError::HookCommandFailed {
+            hook_type: HookType::PreMerge,
+            command_name: Some("test".into()),
+            error: "terminated by signal 2".into(),
+            exit_code: Some(130),
+            log: None,
         }
         .into();
-        assert_eq!(exit_code(&err), Some(1));
+        assert_eq!(exit_code(&err), Some(130));
 
         // HookCommandFailed without code
         let err: anyhow::Error = WorktrunkError::HookCommandFailed {
@@ -1027,20 +1603,75 @@ mod tests {
             command_name: None,
             error: "failed".into(),
             exit_code: None,
+            log: None,
         }
         .into();
-        assert_eq!(exit_code(&err), None);
+        assert_eq!(exit_code(&err), Some(exit_codes::HOOK_FAILED));
 
         // CommandNotApproved, AlreadyDisplayed, GitError
-        assert_eq!(exit_code(&WorktrunkError::CommandNotApproved.into()), None);
+        assert_eq!(
+            exit_code(&WorktrunkError::CommandNotApproved.into()),
+            Some(exit_codes::NOT_APPROVED)
+        );
         assert_eq!(
             exit_code(&WorktrunkError::AlreadyDisplayed { exit_code: 5 }.into()),
             Some(5)
         );
         assert_eq!(
             exit_code(&GitError::DetachedHead { action: None }.into()),
+            Some(exit_codes::GIT_STATE)
+        );
+        assert_eq!(
+            exit_code(
+                &GitError::RebaseConflict {
+                    target_branch: "main".into(),
+                    git_output: String::new(),
+                    source: None,
+                }
+                .into()
+            ),
+            Some(exit_codes::CONFLICT)
+        );
+        assert_eq!(
+            exit_code(
+                &GitError::CherryPickConflict {
+                    target_branch: "release".into(),
+                    commit: "abc1234def".into(),
+                    git_output: String::new(),
+                    source: None,
+                }
+                .into()
+            ),
+            Some(exit_codes::CONFLICT)
+        );
+        assert_eq!(
+            exit_code(&GitError::NotInteractive.into()),
+            Some(exit_codes::NOT_APPROVED)
+        );
+        assert_eq!(
+            exit_code(
+                &GitError::PushFailed {
+                    target_branch: "main".into(),
+                    error: String::new(),
+                    source: None,
+                }
+                .into()
+            ),
+            Some(exit_codes::NETWORK)
+        );
+        assert_eq!(
+            exit_code(
+                &GitError::Other {
+                    message: "x".into()
+                }
+                .into()
+            ),
             None
         );
+        // Context doesn't hide the class
+        let wrapped = anyhow::Error::from(GitError::CannotRemoveMainWorktree)
+            .context("Failed to remove worktree");
+        assert_eq!(exit_code(&wrapped), Some(exit_codes::GIT_STATE));
 
         // Wrapped hook error
         let inner: anyhow::Error = WorktrunkError::HookCommandFailed {
@@ -1048,9 +1679,45 @@ mod tests {
             command_name: Some("lint".into()),
             error: "failed".into(),
             exit_code: Some(7),
+            log: None,
         }
         .into();
-        assert_eq!(exit_code(&add_hook_skip_hint(inner)), Some(7));
+        assert_eq!(
+            exit_code(&add_hook_skip_hint(inner)),
+            Some(exit_codes::HOOK_FAILED)
+        );
+    }
+
+    #[test]
+    fn test_is_interrupted() {
+        let exited = |code| -> anyhow::Error {
+            WorktrunkError::ChildProcessExited {
+                code,
+                message: "test".into(),
+            }
+            .into()
+        };
+        assert!(is_interrupted(&exited(130)));
+        assert!(is_interrupted(&exited(143)));
+        assert!(is_interrupted(&exited(129)));
+        assert!(!is_interrupted(&exited(1)));
+        assert!(!is_interrupted(&exited(137)));
+
+        let hook: anyhow::Error = WorktrunkError::HookCommandFailed {
+            hook_type: HookType::PostCreate,
+            command_name: None,
+            error: "terminated by signal 2".into(),
+            exit_code: Some(130),
+            log: None,
+        }
+        .into();
+        assert!(is_interrupted(&add_hook_skip_hint(hook)));
+        assert!(!is_interrupted(
+            &WorktrunkError::ChildProcessTimedOut {
+                timeout: std::time::Duration::from_secs(1)
+            }
+            .into()
+        ));
     }
 
     #[test]
@@ -1061,6 +1728,7 @@ mod tests {
             command_name: Some("test".into()),
             error: "failed".into(),
             exit_code: Some(1),
+            log: None,
         }
         .into();
         let display = format!("{}", add_hook_skip_hint(inner));
@@ -1104,6 +1772,7 @@ mod tests {
             command_name: Some("lint".into()),
             error: "lint failed".into(),
             exit_code: Some(1),
+            log: None,
         };
         let display = format!("{err}");
         assert!(display.contains("pre-merge") && display.contains("lint"));
@@ -1113,9 +1782,35 @@ mod tests {
             command_name: None,
             error: "setup failed".into(),
             exit_code: None,
+            log: None,
         };
         let display = format!("{err}");
         assert!(display.contains("post-create") && display.contains("setup failed"));
+        assert!(!display.contains("Output saved"));
+
+        let err = WorktrunkError::HookCommandFailed {
+            hook_type: HookType::PreCommit,
+            command_name: Some("test".into()),
+            error: "exit status: 1".into(),
+            exit_code: Some(1),
+            log: Some(PathBuf::from("/tmp/hook-logs/run.log")),
+        };
+        let display = format!("{err}");
+        assert!(display.contains("Output saved to") && display.contains("run.log"));
+
+        // Timeouts show the partial output and how to raise the limit
+        let err = WorktrunkError::HookTimedOut {
+            hook_type: HookType::PostCreate,
+            command_name: Some("install".into()),
+            timeout: std::time::Duration::from_secs(30),
+            output: Some("resolving packages...\n".into()),
+            log: None,
+        };
+        let display = format!("{err}");
+        assert!(display.contains("post-create command timed out after 30s"));
+        assert!(display.contains("resolving packages..."));
+        assert!(display.contains("[timeouts] hook"));
+        assert_eq!(exit_code(&err.into()), Some(124));
 
         // Silent errors
         assert_eq!(format!("{}", WorktrunkError::CommandNotApproved), "");
@@ -1211,6 +1906,52 @@ mod tests {
         assert!(display.contains("exit code 128"));
     }
 
+    #[test]
+    fn test_git_error_operation_in_progress() {
+        let err = GitError::OperationInProgress {
+            holder: Some("wt remove (pid 4242)".into()),
+        };
+        let display = err.to_string();
+        assert!(display.contains("Another worktrunk operation is in progress"));
+        assert!(display.contains("wt remove (pid 4242)"));
+
+        let err = GitError::OperationInProgress { holder: None };
+        assert!(!err.to_string().contains(':'));
+    }
+
+    #[test]
+    fn test_git_error_input_required() {
+        let err = GitError::InputRequired {
+            prompt: "Install shell integration?".into(),
+            hint: "Add --yes to accept".into(),
+        };
+        let display = err.to_string();
+        assert!(display.contains("Cannot prompt in non-interactive mode"));
+        assert!(display.contains("Install shell integration?"));
+        assert!(display.contains("Add --yes to accept"));
+    }
+
+    #[test]
+    fn test_git_error_protected_branch() {
+        let err = GitError::ProtectedBranch {
+            branch: "main".into(),
+            action: "push to".into(),
+            force_hint: true,
+        };
+        let display = err.to_string();
+        assert!(display.contains("Cannot push to protected branch"));
+        assert!(display.contains("main"));
+        assert!(display.contains("--force"));
+        assert_eq!(err.exit_code(), Some(exit_codes::GIT_STATE));
+
+        let err = GitError::ProtectedBranch {
+            branch: "release/1.0".into(),
+            action: "delete".into(),
+            force_hint: false,
+        };
+        assert!(!err.to_string().contains("--force"));
+    }
+
     #[test]
     fn test_git_error_worktree_locked_with_reason() {
         let err = GitError::WorktreeLocked {
@@ -1275,6 +2016,35 @@ mod tests {
         assert!(display.contains("no named commands"));
     }
 
+    #[test]
+    fn test_git_error_secrets_detected() {
+        let err = GitError::SecretsDetected {
+            target_branch: "main".into(),
+            count: 1,
+            report: "src/config.rs:11  aws-access-key  AKIA…MPLE".into(),
+            allowlist: ".config/wt-secrets-allow".into(),
+        };
+        let display = err.to_string();
+        assert!(display.contains("1 possible secret"));
+        assert!(display.contains("aws-access-key"));
+        assert!(display.contains("wt-secrets-allow"));
+        assert!(display.contains("--no-verify"));
+    }
+
+    #[test]
+    fn test_git_error_signing_failed() {
+        let err = GitError::SigningFailed {
+            operation: "squash".into(),
+            key: "ssh key ~/.ssh/id_ed25519.pub".into(),
+            git_output: "error: Couldn't load public key".into(),
+        };
+        let display = err.to_string();
+        assert!(display.contains("squash"));
+        assert!(display.contains("id_ed25519.pub"));
+        assert!(display.contains("wt doctor"));
+        assert_eq!(err.exit_code(), None);
+    }
+
     #[test]
     fn test_git_error_llm_command_failed() {
         // With reproduction command
@@ -1425,6 +2195,7 @@ mod tests {
             command_name: Some("test".into()),
             error: "Test failed".into(),
             exit_code: Some(1),
+            log: None,
         }
         .into();
 
@@ -1446,6 +2217,7 @@ mod tests {
             command_name: Some("build".into()),
             error: "Build failed".into(),
             exit_code: Some(1),
+            log: None,
         }
         .into();
 
@@ -1481,6 +2253,7 @@ mod tests {
         let err = GitError::RebaseConflict {
             target_branch: "main".into(),
             git_output: "".into(),
+            source: None,
         };
         let display = err.to_string();
         assert!(display.contains("incomplete"));
@@ -8,6 +8,7 @@ mod error;
 mod parse;
 pub mod remote_ref;
 mod repository;
+mod retry;
 mod url;
 
 #[cfg(test)]
@@ -38,6 +39,7 @@ pub use diff::{LineDiff, parse_numstat_line};
 pub use error::{
     // Structured command failure info
     FailedCommand,
+    GitCommandError,
     // Typed error enum (Display produces styled output)
     GitError,
     // Special-handling error enum (Display produces styled output)
@@ -48,10 +50,17 @@ pub use error::{
     WorktrunkError,
     // Error inspection functions
     add_hook_skip_hint,
+    error_json,
     exit_code,
+    exit_codes,
+    is_interrupted,
 };
 pub use parse::{parse_porcelain_z, parse_untracked_files};
-pub use repository::{Branch, Repository, ResolvedWorktree, WorkingTree, set_base_path};
+pub use repository::{
+    Branch, OperationLock, Repository, ResolvedWorktree, SigningConfig, SigningFormat, WorkingTree,
+    base_path, set_base_path,
+};
+pub use retry::{DEFAULT_ATTEMPTS, is_transient, set_remote_attempts};
 pub use url::GitRemoteUrl;
 pub use url::{parse_owner_repo, parse_remote_owner};
 /// Why branch content is considered integrated into the target branch.
@@ -318,10 +327,12 @@ pub fn branch_tracks_ref(
     PartialEq,
     Eq,
     clap::ValueEnum,
+    serde::Serialize,
     strum::Display,
     strum::EnumString,
     strum::EnumIter,
 )]
+#[serde(rename_all = "kebab-case")]
 #[strum(serialize_all = "kebab-case")]
 pub enum HookType {
     PostCreate,
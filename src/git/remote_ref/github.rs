@@ -9,9 +9,9 @@ use anyhow::{Context, bail};
 use serde::Deserialize;
 
 use super::{PlatformData, RemoteRefInfo, RemoteRefProvider};
+use crate::forge::forge_cli;
 use crate::git::RefType;
 use crate::git::error::GitError;
-use crate::shell_exec::Cmd;
 
 /// GitHub Pull Request provider.
 #[derive(Debug, Clone, Copy)]
@@ -35,6 +35,8 @@ impl RemoteRefProvider for GitHubProvider {
 #[derive(Debug, Deserialize)]
 struct GhApiPrResponse {
     title: String,
+    #[serde(default)]
+    body: Option<String>,
     user: GhUser,
     state: String,
     #[serde(default)]
@@ -80,7 +82,7 @@ struct GhOwner {
 fn fetch_pr_info(pr_number: u32, repo_root: &Path) -> anyhow::Result<RemoteRefInfo> {
     let api_path = format!("repos/{{owner}}/{{repo}}/pulls/{}", pr_number);
 
-    let output = match Cmd::new("gh")
+    let output = match forge_cli("gh")
         .args(["api", &api_path])
         .current_dir(repo_root)
         .env("GH_PROMPT_DISABLED", "1")
@@ -183,6 +185,7 @@ fn fetch_pr_info(pr_number: u32, repo_root: &Path) -> anyhow::Result<RemoteRefIn
         ref_type: RefType::Pr,
         number: pr_number,
         title: response.title,
+        body: response.body.unwrap_or_default(),
         author: response.user.login,
         state: response.state,
         draft: response.draft,
@@ -202,7 +205,7 @@ fn fetch_pr_info(pr_number: u32, repo_root: &Path) -> anyhow::Result<RemoteRefIn
 
 /// Get the git protocol preference from `gh` (GitHub CLI).
 fn use_ssh_protocol() -> bool {
-    Cmd::new("gh")
+    forge_cli("gh")
         .args(["config", "get", "git_protocol"])
         .run()
         .ok()
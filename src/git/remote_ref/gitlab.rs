@@ -28,9 +28,9 @@ use anyhow::{Context, bail};
 use serde::Deserialize;
 
 use super::{PlatformData, RemoteRefInfo, RemoteRefProvider};
+use crate::forge::forge_cli;
 use crate::git::RefType;
 use crate::git::error::GitError;
-use crate::shell_exec::Cmd;
 
 /// GitLab Merge Request provider.
 #[derive(Debug, Clone, Copy)]
@@ -54,6 +54,8 @@ impl RemoteRefProvider for GitLabProvider {
 #[derive(Debug, Deserialize)]
 struct GlabMrResponse {
     title: String,
+    #[serde(default)]
+    description: Option<String>,
     author: GlabAuthor,
     state: String,
     #[serde(default)]
@@ -88,7 +90,7 @@ struct GlabApiErrorResponse {
 fn fetch_mr_info(mr_number: u32, repo_root: &Path) -> anyhow::Result<RemoteRefInfo> {
     let api_path = format!("projects/:id/merge_requests/{}", mr_number);
 
-    let output = match Cmd::new("glab")
+    let output = match forge_cli("glab")
         .args(["api", &api_path])
         .current_dir(repo_root)
         .env("GLAB_NO_PROMPT", "1")
@@ -173,6 +175,7 @@ fn fetch_mr_info(mr_number: u32, repo_root: &Path) -> anyhow::Result<RemoteRefIn
         ref_type: RefType::Mr,
         number: mr_number,
         title: response.title,
+        body: response.description.unwrap_or_default(),
         author: response.author.username,
         state: response.state,
         draft: response.draft,
@@ -263,7 +266,7 @@ fn fetch_project_urls(
 ) -> anyhow::Result<(Option<String>, Option<String>)> {
     let api_path = format!("projects/{}", project_id);
 
-    let output = Cmd::new("glab")
+    let output = forge_cli("glab")
         .args(["api", &api_path])
         .current_dir(repo_root)
         .env("GLAB_NO_PROMPT", "1")
@@ -279,7 +282,7 @@ fn fetch_project_urls(
 
 /// Get the git protocol configured in `glab` (GitLab CLI).
 pub fn get_git_protocol() -> String {
-    Cmd::new("glab")
+    forge_cli("glab")
         .args(["config", "get", "git_protocol"])
         .run()
         .ok()
@@ -313,6 +316,7 @@ mod tests {
             ref_type: RefType::Pr,
             number: 123,
             title: "Test PR".to_string(),
+            body: String::new(),
             author: "user".to_string(),
             state: "open".to_string(),
             draft: false,
@@ -49,6 +49,8 @@ pub struct RemoteRefInfo {
     pub number: u32,
     /// The PR/MR title.
     pub title: String,
+    /// The PR/MR description (empty when none was written).
+    pub body: String,
     /// The PR/MR author's username.
     pub author: String,
     /// The PR/MR state ("open", "closed", "merged", etc.).
@@ -179,6 +181,7 @@ mod tests {
             ref_type: RefType::Pr,
             number: 101,
             title: "Fix bug".to_string(),
+            body: String::new(),
             author: "alice".to_string(),
             state: "open".to_string(),
             draft: false,
@@ -203,6 +206,7 @@ mod tests {
             ref_type: RefType::Pr,
             number: 42,
             title: "Add feature".to_string(),
+            body: String::new(),
             author: "contributor".to_string(),
             state: "open".to_string(),
             draft: false,
@@ -227,6 +231,7 @@ mod tests {
             ref_type: RefType::Mr,
             number: 101,
             title: "Fix bug".to_string(),
+            body: String::new(),
             author: "contributor".to_string(),
             state: "opened".to_string(),
             draft: false,
@@ -251,6 +256,7 @@ mod tests {
             ref_type: RefType::Pr,
             number: 101,
             title: "Test".to_string(),
+            body: String::new(),
             author: "contributor".to_string(),
             state: "open".to_string(),
             draft: false,
@@ -278,6 +284,7 @@ mod tests {
             ref_type: RefType::Mr,
             number: 101,
             title: "Test".to_string(),
+            body: String::new(),
             author: "contributor".to_string(),
             state: "opened".to_string(),
             draft: false,
@@ -109,6 +109,19 @@ impl<'a> Branch<'a> {
         }
     }
 
+    /// Whether this branch tracks a remote branch that no longer exists.
+    ///
+    /// Usually the branch was deleted on the remote after its PR merged, and
+    /// a fetch with `--prune` removed the tracking ref.
+    pub fn upstream_gone(&self) -> anyhow::Result<bool> {
+        let track = self.repo.run_command(&[
+            "for-each-ref",
+            "--format=%(upstream:track)",
+            &format!("refs/heads/{}", self.name),
+        ])?;
+        Ok(track.trim() == "[gone]")
+    }
+
     /// Unset the upstream tracking branch for this branch.
     ///
     /// This removes the tracking relationship, preventing accidental pushes
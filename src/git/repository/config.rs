@@ -130,10 +130,11 @@ impl Repository {
     /// - For optional operations, provide a fallback (e.g., `.unwrap_or("main")`)
     ///
     /// Detection strategy:
-    /// 1. Check worktrunk cache (`git config worktrunk.default-branch`)
-    /// 2. Try primary remote's local cache (e.g., `origin/HEAD`)
-    /// 3. Query remote (`git ls-remote`) — may take 100ms-2s
-    /// 4. Infer from local branches if no remote
+    /// 1. Check the project config (`default-branch` in `.config/wt.toml`)
+    /// 2. Check worktrunk cache (`git config worktrunk.default-branch`)
+    /// 3. Try primary remote's local cache (e.g., `origin/HEAD`)
+    /// 4. Query remote (`git ls-remote`) — may take 100ms-2s
+    /// 5. Infer from local branches if no remote
     ///
     /// Detection results are cached to `worktrunk.default-branch` for future calls.
     /// Result is also cached in the shared repo cache (shared across all worktrees).
@@ -143,6 +144,23 @@ impl Repository {
         self.cache
             .default_branch
             .get_or_init(|| {
+                // Exists locally, or is the unborn branch HEAD points to (no commits yet)
+                let is_local = |branch: &str| {
+                    self.branch(branch).exists_locally().unwrap_or(false)
+                        || self.is_unborn_head_branch(branch)
+                };
+
+                // The project's choice wins over the cache and detection. A
+                // branch that isn't here yet (e.g. not fetched) falls through;
+                // `wt doctor` reports it.
+                if let Some(branch) = self.project_default_branch() {
+                    if is_local(&branch) {
+                        let _ = self.cache.invalid_default_branch.set(None);
+                        return Some(branch);
+                    }
+                    log::debug!("Project default branch '{branch}' doesn't exist locally");
+                }
+
                 // Fast path: check worktrunk's persistent cache (git config)
                 let configured = self
                     .run_command(&["config", "--get", "worktrunk.default-branch"])
@@ -152,12 +170,7 @@ impl Repository {
 
                 // If configured, validate it exists locally
                 if let Some(ref branch) = configured {
-                    if self.branch(branch).exists_locally().unwrap_or(false) {
-                        let _ = self.cache.invalid_default_branch.set(None);
-                        return Some(branch.clone());
-                    }
-                    // Check if this is the unborn default branch (HEAD points to it but no commits yet)
-                    if self.is_unborn_head_branch(branch) {
+                    if is_local(branch) {
                         let _ = self.cache.invalid_default_branch.set(None);
                         return Some(branch.clone());
                     }
@@ -180,8 +193,11 @@ impl Repository {
                         .ok()
                 });
 
-                // Cache detected result to git config for future runs
-                if let Some(ref branch) = detected {
+                // Cache detected result to git config for future runs; a
+                // dry run skips the cache quietly, like the state store
+                if let Some(ref branch) = detected
+                    && !crate::dry_run::is_dry_run()
+                {
                     let _ = self.run_command(&["config", "worktrunk.default-branch", branch]);
                 }
 
@@ -190,6 +206,15 @@ impl Repository {
             .clone()
     }
 
+    /// The `default-branch` set in the project config, if any.
+    pub fn project_default_branch(&self) -> Option<String> {
+        self.load_project_config()
+            .ok()
+            .flatten()
+            .and_then(|config| config.default_branch)
+            .filter(|branch| !branch.is_empty())
+    }
+
     /// Check if user configured an invalid default branch.
     ///
     /// Returns `Some(branch_name)` if user set `worktrunk.default-branch` to a branch
@@ -290,12 +315,19 @@ impl Repository {
         // 2. Check symbolic-ref HEAD - authoritative for bare repos and empty repos
         // - Bare repo directory: HEAD always points to the default branch
         // - Empty repos: No branches exist yet, but HEAD tells us the intended default
-        // - Linked worktrees: HEAD points to CURRENT branch, so skip this heuristic
-        // - Normal repos: HEAD points to CURRENT branch, so skip this heuristic
-        let is_bare = self.is_bare();
-        let in_linked_worktree = self.current_worktree().is_linked()?;
-        if ((is_bare && !in_linked_worktree) || branches.is_empty())
-            && let Ok(head_ref) = self.run_command(&["symbolic-ref", "HEAD"])
+        // - Normal repos and their worktrees: HEAD points to CURRENT branch, so skip this heuristic
+        //
+        // For bare repos, read HEAD from the bare repo itself so the answer is the
+        // same from any worktree.
+        let head_ref = if self.is_bare() {
+            self.run_command_in_common_dir(&["symbolic-ref", "HEAD"])
+                .ok()
+        } else if branches.is_empty() {
+            self.run_command(&["symbolic-ref", "HEAD"]).ok()
+        } else {
+            None
+        };
+        if let Some(head_ref) = head_ref
             && let Some(branch) = head_ref.trim().strip_prefix("refs/heads/")
         {
             return Ok(branch.to_string());
@@ -346,7 +378,7 @@ impl Repository {
     }
 
     pub(super) fn query_remote_default_branch(&self, remote: &str) -> anyhow::Result<String> {
-        let stdout = self.run_command(&["ls-remote", "--symref", remote, "HEAD"])?;
+        let stdout = self.run_remote_command(&["ls-remote", "--symref", remote, "HEAD"])?;
         DefaultBranchName::from_remote(&stdout).map(DefaultBranchName::into_string)
     }
 
@@ -160,7 +160,8 @@ impl Repository {
 
     /// Determine the effective target for integration checks.
     ///
-    /// If the upstream of the local target (e.g., `origin/main`) is strictly ahead of
+    /// If the upstream of the local target (e.g., `origin/main`, or `upstream/main` with an
+    /// [upstream remote](Self::upstream_remote)) is strictly ahead of
     /// the local target (i.e., local is an ancestor of upstream but not the same commit),
     /// uses the upstream. This handles the common case where a branch was merged remotely
     /// but the user hasn't pulled yet.
@@ -175,10 +176,14 @@ impl Repository {
     /// check integration against both and delete only if integrated into both.
     /// Current behavior: uses only local in diverged state, may miss remote-merged branches.
     pub fn effective_integration_target(&self, local_target: &str) -> String {
-        // Get the upstream ref for the local target (e.g., origin/main for main)
-        let upstream = match self.branch(local_target).upstream() {
-            Ok(Some(upstream)) => upstream,
-            _ => return local_target.to_string(),
+        // Get the upstream ref for the local target: in fork workflows the upstream
+        // remote's branch (upstream/main), otherwise the tracking branch (origin/main)
+        let upstream = match self.fork_upstream_ref(local_target) {
+            Some(fork_ref) => fork_ref,
+            None => match self.branch(local_target).upstream() {
+                Ok(Some(upstream)) => upstream,
+                _ => return local_target.to_string(),
+            },
         };
 
         // If local and upstream are the same commit, prefer local for clearer messaging
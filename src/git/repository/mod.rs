@@ -15,6 +15,7 @@
 //! - `diff.rs` - Diff, history, and commit operations
 //! - `config.rs` - Git config, hints, markers, and default branch detection
 //! - `integration.rs` - Integration detection (same commit, ancestor, trees match)
+//! - `operation_lock.rs` - Advisory lock serializing mutating operations across processes
 
 use std::io::{BufRead, BufReader, Write};
 use std::path::{Path, PathBuf};
@@ -24,7 +25,7 @@ use std::sync::{Arc, LazyLock, Mutex, OnceLock};
 use std::thread;
 use std::time::{Duration, Instant};
 
-use crate::shell_exec::Cmd;
+use crate::shell_exec::{Cmd, RetryPolicy};
 
 use dashmap::DashMap;
 use once_cell::sync::OnceCell;
@@ -36,7 +37,7 @@ use dunce::canonicalize;
 use crate::config::ProjectConfig;
 
 // Import types from parent module
-use super::{DefaultBranchName, GitError, LineDiff, WorktreeInfo};
+use super::{DefaultBranchName, GitCommandError, GitError, LineDiff, WorktreeInfo};
 
 // Re-export types needed by submodules
 pub(super) use super::{BranchCategory, CompletionBranch, DiffStats, GitRemoteUrl};
@@ -47,12 +48,16 @@ mod branches;
 mod config;
 mod diff;
 mod integration;
+mod operation_lock;
 mod remotes;
+mod signing;
 mod working_tree;
 mod worktrees;
 
 // Re-export WorkingTree and Branch
 pub use branch::Branch;
+pub use operation_lock::OperationLock;
+pub use signing::{SigningConfig, SigningFormat};
 pub use working_tree::WorkingTree;
 pub(super) use working_tree::path_to_logging_context;
 
@@ -108,6 +113,8 @@ pub(super) struct RepoCache {
     pub(super) integration_target: OnceCell<Option<String>>,
     /// Primary remote name (None if no remotes configured)
     pub(super) primary_remote: OnceCell<Option<String>>,
+    /// Upstream remote for fork workflows (None unless configured or named `upstream`)
+    pub(super) upstream_remote: OnceCell<Option<String>>,
     /// Primary remote URL (None if no remotes configured or no URL)
     pub(super) primary_remote_url: OnceCell<Option<String>>,
     /// Project identifier derived from remote URL
@@ -164,8 +171,8 @@ pub fn set_base_path(path: PathBuf) {
     BASE_PATH.set(path).ok();
 }
 
-/// Get the base path for repository operations.
-fn base_path() -> &'static PathBuf {
+/// Get the base path for repository operations (`-C`, or `.`).
+pub fn base_path() -> &'static PathBuf {
     BASE_PATH.get().unwrap_or(&DEFAULT_BASE_PATH)
 }
 
@@ -285,11 +292,12 @@ impl Repository {
         &self.discovery_path
     }
 
-    /// Get a worktree view at the current directory.
+    /// Get a worktree view at the path the repository was discovered from:
+    /// the current directory (or `-C`) for [`current()`](Self::current).
     ///
     /// This is the primary way to get a [`WorkingTree`] for worktree-specific operations.
     pub fn current_worktree(&self) -> WorkingTree<'_> {
-        self.worktree_at(base_path().clone())
+        self.worktree_at(self.discovery_path.clone())
     }
 
     /// Get a worktree view at a specific path.
@@ -559,26 +567,45 @@ impl Repository {
     /// # Ok::<(), anyhow::Error>(())
     /// ```
     pub fn run_command(&self, args: &[&str]) -> anyhow::Result<String> {
-        let output = Cmd::new("git")
+        self.run_command_in(&self.discovery_path, args, None)
+    }
+
+    /// Run a git command that talks to a remote (`fetch`, `push`, `ls-remote`).
+    ///
+    /// Like [`run_command`](Self::run_command), but network blips and server
+    /// errors are retried with backoff, up to `[network] attempts` tries.
+    pub fn run_remote_command(&self, args: &[&str]) -> anyhow::Result<String> {
+        self.run_command_in(&self.discovery_path, args, Some(super::retry::GIT_RETRY))
+    }
+
+    /// Run a git command against the repository itself rather than the current worktree.
+    ///
+    /// Runs from `git_common_dir`, so worktree-relative refs like `HEAD` resolve to the
+    /// main worktree's — for bare repos, the bare repo's own `HEAD`.
+    pub(super) fn run_command_in_common_dir(&self, args: &[&str]) -> anyhow::Result<String> {
+        self.run_command_in(&self.git_common_dir, args, None)
+    }
+
+    fn run_command_in(
+        &self,
+        dir: &Path,
+        args: &[&str],
+        retry: Option<RetryPolicy>,
+    ) -> anyhow::Result<String> {
+        let mut cmd = Cmd::new("git")
             .args(args.iter().copied())
-            .current_dir(&self.discovery_path)
-            .context(self.logging_context())
+            .current_dir(dir)
+            .context(path_to_logging_context(dir));
+        // Only remote commands carry a retry policy
+        if let Some(policy) = retry {
+            cmd = cmd.retry(policy).network();
+        }
+        let output = cmd
             .run()
             .with_context(|| format!("Failed to execute: git {}", args.join(" ")))?;
 
         if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            // Normalize carriage returns to newlines for consistent output
-            // Git uses \r for progress updates; in non-TTY contexts this causes snapshot instability
-            let stderr = stderr.replace('\r', "\n");
-            // Some git commands print errors to stdout (e.g., `commit` with nothing to commit)
-            let stdout = String::from_utf8_lossy(&output.stdout);
-            let error_msg = [stderr.trim(), stdout.trim()]
-                .into_iter()
-                .filter(|s| !s.is_empty())
-                .collect::<Vec<_>>()
-                .join("\n");
-            bail!("{}", error_msg);
+            return Err(GitCommandError::from_output(args, dir, &output).into());
         }
 
         let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
@@ -638,6 +665,11 @@ impl Repository {
             delay_ms
         );
 
+        if crate::dry_run::is_dry_run() && crate::dry_run::git_mutates(args) {
+            crate::dry_run::report(&cmd_str, None);
+            return Ok(());
+        }
+
         let mut child = std::process::Command::new("git")
             .args(args)
             .current_dir(&self.discovery_path)
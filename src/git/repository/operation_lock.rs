@@ -0,0 +1,137 @@
+//! Advisory lock serializing mutating operations across `wt` processes.
+//!
+//! Two terminals running `wt switch --create feature` at once would both
+//! plan a creation and one would fail inside `git worktree add`. Mutating
+//! operations instead hold `.git/worktrunk/operation.lock` across their
+//! critical git commands. The lock is an OS file lock, so it is released when
+//! the holding process exits, even if it crashes — it can never go stale.
+//!
+//! A background `wt remove` finishes in the hidden `wt remove-detached`, so
+//! the detached removal holds the lock too.
+
+use std::collections::HashMap;
+use std::fs;
+use std::io::{Read, Seek, Write};
+use std::path::PathBuf;
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use fs2::FileExt;
+
+use super::{GitError, Repository};
+
+/// How long to wait for another process before giving up.
+///
+/// Critical sections are short (a `git worktree add`, a branch update), so a
+/// lock held longer than this is most likely a stuck process.
+const LOCK_WAIT: Duration = Duration::from_secs(10);
+const LOCK_POLL: Duration = Duration::from_millis(50);
+
+/// The lock files held by this process, by path, with their nesting depth.
+///
+/// Operations nest (`wt merge` removes the worktree it merged), and a second
+/// `flock` from the same process would wait on itself, so nested
+/// acquisitions share the outer lock. Keyed by path, as one process can work
+/// on more than one repository.
+static HELD: Mutex<Option<HashMap<PathBuf, (fs::File, usize)>>> = Mutex::new(None);
+
+fn held() -> MutexGuard<'static, Option<HashMap<PathBuf, (fs::File, usize)>>> {
+    HELD.lock().unwrap()
+}
+
+/// Join a lock this process already holds at `path`, if any.
+fn join_held(path: &PathBuf) -> Option<OperationLock> {
+    let mut held = held();
+    let (_, depth) = held.as_mut()?.get_mut(path)?;
+    *depth += 1;
+    Some(OperationLock(path.clone()))
+}
+
+/// Guard for the operation lock; released on drop.
+#[must_use = "the lock is released when the guard is dropped"]
+pub struct OperationLock(PathBuf);
+
+impl Drop for OperationLock {
+    fn drop(&mut self) {
+        let mut held = held();
+        let Some(locks) = held.as_mut() else {
+            return;
+        };
+        if let Some((_, depth)) = locks.get_mut(&self.0) {
+            *depth -= 1;
+            if *depth == 0 {
+                // Dropping the file releases the OS lock
+                locks.remove(&self.0);
+            }
+        }
+    }
+}
+
+impl Repository {
+    /// Take the repository-wide operation lock for `operation` (e.g.
+    /// `"remove"`), waiting briefly for another `wt` process to finish.
+    ///
+    /// Fails with [`GitError::OperationInProgress`] if the lock stays held.
+    pub fn lock_operation(&self, operation: &str) -> anyhow::Result<OperationLock> {
+        let path = self.operation_lock_path();
+        if let Some(lock) = join_held(&path) {
+            return Ok(lock);
+        }
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut file = fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(&path)?;
+
+        // Poll without holding `HELD`, so other threads aren't stalled behind
+        // another process's lock
+        let start = Instant::now();
+        while file.try_lock_exclusive().is_err() {
+            // Another thread of this process took it meanwhile; nest under it
+            if let Some(lock) = join_held(&path) {
+                return Ok(lock);
+            }
+            if start.elapsed() >= LOCK_WAIT {
+                return Err(GitError::OperationInProgress {
+                    holder: read_holder(&mut file),
+                }
+                .into());
+            }
+            std::thread::sleep(LOCK_POLL);
+        }
+
+        // Record the holder for the error other processes show; best-effort
+        let _ = file
+            .set_len(0)
+            .and_then(|()| file.rewind())
+            .and_then(|()| write!(file, "wt {operation} (pid {})", std::process::id()));
+        log::debug!("Acquired operation lock for {operation}");
+
+        held()
+            .get_or_insert_with(HashMap::new)
+            .insert(path.clone(), (file, 1));
+        Ok(OperationLock(path))
+    }
+
+    fn operation_lock_path(&self) -> PathBuf {
+        self.git_common_dir()
+            .join("worktrunk")
+            .join("operation.lock")
+    }
+}
+
+/// What the current holder wrote into the lock file, if readable.
+///
+/// Windows blocks reads of a locked file, so this is `None` there.
+fn read_holder(file: &mut fs::File) -> Option<String> {
+    let mut holder = String::new();
+    file.rewind().ok()?;
+    file.read_to_string(&mut holder).ok()?;
+    let holder = holder.trim();
+    (!holder.is_empty()).then(|| holder.to_string())
+}
@@ -36,21 +36,65 @@ impl Repository {
                 let output = self
                     .run_command(&["config", "--get-regexp", r"remote\..+\.url"])
                     .unwrap_or_default();
-                let first_remote = output.lines().next().and_then(|line| {
-                    // Parse "remote.<name>.url <value>" format
-                    // Use ".url " as delimiter to handle remote names with dots (e.g., "my.remote")
-                    line.strip_prefix("remote.")
-                        .and_then(|s| s.split_once(".url "))
-                        .map(|(name, _)| name)
-                });
-
-                first_remote.map(|s| s.to_string())
+                let remotes: Vec<&str> = output
+                    .lines()
+                    .filter_map(|line| {
+                        // Parse "remote.<name>.url <value>" format
+                        // Use ".url " as delimiter to handle remote names with dots (e.g., "my.remote")
+                        line.strip_prefix("remote.")
+                            .and_then(|s| s.split_once(".url "))
+                            .map(|(name, _)| name)
+                    })
+                    .collect();
+
+                // In fork workflows, pushes go to the fork, never the upstream remote
+                let upstream = self.upstream_remote();
+                remotes
+                    .iter()
+                    .find(|name| upstream.as_deref() != Some(**name))
+                    .or(remotes.first())
+                    .map(|s| s.to_string())
             })
             .clone()
             .ok_or_else(|| anyhow::anyhow!("No remotes configured"))
     }
 
     /// Check if a remote has a URL configured.
+    /// The remote to integrate with in fork workflows (e.g., `upstream`).
+    ///
+    /// Set with `git config worktrunk.upstream-remote <name>`; otherwise a remote
+    /// named `upstream` is used if it exists. When present, integration checks,
+    /// ahead/behind counts, and `wt merge` rebases use `<upstream-remote>/<branch>`
+    /// when it's ahead of the local branch, while pushes keep going to the
+    /// primary remote.
+    ///
+    /// Returns `None` if no such remote has a URL.
+    pub fn upstream_remote(&self) -> Option<String> {
+        self.cache
+            .upstream_remote
+            .get_or_init(|| {
+                let configured = self
+                    .run_command(&["config", "--get", "worktrunk.upstream-remote"])
+                    .ok()
+                    .map(|s| s.trim().to_string())
+                    .filter(|s| !s.is_empty());
+                let name = configured.unwrap_or_else(|| "upstream".to_string());
+                self.remote_has_url(&name).then_some(name)
+            })
+            .clone()
+    }
+
+    /// The upstream remote's copy of `branch` in fork workflows (e.g., `upstream/main`).
+    ///
+    /// Returns `None` without an upstream remote, or if it has no such branch (fetched).
+    pub fn fork_upstream_ref(&self, branch: &str) -> Option<String> {
+        let remote = self.upstream_remote()?;
+        let reference = format!("{remote}/{branch}");
+        self.ref_exists(&format!("refs/remotes/{reference}"))
+            .unwrap_or(false)
+            .then_some(reference)
+    }
+
     fn remote_has_url(&self, remote: &str) -> bool {
         self.run_command(&["config", &format!("remote.{}.url", remote)])
             .map(|url| !url.trim().is_empty())
@@ -211,6 +255,38 @@ impl Repository {
             .and_then(|list| list.url)
     }
 
+    /// Whether the project config lists `branch` under `[branches] protected`.
+    pub fn is_protected_branch(&self, branch: &str) -> bool {
+        self.load_project_config()
+            .ok()
+            .flatten()
+            .is_some_and(|config| config.is_protected_branch(branch))
+    }
+
+    /// The remote-tracking SHA of `branch` when pushing it would not fast-forward.
+    ///
+    /// Returns `None` when `<remote>/<branch>` doesn't exist yet or is an
+    /// ancestor of the local branch. A `Some` means the branch was rewritten
+    /// (rebase, squash, amend) since it was last pushed, or that the remote
+    /// gained commits the branch never had; callers pair the lease with
+    /// `--force-if-includes` to tell the two apart.
+    pub fn rewritten_remote_branch(
+        &self,
+        remote: &str,
+        branch: &str,
+    ) -> anyhow::Result<Option<String>> {
+        let tracking = format!("refs/remotes/{remote}/{branch}");
+        let Ok(remote_sha) = self.run_command(&["rev-parse", "--verify", "-q", &tracking]) else {
+            return Ok(None);
+        };
+        let remote_sha = remote_sha.trim().to_string();
+        if self.is_ancestor(&remote_sha, &format!("refs/heads/{branch}"))? {
+            Ok(None)
+        } else {
+            Ok(Some(remote_sha))
+        }
+    }
+
     /// Check if a ref is a remote tracking branch.
     ///
     /// Returns true if the ref exists under `refs/remotes/` (e.g., `origin/main`).
@@ -223,4 +299,19 @@ impl Repository {
         ])
         .is_ok()
     }
+
+    /// Check if a remote is a partial clone source (`remote.<name>.promisor`).
+    ///
+    /// Partial clones fetch blobs (or trees) lazily; fetches from such a remote
+    /// accept `--filter`.
+    pub fn is_partial_clone_remote(&self, remote: &str) -> bool {
+        self.run_command(&["config", "--get", &format!("remote.{remote}.promisor")])
+            .is_ok_and(|value| value.trim() == "true")
+    }
+
+    /// Check if the repository has truncated history (`git clone --depth`).
+    pub fn is_shallow(&self) -> bool {
+        self.run_command(&["rev-parse", "--is-shallow-repository"])
+            .is_ok_and(|value| value.trim() == "true")
+    }
 }
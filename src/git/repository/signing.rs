@@ -0,0 +1,202 @@
+//! Commit signing (`commit.gpgsign`) for commits worktrunk creates.
+//!
+//! Squash and rebase rewrite commits, so a repository that requires signed
+//! commits needs every rewritten commit signed again. Git does this itself
+//! when `commit.gpgsign` is set; worktrunk passes `--gpg-sign` explicitly so
+//! the intent is visible in traces, and turns git's signing failures into
+//! [`GitError::SigningFailed`] instead of a generic "failed to write commit
+//! object".
+
+use std::path::PathBuf;
+
+use crate::shell_exec::Cmd;
+
+use super::{GitError, Repository};
+
+/// Signature backend from `gpg.format`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::Display)]
+pub enum SigningFormat {
+    #[strum(serialize = "gpg")]
+    OpenPgp,
+    #[strum(serialize = "ssh")]
+    Ssh,
+    #[strum(serialize = "x509")]
+    X509,
+}
+
+/// Signing settings, present only when `commit.gpgsign` is on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SigningConfig {
+    pub format: SigningFormat,
+    /// `user.signingkey`; `None` lets gpg pick the committer's key
+    pub key: Option<String>,
+    /// `gpg.program`, `gpg.ssh.program`, or `gpg.x509.program`
+    pub program: String,
+}
+
+impl SigningConfig {
+    /// Check the signing key is usable, returning why when it isn't.
+    ///
+    /// For OpenPGP and X.509 this asks the signing program for a secret key;
+    /// for SSH it checks the key file exists (literal `key::` keys and agent
+    /// keys are assumed usable).
+    pub fn check_key(&self, repo: &Repository) -> Result<(), String> {
+        match self.format {
+            SigningFormat::Ssh => {
+                let Some(key) = &self.key else {
+                    return Err("user.signingkey is not set".into());
+                };
+                if key.starts_with("key::") || key.starts_with("ssh-") {
+                    return Ok(());
+                }
+                let path = PathBuf::from(shellexpand::tilde(key).as_ref());
+                if path.exists() {
+                    Ok(())
+                } else {
+                    Err(format!("SSH key {key} does not exist"))
+                }
+            }
+            SigningFormat::OpenPgp | SigningFormat::X509 => {
+                let key = match &self.key {
+                    Some(key) => key.clone(),
+                    None => repo
+                        .get_config("user.email")
+                        .ok()
+                        .flatten()
+                        .ok_or("Neither user.signingkey nor user.email is set")?,
+                };
+                let output = Cmd::new(&self.program)
+                    .args(["--list-secret-keys", key.as_str()])
+                    .read_only()
+                    .run()
+                    .map_err(|e| format!("Could not run {}: {e}", self.program))?;
+                if output.status.success() {
+                    Ok(())
+                } else {
+                    Err(format!("{} has no secret key for {key}", self.program))
+                }
+            }
+        }
+    }
+
+    fn describe_key(&self) -> String {
+        match &self.key {
+            Some(key) => format!("{} key {key}", self.format),
+            None => format!("default {} key", self.format),
+        }
+    }
+}
+
+impl Repository {
+    /// Signing settings when `commit.gpgsign` is on, `None` otherwise.
+    pub fn signing_config(&self) -> Option<SigningConfig> {
+        let enabled = self
+            .run_command(&["config", "--type=bool", "commit.gpgsign"])
+            .is_ok_and(|value| value.trim() == "true");
+        if !enabled {
+            return None;
+        }
+        let format = match self.get_config("gpg.format").ok().flatten().as_deref() {
+            Some("ssh") => SigningFormat::Ssh,
+            Some("x509") => SigningFormat::X509,
+            _ => SigningFormat::OpenPgp,
+        };
+        let (program_key, default_program) = match format {
+            SigningFormat::OpenPgp => ("gpg.program", "gpg"),
+            SigningFormat::Ssh => ("gpg.ssh.program", "ssh-keygen"),
+            SigningFormat::X509 => ("gpg.x509.program", "gpgsm"),
+        };
+        let program = self
+            .get_config(program_key)
+            .ok()
+            .flatten()
+            .or_else(|| {
+                (format == SigningFormat::OpenPgp)
+                    .then(|| self.get_config("gpg.openpgp.program").ok().flatten())
+                    .flatten()
+            })
+            .unwrap_or_else(|| default_program.to_string());
+        Some(SigningConfig {
+            format,
+            key: self.get_config("user.signingkey").ok().flatten(),
+            program,
+        })
+    }
+
+    /// Git args requesting a signature when signing is configured.
+    pub fn signing_args(&self) -> &'static [&'static str] {
+        if self.signing_config().is_some() {
+            &["--gpg-sign"]
+        } else {
+            &[]
+        }
+    }
+
+    /// Turn a git failure into [`GitError::SigningFailed`] when signing caused it.
+    ///
+    /// Other errors pass through unchanged.
+    pub fn signing_error(&self, operation: &str, error: anyhow::Error) -> anyhow::Error {
+        let Some(signing) = self.signing_config() else {
+            return error;
+        };
+        let output = error.to_string();
+        if !is_signing_failure(&output) {
+            return error;
+        }
+        GitError::SigningFailed {
+            operation: operation.to_string(),
+            key: signing.describe_key(),
+            git_output: output,
+        }
+        .into()
+    }
+}
+
+/// Whether git output reports a failed signature rather than another error.
+fn is_signing_failure(output: &str) -> bool {
+    const MARKERS: &[&str] = &[
+        "failed to sign the data",
+        "couldn't load public key",
+        "couldn't find key in agent",
+        "no private key",
+        "load key \"",
+        "signing failed",
+    ];
+    let output = output.to_lowercase();
+    MARKERS.iter().any(|marker| output.contains(marker))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_signing_failure() {
+        assert!(is_signing_failure(
+            "error: gpg failed to sign the data\nfatal: failed to write commit object"
+        ));
+        assert!(is_signing_failure(
+            "error: Couldn't load public key /home/u/.ssh/id_ed25519.pub: No such file or directory"
+        ));
+        assert!(is_signing_failure(
+            "error: Load key \"/tmp/key\": invalid format"
+        ));
+        assert!(!is_signing_failure("nothing to commit, working tree clean"));
+    }
+
+    #[test]
+    fn test_describe_key() {
+        let config = SigningConfig {
+            format: SigningFormat::Ssh,
+            key: Some("~/.ssh/id_ed25519.pub".into()),
+            program: "ssh-keygen".into(),
+        };
+        assert_eq!(config.describe_key(), "ssh key ~/.ssh/id_ed25519.pub");
+        let config = SigningConfig {
+            format: SigningFormat::OpenPgp,
+            key: None,
+            program: "gpg".into(),
+        };
+        assert_eq!(config.describe_key(), "default gpg key");
+    }
+}
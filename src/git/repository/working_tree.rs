@@ -2,12 +2,12 @@
 
 use std::path::{Path, PathBuf};
 
-use anyhow::{Context, bail};
+use anyhow::Context;
 
 use crate::shell_exec::Cmd;
 use dunce::canonicalize;
 
-use super::{GitError, LineDiff, Repository};
+use super::{GitCommandError, GitError, LineDiff, Repository};
 
 /// Get a short display name for a path, used in logging context.
 pub fn path_to_logging_context(path: &Path) -> String {
@@ -68,17 +68,31 @@ impl<'a> WorkingTree<'a> {
     /// Run a git command in this worktree and return stdout.
     pub fn run_command(&self, args: &[&str]) -> anyhow::Result<String> {
         let output = self.run_command_output(args)?;
+        self.command_stdout(args, output)
+    }
+
+    /// Run a git command that talks to a remote, retrying transient failures.
+    ///
+    /// See [`Repository::run_remote_command`].
+    pub fn run_remote_command(&self, args: &[&str]) -> anyhow::Result<String> {
+        let output = Cmd::new("git")
+            .args(args.iter().copied())
+            .current_dir(&self.path)
+            .context(path_to_logging_context(&self.path))
+            .retry(crate::git::retry::GIT_RETRY)
+            .network()
+            .run()
+            .with_context(|| format!("Failed to execute: git {}", args.join(" ")))?;
+        self.command_stdout(args, output)
+    }
 
+    fn command_stdout(
+        &self,
+        args: &[&str],
+        output: std::process::Output,
+    ) -> anyhow::Result<String> {
         if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            let stderr = stderr.replace('\r', "\n");
-            let stdout = String::from_utf8_lossy(&output.stdout);
-            let error_msg = [stderr.trim(), stdout.trim()]
-                .into_iter()
-                .filter(|s| !s.is_empty())
-                .collect::<Vec<_>>()
-                .join("\n");
-            bail!("{}", error_msg);
+            return Err(GitCommandError::from_output(args, &self.path, &output).into());
         }
 
         let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
@@ -323,4 +337,72 @@ impl<'a> WorkingTree<'a> {
 
         Ok(backup_sha[..7].to_string())
     }
+
+    /// Check if this worktree declares submodules (has a `.gitmodules` file).
+    pub fn has_submodules(&self) -> bool {
+        self.path.join(".gitmodules").is_file()
+    }
+
+    /// Check out the commits recorded for this worktree's submodules.
+    ///
+    /// New worktrees start with empty submodule directories; this runs
+    /// `git submodule update --init --recursive` to populate them.
+    pub fn update_submodules(&self) -> anyhow::Result<()> {
+        self.run_command(&["submodule", "update", "--init", "--recursive"])?;
+        Ok(())
+    }
+
+    /// Check if this worktree tracks files with Git LFS (`filter=lfs` in `.gitattributes`).
+    pub fn uses_lfs(&self) -> bool {
+        std::fs::read_to_string(self.path.join(".gitattributes"))
+            .is_ok_and(|attributes| attributes.contains("filter=lfs"))
+    }
+
+    /// Check if Git LFS is set up for this worktree: the smudge filter is
+    /// configured and the `post-checkout` hook runs `git lfs`.
+    ///
+    /// Without them, checkouts leave LFS pointer files in place of content.
+    /// The hooks directory is resolved with `git rev-parse --git-path hooks`,
+    /// so `core.hooksPath` is respected.
+    pub fn lfs_installed(&self) -> anyhow::Result<bool> {
+        let filter = self
+            .run_command(&["config", "--get", "filter.lfs.smudge"])
+            .unwrap_or_default();
+        if filter.trim().is_empty() {
+            return Ok(false);
+        }
+        let hooks_dir = self.run_command(&["rev-parse", "--git-path", "hooks"])?;
+        let hook = self.path.join(hooks_dir.trim()).join("post-checkout");
+        Ok(std::fs::read_to_string(hook).is_ok_and(|script| script.contains("git lfs")))
+    }
+
+    /// Deinitialize this worktree's submodules so git can remove the worktree.
+    ///
+    /// `git worktree remove` refuses worktrees containing submodules, even with
+    /// `--force`. Deinit empties the submodule directories, then the per-worktree
+    /// `modules/` directory (the submodules' git dirs) is deleted. Without
+    /// `force`, deinit refuses submodules with local modifications.
+    ///
+    /// No-op when no submodule was ever initialized in this worktree.
+    pub fn deinit_submodules(&self, force: bool) -> anyhow::Result<()> {
+        // The main worktree's `modules/` belongs to the whole repository
+        let git_dir = self.git_dir()?;
+        let modules_dir = git_dir.join("modules");
+        if git_dir == self.repo.git_common_dir() || !modules_dir.exists() {
+            return Ok(());
+        }
+        let mut args = vec!["submodule", "deinit", "--all"];
+        if force {
+            args.push("--force");
+        }
+        self.run_command(&args)?;
+        if crate::dry_run::skip(&format!(
+            "remove {}",
+            crate::path::format_path_for_display(&modules_dir)
+        )) {
+            return Ok(());
+        }
+        std::fs::remove_dir_all(&modules_dir).context("Failed to remove submodule git dirs")?;
+        Ok(())
+    }
 }
@@ -6,8 +6,10 @@ use color_print::cformat;
 use dunce::canonicalize;
 use normalize_path::NormalizePath;
 
-use super::{GitError, Repository, ResolvedWorktree, WorktreeInfo};
+use super::{GitCommandError, GitError, Repository, ResolvedWorktree, WorktreeInfo};
+use crate::git::path_dir_name;
 use crate::path::format_path_for_display;
+use crate::state::{Operation, StateStore};
 
 impl Repository {
     /// List all worktrees for this repository.
@@ -122,6 +124,72 @@ impl Repository {
         Ok(())
     }
 
+    /// Remove a worktree the way `wt remove` does, shared with
+    /// [`ops::remove_worktree`](crate::ops::remove_worktree).
+    ///
+    /// Holds the operation lock across the removal and `then` (which deletes
+    /// the branch, if wanted), stops the worktree's fsmonitor daemon first so
+    /// none are left behind, and journals the removal for `wt undo` with the
+    /// worktree's HEAD and `snapshot`. A failed removal is reported as
+    /// [`GitError::WorktreeRemovalFailed`].
+    pub fn remove_worktree_journaled<T>(
+        &self,
+        path: &Path,
+        branch: Option<&str>,
+        force: bool,
+        snapshot: Option<&str>,
+        then: impl FnOnce() -> anyhow::Result<T>,
+    ) -> anyhow::Result<T> {
+        let worktree = self.worktree_at(path);
+        let commit = worktree
+            .run_command(&["rev-parse", "HEAD"])
+            .ok()
+            .map(|sha| sha.trim().to_string());
+
+        let lock = self.lock_operation("remove")?;
+        // Best effort; keeps builtin fsmonitor daemons from accumulating
+        let _ = worktree.run_command(&["fsmonitor--daemon", "stop"]);
+        if let Err(err) = self.remove_worktree(path, force) {
+            return Err(GitError::WorktreeRemovalFailed {
+                branch: branch.map_or_else(|| path_dir_name(path).to_string(), String::from),
+                path: path.to_path_buf(),
+                error: err.to_string(),
+                source: GitCommandError::find(&err).cloned(),
+            }
+            .into());
+        }
+        let result = then();
+        drop(lock);
+
+        if let Some(commit) = commit {
+            StateStore::new(self.git_common_dir()).record(Operation::WorktreeRemoved {
+                branch: branch.map(String::from),
+                path: path.to_path_buf(),
+                commit,
+                snapshot: snapshot.map(String::from),
+            });
+        }
+        result
+    }
+
+    /// Lock the worktree at `path`, so git won't prune, move or remove it.
+    pub fn lock_worktree(&self, path: &Path, reason: Option<&str>) -> anyhow::Result<()> {
+        let path_str = path.to_string_lossy();
+        let mut args = vec!["worktree", "lock"];
+        if let Some(reason) = reason {
+            args.extend(["--reason", reason]);
+        }
+        args.push(&path_str);
+        self.run_command(&args)?;
+        Ok(())
+    }
+
+    /// Unlock the worktree at `path`.
+    pub fn unlock_worktree(&self, path: &Path) -> anyhow::Result<()> {
+        self.run_command(&["worktree", "unlock", &path.to_string_lossy()])?;
+        Ok(())
+    }
+
     /// Resolve a worktree name, expanding "@" to current, "-" to previous, and "^" to main.
     ///
     /// # Arguments
@@ -222,12 +290,31 @@ impl Repository {
 
     /// Find the "home" path - where to cd when leaving a worktree.
     ///
-    /// Returns the primary worktree if it exists, otherwise the repo root.
+    /// Returns the primary worktree if it exists, otherwise the layout root.
     /// - Normal repos: the main worktree (repo root)
-    /// - Bare repos: the default branch's worktree, or the bare repo directory
+    /// - Bare repos: the default branch's worktree, or the directory holding the worktrees
     pub fn home_path(&self) -> anyhow::Result<PathBuf> {
         Ok(self
             .primary_worktree()?
-            .unwrap_or_else(|| self.repo_path().to_path_buf()))
+            .unwrap_or_else(|| self.layout_root()))
+    }
+
+    /// The directory that holds the repository and, for bare layouts, its worktrees.
+    ///
+    /// - Normal repos: the main worktree (same as `repo_path()`)
+    /// - Bare repos at `myproject/.git`: the parent, `myproject/`
+    /// - Other bare repos: the bare repo directory, which holds the worktrees
+    ///
+    /// Unlike `home_path()`, this is never a worktree, so it survives removing
+    /// the default branch's worktree in a bare layout.
+    pub fn layout_root(&self) -> PathBuf {
+        let repo_path = self.repo_path();
+        if self.is_bare()
+            && repo_path.file_name().is_some_and(|name| name == ".git")
+            && let Some(parent) = repo_path.parent()
+        {
+            return parent.to_path_buf();
+        }
+        repo_path.to_path_buf()
     }
 }
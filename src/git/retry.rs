@@ -0,0 +1,141 @@
+//! Retries for git commands that talk to a remote.
+//!
+//! Fetches and `ls-remote` fail now and then for reasons that go away on
+//! their own: a dropped connection, a DNS hiccup, a 503 from a busy forge.
+//! Those are retried with backoff, up to `[network] attempts` tries. Failures
+//! that would only repeat — bad credentials, a missing repository, a rejected
+//! non-fast-forward push — fail straight away.
+
+use std::process::Output;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::Duration;
+
+use crate::shell_exec::RetryPolicy;
+
+/// Tries per remote command unless the config says otherwise.
+pub const DEFAULT_ATTEMPTS: u32 = 3;
+
+static ATTEMPTS: AtomicU32 = AtomicU32::new(DEFAULT_ATTEMPTS);
+
+/// Delay before the first retry; doubles for each one after.
+const BASE_DELAY: Duration = Duration::from_secs(1);
+
+/// Set how many times a remote command is tried (at least once).
+///
+/// Call this once at startup after reading the config.
+pub fn set_remote_attempts(attempts: u32) {
+    ATTEMPTS.store(attempts.max(1), Ordering::Relaxed);
+}
+
+/// Output that means the same command would fail again.
+const PERMANENT: &[&str] = &[
+    "authentication failed",
+    "permission denied",
+    "could not read username",
+    "could not read password",
+    "repository not found",
+    "does not appear to be a git repository",
+    "couldn't find remote ref",
+    "non-fast-forward",
+    "[rejected]",
+    "[remote rejected]",
+    "returned error: 401",
+    "returned error: 403",
+    "returned error: 404",
+];
+
+/// Output that points at the network or an overloaded server.
+const TRANSIENT: &[&str] = &[
+    "could not resolve host",
+    "temporary failure in name resolution",
+    "connection timed out",
+    "operation timed out",
+    "connection reset",
+    "connection refused",
+    "connection was reset",
+    "unexpected disconnect",
+    "the remote end hung up unexpectedly",
+    "early eof",
+    "rpc failed",
+    "gnutls recv error",
+    "ssl_read",
+    "returned error: 500",
+    "returned error: 502",
+    "returned error: 503",
+    "returned error: 504",
+    "http 500",
+    "http 502",
+    "http 503",
+    "http 504",
+];
+
+/// Whether git's output describes a failure worth retrying.
+///
+/// Permanent causes win: a hung-up connection after an auth failure is still
+/// an auth failure.
+pub fn is_transient(output: &str) -> bool {
+    let output = output.to_lowercase();
+    !PERMANENT.iter().any(|pattern| output.contains(pattern))
+        && TRANSIENT.iter().any(|pattern| output.contains(pattern))
+}
+
+/// Retry policy for [`Cmd::retry`](crate::shell_exec::Cmd::retry) on remote
+/// git commands.
+pub(crate) const GIT_RETRY: RetryPolicy = RetryPolicy {
+    attempts: || ATTEMPTS.load(Ordering::Relaxed),
+    delay: git_retry_delay,
+};
+
+/// How long to wait before retrying a failed remote git command, or `None`
+/// to give up.
+fn git_retry_delay(attempt: u32, output: &Output) -> Option<Duration> {
+    let text = format!(
+        "{}\n{}",
+        String::from_utf8_lossy(&output.stderr),
+        String::from_utf8_lossy(&output.stdout)
+    );
+    retry_delay(attempt, &text, ATTEMPTS.load(Ordering::Relaxed))
+}
+
+fn retry_delay(attempt: u32, text: &str, attempts: u32) -> Option<Duration> {
+    (attempt + 1 < attempts && is_transient(text)).then(|| BASE_DELAY * 2u32.pow(attempt))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_transient() {
+        assert!(is_transient(
+            "fatal: unable to access 'https://github.com/o/r/': Could not resolve host: github.com"
+        ));
+        assert!(is_transient(
+            "error: RPC failed; HTTP 503 curl 22 The requested URL returned error: 503"
+        ));
+        assert!(is_transient("fatal: early EOF"));
+        assert!(!is_transient(
+            "remote: Invalid username or password.\nfatal: Authentication failed for 'https://github.com/o/r/'"
+        ));
+        assert!(!is_transient(
+            "git@github.com: Permission denied (publickey).\nfatal: Could not read from remote repository."
+        ));
+        assert!(!is_transient(
+            " ! [rejected]        main -> main (non-fast-forward)"
+        ));
+        assert!(!is_transient("fatal: couldn't find remote ref feature"));
+    }
+
+    #[test]
+    fn test_retry_delay() {
+        // Transient: exponential backoff until the attempts run out
+        let eof = "fatal: early EOF";
+        assert_eq!(retry_delay(0, eof, 3), Some(Duration::from_secs(1)));
+        assert_eq!(retry_delay(1, eof, 3), Some(Duration::from_secs(2)));
+        assert_eq!(retry_delay(2, eof, 3), None);
+        assert_eq!(retry_delay(0, eof, 1), None);
+
+        // Permanent failures aren't retried
+        assert_eq!(retry_delay(0, "fatal: Authentication failed", 3), None);
+    }
+}
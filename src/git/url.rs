@@ -160,6 +160,17 @@ impl GitRemoteUrl {
     pub fn is_gitlab(&self) -> bool {
         self.host.to_ascii_lowercase().contains("gitlab")
     }
+
+    /// Web page for `branch` on the forge.
+    ///
+    /// GitLab uses `/-/tree/<branch>`; GitHub, Gitea, and Forgejo use `/tree/<branch>`.
+    pub fn branch_web_url(&self, branch: &str) -> String {
+        let tree = if self.is_gitlab() { "-/tree" } else { "tree" };
+        format!(
+            "https://{}/{}/{}/{tree}/{branch}",
+            self.host, self.owner, self.repo
+        )
+    }
 }
 
 /// Extract owner from a git remote URL.
@@ -1037,4 +1048,18 @@ mod tests {
             identifiers
         );
     }
+
+    #[test]
+    fn test_branch_web_url() {
+        let github = GitRemoteUrl::parse("git@github.com:owner/repo.git").unwrap();
+        assert_eq!(
+            github.branch_web_url("feature/x"),
+            "https://github.com/owner/repo/tree/feature/x"
+        );
+        let gitlab = GitRemoteUrl::parse("https://gitlab.com/group/sub/repo.git").unwrap();
+        assert_eq!(
+            gitlab.branch_web_url("main"),
+            "https://gitlab.com/group/sub/repo/-/tree/main"
+        );
+    }
 }
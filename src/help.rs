@@ -77,7 +77,7 @@ pub fn maybe_handle_help_with_pager() -> bool {
         // Fall through if not a help request
     }
 
-    let mut cmd = cli::build_command();
+    let mut cmd = crate::alias::with_aliases(cli::build_command(), &crate::alias::load());
     cmd = cmd.color(clap::ColorChoice::Always); // Force clap to emit ANSI codes
 
     match cmd.try_get_matches_from_mut(args) {
@@ -92,10 +92,14 @@ pub fn maybe_handle_help_with_pager() -> bool {
                     // Render markdown sections (tables, code blocks, prose) with proper wrapping.
                     // Since we disabled clap's wrapping above, our renderer controls all line breaks.
                     let width = worktrunk::styling::get_terminal_width();
-                    let help = crate::md_help::render_markdown_in_help_with_width(
+                    let mut help = crate::md_help::render_markdown_in_help_with_width(
                         &clap_output,
                         Some(width),
                     );
+                    // The pager bypasses anstream, so `--color=never` is applied here
+                    if !worktrunk::styling::colors_enabled(worktrunk::styling::Stream::Stderr) {
+                        help = anstream::adapter::strip_str(&help).to_string();
+                    }
 
                     // show_help_in_pager checks if stdout or stderr is a TTY.
                     // If neither is a TTY (e.g., `wt --help &>file`), it skips the pager.
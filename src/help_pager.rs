@@ -25,42 +25,7 @@ use std::io::{IsTerminal, Write};
 use std::process::Stdio;
 use worktrunk::shell_exec::ShellConfig;
 
-use crate::pager::{git_config_pager, parse_pager_value};
-
-/// Detect pager for help output, following git's pager precedence.
-///
-/// Checks in order: GIT_PAGER → git config core.pager → PAGER → "less"
-///
-/// On Windows without Git Bash, returns None if only `less` would be selected
-/// (since `less` isn't available without Git for Windows).
-fn detect_help_pager() -> Option<String> {
-    let shell = ShellConfig::get();
-
-    // Check environment variables in git's precedence order
-    let pager = std::env::var("GIT_PAGER")
-        .ok()
-        .and_then(|s| parse_pager_value(&s))
-        .or_else(git_config_pager)
-        .or_else(|| {
-            std::env::var("PAGER")
-                .ok()
-                .and_then(|s| parse_pager_value(&s))
-        });
-
-    // If user explicitly configured a pager, use it
-    if pager.is_some() {
-        return pager;
-    }
-
-    // Default to "less" only if we have a POSIX shell (Unix or Git Bash on Windows)
-    // Without Git Bash, less isn't typically available on Windows
-    if shell.is_posix() {
-        Some("less".to_string())
-    } else {
-        log::debug!("No POSIX shell available, skipping pager (less not available)");
-        None
-    }
-}
+use crate::pager::{compute_less_flags, detect_pager};
 
 /// Show help text through a pager with TTY access for interactive scrolling.
 ///
@@ -87,7 +52,7 @@ pub(crate) fn show_help_in_pager(help_text: &str, use_pager: bool) -> std::io::R
         return Ok(());
     }
 
-    let Some(pager_cmd) = detect_help_pager() else {
+    let Some(pager_cmd) = detect_pager() else {
         log::debug!("No pager configured, printing help directly to stderr");
         eprint!("{}", help_text);
         return Ok(());
@@ -143,17 +108,9 @@ pub(crate) fn show_help_in_pager(help_text: &str, use_pager: bool) -> std::io::R
     Ok(())
 }
 
-/// Compute LESS flags by appending our required flags to user's existing LESS setting.
-///
-/// Returns flags suitable for setting LESS env var when spawning less.
-/// Ensures F (quit if one screen), R (colors), X (no termcap init) are always active.
-fn compute_less_flags(user_less: Option<&str>) -> String {
-    format!("{} -FRX", user_less.unwrap_or_default())
-}
-
 #[cfg(test)]
 mod tests {
-    use super::{compute_less_flags, parse_pager_value};
+    use crate::pager::parse_pager_value;
 
     #[test]
     fn test_validate_excludes_cat() {
@@ -170,35 +127,4 @@ mod tests {
         assert_eq!(parse_pager_value("delta"), Some("delta".to_string()));
         assert_eq!(parse_pager_value("less -R"), Some("less -R".to_string()));
     }
-
-    #[test]
-    fn test_compute_less_flags_empty() {
-        // Leading space is fine - less ignores it
-        assert_eq!(compute_less_flags(None), " -FRX");
-        assert_eq!(compute_less_flags(Some("")), " -FRX");
-    }
-
-    #[test]
-    fn test_compute_less_flags_short_options() {
-        // Common case: user has -R (oh-my-zsh default)
-        assert_eq!(compute_less_flags(Some("-R")), "-R -FRX");
-        // User has multiple short flags
-        assert_eq!(compute_less_flags(Some("-iMRS")), "-iMRS -FRX");
-    }
-
-    #[test]
-    fn test_compute_less_flags_long_options() {
-        // Issue #594: --mouse must not become --mouseFRX
-        assert_eq!(compute_less_flags(Some("--mouse")), "--mouse -FRX");
-        // Multiple long options
-        assert_eq!(
-            compute_less_flags(Some("--mouse --shift=4")),
-            "--mouse --shift=4 -FRX"
-        );
-    }
-
-    #[test]
-    fn test_compute_less_flags_mixed() {
-        assert_eq!(compute_less_flags(Some("-R --mouse")), "-R --mouse -FRX");
-    }
 }
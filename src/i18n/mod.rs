@@ -0,0 +1,242 @@
+//! Message catalogs for translated output.
+//!
+//! Messages are written in English at the call site, under a stable key:
+//!
+//! ```
+//! let count = 3;
+//! let text = worktrunk::tr!("list.summary.worktrees", "{count} worktrees", count = count);
+//! assert_eq!(text, "3 worktrees");
+//! ```
+//!
+//! With `[ui] lang` set, the key is looked up in that language's catalog and
+//! the English text is the fallback for anything it doesn't cover. Catalogs
+//! are flat TOML tables of key to template:
+//!
+//! ```toml
+//! "list.summary.worktrees" = "{count} worktrees"
+//! ```
+//!
+//! Built-in catalogs live next to this module. A `locales/<lang>.toml` file in
+//! the user config directory adds to (and overrides) the built-in one, so a
+//! translation can be tried out before it ships.
+//!
+//! Arguments are substituted for `{name}` placeholders after lookup, so they
+//! can carry their own styling. There's no plural machinery: callers pick a
+//! key per form (`list.summary.worktree` vs `list.summary.worktrees`).
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::path::Path;
+use std::sync::OnceLock;
+
+use color_print::cformat;
+
+/// Catalogs compiled into the binary, by language code.
+const BUILTIN: &[(&str, &str)] = &[("fr", include_str!("fr.toml"))];
+
+/// Translations for one language.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Catalog {
+    messages: HashMap<String, String>,
+}
+
+impl Catalog {
+    /// Parse a catalog file.
+    pub fn parse(source: &str) -> Result<Self, toml::de::Error> {
+        Ok(Self {
+            messages: toml::from_str(source)?,
+        })
+    }
+
+    /// The built-in catalog for `lang`, if there is one.
+    pub fn builtin(lang: &str) -> Option<Self> {
+        BUILTIN
+            .iter()
+            .find(|(code, _)| *code == lang)
+            .map(|(_, source)| Self::parse(source).expect("built-in catalogs parse"))
+    }
+
+    /// Translation for `key`, if the catalog has one.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.messages.get(key).map(String::as_str)
+    }
+
+    fn extend(&mut self, other: Catalog) {
+        self.messages.extend(other.messages);
+    }
+}
+
+static ACTIVE: OnceLock<Catalog> = OnceLock::new();
+
+/// Candidate catalog names for a `lang` value, most specific first:
+/// `fr_CA.UTF-8` tries `fr_CA`, then `fr`.
+fn candidates(lang: &str) -> Vec<&str> {
+    let lang = lang.split('.').next().unwrap_or(lang);
+    let mut names = vec![lang];
+    if let Some((base, _)) = lang.split_once(['_', '-']) {
+        names.push(base);
+    }
+    names
+}
+
+/// Load the catalog for `lang`, from the built-ins and `locales_dir`.
+///
+/// Returns `None` when neither has anything for the language.
+pub fn load_catalog(lang: &str, locales_dir: Option<&Path>) -> Option<Catalog> {
+    for name in candidates(lang) {
+        let builtin = Catalog::builtin(name);
+        let user = locales_dir
+            .map(|dir| dir.join(format!("{name}.toml")))
+            .filter(|path| path.is_file())
+            .and_then(|path| {
+                let source = std::fs::read_to_string(&path).ok()?;
+                match Catalog::parse(&source) {
+                    Ok(catalog) => Some(catalog),
+                    Err(err) => {
+                        crate::ops::warn(
+                            &crate::styling::warning_message(cformat!(
+                                "Ignoring <bold>{}</>: {err}",
+                                path.display()
+                            ))
+                            .to_string(),
+                        );
+                        None
+                    }
+                }
+            });
+        match (builtin, user) {
+            (None, None) => continue,
+            (Some(mut catalog), Some(user)) => {
+                catalog.extend(user);
+                return Some(catalog);
+            }
+            (Some(catalog), None) | (None, Some(catalog)) => return Some(catalog),
+        }
+    }
+    None
+}
+
+/// Translate messages into `lang` from now on.
+///
+/// Call this once at startup after reading the config. English (`en`) needs no
+/// catalog; other languages without one warn and stay in English.
+pub fn set_lang(lang: &str) {
+    if lang == "en" || lang.starts_with("en_") || lang.starts_with("en-") {
+        return;
+    }
+    let locales_dir = crate::config::get_config_path()
+        .and_then(|path| path.parent().map(|dir| dir.join("locales")));
+    match load_catalog(lang, locales_dir.as_deref()) {
+        Some(catalog) => {
+            let _ = ACTIVE.set(catalog);
+        }
+        None => crate::ops::warn(
+            &crate::styling::warning_message(cformat!(
+                "No translations for <bold>{lang}</>; using English"
+            ))
+            .to_string(),
+        ),
+    }
+}
+
+/// Fill `{name}` placeholders in `template` from `args`.
+///
+/// Unknown placeholders are left as they are.
+pub fn format(template: &str, args: &[(&str, &dyn Display)]) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let value = after.find('}').and_then(|end| {
+            let name = &after[..end];
+            let (_, value) = args.iter().find(|(arg, _)| *arg == name)?;
+            Some((end, value))
+        });
+        match value {
+            Some((end, value)) => {
+                out.push_str(&value.to_string());
+                rest = &after[end + 1..];
+            }
+            None => {
+                out.push('{');
+                rest = after;
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Translate `key` (falling back to `default`) and fill in `args`.
+///
+/// Usually called through [`tr!`](crate::tr).
+pub fn translate(key: &str, default: &str, args: &[(&str, &dyn Display)]) -> String {
+    let template = ACTIVE
+        .get()
+        .and_then(|catalog| catalog.get(key))
+        .unwrap_or(default);
+    format(template, args)
+}
+
+/// Translate a message: `tr!("key", "English {name}", name = value)`.
+///
+/// See the [module docs](crate::i18n).
+#[macro_export]
+macro_rules! tr {
+    ($key:literal, $default:literal $(, $name:ident = $value:expr)* $(,)?) => {
+        $crate::i18n::translate(
+            $key,
+            $default,
+            &[$((stringify!($name), &$value as &dyn ::std::fmt::Display)),*],
+        )
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format() {
+        assert_eq!(
+            format(
+                "{count} worktrees, {n} ahead",
+                &[("count", &3), ("n", &"1")]
+            ),
+            "3 worktrees, 1 ahead"
+        );
+        // Unknown and unbalanced placeholders pass through
+        assert_eq!(format("{x} {", &[("count", &3)]), "{x} {");
+    }
+
+    #[test]
+    fn test_builtin_catalogs_cover_known_keys() {
+        for (lang, source) in BUILTIN {
+            let catalog = Catalog::parse(source).unwrap_or_else(|e| panic!("{lang}: {e}"));
+            assert!(catalog.get("list.summary.worktrees").is_some(), "{lang}");
+        }
+    }
+
+    #[test]
+    fn test_load_catalog() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("fr.toml"),
+            r#""list.summary.ahead" = "{count} devant""#,
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("xx.toml"), r#""a" = "b""#).unwrap();
+
+        // Region falls back to the language; user entries override built-ins
+        let fr = load_catalog("fr_CA.UTF-8", Some(dir.path())).unwrap();
+        assert_eq!(fr.get("list.summary.ahead"), Some("{count} devant"));
+        assert!(fr.get("list.summary.worktrees").is_some());
+
+        assert_eq!(
+            load_catalog("xx", Some(dir.path())).unwrap().get("a"),
+            Some("b")
+        );
+        assert_eq!(load_catalog("zz", Some(dir.path())), None);
+    }
+}
@@ -4,6 +4,13 @@
 //! - What name the binary was invoked as (`binary_name`)
 //! - Whether we're running as a git subcommand (`is_git_subcommand`)
 //! - Whether shell integration can work (`was_invoked_with_explicit_path`)
+//!
+//! [`GlobalArgs::scan`] reads the global options from the raw arguments, for
+//! the steps that run before clap parses them: `--color`, alias expansion and
+//! plugin dispatch.
+
+use std::ffi::OsString;
+use std::path::PathBuf;
 
 /// Get the binary name from `argv[0]`, falling back to "wt".
 ///
@@ -106,3 +113,111 @@ pub fn was_invoked_with_explicit_path() -> bool {
         .map(|arg0| arg0.contains('/') || arg0.contains('\\'))
         .unwrap_or(false)
 }
+
+/// Global options found in the raw arguments.
+#[derive(Debug, Default, PartialEq)]
+pub struct GlobalArgs {
+    /// Index of the subcommand in `argv`: the first argument that isn't a
+    /// global option or its value
+    pub subcommand: Option<usize>,
+    /// `-C <path>` before the subcommand
+    pub directory: Option<PathBuf>,
+    /// `--config <path>` before the subcommand
+    pub config: Option<PathBuf>,
+    /// `--color <when>`, before or after the subcommand
+    pub color: Option<String>,
+}
+
+impl GlobalArgs {
+    /// Scan `argv` (program name first), skipping the values of the global
+    /// options that take one. Stops at `--`.
+    pub fn scan(argv: &[OsString]) -> Self {
+        let mut found = Self::default();
+        let mut i = 1;
+        while i < argv.len() {
+            let arg = argv[i].to_string_lossy();
+            if arg == "--" {
+                break;
+            }
+            let before_subcommand = found.subcommand.is_none();
+            let value = argv.get(i + 1).map(|v| v.to_string_lossy().into_owned());
+            match arg.as_ref() {
+                "--color" => found.color = value,
+                "-C" if before_subcommand => found.directory = value.map(PathBuf::from),
+                "--config" if before_subcommand => found.config = value.map(PathBuf::from),
+                "-C" | "--config" => {}
+                _ => {
+                    if let Some(when) = arg.strip_prefix("--color=") {
+                        found.color = Some(when.to_string());
+                    } else if before_subcommand {
+                        if let Some(path) = arg.strip_prefix("--config=") {
+                            found.config = Some(PathBuf::from(path));
+                        } else if let Some(path) = arg.strip_prefix("-C") {
+                            found.directory = Some(PathBuf::from(path));
+                        } else if !arg.starts_with('-') {
+                            found.subcommand = Some(i);
+                        }
+                    }
+                    i += 1;
+                    continue;
+                }
+            }
+            // Skip the option's value
+            i += 2;
+        }
+        found
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn argv(args: &[&str]) -> Vec<OsString> {
+        args.iter().map(OsString::from).collect()
+    }
+
+    #[test]
+    fn test_scan_global_args() {
+        // A global option's value that looks like a subcommand isn't one
+        assert_eq!(
+            GlobalArgs::scan(&argv(&[
+                "wt",
+                "-C",
+                "list",
+                "--config=c.toml",
+                "--color",
+                "never",
+                "foo",
+                "x"
+            ])),
+            GlobalArgs {
+                subcommand: Some(6),
+                directory: Some(PathBuf::from("list")),
+                config: Some(PathBuf::from("c.toml")),
+                color: Some("never".into()),
+            }
+        );
+        assert_eq!(
+            GlobalArgs::scan(&argv(&["wt", "-Crepo", "-v", "foo"])),
+            GlobalArgs {
+                subcommand: Some(3),
+                directory: Some(PathBuf::from("repo")),
+                ..Default::default()
+            }
+        );
+        // After the subcommand only --color counts; -C belongs to it
+        assert_eq!(
+            GlobalArgs::scan(&argv(&["wt", "list", "-C", "x", "--color=always"])),
+            GlobalArgs {
+                subcommand: Some(1),
+                color: Some("always".into()),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            GlobalArgs::scan(&argv(&["wt", "--", "--color", "always"])),
+            GlobalArgs::default()
+        );
+    }
+}
@@ -3,15 +3,23 @@
 //! Worktrunk is a CLI tool — see <https://worktrunk.dev> for documentation
 //! and the [README](https://github.com/max-sixty/worktrunk) for an overview.
 //!
-//! The library API is not stable. If you're building tooling that integrates
-//! with worktrunk, please [open an issue](https://github.com/max-sixty/worktrunk/issues)
-//! to discuss your use case.
+//! Tools that embed worktrunk should use [`ops`], which follows semver and
+//! returns typed results without printing. The other modules are not stable.
+//! If `ops` doesn't cover your use case, please
+//! [open an issue](https://github.com/max-sixty/worktrunk/issues) to discuss it.
 
 pub mod config;
+pub mod dry_run;
+pub mod forge;
 pub mod git;
+pub mod i18n;
+pub mod offline;
+pub mod ops;
 pub mod path;
+pub mod perf;
 pub mod shell;
 pub mod shell_exec;
+pub mod state;
 pub mod styling;
 pub mod sync;
 pub mod trace;
@@ -3,6 +3,7 @@ use shell_escape::escape;
 use std::borrow::Cow;
 use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Instant;
 use worktrunk::config::CommitGenerationConfig;
 use worktrunk::git::Repository;
 use worktrunk::path::format_path_for_display;
@@ -281,6 +282,72 @@ const DEFAULT_SQUASH_TEMPLATE: &str = r#"Combine these commits into a single com
 </diff>
 "#;
 
+/// Prompt for `wt resolve --explain`
+const CONFLICT_TEMPLATE: &str = r#"Explain the merge conflict below to the developer resolving it.
+
+<format>
+- What the upstream side changed, in one or two sentences
+- What the commit being applied changed, in one or two sentences
+- A suggested resolution: a short description, or the resolved code when it is short
+- Plain text, no headings or code fences
+</format>
+
+<conflict file="{{ path }}" upstream="{{ upstream }}" commit="{{ commit }}">
+Each hunk shows the upstream side, the common base (after |||||||), then the commit's side.
+
+{{ hunks }}
+</conflict>
+"#;
+
+/// Prompt for `wt step split` grouping suggestions
+const SPLIT_TEMPLATE: &str = r#"Split the uncommitted changes below into a series of reviewable commits, one per logical change.
+
+<format>
+- For each commit, a line with ## and the commit subject, followed by the files it contains, one path per line
+- Every file appears in exactly one commit; order commits so each builds on the previous ones
+- Subject lines under 50 chars, imperative mood, matching the recent commit style
+- Output only the commits, no other text
+</format>
+
+<files>
+{% for file in files %}{{ file }}
+{% endfor %}</files>
+
+<diffstat>
+{{ git_diff_stat }}
+</diffstat>
+
+<diff>
+{{ git_diff }}
+</diff>
+
+<context>
+Branch: {{ branch }}
+{% if recent_commits %}<recent_commits>
+{% for commit in recent_commits %}- {{ commit }}
+{% endfor %}</recent_commits>{% endif %}
+</context>
+"#;
+
+/// Prompt for `wt switch --create --describe`
+const BRANCH_NAME_TEMPLATE: &str = r#"Name a git branch for the work described below.
+
+<format>
+- Output only the branch name, no quotes or other text
+- Lowercase words joined with -, under 50 chars
+{% if patterns %}- Match one of these patterns, where * stands for any text: {{ patterns | join(", ") }}
+{% endif %}- Follow the style of the recent branches
+</format>
+
+<description>
+{{ description }}
+</description>
+{% if recent_branches %}
+<recent_branches>
+{% for branch in recent_branches %}- {{ branch }}
+{% endfor %}</recent_branches>
+{% endif %}"#;
+
 /// Execute an LLM command with the given prompt via stdin.
 ///
 /// The command is a shell string executed via the platform shell (sh on Unix,
@@ -290,6 +357,10 @@ const DEFAULT_SQUASH_TEMPLATE: &str = r#"Combine these commits into a single com
 /// This is the canonical way to execute LLM commands in this codebase.
 /// All LLM execution should go through this function to maintain consistency.
 fn execute_llm_command(command: &str, prompt: &str) -> anyhow::Result<String> {
+    if worktrunk::offline::is_offline() {
+        anyhow::bail!("LLM command skipped (--offline)");
+    }
+
     // Log prompt for debugging (Cmd logs the command itself)
     log::debug!("  Prompt (stdin):");
     for line in prompt.lines() {
@@ -303,6 +374,7 @@ fn execute_llm_command(command: &str, prompt: &str) -> anyhow::Result<String> {
     // it, replace with a deprecation warning + config.new migration to have users
     // add `CLAUDECODE=` to their command string themselves.
     // https://github.com/anthropics/claude-code/issues/25803
+    let started = Instant::now();
     let output = Cmd::new(shell.executable.to_string_lossy())
         .args(&shell.args)
         .arg(command)
@@ -310,6 +382,17 @@ fn execute_llm_command(command: &str, prompt: &str) -> anyhow::Result<String> {
         .env_remove("CLAUDECODE")
         .run()
         .context("Failed to spawn LLM command")?;
+    worktrunk::perf::record(
+        worktrunk::perf::SpanKind::Phase,
+        "commit generation",
+        started.elapsed(),
+    );
+    log::debug!(
+        "LLM command finished in {}ms (ok={}, prompt {} bytes)",
+        started.elapsed().as_millis(),
+        output.status.success(),
+        prompt.len()
+    );
 
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
@@ -463,8 +546,9 @@ fn build_prompt(
 pub(crate) fn generate_commit_message(
     commit_generation_config: &CommitGenerationConfig,
 ) -> anyhow::Result<String> {
-    // Check if commit generation is configured (non-empty command)
-    if commit_generation_config.is_configured() {
+    // Check if commit generation is configured (non-empty command); offline,
+    // the fallback below stands in for the LLM
+    if commit_generation_config.is_configured() && !worktrunk::offline::is_offline() {
         let command = commit_generation_config.command.as_ref().unwrap();
         // Commit generation is explicitly configured - fail if it doesn't work
         return try_generate_commit_message(command, commit_generation_config).map_err(|e| {
@@ -573,8 +657,9 @@ pub(crate) fn generate_squash_message(
     repo_name: &str,
     commit_generation_config: &CommitGenerationConfig,
 ) -> anyhow::Result<String> {
-    // Check if commit generation is configured (non-empty command)
-    if commit_generation_config.is_configured() {
+    // Check if commit generation is configured (non-empty command); offline,
+    // the fallback below stands in for the LLM
+    if commit_generation_config.is_configured() && !worktrunk::offline::is_offline() {
         let command = commit_generation_config.command.as_ref().unwrap();
 
         let prompt = build_squash_prompt(
@@ -599,7 +684,7 @@ pub(crate) fn generate_squash_message(
         });
     }
 
-    // Fallback: deterministic commit message (only when not configured)
+    // Fallback: deterministic commit message (when not configured, or offline)
     let mut commit_message = format!("Squash commits from {}\n\n", target_branch);
     commit_message.push_str("Combined commits:\n");
     for subject in subjects.iter().rev() {
@@ -713,6 +798,148 @@ pub(crate) fn test_commit_generation(
     })
 }
 
+/// Ask the commit generation command how to split the uncommitted changes.
+///
+/// Returns the raw suggestion: `## <subject>` lines, each followed by paths.
+pub(crate) fn suggest_split(
+    commit_generation_config: &CommitGenerationConfig,
+    repo: &Repository,
+    files: &[String],
+    branch: &str,
+) -> anyhow::Result<String> {
+    let Some(command) = commit_generation_config
+        .is_configured()
+        .then_some(commit_generation_config.command.as_ref())
+        .flatten()
+    else {
+        anyhow::bail!("Commit generation is not configured");
+    };
+
+    let diff = repo.run_command(&[
+        "-c",
+        "diff.noprefix=false",
+        "-c",
+        "diff.mnemonicPrefix=false",
+        "--no-pager",
+        "diff",
+        "HEAD",
+    ])?;
+    let stat = repo.run_command(&["--no-pager", "diff", "HEAD", "--stat"])?;
+    let prepared = prepare_diff(diff, stat);
+    let recent_commits = repo.recent_commit_subjects(None, 5);
+
+    let env = Environment::new();
+    let prompt = env
+        .template_from_str(SPLIT_TEMPLATE)?
+        .render(minijinja::context! {
+            files => files,
+            git_diff => prepared.diff,
+            git_diff_stat => prepared.stat,
+            branch => branch,
+            recent_commits => recent_commits.unwrap_or_default(),
+        })?;
+
+    execute_llm_command(command, &prompt).map_err(|e| {
+        worktrunk::git::GitError::LlmCommandFailed {
+            command: command.clone(),
+            error: e.to_string(),
+            reproduction_command: None,
+        }
+        .into()
+    })
+}
+
+/// Ask the commit generation command to explain a conflict.
+///
+/// `hunks` are the conflicted regions in diff3 style (upstream, base, commit).
+/// The explanation is only printed; nothing is applied.
+pub(crate) fn explain_conflict(
+    commit_generation_config: &CommitGenerationConfig,
+    path: &str,
+    upstream: &str,
+    commit: &str,
+    hunks: &str,
+) -> anyhow::Result<String> {
+    let Some(command) = commit_generation_config
+        .is_configured()
+        .then_some(commit_generation_config.command.as_ref())
+        .flatten()
+    else {
+        anyhow::bail!(
+            "Conflict explanations use the commit generation command. Add [commit.generation] to the config."
+        );
+    };
+
+    let env = Environment::new();
+    let prompt = env
+        .template_from_str(CONFLICT_TEMPLATE)?
+        .render(minijinja::context! {
+            path => path,
+            upstream => upstream,
+            commit => commit,
+            hunks => hunks,
+        })?;
+
+    execute_llm_command(command, &prompt).map_err(|e| {
+        worktrunk::git::GitError::LlmCommandFailed {
+            command: command.clone(),
+            error: e.to_string(),
+            reproduction_command: None,
+        }
+        .into()
+    })
+}
+
+/// Ask the commit generation command for a branch name for `description`.
+///
+/// `patterns` are the project's `[branches] naming` patterns and
+/// `recent_branches` show the repository's style. Returns the first line of
+/// the answer with surrounding quotes or backticks removed.
+pub(crate) fn suggest_branch_name(
+    commit_generation_config: &CommitGenerationConfig,
+    description: &str,
+    patterns: &[String],
+    recent_branches: &[String],
+) -> anyhow::Result<String> {
+    let Some(command) = commit_generation_config
+        .is_configured()
+        .then_some(commit_generation_config.command.as_ref())
+        .flatten()
+    else {
+        anyhow::bail!(
+            "Branch names from descriptions use the commit generation command. Add [commit.generation] to the config."
+        );
+    };
+
+    let env = Environment::new();
+    let prompt = env
+        .template_from_str(BRANCH_NAME_TEMPLATE)?
+        .render(minijinja::context! {
+            description => description,
+            patterns => patterns,
+            recent_branches => recent_branches,
+        })?;
+
+    let output = execute_llm_command(command, &prompt).map_err(|e| {
+        anyhow::Error::from(worktrunk::git::GitError::LlmCommandFailed {
+            command: command.clone(),
+            error: e.to_string(),
+            reproduction_command: None,
+        })
+    })?;
+    parse_branch_name(&output)
+        .with_context(|| format!("LLM returned no branch name: {}", output.trim()))
+}
+
+/// The branch name in an LLM answer: its first non-empty line, unquoted.
+fn parse_branch_name(output: &str) -> Option<String> {
+    output
+        .lines()
+        .map(|line| line.trim().trim_matches(['`', '"', '\'']).trim())
+        .find(|line| !line.is_empty())
+        .map(str::to_string)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1416,4 +1643,17 @@ diff --git a/Cargo.lock b/Cargo.lock
         assert!(!is_lock_file("README.md"));
         assert!(!is_lock_file("config.toml"));
     }
+
+    #[test]
+    fn test_parse_branch_name() {
+        assert_eq!(
+            parse_branch_name("fix/flaky-login-test\n").as_deref(),
+            Some("fix/flaky-login-test")
+        );
+        assert_eq!(
+            parse_branch_name("\n`feature/dark-mode`\nExplanation").as_deref(),
+            Some("feature/dark-mode")
+        );
+        assert_eq!(parse_branch_name("  \n\"\"\n"), None);
+    }
 }
@@ -0,0 +1,159 @@
+//! Log filtering by area and JSON log file output.
+//!
+//! `WORKTRUNK_LOG` takes env_logger's filter syntax, with short area names in
+//! place of module paths: `WORKTRUNK_LOG=git=debug,hooks=debug`. Unknown names
+//! pass through unchanged, so full module paths still work.
+//!
+//! `WORKTRUNK_LOG_FILE` sends records to a file as JSON lines instead of
+//! stderr — useful for diagnosing hook failures and slow operations without
+//! mixing logs into command output. Without `WORKTRUNK_LOG`, the file gets
+//! debug records.
+
+use std::fs::{File, OpenOptions};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Log filter area names and the module paths they cover.
+///
+/// Binary modules are prefixed with the crate name at runtime (`wt` or `git_wt`).
+const AREAS: &[(&str, &[&str])] = &[
+    ("git", &["worktrunk::git", "worktrunk::shell_exec"]),
+    ("config", &["worktrunk::config"]),
+    (
+        "hooks",
+        &[
+            "{bin}::commands::hooks",
+            "{bin}::commands::command_executor",
+            "{bin}::commands::process",
+        ],
+    ),
+    ("llm", &["{bin}::llm", "{bin}::output::commit_generation"]),
+    (
+        "ci",
+        &["{bin}::commands::list::ci_status", "{bin}::commands::forge"],
+    ),
+    ("list", &["{bin}::commands::list"]),
+];
+
+/// Expand area names in a `WORKTRUNK_LOG` spec into env_logger module filters.
+///
+/// `git=debug,warn` becomes `worktrunk::git=debug,worktrunk::shell_exec=debug,warn`.
+/// A trailing `/regex` message filter is kept as is.
+pub(crate) fn expand_filter_spec(spec: &str) -> String {
+    let (directives, message_filter) = match spec.split_once('/') {
+        Some((directives, filter)) => (directives, Some(filter)),
+        None => (spec, None),
+    };
+    let bin = env!("CARGO_CRATE_NAME");
+
+    let expanded: Vec<String> = directives
+        .split(',')
+        .map(str::trim)
+        .filter(|d| !d.is_empty())
+        .flat_map(|directive| {
+            let (name, level) = match directive.split_once('=') {
+                Some((name, level)) => (name, Some(level)),
+                None => (directive, None),
+            };
+            match AREAS.iter().find(|(area, _)| *area == name) {
+                Some((_, modules)) => modules
+                    .iter()
+                    .map(|module| {
+                        let module = module.replace("{bin}", bin);
+                        match level {
+                            Some(level) => format!("{module}={level}"),
+                            None => module,
+                        }
+                    })
+                    .collect(),
+                None => vec![directive.to_string()],
+            }
+        })
+        .collect();
+
+    let mut spec = expanded.join(",");
+    if let Some(filter) = message_filter {
+        spec.push('/');
+        spec.push_str(filter);
+    }
+    spec
+}
+
+/// Open the `WORKTRUNK_LOG_FILE` target for appending, if set.
+///
+/// An unwritable path is reported once and logging falls back to stderr.
+pub(crate) fn json_log_file() -> Option<File> {
+    let path = std::env::var_os("WORKTRUNK_LOG_FILE")?;
+    match OpenOptions::new().create(true).append(true).open(&path) {
+        Ok(file) => Some(file),
+        Err(e) => {
+            worktrunk::styling::eprintln!(
+                "{}",
+                worktrunk::styling::warning_message(format!(
+                    "Cannot open WORKTRUNK_LOG_FILE {}: {e}",
+                    path.to_string_lossy()
+                ))
+            );
+            None
+        }
+    }
+}
+
+/// Serialize a log record as one JSON line.
+pub(crate) fn json_line(record: &log::Record<'_>) -> String {
+    let ts_ms = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or_default();
+    let thread = std::thread::current();
+    serde_json::json!({
+        "ts_ms": ts_ms,
+        "level": record.level().as_str(),
+        "target": record.target(),
+        "thread": thread.name().map(str::to_string).unwrap_or_else(|| format!("{:?}", thread.id())),
+        "message": record.args().to_string(),
+    })
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_filter_spec() {
+        assert_eq!(
+            expand_filter_spec("git=debug"),
+            "worktrunk::git=debug,worktrunk::shell_exec=debug"
+        );
+        assert_eq!(expand_filter_spec("config,warn"), "worktrunk::config,warn");
+        assert_eq!(
+            expand_filter_spec("llm=trace"),
+            format!(
+                "{bin}::llm=trace,{bin}::output::commit_generation=trace",
+                bin = env!("CARGO_CRATE_NAME")
+            )
+        );
+        // Unknown names and message filters pass through
+        assert_eq!(
+            expand_filter_spec("worktrunk::sync=debug/wt-trace"),
+            "worktrunk::sync=debug/wt-trace"
+        );
+        assert_eq!(expand_filter_spec(""), "");
+    }
+
+    #[test]
+    fn test_json_line() {
+        let line = json_line(
+            &log::Record::builder()
+                .args(format_args!("$ git status"))
+                .level(log::Level::Debug)
+                .target("worktrunk::shell_exec")
+                .build(),
+        );
+        let value: serde_json::Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(value["level"], "DEBUG");
+        assert_eq!(value["target"], "worktrunk::shell_exec");
+        assert_eq!(value["message"], "$ git status");
+        assert!(value["ts_ms"].as_u64().unwrap() > 0);
+    }
+}
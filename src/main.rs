@@ -6,8 +6,12 @@ use clap::FromArgMatches;
 use clap::error::ErrorKind as ClapErrorKind;
 use color_print::{ceprintln, cformat};
 use std::process;
-use worktrunk::config::{UserConfig, set_config_path};
-use worktrunk::git::{Repository, ResolvedWorktree, exit_code, set_base_path};
+use worktrunk::config::{
+    DisplayConfig, NetworkConfig, ThemeConfig, UiConfig, UserConfig, set_config_path,
+};
+use worktrunk::git::{
+    GitCommandError, Repository, ResolvedWorktree, error_json, exit_code, exit_codes, set_base_path,
+};
 use worktrunk::path::format_path_for_display;
 use worktrunk::shell::extract_filename_from_path;
 use worktrunk::styling::{
@@ -20,6 +24,7 @@ use commands::context::CommandEnv;
 use commands::list::progressive::RenderMode;
 use commands::worktree::RemoveResult;
 
+mod alias;
 mod cli;
 mod commands;
 mod completion;
@@ -29,6 +34,7 @@ mod help;
 pub(crate) mod help_pager;
 mod invocation;
 mod llm;
+mod logging;
 mod md_help;
 mod output;
 mod pager;
@@ -36,42 +42,63 @@ mod verbose_log;
 
 // Re-export invocation utilities at crate level for use by other modules
 pub(crate) use invocation::{
-    binary_name, invocation_path, is_git_subcommand, was_invoked_with_explicit_path,
+    GlobalArgs, binary_name, invocation_path, is_git_subcommand, was_invoked_with_explicit_path,
 };
 
 pub(crate) use crate::cli::OutputFormat;
 
+#[cfg(unix)]
+use commands::daemon::handle_daemon;
 #[cfg(unix)]
 use commands::handle_select;
-use commands::worktree::handle_push;
+use commands::worktree::{handle_push, handle_push_remote};
 use commands::{
-    MergeOptions, OperationMode, RebaseResult, SquashResult, SwitchOptions, add_approvals,
-    clear_approvals, handle_completions, handle_config_create, handle_config_show,
-    handle_configure_shell, handle_hints_clear, handle_hints_get, handle_hook_show, handle_init,
-    handle_list, handle_logs_get, handle_merge, handle_rebase, handle_remove,
-    handle_remove_current, handle_show_theme, handle_squash, handle_state_clear,
-    handle_state_clear_all, handle_state_get, handle_state_set, handle_state_show, handle_switch,
-    handle_unconfigure_shell, resolve_worktree_arg, run_hook, step_commit, step_copy_ignored,
-    step_for_each, step_relocate,
+    AbsorbOptions, AgentOptions, BackportOptions, DoneOptions, MergeOptions, OperationMode,
+    RebaseResult, ResolveOptions, ReviewOptions, SquashResult, StartOptions, SwitchOptions,
+    add_approvals, branch_from_description, branch_from_issue, clear_approvals, handle_absorb,
+    handle_activity, handle_agent, handle_auth_login, handle_auth_logout, handle_auth_status,
+    handle_backport, handle_bench, handle_ci, handle_ci_rerun, handle_completions,
+    handle_config_create, handle_config_edit, handle_config_show, handle_configure_shell,
+    handle_doctor, handle_done, handle_export, handle_gc, handle_graph, handle_group_add,
+    handle_group_list, handle_group_remove, handle_hints_clear, handle_hints_get,
+    handle_hook_check, handle_hook_logs, handle_hook_show, handle_import, handle_init,
+    handle_init_layout, handle_jump, handle_list, handle_list_all_repos, handle_list_stacks,
+    handle_lock, handle_logs_get, handle_mcp, handle_merge, handle_perf_report, handle_prompt,
+    handle_rebase, handle_recent, handle_remove, handle_remove_current, handle_resolve,
+    handle_review, handle_review_done, handle_show_theme, handle_snapshot, handle_snapshot_diff,
+    handle_snapshot_list, handle_snapshot_restore, handle_squash, handle_stack_add,
+    handle_stack_remove, handle_stack_submit, handle_start, handle_state_clear,
+    handle_state_clear_all, handle_state_get, handle_state_set, handle_state_show,
+    handle_state_store, handle_switch, handle_sync, handle_tmux, handle_tmux_sync,
+    handle_unconfigure_shell, handle_undo, handle_unlock, resolve_worktree_arg, run_hook,
+    step_commit, step_copy_ignored, step_db_create, step_db_drop, step_for_each, step_relocate,
+    step_split,
 };
 use output::handle_remove_output;
 
 use cli::{
-    ApprovalsCommand, CiStatusAction, Cli, Commands, ConfigCommand, ConfigShellCommand,
-    DefaultBranchAction, HintsAction, HookCommand, ListSubcommand, LogsAction, MarkerAction,
-    PreviousBranchAction, StateCommand, StepCommand,
+    ApprovalsCommand, AuthCommand, CiCommand, CiStatusAction, Cli, Commands, ConfigCommand,
+    ConfigShellCommand, DefaultBranchAction, GroupCommand, HintsAction, HookCommand,
+    ListSubcommand, LogsAction, MarkerAction, PerfCommand, PreviousBranchAction, ReviewCommand,
+    SnapshotCommand, StackCommand, StateCommand, StepCommand, TmuxCommand,
 };
 use worktrunk::HookType;
 
 /// Enhance clap errors with command-specific hints, then exit.
 ///
-/// For unrecognized subcommands that match nested commands, suggests the full path.
+/// Unrecognized subcommands run a matching `wt-<name>` plugin if there is one;
+/// if they match nested commands, suggests the full path.
 fn enhance_and_exit_error(err: clap::Error) -> ! {
     // For unrecognized subcommands, check if they match a nested subcommand
     // e.g., `wt squash` -> suggest `wt step squash`
     if err.kind() == ClapErrorKind::InvalidSubcommand
         && let Some(unknown) = err.get(clap::error::ContextKind::InvalidSubcommand)
     {
+        // `wt foo` runs a `wt-foo` plugin from PATH, like git
+        if let Some(code) = commands::plugin::run_plugin(&unknown.to_string()) {
+            process::exit(code);
+        }
+
         let cmd = cli::build_command();
         if let Some(suggestion) = cli::suggest_nested_subcommand(&cmd, &unknown.to_string()) {
             ceprintln!(
@@ -89,6 +116,46 @@ fn enhance_and_exit_error(err: clap::Error) -> ! {
     err.exit()
 }
 
+/// Subcommand path for the timing log, e.g. `step commit`.
+fn subcommand_path(matches: &clap::ArgMatches) -> String {
+    let mut names = Vec::new();
+    let mut matches = matches;
+    while let Some((name, sub)) = matches.subcommand() {
+        names.push(name);
+        matches = sub;
+    }
+    names.join(" ")
+}
+
+/// Whether `command` asked for JSON output, so errors should be JSON too.
+fn json_errors(command: &Commands) -> bool {
+    match command {
+        Commands::List {
+            subcommand: Some(ListSubcommand::Statusline { format, .. }),
+            ..
+        }
+        | Commands::List { format, .. } => matches!(format, OutputFormat::Json),
+        _ => false,
+    }
+}
+
+/// Error for a batch whose failures were already printed, exiting with the
+/// first failure's class so scripts see the same code as a single target.
+fn already_displayed(errors: &[anyhow::Error]) -> anyhow::Error {
+    let exit_code = errors
+        .first()
+        .and_then(exit_code)
+        .unwrap_or(exit_codes::FAILURE);
+    worktrunk::git::WorktrunkError::AlreadyDisplayed { exit_code }.into()
+}
+
+/// `--color` from the raw arguments, for output printed before clap parses them.
+fn color_from_args() -> Option<cli::ColorWhen> {
+    let argv: Vec<_> = std::env::args_os().collect();
+    let value = GlobalArgs::scan(&argv).color?;
+    clap::ValueEnum::from_str(&value, true).ok()
+}
+
 fn main() {
     // Configure Rayon's global thread pool for mixed I/O workloads.
     // The `wt list` command runs git operations (CPU + disk I/O) and network
@@ -114,6 +181,11 @@ fn main() {
         return;
     }
 
+    // Apply --color before help or parse errors are printed
+    if let Some(color) = color_from_args() {
+        worktrunk::styling::set_color_choice(color.choice());
+    }
+
     // Handle --help with pager before clap processes it
     if help::maybe_handle_help_with_pager() {
         return;
@@ -124,11 +196,23 @@ fn main() {
     // Clap doesn't support this natively yet - see https://github.com/clap-rs/clap/issues/3320
     // When available, use built-in setting. Until then, could use try_parse() to intercept
     // MissingRequiredArgument errors and print custom messages with ValueEnum::value_variants().
+    let aliases = alias::load();
+    let argv = alias::expand(
+        std::env::args_os().collect(),
+        &aliases,
+        &cli::build_command(),
+    )
+    .unwrap_or_else(|e| {
+        eprintln!("{}", error_message(format!("{e:#}")));
+        process::exit(exit_codes::FAILURE);
+    });
     let cmd = cli::build_command();
-    let matches = cmd.try_get_matches().unwrap_or_else(|e| {
+    let matches = cmd.try_get_matches_from(argv).unwrap_or_else(|e| {
         enhance_and_exit_error(e);
     });
     let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+    // Again after parsing, in case an alias supplied it
+    worktrunk::styling::set_color_choice(cli.color.choice());
 
     // Initialize base path from -C flag if provided
     if let Some(path) = cli.directory {
@@ -148,83 +232,159 @@ fn main() {
 
     // Capture verbose level and command line before cli is partially consumed
     let verbose_level = cli.verbose;
+    let dry_run = cli.dry_run;
     let command_line = std::env::args().collect::<Vec<_>>().join(" ");
 
+    // Opt-in timing log, except for long-running commands, benchmarks and its own report
+    let perf_command = subcommand_path(&matches);
+    if !matches!(
+        perf_command.split(' ').next(),
+        Some("" | "bench" | "daemon" | "mcp" | "perf")
+    ) {
+        worktrunk::perf::init();
+    }
+
     // Set global verbosity level for styled verbose output
     output::set_verbosity(verbose_level);
+    output::set_quiet(cli.quiet);
+    worktrunk::dry_run::set_dry_run(dry_run);
+    output::prompt::set_input_mode(
+        cli.no_input,
+        std::env::var("WT_ASSUME_YES").is_ok_and(|v| !matches!(v.as_str(), "" | "0" | "false")),
+    );
+
+    // Apply display settings before any status output
+    let ui_config = UserConfig::load_section::<UiConfig>("ui").unwrap_or_default();
+    worktrunk::styling::set_ascii_symbols(cli.no_emoji || !ui_config.emoji());
+    worktrunk::styling::set_hyperlinks(ui_config.hyperlinks());
+    pager::set_paging(!cli.no_pager && ui_config.pager());
+    if let Some(lang) = &ui_config.lang {
+        worktrunk::i18n::set_lang(lang);
+    }
+    let network_config = UserConfig::load_section::<NetworkConfig>("network").unwrap_or_default();
+    worktrunk::git::set_remote_attempts(network_config.attempts());
+    worktrunk::offline::set_offline(cli.offline || network_config.offline());
+    if let Some(style) =
+        UserConfig::load_section::<DisplayConfig>("display").and_then(|display| display.paths)
+    {
+        // Relative paths start from the main worktree, like `wt list`
+        let base = Repository::current()
+            .and_then(|repo| repo.home_path())
+            .unwrap_or_else(|_| std::env::current_dir().unwrap_or_default());
+        worktrunk::path::set_path_style(style, base);
+    }
+    if let Some(theme_config) = UserConfig::load_section::<ThemeConfig>("theme") {
+        let (theme, errors) = theme_config.to_theme();
+        worktrunk::styling::set_theme(theme);
+        for error in errors {
+            eprintln!(
+                "{}",
+                warning_message(format!("Ignoring invalid [theme] {error}"))
+            );
+        }
+    }
 
     // -vv enables debug logging via env_logger; -v uses styled output (not logging)
-    // Otherwise, respect RUST_LOG (defaulting to off)
+    // Otherwise, respect WORKTRUNK_LOG (area filters), then RUST_LOG (defaulting to off)
+    // -vv keeps stderr output so verbose.log and the diagnostic report still work
+    let json_log = (cli.verbose < 2).then(logging::json_log_file).flatten();
     let mut builder = if cli.verbose >= 2 {
         let mut b = env_logger::Builder::new();
         b.filter_level(log::LevelFilter::Debug);
         b
+    } else if let Ok(spec) = std::env::var("WORKTRUNK_LOG") {
+        let mut b = env_logger::Builder::new();
+        b.parse_filters(&logging::expand_filter_spec(&spec));
+        b
+    } else if json_log.is_some() {
+        let mut b = env_logger::Builder::new();
+        b.filter_level(log::LevelFilter::Debug);
+        b
     } else {
         env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("off"))
     };
 
-    builder
-        .format(|buf, record| {
-            let msg = record.args().to_string();
-
-            // Map thread ID to a single character (a-z, then A-Z)
-            let thread_id = format!("{:?}", std::thread::current().id());
-            let thread_num = thread_id
-                .strip_prefix("ThreadId(")
-                .and_then(|s| s.strip_suffix(")"))
-                .and_then(|s| s.parse::<usize>().ok())
-                .map(|n| {
-                    if n == 0 {
-                        '0'
-                    } else if n <= 26 {
-                        char::from(b'a' + (n - 1) as u8)
-                    } else if n <= 52 {
-                        char::from(b'A' + (n - 27) as u8)
+    // WORKTRUNK_LOG_FILE: JSON lines to the file, nothing on stderr
+    if let Some(file) = json_log {
+        builder
+            .format(|buf, record| writeln!(buf, "{}", logging::json_line(record)))
+            .target(env_logger::Target::Pipe(Box::new(file)))
+            .init();
+    } else {
+        builder
+            .format(|buf, record| {
+                let msg = record.args().to_string();
+
+                // Map thread ID to a single character (a-z, then A-Z)
+                let thread_id = format!("{:?}", std::thread::current().id());
+                let thread_num = thread_id
+                    .strip_prefix("ThreadId(")
+                    .and_then(|s| s.strip_suffix(")"))
+                    .and_then(|s| s.parse::<usize>().ok())
+                    .map(|n| {
+                        if n == 0 {
+                            '0'
+                        } else if n <= 26 {
+                            char::from(b'a' + (n - 1) as u8)
+                        } else if n <= 52 {
+                            char::from(b'A' + (n - 27) as u8)
+                        } else {
+                            '?'
+                        }
+                    })
+                    .unwrap_or('?');
+
+                // Write plain text to log file (no ANSI codes)
+                verbose_log::write_line(&format!("[{thread_num}] {msg}"));
+
+                // Commands start with $, make only the command bold (not $ or [worktree])
+                if let Some(rest) = msg.strip_prefix("$ ") {
+                    // Split: "git command [worktree]" -> ("git command", " [worktree]")
+                    if let Some(bracket_pos) = rest.find(" [") {
+                        let command = &rest[..bracket_pos];
+                        let worktree = &rest[bracket_pos..];
+                        writeln!(
+                            buf,
+                            "{}",
+                            cformat!("<dim>[{thread_num}]</> $ <bold>{command}</>{worktree}")
+                        )
                     } else {
-                        '?'
+                        writeln!(
+                            buf,
+                            "{}",
+                            cformat!("<dim>[{thread_num}]</> $ <bold>{rest}</>")
+                        )
                     }
-                })
-                .unwrap_or('?');
-
-            // Write plain text to log file (no ANSI codes)
-            verbose_log::write_line(&format!("[{thread_num}] {msg}"));
-
-            // Commands start with $, make only the command bold (not $ or [worktree])
-            if let Some(rest) = msg.strip_prefix("$ ") {
-                // Split: "git command [worktree]" -> ("git command", " [worktree]")
-                if let Some(bracket_pos) = rest.find(" [") {
-                    let command = &rest[..bracket_pos];
-                    let worktree = &rest[bracket_pos..];
-                    writeln!(
-                        buf,
-                        "{}",
-                        cformat!("<dim>[{thread_num}]</> $ <bold>{command}</>{worktree}")
-                    )
+                } else if msg.starts_with("  ! ") {
+                    // Error output - show in red
+                    writeln!(buf, "{}", cformat!("<dim>[{thread_num}]</> <red>{msg}</>"))
                 } else {
-                    writeln!(
-                        buf,
-                        "{}",
-                        cformat!("<dim>[{thread_num}]</> $ <bold>{rest}</>")
-                    )
+                    // Regular output with thread ID
+                    writeln!(buf, "{}", cformat!("<dim>[{thread_num}]</> {msg}"))
                 }
-            } else if msg.starts_with("  ! ") {
-                // Error output - show in red
-                writeln!(buf, "{}", cformat!("<dim>[{thread_num}]</> <red>{msg}</>"))
-            } else {
-                // Regular output with thread ID
-                writeln!(buf, "{}", cformat!("<dim>[{thread_num}]</> {msg}"))
-            }
-        })
-        .init();
+            })
+            .init();
+    }
 
     let Some(command) = cli.command else {
         // No subcommand provided - print help to stderr (stdout is eval'd by shell wrapper)
-        let mut cmd = cli::build_command();
+        let mut cmd = alias::with_aliases(cli::build_command(), &aliases);
         let help = cmd.render_help().ansi().to_string();
         eprintln!("{help}");
         return;
     };
 
+    // A running `wt daemon` answers read-only queries from its warm cache
+    #[cfg(unix)]
+    if verbose_level == 0
+        && !dry_run
+        && commands::daemon::serves(&command)
+        && let Some(code) = commands::daemon::query()
+    {
+        std::process::exit(code);
+    }
+
+    let json_errors = json_errors(&command);
     let result = match command {
         Commands::Config { action } => match action {
             ConfigCommand::Shell { action } => {
@@ -234,12 +394,7 @@ fn main() {
                         let cmd = cmd.unwrap_or_else(binary_name);
                         handle_init(shell, cmd).map_err(|e| anyhow::anyhow!("{}", e))
                     }
-                    ConfigShellCommand::Install {
-                        shell,
-                        yes,
-                        dry_run,
-                        cmd,
-                    } => {
+                    ConfigShellCommand::Install { shell, yes, cmd } => {
                         // Auto-write to shell config files and completions
                         let cmd = cmd.unwrap_or_else(binary_name);
                         handle_configure_shell(shell, yes, dry_run, cmd)
@@ -261,11 +416,7 @@ fn main() {
                                 crate::output::print_shell_install_result(&scan_result)
                             })
                     }
-                    ConfigShellCommand::Uninstall {
-                        shell,
-                        yes,
-                        dry_run,
-                    } => {
+                    ConfigShellCommand::Uninstall { shell, yes } => {
                         let explicit_shell = shell.is_some();
                         handle_unconfigure_shell(shell, yes, dry_run, &binary_name())
                             .map_err(|e| anyhow::anyhow!("{}", e))
@@ -429,6 +580,7 @@ fn main() {
                 }
             }
             ConfigCommand::Create { project } => handle_config_create(project),
+            ConfigCommand::Edit { project } => handle_config_edit(project),
             ConfigCommand::Show { full } => handle_config_show(full),
             ConfigCommand::State { action } => match action {
                 StateCommand::DefaultBranch { action } => match action {
@@ -522,7 +674,18 @@ fn main() {
                     })
                 }
             }
-            StepCommand::Push { target } => handle_push(target.as_deref(), "Pushed to", None),
+            StepCommand::Split { yes, patch } => step_split(yes, patch, dry_run),
+            StepCommand::Push {
+                remote: true,
+                verify,
+                ..
+            } => handle_push_remote(verify),
+            StepCommand::Push {
+                target,
+                verify,
+                force,
+                remote: false,
+            } => handle_push(target.as_deref(), "Pushed to", None, verify, force),
             StepCommand::Rebase { target } => {
                 handle_rebase(target.as_deref()).map(|result| match result {
                     RebaseResult::Rebased => (),
@@ -534,16 +697,14 @@ fn main() {
                     }
                 })
             }
-            StepCommand::CopyIgnored {
-                from,
-                to,
-                dry_run,
-                force,
-            } => step_copy_ignored(from.as_deref(), to.as_deref(), dry_run, force),
-            StepCommand::ForEach { args } => step_for_each(args),
+            StepCommand::CopyIgnored { from, to, force } => {
+                step_copy_ignored(from.as_deref(), to.as_deref(), dry_run, force)
+            }
+            StepCommand::DbCreate => step_db_create(),
+            StepCommand::DbDrop => step_db_drop(),
+            StepCommand::ForEach { group, args } => step_for_each(group.as_deref(), args),
             StepCommand::Relocate {
                 branches,
-                dry_run,
                 commit,
                 clobber,
             } => step_relocate(branches, dry_run, commit, clobber),
@@ -553,6 +714,7 @@ fn main() {
                 hook_type,
                 expanded,
             } => handle_hook_show(hook_type.as_deref(), expanded),
+            HookCommand::Check => handle_hook_check(),
             HookCommand::PostCreate { name, yes, vars } => {
                 run_hook(HookType::PostCreate, yes, None, name.as_deref(), &vars)
             }
@@ -622,6 +784,7 @@ fn main() {
                 name.as_deref(),
                 &vars,
             ),
+            HookCommand::Logs { last } => handle_hook_logs(last),
             HookCommand::Approvals { action } => match action {
                 ApprovalsCommand::Add { all } => add_approvals(all),
                 ApprovalsCommand::Clear { global } => clear_approvals(global),
@@ -675,6 +838,13 @@ fn main() {
             branches,
             remotes,
             full,
+            du,
+            health,
+            columns,
+            width,
+            group_by,
+            all_repos,
+            stacks,
             progressive,
             no_progressive,
         } => match subcommand {
@@ -691,6 +861,7 @@ fn main() {
                 };
                 commands::statusline::run(effective_format)
             }
+            None if stacks => handle_list_stacks(),
             None => {
                 // Load config and merge with CLI flags (CLI flags take precedence)
                 UserConfig::load()
@@ -706,6 +877,11 @@ fn main() {
                         let show_branches = branches || resolved.list.branches();
                         let show_remotes = remotes || resolved.list.remotes();
                         let show_full = full || resolved.list.full();
+                        let columns =
+                            columns.or_else(|| resolved.list.columns().map(<[_]>::to_vec));
+                        if let Some(width) = width {
+                            commands::list::layout::set_width_override(width);
+                        }
 
                         // Convert two bools to Option<bool>: Some(true), Some(false), or None
                         let progressive_opt = match (progressive, no_progressive) {
@@ -713,12 +889,35 @@ fn main() {
                             (_, true) => Some(false),
                             _ => None,
                         };
-                        let render_mode = RenderMode::detect(progressive_opt);
+                        // A row's group isn't known until its data is in
+                        let render_mode = if group_by.is_some() {
+                            RenderMode::Buffered
+                        } else {
+                            RenderMode::detect(progressive_opt)
+                        };
+                        if all_repos {
+                            return handle_list_all_repos(
+                                format,
+                                show_branches,
+                                show_remotes,
+                                show_full,
+                                du,
+                                health,
+                                columns.as_deref(),
+                                group_by,
+                                render_mode,
+                                &config,
+                            );
+                        }
                         handle_list(
                             format,
                             show_branches,
                             show_remotes,
                             show_full,
+                            du,
+                            health,
+                            columns.as_deref(),
+                            group_by,
                             render_mode,
                             &config,
                         )
@@ -731,8 +930,15 @@ fn main() {
             remotes,
             create,
             base,
+            from_issue,
+            describe,
+            sparse,
+            orphan,
+            devcontainer,
+            detach,
             execute,
             execute_args,
+            open,
             yes,
             clobber,
             no_cd,
@@ -740,6 +946,13 @@ fn main() {
         } => UserConfig::load()
             .context("Failed to load config")
             .and_then(|mut config| {
+                let branch = match (from_issue, describe) {
+                    (Some(id), _) => Some(branch_from_issue(&config, &id)?),
+                    (_, Some(description)) => {
+                        Some(branch_from_description(&config, &description, yes)?)
+                    }
+                    (None, None) => branch,
+                };
                 // No branch argument: open interactive picker
                 let Some(branch) = branch else {
                     #[cfg(unix)]
@@ -786,8 +999,13 @@ fn main() {
                         branch: &branch,
                         create,
                         base: base.as_deref(),
+                        sparse: sparse.as_deref(),
+                        orphan,
+                        devcontainer,
+                        detach,
                         execute: execute.as_deref(),
                         execute_args: &execute_args,
+                        open: open.as_ref().map(Option::as_deref),
                         yes,
                         clobber,
                         change_dir: !no_cd,
@@ -797,6 +1015,219 @@ fn main() {
                     &binary_name(),
                 )
             }),
+        Commands::Init { yes } => UserConfig::load()
+            .context("Failed to load config")
+            .and_then(|mut config| handle_init_layout(yes, &mut config, &binary_name())),
+        Commands::Jump { query } => UserConfig::load()
+            .context("Failed to load config")
+            .and_then(|mut config| handle_jump(&query, &mut config, &binary_name())),
+        Commands::Recent { limit } => handle_recent(limit),
+        Commands::Start {
+            target,
+            base,
+            skip,
+            draft_pr,
+            yes,
+            verify,
+        } => UserConfig::load()
+            .context("Failed to load config")
+            .and_then(|mut config| {
+                handle_start(
+                    StartOptions {
+                        target: &target,
+                        base: base.as_deref(),
+                        skip: &skip,
+                        draft_pr,
+                        yes,
+                        verify,
+                    },
+                    &mut config,
+                    &binary_name(),
+                )
+            }),
+        Commands::Agent {
+            task,
+            branch,
+            yes,
+            verify,
+        } => UserConfig::load()
+            .context("Failed to load config")
+            .and_then(|mut config| {
+                handle_agent(
+                    AgentOptions {
+                        task: task.as_deref(),
+                        branch: branch.as_deref(),
+                        yes,
+                        verify,
+                    },
+                    &mut config,
+                    &binary_name(),
+                )
+            }),
+        Commands::Auth { action } => match action {
+            AuthCommand::Status => handle_auth_status(),
+            AuthCommand::Login { forge } => handle_auth_login(forge),
+            AuthCommand::Logout { forge } => handle_auth_logout(forge),
+        },
+        Commands::Ci {
+            action,
+            branch,
+            logs,
+        } => match action {
+            Some(CiCommand::Rerun { branch }) => handle_ci_rerun(branch.as_deref()),
+            None => handle_ci(branch.as_deref(), logs.as_deref()),
+        },
+        Commands::Lock { branch, reason } => handle_lock(branch.as_deref(), reason.as_deref()),
+        Commands::Unlock { branch } => handle_unlock(branch.as_deref()),
+        Commands::Undo { list } => handle_undo(list),
+        Commands::Snapshot { action, message } => match action {
+            None => handle_snapshot(message.as_deref()),
+            Some(SnapshotCommand::List) => handle_snapshot_list(),
+            Some(SnapshotCommand::Restore { id }) => handle_snapshot_restore(id),
+            Some(SnapshotCommand::Diff { id }) => handle_snapshot_diff(id),
+        },
+        Commands::Export { branch, bundle } => handle_export(branch.as_deref(), &bundle),
+        Commands::Import { file, yes, verify } => UserConfig::load()
+            .context("Failed to load config")
+            .and_then(|mut config| handle_import(&file, yes, verify, &mut config, &binary_name())),
+        Commands::Review {
+            action,
+            number,
+            detach,
+            yes,
+            verify,
+        } => UserConfig::load()
+            .context("Failed to load config")
+            .and_then(|mut config| match (action, number) {
+                (Some(ReviewCommand::Done { yes, verify }), _) => {
+                    handle_review_done(yes, verify, &config)
+                }
+                (None, Some(number)) => handle_review(
+                    ReviewOptions {
+                        number,
+                        detach,
+                        yes,
+                        verify,
+                    },
+                    &mut config,
+                    &binary_name(),
+                ),
+                (None, None) => Err(worktrunk::git::GitError::Other {
+                    message: "Missing PR/MR number; usage: wt review <NUMBER>".into(),
+                }
+                .into()),
+            }),
+        Commands::Resolve {
+            list,
+            tool,
+            no_continue,
+            explain,
+        } => handle_resolve(ResolveOptions {
+            list,
+            tool: tool.as_deref(),
+            continue_rebase: !no_continue,
+            explain,
+        }),
+        Commands::Absorb { target, rebase } => handle_absorb(AbsorbOptions {
+            target: target.as_deref(),
+            rebase,
+            dry_run,
+        }),
+        Commands::Backport {
+            commits,
+            to,
+            pr,
+            yes,
+            verify,
+        } => UserConfig::load()
+            .context("Failed to load config")
+            .and_then(|mut config| {
+                handle_backport(
+                    BackportOptions {
+                        commits: &commits,
+                        to: &to,
+                        pr,
+                        yes,
+                        verify,
+                    },
+                    &mut config,
+                    &binary_name(),
+                )
+            }),
+        Commands::Doctor { worktrees } => handle_doctor(worktrees),
+        Commands::Perf { action } => match action {
+            PerfCommand::Report { limit } => handle_perf_report(limit),
+        },
+        Commands::RemoveDetached {
+            path,
+            delete_branch,
+            force,
+        } => commands::process::handle_remove_detached(&path, delete_branch.as_deref(), force),
+        Commands::State { path } => handle_state_store(path),
+        Commands::Bench {
+            worktrees,
+            iterations,
+            repo,
+            phase,
+        } => handle_bench(worktrees, iterations, repo, phase),
+        Commands::Prompt { format } => handle_prompt(format),
+        Commands::Group { action } => match action {
+            GroupCommand::Add { name, branches } => handle_group_add(&name, &branches),
+            GroupCommand::Remove { name, branches } => handle_group_remove(&name, &branches),
+            GroupCommand::List => handle_group_list(),
+        },
+        Commands::Stack { action } => match action {
+            StackCommand::Add { parent, branch } => handle_stack_add(&parent, branch.as_deref()),
+            StackCommand::Submit { branch, draft, yes } => {
+                handle_stack_submit(branch.as_deref(), draft, yes, dry_run)
+            }
+            StackCommand::Remove { branch } => handle_stack_remove(branch.as_deref()),
+        },
+        Commands::Sync => handle_sync(),
+        Commands::Graph { compact } => handle_graph(compact),
+        Commands::Activity { since } => handle_activity(&since),
+        Commands::Gc { drop } => handle_gc(drop),
+        Commands::Mcp => handle_mcp(),
+        #[cfg(unix)]
+        Commands::Daemon { no_watch } => handle_daemon(!no_watch),
+        #[cfg(not(unix))]
+        Commands::Daemon { .. } => Err(anyhow::anyhow!("wt daemon requires Unix domain sockets")),
+        Commands::Tmux { action, branch } => UserConfig::load()
+            .context("Failed to load config")
+            .and_then(|config| match action {
+                Some(TmuxCommand::Sync) => handle_tmux_sync(&config),
+                None => handle_tmux(branch.as_deref(), &config),
+            }),
+        Commands::Done {
+            target,
+            pr,
+            merge,
+            wait_ci,
+            no_remove,
+            yes,
+            verify,
+        } => UserConfig::load()
+            .context("Failed to load config")
+            .and_then(|config| {
+                let finish = if pr {
+                    Some(worktrunk::config::FinishMode::Pr)
+                } else if merge {
+                    Some(worktrunk::config::FinishMode::Merge)
+                } else {
+                    None
+                };
+                handle_done(
+                    DoneOptions {
+                        target: target.as_deref(),
+                        finish,
+                        wait_ci: wait_ci.then_some(true),
+                        remove: no_remove.then_some(false),
+                        yes,
+                        verify,
+                    },
+                    &config,
+                )
+            }),
         Commands::Remove {
             branches,
             delete_branch,
@@ -964,7 +1395,7 @@ fn main() {
                         || !plans_branch_only.is_empty()
                         || plan_current.is_some();
                     if !has_valid_plans {
-                        anyhow::bail!("");
+                        return Err(already_displayed(&all_errors));
                     }
 
                     // Early exit for benchmarking time-to-first-output
@@ -995,7 +1426,7 @@ fn main() {
 
                     // Exit with failure if any validation errors occurred
                     if !all_errors.is_empty() {
-                        anyhow::bail!("");
+                        return Err(already_displayed(&all_errors));
                     }
 
                     Ok(())
@@ -1040,6 +1471,11 @@ fn main() {
         }
     };
 
+    // Messages above describe the operation as if it ran
+    if dry_run && result.is_ok() {
+        eprintln!("{}", info_message("Dry run: no changes were made"));
+    }
+
     if let Err(e) = result {
         // GitError, WorktrunkError, and HookErrorWithHint produce styled output via Display
         if let Some(err) = e.downcast_ref::<worktrunk::git::GitError>() {
@@ -1080,8 +1516,33 @@ fn main() {
             }
         }
 
-        // Preserve exit code from child processes (especially for signals like SIGINT)
-        let code = exit_code(&e).unwrap_or(1);
+        // -v: the git command behind the message, exactly as it ran
+        if verbose_level >= 1
+            && let Some(source) = GitCommandError::find(&e)
+        {
+            let exit = source.exit_code.map_or_else(
+                || "killed by signal".to_string(),
+                |code| format!("exit code {code}"),
+            );
+            eprintln!(
+                "{}",
+                info_message(cformat!(
+                    "Failed: <bold>{}</> in {} <bright-black>({exit})</>",
+                    source.command(),
+                    format_path_for_display(&source.dir)
+                ))
+            );
+        }
+
+        // Machine-readable copy on stdout, where the JSON output would have gone
+        if json_errors {
+            println!("{}", serde_json::json!({ "error": error_json(&e) }));
+        }
+
+        // Class-specific exit code, or the child's own (especially for signals like SIGINT)
+        let code = exit_code(&e).unwrap_or(exit_codes::FAILURE);
+
+        worktrunk::perf::finish(&perf_command);
 
         // Write diagnostic if -vv was used (error case)
         diagnostic::write_if_verbose(verbose_level, &command_line, Some(&e.to_string()));
@@ -1091,6 +1552,8 @@ fn main() {
         process::exit(code);
     }
 
+    worktrunk::perf::finish(&perf_command);
+
     // Write diagnostic if -vv was used (success case)
     diagnostic::write_if_verbose(verbose_level, &command_line, None);
 
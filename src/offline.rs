@@ -0,0 +1,27 @@
+//! Global `--offline` mode.
+//!
+//! Like dry-run, offline mode is enforced where processes are spawned:
+//! commands built with [`Cmd::network`](crate::shell_exec::Cmd::network)
+//! (fetches, pushes, `ls-remote`, forge CLIs, `curl`) fail without running.
+//! Callers that have cached data to fall back on — CI status, the update
+//! check — check [`is_offline`] first and show what they have, marked stale
+//! when it's past its TTL.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static OFFLINE: AtomicBool = AtomicBool::new(false);
+
+/// Enable offline mode for the rest of the process.
+pub fn set_offline(enabled: bool) {
+    OFFLINE.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether `--offline` (or `[network] offline`) is active.
+pub fn is_offline() -> bool {
+    OFFLINE.load(Ordering::Relaxed)
+}
+
+/// Error returned for a network command that offline mode skipped.
+pub(crate) fn refused(program: &str) -> std::io::Error {
+    std::io::Error::other(format!("{program} needs the network; skipped (--offline)"))
+}
@@ -0,0 +1,116 @@
+//! Events for embedders that render worktrunk operations themselves.
+//!
+//! By default [`ops`](super) functions are silent and library warnings (an
+//! unknown config key, say) go to stderr like the CLI's. A GUI or editor
+//! plugin registers an [`EventHandler`] instead: it receives progress,
+//! success, hint, and warning events as plain text, and answers the questions
+//! the CLI would prompt for.
+
+use std::sync::{Arc, RwLock};
+
+use ansi_str::AnsiStr;
+
+/// Something worth telling the user about.
+///
+/// Messages are plain text; the CLI's styling is stripped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Event<'a> {
+    /// An operation started; more events follow
+    Progress(&'a str),
+    /// An operation finished
+    Success(&'a str),
+    Info(&'a str),
+    Hint(&'a str),
+    Warning(&'a str),
+}
+
+/// Receives events from worktrunk operations.
+pub trait EventHandler: Send + Sync {
+    fn event(&self, event: Event<'_>);
+
+    /// Answer a yes/no question the CLI would prompt for.
+    ///
+    /// The default declines, which keeps operations on the safe side.
+    fn confirm(&self, question: &str) -> bool {
+        let _ = question;
+        false
+    }
+}
+
+static HANDLER: RwLock<Option<Arc<dyn EventHandler>>> = RwLock::new(None);
+
+/// Send events to `handler` from now on, process-wide.
+pub fn set_event_handler(handler: Arc<dyn EventHandler>) {
+    *HANDLER.write().unwrap() = Some(handler);
+}
+
+/// Go back to silent operations and warnings on stderr.
+pub fn clear_event_handler() {
+    *HANDLER.write().unwrap() = None;
+}
+
+fn handler() -> Option<Arc<dyn EventHandler>> {
+    HANDLER.read().unwrap().clone()
+}
+
+/// Send an event to the registered handler; nothing happens without one.
+pub(crate) fn emit(event: Event<'_>) {
+    if let Some(handler) = handler() {
+        handler.event(event);
+    }
+}
+
+/// Ask the registered handler; `false` without one.
+pub(crate) fn confirm(question: &str) -> bool {
+    handler().is_some_and(|handler| handler.confirm(question))
+}
+
+/// Report a library warning: to the handler if one is registered, otherwise
+/// printed to stderr as `styled`.
+pub(crate) fn warn(styled: &str) {
+    match handler() {
+        Some(handler) => {
+            let plain = styled.ansi_strip();
+            // Drop the CLI's leading warning symbol
+            let plain = plain.trim_start_matches(|c: char| !c.is_alphanumeric());
+            handler.event(Event::Warning(plain));
+        }
+        None => crate::styling::eprintln!("{styled}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+
+    #[derive(Default)]
+    struct Recorder(Mutex<Vec<String>>);
+
+    impl EventHandler for Recorder {
+        fn event(&self, event: Event<'_>) {
+            self.0.lock().unwrap().push(format!("{event:?}"));
+        }
+    }
+
+    #[test]
+    fn test_handler_receives_plain_events() {
+        let recorder = Arc::new(Recorder::default());
+        set_event_handler(recorder.clone());
+        emit(Event::Progress("Creating worktree"));
+        warn(&crate::styling::warning_message("Config file not found").to_string());
+        assert!(!confirm("Remove anyway?"));
+        clear_event_handler();
+        emit(Event::Success("unseen"));
+
+        assert_eq!(
+            *recorder.0.lock().unwrap(),
+            [
+                r#"Progress("Creating worktree")"#,
+                r#"Warning("Config file not found")"#
+            ]
+        );
+    }
+}
@@ -0,0 +1,349 @@
+//! Worktree operations for tools that embed worktrunk.
+//!
+//! This is the library's stable surface: it follows semver, while the rest
+//! of the crate may change in any release. Functions return typed results
+//! and never print, prompt, or run hooks — approval prompts and output are
+//! the CLI's job, and an embedding tool decides for itself what to show,
+//! optionally from the [`Event`]s an [`EventHandler`] receives.
+//!
+//! ```no_run
+//! use worktrunk::config::UserConfig;
+//! use worktrunk::git::Repository;
+//! use worktrunk::ops;
+//!
+//! # fn main() -> anyhow::Result<()> {
+//! let repo = Repository::at("/path/to/repo")?;
+//! let config = UserConfig::load()?;
+//! let created = ops::create_worktree(&repo, &config, "feature", &ops::CreateOptions::default())?;
+//! for worktree in ops::list_worktrees(&repo)? {
+//!     println!("{} {}", worktree.path.display(), worktree.branch.unwrap_or_default());
+//! }
+//! ops::remove_worktree(&repo, &created.path, &ops::RemoveOptions::default())?;
+//! # Ok(())
+//! # }
+//! ```
+
+mod events;
+
+pub(crate) use events::warn;
+pub use events::{Event, EventHandler, clear_event_handler, set_event_handler};
+
+use std::path::{Path, PathBuf};
+
+use normalize_path::NormalizePath;
+
+use crate::config::UserConfig;
+use crate::git::{GitError, Repository, path_dir_name};
+use crate::path::format_path_for_display;
+
+/// A worktree of the repository.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[non_exhaustive]
+pub struct Worktree {
+    pub path: PathBuf,
+    /// `None` when HEAD is detached
+    pub branch: Option<String>,
+    /// Commit checked out
+    pub head: String,
+    /// Whether this is the main worktree (the one holding `.git`)
+    pub is_main: bool,
+    /// Reason given to `git worktree lock`, if locked
+    pub locked: Option<String>,
+}
+
+/// Options for [`create_worktree`].
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct CreateOptions {
+    /// Start point for a new branch; defaults to the current HEAD. Ignored
+    /// when the branch already exists.
+    pub base: Option<String>,
+    /// Where to put the worktree; defaults to the configured `worktree-path`
+    pub path: Option<PathBuf>,
+}
+
+impl CreateOptions {
+    pub fn base(mut self, base: impl Into<String>) -> Self {
+        self.base = Some(base.into());
+        self
+    }
+
+    pub fn path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+}
+
+/// What [`create_worktree`] did.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[non_exhaustive]
+pub struct Created {
+    pub path: PathBuf,
+    pub branch: String,
+    /// Whether the branch was created along with the worktree
+    pub created_branch: bool,
+}
+
+/// Options for [`remove_worktree`].
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct RemoveOptions {
+    /// Remove even with uncommitted changes or untracked files
+    pub force: bool,
+    /// Keep the branch instead of deleting it when it's merged
+    pub keep_branch: bool,
+}
+
+impl RemoveOptions {
+    pub fn force(mut self, force: bool) -> Self {
+        self.force = force;
+        self
+    }
+
+    pub fn keep_branch(mut self, keep_branch: bool) -> Self {
+        self.keep_branch = keep_branch;
+        self
+    }
+}
+
+/// What [`remove_worktree`] did.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[non_exhaustive]
+pub struct Removed {
+    pub path: PathBuf,
+    pub branch: Option<String>,
+    /// Whether the branch was deleted; git keeps unmerged branches
+    pub deleted_branch: bool,
+}
+
+/// Worktrees of the repository, main worktree first. Worktrees whose
+/// directory was deleted without `git worktree remove` are left out.
+pub fn list_worktrees(repo: &Repository) -> anyhow::Result<Vec<Worktree>> {
+    let infos = repo.list_worktrees()?;
+    let main = infos.first().map(|wt| wt.path.clone());
+    Ok(infos
+        .into_iter()
+        .filter(|wt| !wt.bare && !wt.is_prunable())
+        .map(|wt| Worktree {
+            is_main: main.as_ref() == Some(&wt.path) && !repo.is_bare(),
+            path: wt.path,
+            branch: wt.branch,
+            head: wt.head,
+            locked: wt.locked,
+        })
+        .collect())
+}
+
+/// State of one worktree, from [`worktree_status`].
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[non_exhaustive]
+pub struct WorktreeStatus {
+    #[serde(flatten)]
+    pub worktree: Worktree,
+    /// Uncommitted changes or untracked files
+    pub dirty: bool,
+    /// Commits ahead of the default branch; `None` for the default branch
+    /// itself, or when there's no default branch
+    pub ahead: Option<usize>,
+    /// Commits behind the default branch
+    pub behind: Option<usize>,
+}
+
+/// The state of `branch`'s worktree: uncommitted changes, and how far it has
+/// moved from the default branch.
+pub fn worktree_status(repo: &Repository, branch: &str) -> anyhow::Result<WorktreeStatus> {
+    let worktree = list_worktrees(repo)?
+        .into_iter()
+        .find(|wt| wt.branch.as_deref() == Some(branch))
+        .ok_or_else(|| GitError::Other {
+            message: format!("No worktree for branch {branch}"),
+        })?;
+    let dirty = repo.worktree_at(&worktree.path).is_dirty()?;
+    let (ahead, behind) = match repo.default_branch() {
+        Some(default_branch) if default_branch != branch => {
+            let (ahead, behind) = repo.ahead_behind(&default_branch, &worktree.head)?;
+            (Some(ahead), Some(behind))
+        }
+        _ => (None, None),
+    };
+    Ok(WorktreeStatus {
+        worktree,
+        dirty,
+        ahead,
+        behind,
+    })
+}
+
+/// Where the `worktree-path` template puts the worktree of `branch`.
+///
+/// The default branch lives in the main worktree, except in bare
+/// repositories, which have none.
+pub fn worktree_path(
+    repo: &Repository,
+    config: &UserConfig,
+    branch: &str,
+) -> anyhow::Result<PathBuf> {
+    let repo_root = repo.repo_path();
+    let default_branch = repo.default_branch().unwrap_or_default();
+    if !repo.is_bare() && branch == default_branch {
+        return Ok(repo_root.to_path_buf());
+    }
+
+    let repo_name = repo_root
+        .file_name()
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "Repository path has no filename: {}",
+                format_path_for_display(repo_root)
+            )
+        })?
+        .to_str()
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "Repository path contains invalid UTF-8: {}",
+                format_path_for_display(repo_root)
+            )
+        })?;
+
+    let project = repo.project_identifier().ok();
+    let expanded_path = config.format_path(repo_name, branch, repo, project.as_deref())?;
+
+    Ok(repo_root.join(expanded_path).normalize())
+}
+
+/// Create a worktree for `branch`, creating the branch if it doesn't exist.
+pub fn create_worktree(
+    repo: &Repository,
+    config: &UserConfig,
+    branch: &str,
+    options: &CreateOptions,
+) -> anyhow::Result<Created> {
+    if let Some(existing) = repo.worktree_for_branch(branch)? {
+        return Err(GitError::Other {
+            message: format!(
+                "Branch {branch} already has a worktree at {}",
+                format_path_for_display(&existing)
+            ),
+        }
+        .into());
+    }
+    let path = match &options.path {
+        Some(path) => path.clone(),
+        None => worktree_path(repo, config, branch)?,
+    };
+    if path.exists() {
+        return Err(GitError::Other {
+            message: format!("{} already exists", format_path_for_display(&path)),
+        }
+        .into());
+    }
+
+    events::emit(Event::Progress(&format!(
+        "Creating worktree for {branch}..."
+    )));
+    let created_branch = !repo.branch(branch).exists_locally()?;
+    let path_str = path.to_string_lossy();
+    let mut args = vec!["worktree", "add"];
+    if created_branch {
+        args.extend(["-b", branch, "--", path_str.as_ref()]);
+        if let Some(base) = &options.base {
+            args.push(base);
+        }
+    } else {
+        args.extend(["--", path_str.as_ref(), branch]);
+    }
+    repo.run_command(&args)?;
+    events::emit(Event::Success(&format!(
+        "Created worktree for {branch} @ {}",
+        format_path_for_display(&path)
+    )));
+
+    Ok(Created {
+        path,
+        branch: branch.to_string(),
+        created_branch,
+    })
+}
+
+/// Remove the worktree at `path`, and its branch if it's merged and not
+/// protected by `[branches] protected`.
+///
+/// Refuses to remove the main worktree or a locked one. One with uncommitted
+/// changes is removed only with [`RemoveOptions::force`] or when the
+/// registered [`EventHandler`] confirms it. Like `wt remove`, the removal
+/// holds the operation lock and is journaled for `wt undo`.
+pub fn remove_worktree(
+    repo: &Repository,
+    path: &Path,
+    options: &RemoveOptions,
+) -> anyhow::Result<Removed> {
+    let worktree = list_worktrees(repo)?
+        .into_iter()
+        .find(|wt| wt.path == path)
+        .ok_or_else(|| GitError::Other {
+            message: format!("No worktree at {}", format_path_for_display(path)),
+        })?;
+    if worktree.is_main {
+        return Err(GitError::Other {
+            message: "The main worktree can't be removed".into(),
+        }
+        .into());
+    }
+    if worktree.locked.is_some() {
+        return Err(GitError::WorktreeLocked {
+            branch: worktree
+                .branch
+                .clone()
+                .unwrap_or_else(|| path_dir_name(path).to_string()),
+            path: worktree.path,
+            reason: worktree.locked,
+        }
+        .into());
+    }
+    let mut force = options.force;
+    if !force && repo.worktree_at(path).is_dirty()? {
+        let message = format!("{} has uncommitted changes", format_path_for_display(path));
+        if !events::confirm(&format!("{message}; remove anyway?")) {
+            return Err(GitError::Other { message }.into());
+        }
+        force = true;
+    }
+
+    events::emit(Event::Progress(&format!(
+        "Removing worktree {}...",
+        format_path_for_display(path)
+    )));
+    let branch = worktree.branch.as_deref();
+    let deleted_branch = repo.remove_worktree_journaled(path, branch, force, None, || {
+        Ok(match branch {
+            Some(branch) if !options.keep_branch => delete_merged_branch(repo, branch),
+            _ => false,
+        })
+    })?;
+    events::emit(Event::Success(&format!(
+        "Removed worktree {}",
+        format_path_for_display(path)
+    )));
+
+    Ok(Removed {
+        path: worktree.path,
+        branch: worktree.branch,
+        deleted_branch,
+    })
+}
+
+/// Delete `branch` if git considers it merged; `branch -d` refuses
+/// unmerged branches, which is the check wanted here.
+fn delete_merged_branch(repo: &Repository, branch: &str) -> bool {
+    if repo.is_protected_branch(branch) {
+        events::emit(Event::Info(&format!("Kept protected branch {branch}")));
+        return false;
+    }
+    let deleted = repo.run_command(&["branch", "-d", branch]).is_ok();
+    if !deleted {
+        events::emit(Event::Hint(&format!(
+            "Kept branch {branch}: it isn't merged"
+        )));
+    }
+    deleted
+}
@@ -10,7 +10,7 @@ use color_print::cformat;
 use worktrunk::config::UserConfig;
 use worktrunk::styling::{eprintln, format_toml, hint_message, info_message, success_message};
 
-use super::prompt::{PromptResponse, prompt_yes_no_preview};
+use super::prompt::{PromptResponse, can_prompt, prompt_yes_no_preview};
 
 /// Detected LLM tool available on the system.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -100,7 +100,8 @@ pub fn detect_llm_tool() -> Option<LlmTool> {
 ///
 /// Returns `Ok(true)` if configuration was set up, `Ok(false)` otherwise.
 pub fn prompt_commit_generation(config: &mut UserConfig) -> anyhow::Result<bool> {
-    let is_tty = io::stdin().is_terminal() && io::stderr().is_terminal();
+    // Optional setup prompt: skipped (not an error) under --no-input
+    let is_tty = can_prompt() && io::stderr().is_terminal();
 
     // Skip if already configured
     if config
@@ -24,19 +24,18 @@ use std::os::unix::process::CommandExt;
 use std::path::{Path, PathBuf};
 use std::process::Stdio;
 use std::sync::{Mutex, OnceLock};
+use std::time::Duration;
 
-#[cfg(not(unix))]
 use worktrunk::git::WorktrunkError;
-#[cfg(not(unix))]
 use worktrunk::shell_exec::Cmd;
 use worktrunk::shell_exec::DIRECTIVE_FILE_ENV_VAR;
 #[cfg(unix)]
 use worktrunk::shell_exec::ShellConfig;
 
-// Re-export set_verbosity from the library's styling module.
+// Re-export set_verbosity/set_quiet from the library's styling module.
 // This ensures the binary and library share the same global state.
 // Library code (like expansion.rs) accesses verbosity() directly from styling.
-pub use worktrunk::styling::set_verbosity;
+pub use worktrunk::styling::{set_quiet, set_verbosity};
 
 /// Global output state, lazily initialized on first access.
 ///
@@ -202,7 +201,8 @@ fn write_directive(directive: &str) -> io::Result<()> {
         guard.directive_file.clone()
     };
 
-    let Some(path) = path else {
+    // A dry run leaves the shell where it is
+    let Some(path) = path.filter(|_| !worktrunk::dry_run::is_dry_run()) else {
         return Ok(());
     };
 
@@ -241,6 +241,15 @@ pub fn change_directory(path: impl AsRef<Path>) -> io::Result<()> {
             path_str.replace('\'', "'\\''")
         };
         write_directive(&format!("cd '{}'", escaped))?;
+
+        // Export the worktree's `.wt.env` (see `[env]` in the project config)
+        for (name, value) in crate::commands::worktree::read_env_file(path) {
+            if is_powershell {
+                write_directive(&format!("$env:{name} = '{}'", value.replace('\'', "''")))?;
+            } else {
+                write_directive(&format!("export {name}='{}'", value.replace('\'', "'\\''")))?;
+            }
+        }
     }
 
     Ok(())
@@ -250,7 +259,11 @@ pub fn change_directory(path: impl AsRef<Path>) -> io::Result<()> {
 ///
 /// In interactive mode (no directive file), executes the command directly (replacing process on Unix).
 /// In shell integration mode, writes the command to the directive file.
-pub fn execute(command: impl Into<String>) -> anyhow::Result<()> {
+///
+/// A `timeout` only applies when the command runs directly: it's spawned as a
+/// child rather than exec'd so it can be killed. The shell runs directive
+/// commands after `wt` has exited, beyond its reach.
+pub fn execute(command: impl Into<String>, timeout: Option<Duration>) -> anyhow::Result<()> {
     let command = command.into();
 
     let (has_directive, target_dir) = {
@@ -258,10 +271,18 @@ pub fn execute(command: impl Into<String>) -> anyhow::Result<()> {
         (guard.directive_file.is_some(), guard.target_dir.clone())
     };
 
-    if has_directive {
+    if worktrunk::dry_run::is_dry_run() {
+        worktrunk::dry_run::report(&command, target_dir.as_deref());
+        Ok(())
+    } else if has_directive {
+        if timeout.is_some() {
+            log::debug!("Timeout not enforced: command runs through shell integration");
+        }
         // Write to directive file
         write_directive(&command)?;
         Ok(())
+    } else if timeout.is_some() {
+        spawn_command(command, target_dir.as_deref(), timeout)
     } else {
         // Execute directly
         execute_command(command, target_dir.as_deref())
@@ -297,10 +318,24 @@ fn execute_command(command: String, target_dir: Option<&Path>) -> anyhow::Result
 /// Execute a command in the given directory (non-Unix: spawn and wait)
 #[cfg(not(unix))]
 fn execute_command(command: String, target_dir: Option<&Path>) -> anyhow::Result<()> {
-    let mut cmd = Cmd::shell(&command).stdin(Stdio::inherit());
+    spawn_command(command, target_dir, None)
+}
+
+/// Run a command as a child in the given directory and wait for it
+fn spawn_command(
+    command: String,
+    target_dir: Option<&Path>,
+    timeout: Option<Duration>,
+) -> anyhow::Result<()> {
+    let mut cmd = Cmd::shell(&command)
+        .stdin(Stdio::inherit())
+        .forward_signals();
     if let Some(dir) = target_dir {
         cmd = cmd.current_dir(dir);
     }
+    if let Some(timeout) = timeout {
+        cmd = cmd.timeout(timeout);
+    }
 
     if let Err(err) = cmd.stream() {
         // If the command failed with an exit code, just exit with that code.
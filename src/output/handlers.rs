@@ -16,17 +16,22 @@ use crate::commands::hooks::{
     HookFailureStrategy, execute_hook, prepare_background_hooks, spawn_background_hooks,
 };
 use crate::commands::process::{HookLog, InternalOp, build_remove_command, spawn_detached};
+use crate::commands::snapshot::auto_snapshot;
 use crate::commands::worktree::{BranchDeletionMode, RemoveResult, SwitchBranchInfo, SwitchResult};
 use worktrunk::config::UserConfig;
+use worktrunk::git::GitCommandError;
 use worktrunk::git::GitError;
 use worktrunk::git::IntegrationReason;
 use worktrunk::git::Repository;
 use worktrunk::git::path_dir_name;
 use worktrunk::path::format_path_for_display;
+use worktrunk::state::StateStore;
 use worktrunk::styling::{
-    FormattedMessage, eprintln, error_message, format_with_gutter, hint_message, info_message,
-    progress_message, success_message, suggest_command, warning_message,
+    AnstyleStyle, FormattedMessage, eprintln, error_message, format_with_gutter, hint_message,
+    info_message, paint, progress_message, success_message, suggest_command, theme,
+    warning_message,
 };
+use worktrunk::tr;
 
 use super::shell_integration::{
     compute_shell_warning_reason, explicit_path_hint, git_subcommand_warning,
@@ -133,14 +138,11 @@ fn handle_branch_deletion_result(
 }
 
 // ============================================================================
-// FlagNote: Workaround for cformat! being compile-time only
+// FlagNote: Parenthetical note after a removal message
 // ============================================================================
 //
-// We want to parameterize the color (cyan/green) but can't because cformat!
-// parses color tags at compile time before generic substitution. So we have
-// duplicate methods (after_cyan, after_green) instead of after(color).
-//
-// This is ugly but unavoidable. Keep it encapsulated here.
+// The note's text sits inside the message; its symbol and closing suffix
+// follow it, with the suffix back in the message's theme color.
 // ============================================================================
 
 struct FlagNote {
@@ -178,16 +180,10 @@ impl FlagNote {
         }
     }
 
-    fn after_cyan(&self) -> String {
-        match &self.symbol {
-            Some(s) => cformat!("{}<cyan>{}</>", s, self.suffix),
-            None => String::new(),
-        }
-    }
-
-    fn after_green(&self) -> String {
+    /// The symbol and suffix, with the suffix in the message's `style`.
+    fn after(&self, style: AnstyleStyle) -> String {
         match &self.symbol {
-            Some(s) => cformat!("{}<green>{}</>", s, self.suffix),
+            Some(s) => format!("{s}{}", paint(style, &self.suffix)),
             None => String::new(),
         }
     }
@@ -518,7 +514,11 @@ pub fn handle_switch_output(
 ///
 /// `display_path` is shown when the user's shell won't be in the worktree directory
 /// (shell integration not active). This helps users understand where the command runs.
-pub fn execute_user_command(command: &str, display_path: Option<&Path>) -> anyhow::Result<()> {
+pub fn execute_user_command(
+    command: &str,
+    display_path: Option<&Path>,
+    timeout: Option<std::time::Duration>,
+) -> anyhow::Result<()> {
     // Show what command is being executed (section header + gutter content)
     // Include path when user's shell won't be there (shell integration not active)
     let header = match display_path {
@@ -531,7 +531,7 @@ pub fn execute_user_command(command: &str, display_path: Option<&Path>) -> anyho
     eprintln!("{}", progress_message(header));
     eprintln!("{}", format_bash_with_gutter(command));
 
-    super::execute(command)?;
+    super::execute(command, timeout)?;
 
     Ok(())
 }
@@ -589,16 +589,20 @@ fn handle_branch_only_output(
         // Worktree was pruned - informational, not a warning
         eprintln!(
             "{}",
-            info_message(cformat!(
-                "Worktree directory missing for <bold>{branch_name}</>; pruned"
+            info_message(tr!(
+                "remove.worktree_pruned",
+                "Worktree directory missing for {branch}; pruned",
+                branch = cformat!("<bold>{branch_name}</>")
             ))
         );
     } else {
         // No worktree at all, but branch exists - informational since branch removal will proceed
         eprintln!(
             "{}",
-            info_message(cformat!(
-                "No worktree found for branch <bold>{branch_name}</>"
+            info_message(tr!(
+                "remove.no_worktree",
+                "No worktree found for branch {branch}",
+                branch = cformat!("<bold>{branch_name}</>")
             ))
         );
     }
@@ -627,12 +631,10 @@ fn handle_branch_only_output(
             Some(&deletion.integration_target),
         );
         let flag_text = &flag_note.text;
-        let flag_after = flag_note.after_green();
+        let flag_after = flag_note.after(theme().success);
         eprintln!(
-            "{}",
-            FormattedMessage::new(cformat!(
-                "<green>✓ Removed branch <bold>{branch_name}</>{flag_text}</>{flag_after}"
-            ))
+            "{}{flag_after}",
+            success_message(cformat!("Removed branch <bold>{branch_name}</>{flag_text}"))
         );
     }
 
@@ -818,29 +820,39 @@ impl RemovalDisplayInfo {
 
         if is_background {
             let flag_text = &flag_note.text;
-            let flag_after = flag_note.after_cyan();
+            let flag_after = flag_note.after(theme().progress);
             let msg = if self.branch_deleted() {
-                cformat!(
-                    "<cyan>◎ Removing <bold>{branch_name}</> worktree{force_text} & branch in background{flag_text}</>{flag_after}"
+                format!(
+                    "{}{flag_after}",
+                    progress_message(cformat!(
+                        "Removing <bold>{branch_name}</> worktree{force_text} & branch in background{flag_text}"
+                    ))
                 )
             } else {
-                cformat!(
-                    "<cyan>◎ Removing <bold>{branch_name}</> worktree{force_text} in background</>"
-                )
+                progress_message(cformat!(
+                    "Removing <bold>{branch_name}</> worktree{force_text} in background"
+                ))
+                .to_string()
             };
-            eprintln!("{}", FormattedMessage::new(msg));
+            eprintln!("{msg}");
             Ok(())
         } else {
             let msg = if self.branch_deleted() {
                 let flag_text = &flag_note.text;
-                let flag_after = flag_note.after_green();
-                cformat!(
-                    "<green>✓ Removed <bold>{branch_name}</> worktree{force_text} & branch{flag_text}</>{flag_after}"
+                let flag_after = flag_note.after(theme().success);
+                format!(
+                    "{}{flag_after}",
+                    success_message(cformat!(
+                        "Removed <bold>{branch_name}</> worktree{force_text} & branch{flag_text}"
+                    ))
                 )
             } else {
-                cformat!("<green>✓ Removed <bold>{branch_name}</> worktree{force_text}</>")
+                success_message(cformat!(
+                    "Removed <bold>{branch_name}</> worktree{force_text}"
+                ))
+                .to_string()
             };
-            eprintln!("{}", FormattedMessage::new(msg));
+            eprintln!("{msg}");
             Ok(())
         }
     }
@@ -943,6 +955,21 @@ fn handle_removed_worktree_output(
         stderr().flush()?; // Force flush to ensure shell processes the cd
     }
 
+    // git refuses to remove worktrees containing submodules; deinit them first
+    if let Err(err) = repo
+        .worktree_at(worktree_path)
+        .deinit_submodules(force_worktree)
+    {
+        return Err(GitError::WorktreeRemovalFailed {
+            branch: branch_name
+                .map_or_else(|| path_dir_name(worktree_path).to_string(), String::from),
+            path: worktree_path.to_path_buf(),
+            error: err.to_string(),
+            source: GitCommandError::find(&err).cloned(),
+        }
+        .into());
+    }
+
     // Handle detached HEAD case (no branch known)
     let Some(branch_name) = branch_name else {
         // No branch associated - just remove the worktree
@@ -968,17 +995,7 @@ fn handle_removed_worktree_output(
                 "{}",
                 progress_message("Removing worktree (detached HEAD, no branch to delete)...",)
             );
-            let _ = repo
-                .worktree_at(worktree_path)
-                .run_command(&["fsmonitor--daemon", "stop"]);
-            if let Err(err) = repo.remove_worktree(worktree_path, force_worktree) {
-                return Err(GitError::WorktreeRemovalFailed {
-                    branch: path_dir_name(worktree_path).to_string(),
-                    path: worktree_path.to_path_buf(),
-                    error: err.to_string(),
-                }
-                .into());
-            }
+            repo.remove_worktree_journaled(worktree_path, None, force_worktree, None, || Ok(()))?;
             eprintln!(
                 "{}",
                 success_message("Removed worktree (detached HEAD, no branch to delete)",)
@@ -1031,6 +1048,7 @@ fn handle_removed_worktree_output(
             &HookLog::internal(InternalOp::Remove),
             None,
         )?;
+        release_branch_resources(&repo, branch_name);
 
         spawn_hooks_after_remove(
             main_path,
@@ -1056,29 +1074,31 @@ fn handle_removed_worktree_output(
             eprintln!("{}", format_path_mismatch_warning(branch_name, expected));
         }
 
-        // Stop fsmonitor daemon first (best effort - ignore errors)
-        // This prevents zombie daemons from accumulating when using builtin fsmonitor
-        let _ = repo
-            .worktree_at(worktree_path)
-            .run_command(&["fsmonitor--daemon", "stop"]);
-
-        if let Err(err) = repo.remove_worktree(worktree_path, force_worktree) {
-            return Err(GitError::WorktreeRemovalFailed {
-                branch: branch_name.into(),
-                path: worktree_path.to_path_buf(),
-                error: err.to_string(),
-            }
-            .into());
-        }
+        // A forced removal discards uncommitted changes; keep them for `wt undo`
+        let snapshot = if force_worktree {
+            auto_snapshot(&repo.worktree_at(worktree_path), "remove")
+        } else {
+            None
+        };
 
-        let display_info = RemovalDisplayInfo::from_actual(
-            &repo,
-            branch_name,
-            deletion_mode,
-            pre_computed_integration,
-            target_branch,
+        // Branch deletion runs under the same operation lock as the removal
+        let display_info = repo.remove_worktree_journaled(
+            worktree_path,
+            Some(branch_name),
             force_worktree,
+            snapshot.as_deref(),
+            || {
+                RemovalDisplayInfo::from_actual(
+                    &repo,
+                    branch_name,
+                    deletion_mode,
+                    pre_computed_integration,
+                    target_branch,
+                    force_worktree,
+                )
+            },
         )?;
+        release_branch_resources(&repo, branch_name);
 
         display_info.print_message(branch_name, false)?;
         display_info.print_hints(branch_name, deletion_mode, pre_computed_integration)?;
@@ -1097,6 +1117,19 @@ fn handle_removed_worktree_output(
     }
 }
 
+/// Free a removed worktree's port block and tear down its dev container.
+fn release_branch_resources(repo: &Repository, branch: &str) {
+    let store = StateStore::new(repo.git_common_dir());
+    if store
+        .load()
+        .is_ok_and(|state| state.ports.contains_key(branch))
+        && let Err(e) = store.update(|state| state.ports.remove(branch))
+    {
+        log::debug!("Failed to free port block of {branch}: {e}");
+    }
+    crate::commands::devcontainer::down(repo, branch);
+}
+
 /// Execute a command in a worktree directory
 ///
 /// Redirects child stdout to stderr (via `.stdout(Stdio::from(std::io::stderr()))`) for
@@ -1106,6 +1139,9 @@ fn handle_removed_worktree_output(
 /// If `stdin_content` is provided, it will be piped to the command's stdin. This is used to pass
 /// hook context as JSON to hook commands.
 ///
+/// If `log_path` is provided, the output is also appended to that file (see `Cmd::tee`).
+/// If `timeout` is provided, the command is killed once it runs longer.
+///
 /// ## Color Bleeding Prevention
 ///
 /// This function explicitly resets ANSI codes on stderr before executing child commands.
@@ -1125,6 +1161,8 @@ pub fn execute_command_in_worktree(
     worktree_path: &std::path::Path,
     command: &str,
     stdin_content: Option<&str>,
+    log_path: Option<&std::path::Path>,
+    timeout: Option<std::time::Duration>,
 ) -> anyhow::Result<()> {
     // Flush stdout before executing command to ensure all our messages appear
     // before the child process output
@@ -1145,6 +1183,12 @@ pub fn execute_command_in_worktree(
     if let Some(content) = stdin_content {
         cmd = cmd.stdin_bytes(content);
     }
+    if let Some(log_path) = log_path {
+        cmd = cmd.tee(log_path);
+    }
+    if let Some(timeout) = timeout {
+        cmd = cmd.timeout(timeout);
+    }
 
     cmd.stream()?;
 
@@ -13,7 +13,7 @@
 //!
 //! eprintln!("{}", success_message("Operation complete"));
 //! output::change_directory(&path);
-//! output::execute("git pull");
+//! output::execute("git pull", None);
 //! ```
 //!
 //! ## Shell Integration
@@ -32,14 +32,17 @@
 pub(crate) mod commit_generation;
 mod global;
 pub(crate) mod handlers;
+pub(crate) mod progress;
 pub(crate) mod prompt;
 pub(crate) mod shell_integration;
 
 // Re-export the public API
 pub(crate) use global::{
     change_directory, execute, is_shell_integration_active, post_hook_display_path,
-    pre_hook_display_path, set_verbosity, terminate_output, to_logical_path,
+    pre_hook_display_path, set_quiet, set_verbosity, terminate_output, to_logical_path,
 };
+// Re-export progress indicator
+pub(crate) use progress::Progress;
 // Re-export output handlers
 pub(crate) use handlers::{
     execute_command_in_worktree, execute_user_command, handle_remove_output, handle_switch_output,
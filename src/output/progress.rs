@@ -0,0 +1,132 @@
+//! Progress indicators for slow operations.
+//!
+//! A [`Progress`] draws an animated spinner on stderr while a network or
+//! disk-heavy operation runs, so it doesn't look hung. Like delayed streaming
+//! for `git worktree add`, nothing is drawn for operations that finish within
+//! [`Repository::SLOW_OPERATION_DELAY_MS`](worktrunk::git::Repository::SLOW_OPERATION_DELAY_MS).
+//!
+//! Drawing only happens when stderr is a terminal and `-q` isn't set; in
+//! pipes, logs, and tests the indicator is silent and callers' own progress
+//! messages carry the information. The spinner takes the theme's progress
+//! color, and output goes through `anstream`, so `NO_COLOR` is respected.
+//!
+//! The line is cleared when the indicator is finished or dropped. Don't print
+//! other output while one is active — finish it first.
+
+use std::io::{IsTerminal, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use worktrunk::git::Repository;
+use worktrunk::styling::{eprint, is_quiet, stderr, theme};
+
+/// Spinner animation frames.
+const FRAMES: &[&str] = &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+/// Time between animation frames.
+const FRAME_INTERVAL: Duration = Duration::from_millis(80);
+
+/// Clear the current terminal line and return the cursor to column 0.
+const CLEAR_LINE: &str = "\r\x1b[2K";
+
+struct State {
+    message: Mutex<String>,
+    finished: AtomicBool,
+}
+
+impl State {
+    fn render(&self, frame: &str) -> String {
+        let style = theme().progress;
+        let message = self.message.lock().expect("progress lock poisoned");
+        format!("{style}{frame}{style:#} {message}")
+    }
+}
+
+/// A spinner on stderr.
+///
+/// # Example
+/// ```ignore
+/// let progress = Progress::spinner(cformat!("Fetching <bold>{branch}</>..."));
+/// repo.run_command(&["fetch", remote, branch])?;
+/// progress.finish();
+/// ```
+pub struct Progress {
+    state: Arc<State>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl Progress {
+    /// Start a spinner with `message`.
+    pub fn spinner(message: impl Into<String>) -> Self {
+        let state = Arc::new(State {
+            message: Mutex::new(message.into()),
+            finished: AtomicBool::new(false),
+        });
+        let handle =
+            (std::io::stderr().is_terminal() && !is_quiet()).then(|| spawn_renderer(state.clone()));
+        Self { state, handle }
+    }
+
+    /// Replace the message shown next to the spinner.
+    pub fn set_message(&self, message: impl Into<String>) {
+        *self.state.message.lock().expect("progress lock poisoned") = message.into();
+    }
+
+    /// Stop drawing and clear the line.
+    pub fn finish(self) {
+        // Drop does the work
+    }
+}
+
+impl Drop for Progress {
+    fn drop(&mut self) {
+        self.state.finished.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+fn spawn_renderer(state: Arc<State>) -> JoinHandle<()> {
+    thread::spawn(move || {
+        let delay = Duration::from_millis(Repository::SLOW_OPERATION_DELAY_MS as u64);
+        let start = Instant::now();
+        let mut drawn = false;
+        let mut frame = 0;
+        while !state.finished.load(Ordering::Relaxed) {
+            if start.elapsed() >= delay {
+                eprint!("{CLEAR_LINE}{}", state.render(FRAMES[frame % FRAMES.len()]));
+                let _ = stderr().flush();
+                drawn = true;
+                frame += 1;
+            }
+            thread::sleep(FRAME_INTERVAL);
+        }
+        if drawn {
+            eprint!("{CLEAR_LINE}");
+            let _ = stderr().flush();
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use ansi_str::AnsiStr;
+
+    use super::*;
+
+    fn state(message: &str) -> State {
+        State {
+            message: Mutex::new(message.to_string()),
+            finished: AtomicBool::new(false),
+        }
+    }
+
+    #[test]
+    fn test_render_spinner() {
+        let rendered = state("Fetching").render("⠋");
+        assert_eq!(rendered.ansi_strip(), "⠋ Fetching");
+    }
+}
@@ -1,9 +1,223 @@
 //! Reusable prompt utilities for interactive CLI prompts.
+//!
+//! [`Prompt`] covers confirmations, single and multiple choice, and text
+//! input. Every prompt checks [`can_prompt`] first. With `--no-input`, or when
+//! stdin isn't a terminal, prompts fail with a typed error naming the flag that
+//! answers them instead of blocking on input that will never come.
+//! `WT_ASSUME_YES=1` answers every confirmation as if `--yes` were passed.
 
-use std::io::{self, Write};
+use std::io::{self, IsTerminal, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
 
 use color_print::cformat;
-use worktrunk::styling::PROMPT_SYMBOL;
+use worktrunk::git::GitError;
+use worktrunk::styling::{PROMPT_SYMBOL, eprint, eprintln, prompt_message};
+
+static NO_INPUT: AtomicBool = AtomicBool::new(false);
+static ASSUME_YES: AtomicBool = AtomicBool::new(false);
+
+/// Configure non-interactive mode from `--no-input` and `WT_ASSUME_YES`.
+pub fn set_input_mode(no_input: bool, assume_yes: bool) {
+    NO_INPUT.store(no_input, Ordering::Relaxed);
+    ASSUME_YES.store(assume_yes, Ordering::Relaxed);
+}
+
+/// Whether `WT_ASSUME_YES` is set, meaning every `--yes` flag is implied.
+pub fn assume_yes() -> bool {
+    ASSUME_YES.load(Ordering::Relaxed)
+}
+
+/// Whether prompts may read from stdin.
+pub fn can_prompt() -> bool {
+    !NO_INPUT.load(Ordering::Relaxed) && io::stdin().is_terminal()
+}
+
+/// Error for a prompt that can't be shown, or `Ok` when it can.
+///
+/// `hint` names the flag or argument that answers the prompt up front.
+pub fn require_input(prompt: &str, hint: impl Into<String>) -> anyhow::Result<()> {
+    if can_prompt() {
+        return Ok(());
+    }
+    Err(GitError::InputRequired {
+        prompt: prompt.to_string(),
+        hint: hint.into(),
+    }
+    .into())
+}
+
+/// An interactive question, answered on stderr/stdin.
+///
+/// Callers skip the prompt when their `--yes` flag is passed. With
+/// `WT_ASSUME_YES`, prompts answer themselves: confirmations accept, and
+/// choices and text take their defaults. Otherwise they need a terminal,
+/// failing with [`GitError::InputRequired`] and the hint when there isn't one.
+///
+/// # Example
+/// ```ignore
+/// let drop = Prompt::new("Drop which databases?")
+///     .hint(cformat!("Add <bright-black>--drop</> to drop them all"))
+///     .multi_select(&branches, &[])?;
+/// ```
+pub struct Prompt<'a> {
+    text: &'a str,
+    hint: Option<String>,
+}
+
+impl<'a> Prompt<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Self { text, hint: None }
+    }
+
+    /// How to answer the prompt up front, shown when it can't be asked.
+    pub fn hint(mut self, hint: impl Into<String>) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
+
+    fn require_input(&self) -> anyhow::Result<()> {
+        let hint = self.hint.clone().unwrap_or_else(|| {
+            cformat!(
+                "Add <bright-black>--yes</> (or set <bright-black>WT_ASSUME_YES=1</>) to accept"
+            )
+        });
+        require_input(self.text, hint)
+    }
+
+    /// `[y/N]` (or `[Y/n]`) confirmation; an empty answer takes `default`.
+    pub fn confirm(self, default: bool) -> anyhow::Result<bool> {
+        if assume_yes() {
+            return Ok(true);
+        }
+        self.require_input()?;
+        let options = if default { "[Y/n]" } else { "[y/N]" };
+        let answer = ask(&cformat!("{} <bold>{options}</>", self.text))?;
+        Ok(match answer.to_lowercase().as_str() {
+            "" => default,
+            answer => answer == "y" || answer == "yes",
+        })
+    }
+
+    /// Pick one of `options` by number; an empty answer takes `default`.
+    pub fn select(self, options: &[String], default: usize) -> anyhow::Result<usize> {
+        if assume_yes() {
+            return Ok(default);
+        }
+        self.require_input()?;
+        list_options(options);
+        loop {
+            let answer = ask(&cformat!(
+                "{} <bold>[1-{}, default {}]</>",
+                self.text,
+                options.len(),
+                default + 1
+            ))?;
+            if answer.is_empty() {
+                return Ok(default);
+            }
+            match parse_choices(&answer, options.len()).as_deref() {
+                Some([choice]) => return Ok(*choice),
+                _ => eprintln!(
+                    "{}",
+                    cformat!("<dim>Enter a number from 1 to {}</>", options.len())
+                ),
+            }
+        }
+    }
+
+    /// Pick any of `options` by number (`1,3`, `2-4`, `all`); an empty answer
+    /// takes `selected`.
+    pub fn multi_select(
+        self,
+        options: &[String],
+        selected: &[usize],
+    ) -> anyhow::Result<Vec<usize>> {
+        if assume_yes() {
+            return Ok(selected.to_vec());
+        }
+        self.require_input()?;
+        list_options(options);
+        loop {
+            let answer = ask(&cformat!("{} <bold>[e.g. 1,3 or all]</>", self.text))?;
+            if answer.is_empty() {
+                return Ok(selected.to_vec());
+            }
+            match parse_choices(&answer, options.len()) {
+                Some(choices) => return Ok(choices),
+                None => eprintln!(
+                    "{}",
+                    cformat!("<dim>Enter numbers from 1 to {}</>", options.len())
+                ),
+            }
+        }
+    }
+
+    /// Free-form text; an empty answer takes `default`, when there is one.
+    pub fn text(self, default: Option<&str>) -> anyhow::Result<String> {
+        if let Some(default) = default
+            && assume_yes()
+        {
+            return Ok(default.to_string());
+        }
+        self.require_input()?;
+        loop {
+            let prompt = match default {
+                Some(default) => cformat!("{} <bold>[{default}]</>", self.text),
+                None => self.text.to_string(),
+            };
+            let answer = ask(&prompt)?;
+            match (answer.is_empty(), default) {
+                (false, _) => return Ok(answer),
+                (true, Some(default)) => return Ok(default.to_string()),
+                (true, None) => {}
+            }
+        }
+    }
+}
+
+/// Show `prompt` and read one trimmed line of input.
+fn ask(prompt: &str) -> anyhow::Result<String> {
+    // Blank line before the prompt for visual separation
+    eprintln!();
+    eprint!("{} ", prompt_message(prompt));
+    io::stderr().flush()?;
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    Ok(input.trim().to_string())
+}
+
+/// Numbered options, as `select` and `multi_select` show them.
+fn list_options(options: &[String]) {
+    for (i, option) in options.iter().enumerate() {
+        eprintln!("{}", cformat!("  <bold>{}</> {option}", i + 1));
+    }
+}
+
+/// Zero-based indices for an answer like `1,3`, `2-4` or `all`, in order and
+/// without duplicates; `None` when any part isn't a number from 1 to `len`.
+fn parse_choices(answer: &str, len: usize) -> Option<Vec<usize>> {
+    if answer.eq_ignore_ascii_case("all") {
+        return Some((0..len).collect());
+    }
+    let mut choices = Vec::new();
+    for part in answer.split([',', ' ']).filter(|part| !part.is_empty()) {
+        let (start, end) = match part.split_once('-') {
+            Some((start, end)) => (start.trim().parse().ok()?, end.trim().parse().ok()?),
+            None => {
+                let n: usize = part.parse().ok()?;
+                (n, n)
+            }
+        };
+        if start == 0 || end > len || start > end {
+            return None;
+        }
+        choices.extend(start - 1..end);
+    }
+    choices.sort_unstable();
+    choices.dedup();
+    (!choices.is_empty()).then_some(choices)
+}
 
 /// Response from a `[y/N/?]` prompt.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -23,6 +237,8 @@ pub enum PromptResponse {
 /// # Returns
 /// * `Ok(Accepted)` if user enters `y` or `yes`
 /// * `Ok(Declined)` if user enters anything else (including empty)
+/// * `Err(InputRequired)` if prompting isn't possible; callers handle `--yes`
+///   before prompting
 ///
 /// # Example
 /// ```ignore
@@ -40,7 +256,12 @@ pub enum PromptResponse {
 pub fn prompt_yes_no_preview(
     prompt_text: &str,
     show_preview: impl Fn(),
-) -> io::Result<PromptResponse> {
+) -> anyhow::Result<PromptResponse> {
+    require_input(
+        prompt_text,
+        cformat!("Add <bright-black>--yes</> (or set <bright-black>WT_ASSUME_YES=1</>) to accept"),
+    )?;
+
     // Blank line before first prompt for visual separation
     worktrunk::styling::eprintln!();
 
@@ -69,3 +290,21 @@ pub fn prompt_yes_no_preview(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_choices() {
+        assert_eq!(parse_choices("2", 3), Some(vec![1]));
+        assert_eq!(parse_choices("3,1", 3), Some(vec![0, 2]));
+        assert_eq!(parse_choices("1 2-3 2", 4), Some(vec![0, 1, 2]));
+        assert_eq!(parse_choices("ALL", 2), Some(vec![0, 1]));
+        assert_eq!(parse_choices("0", 3), None);
+        assert_eq!(parse_choices("4", 3), None);
+        assert_eq!(parse_choices("3-2", 3), None);
+        assert_eq!(parse_choices("x", 3), None);
+        assert_eq!(parse_choices(",", 3), None);
+    }
+}
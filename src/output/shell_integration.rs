@@ -68,6 +68,7 @@ use worktrunk::styling::{
     eprintln, format_bash_with_gutter, hint_message, info_message, success_message, warning_message,
 };
 
+use super::prompt::can_prompt;
 use crate::commands::configure_shell::{
     ConfigAction, handle_configure_shell, prompt_for_install, scan_shell_configs,
 };
@@ -377,7 +378,8 @@ pub fn prompt_shell_integration(
         return Ok(false);
     }
 
-    let is_tty = std::io::stdin().is_terminal() && std::io::stderr().is_terminal();
+    // Optional setup prompt: skipped (not an error) under --no-input
+    let is_tty = can_prompt() && std::io::stderr().is_terminal();
 
     // Check the current shell (via $SHELL or PSModulePath fallback)
     // Only prompt if current shell is supported (so they benefit immediately)
@@ -1,4 +1,30 @@
+//! Pager detection and paging of long stdout output.
+//!
+//! Both `--help` ([`crate::help_pager`]) and long command output follow git's
+//! pager precedence: GIT_PAGER → core.pager config → PAGER → "less".
+//!
+//! Command output is paged the way git pages `git log`: only when stdout is a
+//! terminal and the output is taller than it, with `less -FRX` semantics so
+//! colors survive and the output stays on screen after quitting. `--no-pager`
+//! and `[ui] pager = false` turn paging off.
+
+use std::io::{IsTerminal, Write};
+use std::process::Stdio;
+use std::sync::atomic::{AtomicBool, Ordering};
+
 use worktrunk::git::Repository;
+use worktrunk::shell_exec::ShellConfig;
+use worktrunk::styling::{Stream, colors_enabled, print};
+
+/// Whether long output may be paged, from `--no-pager` and `[ui] pager`.
+static PAGING: AtomicBool = AtomicBool::new(true);
+
+/// Turn paging of long output on or off.
+///
+/// Call this once at startup after parsing CLI arguments.
+pub(crate) fn set_paging(enabled: bool) {
+    PAGING.store(enabled, Ordering::Relaxed);
+}
 
 /// Parse a pager value, treating empty strings and "cat" as "no pager".
 pub(crate) fn parse_pager_value(value: &str) -> Option<String> {
@@ -13,3 +39,149 @@ pub(crate) fn git_config_pager() -> Option<String> {
         .ok()
         .and_then(|output| parse_pager_value(&output))
 }
+
+/// Detect the pager, following git's pager precedence.
+///
+/// Checks in order: GIT_PAGER → git config core.pager → PAGER → "less"
+///
+/// On Windows without Git Bash, returns None if only `less` would be selected
+/// (since `less` isn't available without Git for Windows).
+pub(crate) fn detect_pager() -> Option<String> {
+    let shell = ShellConfig::get();
+
+    // Check environment variables in git's precedence order
+    let pager = std::env::var("GIT_PAGER")
+        .ok()
+        .and_then(|s| parse_pager_value(&s))
+        .or_else(git_config_pager)
+        .or_else(|| {
+            std::env::var("PAGER")
+                .ok()
+                .and_then(|s| parse_pager_value(&s))
+        });
+
+    // If user explicitly configured a pager, use it
+    if pager.is_some() {
+        return pager;
+    }
+
+    // Default to "less" only if we have a POSIX shell (Unix or Git Bash on Windows)
+    // Without Git Bash, less isn't typically available on Windows
+    if shell.is_posix() {
+        Some("less".to_string())
+    } else {
+        log::debug!("No POSIX shell available, skipping pager (less not available)");
+        None
+    }
+}
+
+/// Compute LESS flags by appending our required flags to user's existing LESS setting.
+///
+/// Returns flags suitable for setting LESS env var when spawning less.
+/// Ensures F (quit if one screen), R (colors), X (no termcap init) are always active.
+pub(crate) fn compute_less_flags(user_less: Option<&str>) -> String {
+    format!("{} -FRX", user_less.unwrap_or_default())
+}
+
+/// Print `text` to stdout, through the pager when it doesn't fit the terminal.
+///
+/// `text` is complete lines, styled as for `println!`.
+pub(crate) fn page_stdout(text: &str) -> std::io::Result<()> {
+    if !try_page(text)? {
+        print!("{text}");
+    }
+    Ok(())
+}
+
+/// Send `text` to the pager, returning false when it should be printed
+/// directly instead.
+///
+/// That's when paging is off, stdout isn't a terminal, the output fits, no
+/// pager is configured, or the pager fails to start.
+pub(crate) fn try_page(text: &str) -> std::io::Result<bool> {
+    let stdout = std::io::stdout();
+    if !PAGING.load(Ordering::Relaxed) || !stdout.is_terminal() {
+        return Ok(false);
+    }
+    let fits = terminal_size::terminal_size_of(&stdout)
+        .is_none_or(|(_, height)| text.lines().count() < height.0 as usize);
+    if fits {
+        return Ok(false);
+    }
+    let Some(pager_cmd) = detect_pager() else {
+        return Ok(false);
+    };
+
+    // The pager bypasses anstream, so `--color=never` and NO_COLOR are applied here
+    let text = if colors_enabled(Stream::Stdout) {
+        text.to_string()
+    } else {
+        anstream::adapter::strip_str(text).to_string()
+    };
+
+    let shell = ShellConfig::get();
+    log::debug!("$ {} (pager)", pager_cmd);
+    let mut cmd = shell.command(&pager_cmd);
+    // Prevent subprocesses from writing to the directive file
+    cmd.env_remove(worktrunk::shell_exec::DIRECTIVE_FILE_ENV_VAR);
+    let less_flags = compute_less_flags(std::env::var("LESS").ok().as_deref());
+    let mut child = match cmd.stdin(Stdio::piped()).env("LESS", &less_flags).spawn() {
+        Ok(child) => child,
+        Err(e) => {
+            log::debug!(
+                "Failed to spawn pager '{}' with {}: {}",
+                pager_cmd,
+                shell.name,
+                e
+            );
+            return Ok(false);
+        }
+    };
+
+    // Quitting the pager before reading everything closes the pipe
+    if let Some(mut stdin) = child.stdin.take()
+        && let Err(e) = stdin.write_all(text.as_bytes())
+        && e.kind() != std::io::ErrorKind::BrokenPipe
+    {
+        return Err(e);
+    }
+
+    child.wait()?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::compute_less_flags;
+
+    #[test]
+    fn test_compute_less_flags_empty() {
+        // Leading space is fine - less ignores it
+        assert_eq!(compute_less_flags(None), " -FRX");
+        assert_eq!(compute_less_flags(Some("")), " -FRX");
+    }
+
+    #[test]
+    fn test_compute_less_flags_short_options() {
+        // Common case: user has -R (oh-my-zsh default)
+        assert_eq!(compute_less_flags(Some("-R")), "-R -FRX");
+        // User has multiple short flags
+        assert_eq!(compute_less_flags(Some("-iMRS")), "-iMRS -FRX");
+    }
+
+    #[test]
+    fn test_compute_less_flags_long_options() {
+        // Issue #594: --mouse must not become --mouseFRX
+        assert_eq!(compute_less_flags(Some("--mouse")), "--mouse -FRX");
+        // Multiple long options
+        assert_eq!(
+            compute_less_flags(Some("--mouse --shift=4")),
+            "--mouse --shift=4 -FRX"
+        );
+    }
+
+    #[test]
+    fn test_compute_less_flags_mixed() {
+        assert_eq!(compute_less_flags(Some("-R --mouse")), "-R --mouse -FRX");
+    }
+}
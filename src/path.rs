@@ -1,15 +1,14 @@
 use path_slash::PathExt as _;
 use shell_escape::unix::escape;
 use std::borrow::Cow;
-use std::path::Path;
+use std::path::{Component, Path, PathBuf};
+use std::sync::OnceLock;
 
 use sanitize_filename::{Options as SanitizeOptions, sanitize_with_options};
 
-use crate::config::short_hash;
+use crate::config::{PathStyle, short_hash};
 #[cfg(windows)]
 use crate::shell_exec::{Cmd, ShellConfig};
-#[cfg(windows)]
-use std::path::PathBuf;
 
 /// Convert a path to POSIX format for Git Bash compatibility.
 ///
@@ -32,7 +31,11 @@ pub fn to_posix_path(path: &str) -> String {
         return path.to_string();
     };
 
-    let Ok(output) = Cmd::new(cygpath.to_string_lossy()).args(["-u", path]).run() else {
+    let Ok(output) = Cmd::new(cygpath.to_string_lossy())
+        .args(["-u", path])
+        .read_only()
+        .run()
+    else {
         return path.to_string();
     };
 
@@ -107,6 +110,18 @@ fn needs_shell_escaping(s: &str) -> bool {
 /// - `/tmp/repo` → `/tmp/repo` (no escaping needed)
 /// - `/tmp/my repo` → `'/tmp/my repo'` (needs quoting)
 pub fn format_path_for_display(path: &Path) -> String {
+    match configured_path_style() {
+        Some((PathStyle::Relative, base)) => {
+            if let Some(relative) = format_path_relative(path, base)
+                && !needs_shell_escaping(&relative)
+            {
+                return relative;
+            }
+        }
+        Some((PathStyle::Absolute, _)) => return quote_path(path),
+        Some((PathStyle::Home, _)) | None => {}
+    }
+
     // Try to use tilde for home directory paths
     if let Some(home) = home_dir()
         && let Ok(stripped) = path.strip_prefix(&home)
@@ -126,6 +141,11 @@ pub fn format_path_for_display(path: &Path) -> String {
     }
 
     // Non-home path or escaping needed - use POSIX quoting
+    quote_path(path)
+}
+
+/// Absolute path with POSIX quoting when needed.
+fn quote_path(path: &Path) -> String {
     // Use to_slash_lossy for Windows compatibility (forward slashes in shell hints)
     let original = path.to_slash_lossy();
     match escape(Cow::Borrowed(&original)) {
@@ -134,6 +154,48 @@ pub fn format_path_for_display(path: &Path) -> String {
     }
 }
 
+/// Format `path` relative to `base`, the way `wt list` shows worktree paths.
+///
+/// - `base` itself: `.`
+/// - Child of `base`: `./subdir`
+/// - Sibling: `../sibling`
+///
+/// Returns `None` when no relative path exists (e.g., different drives on Windows).
+pub fn format_path_relative(path: &Path, base: &Path) -> Option<String> {
+    if path == base {
+        return Some(".".to_string());
+    }
+    let relative = pathdiff::diff_paths(path, base)?;
+    // Paths outside `base` start with ".."; others get "./" (or `.\` on Windows) for clarity
+    Some(
+        if relative.components().next() == Some(Component::ParentDir) {
+            relative.display().to_string()
+        } else {
+            format!(".{}{}", std::path::MAIN_SEPARATOR, relative.display())
+        },
+    )
+}
+
+/// Path style from `[display] paths`, with the base for relative paths.
+static PATH_STYLE: OnceLock<(PathStyle, PathBuf)> = OnceLock::new();
+
+/// Set how paths are displayed, overriding the per-context defaults.
+///
+/// `base` is the directory relative paths start from (the main worktree).
+/// Call this once at startup; later calls are ignored.
+pub fn set_path_style(style: PathStyle, base: PathBuf) {
+    let _ = PATH_STYLE.set((style, base));
+}
+
+/// The configured path style, if `[display] paths` is set.
+///
+/// Unset, messages use `~/...` and `wt list` uses paths relative to the main worktree.
+pub fn configured_path_style() -> Option<(PathStyle, &'static Path)> {
+    PATH_STYLE
+        .get()
+        .map(|(style, base)| (*style, base.as_path()))
+}
+
 /// Sanitize a string for use as a filename on all platforms.
 ///
 /// Uses `sanitize-filename` crate to handle invalid characters, control characters,
@@ -169,7 +231,10 @@ pub fn sanitize_for_filename(value: &str) -> String {
 mod tests {
     use std::path::PathBuf;
 
-    use super::{format_path_for_display, home_dir, sanitize_for_filename, to_posix_path};
+    use super::{
+        format_path_for_display, format_path_relative, home_dir, sanitize_for_filename,
+        to_posix_path,
+    };
 
     #[test]
     fn shortens_path_under_home() {
@@ -267,6 +332,19 @@ mod tests {
         assert_eq!(result, "/definitely/not/under/home/dir");
     }
 
+    #[test]
+    #[cfg(not(windows))]
+    fn test_format_path_relative() {
+        let base = PathBuf::from("/code/project");
+        let relative = |path: &str| format_path_relative(&PathBuf::from(path), &base);
+        assert_eq!(relative("/code/project").as_deref(), Some("."));
+        assert_eq!(relative("/code/project/sub").as_deref(), Some("./sub"));
+        assert_eq!(
+            relative("/code/project.feature").as_deref(),
+            Some("../project.feature")
+        );
+    }
+
     #[test]
     #[cfg(not(windows))]
     fn test_to_posix_path_on_unix() {
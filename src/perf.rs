@@ -0,0 +1,192 @@
+//! Opt-in local timing log.
+//!
+//! With `WORKTRUNK_PERF_LOG=1`, each command appends one JSON line to
+//! `.git/wt-logs/perf.jsonl`: how long the command took, and how long each
+//! subprocess and phase inside it took. `wt perf report` summarizes the file.
+//! Nothing leaves the machine.
+//!
+//! Subprocesses are recorded by [`crate::shell_exec::Cmd`]; phases by
+//! [`phase`] guards around work that isn't a single subprocess (hooks, commit
+//! message generation, `wt list` collection).
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+/// Environment variable that turns the timing log on.
+pub const PERF_LOG_ENV: &str = "WORKTRUNK_PERF_LOG";
+
+/// File name of the timing log in the repository's `wt-logs` directory.
+pub const PERF_LOG_FILE: &str = "perf.jsonl";
+
+/// Entries kept in the log; older ones are dropped.
+const MAX_ENTRIES: usize = 1000;
+
+/// Spans kept per command, so a long-running command can't grow without bound.
+const MAX_SPANS: usize = 10_000;
+
+static RECORDER: OnceLock<Recorder> = OnceLock::new();
+
+struct Recorder {
+    started: Instant,
+    path: PathBuf,
+    spans: Mutex<Vec<Span>>,
+}
+
+/// What a span timed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SpanKind {
+    /// A subprocess: git, a forge CLI, a hook's shell
+    Subprocess,
+    /// A stretch of work inside the command
+    Phase,
+}
+
+/// One timed subprocess or phase.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Span {
+    pub kind: SpanKind,
+    /// Command line for subprocesses, label for phases
+    pub name: String,
+    pub dur_us: u64,
+}
+
+/// One command's line in the log.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Entry {
+    /// Unix timestamp when the command finished
+    pub ts: u64,
+    /// Subcommand path, e.g. `list` or `step commit`
+    pub command: String,
+    pub dur_us: u64,
+    pub spans: Vec<Span>,
+}
+
+/// Start recording when `WORKTRUNK_PERF_LOG` is set and we're in a repository.
+pub fn init() {
+    let enabled =
+        std::env::var(PERF_LOG_ENV).is_ok_and(|v| !matches!(v.as_str(), "" | "0" | "false"));
+    if !enabled {
+        return;
+    }
+    let started = Instant::now();
+    let Ok(repo) = crate::git::Repository::current() else {
+        return;
+    };
+    let _ = RECORDER.set(Recorder {
+        started,
+        path: repo.wt_logs_dir().join(PERF_LOG_FILE),
+        spans: Mutex::new(Vec::new()),
+    });
+}
+
+/// Record a span. No-op unless recording.
+pub fn record(kind: SpanKind, name: &str, duration: Duration) {
+    if let Some(recorder) = RECORDER.get()
+        && let Ok(mut spans) = recorder.spans.lock()
+        && spans.len() < MAX_SPANS
+    {
+        spans.push(Span {
+            kind,
+            name: name.to_string(),
+            dur_us: duration.as_micros() as u64,
+        });
+    }
+}
+
+/// Time a phase until the returned guard is dropped.
+pub fn phase(name: impl Into<String>) -> PhaseGuard {
+    PhaseGuard {
+        name: name.into(),
+        started: Instant::now(),
+    }
+}
+
+/// Records its phase when dropped; see [`phase`].
+pub struct PhaseGuard {
+    name: String,
+    started: Instant,
+}
+
+impl Drop for PhaseGuard {
+    fn drop(&mut self) {
+        record(SpanKind::Phase, &self.name, self.started.elapsed());
+    }
+}
+
+/// Append this command's entry to the log.
+///
+/// Errors are ignored: the timing log must never fail a command.
+pub fn finish(command: &str) {
+    let Some(recorder) = RECORDER.get() else {
+        return;
+    };
+    let spans = recorder
+        .spans
+        .lock()
+        .map(|mut spans| std::mem::take(&mut *spans))
+        .unwrap_or_default();
+    let entry = Entry {
+        ts: crate::utils::get_now(),
+        command: command.to_string(),
+        dur_us: recorder.started.elapsed().as_micros() as u64,
+        spans,
+    };
+    let _ = append(&recorder.path, &entry);
+}
+
+fn append(path: &Path, entry: &Entry) -> std::io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let mut lines: Vec<String> = fs::read_to_string(path)
+        .map(|contents| contents.lines().map(str::to_string).collect())
+        .unwrap_or_default();
+    lines.push(serde_json::to_string(entry)?);
+    let keep = lines.len().saturating_sub(MAX_ENTRIES);
+    fs::write(path, lines[keep..].join("\n") + "\n")
+}
+
+/// Read the timing log, skipping lines that don't parse.
+pub fn read_log(path: &Path) -> Vec<Entry> {
+    fs::read_to_string(path)
+        .map(|contents| {
+            contents
+                .lines()
+                .filter_map(|line| serde_json::from_str(line).ok())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_append_keeps_newest_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("wt-logs").join(PERF_LOG_FILE);
+        for i in 0..MAX_ENTRIES + 2 {
+            let entry = Entry {
+                ts: i as u64,
+                command: "list".into(),
+                dur_us: 1000,
+                spans: vec![Span {
+                    kind: SpanKind::Subprocess,
+                    name: "git status".into(),
+                    dur_us: 500,
+                }],
+            };
+            append(&path, &entry).unwrap();
+        }
+        let entries = read_log(&path);
+        assert_eq!(entries.len(), MAX_ENTRIES);
+        assert_eq!(entries[0].ts, 2);
+        assert_eq!(entries[0].spans[0].kind, SpanKind::Subprocess);
+    }
+}
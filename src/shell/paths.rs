@@ -34,6 +34,7 @@ fn parse_nu_config_output(stdout: &[u8]) -> Option<PathBuf> {
 fn query_nu_config_dir() -> Option<PathBuf> {
     let output = crate::shell_exec::Cmd::new("nu")
         .args(["-c", "echo $nu.default-config-dir"])
+        .read_only()
         .run()
         .ok()
         .filter(|o| o.status.success())?;
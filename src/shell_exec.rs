@@ -16,6 +16,7 @@ use std::time::Instant;
 
 use wait_timeout::ChildExt;
 
+use crate::dry_run;
 use crate::git::{GitError, WorktrunkError};
 use crate::sync::Semaphore;
 
@@ -259,6 +260,16 @@ fn log_output(output: &std::process::Output) {
     }
 }
 
+/// Print an executed command and its duration for `-v`.
+fn print_verbose_command(cmd_str: &str, context: Option<&str>, elapsed: std::time::Duration) {
+    let context = context.map(|ctx| format!(" [{ctx}]")).unwrap_or_default();
+    let ms = elapsed.as_millis();
+    crate::styling::eprintln!(
+        "{}",
+        color_print::cformat!("<dim>$ {cmd_str}{context} ({ms}ms)</>")
+    );
+}
+
 /// Implementation of timeout-based command execution.
 ///
 /// Spawns the process, captures stdout/stderr in background threads, and waits with timeout.
@@ -375,6 +386,24 @@ pub struct Cmd {
     stdin_cfg: Option<std::process::Stdio>,
     /// If true, forward signals to child process group (for stream(), Unix only)
     forward_signals: bool,
+    /// Log file that also receives the child's output (for stream())
+    tee: Option<PathBuf>,
+    /// Retry policy for run(): delay before the next attempt, or None to stop
+    retry: Option<RetryPolicy>,
+    /// If true, the command talks to the network and is refused in offline mode
+    network: bool,
+    /// If true, the command changes nothing and also runs under `--dry-run`
+    read_only: bool,
+}
+
+/// When `.run()` retries a failed command.
+#[derive(Clone, Copy)]
+pub struct RetryPolicy {
+    /// Most tries in total, counting the first.
+    pub attempts: fn() -> u32,
+    /// Given the retry count so far and a failed attempt's output, how long
+    /// to wait before trying again — or `None` to return the failure.
+    pub delay: fn(u32, &std::process::Output) -> Option<std::time::Duration>,
 }
 
 impl Cmd {
@@ -396,6 +425,10 @@ impl Cmd {
             stdout_cfg: None,
             stdin_cfg: None,
             forward_signals: false,
+            tee: None,
+            retry: None,
+            network: false,
+            read_only: false,
         }
     }
 
@@ -419,6 +452,10 @@ impl Cmd {
             stdout_cfg: None,
             stdin_cfg: None,
             forward_signals: false,
+            tee: None,
+            retry: None,
+            network: false,
+            read_only: false,
         }
     }
 
@@ -459,15 +496,44 @@ impl Cmd {
         self
     }
 
-    /// Set a timeout for command execution (only applies to `.run()`).
+    /// Set a timeout for command execution.
     ///
-    /// Note: Timeout is not supported by `.stream()` since streaming commands
-    /// are interactive and should not be time-limited.
+    /// `.run()` kills the process and fails with `TimedOut`. `.stream()` kills
+    /// the child (its whole process group with `.forward_signals()`) and fails
+    /// with [`WorktrunkError::ChildProcessTimedOut`].
     pub fn timeout(mut self, duration: std::time::Duration) -> Self {
         self.timeout = Some(duration);
         self
     }
 
+    /// Retry `.run()` when it exits non-zero and `policy` returns a delay.
+    ///
+    /// Each retry is announced with a warning. Spawn errors are not retried.
+    /// Doesn't apply to `.stream()`, whose output has already reached the
+    /// terminal.
+    pub fn retry(mut self, policy: RetryPolicy) -> Self {
+        self.retry = Some(policy);
+        self
+    }
+
+    /// Mark the command as needing the network.
+    ///
+    /// In [offline mode](crate::offline), `run()` returns an error instead of
+    /// spawning it.
+    pub fn network(mut self) -> Self {
+        self.network = true;
+        self
+    }
+
+    /// Mark the command as read-only so it still runs under `--dry-run`.
+    ///
+    /// Only needed for programs [`dry_run::is_read_only`] doesn't recognize;
+    /// under `--dry-run` every other command is printed instead of run.
+    pub fn read_only(mut self) -> Self {
+        self.read_only = true;
+        self
+    }
+
     /// Set an environment variable.
     pub fn env(mut self, key: impl Into<String>, val: impl Into<String>) -> Self {
         self.envs.push((key.into(), val.into()));
@@ -503,7 +569,7 @@ impl Cmd {
         self
     }
 
-    /// Forward signals (SIGINT, SIGTERM) to child process group.
+    /// Forward signals (SIGINT, SIGTERM, SIGHUP, SIGQUIT) to child process group.
     ///
     /// On Unix, spawns the child in its own process group and forwards signals
     /// with escalation (SIGINT → SIGTERM → SIGKILL). This enables clean shutdown
@@ -515,6 +581,18 @@ impl Cmd {
         self
     }
 
+    /// Also append the child's stdout and stderr to a log file.
+    ///
+    /// Both streams are piped through worktrunk and written to stderr as they
+    /// arrive, so the child no longer sees a TTY (and any `.stdout()` setting
+    /// is ignored). The log file and its directory are created on spawn.
+    ///
+    /// Only affects `.stream()`.
+    pub fn tee(mut self, log_path: impl Into<PathBuf>) -> Self {
+        self.tee = Some(log_path.into());
+        self
+    }
+
     /// Execute the command and return its output.
     ///
     /// Captures stdout/stderr and returns them in `Output`. For interactive
@@ -531,6 +609,52 @@ impl Cmd {
             "Cmd::shell() commands must use .stream(), not .run()"
         );
 
+        if self.network && crate::offline::is_offline() {
+            log::debug!("Offline; skipping {} {}", self.program, self.args.join(" "));
+            return Err(crate::offline::refused(&self.program));
+        }
+
+        let mut attempt = 0;
+        loop {
+            let result = self.run_once();
+            if let (Some(policy), Ok(output)) = (self.retry, &result)
+                && !output.status.success()
+                && let Some(delay) = (policy.delay)(attempt, output)
+            {
+                let what = match self.args.first() {
+                    Some(sub) => format!("{} {sub}", self.program),
+                    None => self.program.clone(),
+                };
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                let reason = stderr
+                    .lines()
+                    .rev()
+                    .map(str::trim)
+                    .find(|line| !line.is_empty())
+                    .unwrap_or("no output");
+                crate::ops::warn(
+                    &crate::styling::warning_message(color_print::cformat!(
+                        "<bold>{what}</> failed ({reason}); retrying in {}s ({}/{})",
+                        delay.as_secs_f32(),
+                        attempt + 2,
+                        (policy.attempts)()
+                    ))
+                    .to_string(),
+                );
+                std::thread::sleep(delay);
+                attempt += 1;
+                continue;
+            }
+            return result;
+        }
+    }
+
+    /// Whether the command is known not to change anything (see [`dry_run`]).
+    fn runs_in_dry_run(&self) -> bool {
+        self.read_only || dry_run::is_read_only(&self.program, &self.args)
+    }
+
+    fn run_once(&self) -> std::io::Result<std::process::Output> {
         // Build command string for logging
         let cmd_str = if self.args.is_empty() {
             self.program.clone()
@@ -544,6 +668,11 @@ impl Cmd {
             None => log::debug!("$ {}", cmd_str),
         }
 
+        if dry_run::is_dry_run() && !self.runs_in_dry_run() {
+            dry_run::report(&cmd_str, None);
+            return Ok(dry_run::skipped_output());
+        }
+
         // Acquire semaphore to limit concurrent commands
         let _guard = get_semaphore().acquire();
 
@@ -575,7 +704,7 @@ impl Cmd {
         let effective_timeout = self.timeout.or_else(|| COMMAND_TIMEOUT.with(|t| t.get()));
 
         // Execute with or without stdin
-        let result = if let Some(stdin_data) = self.stdin_data {
+        let result = if let Some(stdin_data) = &self.stdin_data {
             // Stdin piping requires spawn/write/wait
             // Note: stdin path doesn't support timeout (would need async I/O)
             cmd.stdin(Stdio::piped())
@@ -586,7 +715,7 @@ impl Cmd {
 
             // Write stdin data (ignore BrokenPipe - some commands exit early)
             if let Some(mut stdin) = child.stdin.take()
-                && let Err(e) = stdin.write_all(&stdin_data)
+                && let Err(e) = stdin.write_all(stdin_data)
                 && e.kind() != std::io::ErrorKind::BrokenPipe
             {
                 return Err(e);
@@ -601,6 +730,13 @@ impl Cmd {
             cmd.output()
         };
 
+        // `-v` shows each command with its duration; `-vv` has it in the debug log
+        if crate::styling::verbosity() == 1 {
+            print_verbose_command(&cmd_str, self.context.as_deref(), t0.elapsed());
+        }
+
+        crate::perf::record(crate::perf::SpanKind::Subprocess, &cmd_str, t0.elapsed());
+
         // Log trace
         let dur_us = t0.elapsed().as_micros() as u64;
         match (&result, &self.context) {
@@ -661,7 +797,8 @@ impl Cmd {
     /// - Optionally inherits stdin for interactive commands (via `.stdin(Stdio::inherit())`)
     /// - Optionally forwards signals to child process group (via `.forward_signals()`)
     /// - Does not use concurrency limiting (streaming commands run sequentially by nature)
-    /// - Does not support timeout (interactive commands should not be time-limited)
+    /// - Only time-limited when `.timeout()` is set explicitly (the thread-local
+    ///   timeout doesn't apply)
     ///
     /// Shell commands created via `Cmd::shell()` are executed through the platform's
     /// shell (`sh -c` on Unix, Git Bash on Windows).
@@ -670,7 +807,7 @@ impl Cmd {
     pub fn stream(self) -> anyhow::Result<()> {
         #[cfg(unix)]
         use {
-            signal_hook::consts::{SIGINT, SIGTERM},
+            signal_hook::consts::{SIGHUP, SIGINT, SIGQUIT, SIGTERM},
             signal_hook::iterator::Signals,
             std::os::unix::process::CommandExt,
         };
@@ -711,11 +848,26 @@ impl Cmd {
             None => log::debug!("$ {} (streaming, {})", cmd_str, exec_mode),
         }
 
+        // Streamed shell commands are hooks and user commands; they never run
+        if dry_run::is_dry_run() && (self.shell_wrap || !self.runs_in_dry_run()) {
+            dry_run::report(&cmd_str, Some(working_dir));
+            return Ok(());
+        }
+
         #[cfg(not(unix))]
         let _ = self.forward_signals;
 
-        // Determine stdout handling (default: inherit)
-        let stdout_mode = self.stdout_cfg.unwrap_or_else(std::process::Stdio::inherit);
+        let log_file = self.tee.as_deref().map(open_log).transpose()?;
+
+        // Determine stdout handling (default: inherit; piped when teeing)
+        let (stdout_mode, stderr_mode) = if log_file.is_some() {
+            (Stdio::piped(), Stdio::piped())
+        } else {
+            (
+                self.stdout_cfg.unwrap_or_else(Stdio::inherit),
+                Stdio::inherit(), // Preserve TTY for errors
+            )
+        };
 
         // Determine stdin handling (stdin_bytes takes precedence, then stdin cfg, then null)
         let stdin_mode = if self.stdin_data.is_some() {
@@ -726,7 +878,9 @@ impl Cmd {
 
         #[cfg(unix)]
         let mut signals = if self.forward_signals {
-            Some(Signals::new([SIGINT, SIGTERM])?)
+            // SIGHUP too: when the terminal closes, only wt's process group
+            // gets it, and the child's own group would keep running
+            Some(Signals::new([SIGINT, SIGTERM, SIGHUP, SIGQUIT])?)
         } else {
             None
         };
@@ -741,7 +895,7 @@ impl Cmd {
         cmd.current_dir(working_dir)
             .stdin(stdin_mode)
             .stdout(stdout_mode)
-            .stderr(std::process::Stdio::inherit()) // Preserve TTY for errors
+            .stderr(stderr_mode)
             // Prevent vergen "overridden" warning in nested cargo builds
             .env_remove("VERGEN_GIT_DESCRIBE");
 
@@ -756,6 +910,7 @@ impl Cmd {
         // Applied last to ensure it can't be re-added by user-provided envs.
         cmd.env_remove(DIRECTIVE_FILE_ENV_VAR);
 
+        let started = Instant::now();
         let mut child = cmd.spawn().map_err(|e| {
             anyhow::Error::from(GitError::Other {
                 message: format!("Failed to execute command ({}): {}", exec_mode, e),
@@ -772,9 +927,24 @@ impl Cmd {
         }
         // stdin handle is dropped here, closing the pipe
 
-        // Wait for child with optional signal forwarding
+        let copiers = log_file.map(|log| {
+            let log = std::sync::Arc::new(std::sync::Mutex::new(log));
+            let (done_tx, done_rx) = crossbeam_channel::bounded(2);
+            if let Some(out) = child.stdout.take() {
+                spawn_tee(out, log.clone(), done_tx.clone());
+            }
+            if let Some(err) = child.stderr.take() {
+                spawn_tee(err, log, done_tx);
+            }
+            done_rx
+        });
+
+        // Wait for child with optional signal forwarding and timeout
+        #[cfg(unix)]
+        let deadline = self.timeout.map(|timeout| Instant::now() + timeout);
+        let mut timed_out = false;
         #[cfg(unix)]
-        let (status, seen_signal) = if self.forward_signals {
+        let (status, seen_signal) = if self.forward_signals || deadline.is_some() {
             let child_pgid = child.id() as i32;
             let mut seen_signal: Option<i32> = None;
             loop {
@@ -785,6 +955,15 @@ impl Cmd {
                 })? {
                     break (status, seen_signal);
                 }
+                if !timed_out && deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+                    timed_out = true;
+                    if self.forward_signals {
+                        forward_signal_with_escalation(child_pgid, SIGTERM);
+                    } else {
+                        let _ = child.kill();
+                    }
+                    continue;
+                }
                 if let Some(signals) = signals.as_mut() {
                     for sig in signals.pending() {
                         if seen_signal.is_none() {
@@ -805,12 +984,49 @@ impl Cmd {
         };
 
         #[cfg(not(unix))]
-        let status = child.wait().map_err(|e| {
+        let status = match self.timeout {
+            Some(timeout) => match child.wait_timeout(timeout) {
+                Ok(Some(status)) => Ok(status),
+                Ok(None) => {
+                    timed_out = true;
+                    let _ = child.kill();
+                    child.wait()
+                }
+                Err(e) => Err(e),
+            },
+            None => child.wait(),
+        }
+        .map_err(|e| {
             anyhow::Error::from(GitError::Other {
                 message: format!("Failed to wait for command: {}", e),
             })
         })?;
 
+        crate::perf::record(
+            crate::perf::SpanKind::Subprocess,
+            &cmd_str,
+            started.elapsed(),
+        );
+
+        // Let the copiers drain what the child wrote. A background process the
+        // child left running may hold the pipes open indefinitely, so don't
+        // wait on it beyond a short grace period.
+        if let Some(done_rx) = copiers {
+            let deadline = Instant::now() + std::time::Duration::from_millis(500);
+            for _ in 0..2 {
+                if done_rx.recv_deadline(deadline).is_err() {
+                    break;
+                }
+            }
+        }
+
+        if timed_out {
+            return Err(WorktrunkError::ChildProcessTimedOut {
+                timeout: self.timeout.unwrap_or_default(),
+            }
+            .into());
+        }
+
         // Handle signals (Unix only)
         #[cfg(unix)]
         if let Some(sig) = seen_signal {
@@ -843,6 +1059,49 @@ impl Cmd {
     }
 }
 
+/// Open (appending) a `.tee()` log file, creating its directory.
+fn open_log(path: &std::path::Path) -> anyhow::Result<std::fs::File> {
+    let open = || {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+    };
+    open().map_err(|e| {
+        GitError::Other {
+            message: format!("Failed to open log file {}: {e}", path.display()),
+        }
+        .into()
+    })
+}
+
+/// Copy a child's output pipe to stderr and the shared log file until EOF.
+fn spawn_tee(
+    mut pipe: impl Read + Send + 'static,
+    log: std::sync::Arc<std::sync::Mutex<std::fs::File>>,
+    done: crossbeam_channel::Sender<()>,
+) {
+    std::thread::spawn(move || {
+        let mut buf = [0u8; 8192];
+        loop {
+            match pipe.read(&mut buf) {
+                Ok(0) => break,
+                Ok(n) => {
+                    // Best-effort: a full disk shouldn't kill the hook's output
+                    let _ = std::io::stderr().write_all(&buf[..n]);
+                    let _ = log.lock().unwrap().write_all(&buf[..n]);
+                }
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(_) => break,
+            }
+        }
+        let _ = done.send(());
+    });
+}
+
 // ============================================================================
 // Signal forwarding helpers (Unix only)
 // ============================================================================
@@ -868,6 +1127,8 @@ fn forward_signal_with_escalation(pgid: i32, sig: i32) {
     let initial_signal = match sig {
         signal_hook::consts::SIGINT => nix::sys::signal::Signal::SIGINT,
         signal_hook::consts::SIGTERM => nix::sys::signal::Signal::SIGTERM,
+        signal_hook::consts::SIGHUP => nix::sys::signal::Signal::SIGHUP,
+        signal_hook::consts::SIGQUIT => nix::sys::signal::Signal::SIGQUIT,
         _ => return,
     };
 
@@ -883,7 +1144,7 @@ fn forward_signal_with_escalation(pgid: i32, sig: i32) {
             }
         }
     } else {
-        // SIGTERM - escalate directly to SIGKILL
+        // SIGTERM, SIGHUP, SIGQUIT - escalate directly to SIGKILL
         if !wait_for_exit(pgid.as_raw(), grace) {
             let _ = nix::sys::signal::killpg(pgid, nix::sys::signal::Signal::SIGKILL);
         }
@@ -1070,6 +1331,22 @@ mod tests {
         assert_eq!(result.unwrap_err().kind(), std::io::ErrorKind::TimedOut);
     }
 
+    #[test]
+    #[cfg(unix)]
+    fn test_cmd_stream_timeout_kills_process_group() {
+        let started = Instant::now();
+        let err = Cmd::shell("sleep 10 & sleep 10")
+            .timeout(Duration::from_millis(100))
+            .forward_signals()
+            .stream()
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<WorktrunkError>(),
+            Some(WorktrunkError::ChildProcessTimedOut { .. })
+        ));
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
     #[test]
     fn test_cmd_without_timeout_completes() {
         let result = Cmd::new("echo").arg("no timeout").run();
@@ -0,0 +1,513 @@
+//! Per-repository state store.
+//!
+//! Worktrunk metadata that outgrows a git config key — notes, pins, access
+//! history, named groups, branch stacks, `wt start` progress, port blocks,
+//! dev containers, background hook runs, archive records and the undo
+//! journal — lives in one JSON document at `.git/worktrunk/state.json`,
+//! shared by all worktrees.
+//!
+//! Reads see a complete file because writes go through a temp file and a
+//! rename. Read-modify-write cycles hold an exclusive lock on
+//! `.git/worktrunk/state.lock`, so concurrent `wt` processes don't drop each
+//! other's updates.
+//!
+//! The document carries a schema version. Older documents are migrated on
+//! load (and written back in the new shape on the next update); documents
+//! from a newer worktrunk are rejected rather than silently truncated.
+//! Version 0 is the frecency database that predates the store
+//! (`.git/wt-cache/frecency.json`), imported when `state.json` doesn't exist.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, bail};
+use fs2::FileExt;
+use serde::{Deserialize, Serialize};
+
+use crate::config::StartStep;
+use crate::path::format_path_for_display;
+use crate::utils::get_now;
+
+/// Current schema version of `state.json`.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// Background hook runs kept per repository; older ones are dropped.
+const MAX_BACKGROUND_HOOKS: usize = 50;
+
+/// Journal entries kept for `wt undo`; older ones are dropped.
+const MAX_JOURNAL_ENTRIES: usize = 50;
+
+/// First port of the first block handed out by [`State::port_block`].
+pub const PORT_BASE: u16 = 3000;
+
+/// Ports in each branch's block.
+pub const PORT_BLOCK_SIZE: u16 = 10;
+
+/// Contents of `state.json`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct State {
+    pub version: u32,
+    /// Free-form notes, keyed by branch
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub notes: BTreeMap<String, String>,
+    /// Pinned branches
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub pins: BTreeSet<String>,
+    /// Visit history used for frecency ranking, keyed by worktree path
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub access: BTreeMap<PathBuf, AccessEntry>,
+    /// Named sets of branches, for `wt group` and `for-each --group`
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub groups: BTreeMap<String, BTreeSet<String>>,
+    /// Most recent background hook runs, oldest first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub background_hooks: Vec<BackgroundHookRun>,
+    /// Worktrees that were archived rather than removed
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub archives: Vec<ArchiveRecord>,
+    /// Mutating operations, oldest first, for `wt undo`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub journal: Vec<JournalEntry>,
+    /// Commands stopped by a rebase conflict, keyed by branch, so
+    /// `wt resolve` can say how to finish once the rebase completes
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub paused: BTreeMap<String, String>,
+    /// Stacked branches, each mapped to the branch it's built on
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub stacks: BTreeMap<String, String>,
+    /// Steps an unfinished `wt start` already completed, keyed by branch
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub starts: BTreeMap<String, Vec<StartStep>>,
+    /// First port of each branch's port block, keyed by branch
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub ports: BTreeMap<String, u16>,
+    /// Dev container project names, keyed by branch
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub devcontainers: BTreeMap<String, String>,
+    /// Drop command of each branch's database, expanded when the database
+    /// was created (empty without `[database] drop`), keyed by branch
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub databases: BTreeMap<String, String>,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            version: SCHEMA_VERSION,
+            notes: BTreeMap::new(),
+            pins: BTreeSet::new(),
+            access: BTreeMap::new(),
+            groups: BTreeMap::new(),
+            background_hooks: Vec::new(),
+            archives: Vec::new(),
+            journal: Vec::new(),
+            paused: BTreeMap::new(),
+            stacks: BTreeMap::new(),
+            starts: BTreeMap::new(),
+            ports: BTreeMap::new(),
+            devcontainers: BTreeMap::new(),
+            databases: BTreeMap::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AccessEntry {
+    /// Accumulated visit count (decays as the history ages)
+    pub rank: f64,
+    /// Unix timestamp of the most recent visit
+    pub last_accessed: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BackgroundHookRun {
+    pub branch: String,
+    /// Log spec, e.g. `user:post-start:server` (see `wt config state logs`)
+    pub hook: String,
+    pub log: PathBuf,
+    /// Unix timestamp when the hook was spawned
+    pub started_at: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ArchiveRecord {
+    pub branch: String,
+    /// Commit the branch pointed at when archived
+    pub commit: String,
+    /// Where the worktree lived
+    pub path: PathBuf,
+    /// Unix timestamp of the archive
+    pub archived_at: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JournalEntry {
+    /// Unix timestamp of the operation
+    pub at: u64,
+    #[serde(flatten)]
+    pub operation: Operation,
+}
+
+/// A mutating operation, with what's needed to reverse it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+pub enum Operation {
+    /// `wt switch --create` (or switching to a branch without a worktree)
+    WorktreeCreated {
+        branch: String,
+        path: PathBuf,
+        /// HEAD of the new worktree
+        commit: String,
+        /// Whether the branch was created along with the worktree
+        created_branch: bool,
+    },
+    /// `wt remove`; the branch may have been deleted too
+    WorktreeRemoved {
+        /// `None` for a detached worktree
+        branch: Option<String>,
+        path: PathBuf,
+        /// HEAD of the worktree when it was removed
+        commit: String,
+        /// Snapshot of uncommitted changes taken before a forced removal
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        snapshot: Option<String>,
+    },
+    /// A branch moved by rebase, squash, push or backport
+    RefMoved {
+        branch: String,
+        before: String,
+        after: String,
+        /// `rebase`, `squash`, `push` or `backport`
+        operation: String,
+        /// Snapshot of uncommitted changes taken before the branch moved
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        snapshot: Option<String>,
+    },
+}
+
+impl State {
+    /// Append an operation to the undo journal.
+    pub fn record(&mut self, operation: Operation, at: u64) {
+        self.journal.push(JournalEntry { at, operation });
+        let excess = self.journal.len().saturating_sub(MAX_JOURNAL_ENTRIES);
+        self.journal.drain(..excess);
+    }
+
+    /// First port of `branch`'s block of [`PORT_BLOCK_SIZE`] ports.
+    ///
+    /// A branch without a block gets the lowest free one, which it keeps
+    /// until its worktree is removed. `None` once every block is taken.
+    pub fn port_block(&mut self, branch: &str) -> Option<u16> {
+        if let Some(&port) = self.ports.get(branch) {
+            return Some(port);
+        }
+        let taken: BTreeSet<u16> = self.ports.values().copied().collect();
+        let port = (PORT_BASE..=u16::MAX - (PORT_BLOCK_SIZE - 1))
+            .step_by(usize::from(PORT_BLOCK_SIZE))
+            .find(|port| !taken.contains(port))?;
+        self.ports.insert(branch.to_string(), port);
+        Some(port)
+    }
+
+    /// Record a background hook run, replacing an earlier run of the same
+    /// hook on the same branch.
+    pub fn record_background_hook(&mut self, run: BackgroundHookRun) {
+        self.background_hooks
+            .retain(|r| !(r.branch == run.branch && r.hook == run.hook));
+        self.background_hooks.push(run);
+        let excess = self
+            .background_hooks
+            .len()
+            .saturating_sub(MAX_BACKGROUND_HOOKS);
+        self.background_hooks.drain(..excess);
+    }
+}
+
+/// Handle to a repository's `state.json`.
+#[derive(Debug, Clone)]
+pub struct StateStore {
+    dir: PathBuf,
+    legacy_frecency: PathBuf,
+}
+
+impl StateStore {
+    /// Store for the repository whose common git directory is `git_common_dir`.
+    pub fn new(git_common_dir: &Path) -> Self {
+        Self {
+            dir: git_common_dir.join("worktrunk"),
+            legacy_frecency: git_common_dir.join("wt-cache").join("frecency.json"),
+        }
+    }
+
+    /// Path to `state.json`.
+    pub fn path(&self) -> PathBuf {
+        self.dir.join("state.json")
+    }
+
+    /// Directory holding per-run output logs of foreground hooks.
+    pub fn hook_logs_dir(&self) -> PathBuf {
+        self.dir.join("hook-logs")
+    }
+
+    fn lock_path(&self) -> PathBuf {
+        self.dir.join("state.lock")
+    }
+
+    /// Load and migrate the state. A missing file is an empty state.
+    pub fn load(&self) -> anyhow::Result<State> {
+        let path = self.path();
+        let json = match fs::read_to_string(&path) {
+            Ok(json) => json,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                // Best-effort: a corrupt legacy file isn't worth failing over
+                return Ok(fs::read_to_string(&self.legacy_frecency)
+                    .ok()
+                    .and_then(|json| serde_json::from_str(&json).ok())
+                    .and_then(|value| migrate(value).ok())
+                    .unwrap_or_default());
+            }
+            Err(e) => {
+                return Err(e)
+                    .with_context(|| format!("Failed to read {}", format_path_for_display(&path)));
+            }
+        };
+        let value: serde_json::Value = serde_json::from_str(&json)
+            .with_context(|| format!("Failed to parse {}", format_path_for_display(&path)))?;
+        migrate(value).with_context(|| format!("Invalid {}", format_path_for_display(&path)))
+    }
+
+    /// Append `operation` to the undo journal. Best-effort: a failure only
+    /// costs the undo entry.
+    pub fn record(&self, operation: Operation) {
+        if let Err(e) = self.update(|state| state.record(operation, get_now())) {
+            log::debug!("Failed to record operation for undo: {e:#}");
+        }
+    }
+
+    /// Load, mutate and save the state under an exclusive lock.
+    ///
+    /// Other processes calling `update` wait for the lock, so each sees the
+    /// previous update's result. Under `--dry-run` the change isn't saved.
+    pub fn update<T>(&self, mutate: impl FnOnce(&mut State) -> T) -> anyhow::Result<T> {
+        let _lock = self.lock()?;
+        let mut state = self.load()?;
+        let result = mutate(&mut state);
+        if !crate::dry_run::is_dry_run() {
+            self.save(&state)?;
+        }
+        Ok(result)
+    }
+
+    /// Remove `state.json`. Returns whether there was one.
+    pub fn clear(&self) -> anyhow::Result<bool> {
+        let _lock = self.lock()?;
+        let _ = fs::remove_file(&self.legacy_frecency);
+        match fs::remove_file(self.path()) {
+            Ok(()) => Ok(true),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(false),
+            Err(e) => Err(e).context("Failed to remove state file"),
+        }
+    }
+
+    /// Exclusive lock, released when the returned file is dropped.
+    fn lock(&self) -> anyhow::Result<fs::File> {
+        fs::create_dir_all(&self.dir).with_context(|| {
+            format!(
+                "Failed to create state directory {}",
+                format_path_for_display(&self.dir)
+            )
+        })?;
+        let file = fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(self.lock_path())
+            .context("Failed to open state lock file")?;
+        file.lock_exclusive()
+            .context("Failed to acquire state lock")?;
+        Ok(file)
+    }
+
+    /// Write via a temp file and rename so readers never see a partial file.
+    fn save(&self, state: &State) -> anyhow::Result<()> {
+        let path = self.path();
+        let json = serde_json::to_string_pretty(state)?;
+        let temp_path = path.with_extension("json.tmp");
+        fs::write(&temp_path, json)
+            .with_context(|| format!("Failed to write {}", format_path_for_display(&temp_path)))?;
+        #[cfg(windows)]
+        let _ = fs::remove_file(&path);
+        fs::rename(&temp_path, &path)
+            .with_context(|| format!("Failed to write {}", format_path_for_display(&path)))
+    }
+}
+
+/// Upgrade a parsed document to [`SCHEMA_VERSION`].
+///
+/// Each step rewrites the JSON from version N to N+1, so old files go
+/// through every step in order.
+fn migrate(mut value: serde_json::Value) -> anyhow::Result<State> {
+    let Some(object) = value.as_object_mut() else {
+        bail!("expected a JSON object");
+    };
+    // Unversioned documents predate the schema field
+    let mut version = object
+        .get("version")
+        .and_then(serde_json::Value::as_u64)
+        .unwrap_or(0);
+    if version > u64::from(SCHEMA_VERSION) {
+        bail!(
+            "schema version {version} is newer than this worktrunk supports ({SCHEMA_VERSION}); upgrade worktrunk"
+        );
+    }
+    while version < u64::from(SCHEMA_VERSION) {
+        match version {
+            // v0 → v1: frecency.json kept access history in `entries`
+            0 => {
+                if let Some(entries) = object.remove("entries") {
+                    object.entry("access").or_insert(entries);
+                }
+            }
+            _ => unreachable!("no migration from schema version {version}"),
+        }
+        version += 1;
+    }
+    object.insert("version".into(), SCHEMA_VERSION.into());
+    Ok(serde_json::from_value(value)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_file_is_empty_state() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = StateStore::new(dir.path());
+        assert_eq!(store.load().unwrap(), State::default());
+        assert!(!store.clear().unwrap());
+    }
+
+    #[test]
+    fn test_update_round_trips() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = StateStore::new(dir.path());
+        store
+            .update(|state| {
+                state.pins.insert("feature".into());
+                state
+                    .notes
+                    .insert("feature".into(), "waiting on review".into());
+                state
+                    .groups
+                    .entry("backend".into())
+                    .or_default()
+                    .insert("feature".into());
+                state
+                    .starts
+                    .insert("feature".into(), vec![StartStep::Setup]);
+            })
+            .unwrap();
+
+        let state = store.load().unwrap();
+        assert!(state.pins.contains("feature"));
+        assert_eq!(state.starts["feature"], [StartStep::Setup]);
+        assert!(state.groups["backend"].contains("feature"));
+        assert_eq!(state.notes["feature"], "waiting on review");
+        assert!(store.clear().unwrap());
+        assert_eq!(store.load().unwrap(), State::default());
+    }
+
+    #[test]
+    fn test_migrate_unversioned_document() {
+        let state = migrate(serde_json::json!({
+            "entries": {"/repo.feature": {"rank": 2.0, "last_accessed": 10}}
+        }))
+        .unwrap();
+        assert_eq!(state.version, SCHEMA_VERSION);
+        assert_eq!(state.access[Path::new("/repo.feature")].rank, 2.0);
+    }
+
+    #[test]
+    fn test_load_imports_legacy_frecency() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("wt-cache")).unwrap();
+        fs::write(
+            dir.path().join("wt-cache/frecency.json"),
+            r#"{"entries":{"/repo.feature":{"rank":3.0,"last_accessed":5}}}"#,
+        )
+        .unwrap();
+
+        let store = StateStore::new(dir.path());
+        assert_eq!(store.load().unwrap().access.len(), 1);
+        store.update(|_| {}).unwrap();
+        assert!(store.path().exists());
+    }
+
+    #[test]
+    fn test_migrate_rejects_newer_schema() {
+        let err = migrate(serde_json::json!({"version": SCHEMA_VERSION + 1})).unwrap_err();
+        assert!(err.to_string().contains("newer"), "{err}");
+    }
+
+    #[test]
+    fn test_journal_round_trips_and_caps() {
+        let mut state = State::default();
+        for i in 0..=MAX_JOURNAL_ENTRIES as u64 {
+            state.record(
+                Operation::RefMoved {
+                    branch: "feature".into(),
+                    before: format!("{i}"),
+                    after: format!("{}", i + 1),
+                    operation: "rebase".into(),
+                    snapshot: None,
+                },
+                i,
+            );
+        }
+        assert_eq!(state.journal.len(), MAX_JOURNAL_ENTRIES);
+        assert_eq!(state.journal[0].at, 1);
+
+        let json = serde_json::to_value(&state.journal[0]).unwrap();
+        assert_eq!(json["kind"], "ref-moved");
+        let back: JournalEntry = serde_json::from_value(json).unwrap();
+        assert_eq!(back, state.journal[0]);
+    }
+
+    #[test]
+    fn test_port_block_is_stable_and_reuses_freed_blocks() {
+        let mut state = State::default();
+        assert_eq!(state.port_block("a"), Some(PORT_BASE));
+        assert_eq!(state.port_block("b"), Some(PORT_BASE + PORT_BLOCK_SIZE));
+        assert_eq!(state.port_block("a"), Some(PORT_BASE));
+
+        state.ports.remove("a");
+        assert_eq!(state.port_block("c"), Some(PORT_BASE));
+        assert_eq!(state.port_block("b"), Some(PORT_BASE + PORT_BLOCK_SIZE));
+    }
+
+    #[test]
+    fn test_record_background_hook_replaces_and_caps() {
+        let run = |branch: &str, hook: &str, at| BackgroundHookRun {
+            branch: branch.into(),
+            hook: hook.into(),
+            log: PathBuf::from("x.log"),
+            started_at: at,
+        };
+        let mut state = State::default();
+        state.record_background_hook(run("a", "user:post-start:server", 1));
+        state.record_background_hook(run("a", "user:post-start:server", 2));
+        assert_eq!(
+            state.background_hooks,
+            vec![run("a", "user:post-start:server", 2)]
+        );
+
+        for i in 0..MAX_BACKGROUND_HOOKS as u64 {
+            state.record_background_hook(run(&format!("b{i}"), "user:post-start:server", i));
+        }
+        assert_eq!(state.background_hooks.len(), MAX_BACKGROUND_HOOKS);
+        assert_eq!(state.background_hooks[0].branch, "b0");
+    }
+}
@@ -1,56 +1,36 @@
-//! Style constants and symbols for terminal output
+//! Symbols and message formatting for terminal output
 //!
 //! # Styling with color-print
 //!
-//! Use `cformat!` with HTML-like tags for all user-facing messages:
+//! Use `cformat!` with HTML-like tags for emphasis inside user-facing
+//! messages, and a message function for the message's own color and symbol:
 //!
 //! ```
 //! use color_print::cformat;
+//! use worktrunk::styling::success_message;
 //!
-//! // Simple styling
-//! let msg = cformat!("<green>Success message</>");
-//!
-//! // Nested styles - bold inherits green
+//! // Nested styles - bold inherits the success color
 //! let branch = "feature";
-//! let msg = cformat!("<green>Removed branch <bold>{branch}</> successfully</>");
+//! let msg = success_message(cformat!("Removed branch <bold>{branch}</>"));
 //!
-//! // Semantic mapping:
-//! // - Errors: <red>...</>
-//! // - Warnings: <yellow>...</>
-//! // - Hints: <dim>...</>
-//! // - Progress: <cyan>...</>
-//! // - Success: <green>...</>
-//! // - Secondary: <bright-black>...</>
+//! // Emphasis tags carry no meaning of their own:
+//! // - Names: <bold>...</>
+//! // - Commands: <bright-black>...</>
 //! ```
 //!
-//! # anstyle constants
-//!
-//! A few `Style` constants remain for programmatic use with `StyledLine` and
-//! table rendering where computed styles are needed at runtime.
-
-use anstyle::{AnsiColor, Color, Style};
-use color_print::cstr;
-
-// ============================================================================
-// Programmatic Style Constants (for StyledLine, tables, computed styles)
-// ============================================================================
+//! Semantic colors (errors, warnings, hints, progress, success, headings,
+//! diff stats, gutters) come from the active [`Theme`](super::Theme), which
+//! users can override with `[theme]` in user config. Wrap whole messages with
+//! the message functions (`error_message` etc.) rather than writing the color
+//! and symbol by hand, and use `theme().addition` / `theme().deletion` for
+//! computed styles in `StyledLine` and table rendering.
 
-/// Addition style for diffs (green) - used in table rendering
-pub const ADDITION: Style = Style::new().fg_color(Some(Color::Ansi(AnsiColor::Green)));
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
 
-/// Deletion style for diffs (red) - used in table rendering
-pub const DELETION: Style = Style::new().fg_color(Some(Color::Ansi(AnsiColor::Red)));
+use anstyle::Style;
 
-/// Gutter style for quoted content (commands, config, error details)
-///
-/// We wanted the dimmest/most subtle background that works on both dark and light
-/// terminals. BrightWhite was the best we could find among basic ANSI colors, but
-/// we're open to better ideas. Options considered:
-/// - Black/BrightBlack: too dark on light terminals
-/// - Reverse video: just flips which terminal looks good
-/// - 256-color grays: better but not universally supported
-/// - No background: loses the visual separation we want
-pub const GUTTER: Style = Style::new().bg_color(Some(Color::Ansi(AnsiColor::BrightWhite)));
+use super::theme::{paint, theme};
 
 /// Default width for help text rendering when terminal width is unknown.
 /// Used in both the CLI binary and tests for consistent output in docs.
@@ -60,36 +40,122 @@ pub const DEFAULT_HELP_WIDTH: usize = 98;
 // Message Symbols
 // ============================================================================
 //
-// Single-width Unicode symbols for message prefixes with embedded colors.
-// Using `cstr!` to create colored `&'static str` constants that work everywhere.
+// Single-width Unicode symbols for message prefixes. Each renders in the
+// active theme's color, or as a plain ASCII marker when emoji are disabled
+// (`--no-emoji` / `ui.emoji = false`) for terminals and CI logs whose fonts
+// mangle them.
+
+/// Whether message symbols render as ASCII markers.
+static ASCII_SYMBOLS: AtomicBool = AtomicBool::new(false);
+
+/// Use ASCII markers (`ERROR:`, `OK:`) instead of Unicode symbols.
+///
+/// Call this once at startup after parsing CLI arguments and config.
+pub fn set_ascii_symbols(ascii: bool) {
+    ASCII_SYMBOLS.store(ascii, Ordering::Relaxed);
+}
+
+/// Whether ASCII markers are in use (see [`set_ascii_symbols`]).
+pub fn ascii_symbols() -> bool {
+    ASCII_SYMBOLS.load(Ordering::Relaxed)
+}
+
+/// A message symbol. Displays as the styled glyph for the current mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Symbol {
+    Progress,
+    Success,
+    Error,
+    Warning,
+    Hint,
+    Info,
+    Prompt,
+    /// Marks protected branches in `wt list`
+    Protected,
+}
+
+impl Symbol {
+    /// The unstyled glyph: Unicode, or ASCII when emoji are disabled.
+    pub fn glyph(self) -> &'static str {
+        if ascii_symbols() {
+            self.ascii()
+        } else {
+            self.unicode()
+        }
+    }
+
+    fn unicode(self) -> &'static str {
+        match self {
+            Symbol::Progress => "◎",
+            Symbol::Success => "✓",
+            Symbol::Error => "✗",
+            Symbol::Warning => "▲",
+            Symbol::Hint => "↳",
+            Symbol::Info => "○",
+            Symbol::Prompt => "❯",
+            Symbol::Protected => "🛡",
+        }
+    }
+
+    fn ascii(self) -> &'static str {
+        match self {
+            Symbol::Progress => "==>",
+            Symbol::Success => "OK:",
+            Symbol::Error => "ERROR:",
+            Symbol::Warning => "WARNING:",
+            Symbol::Hint => "HINT:",
+            Symbol::Info => "INFO:",
+            Symbol::Prompt => "?",
+            Symbol::Protected => "[protected]",
+        }
+    }
+
+    fn style(self) -> Style {
+        let theme = theme();
+        match self {
+            Symbol::Progress => theme.progress,
+            Symbol::Success => theme.success,
+            Symbol::Error => theme.error,
+            Symbol::Warning => theme.warning,
+            Symbol::Hint => theme.hint,
+            Symbol::Info => theme.info,
+            Symbol::Prompt => theme.prompt,
+            Symbol::Protected => Style::new(),
+        }
+    }
+}
+
+impl fmt::Display for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&paint(self.style(), self.glyph()))
+    }
+}
 
 /// Progress symbol (cyan ◎)
-pub const PROGRESS_SYMBOL: &str = cstr!("<cyan>◎</>");
+pub const PROGRESS_SYMBOL: Symbol = Symbol::Progress;
 
 /// Success symbol (green ✓)
-pub const SUCCESS_SYMBOL: &str = cstr!("<green>✓</>");
+pub const SUCCESS_SYMBOL: Symbol = Symbol::Success;
 
 /// Error symbol (red ✗)
-pub const ERROR_SYMBOL: &str = cstr!("<red>✗</>");
+pub const ERROR_SYMBOL: Symbol = Symbol::Error;
 
 /// Warning symbol (yellow ▲)
-pub const WARNING_SYMBOL: &str = cstr!("<yellow>▲</>");
+pub const WARNING_SYMBOL: Symbol = Symbol::Warning;
 
 /// Hint symbol (dim ↳)
-pub const HINT_SYMBOL: &str = cstr!("<dim>↳</>");
+pub const HINT_SYMBOL: Symbol = Symbol::Hint;
 
 /// Info symbol (dim ○) - for neutral status
-pub const INFO_SYMBOL: &str = cstr!("<dim>○</>");
+pub const INFO_SYMBOL: Symbol = Symbol::Info;
 
 /// Prompt symbol (cyan ❯) - for questions requiring user input
-pub const PROMPT_SYMBOL: &str = cstr!("<cyan>❯</>");
+pub const PROMPT_SYMBOL: Symbol = Symbol::Prompt;
 
 // ============================================================================
 // Formatted Message Type
 // ============================================================================
 
-use std::fmt;
-
 /// A message that has already been formatted with emoji and styling.
 ///
 /// This type provides compile-time prevention of double-formatting. Message
@@ -164,7 +230,10 @@ impl From<FormattedMessage> for String {
 // All functions take `impl AsRef<str>` (which FormattedMessage does NOT
 // implement) and return `FormattedMessage`, preventing double-formatting.
 
-use color_print::cformat;
+/// Symbol and text in the same style, separated by a space.
+fn themed(symbol: Symbol, content: &str) -> FormattedMessage {
+    FormattedMessage(format!("{symbol} {}", paint(symbol.style(), content)))
+}
 
 /// Format an error message with symbol and red styling
 ///
@@ -177,27 +246,27 @@ use color_print::cformat;
 /// println!("{}", error_message(cformat!("Branch <bold>{name}</> not found")));
 /// ```
 pub fn error_message(content: impl AsRef<str>) -> FormattedMessage {
-    FormattedMessage(cformat!("{ERROR_SYMBOL} <red>{}</>", content.as_ref()))
+    themed(Symbol::Error, content.as_ref())
 }
 
 /// Format a hint message with symbol and dim styling
 pub fn hint_message(content: impl AsRef<str>) -> FormattedMessage {
-    FormattedMessage(cformat!("{HINT_SYMBOL} <dim>{}</>", content.as_ref()))
+    themed(Symbol::Hint, content.as_ref())
 }
 
 /// Format a warning message with symbol and yellow styling
 pub fn warning_message(content: impl AsRef<str>) -> FormattedMessage {
-    FormattedMessage(cformat!("{WARNING_SYMBOL} <yellow>{}</>", content.as_ref()))
+    themed(Symbol::Warning, content.as_ref())
 }
 
 /// Format a success message with symbol and green styling
 pub fn success_message(content: impl AsRef<str>) -> FormattedMessage {
-    FormattedMessage(cformat!("{SUCCESS_SYMBOL} <green>{}</>", content.as_ref()))
+    themed(Symbol::Success, content.as_ref())
 }
 
 /// Format a progress message with symbol and cyan styling
 pub fn progress_message(content: impl AsRef<str>) -> FormattedMessage {
-    FormattedMessage(cformat!("{PROGRESS_SYMBOL} <cyan>{}</>", content.as_ref()))
+    themed(Symbol::Progress, content.as_ref())
 }
 
 /// Format an info message with symbol (no color on text - neutral status)
@@ -207,10 +276,10 @@ pub fn info_message(content: impl AsRef<str>) -> FormattedMessage {
 
 /// Format a prompt message with symbol and cyan styling
 pub fn prompt_message(content: impl AsRef<str>) -> FormattedMessage {
-    FormattedMessage(cformat!("{PROMPT_SYMBOL} <cyan>{}</>", content.as_ref()))
+    themed(Symbol::Prompt, content.as_ref())
 }
 
-/// Format a section heading (cyan uppercase text, no emoji)
+/// Format a section heading (uppercase text in the theme's heading color, no emoji)
 ///
 /// Used for organizing output into distinct sections. Headings can have
 /// optional suffix info (e.g., path, location).
@@ -228,57 +297,52 @@ pub fn prompt_message(content: impl AsRef<str>) -> FormattedMessage {
 /// ```
 pub fn format_heading(title: &str, suffix: Option<&str>) -> String {
     match suffix {
-        Some(s) => cformat!("<cyan>{}</>  {}", title, s),
-        None => cformat!("<cyan>{}</>", title),
+        Some(s) => format!("{}  {s}", paint(theme().heading, title)),
+        None => paint(theme().heading, title),
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use color_print::cformat;
 
     // ============================================================================
-    // Style Constants Tests
+    // Symbol Constants Tests
     // ============================================================================
 
     #[test]
-    fn test_addition_style() {
-        // ADDITION should be green foreground
-        let rendered = ADDITION.render().to_string();
-        // Green is ANSI 32
-        assert!(rendered.contains("32"));
-    }
-
-    #[test]
-    fn test_deletion_style() {
-        // DELETION should be red foreground
-        let rendered = DELETION.render().to_string();
-        // Red is ANSI 31
-        assert!(rendered.contains("31"));
+    fn test_symbol_constants() {
+        // Symbols render with ANSI codes around the Unicode character
+        assert!(PROGRESS_SYMBOL.to_string().contains("◎"));
+        assert!(SUCCESS_SYMBOL.to_string().contains("✓"));
+        assert!(ERROR_SYMBOL.to_string().contains("✗"));
+        assert!(WARNING_SYMBOL.to_string().contains("▲"));
+        assert!(HINT_SYMBOL.to_string().contains("↳"));
+        assert!(INFO_SYMBOL.to_string().contains("○"));
+        assert!(PROMPT_SYMBOL.to_string().contains("❯"));
     }
 
     #[test]
-    fn test_gutter_style() {
-        // GUTTER should have bright white background
-        let rendered = GUTTER.render().to_string();
-        // BrightWhite background is ANSI 107
-        assert!(rendered.contains("107"));
-    }
-
-    // ============================================================================
-    // Symbol Constants Tests
-    // ============================================================================
-
-    #[test]
-    fn test_symbol_constants() {
-        // Symbols are pre-colored with ANSI codes, but contain the Unicode character
-        assert!(PROGRESS_SYMBOL.contains("◎"));
-        assert!(SUCCESS_SYMBOL.contains("✓"));
-        assert!(ERROR_SYMBOL.contains("✗"));
-        assert!(WARNING_SYMBOL.contains("▲"));
-        assert!(HINT_SYMBOL.contains("↳"));
-        assert!(INFO_SYMBOL.contains("○"));
-        assert!(PROMPT_SYMBOL.contains("❯"));
+    fn test_ascii_symbols() {
+        // Tests share the global mode, so check the ASCII table directly
+        assert_eq!(Symbol::Error.ascii(), "ERROR:");
+        assert_eq!(Symbol::Success.ascii(), "OK:");
+        assert_eq!(Symbol::Protected.ascii(), "[protected]");
+        assert!(
+            [
+                Symbol::Progress,
+                Symbol::Success,
+                Symbol::Error,
+                Symbol::Warning,
+                Symbol::Hint,
+                Symbol::Info,
+                Symbol::Prompt,
+                Symbol::Protected,
+            ]
+            .iter()
+            .all(|s| s.ascii().is_ascii())
+        );
     }
 
     // ============================================================================
@@ -288,7 +352,7 @@ mod tests {
     #[test]
     fn test_error_message() {
         let msg = error_message("Something went wrong");
-        assert!(msg.as_str().contains(ERROR_SYMBOL));
+        assert!(msg.as_str().contains(&ERROR_SYMBOL.to_string()));
         assert!(msg.as_str().contains("Something went wrong"));
     }
 
@@ -296,7 +360,7 @@ mod tests {
     fn test_error_message_with_inner_styling() {
         let name = "feature";
         let msg = error_message(cformat!("Branch <bold>{name}</> not found"));
-        assert!(msg.as_str().contains(ERROR_SYMBOL));
+        assert!(msg.as_str().contains(&ERROR_SYMBOL.to_string()));
         assert!(msg.as_str().contains("Branch"));
         assert!(msg.as_str().contains("feature"));
     }
@@ -304,42 +368,42 @@ mod tests {
     #[test]
     fn test_hint_message() {
         let msg = hint_message("Try running --help");
-        assert!(msg.as_str().contains(HINT_SYMBOL));
+        assert!(msg.as_str().contains(&HINT_SYMBOL.to_string()));
         assert!(msg.as_str().contains("Try running --help"));
     }
 
     #[test]
     fn test_warning_message() {
         let msg = warning_message("Deprecated option");
-        assert!(msg.as_str().contains(WARNING_SYMBOL));
+        assert!(msg.as_str().contains(&WARNING_SYMBOL.to_string()));
         assert!(msg.as_str().contains("Deprecated option"));
     }
 
     #[test]
     fn test_success_message() {
         let msg = success_message("Operation completed");
-        assert!(msg.as_str().contains(SUCCESS_SYMBOL));
+        assert!(msg.as_str().contains(&SUCCESS_SYMBOL.to_string()));
         assert!(msg.as_str().contains("Operation completed"));
     }
 
     #[test]
     fn test_progress_message() {
         let msg = progress_message("Loading data...");
-        assert!(msg.as_str().contains(PROGRESS_SYMBOL));
+        assert!(msg.as_str().contains(&PROGRESS_SYMBOL.to_string()));
         assert!(msg.as_str().contains("Loading data..."));
     }
 
     #[test]
     fn test_info_message() {
         let msg = info_message("5 items found");
-        assert!(msg.as_str().contains(INFO_SYMBOL));
+        assert!(msg.as_str().contains(&INFO_SYMBOL.to_string()));
         assert!(msg.as_str().contains("5 items found"));
     }
 
     #[test]
     fn test_prompt_message() {
         let msg = prompt_message("Continue?");
-        assert!(msg.as_str().contains(PROMPT_SYMBOL));
+        assert!(msg.as_str().contains(&PROMPT_SYMBOL.to_string()));
         assert!(msg.as_str().contains("Continue?"));
     }
 
@@ -379,7 +443,7 @@ mod tests {
     fn test_formatted_message_into_inner() {
         let msg = success_message("Done");
         let inner: String = msg.into_inner();
-        assert!(inner.contains(SUCCESS_SYMBOL));
+        assert!(inner.contains(&SUCCESS_SYMBOL.to_string()));
         assert!(inner.contains("Done"));
     }
 }
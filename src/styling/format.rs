@@ -111,7 +111,7 @@ pub(super) fn wrap_text_at_width(text: &str, max_width: usize) -> Vec<String> {
 /// print!("{}", format_with_gutter("hello world", Some(80)));
 /// ```
 pub fn format_with_gutter(content: &str, max_width: Option<usize>) -> String {
-    let gutter = super::GUTTER;
+    let gutter = super::theme().gutter;
 
     // Use provided width or detect terminal width (respects COLUMNS env var)
     let term_width = max_width.unwrap_or_else(get_terminal_width);
@@ -249,7 +249,7 @@ fn format_bash_with_gutter_impl(content: &str, width_override: Option<usize>) ->
         .replace("}}", &format!("{TPL_CLOSE} "));
     let content = normalized.as_str();
 
-    let gutter = super::GUTTER;
+    let gutter = super::theme().gutter;
     let reset = anstyle::Reset;
     let dim = anstyle::Style::new().dimmed();
     let string_style = bash_token_style("string").unwrap_or(dim);
@@ -93,7 +93,7 @@ pub(super) fn bash_token_style(kind: &str) -> Option<Style> {
 pub fn format_toml(content: &str) -> String {
     // synoptic has built-in TOML support, so this always succeeds
     let mut highlighter = from_extension("toml", 4).expect("synoptic supports TOML");
-    let gutter = super::GUTTER;
+    let gutter = super::theme().gutter;
     let lines: Vec<String> = content.lines().map(|s| s.to_string()).collect();
 
     // Process all lines through the highlighter
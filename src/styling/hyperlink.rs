@@ -1,9 +1,53 @@
 //! OSC 8 hyperlink support for terminal output.
+//!
+//! Detection comes from `supports-hyperlinks` (which honors `FORCE_HYPERLINK`);
+//! `[ui] hyperlinks = false` turns links off regardless.
+
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 use osc8::Hyperlink;
+use path_slash::PathExt as _;
 
 // Re-export for direct use
-pub use supports_hyperlinks::{Stream, on as supports_hyperlinks};
+pub use supports_hyperlinks::Stream;
+
+/// Global hyperlink switch, set at startup from `[ui] hyperlinks`.
+static HYPERLINKS: AtomicBool = AtomicBool::new(true);
+
+/// Enable or disable hyperlinks.
+///
+/// Call this once at startup, before any output.
+pub fn set_hyperlinks(enabled: bool) {
+    HYPERLINKS.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether to emit hyperlinks on `stream`: enabled and supported by the terminal.
+pub fn supports_hyperlinks(stream: Stream) -> bool {
+    HYPERLINKS.load(Ordering::Relaxed) && supports_hyperlinks::on(stream)
+}
+
+/// `file://` URL for a local path, percent-encoding characters that would end
+/// or confuse the URL.
+pub fn file_url(path: &Path) -> String {
+    let mut url = String::from("file://");
+    let path = path.to_slash_lossy();
+    if !path.starts_with('/') {
+        // Windows drive paths: file:///C:/...
+        url.push('/');
+    }
+    for c in path.chars() {
+        match c {
+            ' ' => url.push_str("%20"),
+            '%' => url.push_str("%25"),
+            '#' => url.push_str("%23"),
+            '?' => url.push_str("%3F"),
+            c if c.is_control() => url.push_str(&format!("%{:02X}", c as u32)),
+            c => url.push(c),
+        }
+    }
+    url
+}
 
 /// Format text as a clickable hyperlink for stdout, or return plain text if unsupported.
 pub fn hyperlink_stdout(url: &str, text: &str) -> String {
@@ -65,6 +109,23 @@ mod tests {
         assert!(result == "link" || result.contains("https://example.com"));
     }
 
+    #[test]
+    fn test_hyperlinks_off_switch() {
+        set_hyperlinks(false);
+        assert!(!supports_hyperlinks(Stream::Stdout));
+        assert_eq!(hyperlink_stdout("https://example.com", "link"), "link");
+        set_hyperlinks(true);
+    }
+
+    #[test]
+    #[cfg(not(windows))]
+    fn test_file_url() {
+        assert_eq!(
+            file_url(Path::new("/code/my repo#1")),
+            "file:///code/my%20repo%231"
+        );
+    }
+
     #[test]
     fn test_strip_osc8_hyperlinks_removes_hyperlink() {
         // OSC 8 format: ESC ] 8 ; params ; URL ST TEXT ESC ] 8 ; ; ST
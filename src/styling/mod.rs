@@ -18,13 +18,17 @@ mod format;
 mod highlighting;
 mod hyperlink;
 mod line;
+mod quiet;
 mod suggest;
+mod theme;
 
 use ansi_str::AnsiStr;
 use unicode_width::UnicodeWidthStr;
 
 // Re-exports from anstream (auto-detecting output)
-pub use anstream::{eprint, eprintln, print, println, stderr, stdout};
+pub use anstream::{print, println, stderr, stdout};
+// Status output to stderr goes through the quiet filter
+pub use crate::{eprint, eprintln};
 
 // Re-exports from anstyle (for composition)
 pub use anstyle::Style as AnstyleStyle;
@@ -35,9 +39,15 @@ pub use constants::*;
 pub(crate) use format::format_bash_with_gutter_at_width;
 pub use format::{GUTTER_OVERHEAD, format_bash_with_gutter, format_with_gutter, wrap_styled_text};
 pub use highlighting::format_toml;
-pub use hyperlink::{Stream, hyperlink_stdout, strip_osc8_hyperlinks, supports_hyperlinks};
+pub use hyperlink::{
+    Stream, file_url, hyperlink_stdout, set_hyperlinks, strip_osc8_hyperlinks, supports_hyperlinks,
+};
 pub use line::{StyledLine, StyledString, truncate_visible};
+#[doc(hidden)]
+pub use quiet::write_status;
+pub use quiet::{is_quiet, set_quiet};
 pub use suggest::suggest_command;
+pub use theme::{Theme, paint, parse_style, set_theme, theme};
 
 // ============================================================================
 // Verbosity
@@ -47,6 +57,8 @@ use std::sync::atomic::{AtomicU8, Ordering};
 
 /// Global verbosity level, set at startup.
 /// 0 = normal, 1 = verbose (-v), 2+ = debug (-vv)
+///
+/// Quiet mode (`-q`) is tracked separately; see [`is_quiet`].
 static VERBOSITY: AtomicU8 = AtomicU8::new(0);
 
 /// Set the global verbosity level.
@@ -59,12 +71,60 @@ pub fn set_verbosity(level: u8) {
 /// Get the current verbosity level.
 ///
 /// - 0: normal (no verbose output)
-/// - 1: verbose (`-v`) - nice styled output for templates, etc.
+/// - 1: verbose (`-v`) - git commands with timing, styled output for templates, etc.
 /// - 2+: debug (`-vv`) - full debug logging
 pub fn verbosity() -> u8 {
     VERBOSITY.load(Ordering::Relaxed)
 }
 
+// ============================================================================
+// Color
+// ============================================================================
+
+/// Set when output is colored, from `--color`.
+///
+/// Applies to stdout and stderr alike, since anstream consults the choice on
+/// every write. `Auto` keeps the usual detection: `NO_COLOR`, then
+/// `CLICOLOR_FORCE`, then whether the stream is a terminal.
+pub fn set_color_choice(choice: anstream::ColorChoice) {
+    choice.write_global();
+}
+
+/// Whether output written to `stream` keeps its colors.
+///
+/// For output that bypasses anstream: the progressive `wt list` table, the
+/// help pager, and requests to `wt daemon`.
+pub fn colors_enabled(stream: Stream) -> bool {
+    let choice = if matches!(stream, Stream::Stdout) {
+        anstream::AutoStream::choice(&std::io::stdout())
+    } else {
+        anstream::AutoStream::choice(&std::io::stderr())
+    };
+    choice != anstream::ColorChoice::Never
+}
+
+/// Whether `--color=never` was passed.
+///
+/// Prompt and statusline output is captured by shells and tools that want ANSI
+/// codes although stdout isn't a terminal, so it's plain only on request.
+pub fn color_never() -> bool {
+    anstream::ColorChoice::global() == anstream::ColorChoice::Never
+}
+
+/// Print `value` to stdout as pretty-printed JSON, never styled.
+///
+/// JSON is for programs, so it skips anstream and `--color=always` can't
+/// reach it; serde escapes any control characters inside string values.
+pub fn print_json<T: serde::Serialize + ?Sized>(value: &T) -> anyhow::Result<()> {
+    use std::io::Write;
+
+    let json = serde_json::to_string_pretty(value)?;
+    let mut stdout = std::io::stdout().lock();
+    writeln!(stdout, "{json}")?;
+    stdout.flush()?;
+    Ok(())
+}
+
 /// Get terminal width, or `usize::MAX` if detection fails.
 ///
 /// Prefers direct terminal size detection over COLUMNS environment variable,
@@ -0,0 +1,129 @@
+//! Quiet mode (`-q`): status output reduced to errors.
+//!
+//! Status messages all go through this module's `eprint!`/`eprintln!`, which
+//! replace anstream's. In quiet mode, lines starting with a success,
+//! progress, info, hint, or warning symbol are dropped, along with indented
+//! continuation lines (gutter blocks) that follow them and blank separator
+//! lines. Errors, prompts, and their details pass through unchanged.
+//!
+//! Filtering by symbol keeps call sites unchanged: code keeps writing
+//! `eprintln!("{}", success_message(..))` and the decision is made here.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use ansi_str::AnsiStr;
+
+use super::Symbol;
+
+/// Global quiet flag, set at startup.
+static QUIET: AtomicBool = AtomicBool::new(false);
+
+/// Whether the last line written was dropped, so its continuation lines are too.
+static SUPPRESSING: AtomicBool = AtomicBool::new(false);
+
+/// Symbols of messages dropped in quiet mode.
+const SUPPRESSED_SYMBOLS: [Symbol; 5] = [
+    Symbol::Success,
+    Symbol::Progress,
+    Symbol::Info,
+    Symbol::Hint,
+    Symbol::Warning,
+];
+
+/// Set quiet mode.
+///
+/// Call this once at startup after parsing CLI arguments.
+pub fn set_quiet(quiet: bool) {
+    QUIET.store(quiet, Ordering::Relaxed);
+}
+
+/// Whether quiet mode (`-q`) is active.
+pub fn is_quiet() -> bool {
+    QUIET.load(Ordering::Relaxed)
+}
+
+/// Write status text to stderr, applying the quiet filter. Used by `eprint!`/`eprintln!`.
+#[doc(hidden)]
+pub fn write_status(text: String) {
+    if !is_quiet() {
+        anstream::eprint!("{text}");
+        return;
+    }
+    let kept = filter_quiet(&text);
+    if !kept.is_empty() {
+        anstream::eprint!("{kept}");
+    }
+}
+
+/// Drop the lines of `text` that quiet mode hides.
+fn filter_quiet(text: &str) -> String {
+    text.split_inclusive('\n')
+        .filter(|line| {
+            let plain = line.ansi_strip();
+            if plain.trim().is_empty() {
+                return false;
+            }
+            if plain.starts_with(char::is_whitespace) {
+                return !SUPPRESSING.load(Ordering::Relaxed);
+            }
+            // Match the glyph as rendered, so ASCII markers are filtered too
+            let suppress = SUPPRESSED_SYMBOLS
+                .iter()
+                .any(|symbol| plain.starts_with(symbol.glyph()));
+            SUPPRESSING.store(suppress, Ordering::Relaxed);
+            !suppress
+        })
+        .collect()
+}
+
+/// Print status output to stderr, honoring quiet mode. Replaces `anstream::eprint!`.
+#[macro_export]
+macro_rules! eprint {
+    ($($arg:tt)*) => {
+        $crate::styling::write_status(::std::format!($($arg)*))
+    };
+}
+
+/// Print a status line to stderr, honoring quiet mode. Replaces `anstream::eprintln!`.
+#[macro_export]
+macro_rules! eprintln {
+    () => {
+        $crate::styling::write_status(::std::string::String::from("\n"))
+    };
+    ($($arg:tt)*) => {
+        $crate::styling::write_status(::std::format!("{}\n", ::std::format_args!($($arg)*)))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::styling::{
+        error_message, format_with_gutter, hint_message, success_message, warning_message,
+    };
+
+    #[test]
+    fn test_filter_quiet() {
+        let error = format!("{}\n", error_message("Branch not found"));
+        assert_eq!(filter_quiet(&error), error);
+
+        let success = format!("{}\n", success_message("Created worktree"));
+        assert_eq!(filter_quiet(&success), "");
+        assert_eq!(filter_quiet(&format!("{}\n", warning_message("Dirty"))), "");
+        assert_eq!(filter_quiet("\n"), "");
+
+        // An error with its hint keeps only the error
+        let with_hint = format!("{}\n{}\n", error_message("Failed"), hint_message("Retry"));
+        assert_eq!(
+            filter_quiet(&with_hint),
+            format!("{}\n", error_message("Failed"))
+        );
+
+        // Gutter blocks follow the message they belong to
+        let gutter = format!("{}\n", format_with_gutter("npm install", Some(80)));
+        filter_quiet(&success);
+        assert_eq!(filter_quiet(&gutter), "");
+        filter_quiet(&error);
+        assert_eq!(filter_quiet(&gutter), gutter);
+    }
+}
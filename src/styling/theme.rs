@@ -0,0 +1,260 @@
+//! Output colors, overridable from user config.
+//!
+//! The theme is the single source of semantic colors: message functions,
+//! headings, diff stats, and gutters all read [`theme()`], so a `[theme]`
+//! preset or override applies to every command. Literal `cformat!` colors are
+//! for emphasis that carries no meaning of its own (`<bold>`, `<bright-black>`
+//! commands).
+//!
+//! The default theme matches the `cformat!` tags used before themes existed,
+//! down to the reset codes, so output is byte-identical unless the user
+//! configures `[theme]`. The light preset replaces dim and cyan, which are
+//! hard to read on light backgrounds.
+//!
+//! Style strings are space-separated words: a color name (`red`,
+//! `bright_red`, `bright-black`), an ANSI 256 index (`208`), or a hex color
+//! (`#ff8800`), plus effects (`bold`, `dim`, `italic`, `underline`). A word
+//! prefixed with `on_` sets the background: `"white on_red bold"`.
+
+use std::sync::OnceLock;
+
+use anstyle::{AnsiColor, Color, Effects, Style};
+
+/// Styles for each message type and for the non-message output that shares
+/// their palette. Message styles apply to the symbol and text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    pub error: Style,
+    pub warning: Style,
+    pub hint: Style,
+    pub success: Style,
+    pub progress: Style,
+    /// Info messages style the symbol only; their text is left neutral.
+    pub info: Style,
+    pub prompt: Style,
+    /// Section headings (`format_heading`)
+    pub heading: Style,
+    /// Added lines in diff stats (`+12`)
+    pub addition: Style,
+    /// Deleted lines in diff stats (`-3`)
+    pub deletion: Style,
+    /// Background of the gutter beside quoted content (commands, config,
+    /// error details).
+    ///
+    /// We wanted the dimmest/most subtle background that works on both dark
+    /// and light terminals. BrightWhite was the best we could find among basic
+    /// ANSI colors, but we're open to better ideas. Options considered:
+    /// - Black/BrightBlack: too dark on light terminals
+    /// - Reverse video: just flips which terminal looks good
+    /// - 256-color grays: better but not universally supported
+    /// - No background: loses the visual separation we want
+    pub gutter: Style,
+}
+
+const fn fg(color: AnsiColor) -> Style {
+    Style::new().fg_color(Some(Color::Ansi(color)))
+}
+
+const fn bg(color: AnsiColor) -> Style {
+    Style::new().bg_color(Some(Color::Ansi(color)))
+}
+
+impl Theme {
+    /// Colors for dark terminal backgrounds (the default).
+    pub const fn dark() -> Self {
+        Self {
+            error: fg(AnsiColor::Red),
+            warning: fg(AnsiColor::Yellow),
+            hint: Style::new().dimmed(),
+            success: fg(AnsiColor::Green),
+            progress: fg(AnsiColor::Cyan),
+            info: Style::new().dimmed(),
+            prompt: fg(AnsiColor::Cyan),
+            heading: fg(AnsiColor::Cyan),
+            addition: fg(AnsiColor::Green),
+            deletion: fg(AnsiColor::Red),
+            gutter: bg(AnsiColor::BrightWhite),
+        }
+    }
+
+    /// Colors for light terminal backgrounds: no dim, and darker hues than
+    /// yellow and cyan.
+    pub const fn light() -> Self {
+        Self {
+            error: fg(AnsiColor::Red),
+            warning: fg(AnsiColor::Magenta),
+            hint: fg(AnsiColor::Blue),
+            success: fg(AnsiColor::Green),
+            progress: fg(AnsiColor::Blue),
+            info: fg(AnsiColor::BrightBlack),
+            prompt: fg(AnsiColor::Blue),
+            heading: fg(AnsiColor::Blue),
+            addition: fg(AnsiColor::Green),
+            deletion: fg(AnsiColor::Red),
+            gutter: bg(AnsiColor::BrightWhite),
+        }
+    }
+
+    /// Look up a preset by name.
+    pub fn preset(name: &str) -> Option<Self> {
+        match name {
+            "dark" => Some(Self::dark()),
+            "light" => Some(Self::light()),
+            _ => None,
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+static THEME: OnceLock<Theme> = OnceLock::new();
+
+/// Set the theme used for all styled output.
+///
+/// Call this once at startup; later calls are ignored.
+pub fn set_theme(theme: Theme) {
+    let _ = THEME.set(theme);
+}
+
+/// The active theme (the dark default unless [`set_theme`] was called).
+pub fn theme() -> &'static Theme {
+    THEME.get_or_init(Theme::default)
+}
+
+/// Wrap `text` in `style`, closing with targeted resets rather than a full
+/// reset so styling around `text` (e.g. from `cformat!`) is preserved.
+pub fn paint(style: Style, text: &str) -> String {
+    if style == Style::new() {
+        return text.to_string();
+    }
+    let mut reset = String::new();
+    let effects = style.get_effects();
+    if effects.contains(Effects::BOLD) || effects.contains(Effects::DIMMED) {
+        reset.push_str("\x1b[22m");
+    }
+    if effects.contains(Effects::ITALIC) {
+        reset.push_str("\x1b[23m");
+    }
+    if effects.contains(Effects::UNDERLINE) {
+        reset.push_str("\x1b[24m");
+    }
+    if style.get_fg_color().is_some() {
+        reset.push_str("\x1b[39m");
+    }
+    if style.get_bg_color().is_some() {
+        reset.push_str("\x1b[49m");
+    }
+    format!("{}{text}{reset}", style.render())
+}
+
+/// Parse a style string such as `"bright_red bold"` or `"#0066cc"`.
+pub fn parse_style(spec: &str) -> Result<Style, String> {
+    let mut style = Style::new();
+    for word in spec.split_whitespace() {
+        let word = word.to_ascii_lowercase().replace('-', "_");
+        style = match word.as_str() {
+            "bold" => style.bold(),
+            "dim" | "dimmed" => style.dimmed(),
+            "italic" => style.italic(),
+            "underline" => style.underline(),
+            "none" | "default" => style,
+            _ => match word.strip_prefix("on_") {
+                Some(bg) => style.bg_color(Some(parse_color(bg)?)),
+                None => style.fg_color(Some(parse_color(&word)?)),
+            },
+        };
+    }
+    Ok(style)
+}
+
+fn parse_color(word: &str) -> Result<Color, String> {
+    if let Some(hex) = word.strip_prefix('#') {
+        let channel = |i: usize| {
+            hex.get(i..i + 2)
+                .and_then(|c| u8::from_str_radix(c, 16).ok())
+        };
+        return match (hex.len(), channel(0), channel(2), channel(4)) {
+            (6, Some(r), Some(g), Some(b)) => Ok(Color::Rgb(anstyle::RgbColor(r, g, b))),
+            _ => Err(format!("invalid hex color `#{hex}`")),
+        };
+    }
+    if let Ok(index) = word.parse::<u8>() {
+        return Ok(Color::Ansi256(anstyle::Ansi256Color(index)));
+    }
+    let color = match word {
+        "black" => AnsiColor::Black,
+        "red" => AnsiColor::Red,
+        "green" => AnsiColor::Green,
+        "yellow" => AnsiColor::Yellow,
+        "blue" => AnsiColor::Blue,
+        "magenta" => AnsiColor::Magenta,
+        "cyan" => AnsiColor::Cyan,
+        "white" => AnsiColor::White,
+        "bright_black" | "gray" | "grey" => AnsiColor::BrightBlack,
+        "bright_red" => AnsiColor::BrightRed,
+        "bright_green" => AnsiColor::BrightGreen,
+        "bright_yellow" => AnsiColor::BrightYellow,
+        "bright_blue" => AnsiColor::BrightBlue,
+        "bright_magenta" => AnsiColor::BrightMagenta,
+        "bright_cyan" => AnsiColor::BrightCyan,
+        "bright_white" => AnsiColor::BrightWhite,
+        _ => return Err(format!("unknown color or effect `{word}`")),
+    };
+    Ok(Color::Ansi(color))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use color_print::cformat;
+
+    #[test]
+    fn test_parse_style() {
+        assert_eq!(parse_style("bright_red"), Ok(fg(AnsiColor::BrightRed)));
+        assert_eq!(parse_style("bright-red"), Ok(fg(AnsiColor::BrightRed)));
+        assert_eq!(parse_style("blue dim"), Ok(fg(AnsiColor::Blue).dimmed()));
+        assert_eq!(
+            parse_style("208 bold"),
+            Ok(Style::new()
+                .fg_color(Some(Color::Ansi256(anstyle::Ansi256Color(208))))
+                .bold())
+        );
+        assert_eq!(
+            parse_style("#ff8800"),
+            Ok(Style::new().fg_color(Some(Color::Rgb(anstyle::RgbColor(255, 136, 0)))))
+        );
+        assert_eq!(
+            parse_style("white on_red"),
+            Ok(fg(AnsiColor::White).bg_color(Some(Color::Ansi(AnsiColor::Red))))
+        );
+        assert_eq!(parse_style(""), Ok(Style::new()));
+        assert!(parse_style("reddish").is_err());
+        assert!(parse_style("#ff88").is_err());
+    }
+
+    #[test]
+    fn test_paint_matches_cformat() {
+        // The default theme must render exactly what the old cformat! tags did
+        let dark = Theme::dark();
+        assert_eq!(paint(dark.error, "x"), cformat!("<red>x</>"));
+        assert_eq!(paint(dark.hint, "x"), cformat!("<dim>x</>"));
+        assert_eq!(paint(dark.progress, "x"), cformat!("<cyan>x</>"));
+        assert_eq!(paint(dark.heading, "x"), cformat!("<cyan>x</>"));
+        assert_eq!(paint(dark.addition, "+1"), cformat!("<green>+1</>"));
+        assert_eq!(paint(dark.deletion, "-1"), cformat!("<red>-1</>"));
+        // BrightWhite background is ANSI 107
+        assert!(dark.gutter.render().to_string().contains("107"));
+        assert_eq!(paint(Style::new(), "x"), "x");
+    }
+
+    #[test]
+    fn test_preset() {
+        assert_eq!(Theme::preset("dark"), Some(Theme::dark()));
+        assert_eq!(Theme::preset("light"), Some(Theme::light()));
+        assert_eq!(Theme::preset("solarized"), None);
+    }
+}
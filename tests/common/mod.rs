@@ -2301,6 +2301,12 @@ fn setup_snapshot_settings_for_paths_with_home(
     };
     settings.set_snapshot_path("../snapshots");
 
+    // Drop `-v` command traces (`$ git ... (12ms)`); their order and timing vary
+    settings.add_filter(
+        r"(?m)^(?:\x1b\[[0-9;]*m)*\$ [^\n]* \(\d+ms\)(?:\x1b\[[0-9;]*m)*\n",
+        "",
+    );
+
     // Normalize project root path (for test fixtures)
     // This must come before repo path filter to avoid partial matches
     let project_root = std::env::var("CARGO_MANIFEST_DIR")
@@ -0,0 +1,109 @@
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+
+/// Feature worktree with two commits, each adding its own file.
+fn feature_with_commits(repo: &TestRepo) -> std::path::PathBuf {
+    let feature_wt = repo.root_path().parent().unwrap().join("repo.feature");
+    repo.run_git(&[
+        "worktree",
+        "add",
+        feature_wt.to_str().unwrap(),
+        "-b",
+        "feature",
+    ]);
+    std::fs::write(feature_wt.join("a.txt"), "a1\na2\na3\n").unwrap();
+    repo.run_git_in(&feature_wt, &["add", "a.txt"]);
+    repo.run_git_in(&feature_wt, &["commit", "-m", "Add a"]);
+    std::fs::write(feature_wt.join("b.txt"), "b1\nb2\n").unwrap();
+    repo.run_git_in(&feature_wt, &["add", "b.txt"]);
+    repo.run_git_in(&feature_wt, &["commit", "-m", "Add b"]);
+    feature_wt
+}
+
+fn subjects(repo: &TestRepo, worktree: &std::path::Path) -> String {
+    let output = repo
+        .git_command()
+        .args(["log", "--format=%s", "main..HEAD"])
+        .current_dir(worktree)
+        .output()
+        .unwrap();
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+#[rstest]
+fn test_absorb_creates_fixups(repo: TestRepo) {
+    let feature_wt = feature_with_commits(&repo);
+    std::fs::write(feature_wt.join("a.txt"), "a1\nA2\na3\n").unwrap();
+    std::fs::write(feature_wt.join("b.txt"), "b1\nB2\n").unwrap();
+    std::fs::write(feature_wt.join("new.txt"), "new\n").unwrap();
+    repo.run_git_in(&feature_wt, &["add", "a.txt", "b.txt", "new.txt"]);
+
+    let output = repo
+        .wt_command()
+        .arg("absorb")
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(
+        subjects(&repo, &feature_wt),
+        "fixup! Add b\nfixup! Add a\nAdd b\nAdd a\n"
+    );
+
+    // The new file can't be blamed, so it stays staged
+    let staged = repo
+        .git_command()
+        .args(["diff", "--cached", "--name-only"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert_eq!(String::from_utf8_lossy(&staged.stdout), "new.txt\n");
+}
+
+#[rstest]
+fn test_absorb_rebase(repo: TestRepo) {
+    let feature_wt = feature_with_commits(&repo);
+    std::fs::write(feature_wt.join("a.txt"), "a1\nA2\na3\n").unwrap();
+    repo.run_git_in(&feature_wt, &["add", "a.txt"]);
+
+    let output = repo
+        .wt_command()
+        .args(["absorb", "--rebase"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(subjects(&repo, &feature_wt), "Add b\nAdd a\n");
+    let content = repo
+        .git_command()
+        .args(["show", "HEAD~1:a.txt"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert_eq!(String::from_utf8_lossy(&content.stdout), "a1\nA2\na3\n");
+}
+
+#[rstest]
+fn test_absorb_dry_run(repo: TestRepo) {
+    let feature_wt = feature_with_commits(&repo);
+    std::fs::write(feature_wt.join("a.txt"), "a1\nA2\na3\n").unwrap();
+    repo.run_git_in(&feature_wt, &["add", "a.txt"]);
+
+    let output = repo
+        .wt_command()
+        .args(["--dry-run", "absorb"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Add a"));
+    assert_eq!(subjects(&repo, &feature_wt), "Add b\nAdd a\n");
+}
@@ -0,0 +1,44 @@
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+
+#[rstest]
+fn test_activity_groups_by_worktree(repo: TestRepo) {
+    let parent = repo.root_path().parent().unwrap();
+    let feature_wt = parent.join("repo.feature");
+    let idle_wt = parent.join("repo.idle");
+    repo.run_git(&[
+        "worktree",
+        "add",
+        feature_wt.to_str().unwrap(),
+        "-b",
+        "feature",
+    ]);
+    repo.run_git(&["worktree", "add", idle_wt.to_str().unwrap(), "-b", "idle"]);
+    std::fs::write(feature_wt.join("feature.txt"), "one\ntwo\n").unwrap();
+    repo.run_git_in(&feature_wt, &["add", "feature.txt"]);
+    repo.run_git_in(&feature_wt, &["commit", "-m", "Add feature"]);
+
+    // Test commits use a fixed date, so the period must reach back to it
+    let output = repo
+        .wt_command()
+        .args(["activity", "--since", "2000-01-01"])
+        .env("NO_COLOR", "1")
+        .env_remove("CLICOLOR_FORCE")
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("feature  1 commit  +2 -0"), "{stdout}");
+    assert!(stdout.contains("Add feature"), "{stdout}");
+    // The fixture's initial commit counts once, under main only, next to
+    // one commit on each of its feature-a/b/c worktrees and ours
+    assert!(stdout.contains("Total  5 commits"), "{stdout}");
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("No commits since 2000-01-01"), "{stderr}");
+    assert!(stderr.contains("idle"), "{stderr}");
+}
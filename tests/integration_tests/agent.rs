@@ -0,0 +1,53 @@
+use crate::common::{TestRepo, repo, wait_for};
+use rstest::rstest;
+
+#[rstest]
+fn test_agent_runs_task_and_merges(repo: TestRepo) {
+    repo.write_test_config(
+        r#"[agent]
+command = "printf '%s\n' {{ task }} > task.txt && git add task.txt && git commit -qm 'Do the task'"
+"#,
+    );
+    let output = repo
+        .wt_command()
+        .args(["agent", "--yes", "Write the task file"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let log = repo.git_output(&["log", "--format=%s", "main"]);
+    assert!(log.contains("Do the task"), "{log}");
+    // The merge removes the agent's worktree and branch in the background
+    wait_for("agent branch removal", || {
+        repo.git_output(&["branch", "--list", "agent/*"]).is_empty()
+    });
+}
+
+#[rstest]
+fn test_agent_without_changes_keeps_worktree(repo: TestRepo) {
+    repo.write_test_config("[agent]\ncommand = \"true\"\n");
+    let output = repo
+        .wt_command()
+        .args(["agent", "--branch", "idle", "Nothing to do"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Agent made no changes"), "{stderr}");
+    assert!(stderr.contains("wt remove idle"), "{stderr}");
+}
+
+#[rstest]
+fn test_agent_requires_command(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["agent", "Anything"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("[agent] command"));
+}
@@ -0,0 +1,46 @@
+use crate::common::{TestRepo, repo};
+use ansi_str::AnsiStr;
+use rstest::rstest;
+
+#[rstest]
+fn test_alias_expands_to_builtin(repo: TestRepo) {
+    repo.write_test_config(
+        r#"[alias]
+new = "switch --create {{ args }} --no-cd"
+ls = "list --format=json"
+"#,
+    );
+
+    let output = repo.wt_command().args(["new", "feature"]).output().unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let output = repo.wt_command().arg("ls").output().unwrap();
+    assert!(output.status.success());
+    let items: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(items.iter().any(|item| item["branch"] == "feature"));
+}
+
+#[rstest]
+fn test_alias_listed_in_help(repo: TestRepo) {
+    repo.write_test_config("[alias]\nst = \"step for-each -- git status --short\"\n");
+    let output = repo.wt_command().arg("--help").output().unwrap();
+    let help = String::from_utf8_lossy(&output.stdout).into_owned()
+        + &String::from_utf8_lossy(&output.stderr);
+    // Help renders the backticks as styling
+    assert!(
+        help.ansi_strip()
+            .contains("Alias for wt step for-each -- git status --short")
+    );
+}
+
+#[rstest]
+fn test_alias_to_unknown_command_fails(repo: TestRepo) {
+    repo.write_test_config("[alias]\noops = \"frobnicate\"\n");
+    let output = repo.wt_command().arg("oops").output().unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("isn't a wt command"));
+}
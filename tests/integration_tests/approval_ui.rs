@@ -309,6 +309,27 @@ fn test_yes_bypasses_tty_check(repo: TestRepo) {
     ));
 }
 
+/// `WT_ASSUME_YES` stands in for `--yes` on every command, without saving approvals.
+#[rstest]
+fn test_assume_yes_env_bypasses_approval(repo: TestRepo) {
+    repo.write_project_config(r#"post-create = "echo 'test command'""#);
+    repo.commit("Add config");
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "test-assume-yes"])
+        .env("WT_ASSUME_YES", "1")
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let config = fs::read_to_string(repo.test_config_path()).unwrap_or_default();
+    assert!(!config.contains("approved-commands"), "config: {config}");
+}
+
 ///
 /// When `wt hook post-merge` runs standalone (not via `wt merge`), the `{{ target }}`
 /// variable should be the current branch, not always the default branch.
@@ -579,3 +600,32 @@ third = "echo 'third' >> output.txt"
         "All commands should have run in order"
     );
 }
+
+#[rstest]
+fn test_step_hook_path_filters(mut repo: TestRepo) {
+    repo.write_project_config(
+        r#"[pre-merge]
+feature = { command = "echo '{{ changed_files }}' >> output.txt", paths = ["feature.txt"] }
+docs = { command = "echo 'docs' >> output.txt", paths = ["docs/"] }
+"#,
+    );
+    repo.commit("Add pre-merge hooks");
+    let feature_wt = repo.add_feature();
+
+    let output = repo
+        .wt_command()
+        .args(["hook", "pre-merge", "--yes"])
+        .current_dir(&feature_wt)
+        .env("NO_COLOR", "1")
+        .output()
+        .expect("Failed to run wt hook pre-merge");
+    assert!(
+        output.status.success(),
+        "wt hook pre-merge failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    // Only the command whose paths changed on the branch ran
+    let content = fs::read_to_string(feature_wt.join("output.txt")).unwrap();
+    assert_eq!(content.lines().collect::<Vec<_>>(), vec!["feature.txt"]);
+}
@@ -0,0 +1,77 @@
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+
+/// A `release` branch cut from main, and a feature worktree adding `fix.txt`
+/// after it.
+fn release_and_feature(repo: &mut TestRepo) {
+    repo.run_git(&["branch", "release", "main"]);
+    let feature_wt = repo.add_worktree("feature");
+    repo.commit_in_worktree(&feature_wt, "fix.txt", "fix\n", "Fix the crash");
+}
+
+#[rstest]
+fn test_backport_branch(mut repo: TestRepo) {
+    release_and_feature(&mut repo);
+
+    let output = repo
+        .wt_command()
+        .args(["backport", "feature", "--to", "release", "--yes"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let message = repo.git_output(&["log", "-1", "--format=%B", "release"]);
+    assert!(message.starts_with("Fix the crash"), "{message}");
+    assert!(message.contains("cherry picked from commit"), "{message}");
+    // The cherry-pick happened in a worktree for release, which stays
+    let worktrees = repo.git_output(&["worktree", "list", "--porcelain"]);
+    assert!(
+        worktrees.contains("branch refs/heads/release"),
+        "{worktrees}"
+    );
+}
+
+#[rstest]
+fn test_backport_conflict_stops_in_target(mut repo: TestRepo) {
+    let release_wt = repo.add_worktree("release");
+    repo.commit_in_worktree(&release_wt, "fix.txt", "other\n", "Release fix");
+    let feature_wt = repo.add_worktree("feature");
+    repo.commit_in_worktree(&feature_wt, "fix.txt", "fix\n", "Fix the crash");
+    let commit = repo.git_output(&["rev-parse", "feature"]);
+
+    let output = repo
+        .wt_command()
+        .args(["backport", &commit, "--to", "release", "--yes"])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert_eq!(output.status.code(), Some(5), "{stderr}");
+    assert!(stderr.contains("git cherry-pick --continue"), "{stderr}");
+
+    // Left in progress in the release worktree for the user to finish
+    let status = repo
+        .git_command()
+        .args(["status", "--porcelain"])
+        .current_dir(&release_wt)
+        .output()
+        .unwrap();
+    assert_eq!(String::from_utf8_lossy(&status.stdout), "AA fix.txt\n");
+}
+
+#[rstest]
+fn test_backport_merged_branch_needs_commits(mut repo: TestRepo) {
+    repo.run_git(&["branch", "release", "main"]);
+    repo.add_worktree("feature");
+
+    let output = repo
+        .wt_command()
+        .args(["backport", "feature", "--to", "release", "--yes"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("name the commits instead"));
+}
@@ -849,3 +849,62 @@ fn test_bare_repo_bootstrap_first_worktree() {
     );
     assert!(stdout.contains("main"), "Should list main worktree");
 }
+
+#[test]
+fn test_bare_repo_remove_default_worktree_lands_in_repo_dir() {
+    let test = BareRepoTest::new();
+
+    let main_worktree = test.create_worktree("main", "main");
+    test.commit_in(&main_worktree, "Initial commit");
+    test.create_worktree("feature", "feature");
+
+    // Removing the default branch's worktree from inside it: there's no main
+    // checkout to return to, so the shell should land in the bare repo directory
+    let (directive_path, _guard) = directive_file();
+    let mut cmd = wt_command();
+    test.configure_wt_cmd(&mut cmd);
+    configure_directive_file(&mut cmd, &directive_path);
+    cmd.args(["remove", "--no-delete-branch", "--foreground"])
+        .current_dir(&main_worktree);
+
+    let output = cmd.output().unwrap();
+    assert!(
+        output.status.success(),
+        "wt remove failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(!main_worktree.exists());
+
+    let directives = fs::read_to_string(&directive_path).unwrap_or_default();
+    let expected = test.bare_repo_path().to_string_lossy();
+    assert!(
+        directives.contains(&*expected) && !directives.contains(&*main_worktree.to_string_lossy()),
+        "Directive should cd to {expected}, got: {directives}"
+    );
+}
+
+#[test]
+fn test_bare_repo_default_branch_from_linked_worktree() {
+    let test = BareRepoTest::new();
+
+    let main_worktree = test.create_worktree("main", "main");
+    test.commit_in(&main_worktree, "Initial commit");
+    let feature_worktree = test.create_worktree("feature", "feature");
+    test.commit_in(&feature_worktree, "Feature work");
+    test.create_worktree("develop", "develop");
+    let mut git = Command::new("git");
+    git.args(["symbolic-ref", "HEAD", "refs/heads/develop"])
+        .current_dir(test.bare_repo_path());
+    test.configure_git_cmd(&mut git);
+    assert!(git.output().unwrap().status.success());
+
+    // Several branches and no remote: the bare repo's HEAD decides (over
+    // init.defaultBranch), even from a worktree whose own HEAD is another branch
+    let mut cmd = wt_command();
+    test.configure_wt_cmd(&mut cmd);
+    cmd.args(["config", "state", "default-branch"])
+        .current_dir(&feature_worktree);
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "develop");
+}
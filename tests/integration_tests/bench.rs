@@ -0,0 +1,32 @@
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+
+#[rstest]
+fn test_bench_synthetic_repo(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["bench", "--worktrees", "2", "--iterations", "2"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr:\n{stderr}");
+    // Main worktree plus two linked ones
+    assert!(stdout.contains("3 items, 2 runs"), "stdout:\n{stdout}");
+    assert!(stdout.contains("collect  median"), "stdout:\n{stdout}");
+    assert!(stdout.contains("layout   median"), "stdout:\n{stdout}");
+}
+
+#[rstest]
+fn test_bench_phase_prints_nanoseconds(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["bench", "--phase", "layout", "--iterations", "3", "--repo"])
+        .arg(repo.root_path())
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr:\n{stderr}");
+    assert!(stdout.trim().parse::<u64>().is_ok(), "stdout:\n{stdout}");
+}
@@ -0,0 +1,71 @@
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+
+fn wt(repo: &TestRepo, args: &[&str]) -> std::process::Output {
+    let output = repo.wt_command().args(args).output().unwrap();
+    assert!(
+        output.status.success(),
+        "wt {}: {}",
+        args.join(" "),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    output
+}
+
+#[rstest]
+fn test_export_import_roundtrip(mut repo: TestRepo) {
+    let worktree = repo.add_worktree("feature");
+    std::fs::write(worktree.join("staged.txt"), "staged\n").unwrap();
+    repo.run_git(&["-C", worktree.to_str().unwrap(), "add", "staged.txt"]);
+    std::fs::write(worktree.join("notes.txt"), "draft\n").unwrap();
+    let head = repo.git_output(&["rev-parse", "feature"]);
+
+    let bundle = repo.root_path().parent().unwrap().join("feature.bundle");
+    let output = wt(
+        &repo,
+        &["export", "feature", "--bundle", bundle.to_str().unwrap()],
+    );
+    assert!(
+        String::from_utf8_lossy(&output.stderr).contains("with its uncommitted changes"),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    // Exporting leaves the worktree alone and keeps no refs behind
+    assert!(worktree.join("notes.txt").exists());
+    assert_eq!(
+        repo.git_output(&["for-each-ref", "refs/wt-export/", "refs/wt-snapshot/"]),
+        ""
+    );
+
+    // Pretend this is another machine: no branch, no worktree
+    repo.run_git(&["worktree", "remove", "--force", worktree.to_str().unwrap()]);
+    repo.run_git(&["branch", "-D", "feature"]);
+
+    let output = wt(&repo, &["import", bundle.to_str().unwrap(), "--yes"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Restored"), "{stderr}");
+    assert_eq!(repo.git_output(&["rev-parse", "feature"]), head);
+    assert_eq!(
+        std::fs::read_to_string(worktree.join("notes.txt")).unwrap(),
+        "draft\n"
+    );
+    assert_eq!(
+        repo.git_output(&[
+            "-C",
+            worktree.to_str().unwrap(),
+            "diff",
+            "--cached",
+            "--name-only"
+        ]),
+        "staged.txt"
+    );
+    assert_eq!(repo.git_output(&["for-each-ref", "refs/wt-export/"]), "");
+
+    // A second import would clobber the branch
+    let output = repo
+        .wt_command()
+        .args(["import", bundle.to_str().unwrap(), "--yes"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+}
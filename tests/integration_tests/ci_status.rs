@@ -762,3 +762,13 @@ fn test_list_full_with_gitlab_ci_rate_limit(mut repo: TestRepo) {
         assert_cmd_snapshot!("gitlab_ci_rate_limit", cmd);
     });
 }
+
+/// `wt ci` needs a forge; without a GitHub/GitLab remote it says how to set one.
+#[rstest]
+#[case::show(&["ci"])]
+#[case::rerun(&["ci", "rerun"])]
+fn test_ci_without_forge_remote(repo: TestRepo, #[case] args: &[&str]) {
+    let output = repo.wt_command().args(args).output().unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("ci.platform"));
+}
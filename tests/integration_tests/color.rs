@@ -0,0 +1,65 @@
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+
+#[rstest]
+fn test_color_never_overrides_clicolor_force(repo: TestRepo) {
+    // The test environment sets CLICOLOR_FORCE=1
+    let output = repo
+        .wt_command()
+        .args(["list", "--color=never"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("main"), "stdout:\n{stdout}");
+    assert!(!stdout.contains('\x1b'), "stdout:\n{stdout}");
+
+    // Applies to status output on stderr too
+    let output = repo
+        .wt_command()
+        .args(["--color", "never", "switch", "no-such-branch"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("no-such-branch"), "stderr:\n{stderr}");
+    assert!(!stderr.contains('\x1b'), "stderr:\n{stderr}");
+}
+
+#[rstest]
+fn test_color_always_overrides_no_color(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["list", "--color=always"])
+        .env_remove("CLICOLOR_FORCE")
+        .env("NO_COLOR", "1")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains('\x1b'), "stdout:\n{stdout}");
+}
+
+#[test]
+fn test_color_never_help() {
+    let output = crate::common::wt_command()
+        .args(["--color=never", "list", "-h"])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("--format"), "stderr:\n{stderr}");
+    assert!(!stderr.contains('\x1b'), "stderr:\n{stderr}");
+}
+
+#[rstest]
+fn test_json_never_styled(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["list", "--format=json", "--color=always"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(!stdout.contains('\x1b'), "stdout:\n{stdout}");
+    serde_json::from_str::<serde_json::Value>(&stdout).unwrap();
+}
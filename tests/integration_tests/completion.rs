@@ -585,18 +585,21 @@ fn test_complete_step_subcommands(repo: TestRepo) {
     // Git operations
     assert!(subcommands.contains(&"commit"), "Missing commit");
     assert!(subcommands.contains(&"squash"), "Missing squash");
+    assert!(subcommands.contains(&"split"), "Missing split");
     assert!(subcommands.contains(&"push"), "Missing push");
     assert!(subcommands.contains(&"rebase"), "Missing rebase");
     assert!(
         subcommands.contains(&"copy-ignored"),
         "Missing copy-ignored"
     );
+    assert!(subcommands.contains(&"db-create"), "Missing db-create");
+    assert!(subcommands.contains(&"db-drop"), "Missing db-drop");
     assert!(subcommands.contains(&"for-each"), "Missing for-each");
     assert!(subcommands.contains(&"relocate"), "Missing relocate");
     assert_eq!(
         subcommands.len(),
-        7,
-        "Should have exactly 7 step subcommands"
+        10,
+        "Should have exactly 10 step subcommands"
     );
 }
 
@@ -611,6 +614,7 @@ fn test_complete_hook_subcommands(repo: TestRepo) {
     let subcommands = value_suggestions(&stdout);
     // Hook types and commands
     assert!(subcommands.contains(&"show"), "Missing show");
+    assert!(subcommands.contains(&"check"), "Missing check");
     assert!(subcommands.contains(&"post-create"), "Missing post-create");
     assert!(subcommands.contains(&"post-start"), "Missing post-start");
     assert!(subcommands.contains(&"post-switch"), "Missing post-switch");
@@ -619,11 +623,12 @@ fn test_complete_hook_subcommands(repo: TestRepo) {
     assert!(subcommands.contains(&"post-merge"), "Missing post-merge");
     assert!(subcommands.contains(&"pre-remove"), "Missing pre-remove");
     assert!(subcommands.contains(&"post-remove"), "Missing post-remove");
+    assert!(subcommands.contains(&"logs"), "Missing logs");
     assert!(subcommands.contains(&"approvals"), "Missing approvals");
     assert_eq!(
         subcommands.len(),
-        10,
-        "Should have exactly 10 hook subcommands"
+        12,
+        "Should have exactly 12 hook subcommands"
     );
 
     // Test 2: Partial input "po" - filters to post-* subcommands
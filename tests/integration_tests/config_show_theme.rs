@@ -8,3 +8,21 @@ fn test_show_theme() {
 
     assert_cmd_snapshot!(cmd);
 }
+
+#[test]
+fn test_show_theme_no_emoji() {
+    let output = wt_command()
+        .args(["config", "shell", "show-theme", "--no-emoji"])
+        .env("NO_COLOR", "1")
+        .env_remove("CLICOLOR_FORCE")
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(stderr.contains("ERROR: Branch"), "stderr:\n{stderr}");
+    assert!(stderr.contains("OK: Created worktree"), "stderr:\n{stderr}");
+    assert!(
+        !stderr.contains('✗') && !stderr.contains('✓'),
+        "stderr:\n{stderr}"
+    );
+}
@@ -0,0 +1,45 @@
+use std::process::Stdio;
+use std::time::{Duration, Instant};
+
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+
+fn list_json(repo: &TestRepo, bypass: bool) -> String {
+    let mut cmd = repo.wt_command();
+    cmd.args(["list", "--format=json"]);
+    if bypass {
+        cmd.env("WORKTRUNK_NO_DAEMON", "1");
+    }
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[rstest]
+fn test_daemon_serves_list(repo: TestRepo) {
+    let socket = repo.root_path().join(".git/wt-daemon.sock");
+    let mut daemon = repo
+        .wt_command()
+        .arg("daemon")
+        .stderr(Stdio::null())
+        .spawn()
+        .unwrap();
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while !socket.exists() && Instant::now() < deadline {
+        std::thread::sleep(Duration::from_millis(50));
+    }
+    assert!(socket.exists(), "daemon didn't create its socket");
+
+    // Answers match an uncached run, including when served from the cache
+    let direct = list_json(&repo, true);
+    assert_eq!(list_json(&repo, false), direct);
+    assert_eq!(list_json(&repo, false), direct);
+
+    // Only one daemon per repository
+    let output = repo.wt_command().arg("daemon").output().unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("already running"));
+
+    daemon.kill().unwrap();
+    daemon.wait().unwrap();
+}
@@ -311,3 +311,44 @@ fn test_resolve_caret_fails_when_default_branch_unavailable(repo: TestRepo) {
         err_msg
     );
 }
+
+/// `wt config state default-branch`, run in the repo so its project config
+/// (read from the current worktree) applies.
+fn state_default_branch(repo: &TestRepo) -> String {
+    let output = repo
+        .wt_command()
+        .args(["config", "state", "default-branch"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+#[rstest]
+fn test_get_default_branch_from_project_config(repo: TestRepo) {
+    repo.git_command()
+        .args(["branch", "develop"])
+        .status()
+        .unwrap();
+    repo.write_project_config(r#"default-branch = "develop""#);
+
+    // Project config takes precedence over the cached value
+    repo.git_command()
+        .args(["config", "worktrunk.default-branch", "main"])
+        .status()
+        .unwrap();
+    assert_eq!(state_default_branch(&repo), "develop");
+}
+
+#[rstest]
+fn test_get_default_branch_project_config_missing_branch(repo: TestRepo) {
+    // A project default that doesn't exist locally falls through to detection
+    repo.write_project_config(r#"default-branch = "develop""#);
+
+    assert_eq!(state_default_branch(&repo), "main");
+}
@@ -18,6 +18,7 @@
 use std::fs;
 use std::path::PathBuf;
 
+use ansi_str::AnsiStr;
 use insta::assert_snapshot;
 use rstest::rstest;
 
@@ -398,7 +399,7 @@ fn test_vv_writes_diagnostic_on_error(mut repo: TestRepo) {
 }
 
 /// With just -v (not -vv), no logging files should be written.
-/// -v is reserved for future use; -vv is required for debug logging.
+/// -v shows commands on stderr; -vv is required for debug logging.
 #[rstest]
 fn test_v_does_not_enable_logging(repo: TestRepo) {
     // Run a successful command with just -v
@@ -421,6 +422,66 @@ fn test_v_does_not_enable_logging(repo: TestRepo) {
     );
 }
 
+/// -v prints each git command with its duration.
+#[rstest]
+fn test_v_shows_git_commands(repo: TestRepo) {
+    let output = repo.wt_command().args(["list", "-v"]).output().unwrap();
+    assert!(output.status.success(), "Command should succeed");
+
+    let stderr = String::from_utf8_lossy(&output.stderr)
+        .ansi_strip()
+        .into_owned();
+    assert!(
+        stderr
+            .lines()
+            .any(|line| line.contains("$ git ") && line.ends_with("ms)")),
+        "-v should show git commands with timing, got:\n{stderr}"
+    );
+}
+
+/// -q drops success messages and hints but still reports errors.
+#[rstest]
+fn test_quiet_errors_only(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["-q", "switch", "--create", "quiet-test"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "Command should succeed");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        !stderr.contains('✓') && !stderr.contains('↳'),
+        "-q should suppress success messages and hints, got:\n{stderr}"
+    );
+
+    let output = repo
+        .wt_command()
+        .args(["-q", "switch", "does-not-exist"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains('✗'),
+        "-q should keep errors, got:\n{stderr}"
+    );
+    assert!(
+        !stderr.contains('↳'),
+        "-q should drop hints, got:\n{stderr}"
+    );
+}
+
+/// -q and -v are mutually exclusive.
+#[rstest]
+fn test_quiet_conflicts_with_verbose(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["list", "-q", "-v"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+}
+
 /// With -vv outside a git repo, command should still work (no crash).
 #[test]
 fn test_vv_outside_repo_no_crash() {
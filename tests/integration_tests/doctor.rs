@@ -0,0 +1,59 @@
+use crate::common::{TestRepo, repo};
+use ansi_str::AnsiStr;
+use rstest::rstest;
+
+#[rstest]
+fn test_doctor_invalid_project_config_fails(repo: TestRepo) {
+    repo.write_project_config("this is not valid toml {{{");
+
+    let output = repo.wt_command().arg("doctor").output().unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert_eq!(output.status.code(), Some(1), "stderr:\n{stderr}");
+    assert!(stderr.contains("Invalid config"), "stderr:\n{stderr}");
+}
+
+#[rstest]
+fn test_doctor_reports_orphaned_worktree(mut repo: TestRepo) {
+    let path = repo.add_worktree("feature");
+    std::fs::remove_dir_all(&path).unwrap();
+
+    let output = repo.wt_command().arg("doctor").output().unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(
+        stderr.contains("missing directories") && stderr.contains("git worktree prune"),
+        "stderr:\n{stderr}"
+    );
+}
+
+#[rstest]
+fn test_doctor_worktrees_explains_flags(mut repo: TestRepo) {
+    repo.add_worktree("feature");
+    repo.lock_worktree("feature", Some("on a USB drive"));
+    let detached = repo.add_worktree("detached");
+    repo.run_git_in(&detached, &["checkout", "--quiet", "--detach"]);
+
+    let output = repo
+        .wt_command()
+        .args(["doctor", "--worktrees"])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let stderr = stderr.ansi_strip();
+
+    assert!(output.status.success(), "stderr:\n{stderr}");
+    assert!(stderr.contains("main healthy"), "stderr:\n{stderr}");
+    assert!(
+        stderr.contains("feature: locked, so git won't prune, move or remove it (on a USB drive)"),
+        "stderr:\n{stderr}"
+    );
+    assert!(stderr.contains("git worktree unlock"), "stderr:\n{stderr}");
+    assert!(
+        stderr.contains("repo.detached: HEAD is detached; new commits aren't on any branch")
+            && stderr.contains("git switch -c"),
+        "stderr:\n{stderr}"
+    );
+    // Environment checks are skipped
+    assert!(!stderr.contains("SHELL INTEGRATION"), "stderr:\n{stderr}");
+}
@@ -0,0 +1,46 @@
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+
+#[rstest]
+fn test_dry_run_switch_create_changes_nothing(repo: TestRepo) {
+    repo.write_project_config("post-create = \"touch created\"\n");
+
+    let output = repo
+        .wt_command()
+        .args(["--dry-run", "switch", "--create", "feature", "--yes"])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert!(stderr.contains("Would run"), "stderr: {stderr}");
+    assert!(stderr.contains("worktree add"), "stderr: {stderr}");
+    assert!(stderr.contains("touch created"), "stderr: {stderr}");
+    assert!(stderr.contains("no changes were made"), "stderr: {stderr}");
+
+    let branches = repo
+        .git_command()
+        .args(["branch", "--list", "feature"])
+        .output()
+        .unwrap();
+    assert!(branches.stdout.is_empty());
+    let worktree = repo.root_path().parent().unwrap().join(format!(
+        "{}.feature",
+        repo.root_path().file_name().unwrap().to_string_lossy()
+    ));
+    assert!(!worktree.exists());
+}
+
+#[rstest]
+fn test_dry_run_remove_keeps_worktree(mut repo: TestRepo) {
+    let feature = repo.add_worktree("feature");
+
+    let output = repo
+        .wt_command()
+        .args(["--dry-run", "remove", "feature", "--foreground"])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert!(stderr.contains("worktree remove"), "stderr: {stderr}");
+    assert!(feature.exists());
+}
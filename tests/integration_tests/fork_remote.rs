@@ -0,0 +1,42 @@
+use crate::common::{TestRepo, repo_with_remote};
+use rstest::rstest;
+
+/// Add an `upstream` remote whose `main` is one commit ahead of local `main`.
+fn setup_upstream_ahead(repo: &TestRepo) {
+    let upstream = repo.root_path().parent().unwrap().join("upstream.git");
+    repo.run_git(&[
+        "init",
+        "--bare",
+        "--initial-branch",
+        "main",
+        upstream.to_str().unwrap(),
+    ]);
+    repo.run_git(&["remote", "add", "upstream", upstream.to_str().unwrap()]);
+
+    std::fs::write(repo.root_path().join("upstream.txt"), "from upstream").unwrap();
+    repo.run_git(&["add", "upstream.txt"]);
+    repo.run_git(&["commit", "-m", "Upstream change"]);
+    repo.run_git(&["push", "upstream", "main"]);
+    repo.run_git(&["reset", "--hard", "HEAD~1"]);
+}
+
+#[rstest]
+fn test_merge_rebases_onto_upstream_remote(#[from(repo_with_remote)] mut repo: TestRepo) {
+    let feature = repo.add_worktree_with_commit("feature", "feature.txt", "feature", "Add feature");
+    setup_upstream_ahead(&repo);
+
+    let output = repo
+        .wt_command()
+        .args(["merge", "--yes", "--no-remove"])
+        .current_dir(&feature)
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert!(stderr.contains("upstream/main"), "stderr: {stderr}");
+
+    // Local main now includes the upstream commit and the feature
+    let files = repo.git_output(&["ls-tree", "--name-only", "main"]);
+    assert!(files.lines().any(|f| f == "upstream.txt"), "files: {files}");
+    assert!(files.lines().any(|f| f == "feature.txt"), "files: {files}");
+}
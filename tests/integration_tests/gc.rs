@@ -0,0 +1,72 @@
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+
+#[rstest]
+fn test_gc_reports_and_drops_orphaned_databases(mut repo: TestRepo) {
+    repo.write_project_config(
+        r#"[database]
+create = "echo {{ branch_slug }} > ../created-db"
+drop = "echo {{ branch_slug }} > ../dropped-db"
+"#,
+    );
+    repo.run_git(&["add", ".config"]);
+    repo.commit("Add database config");
+    let feature_wt = repo.add_worktree("feature");
+    let parent = feature_wt.parent().unwrap().to_path_buf();
+
+    let output = repo
+        .wt_command()
+        .args(["step", "db-create"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let created = std::fs::read_to_string(parent.join("created-db")).unwrap();
+    assert!(created.starts_with("feature_"), "{created}");
+
+    // Nothing is orphaned while the worktree exists
+    let output = repo.wt_command().arg("gc").output().unwrap();
+    assert!(String::from_utf8_lossy(&output.stderr).contains("No orphaned databases"));
+
+    // Removing the worktree without hooks leaves the database behind
+    repo.run_git(&[
+        "worktree",
+        "remove",
+        "--force",
+        feature_wt.to_str().unwrap(),
+    ]);
+    let output = repo.wt_command().arg("gc").output().unwrap();
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("outlived its worktree"), "{stderr}");
+    assert!(stderr.contains("dropped-db"), "{stderr}");
+    assert!(!parent.join("dropped-db").exists());
+
+    let output = repo.wt_command().args(["gc", "--drop"]).output().unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(
+        std::fs::read_to_string(parent.join("dropped-db")).unwrap(),
+        created
+    );
+    let output = repo.wt_command().arg("gc").output().unwrap();
+    assert!(String::from_utf8_lossy(&output.stderr).contains("No orphaned databases"));
+}
+
+#[rstest]
+fn test_step_db_create_without_config(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["step", "db-create"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("[database]"));
+}
@@ -12,6 +12,7 @@ fn display_worktree_removal_failed() {
         branch: "feature-x".into(),
         path: PathBuf::from("/tmp/repo.feature-x"),
         error: "fatal: worktree is dirty\nerror: could not remove worktree".into(),
+        source: None,
     };
 
     assert_snapshot!("worktree_removal_failed", err.to_string());
@@ -174,6 +175,7 @@ fn display_push_failed() {
     let err = GitError::PushFailed {
         target_branch: "main".into(),
         error: "To /Users/user/workspace/repo/.git\n ! [remote rejected] HEAD -> main (Up-to-date check failed)\nerror: failed to push some refs to '/Users/user/workspace/repo/.git'".into(),
+        source: None,
     };
 
     assert_snapshot!("push_failed", err.to_string());
@@ -217,11 +219,24 @@ fn display_rebase_conflict() {
     let err = GitError::RebaseConflict {
         target_branch: "main".into(),
         git_output: "CONFLICT (content): Merge conflict in src/main.rs".into(),
+        source: None,
     };
 
     assert_snapshot!("rebase_conflict", err.to_string());
 }
 
+#[test]
+fn display_cherry_pick_conflict() {
+    let err = GitError::CherryPickConflict {
+        target_branch: "release".into(),
+        commit: "abc1234def5678".into(),
+        git_output: "CONFLICT (content): Merge conflict in src/main.rs".into(),
+        source: None,
+    };
+
+    assert_snapshot!("cherry_pick_conflict", err.to_string());
+}
+
 // ============================================================================
 // Validation/other errors
 // ============================================================================
@@ -303,6 +318,7 @@ fn display_hook_command_failed_with_name() {
         command_name: Some("test".into()),
         error: "exit code 1".into(),
         exit_code: Some(1),
+        log: None,
     };
 
     assert_snapshot!("hook_command_failed_with_name", err.to_string());
@@ -315,6 +331,7 @@ fn display_hook_command_failed_without_name() {
         command_name: None,
         error: "command not found".into(),
         exit_code: Some(127),
+        log: None,
     };
 
     assert_snapshot!("hook_command_failed_without_name", err.to_string());
@@ -328,6 +345,7 @@ fn display_hook_command_failed_with_skip_hint() {
         command_name: Some("test".into()),
         error: "exit code 1".into(),
         exit_code: Some(1),
+        log: None,
     }
     .into();
 
@@ -0,0 +1,53 @@
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+
+#[rstest]
+fn test_graph_shows_worktree_branches(repo: TestRepo) {
+    let feature_wt = repo.root_path().parent().unwrap().join("repo.feature");
+    repo.run_git(&[
+        "worktree",
+        "add",
+        feature_wt.to_str().unwrap(),
+        "-b",
+        "feature",
+    ]);
+    std::fs::write(feature_wt.join("feature.txt"), "feature\n").unwrap();
+    repo.run_git_in(&feature_wt, &["add", "feature.txt"]);
+    repo.run_git_in(&feature_wt, &["commit", "-m", "Add feature"]);
+    std::fs::write(repo.root_path().join("main.txt"), "main\n").unwrap();
+    repo.run_git(&["add", "main.txt"]);
+    repo.commit("Advance main");
+    // A branch without a worktree stays out of the graph
+    repo.run_git(&["branch", "unrelated", "HEAD~1"]);
+    repo.run_git(&["commit", "--allow-empty", "-m", "Only on main"]);
+
+    let output = repo
+        .wt_command()
+        .arg("graph")
+        .env("NO_COLOR", "1")
+        .env_remove("CLICOLOR_FORCE")
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("(feature) Add feature"), "{stdout}");
+    assert!(stdout.contains("main) Only on main"), "{stdout}");
+    assert!(stdout.contains("Advance main"), "{stdout}");
+    assert!(!stdout.contains("unrelated"), "{stdout}");
+    // The shared base is the boundary
+    assert!(stdout.lines().any(|l| l.starts_with("o ")), "{stdout}");
+}
+
+#[test]
+fn test_graph_without_worktrees() {
+    // The standard fixture has worktrees; start from an empty repo instead
+    let repo = TestRepo::empty();
+    repo.commit("Initial commit");
+    let output = repo.wt_command().arg("graph").output().unwrap();
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Nothing to graph"));
+}
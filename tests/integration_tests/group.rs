@@ -0,0 +1,87 @@
+//! Integration tests for `wt group` and `wt step for-each --group`
+
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+
+#[rstest]
+fn test_group_add_list_remove(repo: TestRepo) {
+    let run = |args: &[&str]| {
+        let output = repo.wt_command().args(args).output().unwrap();
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        String::from_utf8_lossy(&output.stdout).into_owned()
+    };
+
+    run(&["group", "add", "backend", "feature-b", "feature-a"]);
+    run(&["group", "add", "frontend", "feature-c"]);
+    assert_eq!(
+        run(&["group", "list"]),
+        "backend: feature-a feature-b\nfrontend: feature-c\n"
+    );
+
+    run(&["group", "remove", "backend", "feature-a"]);
+    run(&["group", "remove", "frontend"]);
+    assert_eq!(run(&["group", "list"]), "backend: feature-b\n");
+}
+
+#[rstest]
+fn test_group_remove_unknown(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["group", "remove", "missing"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("No group named"));
+}
+
+/// `for-each --group` only visits worktrees whose branch is in the group.
+#[rstest]
+fn test_for_each_group_filters_worktrees(mut repo: TestRepo) {
+    repo.add_worktree("api");
+    repo.add_worktree("web");
+
+    let output = repo
+        .wt_command()
+        .args(["group", "add", "backend", "api", "not-checked-out"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let output = repo
+        .wt_command()
+        .args([
+            "step",
+            "for-each",
+            "--group",
+            "backend",
+            "--",
+            "git",
+            "branch",
+            "--show-current",
+        ])
+        .env("NO_COLOR", "1")
+        .env_remove("CLICOLOR_FORCE")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    // Child output goes to stderr, after each worktree's heading
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Running in api"), "{stderr}");
+    assert!(!stderr.contains("Running in web"), "{stderr}");
+    assert!(!stderr.contains("Running in main"), "{stderr}");
+}
+
+#[rstest]
+fn test_for_each_unknown_group(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["step", "for-each", "--group", "missing", "--", "true"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("No group named"));
+}
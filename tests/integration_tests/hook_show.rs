@@ -480,3 +480,64 @@ valid = "echo branch={{ branch }} repo={{ repo }}"
         assert_cmd_snapshot!(cmd);
     });
 }
+
+#[rstest]
+fn test_hook_list_shows_conditions(repo: TestRepo, temp_home: TempDir) {
+    repo.write_project_config(
+        r#"[pre-merge]
+test = { command = "cargo test", paths = ["src/**"] }
+"#,
+    );
+    repo.commit("Add project config");
+
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    cmd.args(["hook", "list"]).current_dir(repo.root_path());
+    set_temp_home_env(&mut cmd, temp_home.path());
+    let output = cmd.output().unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert!(stderr.contains("paths src/**"), "stderr: {stderr}");
+}
+
+#[rstest]
+fn test_hook_check(repo: TestRepo, temp_home: TempDir) {
+    repo.write_project_config(
+        r#"[pre-merge]
+status = "git status && git diff --stat"
+"#,
+    );
+    repo.commit("Add project config");
+
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    cmd.args(["hook", "check"]).current_dir(repo.root_path());
+    set_temp_home_env(&mut cmd, temp_home.path());
+    let output = cmd.output().unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert!(stderr.contains("status"), "stderr: {stderr}");
+}
+
+#[rstest]
+fn test_hook_check_missing_program(repo: TestRepo, temp_home: TempDir) {
+    repo.write_project_config(
+        r#"[post-create]
+deps = "cd web && wt-test-no-such-program install"
+"#,
+    );
+    repo.commit("Add project config");
+
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    cmd.args(["hook", "check"]).current_dir(repo.root_path());
+    set_temp_home_env(&mut cmd, temp_home.path());
+    let output = cmd.output().unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success(), "stderr: {stderr}");
+    assert!(
+        stderr.contains("wt-test-no-such-program"),
+        "stderr: {stderr}"
+    );
+    assert!(stderr.contains("not found on PATH"), "stderr: {stderr}");
+}
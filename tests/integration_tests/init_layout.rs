@@ -0,0 +1,56 @@
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+use std::fs;
+
+#[rstest]
+fn test_init_converts_to_bare_layout(repo: TestRepo) {
+    let root = repo.root_path().to_path_buf();
+    fs::write(root.join("staged.txt"), "staged").unwrap();
+    repo.run_git(&["add", "staged.txt"]);
+    fs::write(root.join("untracked.txt"), "untracked").unwrap();
+
+    let output = repo
+        .wt_command()
+        .args(["init", "--yes"])
+        .current_dir(&root)
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+
+    let worktree = root.join("main");
+    assert!(worktree.join("untracked.txt").exists());
+    assert!(!root.join("staged.txt").exists());
+    assert_eq!(
+        repo.git_output(&["-C", ".git", "rev-parse", "--is-bare-repository"]),
+        "true"
+    );
+
+    // Staged changes stay staged in the new worktree
+    let staged = repo
+        .git_command()
+        .args(["diff", "--cached", "--name-only"])
+        .current_dir(&worktree)
+        .output()
+        .unwrap();
+    assert_eq!(String::from_utf8_lossy(&staged.stdout).trim(), "staged.txt");
+
+    let config = fs::read_to_string(repo.test_config_path()).unwrap();
+    assert!(
+        config.contains(r#"worktree-path = "../{{ branch | sanitize }}""#),
+        "config: {config}"
+    );
+}
+
+#[rstest]
+fn test_init_refuses_linked_worktree(mut repo: TestRepo) {
+    let feature = repo.add_worktree("feature");
+    let output = repo
+        .wt_command()
+        .args(["init", "--yes"])
+        .current_dir(&feature)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("main worktree"));
+}
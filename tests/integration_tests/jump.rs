@@ -0,0 +1,34 @@
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+
+#[rstest]
+fn test_jump_prefers_most_visited(mut repo: TestRepo) {
+    repo.add_worktree("feature-auth");
+    repo.add_worktree("feature-billing");
+
+    for branch in ["feature-billing", "feature-billing", "feature-auth"] {
+        let output = repo.wt_command().args(["switch", branch]).output().unwrap();
+        assert!(output.status.success());
+    }
+
+    let output = repo
+        .wt_command()
+        .args(["jump", "feature"])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert!(stderr.contains("feature-billing"), "stderr: {stderr}");
+}
+
+#[rstest]
+fn test_jump_no_match(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["jump", "nothing"])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success());
+    assert!(stderr.contains("No worktree matches"), "stderr: {stderr}");
+}
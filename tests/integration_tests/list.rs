@@ -324,6 +324,37 @@ fn test_list_with_remotes_flag(#[from(repo_with_remote)] repo: TestRepo) {
     });
 }
 
+/// Remote-only branches have their own kind, so scripts can tell them from
+/// local branches whose names contain a slash.
+#[rstest]
+fn test_list_with_remotes_json_kind(#[from(repo_with_remote)] repo: TestRepo) {
+    repo.create_branch("remote-feature");
+    repo.push_branch("remote-feature");
+    repo.run_git(&["branch", "-D", "remote-feature"]);
+    repo.create_branch("feature/local");
+
+    let output = repo
+        .wt_command()
+        .args(["list", "--branches", "--remotes", "--format=json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+
+    let remote = json
+        .iter()
+        .find(|item| item["branch"] == "origin/remote-feature")
+        .unwrap();
+    assert_eq!(remote["kind"], "remote");
+    assert!(remote["symbols"].as_str().unwrap().contains('⤓'));
+
+    let local = json
+        .iter()
+        .find(|item| item["branch"] == "feature/local")
+        .unwrap();
+    assert_eq!(local["kind"], "branch");
+}
+
 #[rstest]
 fn test_list_with_remotes_and_branches(#[from(repo_with_remote)] repo: TestRepo) {
     // Create local-only branches (not worktrees, not pushed)
@@ -2966,3 +2997,154 @@ fn test_list_empty_repo_json() {
     assert_eq!(item["commit"]["sha"], "");
     assert_eq!(item["commit"]["short_sha"], "");
 }
+
+/// Tests that `--du` reports each worktree's size in JSON, and only with the flag.
+#[rstest]
+fn test_list_du_json(mut repo: TestRepo) {
+    let feature = repo.add_worktree("feature");
+    std::fs::write(feature.join("big.bin"), vec![0u8; 256 * 1024]).unwrap();
+
+    let sizes = |args: &[&str]| -> Vec<(String, Option<u64>)> {
+        let output = repo.wt_command().args(args).output().unwrap();
+        assert!(output.status.success());
+        let json: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+        json.iter()
+            .map(|item| {
+                let branch = item["branch"].as_str().unwrap().to_string();
+                (branch, item["worktree"]["size"].as_u64())
+            })
+            .collect()
+    };
+
+    assert!(
+        sizes(&["list", "--format=json"])
+            .iter()
+            .all(|(_, size)| size.is_none())
+    );
+
+    let sizes = sizes(&["list", "--format=json", "--du"]);
+    let size_of = |name: &str| {
+        sizes
+            .iter()
+            .find(|(branch, _)| branch == name)
+            .and_then(|(_, size)| *size)
+            .unwrap_or_else(|| panic!("no size for {name}: {sizes:?}"))
+    };
+    assert!(size_of("main") > 0);
+    assert!(size_of("feature") >= 256 * 1024);
+}
+
+/// `--all-repos` lists each repository from `[repos]`, grouped in JSON.
+#[rstest]
+fn test_list_all_repos_json(mut repo: TestRepo) {
+    repo.add_worktree("feature");
+    let roots = repo.root_path().parent().unwrap().join("no-such-root");
+    repo.write_test_config(&format!(
+        "[repos]\npaths = ['{}']\nroots = ['{}']\n",
+        repo.root_path().display(),
+        roots.display()
+    ));
+
+    let output = repo
+        .wt_command()
+        .args(["list", "--all-repos", "--format=json"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let groups: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(groups.len(), 1);
+    let branches: Vec<_> = groups[0]["items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|item| item["branch"].as_str().unwrap())
+        .collect();
+    assert!(branches.contains(&"main"));
+    assert!(branches.contains(&"feature"));
+}
+
+/// Without `[repos]`, `--all-repos` explains what to configure.
+#[rstest]
+fn test_list_all_repos_unconfigured(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["list", "--all-repos"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("[repos]"));
+}
+
+/// Tests that `--health` flags worktree problems in JSON, and only with the flag.
+#[rstest]
+fn test_list_health_json(mut repo: TestRepo) {
+    repo.add_worktree("feature");
+    repo.lock_worktree("feature", None);
+
+    let health = |args: &[&str]| -> Vec<(String, Option<Vec<String>>)> {
+        let output = repo.wt_command().args(args).output().unwrap();
+        assert!(output.status.success());
+        let json: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+        json.iter()
+            .map(|item| {
+                let branch = item["branch"].as_str().unwrap().to_string();
+                let flags = item["worktree"]["health"].as_array().map(|flags| {
+                    flags
+                        .iter()
+                        .map(|f| f.as_str().unwrap().to_string())
+                        .collect()
+                });
+                (branch, flags)
+            })
+            .collect()
+    };
+
+    assert!(
+        health(&["list", "--format=json"])
+            .iter()
+            .filter(|(branch, _)| branch == "main" || branch == "feature")
+            .all(|(_, flags)| flags.is_none())
+    );
+
+    let health = health(&["list", "--format=json", "--health"]);
+    let flags_of = |name: &str| {
+        health
+            .iter()
+            .find(|(branch, _)| branch == name)
+            .and_then(|(_, flags)| flags.clone())
+            .unwrap_or_else(|| panic!("no health for {name}: {health:?}"))
+    };
+    assert_eq!(flags_of("main"), Vec::<String>::new());
+    assert_eq!(flags_of("feature"), vec!["locked".to_string()]);
+}
+
+#[rstest]
+fn test_list_group_by_status(mut repo: TestRepo) {
+    let feature = repo.add_worktree("feature");
+    std::fs::write(feature.join("scratch.txt"), "uncommitted").unwrap();
+
+    let output = repo
+        .wt_command()
+        .args(["list", "--group-by", "status"])
+        .env("NO_COLOR", "1")
+        .env_remove("CLICOLOR_FORCE")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let lines: Vec<&str> = stdout.lines().collect();
+    let position = |needle: &str| {
+        lines
+            .iter()
+            .position(|line| line.contains(needle))
+            .unwrap_or_else(|| panic!("{needle:?} missing from:\n{stdout}"))
+    };
+    // Dirty worktrees come first, each under its group's heading
+    assert!(position("DIRTY") < position("feature"));
+    assert!(position("feature") < position("CLEAN"));
+    assert!(position("CLEAN") < position("@ main"));
+}
@@ -473,3 +473,76 @@ timeout-ms = 1
         stderr
     );
 }
+
+#[rstest]
+fn test_list_config_column_widths(mut repo: TestRepo) {
+    repo.add_worktree("feature-with-a-long-name");
+    repo.write_test_config(
+        r#"[list.column-widths]
+branch = { max = 12 }
+"#,
+    );
+
+    let output = repo
+        .wt_command()
+        .arg("list")
+        .current_dir(repo.root_path())
+        .env("NO_COLOR", "1")
+        .env_remove("CLICOLOR_FORCE")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("+ feature-wit…"), "stdout:\n{stdout}");
+    assert!(
+        !stdout.contains("+ feature-with-a-long-name"),
+        "stdout:\n{stdout}"
+    );
+}
+
+#[rstest]
+fn test_list_width_flag(mut repo: TestRepo) {
+    repo.commit("A commit message long enough to need the message column's full width");
+    repo.add_worktree("feature");
+
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    cmd.args(["list", "--width", "60"])
+        .env("NO_COLOR", "1")
+        .env_remove("CLICOLOR_FORCE")
+        .current_dir(repo.root_path());
+
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    for line in stdout.lines() {
+        assert!(line.chars().count() <= 60, "line too wide: {line:?}");
+    }
+}
+
+#[rstest]
+fn test_list_no_pager(mut repo: TestRepo, temp_home: TempDir) {
+    repo.add_worktree("feature");
+    let run = |args: &[&str]| {
+        let mut cmd = wt_command();
+        repo.configure_wt_cmd(&mut cmd);
+        set_temp_home_env(&mut cmd, temp_home.path());
+        cmd.args(args).current_dir(repo.root_path());
+        let output = cmd.output().unwrap();
+        assert!(output.status.success());
+        String::from_utf8_lossy(&output.stdout).into_owned()
+    };
+
+    // Piped output is never paged, so --no-pager and the config key change nothing
+    let expected = run(&["list"]);
+    assert!(expected.contains("feature"), "stdout:\n{expected}");
+    assert_eq!(run(&["list", "--no-pager"]), expected);
+    let global_config_dir = temp_home.path().join(".config").join("worktrunk");
+    fs::create_dir_all(&global_config_dir).unwrap();
+    fs::write(
+        global_config_dir.join("config.toml"),
+        "[ui]\npager = false\n",
+    )
+    .unwrap();
+    assert_eq!(run(&["list"]), expected);
+}
@@ -0,0 +1,102 @@
+use crate::common::{TestRepo, repo};
+use ansi_str::AnsiStr;
+use rstest::rstest;
+
+#[rstest]
+fn test_lock_refuses_remove_until_unlocked(mut repo: TestRepo) {
+    let path = repo.add_worktree("feature");
+
+    let output = repo
+        .wt_command()
+        .args(["lock", "feature", "--reason", "on a USB drive"])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr)
+        .ansi_strip()
+        .into_owned();
+    assert!(output.status.success(), "stderr:\n{stderr}");
+    assert!(
+        stderr.contains("Locked feature (on a USB drive)"),
+        "stderr:\n{stderr}"
+    );
+
+    let output = repo
+        .wt_command()
+        .args(["list", "--format=json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    let item = json
+        .iter()
+        .find(|item| item["branch"] == "feature")
+        .unwrap();
+    assert_eq!(item["worktree"]["state"], "locked");
+    assert_eq!(item["worktree"]["reason"], "on a USB drive");
+    assert!(item["symbols"].as_str().unwrap().contains('⊞'));
+
+    let output = repo
+        .wt_command()
+        .args(["remove", "--foreground", "feature"])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr)
+        .ansi_strip()
+        .into_owned();
+    assert!(!output.status.success(), "stderr:\n{stderr}");
+    assert!(
+        stderr.contains("Cannot remove feature, worktree is locked (on a USB drive)"),
+        "stderr:\n{stderr}"
+    );
+    assert!(path.exists());
+
+    let output = repo
+        .wt_command()
+        .args(["unlock", "feature"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let output = repo
+        .wt_command()
+        .args(["remove", "--foreground", "feature"])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr:\n{stderr}");
+    assert!(!path.exists());
+}
+
+#[rstest]
+fn test_remove_keeps_branch_of_locked_missing_worktree(mut repo: TestRepo) {
+    // `git worktree prune` skips locked entries, so removing must not fall
+    // through to deleting the branch
+    let path = repo.add_worktree("feature");
+    repo.lock_worktree("feature", Some("on a USB drive"));
+    std::fs::remove_dir_all(&path).unwrap();
+
+    let output = repo
+        .wt_command()
+        .args(["remove", "--foreground", "feature"])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success(), "stderr:\n{stderr}");
+    assert!(stderr.contains("worktree is locked"), "stderr:\n{stderr}");
+
+    let branches = repo
+        .git_command()
+        .args(["branch", "--list", "feature"])
+        .output()
+        .unwrap();
+    assert!(String::from_utf8_lossy(&branches.stdout).contains("feature"));
+}
+
+#[rstest]
+fn test_lock_main_worktree_refused(repo: TestRepo) {
+    let output = repo.wt_command().arg("lock").output().unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(!output.status.success(), "stderr:\n{stderr}");
+    assert!(stderr.contains("can't be locked"), "stderr:\n{stderr}");
+}
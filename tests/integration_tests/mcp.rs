@@ -0,0 +1,106 @@
+use std::io::Write;
+use std::process::Stdio;
+
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+
+/// Send `requests` to `wt mcp`, one per line, and return its responses.
+fn mcp_session(repo: &TestRepo, requests: &[serde_json::Value]) -> Vec<serde_json::Value> {
+    let mut child = repo
+        .wt_command()
+        .arg("mcp")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let mut stdin = child.stdin.take().unwrap();
+    for request in requests {
+        writeln!(stdin, "{request}").unwrap();
+    }
+    drop(stdin);
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+    String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect()
+}
+
+#[rstest]
+fn test_mcp_create_exec_and_list(repo: TestRepo) {
+    let responses = mcp_session(
+        &repo,
+        &[
+            serde_json::json!({"jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {}}),
+            serde_json::json!({"jsonrpc": "2.0", "method": "notifications/initialized"}),
+            serde_json::json!({"jsonrpc": "2.0", "id": 2, "method": "tools/call",
+                "params": {"name": "create", "arguments": {"branch": "agent-task"}}}),
+            serde_json::json!({"jsonrpc": "2.0", "id": 3, "method": "tools/call",
+                "params": {"name": "exec", "arguments": {"branch": "agent-task", "command": "git branch --show-current"}}}),
+            serde_json::json!({"jsonrpc": "2.0", "id": 4, "method": "tools/call",
+                "params": {"name": "status", "arguments": {"branch": "agent-task"}}}),
+        ],
+    );
+    // The notification gets no response
+    assert_eq!(responses.len(), 4);
+    assert_eq!(responses[0]["result"]["serverInfo"]["name"], "worktrunk");
+
+    let create = &responses[1]["result"];
+    assert_eq!(create["isError"], false, "{create}");
+
+    let exec = &responses[2]["result"];
+    assert_eq!(exec["isError"], false, "{exec}");
+    assert_eq!(exec["content"][0]["text"], "agent-task");
+
+    let status = &responses[3]["result"];
+    assert_eq!(status["isError"], false, "{status}");
+    let item: serde_json::Value =
+        serde_json::from_str(status["content"][0]["text"].as_str().unwrap()).unwrap();
+    assert_eq!(item["branch"], "agent-task");
+    assert_eq!(item["dirty"], false);
+}
+
+#[rstest]
+fn test_mcp_exec_without_worktree(repo: TestRepo) {
+    let responses = mcp_session(
+        &repo,
+        &[
+            serde_json::json!({"jsonrpc": "2.0", "id": 1, "method": "tools/call",
+            "params": {"name": "exec", "arguments": {"branch": "missing", "command": "true"}}}),
+        ],
+    );
+    assert_eq!(responses[0]["result"]["isError"], true);
+    assert_eq!(
+        responses[0]["result"]["content"][0]["text"],
+        "No worktree for branch missing"
+    );
+}
+
+#[rstest]
+fn test_mcp_refuses_option_like_branches(repo: TestRepo) {
+    let responses = mcp_session(
+        &repo,
+        &[
+            serde_json::json!({"jsonrpc": "2.0", "id": 1, "method": "tools/call",
+                "params": {"name": "remove", "arguments": {"branch": "-D"}}}),
+            serde_json::json!({"jsonrpc": "2.0", "id": 2, "method": "tools/call",
+                "params": {"name": "create", "arguments": {"branch": "--execute=touch pwned"}}}),
+            serde_json::json!({"jsonrpc": "2.0", "id": 3, "method": "tools/call",
+                "params": {"name": "create", "arguments": {"branch": "bad..name"}}}),
+        ],
+    );
+    assert_eq!(
+        responses[0]["result"]["content"][0]["text"],
+        "Invalid branch name: -D"
+    );
+    assert_eq!(
+        responses[1]["result"]["content"][0]["text"],
+        "Invalid branch name: --execute=touch pwned"
+    );
+    assert_eq!(
+        responses[2]["result"]["content"][0]["text"],
+        "Invalid branch name: bad..name"
+    );
+    assert!(!repo.root_path().join("pwned").exists());
+}
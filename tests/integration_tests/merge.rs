@@ -2039,3 +2039,70 @@ fn test_merge_squash_ignored_with_no_commit(repo_with_multi_commit_feature: Test
         Some(feature_wt)
     ));
 }
+
+// =============================================================================
+// Multiple targets: merge into the first, backport to the rest
+// =============================================================================
+
+#[rstest]
+fn test_merge_backports_to_second_target(merge_scenario: (TestRepo, PathBuf)) {
+    let (repo, feature_wt) = merge_scenario;
+    // A release branch cut before the feature, without a worktree
+    repo.run_git(&["branch", "release", "main"]);
+
+    let output = make_snapshot_cmd(&repo, "merge", &["main,release"], Some(&feature_wt))
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let files = repo.git_output(&["ls-tree", "--name-only", "release"]);
+    assert!(files.lines().any(|file| file == "feature.txt"), "{files}");
+    let message = repo.git_output(&["log", "-1", "--format=%B", "release"]);
+    assert!(message.contains("cherry picked from commit"), "{message}");
+    // The temporary worktree is gone
+    let worktrees = repo.git_output(&["worktree", "list"]);
+    assert!(!worktrees.contains("wt-backport"), "{worktrees}");
+}
+
+#[rstest]
+fn test_merge_backport_conflict_leaves_target_unchanged(mut repo: TestRepo) {
+    let release_wt = repo.add_worktree("release");
+    repo.commit_in_worktree(&release_wt, "feature.txt", "release content", "Release fix");
+    let release_before = repo.git_output(&["rev-parse", "release"]);
+    let feature_wt = repo.add_worktree("feature");
+    repo.commit_in_worktree(
+        &feature_wt,
+        "feature.txt",
+        "feature content",
+        "Add feature file",
+    );
+
+    let output = make_snapshot_cmd(&repo, "merge", &["main,release"], Some(&feature_wt))
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert_eq!(output.status.code(), Some(5), "stderr: {stderr}");
+    assert!(stderr.contains("left unchanged"), "stderr: {stderr}");
+
+    // The merge into main still went through; release is as it was
+    let files = repo.git_output(&["ls-tree", "--name-only", "main"]);
+    assert!(files.lines().any(|file| file == "feature.txt"), "{files}");
+    assert_eq!(repo.git_output(&["rev-parse", "release"]), release_before);
+    let status = repo.git_output(&["-C", release_wt.to_str().unwrap(), "status", "--porcelain"]);
+    assert!(status.is_empty(), "{status}");
+}
+
+#[rstest]
+fn test_merge_rejects_target_listed_twice(merge_scenario: (TestRepo, PathBuf)) {
+    let (repo, feature_wt) = merge_scenario;
+
+    let output = make_snapshot_cmd(&repo, "merge", &["main,main"], Some(&feature_wt))
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("main is the merge target"));
+}
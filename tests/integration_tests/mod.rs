@@ -4,6 +4,10 @@
 //
 // Windows path differences are handled by snapshot filters in setup_snapshot_settings().
 
+pub mod absorb;
+pub mod activity;
+pub mod agent;
+pub mod alias;
 pub mod analyze_trace;
 // column_alignment merged into spacing_edge_cases
 pub mod approval_pty;
@@ -11,9 +15,14 @@ pub mod approval_pty;
 pub mod approval_save;
 pub mod approval_ui;
 pub mod approvals;
+pub mod auth;
+pub mod backport;
 pub mod bare_repository;
+pub mod bench;
+pub mod bundle;
 pub mod cache_sharing;
 pub mod ci_status;
+pub mod color;
 pub mod column_alignment_verification;
 pub mod completion;
 pub mod completion_validation;
@@ -22,38 +31,64 @@ pub mod config_show;
 pub mod config_show_theme;
 pub mod config_state;
 pub mod configure_shell;
+#[cfg(unix)]
+pub mod daemon;
 pub mod default_branch;
 pub mod diagnostic;
 pub mod directives;
 pub mod doc_templates;
+pub mod doctor;
+pub mod dry_run;
 pub mod e2e_shell;
 pub mod e2e_shell_post_start;
 pub mod for_each;
+pub mod fork_remote;
+pub mod gc;
 pub mod git_error_display;
+pub mod graph;
+pub mod group;
 pub mod help;
 pub mod hook_show;
 pub mod init;
+pub mod init_layout;
+pub mod jump;
 pub mod list;
 pub mod list_column_alignment;
 pub mod list_config;
 pub mod list_progressive;
+pub mod lock;
+pub mod mcp;
 pub mod merge;
+pub mod ops;
 pub mod output_system_guard;
+pub mod perf;
+#[cfg(unix)]
+pub mod plugin;
 pub mod post_start_commands;
+pub mod prompt;
 pub mod push;
 pub mod readme_sync;
+pub mod recent;
 pub mod remove;
 pub mod repository;
+pub mod resolve;
+pub mod review;
 pub mod security;
 pub mod select_config;
 pub mod shell_integration_prompt;
 pub mod shell_integration_windows;
 pub mod shell_powershell;
 pub mod shell_wrapper;
+pub mod snapshot;
 pub mod spacing_edge_cases;
+pub mod stack;
+pub mod state_store;
 pub mod statusline;
 pub mod step_copy_ignored;
 pub mod step_relocate;
+pub mod step_split;
+pub mod submodules;
 pub mod switch;
 pub mod switch_picker;
+pub mod undo;
 pub mod user_hooks;
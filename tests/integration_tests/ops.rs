@@ -0,0 +1,141 @@
+//! Tests for the `worktrunk::ops` library API.
+
+use std::fs;
+use std::sync::{Arc, Mutex};
+
+use worktrunk::config::UserConfig;
+use worktrunk::git::Repository;
+use worktrunk::ops::{self, CreateOptions, Event, EventHandler, RemoveOptions};
+use worktrunk::state::{Operation, StateStore};
+
+use crate::common::TestRepo;
+
+#[test]
+fn test_ops_create_list_remove() {
+    let repo = TestRepo::new();
+    let repository = Repository::at(repo.root_path().to_path_buf()).unwrap();
+    let path = repo.root_path().parent().unwrap().join("repo.feature");
+
+    let created = ops::create_worktree(
+        &repository,
+        &UserConfig::default(),
+        "feature",
+        &CreateOptions::default().path(&path),
+    )
+    .unwrap();
+    assert!(created.created_branch);
+    assert!(path.join(".git").exists());
+
+    let worktrees = ops::list_worktrees(&repository).unwrap();
+    assert_eq!(worktrees.len(), 2);
+    assert!(worktrees[0].is_main);
+    assert_eq!(worktrees[1].branch.as_deref(), Some("feature"));
+
+    let status = ops::worktree_status(&repository, "feature").unwrap();
+    assert!(!status.dirty);
+    assert_eq!((status.ahead, status.behind), (Some(0), Some(0)));
+    assert!(ops::worktree_status(&repository, "missing").is_err());
+
+    // The main worktree and dirty worktrees are refused
+    assert!(
+        ops::remove_worktree(&repository, &worktrees[0].path, &RemoveOptions::default()).is_err()
+    );
+    fs::write(path.join("scratch.txt"), "wip").unwrap();
+    assert!(ops::worktree_status(&repository, "feature").unwrap().dirty);
+    assert!(
+        ops::remove_worktree(&repository, &worktrees[1].path, &RemoveOptions::default()).is_err()
+    );
+
+    // The branch has no commits of its own, so it's merged and deleted
+    let removed = ops::remove_worktree(
+        &repository,
+        &worktrees[1].path,
+        &RemoveOptions::default().force(true),
+    )
+    .unwrap();
+    assert!(removed.deleted_branch);
+    assert!(!path.exists());
+    assert_eq!(ops::list_worktrees(&repository).unwrap().len(), 1);
+}
+
+#[test]
+fn test_ops_remove_keeps_protected_branch_and_journals() {
+    let repo = TestRepo::new();
+    repo.write_project_config("[branches]\nprotected = [\"release/*\"]\n");
+    let repository = Repository::at(repo.root_path().to_path_buf()).unwrap();
+    let path = repo.root_path().parent().unwrap().join("repo.release");
+
+    ops::create_worktree(
+        &repository,
+        &UserConfig::default(),
+        "release/1.0",
+        &CreateOptions::default().path(&path),
+    )
+    .unwrap();
+    let removed = ops::remove_worktree(&repository, &path, &RemoveOptions::default()).unwrap();
+    // Merged, but protected
+    assert!(!removed.deleted_branch);
+    assert!(repository.branch("release/1.0").exists_locally().unwrap());
+
+    // Journaled like `wt remove`, so `wt undo` can bring it back
+    let state = StateStore::new(repository.git_common_dir()).load().unwrap();
+    assert!(matches!(
+        &state.journal.last().unwrap().operation,
+        Operation::WorktreeRemoved { branch: Some(branch), .. } if branch == "release/1.0"
+    ));
+}
+
+/// Records events about the `repo.events` worktree and confirms removing it.
+/// The handler is process-wide, so events from other tests are ignored.
+#[derive(Default)]
+struct Recorder(Mutex<Vec<String>>);
+
+impl EventHandler for Recorder {
+    fn event(&self, event: Event<'_>) {
+        let event = format!("{event:?}");
+        if event.contains("events") {
+            self.0.lock().unwrap().push(event);
+        }
+    }
+
+    fn confirm(&self, question: &str) -> bool {
+        if !question.contains("repo.events") {
+            return false;
+        }
+        self.0.lock().unwrap().push(format!("confirm: {question}"));
+        true
+    }
+}
+
+#[test]
+fn test_ops_events() {
+    let repo = TestRepo::new();
+    let repository = Repository::at(repo.root_path().to_path_buf()).unwrap();
+    let path = repo.root_path().parent().unwrap().join("repo.events");
+    let recorder = Arc::new(Recorder::default());
+    ops::set_event_handler(recorder.clone());
+
+    let created = ops::create_worktree(
+        &repository,
+        &UserConfig::default(),
+        "events",
+        &CreateOptions::default().path(&path),
+    )
+    .unwrap();
+    fs::write(path.join("scratch.txt"), "wip").unwrap();
+    // The handler confirms removing the dirty worktree
+    ops::remove_worktree(&repository, &created.path, &RemoveOptions::default()).unwrap();
+    ops::clear_event_handler();
+
+    let events = recorder.0.lock().unwrap();
+    assert!(events[0].starts_with("Progress(\"Creating worktree for events"));
+    assert!(events[1].starts_with("Success(\"Created worktree for events"));
+    assert!(events[2].starts_with("confirm: "));
+    assert!(events[2].ends_with("has uncommitted changes; remove anyway?"));
+    assert!(
+        events
+            .last()
+            .unwrap()
+            .starts_with("Success(\"Removed worktree")
+    );
+}
@@ -36,6 +36,32 @@ const STDOUT_ALLOWED_PATHS: &[&str] = &[
     "config/hints.rs",
     // LLM prompt output for wt step commit --show-prompt
     "step_commands.rs",
+    // Prompt segment for wt prompt, read by shell prompts
+    "prompt.rs",
+    // Visited worktrees for wt recent
+    "recent.rs",
+    // State file JSON (or its path) for wt state
+    "state_store.rs",
+    // Journal listing for wt undo --list
+    "undo.rs",
+    // Run logs for wt hook logs
+    "hook_logs.rs",
+    // Group listing for wt group list
+    "group.rs",
+    // CI job listing for wt ci
+    "ci.rs",
+    // Conflicted file list for wt resolve --list
+    "resolve.rs",
+    // Stack tree for wt list --stacks
+    "stack.rs",
+    // Commit graph for wt graph
+    "graph.rs",
+    // Snapshot listing for wt snapshot list
+    "snapshot.rs",
+    // Timing summary for wt perf report
+    "perf.rs",
+    // Timings for the hidden wt bench command
+    "bench.rs",
 ];
 
 /// Substrings that indicate the line is a special case (e.g., in a comment or test reference)
@@ -0,0 +1,40 @@
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+
+#[rstest]
+fn test_perf_report_empty(repo: TestRepo) {
+    let output = repo.wt_command().args(["perf", "report"]).output().unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr:\n{stderr}");
+    assert!(
+        stderr.contains("No timings logged yet"),
+        "stderr:\n{stderr}"
+    );
+}
+
+#[rstest]
+fn test_perf_log_and_report(repo: TestRepo) {
+    // Off by default
+    let output = repo.wt_command().arg("list").output().unwrap();
+    assert!(output.status.success());
+    let log = repo.root_path().join(".git/wt-logs/perf.jsonl");
+    assert!(!log.exists());
+
+    let output = repo
+        .wt_command()
+        .arg("list")
+        .env("WORKTRUNK_PERF_LOG", "1")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let contents = std::fs::read_to_string(&log).unwrap();
+    assert_eq!(contents.lines().count(), 1, "{contents}");
+    assert!(contents.contains(r#""command":"list""#), "{contents}");
+
+    let output = repo.wt_command().args(["perf", "report"]).output().unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success());
+    assert!(stdout.contains("list"), "stdout:\n{stdout}");
+    assert!(stdout.contains("git "), "stdout:\n{stdout}");
+    assert!(stdout.contains("list collect"), "stdout:\n{stdout}");
+}
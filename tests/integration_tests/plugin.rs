@@ -0,0 +1,38 @@
+use std::os::unix::fs::PermissionsExt;
+
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+
+#[rstest]
+fn test_external_subcommand_runs_plugin(repo: TestRepo) {
+    let bin = tempfile::tempdir().unwrap();
+    let plugin = bin.path().join("wt-hello");
+    std::fs::write(
+        &plugin,
+        "#!/bin/sh\necho \"args: $*\"\necho \"branch: $WORKTRUNK_BRANCH\"\n",
+    )
+    .unwrap();
+    std::fs::set_permissions(&plugin, std::fs::Permissions::from_mode(0o755)).unwrap();
+    let path = std::env::join_paths(std::iter::once(bin.path().to_path_buf()).chain(
+        std::env::split_paths(&std::env::var_os("PATH").unwrap_or_default()),
+    ))
+    .unwrap();
+
+    let output = repo
+        .wt_command()
+        .args(["hello", "--flag", "value"])
+        .env("PATH", path)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "args: --flag value\nbranch: main\n"
+    );
+}
+
+#[rstest]
+fn test_unknown_subcommand_without_plugin_errors(repo: TestRepo) {
+    let output = repo.wt_command().arg("frobnicate").output().unwrap();
+    assert_eq!(output.status.code(), Some(2));
+}
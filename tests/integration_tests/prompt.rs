@@ -0,0 +1,44 @@
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+
+#[rstest]
+fn test_prompt_dirty_worktree(mut repo: TestRepo) {
+    let path = repo.add_worktree("feature");
+    std::fs::write(path.join("file.txt"), "changed").unwrap();
+    repo.run_git_in(&path, &["add", "file.txt"]);
+
+    let output = repo
+        .wt_command()
+        .args(["prompt", "--format=json"])
+        .current_dir(&path)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let value: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(value["branch"], "feature");
+    assert_eq!(value["dirty"], true);
+
+    let output = repo
+        .wt_command()
+        .arg("prompt")
+        .env("NO_COLOR", "1")
+        .current_dir(&path)
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("feature"), "stdout: {stdout}");
+    assert!(stdout.contains('*'), "stdout: {stdout}");
+}
+
+#[rstest]
+fn test_prompt_outside_repo(repo: TestRepo) {
+    let dir = tempfile::tempdir().unwrap();
+    let output = repo
+        .wt_command()
+        .arg("prompt")
+        .current_dir(dir.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+}
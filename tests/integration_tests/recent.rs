@@ -0,0 +1,46 @@
+use crate::common::{TestRepo, repo};
+use ansi_str::AnsiStr;
+use rstest::rstest;
+
+#[rstest]
+fn test_recent_orders_by_frecency(mut repo: TestRepo) {
+    repo.add_worktree("feature-auth");
+    repo.add_worktree("feature-billing");
+    repo.add_worktree("feature-unused");
+
+    for branch in ["feature-billing", "feature-billing", "feature-auth"] {
+        let output = repo.wt_command().args(["switch", branch]).output().unwrap();
+        assert!(output.status.success());
+    }
+
+    let output = repo.wt_command().arg("recent").output().unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout)
+        .ansi_strip()
+        .into_owned();
+    assert!(output.status.success());
+    let branches: Vec<&str> = stdout
+        .lines()
+        .filter_map(|line| line.split_whitespace().next())
+        .collect();
+    assert_eq!(branches, ["feature-billing", "feature-auth"]);
+
+    let output = repo
+        .wt_command()
+        .args(["recent", "-n", "1"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.lines().count(), 1, "stdout: {stdout}");
+}
+
+#[rstest]
+fn test_recent_without_visits(repo: TestRepo) {
+    let output = repo.wt_command().arg("recent").output().unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+    assert!(
+        stderr.contains("No visits recorded yet"),
+        "stderr: {stderr}"
+    );
+}
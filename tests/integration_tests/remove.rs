@@ -1809,3 +1809,49 @@ fn test_remove_pruned_worktree_keep_branch(mut repo: TestRepo) {
         "Branch should still exist"
     );
 }
+
+#[rstest]
+fn test_remove_protected_branch(mut repo: TestRepo) {
+    repo.write_project_config("[branches]\nprotected = [\"release/*\"]\n");
+    repo.commit("Add project config");
+    let worktree_path = repo.add_worktree("release/1.0");
+
+    // -D is refused outright
+    let output = repo
+        .wt_command()
+        .args(["remove", "--foreground", "-D", "release/1.0"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Cannot delete protected branch"),
+        "stderr: {stderr}"
+    );
+    assert!(worktree_path.exists());
+
+    // A plain remove drops the worktree but keeps the integrated branch
+    let output = repo
+        .wt_command()
+        .args(["remove", "--foreground", "release/1.0"])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert!(
+        stderr.contains("Keeping protected branch"),
+        "stderr: {stderr}"
+    );
+    assert!(!worktree_path.exists());
+    let branch_list = repo
+        .git_command()
+        .args(["branch", "--list", "release/1.0"])
+        .output()
+        .unwrap();
+    assert!(
+        !String::from_utf8_lossy(&branch_list.stdout)
+            .trim()
+            .is_empty(),
+        "Protected branch should be kept"
+    );
+}
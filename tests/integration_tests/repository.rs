@@ -871,3 +871,49 @@ fn test_branch_diff_stats_scoped_to_sparse_checkout() {
     assert_eq!(full_stats.added, 4, "full: inside/ + outside/ additions");
     assert_eq!(full_stats.deleted, 2, "full: inside/ + outside/ deletions");
 }
+
+#[test]
+fn test_rewritten_remote_branch() {
+    let mut repo = TestRepo::new();
+    repo.setup_remote("main");
+    repo.run_git(&["checkout", "-b", "feature"]);
+    fs::write(repo.root_path().join("feature.txt"), "one\n").unwrap();
+    repo.run_git(&["add", "."]);
+    repo.run_git(&["commit", "-m", "feature"]);
+
+    let repository = Repository::at(repo.root_path().to_path_buf()).unwrap();
+    // Never pushed: nothing to lease
+    assert_eq!(
+        repository
+            .rewritten_remote_branch("origin", "feature")
+            .unwrap(),
+        None
+    );
+
+    repo.run_git(&["push", "origin", "feature"]);
+    let pushed = repository
+        .run_command(&["rev-parse", "feature"])
+        .unwrap()
+        .trim()
+        .to_string();
+
+    // New commits on top fast-forward
+    fs::write(repo.root_path().join("feature.txt"), "two\n").unwrap();
+    repo.run_git(&["commit", "-am", "more"]);
+    assert_eq!(
+        repository
+            .rewritten_remote_branch("origin", "feature")
+            .unwrap(),
+        None
+    );
+
+    // Rewriting the pushed commit needs a force push
+    repo.run_git(&["reset", "--soft", "HEAD~2"]);
+    repo.run_git(&["commit", "-m", "squashed"]);
+    assert_eq!(
+        repository
+            .rewritten_remote_branch("origin", "feature")
+            .unwrap(),
+        Some(pushed)
+    );
+}
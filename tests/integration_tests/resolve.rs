@@ -0,0 +1,112 @@
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+
+/// Feature worktree whose `wt merge main` stops on a conflict in `shared.txt`.
+fn conflicted_merge(repo: &TestRepo) -> std::path::PathBuf {
+    std::fs::write(repo.root_path().join("shared.txt"), "initial content\n").unwrap();
+    repo.run_git(&["add", "shared.txt"]);
+    repo.commit("Add shared file");
+
+    let feature_wt = repo.root_path().parent().unwrap().join("repo.feature");
+    repo.run_git(&[
+        "worktree",
+        "add",
+        feature_wt.to_str().unwrap(),
+        "-b",
+        "feature",
+    ]);
+    std::fs::write(feature_wt.join("shared.txt"), "feature version\n").unwrap();
+    repo.run_git_in(
+        &feature_wt,
+        &["commit", "-am", "Update shared.txt in feature"],
+    );
+
+    std::fs::write(repo.root_path().join("shared.txt"), "main version\n").unwrap();
+    repo.run_git(&["commit", "-am", "Update shared.txt in main"]);
+
+    let output = repo
+        .wt_command()
+        .args(["merge", "main"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("wt resolve"));
+    feature_wt
+}
+
+#[rstest]
+fn test_resolve_without_rebase(repo: TestRepo) {
+    let output = repo.wt_command().arg("resolve").output().unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("No rebase in progress"));
+}
+
+#[rstest]
+fn test_resolve_with_merge_tool(repo: TestRepo) {
+    let feature_wt = conflicted_merge(&repo);
+
+    let output = repo
+        .wt_command()
+        .args(["resolve", "--list"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "shared.txt\n");
+
+    // A merge tool that takes the feature side
+    repo.run_git(&[
+        "config",
+        "mergetool.theirs.cmd",
+        "cp \"$REMOTE\" \"$MERGED\"",
+    ]);
+    repo.run_git(&["config", "mergetool.theirs.trustExitCode", "true"]);
+    repo.run_git(&["config", "mergetool.keepBackup", "false"]);
+
+    let output = repo
+        .wt_command()
+        .args(["resolve", "--tool", "theirs"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(stderr.contains("(1/1 resolved)"), "{stderr}");
+    assert!(stderr.contains("Rebase complete"), "{stderr}");
+    // The interrupted merge is suggested once
+    assert!(stderr.contains("wt merge main"), "{stderr}");
+    assert_eq!(
+        std::fs::read_to_string(feature_wt.join("shared.txt")).unwrap(),
+        "feature version\n"
+    );
+}
+
+#[rstest]
+fn test_resolve_explain(repo: TestRepo) {
+    let feature_wt = conflicted_merge(&repo);
+    repo.write_test_config(
+        r#"[commit.generation]
+command = "grep -q 'feature version' && echo 'The commit rewrites the line upstream changed'"
+"#,
+    );
+    repo.run_git(&[
+        "config",
+        "mergetool.theirs.cmd",
+        "cp \"$REMOTE\" \"$MERGED\"",
+    ]);
+    repo.run_git(&["config", "mergetool.theirs.trustExitCode", "true"]);
+
+    let output = repo
+        .wt_command()
+        .args(["resolve", "--explain", "--tool", "theirs", "--no-continue"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(
+        stderr.contains("The commit rewrites the line upstream changed"),
+        "{stderr}"
+    );
+    assert!(stderr.contains("git rebase --continue"), "{stderr}");
+}
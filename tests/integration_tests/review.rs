@@ -0,0 +1,135 @@
+use crate::common::mock_commands::{MockConfig, MockResponse, copy_mock_binary};
+use crate::common::{TestRepo, repo_with_remote, wait_for};
+use rstest::rstest;
+use std::path::Path;
+
+const PR_RESPONSE: &str = r#"{
+    "title": "Fix authentication bug in login flow",
+    "body": "Tokens expired a minute early.\n\nTo test: log in and wait.",
+    "user": {"login": "alice"},
+    "state": "open",
+    "draft": false,
+    "head": {
+        "ref": "feature-auth",
+        "repo": {"name": "test-repo", "owner": {"login": "owner"}}
+    },
+    "base": {
+        "ref": "main",
+        "repo": {"name": "test-repo", "owner": {"login": "owner"}}
+    },
+    "html_url": "https://github.com/owner/test-repo/pull/101"
+}"#;
+
+/// Point origin at GitHub (redirected to the local bare remote), publish
+/// `feature-auth` and `refs/pull/101/head` there, and mock `gh api`.
+fn setup_pr(repo: &TestRepo) -> std::path::PathBuf {
+    repo.run_git(&["push", "origin", "main:refs/heads/feature-auth"]);
+    repo.run_git(&["push", "origin", "main:refs/pull/101/head"]);
+    let bare_url = repo.git_output(&["config", "remote.origin.url"]);
+    repo.run_git(&[
+        "remote",
+        "set-url",
+        "origin",
+        "https://github.com/owner/test-repo.git",
+    ]);
+    repo.run_git(&[
+        "config",
+        &format!("url.{bare_url}.insteadOf"),
+        "https://github.com/owner/test-repo.git",
+    ]);
+
+    let mock_bin = repo.root_path().join("mock-bin");
+    std::fs::create_dir_all(&mock_bin).unwrap();
+    copy_mock_binary(&mock_bin, "gh");
+    std::fs::write(mock_bin.join("pr_response.json"), PR_RESPONSE).unwrap();
+    MockConfig::new("gh")
+        .version("gh version 2.0.0 (mock)")
+        .command("api", MockResponse::file("pr_response.json"))
+        .command("_default", MockResponse::exit(1))
+        .write(&mock_bin);
+    mock_bin
+}
+
+fn wt(repo: &TestRepo, mock_bin: &Path, dir: &Path, args: &[&str]) -> std::process::Output {
+    let mut cmd = repo.wt_command();
+    cmd.current_dir(dir).env("MOCK_CONFIG_DIR", mock_bin);
+    let path = std::env::var_os("PATH").unwrap_or_default();
+    let mut paths: Vec<_> = std::env::split_paths(&path).collect();
+    paths.insert(0, mock_bin.to_path_buf());
+    cmd.env("PATH", std::env::join_paths(paths).unwrap());
+    let output = cmd.args(args).output().unwrap();
+    assert!(
+        output.status.success(),
+        "wt {}: {}",
+        args.join(" "),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    output
+}
+
+#[rstest]
+fn test_review_tracking_and_done(#[from(repo_with_remote)] repo: TestRepo) {
+    let mock_bin = setup_pr(&repo);
+
+    let output = wt(
+        &repo,
+        &mock_bin,
+        repo.root_path(),
+        &["review", "101", "--yes"],
+    );
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Tokens expired a minute early."),
+        "{stderr}"
+    );
+    let worktree = repo.root_path().parent().unwrap().join("repo.feature-auth");
+    assert!(worktree.exists());
+    assert_eq!(
+        repo.git_output(&["config", "worktrunk.review.pr-101.branch"]),
+        "feature-auth"
+    );
+
+    // The branch was created by the review, so it goes too
+    wt(&repo, &mock_bin, &worktree, &["review", "done", "--yes"]);
+    wait_for("review worktree removal", || {
+        !worktree.exists()
+            && repo
+                .git_output(&["branch", "--list", "feature-auth"])
+                .is_empty()
+    });
+    let recorded = repo
+        .git_command()
+        .args(["config", "--get-regexp", r"^worktrunk\.review\."])
+        .output()
+        .unwrap();
+    assert!(recorded.stdout.is_empty());
+}
+
+#[rstest]
+fn test_review_detached(#[from(repo_with_remote)] repo: TestRepo) {
+    let mock_bin = setup_pr(&repo);
+
+    wt(
+        &repo,
+        &mock_bin,
+        repo.root_path(),
+        &["review", "101", "--detach"],
+    );
+    let worktree = repo.root_path().parent().unwrap().join("repo.pr-101");
+    assert!(worktree.exists());
+    assert_eq!(
+        repo.git_output(&["-C", worktree.to_str().unwrap(), "rev-parse", "HEAD"]),
+        repo.git_output(&["rev-parse", "main"])
+    );
+    assert!(
+        repo.git_output(&["branch", "--list", "feature-auth"])
+            .is_empty()
+    );
+}
+
+#[rstest]
+fn test_review_done_outside_review(#[from(repo_with_remote)] repo: TestRepo) {
+    let output = repo.wt_command().args(["review", "done"]).output().unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Not a review worktree"));
+}
@@ -781,8 +781,8 @@ mod unix_tests {
 
         // Shell-agnostic assertions: these must be true for ALL shells
         assert_eq!(
-            output.exit_code, 1,
-            "{}: Command should fail with exit code 1",
+            output.exit_code, 3,
+            "{}: Command should fail with the git-state exit code",
             shell
         );
         output.assert_no_directive_leaks();
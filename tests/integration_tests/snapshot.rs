@@ -0,0 +1,78 @@
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+
+fn wt(repo: &TestRepo, args: &[&str]) -> std::process::Output {
+    let output = repo.wt_command().args(args).output().unwrap();
+    assert!(
+        output.status.success(),
+        "wt {}: {}",
+        args.join(" "),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    output
+}
+
+#[rstest]
+fn test_snapshot_restore(repo: TestRepo) {
+    repo.commit("Base");
+    let root = repo.root_path();
+    std::fs::write(root.join("staged.txt"), "staged\n").unwrap();
+    repo.run_git(&["add", "staged.txt"]);
+    std::fs::write(root.join("file.txt"), "experiment\n").unwrap();
+    let head = repo.git_output(&["rev-parse", "HEAD"]);
+    let status = repo.git_output(&["status", "--porcelain"]);
+
+    let output = wt(&repo, &["snapshot", "before experiment"]);
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Saved snapshot"));
+    // Snapshotting leaves the worktree, index and HEAD as they were
+    assert_eq!(repo.git_output(&["status", "--porcelain"]), status);
+    assert_eq!(repo.git_output(&["rev-parse", "HEAD"]), head);
+    assert_eq!(repo.git_output(&["stash", "list"]), "");
+
+    let list = wt(&repo, &["snapshot", "list"]);
+    let stdout = String::from_utf8_lossy(&list.stdout);
+    assert!(
+        stdout.contains("1  ") && stdout.contains("before experiment"),
+        "{stdout}"
+    );
+
+    // Throw the changes away, then bring them back
+    repo.run_git(&["reset", "--hard"]);
+    std::fs::write(root.join("file.txt"), "something else\n").unwrap();
+    let diff = wt(&repo, &["snapshot", "diff"]);
+    assert!(String::from_utf8_lossy(&diff.stdout).contains("something else"));
+
+    let output = wt(&repo, &["snapshot", "restore"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Saved current changes as snapshot"),
+        "{stderr}"
+    );
+    assert!(stderr.contains("Restored snapshot"), "{stderr}");
+    assert_eq!(repo.git_output(&["status", "--porcelain"]), status);
+    assert_eq!(
+        std::fs::read_to_string(root.join("file.txt")).unwrap(),
+        "experiment\n"
+    );
+
+    // The changes overwritten by the restore are snapshot 2
+    wt(&repo, &["snapshot", "restore", "2"]);
+    assert_eq!(
+        std::fs::read_to_string(root.join("file.txt")).unwrap(),
+        "something else\n"
+    );
+}
+
+#[rstest]
+fn test_snapshot_clean_worktree(repo: TestRepo) {
+    let output = wt(&repo, &["snapshot"]);
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Nothing to snapshot"));
+
+    let output = repo
+        .wt_command()
+        .args(["snapshot", "restore", "4"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("No snapshot"));
+}
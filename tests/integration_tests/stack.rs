@@ -0,0 +1,148 @@
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+
+/// Worktrees `repo.api` on `main` and `repo.ui` on `api`, each with a commit.
+fn stacked(repo: &TestRepo) -> (std::path::PathBuf, std::path::PathBuf) {
+    let parent = repo.root_path().parent().unwrap();
+    let api_wt = parent.join("repo.api");
+    let ui_wt = parent.join("repo.ui");
+    repo.run_git(&["worktree", "add", api_wt.to_str().unwrap(), "-b", "api"]);
+    std::fs::write(api_wt.join("api.txt"), "api\n").unwrap();
+    repo.run_git_in(&api_wt, &["add", "api.txt"]);
+    repo.run_git_in(&api_wt, &["commit", "-m", "Add api"]);
+    repo.run_git(&[
+        "worktree",
+        "add",
+        ui_wt.to_str().unwrap(),
+        "-b",
+        "ui",
+        "api",
+    ]);
+    std::fs::write(ui_wt.join("ui.txt"), "ui\n").unwrap();
+    repo.run_git_in(&ui_wt, &["add", "ui.txt"]);
+    repo.run_git_in(&ui_wt, &["commit", "-m", "Add ui"]);
+
+    for args in [
+        ["stack", "add", "main", "api"],
+        ["stack", "add", "api", "ui"],
+    ] {
+        let output = repo.wt_command().args(args).output().unwrap();
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+    (api_wt, ui_wt)
+}
+
+#[rstest]
+fn test_stack_add_rejects_cycle(repo: TestRepo) {
+    stacked(&repo);
+    let output = repo
+        .wt_command()
+        .args(["stack", "add", "ui", "api"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("already stacked on it"));
+}
+
+/// `wt list --stacks` without colors.
+fn list_stacks(repo: &TestRepo) -> String {
+    let output = repo
+        .wt_command()
+        .args(["list", "--stacks"])
+        .env("NO_COLOR", "1")
+        .env_remove("CLICOLOR_FORCE")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+#[rstest]
+fn test_list_stacks(repo: TestRepo) {
+    stacked(&repo);
+    assert_eq!(list_stacks(&repo), "main\n└─ api  ↑1\n   └─ ui  ↑1\n");
+}
+
+#[rstest]
+fn test_sync_rebases_in_order(repo: TestRepo) {
+    let (api_wt, ui_wt) = stacked(&repo);
+    std::fs::write(repo.root_path().join("main.txt"), "main\n").unwrap();
+    repo.run_git(&["add", "main.txt"]);
+    repo.commit("Advance main");
+
+    let output = repo
+        .wt_command()
+        .arg("sync")
+        .env("NO_COLOR", "1")
+        .env_remove("CLICOLOR_FORCE")
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let api_at = stderr.find("Rebased api").unwrap();
+    let ui_at = stderr.find("Rebased ui").unwrap();
+    assert!(api_at < ui_at, "{stderr}");
+
+    // main → api → ui, one commit each
+    assert!(api_wt.join("main.txt").exists());
+    assert!(ui_wt.join("main.txt").exists());
+    let log = repo
+        .git_command()
+        .args(["log", "--format=%s", "main..ui"])
+        .output()
+        .unwrap();
+    assert_eq!(String::from_utf8_lossy(&log.stdout), "Add ui\nAdd api\n");
+
+    let output = repo.wt_command().arg("sync").output().unwrap();
+    assert!(String::from_utf8_lossy(&output.stderr).contains("up to date"));
+}
+
+#[rstest]
+fn test_stack_remove_reparents_children(repo: TestRepo) {
+    stacked(&repo);
+    let output = repo
+        .wt_command()
+        .args(["stack", "remove", "api"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(list_stacks(&repo), "main\n└─ ui  ↑2\n");
+}
+
+#[rstest]
+fn test_stack_submit_dry_run(repo: TestRepo) {
+    let (_, ui_wt) = stacked(&repo);
+    let output = repo
+        .wt_command()
+        .args(["--dry-run", "stack", "submit"])
+        .current_dir(&ui_wt)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("api → main"), "{stderr}");
+    assert!(stderr.contains("ui → api"), "{stderr}");
+}
+
+#[rstest]
+fn test_stack_submit_unstacked_branch(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["stack", "submit"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("isn't part of a stack"));
+}
@@ -0,0 +1,45 @@
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+
+#[rstest]
+fn test_state_records_access_history(mut repo: TestRepo) {
+    let feature = repo.add_worktree("feature");
+    let output = repo
+        .wt_command()
+        .args(["switch", "feature"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let output = repo.wt_command().arg("state").output().unwrap();
+    assert!(output.status.success());
+    let state: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(state["version"], 1);
+    let access = state["access"].as_object().unwrap();
+    assert!(
+        access
+            .keys()
+            .any(|path| path.ends_with(&*feature.file_name().unwrap().to_string_lossy())),
+        "access: {access:?}"
+    );
+
+    let output = repo
+        .wt_command()
+        .args(["state", "--path"])
+        .output()
+        .unwrap();
+    let path = String::from_utf8_lossy(&output.stdout);
+    assert!(path.trim().ends_with("state.json"), "path: {path}");
+}
+
+#[rstest]
+fn test_state_rejects_newer_schema(repo: TestRepo) {
+    let dir = repo.root_path().join(".git/worktrunk");
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("state.json"), r#"{"version": 999}"#).unwrap();
+
+    let output = repo.wt_command().arg("state").output().unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("newer"), "stderr: {stderr}");
+}
@@ -0,0 +1,75 @@
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+
+fn log_subjects(repo: &TestRepo) -> Vec<String> {
+    let output = repo
+        .git_command()
+        .args(["log", "--format=%s", "-n", "3"])
+        .output()
+        .unwrap();
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(str::to_string)
+        .collect()
+}
+
+fn write_changes(repo: &TestRepo) {
+    for (path, content) in [
+        ("src/lib.rs", "pub fn a() {}\n"),
+        ("docs/guide.md", "# Guide\n"),
+    ] {
+        let path = repo.root_path().join(path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+}
+
+#[rstest]
+fn test_step_split_by_directory(repo: TestRepo) {
+    write_changes(&repo);
+
+    let output = repo
+        .wt_command()
+        .args(["step", "split", "--yes"])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(stderr.contains("Split into 2 commits"), "{stderr}");
+    assert_eq!(log_subjects(&repo)[..2], ["Update src", "Update docs"]);
+}
+
+#[rstest]
+fn test_step_split_llm_groups(repo: TestRepo) {
+    write_changes(&repo);
+    repo.write_test_config(
+        r#"[commit.generation]
+command = "cat >/dev/null; printf '## Add library and guide\nsrc/lib.rs\ndocs/guide.md\n'"
+"#,
+    );
+
+    let output = repo
+        .wt_command()
+        .args(["step", "split", "--yes"])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert_eq!(log_subjects(&repo)[0], "Add library and guide");
+}
+
+#[rstest]
+fn test_step_split_dry_run(repo: TestRepo) {
+    write_changes(&repo);
+    let before = log_subjects(&repo);
+
+    let output = repo
+        .wt_command()
+        .args(["step", "split", "--dry-run"])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(stderr.contains("Update docs"), "{stderr}");
+    assert_eq!(log_subjects(&repo), before);
+}
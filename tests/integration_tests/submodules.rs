@@ -0,0 +1,81 @@
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+
+/// Add a submodule `lib` (from a sibling repo) to `main` and commit it.
+fn add_submodule(repo: &TestRepo) {
+    let lib = repo.root_path().parent().unwrap().join("lib-src");
+    std::fs::create_dir_all(&lib).unwrap();
+    repo.run_git_in(&lib, &["init", "--initial-branch", "main"]);
+    std::fs::write(lib.join("lib.txt"), "library").unwrap();
+    repo.run_git_in(&lib, &["add", "lib.txt"]);
+    repo.run_git_in(&lib, &["commit", "-m", "Add library"]);
+
+    // Local-path submodules need file transport, disabled by default since git
+    // 2.38.1. Set in the test's global config, as the submodule clones don't
+    // read this repo's config
+    repo.run_git(&["config", "--global", "protocol.file.allow", "always"]);
+    repo.run_git(&["submodule", "add", lib.to_str().unwrap(), "lib"]);
+    repo.run_git(&["commit", "-m", "Add lib submodule"]);
+}
+
+fn feature_path(repo: &TestRepo) -> std::path::PathBuf {
+    repo.root_path().parent().unwrap().join(format!(
+        "{}.feature",
+        repo.root_path().file_name().unwrap().to_string_lossy()
+    ))
+}
+
+#[rstest]
+fn test_switch_create_initializes_submodules(repo: TestRepo) {
+    add_submodule(&repo);
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "feature"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(feature_path(&repo).join("lib/lib.txt").exists());
+}
+
+#[rstest]
+fn test_switch_create_skips_submodules_when_disabled(repo: TestRepo) {
+    add_submodule(&repo);
+    std::fs::write(repo.test_config_path(), "[submodules]\nupdate = false\n").unwrap();
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "feature"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert!(!feature_path(&repo).join("lib/lib.txt").exists());
+}
+
+#[rstest]
+fn test_remove_deinits_submodules(repo: TestRepo) {
+    add_submodule(&repo);
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "feature"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    // Plain `git worktree remove` refuses: "working trees containing submodules cannot be moved or removed"
+    let output = repo
+        .wt_command()
+        .args(["remove", "--foreground", "feature"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(!feature_path(&repo).exists());
+}
@@ -3284,3 +3284,411 @@ fn test_switch_base_without_create_warns_not_errors(repo: TestRepo) {
         &["base-test", "--base", "-"],
     );
 }
+
+#[rstest]
+fn test_switch_create_sparse_profile(repo: TestRepo) {
+    for dir in ["packages/web", "packages/api"] {
+        fs::create_dir_all(repo.root_path().join(dir)).unwrap();
+        fs::write(repo.root_path().join(dir).join("index.js"), "").unwrap();
+    }
+    repo.run_git(&["add", "packages"]);
+    repo.run_git(&["commit", "-m", "Add packages"]);
+    repo.write_project_config("[sparse-profiles]\nweb = [\"packages/web\"]\n");
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "feature", "--sparse", "web"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let worktree = repo.root_path().parent().unwrap().join(format!(
+        "{}.feature",
+        repo.root_path().file_name().unwrap().to_string_lossy()
+    ));
+    assert!(worktree.join("packages/web/index.js").exists());
+    assert!(!worktree.join("packages/api").exists());
+}
+
+#[rstest]
+fn test_switch_create_unknown_sparse_profile(repo: TestRepo) {
+    repo.write_project_config("[sparse-profiles]\nweb = [\"packages/web\"]\n");
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "feature", "--sparse", "mobile"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("available: web"), "stderr: {stderr}");
+}
+
+// Windows symlinks need Developer Mode
+#[cfg(unix)]
+#[rstest]
+fn test_switch_create_links_build_cache(repo: TestRepo) {
+    fs::write(repo.root_path().join(".gitignore"), "target/\n").unwrap();
+    repo.run_git(&["add", ".gitignore"]);
+    repo.run_git(&["commit", "-m", "Ignore target"]);
+    fs::create_dir_all(repo.root_path().join("target/debug")).unwrap();
+    fs::write(repo.root_path().join("target/debug/app"), "binary").unwrap();
+    repo.write_project_config("[build-cache]\ndirs = [\"target\", \"src\"]\n");
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "feature"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("not gitignored"), "stderr: {stderr}");
+
+    let worktree = repo.root_path().parent().unwrap().join(format!(
+        "{}.feature",
+        repo.root_path().file_name().unwrap().to_string_lossy()
+    ));
+    let target = worktree.join("target");
+    assert!(target.symlink_metadata().unwrap().file_type().is_symlink());
+    assert_eq!(
+        fs::read_to_string(target.join("debug/app")).unwrap(),
+        "binary"
+    );
+}
+
+#[rstest]
+fn test_switch_create_writes_env_file(repo: TestRepo) {
+    repo.write_project_config(
+        "[env]\nAPP_BRANCH = \"{{ branch }}\"\nPORT = \"{{ branch | hash_port }}\"\n",
+    );
+
+    let (directive_path, _guard) = directive_file();
+    let mut cmd = repo.wt_command();
+    configure_directive_file(&mut cmd, &directive_path);
+    let output = cmd
+        .args(["switch", "--create", "feature"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let worktree = repo.root_path().parent().unwrap().join(format!(
+        "{}.feature",
+        repo.root_path().file_name().unwrap().to_string_lossy()
+    ));
+    let env = fs::read_to_string(worktree.join(".wt.env")).unwrap();
+    assert!(env.starts_with("APP_BRANCH=feature\nPORT=1"), "env: {env}");
+
+    // Exported along with the cd, and excluded from git status
+    let directives = fs::read_to_string(&directive_path).unwrap();
+    assert!(
+        directives.contains("export APP_BRANCH='feature'"),
+        "directives: {directives}"
+    );
+    let status = repo
+        .git_command()
+        .args(["status", "--porcelain"])
+        .current_dir(&worktree)
+        .output()
+        .unwrap();
+    assert!(String::from_utf8_lossy(&status.stdout).trim().is_empty());
+}
+
+#[rstest]
+fn test_switch_create_allocates_port_blocks(repo: TestRepo) {
+    repo.write_project_config("[env]\nPORT = \"{{ port }}-{{ port_end }}\"\n");
+
+    for branch in ["first", "second"] {
+        let output = repo
+            .wt_command()
+            .args(["switch", "--create", branch])
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "stderr: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    let env = |branch: &str| {
+        let worktree = repo.root_path().parent().unwrap().join(format!(
+            "{}.{branch}",
+            repo.root_path().file_name().unwrap().to_string_lossy()
+        ));
+        fs::read_to_string(worktree.join(".wt.env")).unwrap()
+    };
+    assert_eq!(env("first"), "PORT=3000-3009\n");
+    assert_eq!(env("second"), "PORT=3010-3019\n");
+}
+
+#[rstest]
+fn test_switch_from_issue(repo: TestRepo) {
+    let mock_bin = repo.root_path().join("mock-bin");
+    fs::create_dir_all(&mock_bin).unwrap();
+    copy_mock_binary(&mock_bin, "gh");
+    MockConfig::new("gh")
+        .version("gh version 2.0.0 (mock)")
+        .command(
+            "issue view 1234",
+            MockResponse::output(
+                r#"{"title":"Add dark mode","url":"https://github.com/org/repo/issues/1234"}"#,
+            ),
+        )
+        .command("_default", MockResponse::exit(1))
+        .write(&mock_bin);
+    repo.write_test_config(
+        "[start]\ntracker = \"github\"\nbranch = \"feat/{{ issue }}-{{ slug }}\"\n",
+    );
+
+    let mut cmd = repo.wt_command();
+    cmd.args(["switch", "--create", "--from-issue", "1234", "--no-cd"]);
+    configure_mock_gh_env(&mut cmd, &mock_bin);
+    let output = cmd.output().unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(
+        repo.git_output(&["branch", "--list", "feat/1234-add-dark-mode"])
+            .contains("feat/1234-add-dark-mode")
+    );
+
+    // The issue link is stored as the branch's note and shown by `wt list`
+    let output = repo
+        .wt_command()
+        .args(["list", "--format=json"])
+        .output()
+        .unwrap();
+    let items: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let item = items
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|item| item["branch"] == "feat/1234-add-dark-mode")
+        .unwrap();
+    assert_eq!(item["note"], "https://github.com/org/repo/issues/1234");
+}
+
+#[rstest]
+fn test_switch_create_devcontainer(repo: TestRepo) {
+    let mock_bin = repo.root_path().join("mock-bin");
+    fs::create_dir_all(&mock_bin).unwrap();
+    copy_mock_binary(&mock_bin, "devcontainer");
+    MockConfig::new("devcontainer")
+        .command(
+            "_default",
+            MockResponse::output("{\"outcome\":\"success\"}"),
+        )
+        .write(&mock_bin);
+    let project: String = format!(
+        "{}-feature",
+        repo.root_path().file_name().unwrap().to_string_lossy()
+    )
+    .to_lowercase()
+    .chars()
+    .map(|c| {
+        if c.is_ascii_alphanumeric() || c == '_' {
+            c
+        } else {
+            '-'
+        }
+    })
+    .collect();
+    copy_mock_binary(&mock_bin, "docker");
+    MockConfig::new("docker")
+        .command("ps", MockResponse::output(&format!("{project}\trunning\n")))
+        .command("_default", MockResponse::output(""))
+        .write(&mock_bin);
+
+    let mut cmd = repo.wt_command();
+    cmd.args(["switch", "--create", "feature", "--devcontainer", "--no-cd"]);
+    configure_mock_gh_env(&mut cmd, &mock_bin);
+    let output = cmd.output().unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert!(stderr.contains("Started dev container"), "stderr: {stderr}");
+
+    // The container's state shows in `wt list`
+    let mut cmd = repo.wt_command();
+    cmd.args(["list", "--format=json"]);
+    configure_mock_gh_env(&mut cmd, &mock_bin);
+    let output = cmd.output().unwrap();
+    let items: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let item = items
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|item| item["branch"] == "feature")
+        .unwrap();
+    assert_eq!(item["container"], "running");
+
+    // `wt remove` tears it down
+    let mut cmd = repo.wt_command();
+    cmd.args(["remove", "feature", "--foreground"]);
+    configure_mock_gh_env(&mut cmd, &mock_bin);
+    let output = cmd.output().unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert!(stderr.contains("Removed dev container"), "stderr: {stderr}");
+}
+
+#[rstest]
+fn test_switch_describe(repo: TestRepo) {
+    repo.write_test_config(
+        r#"[commit.generation]
+command = "cat >/dev/null && echo '`fix/flaky-login-test`'"
+"#,
+    );
+    repo.write_project_config("[branches]\nnaming = [\"feature/*\"]\n");
+    repo.commit("Add project config");
+
+    let output = repo
+        .wt_command()
+        .args([
+            "switch",
+            "--create",
+            "--describe",
+            "fix flaky login test",
+            "--yes",
+            "--no-cd",
+        ])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert!(
+        repo.git_output(&["branch", "--list", "fix/flaky-login-test"])
+            .contains("fix/flaky-login-test")
+    );
+    // The suggestion is kept, but flagged as not matching the naming patterns
+    assert!(stderr.contains("[branches] naming"), "stderr: {stderr}");
+}
+
+#[rstest]
+fn test_switch_from_issue_requires_create(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["switch", "--from-issue", "1234"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("--create"), "stderr: {stderr}");
+}
+
+#[rstest]
+fn test_switch_detach_at_tag(repo: TestRepo) {
+    repo.run_git(&["tag", "v1.0"]);
+    repo.commit("After the release");
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--detach", "v1.0", "--no-cd"])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert!(
+        stderr.contains("Created detached worktree for"),
+        "stderr: {stderr}"
+    );
+    let worktrees = repo.git_output(&["worktree", "list", "--porcelain"]);
+    assert!(worktrees.contains("repo.v1.0\n"), "{worktrees}");
+    assert!(worktrees.contains("detached"), "{worktrees}");
+
+    // Switching again reuses it
+    let output = repo
+        .wt_command()
+        .args(["switch", "--detach", "v1.0", "--no-cd"])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert!(
+        stderr.contains("Switched to detached worktree"),
+        "stderr: {stderr}"
+    );
+
+    // `wt list` names the tag in place of a branch
+    let output = repo.wt_command().arg("list").output().unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("(at v1.0)"), "stdout: {stdout}");
+}
+
+#[rstest]
+fn test_switch_detach_unknown_ref(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["switch", "--detach", "no-such-tag"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("no-such-tag"), "stderr: {stderr}");
+}
+
+#[rstest]
+fn test_switch_detach_conflicts_with_create(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["switch", "--detach", "--create", "v1.0"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("--create"), "stderr: {stderr}");
+}
+
+#[rstest]
+fn test_switch_create_orphan(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "gh-pages", "--orphan", "--no-cd"])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert!(stderr.contains("has no commits yet"), "stderr: {stderr}");
+
+    // No files checked out, and the branch is unborn
+    let wt_path = repo.root_path().parent().unwrap().join("repo.gh-pages");
+    let entries: Vec<_> = std::fs::read_dir(&wt_path)
+        .unwrap()
+        .map(|e| e.unwrap().file_name())
+        .filter(|name| name != ".git")
+        .collect();
+    assert!(entries.is_empty(), "{entries:?}");
+    let head = repo.git_output(&["-C", wt_path.to_str().unwrap(), "symbolic-ref", "HEAD"]);
+    assert_eq!(head, "refs/heads/gh-pages");
+    let output = repo
+        .git_command()
+        .args(["rev-parse", "--verify", "--quiet", "refs/heads/gh-pages"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+}
+
+#[rstest]
+fn test_switch_orphan_conflicts_with_base(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "docs", "--orphan", "--base", "main"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("cannot be used with"), "stderr: {stderr}");
+}
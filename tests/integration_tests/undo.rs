@@ -0,0 +1,96 @@
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+
+fn branch_exists(repo: &TestRepo, branch: &str) -> bool {
+    repo.git_command()
+        .args([
+            "rev-parse",
+            "--verify",
+            "--quiet",
+            &format!("refs/heads/{branch}"),
+        ])
+        .output()
+        .unwrap()
+        .status
+        .success()
+}
+
+#[rstest]
+fn test_undo_switch_create(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "feature", "--yes"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let worktree = repo.root_path().parent().unwrap().join(format!(
+        "{}.feature",
+        repo.root_path().file_name().unwrap().to_string_lossy()
+    ));
+    assert!(worktree.exists());
+
+    let output = repo.wt_command().args(["undo", "--list"]).output().unwrap();
+    assert!(String::from_utf8_lossy(&output.stdout).contains("create"));
+
+    let output = repo.wt_command().arg("undo").output().unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(!worktree.exists());
+    assert!(!branch_exists(&repo, "feature"));
+
+    // The journal is empty again
+    let output = repo.wt_command().arg("undo").output().unwrap();
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Nothing to undo"));
+}
+
+#[rstest]
+fn test_undo_remove_restores_worktree(mut repo: TestRepo) {
+    let worktree = repo.add_worktree("feature");
+    let output = repo
+        .wt_command()
+        .args(["remove", "feature", "--foreground", "--yes"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert!(!worktree.exists());
+
+    let output = repo.wt_command().arg("undo").output().unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(worktree.exists());
+    assert!(branch_exists(&repo, "feature"));
+}
+
+#[rstest]
+fn test_undo_forced_remove_restores_untracked(mut repo: TestRepo) {
+    let worktree = repo.add_worktree("feature");
+    std::fs::write(worktree.join("notes.txt"), "draft\n").unwrap();
+    let output = repo
+        .wt_command()
+        .args(["remove", "feature", "--force", "--foreground", "--yes"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(!worktree.exists());
+
+    let output = repo.wt_command().arg("undo").output().unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(
+        std::fs::read_to_string(worktree.join("notes.txt")).unwrap(),
+        "draft\n"
+    );
+}
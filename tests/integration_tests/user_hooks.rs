@@ -91,6 +91,75 @@ approved-commands = ["echo 'PROJECT_HOOK' >> hook_order.txt"]
     assert_eq!(lines[1], "PROJECT_HOOK", "Project hook should run second");
 }
 
+#[rstest]
+fn test_user_hooks_after_project_run_last(repo: TestRepo) {
+    repo.write_project_config(r#"post-create = "echo 'PROJECT_HOOK' >> hook_order.txt""#);
+    repo.commit("Add project config");
+
+    repo.write_test_config(
+        r#"[post-create]
+late = { command = "echo 'LATE_USER_HOOK' >> hook_order.txt", after-project = true }
+early = "echo 'USER_HOOK' >> hook_order.txt"
+
+[projects."../origin"]
+approved-commands = ["echo 'PROJECT_HOOK' >> hook_order.txt"]
+"#,
+    );
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "feature"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let worktree_path = repo.root_path().parent().unwrap().join("repo.feature");
+    let contents = fs::read_to_string(worktree_path.join("hook_order.txt")).unwrap();
+    let lines: Vec<&str> = contents.lines().collect();
+    assert_eq!(lines, ["USER_HOOK", "PROJECT_HOOK", "LATE_USER_HOOK"]);
+}
+
+#[rstest]
+fn test_project_skips_user_hooks(repo: TestRepo) {
+    repo.write_project_config(
+        r#"[user-hooks]
+skip = ["direnv"]
+"#,
+    );
+    repo.commit("Add project config");
+
+    repo.write_test_config(
+        r#"[post-create]
+direnv = "echo 'SKIPPED' > skipped.txt"
+setup = "echo 'RAN' > ran.txt"
+"#,
+    );
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "feature"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let worktree_path = repo.root_path().parent().unwrap().join("repo.feature");
+    assert!(worktree_path.join("ran.txt").exists());
+    assert!(
+        !worktree_path.join("skipped.txt").exists(),
+        "Skipped user hook should not run"
+    );
+
+    // Asking for the hook by name still runs it
+    let output = repo
+        .wt_command()
+        .args(["hook", "post-create", "user:direnv"])
+        .current_dir(&worktree_path)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert!(worktree_path.join("skipped.txt").exists());
+}
+
 #[rstest]
 fn test_user_hooks_no_approval_required(repo: TestRepo) {
     // Write user config with hook but NO pre-approved commands
@@ -1744,3 +1813,104 @@ submodule = "echo '4' >> hook_order.txt"
         "Hooks should execute in TOML insertion order (vscode, claude, copy, submodule)"
     );
 }
+
+#[rstest]
+fn test_user_hook_output_logged(mut repo: TestRepo) {
+    let feature_wt = repo.add_worktree("feature");
+    fs::write(feature_wt.join("uncommitted.txt"), "uncommitted content").unwrap();
+    repo.write_test_config(
+        r#"[pre-commit]
+lint = "echo 'lint: 2 problems' && exit 1"
+"#,
+    );
+
+    let output = repo
+        .wt_command()
+        .args(["merge", "main", "--yes"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    // Output still streams to the terminal, and the error points at the log
+    assert!(stderr.contains("lint: 2 problems"), "stderr: {stderr}");
+    assert!(stderr.contains("Output saved to"), "stderr: {stderr}");
+
+    let output = repo
+        .wt_command()
+        .args(["hook", "logs", "--last"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "lint: 2 problems\n"
+    );
+
+    let output = repo.wt_command().args(["hook", "logs"]).output().unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("user-pre-commit-lint"), "stdout: {stdout}");
+}
+
+#[rstest]
+#[cfg(unix)]
+fn test_user_hook_timeout_kills_hung_command(mut repo: TestRepo) {
+    // pre-remove blocks, so its timeout fails the command
+    repo.write_test_config(
+        r#"[pre-remove]
+hang = { command = "echo 'cleaning...' && sleep 30", timeout = 1 }
+"#,
+    );
+    repo.add_worktree("feature");
+
+    let started = std::time::Instant::now();
+    let output = repo
+        .wt_command()
+        .args(["remove", "feature", "--foreground"])
+        .output()
+        .unwrap();
+    assert!(started.elapsed() < Duration::from_secs(20));
+    assert_eq!(output.status.code(), Some(124));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("pre-remove command timed out after 1s"),
+        "stderr: {stderr}"
+    );
+    assert!(stderr.contains("cleaning..."), "stderr: {stderr}");
+}
+
+#[rstest]
+#[cfg(unix)]
+fn test_user_hook_interrupt_rolls_back_worktree(repo: TestRepo) {
+    // The hook's shell kills itself with SIGINT, as a Ctrl-C would
+    repo.write_test_config(
+        r#"[post-create]
+install = "kill -INT $$"
+"#,
+    );
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "feature"])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(130));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("removed partially-created worktree"),
+        "stderr: {stderr}"
+    );
+
+    let worktrees = repo
+        .git_command()
+        .args(["worktree", "list"])
+        .output()
+        .unwrap();
+    assert!(!String::from_utf8_lossy(&worktrees.stdout).contains("[feature]"));
+    let branch = repo
+        .git_command()
+        .args(["branch", "--list", "feature"])
+        .output()
+        .unwrap();
+    assert!(String::from_utf8_lossy(&branch.stdout).trim().is_empty());
+}